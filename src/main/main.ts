@@ -6,18 +6,51 @@
  * strips X-Frame-Options headers to allow embedding Gemini in an iframe.
  */
 
-import { app, BrowserWindow, crashReporter, session } from 'electron';
+import { app, BrowserWindow, crashReporter, dialog, net, session } from 'electron';
 import * as fs from 'fs';
+import type { Server } from 'net';
 import * as path from 'path';
-import { setupHeaderStripping, setupWebviewSecurity, setupMediaPermissions } from './utils/security';
+import { setupHeaderStripping, setupWebviewSecurity, setupMediaPermissions, loadHeaderPolicy } from './utils/security';
 import { getDistHtmlPath } from './utils/paths';
+import { acquireDataDirLock, releaseDataDirLock } from './utils/dataDirLock';
+import { isSafeMode } from './utils/safeMode';
+import { getProfileDataPath, parseProfileArg } from './utils/profile';
+import { emitTestEvent, enableTestEvents, isTestIpcMode } from './utils/testEvents';
+import {
+    HEADLESS_EXPORT_USAGE,
+    parseHeadlessExportArgs,
+    runHeadlessExportJob,
+    type HeadlessExportOptions,
+} from './utils/headlessExport';
+import {
+    getInstanceCommandSocketPath,
+    parseInstanceCommandArgs,
+    startInstanceCommandServer,
+    type InstanceCommand,
+} from './utils/instanceCommands';
+import { showToast } from './utils/toast';
+import { removeTitlebarWidget, setTitlebarWidget, TITLEBAR_WIDGET_ORDER } from './utils/titlebarWidgets';
+import { formatValidationIssue } from './utils/settingsSchema';
+import { createProxyLoginListener } from './utils/networkSettings';
+import { ENV_OVERRIDE_PREFIX } from './utils/envOverrides';
 import { isLinux, isWindows, APP_ID } from './utils/constants';
 
-import { createLogger } from './utils/logger';
+import { createLogger, setLogLevel } from './utils/logger';
 
 // Setup Logger
 const logger = createLogger('[Main]');
 
+// Apply --log-level=<spec> (e.g. 'debug' or 'warn,ExportManager=debug') before startup logging
+const logLevelArg = app.commandLine.getSwitchValue('log-level');
+if (logLevelArg && !setLogLevel(logLevelArg)) {
+    logger.warn(`Ignoring invalid --log-level value: ${logLevelArg}`);
+}
+
+// --test-ipc streams lifecycle events to stdout for external E2E frameworks
+if (isTestIpcMode()) {
+    enableTestEvents();
+}
+
 // Log critical environment info early for CI debugging
 logger.debug('=== ELECTRON STARTUP DEBUG INFO ===');
 logger.debug('Platform:', process.platform);
@@ -69,6 +102,19 @@ if (isWindows) {
     app.setAppUserModelId(APP_ID);
 }
 
+// --profile <name> switches to the profile's own data directory. This must run after
+// app.setName (which determines the default directory) and before anything reads it.
+const profile = parseProfileArg();
+if (profile && 'error' in profile) {
+    console.error(profile.error);
+    process.exit(2);
+} else if (profile) {
+    const profileDataPath = getProfileDataPath(app.getPath('userData'), profile.name);
+    fs.mkdirSync(profileDataPath, { recursive: true });
+    app.setPath('userData', profileDataPath);
+    logger.log(`Using profile "${profile.name}":`, profileDataPath);
+}
+
 /**
  * Initialize crash reporter EARLY (before app ready).
  * This is critical for preventing OS crash dialogs on Windows/macOS/Linux.
@@ -108,6 +154,8 @@ import UpdateManager, { AutoUpdateSettings } from './managers/updateManager';
 import ExportManager from './managers/exportManager';
 import LlmManager from './managers/llmManager';
 import SettingsStore from './store';
+import type { UserPreferences } from './managers/ipc/types';
+import type { GeminiLocation } from '../shared/types/navigation';
 
 // Path to the production build
 const distIndexPath = getDistHtmlPath('index.html');
@@ -138,6 +186,13 @@ let exportManager: ExportManager;
 /** Handler for response-complete events (stored for cleanup) */
 let responseCompleteHandler: (() => void) | null = null;
 
+// Local socket other processes use to send commands to this instance
+let instanceCommandServer: Server | null = null;
+
+/** How often the titlebar's network status indicator is refreshed */
+const NETWORK_CHECK_INTERVAL_MS = 5000;
+let networkCheckTimer: ReturnType<typeof setInterval> | null = null;
+
 /**
  * Initialize all application managers.
  * This function encapsulates manager creation for better testability and clarity.
@@ -167,6 +222,7 @@ function initializeManagers(): void {
         defaults: {
             autoUpdateEnabled: true,
         },
+        envPrefix: ENV_OVERRIDE_PREFIX,
     });
     logger.debug('initializeManagers() - SettingsStore created');
 
@@ -221,6 +277,176 @@ function initializeManagers(): void {
     logger.debug('initializeManagers() - All managers initialized successfully');
 }
 
+/**
+ * Forward lifecycle events to the --test-ipc stream.
+ * Called once the main window exists.
+ */
+function setupTestEvents(): void {
+    const win = windowManager.getMainWindow();
+    if (win) {
+        emitTestEvent('main-window-created', { bounds: win.getBounds() });
+        win.on('resize', () => emitTestEvent('window-bounds-changed', { bounds: win.getBounds() }));
+        win.webContents.on('did-finish-load', () => emitTestEvent('main-window-loaded'));
+    }
+
+    windowManager.on('gemini-frame-loaded', () => emitTestEvent('gemini-loaded'));
+    windowManager.on('gemini-location-changed', (location: GeminiLocation) =>
+        emitTestEvent('gemini-location-changed', { url: location.url, conversationId: location.conversationId })
+    );
+    windowManager.on('immersive-mode-changed', (enabled: boolean) =>
+        emitTestEvent('immersive-mode-changed', { enabled })
+    );
+    windowManager.on('always-on-top-changed', (enabled: boolean) =>
+        emitTestEvent('always-on-top-changed', { enabled })
+    );
+}
+
+/**
+ * Show the main window, recreating it if it was closed.
+ */
+function showMainWindow(): void {
+    if (windowManager.getMainWindow()) {
+        windowManager.restoreFromTray();
+    } else {
+        windowManager.createMainWindow();
+    }
+}
+
+/**
+ * Run a command from a second instance or the instance command socket.
+ * @param command - Validated command
+ */
+function executeInstanceCommand(command: InstanceCommand): void {
+    logger.log(`Instance command: ${command.command}`);
+    if (command.command === 'new-window') {
+        windowManager.createAdditionalMainWindow();
+        return;
+    }
+    showMainWindow();
+
+    switch (command.command) {
+        case 'focus':
+            break;
+        case 'ask':
+            windowManager.emit('ask-triggered', command.text);
+            break;
+        case 'toggle-compact-mode':
+            windowManager.toggleCompactMode();
+            break;
+        case 'move-to-display':
+            if (!windowManager.moveToDisplay(command.display - 1)) {
+                throw new Error(`Cannot move the window to display ${command.display}`);
+            }
+            break;
+        case 'focus-session':
+            windowManager.emit('focus-session-start-triggered', command.minutes);
+            break;
+        case 'navigate':
+            windowManager.navigateGemini(command.url);
+            break;
+        case 'export':
+            windowManager.emit(command.format === 'pdf' ? 'print-to-pdf-triggered' : 'export-markdown-triggered');
+            break;
+    }
+}
+
+/**
+ * Start accepting commands from other processes on the instance command socket.
+ * Only the instance that owns the data directory listens: the socket lives
+ * there, and a read-only instance (e.g. another OS user) must not take it over.
+ */
+function startInstanceCommands(): void {
+    if (!dataDirLock.acquired) {
+        logger.log('Not accepting instance commands: data directory is owned by another instance');
+        return;
+    }
+
+    const socketPath = getInstanceCommandSocketPath(app.getPath('userData'));
+    try {
+        instanceCommandServer = startInstanceCommandServer(socketPath, executeInstanceCommand);
+        instanceCommandServer.on('error', (error) => {
+            logger.error('Instance command socket error:', error);
+        });
+        logger.log('Accepting instance commands on', socketPath);
+    } catch (error) {
+        logger.error('Failed to start instance command socket:', error);
+    }
+}
+
+/**
+ * Publish the titlebar indicators owned by the app itself: the active profile,
+ * a read-only data directory and the network status.
+ */
+function setupTitlebarWidgets(): void {
+    if (profile && 'name' in profile) {
+        setTitlebarWidget({
+            id: 'profile',
+            label: profile.name,
+            tooltip: `Profile: ${profile.name}`,
+            tone: 'info',
+            order: TITLEBAR_WIDGET_ORDER.profile,
+        });
+    }
+
+    if (!dataDirLock.acquired) {
+        setTitlebarWidget({
+            id: 'read-only',
+            label: 'Read-only',
+            tooltip: 'Another instance is using the data folder; changes to settings are not saved',
+            tone: 'warning',
+            order: TITLEBAR_WIDGET_ORDER.readOnly,
+        });
+    }
+
+    const updateNetworkStatus = () => {
+        if (net.isOnline()) {
+            removeTitlebarWidget('offline');
+        } else {
+            setTitlebarWidget({
+                id: 'offline',
+                label: 'Offline',
+                tooltip: 'No network connection',
+                tone: 'error',
+                order: TITLEBAR_WIDGET_ORDER.offline,
+            });
+        }
+    };
+    updateNetworkStatus();
+    networkCheckTimer = setInterval(updateNetworkStatus, NETWORK_CHECK_INTERVAL_MS);
+}
+
+/**
+ * Run `gemini-desktop export ...`: export one conversation without any window
+ * or manager, print the result and exit.
+ * @param options - Parsed export options
+ * @param dataDirLocked - Whether this process holds the data directory lock
+ */
+function runHeadlessExport(options: HeadlessExportOptions, dataDirLocked: boolean): void {
+    if (!dataDirLocked) {
+        // The signed-in session lives in the data directory, which a running instance holds
+        console.error('Gemini Desktop is running; close it before running a headless export.');
+        app.exit(1);
+        return;
+    }
+
+    const store = new SettingsStore<UserPreferences>({ configName: 'user-preferences' });
+    app.on('login', createProxyLoginListener(store));
+
+    app.dock?.hide();
+    app.whenReady()
+        .then(() => runHeadlessExportJob(options, store, new ExportManager(), session.defaultSession))
+        .then(() => {
+            console.log(`Exported ${options.conversationUrl} to ${options.outPath}`);
+            releaseDataDirLock();
+            app.exit(0);
+        })
+        .catch((error) => {
+            console.error(`Export failed: ${(error as Error).message}`);
+            releaseDataDirLock();
+            app.exit(1);
+        });
+}
+
 /**
  * Gracefully shut down the application.
  * Cleans up all managers before exiting.
@@ -250,6 +476,8 @@ function gracefulShutdown(exitCode: number = 0): void {
             windowManager.setQuitting(true);
         }
 
+        releaseDataDirLock();
+
         logger.log('Graceful shutdown completed');
     } catch (cleanupError) {
         // Log cleanup errors but don't throw - we still need to exit
@@ -262,31 +490,50 @@ function gracefulShutdown(exitCode: number = 0): void {
     }, 500);
 }
 
-// Initialize managers before requesting instance lock
-logger.debug('About to call initializeManagers()');
-initializeManagers();
-logger.debug('initializeManagers() completed');
+// Lock the data directory before any settings store is created. If another
+// instance (e.g. another OS user sharing a portable data dir) holds it, run read-only.
+const dataDirLock = acquireDataDirLock(app.getPath('userData'));
+
+// `gemini-desktop export ...` runs without managers, windows or the single instance lock
+const headlessExport = parseHeadlessExportArgs();
+
+if (headlessExport && 'error' in headlessExport) {
+    console.error(`${headlessExport.error}\n${HEADLESS_EXPORT_USAGE}`);
+    app.exit(2);
+} else if (headlessExport) {
+    runHeadlessExport(headlessExport, dataDirLock.acquired);
+} else {
+    // Initialize managers before requesting instance lock
+    logger.debug('About to call initializeManagers()');
+    initializeManagers();
+    logger.debug('initializeManagers() completed');
+}
 
-// Single Instance Lock
+// Single Instance Lock (not for a headless export, which must not focus a running instance)
 logger.debug('About to request single instance lock');
-const gotTheLock = app.requestSingleInstanceLock();
+const gotTheLock = !headlessExport && app.requestSingleInstanceLock();
 logger.debug('Single instance lock result:', gotTheLock);
 
-if (!gotTheLock) {
+if (headlessExport) {
+    logger.debug('Headless export: skipping window setup');
+} else if (!gotTheLock) {
     logger.log('Another instance is already running. Quitting...');
     app.exit(0);
 } else {
     logger.debug('Got the lock, setting up second-instance handler');
 
-    app.on('second-instance', () => {
-        // Someone tried to run a second instance, we should focus our window.
-        logger.log('Second instance detected. Focusing existing window...');
-        if (windowManager) {
-            if (windowManager.getMainWindow()) {
-                windowManager.restoreFromTray();
-            } else {
-                windowManager.createMainWindow();
-            }
+    app.on('second-instance', (_event, argv) => {
+        // Someone tried to run a second instance: run its command (--ask, --navigate,
+        // --export) or just focus our window.
+        logger.log('Second instance detected. Forwarding its command...');
+        if (!windowManager) return;
+
+        const command = parseInstanceCommandArgs(argv);
+        if ('error' in command) {
+            logger.warn(`Ignoring second instance command: ${command.error}`);
+            executeInstanceCommand({ command: 'focus' });
+        } else {
+            executeInstanceCommand(command);
         }
     });
 
@@ -312,7 +559,7 @@ if (!gotTheLock) {
         logger.log('App ready - starting initialization');
 
         // Apply security settings to default session (used by all windows)
-        setupHeaderStripping(session.defaultSession);
+        setupHeaderStripping(session.defaultSession, loadHeaderPolicy());
         setupMediaPermissions(session.defaultSession);
 
         ipcManager.setupIpcHandlers();
@@ -329,6 +576,56 @@ if (!gotTheLock) {
         logger.debug('createMainWindow() returned');
         logger.log('Main window created');
 
+        if (isTestIpcMode()) {
+            setupTestEvents();
+            emitTestEvent('app-ready');
+        }
+
+        startInstanceCommands();
+        setupTitlebarWidgets();
+
+        if (isSafeMode()) {
+            logger.warn('Running in safe mode: customizations disabled, settings are not loaded or saved');
+            const safeModeWindow = windowManager.getMainWindow();
+            safeModeWindow?.webContents.once('did-finish-load', () => {
+                showToast(safeModeWindow, {
+                    type: 'warning',
+                    title: 'Safe mode',
+                    message: 'Customizations are disabled and settings changes will not be saved.',
+                    duration: null,
+                });
+            });
+        }
+
+        const settingsIssues = ipcManager.store.getValidationIssues();
+        if (settingsIssues.length > 0) {
+            const issuesWindow = windowManager.getMainWindow();
+            issuesWindow?.webContents.once('did-finish-load', () => {
+                showToast(issuesWindow, {
+                    type: 'error',
+                    title: 'Settings file has errors',
+                    message:
+                        `${settingsIssues.map(formatValidationIssue).join('; ')}. ` +
+                        `Defaults are used for these settings. Fix ${ipcManager.store._path} ` +
+                        'or use Options > Reset to restore defaults.',
+                    duration: null,
+                });
+            });
+        }
+
+        if (!dataDirLock.acquired) {
+            const owner = windowManager.getMainWindow();
+            const options = {
+                type: 'warning' as const,
+                title: 'Data directory in use',
+                message: dataDirLock.error.message,
+                detail: 'Gemini Desktop is running in read-only mode. Settings changes will not be saved until the other instance is closed.',
+            };
+            (owner ? dialog.showMessageBox(owner, options) : dialog.showMessageBox(options)).catch((error) =>
+                logger.error('Failed to show data directory lock dialog:', error)
+            );
+        }
+
         // Set main window reference for badge manager (needed for Windows overlay)
         badgeManager.setMainWindow(windowManager.getMainWindow());
         logger.log('Badge manager configured');
@@ -341,6 +638,7 @@ if (!gotTheLock) {
                 defaults: {
                     responseNotificationsEnabled: true,
                 },
+                envPrefix: ENV_OVERRIDE_PREFIX,
             });
             notificationManager = new NotificationManager(mainWindow, badgeManager, notificationSettings);
 
@@ -355,6 +653,10 @@ if (!gotTheLock) {
                     } catch (error) {
                         logger.error('Error in NotificationManager.onResponseComplete:', error);
                     }
+                    // Copy the answer to the clipboard if auto-copy is enabled
+                    ipcManager.onResponseComplete().catch((error) => {
+                        logger.error('Error in IpcManager.onResponseComplete:', error);
+                    });
                 };
                 mainWindowInstance.on('response-complete', responseCompleteHandler);
                 logger.log('NotificationManager subscribed to response-complete events');
@@ -404,9 +706,9 @@ if (!gotTheLock) {
     });
 }
 
-// Quit when all windows are closed (except on macOS)
+// Quit when all windows are closed (except on macOS). A headless export exits on its own.
 app.on('window-all-closed', () => {
-    if (process.platform !== 'darwin') {
+    if (process.platform !== 'darwin' && !headlessExport) {
         app.quit();
     }
 });
@@ -416,11 +718,15 @@ app.on('before-quit', () => {
 });
 
 app.on('will-quit', () => {
+    emitTestEvent('app-quitting');
+    instanceCommandServer?.close();
+    if (networkCheckTimer) clearInterval(networkCheckTimer);
     hotkeyManager.unregisterAll();
     trayManager.destroyTray();
     updateManager.destroy();
     llmManager.dispose();
     ipcManager.dispose();
+    releaseDataDirLock();
     // Clean up response-complete listener
     const mainWindowInstance = windowManager?.getMainWindowInstance();
     if (mainWindowInstance && responseCompleteHandler) {
@@ -0,0 +1,219 @@
+/**
+ * Card Manager for the Electron main process.
+ * Manages pinned answer cards: small always-on-top windows that each show a
+ * single Gemini answer rendered with the export stylesheet.
+ *
+ * @module CardManager
+ */
+
+import { BrowserWindow, clipboard, type WebContents } from 'electron';
+import { randomUUID } from 'crypto';
+import { createLogger } from '../utils/logger';
+import { getCardPreloadPath } from '../utils/paths';
+import { EXPORT_DOCUMENT_CSS } from '../utils/exportHtml';
+
+const logger = createLogger('[CardManager]');
+
+/** Default card size */
+const CARD_WIDTH = 420;
+const CARD_HEIGHT = 320;
+
+/** Offset applied to each new card so stacked cards stay visible */
+const CARD_CASCADE_OFFSET = 24;
+
+/** Number of cascade steps before positions wrap around */
+const CARD_CASCADE_STEPS = 8;
+
+/** Card pages are static: no scripts, inline styles only, remote images allowed */
+const CARD_CONTENT_SECURITY_POLICY = "default-src 'none'; img-src https: data:; style-src 'unsafe-inline'";
+
+/**
+ * Content shown in a pinned card.
+ */
+export interface CardContent {
+    /** Answer content as HTML (not a full document) */
+    html: string;
+    /** Answer as Markdown, used by the Copy button */
+    markdown: string;
+}
+
+interface Card {
+    id: string;
+    window: BrowserWindow;
+    markdown: string;
+}
+
+/**
+ * Manages multiple pinned answer cards.
+ *
+ * Each card is a frameless, always-on-top BrowserWindow loading a static
+ * HTML document whose CSP blocks all scripts. A dedicated preload wires the Copy
+ * and Close buttons back to the main process via IPC.
+ *
+ * @class CardManager
+ */
+export default class CardManager {
+    /** Open cards by ID */
+    private readonly cards = new Map<string, Card>();
+
+    /** Position of the next card in the cascade */
+    private cascadeIndex = 0;
+
+    /**
+     * Open a new card showing the given answer.
+     * @param content - Answer content
+     * @returns The new card's ID
+     */
+    createCard(content: CardContent): string {
+        const id = randomUUID();
+        const offset = (this.cascadeIndex++ % CARD_CASCADE_STEPS) * CARD_CASCADE_OFFSET;
+
+        const win = new BrowserWindow({
+            width: CARD_WIDTH,
+            height: CARD_HEIGHT,
+            x: 80 + offset,
+            y: 80 + offset,
+            minWidth: 240,
+            minHeight: 160,
+            frame: false,
+            alwaysOnTop: true,
+            skipTaskbar: true,
+            resizable: true,
+            minimizable: false,
+            maximizable: false,
+            fullscreenable: false,
+            show: false,
+            title: 'Pinned Answer',
+            webPreferences: {
+                preload: getCardPreloadPath(),
+                contextIsolation: true,
+                nodeIntegration: false,
+                sandbox: true,
+            },
+        });
+
+        this.cards.set(id, { id, window: win, markdown: content.markdown });
+
+        win.once('ready-to-show', () => win.showInactive());
+        win.on('closed', () => {
+            this.cards.delete(id);
+            logger.log(`Card closed: ${id}`);
+        });
+
+        // Links inside an answer must not navigate the card
+        win.webContents.on('will-navigate', (event) => event.preventDefault());
+        win.webContents.setWindowOpenHandler(() => ({ action: 'deny' }));
+
+        win.loadURL(`data:text/html;charset=utf-8,${encodeURIComponent(this.generateCardHtml(content.html))}`).catch(
+            (error) => logger.error('Failed to load card content:', error)
+        );
+
+        logger.log(`Card created: ${id} (${this.cards.size} open)`);
+        return id;
+    }
+
+    /**
+     * Copy the Markdown of the card hosted by the given WebContents.
+     * @param webContents - Sender of the copy request
+     * @returns True if a card was found and copied
+     */
+    copyCard(webContents: WebContents): boolean {
+        const card = this.findCard(webContents);
+        if (!card) return false;
+
+        clipboard.writeText(card.markdown);
+        logger.log(`Card copied: ${card.id}`);
+        return true;
+    }
+
+    /**
+     * Close the card hosted by the given WebContents.
+     * @param webContents - Sender of the close request
+     * @returns True if a card was found and closed
+     */
+    closeCard(webContents: WebContents): boolean {
+        const card = this.findCard(webContents);
+        if (!card) return false;
+
+        if (!card.window.isDestroyed()) card.window.close();
+        return true;
+    }
+
+    /**
+     * Close every open card.
+     */
+    closeAll(): void {
+        for (const card of this.cards.values()) {
+            if (!card.window.isDestroyed()) card.window.destroy();
+        }
+        this.cards.clear();
+    }
+
+    /**
+     * @returns Number of open cards
+     */
+    getCardCount(): number {
+        return this.cards.size;
+    }
+
+    private findCard(webContents: WebContents): Card | undefined {
+        for (const card of this.cards.values()) {
+            if (!card.window.isDestroyed() && card.window.webContents === webContents) {
+                return card;
+            }
+        }
+        return undefined;
+    }
+
+    /**
+     * Wraps answer HTML in a card document with a draggable toolbar.
+     */
+    private generateCardHtml(contentHtml: string): string {
+        return `
+        <!DOCTYPE html>
+        <html>
+        <head>
+            <meta charset="UTF-8">
+            <meta http-equiv="Content-Security-Policy" content="${CARD_CONTENT_SECURITY_POLICY}">
+            <style>
+                ${EXPORT_DOCUMENT_CSS}
+                body {
+                    max-width: none;
+                    padding: 0;
+                    font-size: 14px;
+                }
+                .card-toolbar {
+                    position: sticky;
+                    top: 0;
+                    display: flex;
+                    justify-content: flex-end;
+                    gap: 4px;
+                    padding: 6px 8px;
+                    background: #f6f8fa;
+                    border-bottom: 1px solid #e1e4e8;
+                    -webkit-app-region: drag;
+                }
+                .card-toolbar button {
+                    -webkit-app-region: no-drag;
+                    border: none;
+                    border-radius: 4px;
+                    padding: 2px 8px;
+                    background: transparent;
+                    cursor: pointer;
+                    font: inherit;
+                }
+                .card-toolbar button:hover { background: #e1e4e8; }
+                .content { padding: 12px 16px; }
+            </style>
+        </head>
+        <body>
+            <div class="card-toolbar">
+                <button id="card-copy" title="Copy as Markdown">Copy</button>
+                <button id="card-close" title="Close">&#x2715;</button>
+            </div>
+            <div class="content">${contentHtml}</div>
+        </body>
+        </html>
+        `;
+    }
+}
@@ -1,13 +1,30 @@
-import { app, dialog, BrowserWindow, WebContents } from 'electron';
+import { app, dialog, session, BrowserWindow, Notification, WebContents } from 'electron';
 import * as fs from 'fs/promises';
 import * as path from 'path';
+import { randomUUID } from 'crypto';
 import { createLogger } from '../utils/logger';
 import { IPC_CHANNELS } from '../../shared/constants/ipc-channels';
 import { CHAT_EXTRACTION_SCRIPT } from '../utils/chatExtraction';
+import { buildExportFilename, DEFAULT_EXPORT_FILENAME_TEMPLATE } from '../utils/exportFilename';
+import { EXPORT_DOCUMENT_CSS } from '../utils/exportHtml';
+import { getGeminiConversationId } from '../utils/geminiFrame';
+import { emitTestEvent } from '../utils/testEvents';
+import { removeTitlebarWidget, setTitlebarWidget, TITLEBAR_WIDGET_ORDER } from '../utils/titlebarWidgets';
+import { ensureDiskSpace, isDiskFullError } from '../utils/diskSpace';
+import { isFocusSessionActive } from '../utils/focusSession';
+import {
+    buildFileReferenceIndexHtml,
+    buildFileReferenceIndexMarkdown,
+    findFileReferences,
+    type FileReference,
+} from '../utils/fileReferences';
 import TurndownService from 'turndown';
 // @ts-ignore
 import { gfm } from 'turndown-plugin-gfm';
 import { marked } from 'marked';
+import { publishConfluencePage, toConfluenceStorage, validateConfluenceSettings } from '../utils/confluence';
+import type { ExportFormat, ExportStage, ResearchReportFormat } from '../../shared/types/export';
+import type { ConfluenceSettings } from '../../shared/types/confluence';
 
 const logger = createLogger('[ExportManager]');
 
@@ -15,17 +32,136 @@ interface ChatTurn {
     role: 'user' | 'model';
     text: string;
     html?: string;
+    /** Model that wrote the answer (e.g. '2.5 Flash'), if Gemini showed it */
+    modelName?: string;
+}
+
+/** Model names as Gemini displays them; anything else is dropped rather than escaped */
+const MODEL_NAME_PATTERN = /^[\w .()+-]{1,40}$/;
+
+/**
+ * A Canvas document open beside the conversation.
+ */
+interface CanvasDocument {
+    title: string;
+    text: string;
+    html: string;
+    /** Labels of earlier versions, if the version history was shown */
+    versions: string[];
+}
+
+/**
+ * A source cited by a Deep Research report.
+ */
+interface ResearchSource {
+    title: string;
+    url: string;
+}
+
+/**
+ * A Deep Research report open beside the conversation.
+ */
+interface ResearchReport {
+    title: string;
+    text: string;
+    /** Report body as HTML, keeping its headings and appendix tables */
+    html: string;
+    /** Cited sources, in the order Gemini lists them */
+    sources: ResearchSource[];
 }
 
 interface ChatData {
     title: string;
     timestamp: string;
     conversation: ChatTurn[];
+    /** The open Canvas document, if any */
+    canvas?: CanvasDocument | null;
+    /** The open Deep Research report, if any */
+    report?: ResearchReport | null;
+    /** Conversation ID parsed from the Gemini URL, if available */
+    id?: string;
+    /** Name of the Gem the conversation belongs to, if any */
+    gem?: string;
+}
+
+/** Longest Gem name kept; Gem names are user-chosen, so cap what ends up in headers and filenames */
+const MAX_GEM_NAME_LENGTH = 100;
+
+/**
+ * The latest Gemini answer in each supported representation.
+ */
+export interface LastResponse {
+    markdown: string;
+    text: string;
+    /** Answer content as HTML (not a full document) */
+    html: string;
+    /** Model that wrote the answer, if known */
+    modelName?: string;
+    /** The prompt the answer replies to, if found */
+    prompt?: string;
+}
+
+/**
+ * A running export job, tracked for progress reporting and cancellation.
+ */
+interface ExportJob {
+    id: string;
+    format: ExportFormat;
+    webContents: WebContents;
+    cancelled: boolean;
+    /** Hidden window used for PDF rendering, destroyed on cancel */
+    renderWindow: BrowserWindow | null;
+    /** Window that requested the export, used for taskbar progress */
+    ownerWindow: BrowserWindow | null;
+    /** OS notification shown while the export runs in the background */
+    notification: Notification | null;
+}
+
+/** Only web sources are linked from exported reports */
+function isWebSource(source: ResearchSource): boolean {
+    try {
+        return ['http:', 'https:'].includes(new URL(source.url).protocol);
+    } catch {
+        return false;
+    }
+}
+
+/** Escape text for interpolation into HTML */
+function escapeHtml(text: string): string {
+    return text.replace(/&/g, '&amp;').replace(/</g, '&lt;').replace(/>/g, '&gt;').replace(/"/g, '&quot;');
+}
+
+/** Stages after which a job is finished */
+const TERMINAL_STAGES: readonly ExportStage[] = ['done', 'cancelled', 'error'];
+
+/** How long a headless export waits for the conversation to render */
+const HEADLESS_LOAD_TIMEOUT_MS = 60_000;
+
+/** Interval between extraction attempts while a headless export waits */
+const HEADLESS_POLL_INTERVAL_MS = 1_000;
+
+/** Human-readable format names for notifications */
+const FORMAT_LABELS: Record<ExportFormat, string> = { pdf: 'PDF', markdown: 'Markdown', confluence: 'Confluence' };
+
+/** Shown instead of the format's error message when the disk is (nearly) full */
+const EXPORT_DISK_FULL_MESSAGE = 'Not enough disk space to save the export. Free up space and try again.';
+
+/**
+ * Thrown inside an export pipeline when its job has been cancelled.
+ */
+class ExportCancelledError extends Error {
+    constructor() {
+        super('Export cancelled');
+        this.name = 'ExportCancelledError';
+    }
 }
 
 export default class ExportManager {
     private turndown: TurndownService;
 
+    /** Running export jobs by ID */
+    private readonly jobs = new Map<string, ExportJob>();
+
     constructor() {
         this.turndown = new TurndownService({
             headingStyle: 'atx',
@@ -113,50 +249,422 @@ export default class ExportManager {
                 );
             }
 
-            return data as ChatData;
+            const conversation = (data?.conversation ?? []).map((turn: ChatTurn) => ({
+                ...turn,
+                modelName: turn.modelName && MODEL_NAME_PATTERN.test(turn.modelName) ? turn.modelName : undefined,
+            }));
+            const canvas = data?.canvas?.text ? data.canvas : null;
+            const report = data?.report?.text
+                ? { ...data.report, sources: (data.report.sources ?? []).filter(isWebSource) }
+                : null;
+            const gem = typeof data?.gem === 'string' ? data.gem.slice(0, MAX_GEM_NAME_LENGTH) || undefined : undefined;
+            const id = getGeminiConversationId(targetFrame.url);
+            return { ...data, conversation, canvas, report, gem, id } as ChatData;
         } catch (error) {
             logger.error('Failed to extract chat data:', error);
             return null;
         }
     }
 
+    /**
+     * Cancels a running export job.
+     * @param jobId - The job ID reported in progress events
+     * @returns True if the job was found and cancelled
+     */
+    cancelExport(jobId: string): boolean {
+        const job = this.jobs.get(jobId);
+        if (!job || job.cancelled) return false;
+
+        job.cancelled = true;
+        if (job.renderWindow && !job.renderWindow.isDestroyed()) {
+            // Abort an in-flight printToPDF
+            job.renderWindow.destroy();
+        }
+        logger.log(`Export job ${jobId} cancelled`);
+        return true;
+    }
+
+    /**
+     * Registers a new export job.
+     */
+    private startJob(webContents: WebContents, format: ExportFormat): ExportJob {
+        const job: ExportJob = {
+            id: randomUUID(),
+            format,
+            webContents,
+            cancelled: false,
+            renderWindow: null,
+            ownerWindow: BrowserWindow.fromWebContents(webContents),
+            notification: null,
+        };
+        this.jobs.set(job.id, job);
+        this.updateExportsWidget();
+        return job;
+    }
+
+    /**
+     * Shows the number of running exports in the titlebar.
+     */
+    private updateExportsWidget(): void {
+        const count = this.jobs.size;
+        if (count === 0) {
+            removeTitlebarWidget('exports');
+            return;
+        }
+
+        setTitlebarWidget({
+            id: 'exports',
+            label: count === 1 ? 'Exporting…' : `Exporting ${count}…`,
+            tooltip: count === 1 ? 'An export is running' : `${count} exports are running`,
+            tone: 'info',
+            order: TITLEBAR_WIDGET_ORDER.exports,
+        });
+    }
+
+    /**
+     * Sends a progress event for a job to the requesting renderer.
+     */
+    private reportProgress(job: ExportJob, stage: ExportStage, percent: number): void {
+        this.updateSystemProgress(job, stage, percent);
+        if (TERMINAL_STAGES.includes(stage)) {
+            emitTestEvent('export-finished', { format: job.format, stage });
+        }
+        if (job.webContents.isDestroyed()) return;
+        job.webContents.send(IPC_CHANNELS.EXPORT_PROGRESS, {
+            jobId: job.id,
+            format: job.format,
+            stage,
+            percent,
+        });
+    }
+
+    /**
+     * Mirrors job progress outside the app: taskbar/dock progress on the owning
+     * window, plus an OS notification with a Cancel action if the window is in
+     * the background and no focus session is running. Notification actions are
+     * only supported on macOS; other platforms rely on the taskbar progress and
+     * the in-app toast.
+     */
+    private updateSystemProgress(job: ExportJob, stage: ExportStage, percent: number): void {
+        const win = job.ownerWindow;
+        const finished = TERMINAL_STAGES.includes(stage);
+
+        if (win && !win.isDestroyed()) {
+            // -1 removes the progress bar
+            win.setProgressBar(finished ? -1 : percent / 100);
+        }
+
+        if (finished) {
+            job.notification?.close();
+            job.notification = null;
+            return;
+        }
+
+        if (
+            !job.notification &&
+            win &&
+            !win.isDestroyed() &&
+            !win.isFocused() &&
+            !isFocusSessionActive() &&
+            Notification.isSupported()
+        ) {
+            try {
+                const notification = new Notification({
+                    title: `Exporting chat to ${FORMAT_LABELS[job.format]}…`,
+                    body: 'The export is running in the background.',
+                    silent: true,
+                    actions: [{ type: 'button', text: 'Cancel' }],
+                });
+                notification.on('action', () => this.cancelExport(job.id));
+                notification.show();
+                job.notification = notification;
+            } catch (error) {
+                logger.error('Failed to show export progress notification:', error);
+            }
+        }
+    }
+
+    /**
+     * Throws if the job has been cancelled.
+     */
+    private throwIfCancelled(job: ExportJob): void {
+        if (job.cancelled) throw new ExportCancelledError();
+    }
+
+    /**
+     * Runs an export pipeline as a tracked job, reporting terminal progress
+     * and showing a toast for success, cancellation or failure.
+     */
+    private async runJob(
+        webContents: WebContents,
+        format: ExportFormat,
+        successMessage: string,
+        errorMessage: string,
+        pipeline: (job: ExportJob) => Promise<void>
+    ): Promise<void> {
+        const job = this.startJob(webContents, format);
+        try {
+            await pipeline(job);
+            this.throwIfCancelled(job);
+            this.reportProgress(job, 'done', 100);
+            webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: successMessage, type: 'success' });
+        } catch (error) {
+            if (job.cancelled || error instanceof ExportCancelledError) {
+                this.reportProgress(job, 'cancelled', 0);
+                webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: 'Export cancelled', type: 'info' });
+            } else if (isDiskFullError(error)) {
+                logger.error(`${errorMessage}:`, error);
+                this.reportProgress(job, 'error', 0);
+                webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: EXPORT_DISK_FULL_MESSAGE, type: 'error' });
+            } else {
+                logger.error(`${errorMessage}:`, error);
+                this.reportProgress(job, 'error', 0);
+                webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: errorMessage, type: 'error' });
+            }
+        } finally {
+            this.jobs.delete(job.id);
+            this.updateExportsWidget();
+        }
+    }
+
+    /**
+     * Writes an export, refusing up front if it would leave the disk almost full.
+     * @throws DiskFullError if free space is below the low disk space threshold
+     */
+    private async writeExportFile(filePath: string, content: string | Buffer): Promise<void> {
+        await ensureDiskSpace(path.dirname(filePath), Buffer.byteLength(content));
+        await fs.writeFile(filePath, content);
+    }
+
+    /**
+     * Returns the latest Gemini answer as Markdown, plain text and rendered HTML,
+     * with the prompt it replies to.
+     * @param webContents - WebContents hosting the Gemini frame
+     * @returns The last model turn, or null if none was found
+     */
+    async getLastResponse(webContents: WebContents): Promise<LastResponse | null> {
+        const conversation = (await this.extractChatData(webContents))?.conversation ?? [];
+        const lastModelIndex = conversation.map((turn) => turn.role).lastIndexOf('model');
+        const lastModelTurn = conversation[lastModelIndex];
+        if (!lastModelTurn) return null;
+
+        const prompt = conversation
+            .slice(0, lastModelIndex)
+            .filter((turn) => turn.role === 'user')
+            .pop()?.text;
+        return {
+            markdown: lastModelTurn.html ? this.turndown.turndown(lastModelTurn.html) : lastModelTurn.text,
+            text: lastModelTurn.text,
+            // Same content HTML the PDF export renders
+            html: lastModelTurn.html || (marked.parse(lastModelTurn.text) as string),
+            modelName: lastModelTurn.modelName,
+            prompt,
+        };
+    }
+
+    /**
+     * Returns the open conversation as the Markdown the Markdown export writes.
+     * @param webContents - WebContents hosting the Gemini frame
+     * @returns The Markdown, or null if there is no conversation
+     */
+    async getConversationMarkdown(webContents: WebContents): Promise<string | null> {
+        const data = await this.extractChatData(webContents);
+        if (!data || data.conversation.length === 0) return null;
+        return this.generateMarkdown(data);
+    }
+
     /**
      * Exports chat to Markdown.
+     * @param webContents - WebContents hosting the Gemini frame
+     * @param filenameTemplate - Filename template for the save dialog default
      */
-    async exportToMarkdown(webContents: WebContents): Promise<void> {
+    async exportToMarkdown(
+        webContents: WebContents,
+        filenameTemplate: string = DEFAULT_EXPORT_FILENAME_TEMPLATE
+    ): Promise<void> {
         const data = await this.extractChatData(webContents);
         if (!data) {
             webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: 'Failed to extract chat data', type: 'error' });
             return;
         }
 
-        let markdown = `# ${data.title}\n\n*Exported on ${new Date(data.timestamp).toLocaleString()}*\n\n---\n\n`;
+        const markdown = this.generateMarkdown(data);
 
-        for (const turn of data.conversation) {
-            const role = turn.role === 'user' ? '## You' : '## Gemini';
-            const content = turn.html ? this.turndown.turndown(turn.html) : turn.text;
-            markdown += `${role}\n\n${content}\n\n---\n\n`;
+        const { filePath, canceled } = await dialog.showSaveDialog({
+            title: 'Save Chat as Markdown',
+            defaultPath: path.join(
+                app.getPath('downloads'),
+                buildExportFilename(filenameTemplate, {
+                    title: data.title,
+                    timestamp: data.timestamp,
+                    id: data.id,
+                    gem: data.gem,
+                    format: 'md',
+                })
+            ),
+            filters: [{ name: 'Markdown Files', extensions: ['md'] }],
+        });
+
+        if (canceled || !filePath) return;
+
+        await this.runJob(
+            webContents,
+            'markdown',
+            'Chat exported to Markdown',
+            'Failed to write Markdown',
+            async (job) => {
+                this.reportProgress(job, 'writing', 50);
+                await this.writeExportFile(filePath, markdown);
+            }
+        );
+    }
+
+    /**
+     * Exports the open Canvas document to Markdown.
+     * @param webContents - WebContents hosting the Gemini frame
+     * @param filenameTemplate - Filename template for the save dialog default
+     */
+    async exportCanvasToMarkdown(
+        webContents: WebContents,
+        filenameTemplate: string = DEFAULT_EXPORT_FILENAME_TEMPLATE
+    ): Promise<void> {
+        const data = await this.extractChatData(webContents);
+        if (!data) {
+            webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: 'Failed to extract chat data', type: 'error' });
+            return;
+        }
+        if (!data.canvas) {
+            webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: 'No Canvas is open', type: 'info' });
+            return;
         }
 
+        const title = data.canvas.title || data.title;
+        const markdown =
+            `# ${title}\n\n${this.getHeaderMarkdown(data)}\n\n---\n\n` +
+            `${this.getCanvasMarkdown(data.canvas)}\n`;
+
         const { filePath, canceled } = await dialog.showSaveDialog({
-            title: 'Save Chat as Markdown',
+            title: 'Save Canvas as Markdown',
             defaultPath: path.join(
                 app.getPath('downloads'),
-                `${data.title.replace(/\s+/g, '_').replace(/[/\\?%*:|"<>]/g, '-')}.md`
+                buildExportFilename(filenameTemplate, {
+                    title,
+                    timestamp: data.timestamp,
+                    id: data.id,
+                    gem: data.gem,
+                    format: 'md',
+                })
             ),
             filters: [{ name: 'Markdown Files', extensions: ['md'] }],
         });
 
         if (canceled || !filePath) return;
 
-        await fs.writeFile(filePath, markdown);
-        webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: 'Chat exported to Markdown', type: 'success' });
+        await this.runJob(
+            webContents,
+            'markdown',
+            'Canvas exported to Markdown',
+            'Failed to write Markdown',
+            async (job) => {
+                this.reportProgress(job, 'writing', 50);
+                await this.writeExportFile(filePath, markdown);
+            }
+        );
+    }
+
+    /**
+     * Exports the open Deep Research report as a document of its own, keeping
+     * its sections, tables and source list.
+     * @param webContents - WebContents hosting the Gemini frame
+     * @param format - Output format
+     * @param filenameTemplate - Filename template for the save dialog default
+     */
+    async exportResearchReport(
+        webContents: WebContents,
+        format: ResearchReportFormat,
+        filenameTemplate: string = DEFAULT_EXPORT_FILENAME_TEMPLATE
+    ): Promise<void> {
+        const data = await this.extractChatData(webContents);
+        if (!data) {
+            webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: 'Failed to extract chat data', type: 'error' });
+            return;
+        }
+        if (!data.report) {
+            webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: 'No Deep Research report is open', type: 'info' });
+            return;
+        }
+
+        const report = data.report;
+        const title = report.title || data.title;
+        const extension = format === 'pdf' ? 'pdf' : 'md';
+
+        const { filePath, canceled } = await dialog.showSaveDialog({
+            title: `Save Research Report as ${FORMAT_LABELS[format]}`,
+            defaultPath: path.join(
+                app.getPath('downloads'),
+                buildExportFilename(filenameTemplate, {
+                    title,
+                    timestamp: data.timestamp,
+                    id: data.id,
+                    gem: data.gem,
+                    format: extension,
+                })
+            ),
+            filters: [
+                format === 'pdf'
+                    ? { name: 'PDF Files', extensions: ['pdf'] }
+                    : { name: 'Markdown Files', extensions: ['md'] },
+            ],
+        });
+
+        if (canceled || !filePath) return;
+
+        if (format === 'markdown') {
+            const markdown =
+                `# ${title}\n\n${this.getHeaderMarkdown(data)}\n\n---\n\n` +
+                `${this.getResearchReportMarkdown(report)}\n`;
+            await this.runJob(
+                webContents,
+                'markdown',
+                'Research report exported to Markdown',
+                'Failed to write Markdown',
+                async (job) => {
+                    this.reportProgress(job, 'writing', 50);
+                    await this.writeExportFile(filePath, markdown);
+                }
+            );
+            return;
+        }
+
+        await this.runJob(
+            webContents,
+            'pdf',
+            'Research report exported to PDF',
+            'Failed to generate PDF',
+            async (job) => {
+                this.reportProgress(job, 'preparing', 10);
+                const htmlContent = this.generateResearchReportHtml(data, report);
+                this.throwIfCancelled(job);
+
+                this.reportProgress(job, 'rendering', 30);
+                const pdfBuffer = await this.renderHtmlToPdf(htmlContent, job);
+                this.throwIfCancelled(job);
+
+                this.reportProgress(job, 'writing', 90);
+                await this.writeExportFile(filePath, pdfBuffer);
+            }
+        );
     }
 
     /**
      * Exports chat to PDF (High-fidelity rendered HTML).
+     * @param webContents - WebContents hosting the Gemini frame
+     * @param filenameTemplate - Filename template for the save dialog default
      */
-    async exportToPdf(webContents: WebContents): Promise<void> {
+    async exportToPdf(
+        webContents: WebContents,
+        filenameTemplate: string = DEFAULT_EXPORT_FILENAME_TEMPLATE
+    ): Promise<void> {
         const data = await this.extractChatData(webContents);
         if (!data) {
             webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: 'Failed to extract chat data', type: 'error' });
@@ -167,31 +675,251 @@ export default class ExportManager {
             title: 'Save Chat as PDF',
             defaultPath: path.join(
                 app.getPath('downloads'),
-                `${data.title.replace(/\s+/g, '_').replace(/[/\\?%*:|"<>]/g, '-')}.pdf`
+                buildExportFilename(filenameTemplate, {
+                    title: data.title,
+                    timestamp: data.timestamp,
+                    id: data.id,
+                    gem: data.gem,
+                    format: 'pdf',
+                })
             ),
             filters: [{ name: 'PDF Files', extensions: ['pdf'] }],
         });
 
         if (canceled || !filePath) return;
 
-        try {
+        await this.runJob(webContents, 'pdf', 'Chat exported to PDF', 'Failed to generate PDF', async (job) => {
+            this.reportProgress(job, 'preparing', 10);
             const htmlContent = this.generatePdfHtml(data);
-            const pdfBuffer = await this.renderHtmlToPdf(htmlContent);
-            await fs.writeFile(filePath, pdfBuffer);
-            webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: 'Chat exported to PDF', type: 'success' });
-        } catch (error) {
-            logger.error('Failed to generate PDF:', error);
-            webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: 'Failed to generate PDF', type: 'error' });
+            this.throwIfCancelled(job);
+
+            this.reportProgress(job, 'rendering', 30);
+            const pdfBuffer = await this.renderHtmlToPdf(htmlContent, job);
+            this.throwIfCancelled(job);
+
+            this.reportProgress(job, 'writing', 90);
+            await this.writeExportFile(filePath, pdfBuffer);
+        });
+    }
+
+    /**
+     * Exports chat to a Confluence page, updating the page if one with the
+     * chat's title already exists in the space.
+     * @param webContents - WebContents hosting the Gemini frame
+     * @param settings - Confluence connection and target settings
+     */
+    async exportToConfluence(webContents: WebContents, settings: ConfluenceSettings): Promise<void> {
+        const problem = validateConfluenceSettings(settings, true);
+        if (problem) {
+            webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: `Cannot export: ${problem}`, type: 'error' });
+            return;
+        }
+
+        const data = await this.extractChatData(webContents);
+        if (!data) {
+            webContents.send(IPC_CHANNELS.TOAST_SHOW, { message: 'Failed to extract chat data', type: 'error' });
+            return;
+        }
+
+        await this.runJob(
+            webContents,
+            'confluence',
+            'Chat exported to Confluence',
+            'Failed to export to Confluence',
+            async (job) => {
+                this.reportProgress(job, 'preparing', 10);
+                const storage = this.generateConfluenceStorage(data);
+                this.throwIfCancelled(job);
+
+                this.reportProgress(job, 'uploading', 50);
+                const pageUrl = await publishConfluencePage(session.defaultSession, settings, data.title, storage);
+                logger.log(`Chat exported to Confluence: ${pageUrl}`);
+            }
+        );
+    }
+
+    /**
+     * Exports a conversation to a file without any UI: the conversation is
+     * loaded in a hidden window using the app's signed-in session, extracted
+     * once its turns have rendered, and written to the given path. Used by the
+     * `export` command-line subcommand.
+     * @param conversationUrl - Gemini conversation URL
+     * @param format - Output format
+     * @param filePath - Destination file
+     * @param timeoutMs - How long to wait for the conversation to render
+     * @throws If the URL is not a Gemini URL, Gemini asks to sign in, or the conversation never renders
+     */
+    async exportConversationToFile(
+        conversationUrl: string,
+        format: Extract<ExportFormat, 'pdf' | 'markdown'>,
+        filePath: string,
+        timeoutMs: number = HEADLESS_LOAD_TIMEOUT_MS
+    ): Promise<void> {
+        if (!this.isAllowedGeminiUrl(conversationUrl)) {
+            throw new Error(`Not a Gemini URL: ${conversationUrl}`);
+        }
+
+        const win = new BrowserWindow({
+            show: false,
+            webPreferences: {
+                contextIsolation: true,
+                nodeIntegration: false,
+                sandbox: true,
+            },
+        });
+
+        try {
+            await win.loadURL(conversationUrl);
+            if (!this.isAllowedGeminiUrl(win.webContents.getURL())) {
+                throw new Error('Gemini asked to sign in; sign in from the app first');
+            }
+
+            const data = await this.waitForChatData(win.webContents, timeoutMs);
+            if (!data) {
+                throw new Error('Timed out waiting for the conversation to load');
+            }
+
+            if (format === 'markdown') {
+                await this.writeExportFile(filePath, this.generateMarkdown(data));
+            } else {
+                await this.writeExportFile(filePath, await this.renderHtmlToPdf(this.generatePdfHtml(data)));
+            }
+            logger.log(`Conversation exported to ${filePath} (${data.conversation.length} turns)`);
+        } finally {
+            if (!win.isDestroyed()) win.destroy();
         }
     }
 
+    /**
+     * Extracts chat data once the conversation has rendered. Gemini loads
+     * conversations after the page itself, so an early extraction finds no turns.
+     * @returns The chat data, or null if no turns appeared in time
+     */
+    private async waitForChatData(webContents: WebContents, timeoutMs: number): Promise<ChatData | null> {
+        const deadline = Date.now() + timeoutMs;
+        while (Date.now() < deadline) {
+            const data = await this.extractChatData(webContents);
+            if (data && data.conversation.length > 0) return data;
+            await new Promise((resolve) => setTimeout(resolve, HEADLESS_POLL_INTERVAL_MS));
+        }
+        return null;
+    }
+
+    /**
+     * Local files referenced in Gemini's answers, for the export's file index.
+     */
+    private getFileReferences(data: ChatData): FileReference[] {
+        const answers = data.conversation.filter((turn) => turn.role === 'model').map((turn) => turn.text);
+        return findFileReferences(answers.join('\n'));
+    }
+
+    /**
+     * Heading for a turn in exports: 'You', or 'Gemini' with the answering model when known.
+     */
+    private getRoleLabel(turn: ChatTurn): string {
+        if (turn.role === 'user') return 'You';
+        return turn.modelName ? `Gemini (${turn.modelName})` : 'Gemini';
+    }
+
+    /**
+     * Markdown lines under the export title: export date and, for Gem chats, the Gem name.
+     */
+    private getHeaderMarkdown(data: ChatData): string {
+        const exported = `*Exported on ${new Date(data.timestamp).toLocaleString()}*`;
+        return data.gem ? `${exported}\n\n*Gem: ${data.gem}*` : exported;
+    }
+
+    /**
+     * Heading for the Canvas section of chat exports.
+     */
+    private getCanvasLabel(canvas: CanvasDocument): string {
+        return canvas.title ? `Canvas: ${canvas.title}` : 'Canvas';
+    }
+
+    /**
+     * Canvas content as Markdown, followed by its earlier versions when known.
+     */
+    private getCanvasMarkdown(canvas: CanvasDocument): string {
+        let markdown = canvas.html ? this.turndown.turndown(canvas.html) : canvas.text;
+        if (canvas.versions.length > 0) {
+            markdown += `\n\n### Version history\n\n${canvas.versions.map((version) => `- ${version}`).join('\n')}`;
+        }
+        return markdown;
+    }
+
+    /**
+     * Generates the Markdown document for a chat export.
+     */
+    private generateMarkdown(data: ChatData): string {
+        let markdown = `# ${data.title}\n\n${this.getHeaderMarkdown(data)}\n\n---\n\n`;
+
+        for (const turn of data.conversation) {
+            const role = `## ${this.getRoleLabel(turn)}`;
+            const content = turn.html ? this.turndown.turndown(turn.html) : turn.text;
+            markdown += `${role}\n\n${content}\n\n---\n\n`;
+        }
+        if (data.canvas) {
+            markdown += `## ${this.getCanvasLabel(data.canvas)}\n\n${this.getCanvasMarkdown(data.canvas)}\n\n---\n\n`;
+        }
+        return markdown + buildFileReferenceIndexMarkdown(this.getFileReferences(data));
+    }
+
+    /**
+     * Report body and numbered source list as Markdown.
+     */
+    private getResearchReportMarkdown(report: ResearchReport): string {
+        let markdown = report.html ? this.turndown.turndown(report.html) : report.text;
+        if (report.sources.length > 0) {
+            const sources = report.sources.map(
+                (source, i) => `${i + 1}. [${source.title || source.url}](${source.url})`
+            );
+            markdown += `\n\n## Sources\n\n${sources.join('\n')}`;
+        }
+        return markdown;
+    }
+
+    /**
+     * Generates the PDF document for a Deep Research report. The report's own
+     * HTML is used as-is so its headings and tables survive.
+     */
+    private generateResearchReportHtml(data: ChatData, report: ResearchReport): string {
+        const title = report.title || data.title;
+        const sourceItems = report.sources.map(
+            (source) => `<li><a href="${escapeHtml(source.url)}">${escapeHtml(source.title || source.url)}</a></li>`
+        );
+        const sourcesHtml = sourceItems.length > 0 ? `<h2>Sources</h2><ol>${sourceItems.join('')}</ol>` : '';
+
+        return `
+        <!DOCTYPE html>
+        <html>
+        <head>
+            <meta charset="UTF-8">
+            <style>
+                ${EXPORT_DOCUMENT_CSS}
+            </style>
+        </head>
+        <body>
+            <div class="header">
+                <h1 class="title">${escapeHtml(title)}</h1>
+                <div class="timestamp">Exported on ${new Date(data.timestamp).toLocaleString()}</div>
+                ${data.gem ? `<div class="timestamp">Gem: ${escapeHtml(data.gem)}</div>` : ''}
+            </div>
+            <div class="content">
+                ${report.html || marked.parse(report.text)}
+                ${sourcesHtml}
+            </div>
+        </body>
+        </html>
+        `;
+    }
+
     /**
      * Generates a professionally styled HTML document for the PDF.
      */
     private generatePdfHtml(data: ChatData): string {
         const turnsHtml = data.conversation
             .map((turn) => {
-                const roleLabel = turn.role === 'user' ? 'You' : 'Gemini';
+                const roleLabel = this.getRoleLabel(turn);
                 const roleClass = turn.role === 'user' ? 'user-role' : 'model-role';
                 // Use the extracted HTML if available, otherwise convert Markdown to HTML
                 const contentHtml = turn.html || marked.parse(turn.text);
@@ -205,123 +933,74 @@ export default class ExportManager {
             })
             .join('');
 
+        const canvasHtml = data.canvas
+            ? `
+                <div class="chat-turn">
+                    <div class="role-header model-role">${escapeHtml(this.getCanvasLabel(data.canvas))}</div>
+                    <div class="content">${marked.parse(this.getCanvasMarkdown(data.canvas))}</div>
+                </div>
+            `
+            : '';
+
         return `
         <!DOCTYPE html>
         <html>
         <head>
             <meta charset="UTF-8">
             <style>
-                body {
-                    font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, Helvetica, Arial, sans-serif;
-                    line-height: 1.6;
-                    color: #333;
-                    max-width: 800px;
-                    margin: 0 auto;
-                    padding: 40px;
-                    background: #fff;
-                }
-                .header {
-                    text-align: center;
-                    border-bottom: 2px solid #eee;
-                    margin-bottom: 40px;
-                    padding-bottom: 20px;
-                }
-                .title {
-                    font-size: 28px;
-                    font-weight: bold;
-                    margin: 0;
-                    color: #1a1a1b;
-                }
-                .timestamp {
-                    font-size: 14px;
-                    color: #666;
-                    margin-top: 8px;
-                }
-                .chat-turn {
-                    margin-bottom: 40px;
-                    page-break-inside: avoid;
-                }
-                .role-header {
-                    font-size: 18px;
-                    font-weight: 600;
-                    margin-bottom: 12px;
-                    padding-bottom: 4px;
-                    border-bottom: 1px solid #f0f0f0;
-                }
-                .user-role { color: #1a73e8; }
-                .model-role { color: #1e1e1e; }
-                .content {
-                    font-size: 15px;
-                    overflow-wrap: break-word;
-                }
-                pre {
-                    background: #f6f8fa;
-                    padding: 16px;
-                    border-radius: 8px;
-                    overflow-x: auto;
-                    font-family: inherit;
-                    border: 1px solid #e1e4e8;
-                }
-                code {
-                    font-family: ui-monospace, SFMono-Regular, SF Mono, Menlo, Consolas, Liberation Mono, monospace;
-                    font-size: 85%;
-                    background: rgba(175, 184, 193, 0.2);
-                    padding: 0.2em 0.4em;
-                    border-radius: 6px;
-                }
-                pre code {
-                    background: none;
-                    padding: 0;
-                    font-size: 13px;
-                }
-                table {
-                    border-collapse: collapse;
-                    width: 100%;
-                    margin: 16px 0;
-                }
-                th, td {
-                    border: 1px solid #dfe2e5;
-                    padding: 8px 12px;
-                    text-align: left;
-                }
-                th { background-color: #f6f8fa; }
-                tr:nth-child(even) { background-color: #fafbfc; }
-                blockquote {
-                    margin: 0 0 16px;
-                    padding: 0 1em;
-                    color: #6a737d;
-                    border-left: 0.25em solid #dfe2e5;
-                }
-                img { max-width: 100%; }
-                @media print {
-                    body { padding: 0; }
-                    .chat-turn { page-break-inside: avoid; border-bottom: none; }
-                }
+                ${EXPORT_DOCUMENT_CSS}
             </style>
         </head>
         <body>
             <div class="header">
                 <h1 class="title">${data.title}</h1>
                 <div class="timestamp">Exported on ${new Date(data.timestamp).toLocaleString()}</div>
+                ${data.gem ? `<div class="timestamp">Gem: ${escapeHtml(data.gem)}</div>` : ''}
             </div>
             <div class="conversation">
                 ${turnsHtml}
+                ${canvasHtml}
             </div>
+            ${buildFileReferenceIndexHtml(this.getFileReferences(data))}
         </body>
         </html>
         `;
     }
 
+    /**
+     * Generates the Confluence page body in storage format. Turns go through
+     * Markdown first so the HTML handed to Confluence is well-formed.
+     */
+    private generateConfluenceStorage(data: ChatData): string {
+        const turns = data.conversation.map((turn) => {
+            const role = this.getRoleLabel(turn);
+            const markdown = turn.html ? this.turndown.turndown(turn.html) : turn.text;
+            return `<h2>${role}</h2>${toConfluenceStorage(marked.parse(markdown) as string)}`;
+        });
+        if (data.canvas) {
+            const canvasHtml = marked.parse(this.getCanvasMarkdown(data.canvas)) as string;
+            turns.push(`<h2>${escapeHtml(this.getCanvasLabel(data.canvas))}</h2>${toConfluenceStorage(canvasHtml)}`);
+        }
+
+        return (
+            `<p><em>Exported on ${new Date(data.timestamp).toLocaleString()}</em></p>` +
+            (data.gem ? `<p><em>Gem: ${escapeHtml(data.gem)}</em></p>` : '') +
+            turns.join('<hr />') +
+            buildFileReferenceIndexHtml(this.getFileReferences(data))
+        );
+    }
+
     /**
      * Renders HTML content to a PDF buffer using a hidden BrowserWindow.
      */
-    private async renderHtmlToPdf(html: string): Promise<Buffer> {
+    private async renderHtmlToPdf(html: string, job?: ExportJob): Promise<Buffer> {
         const win = new BrowserWindow({
             show: false,
             webPreferences: {
                 offscreen: true,
             },
         });
+        if (job) job.renderWindow = win;
 
         try {
             await win.loadURL(`data:text/html;charset=utf-8,${encodeURIComponent(html)}`);
@@ -337,7 +1016,8 @@ export default class ExportManager {
             });
             return Buffer.from(data);
         } finally {
-            win.destroy();
+            if (job) job.renderWindow = null;
+            if (!win.isDestroyed()) win.destroy();
         }
     }
 }
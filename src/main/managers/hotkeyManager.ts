@@ -399,7 +399,7 @@ export default class HotkeyManager {
         }
 
         // Skip global shortcut registration on Linux (Wayland limitations)
-        if (isLinux && !ENABLE_GLOBAL_HOTKEYS_ON_LINUX) {
+        if (!this.areGlobalHotkeysSupported()) {
             logger.log(`Global hotkey setting updated: ${id} = ${enabled} (registration skipped on Linux)`);
             return;
         }
@@ -452,7 +452,7 @@ export default class HotkeyManager {
      */
     registerShortcuts(): void {
         // Skip registration on Linux when disabled (Wayland limitations)
-        if (isLinux && !ENABLE_GLOBAL_HOTKEYS_ON_LINUX) {
+        if (!this.areGlobalHotkeysSupported()) {
             logger.warn('Global hotkeys are disabled on Linux due to Wayland limitations.');
             return;
         }
@@ -614,6 +614,14 @@ export default class HotkeyManager {
         return this.shortcutActions.filter((action) => !isGlobalHotkey(action.id));
     }
 
+    /**
+     * Whether global hotkeys can be registered on this platform.
+     * They are disabled on Linux (see ENABLE_GLOBAL_HOTKEYS_ON_LINUX).
+     */
+    areGlobalHotkeysSupported(): boolean {
+        return !isLinux || ENABLE_GLOBAL_HOTKEYS_ON_LINUX;
+    }
+
     // =========================================================================
     // Deprecated methods (for backwards compatibility during transition)
     // =========================================================================
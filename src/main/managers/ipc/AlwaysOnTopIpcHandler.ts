@@ -110,7 +110,7 @@ export class AlwaysOnTopIpcHandler extends BaseIpcHandler {
             this.logger.log(`Always on top changed to: ${enabled} (persisted and broadcasting)`);
 
             // Broadcast to all windows
-            this.broadcastToAllWindows(IPC_CHANNELS.ALWAYS_ON_TOP_CHANGED, { enabled });
+            this.publishEvent(IPC_CHANNELS.ALWAYS_ON_TOP_CHANGED, { enabled });
         } catch (error) {
             this.logger.error('Error handling always on top change:', {
                 error: (error as Error).message,
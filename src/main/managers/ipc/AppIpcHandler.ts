@@ -1,13 +1,13 @@
 /**
  * App IPC Handler.
  *
- * Handles application-level IPC channels for opening options windows
- * and Google sign-in authentication.
+ * Handles application-level IPC channels for opening options windows,
+ * Google sign-in authentication and quitting.
  *
  * @module ipc/AppIpcHandler
  */
 
-import { ipcMain } from 'electron';
+import { app, ipcMain } from 'electron';
 import { BaseIpcHandler } from './BaseIpcHandler';
 import { IPC_CHANNELS, GOOGLE_ACCOUNTS_URL } from '../../utils/constants';
 
@@ -17,6 +17,7 @@ import { IPC_CHANNELS, GOOGLE_ACCOUNTS_URL } from '../../utils/constants';
  * Currently handles:
  * - `open-options` - Opens the options window, optionally to a specific tab
  * - `open-google-signin` - Opens Google sign-in authentication window
+ * - `app:quit` - Quits the app, bypassing close to tray
  */
 export class AppIpcHandler extends BaseIpcHandler {
     /**
@@ -46,5 +47,11 @@ export class AppIpcHandler extends BaseIpcHandler {
                 throw error;
             }
         });
+
+        // Quit for real; before-quit marks the main window as quitting so it is not hidden to tray
+        ipcMain.on(IPC_CHANNELS.APP_QUIT, () => {
+            this.logger.log('Quit requested');
+            app.quit();
+        });
     }
 }
@@ -0,0 +1,173 @@
+/**
+ * Auto-Copy IPC Handler.
+ *
+ * Handles IPC channels for answer-to-clipboard automation:
+ * - auto-copy:get-settings - Returns the auto-copy mode and format
+ * - auto-copy:set-settings - Updates the auto-copy mode and/or format
+ *
+ * Also copies the latest answer to the clipboard when a response completes,
+ * depending on the configured mode.
+ *
+ * @module ipc/AutoCopyIpcHandler
+ */
+
+import { ipcMain, clipboard, Notification } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS, APP_NAME } from '../../utils/constants';
+import { showToast } from '../../utils/toast';
+import { isFocusSessionActive } from '../../utils/focusSession';
+import {
+    AUTO_COPY_FORMATS,
+    AUTO_COPY_MODES,
+    type AutoCopyFormat,
+    type AutoCopyMode,
+    type AutoCopySettings,
+} from '../../../shared/types/auto-copy';
+
+/**
+ * Handler for auto-copy IPC channels and response-complete clipboard automation.
+ */
+export class AutoCopyIpcHandler extends BaseIpcHandler {
+    /** Whether the next completed response was requested from Quick Chat */
+    private quickChatPending = false;
+
+    /** Bound listener for quick-chat-submitted events (for removal) */
+    private readonly onQuickChatSubmitted = (): void => {
+        this.quickChatPending = true;
+    };
+
+    /**
+     * Register auto-copy IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.AUTO_COPY_GET_SETTINGS, (): AutoCopySettings => {
+            return this._getSettings();
+        });
+
+        ipcMain.on(IPC_CHANNELS.AUTO_COPY_SET_SETTINGS, (_event, settings: Partial<AutoCopySettings>) => {
+            this._handleSetSettings(settings);
+        });
+
+        // Track Quick Chat submissions so only their answers are copied in 'quick-chat' mode
+        this.deps.windowManager.on('quick-chat-submitted', this.onQuickChatSubmitted);
+    }
+
+    /**
+     * Unregister auto-copy IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.AUTO_COPY_GET_SETTINGS);
+        ipcMain.removeAllListeners(IPC_CHANNELS.AUTO_COPY_SET_SETTINGS);
+        this.deps.windowManager.removeListener('quick-chat-submitted', this.onQuickChatSubmitted);
+    }
+
+    /**
+     * Handle a completed Gemini response.
+     * Copies the latest answer to the clipboard if the current mode allows it.
+     */
+    async onResponseComplete(): Promise<void> {
+        const { mode, format } = this._getSettings();
+        const fromQuickChat = this.quickChatPending;
+        this.quickChatPending = false;
+
+        if (mode === 'off' || (mode === 'quick-chat' && !fromQuickChat)) {
+            return;
+        }
+
+        if (!this.deps.exportManager) {
+            this.logger.warn('ExportManager not available - cannot copy answer');
+            return;
+        }
+
+        const mainWindow = this.deps.windowManager.getMainWindow();
+        if (!mainWindow || mainWindow.isDestroyed()) {
+            this.logger.warn('Cannot copy answer: main window not found or destroyed');
+            return;
+        }
+
+        try {
+            const response = await this.deps.exportManager.getLastResponse(mainWindow.webContents);
+            if (!response) {
+                this.logger.warn('No answer found to copy');
+                return;
+            }
+
+            clipboard.writeText(format === 'markdown' ? response.markdown : response.text);
+            this.logger.log(`Answer copied to clipboard as ${format}`);
+            this._notifyCopied(mainWindow);
+        } catch (error) {
+            this.handleError('copying answer to clipboard', error);
+        }
+    }
+
+    /**
+     * Confirm the copy to the user: a toast in the main window, plus a native
+     * notification when the window is not focused and no focus session is running.
+     * @param mainWindow - The main window
+     */
+    private _notifyCopied(mainWindow: Electron.BrowserWindow): void {
+        showToast(mainWindow, { type: 'success', message: 'Answer copied to clipboard' });
+
+        if (!mainWindow.isFocused() && !isFocusSessionActive() && Notification.isSupported()) {
+            try {
+                new Notification({ title: APP_NAME, body: 'Answer copied to clipboard', silent: true }).show();
+            } catch (error) {
+                this.logger.error('Failed to show auto-copy notification:', error);
+            }
+        }
+    }
+
+    /**
+     * Get the current auto-copy settings, falling back to defaults for invalid values.
+     * @returns Auto-copy settings
+     */
+    private _getSettings(): AutoCopySettings {
+        try {
+            const mode = this.deps.store.get('autoCopyMode') as AutoCopyMode;
+            const format = this.deps.store.get('autoCopyFormat') as AutoCopyFormat;
+            return {
+                mode: AUTO_COPY_MODES.includes(mode) ? mode : 'off',
+                format: AUTO_COPY_FORMATS.includes(format) ? format : 'markdown',
+            };
+        } catch (error) {
+            this.logger.error('Error getting auto-copy settings:', error);
+            return { mode: 'off', format: 'markdown' };
+        }
+    }
+
+    /**
+     * Handle auto-copy:set-settings request.
+     * @param settings - Partial settings to update
+     */
+    private _handleSetSettings(settings: Partial<AutoCopySettings>): void {
+        try {
+            if (!settings || typeof settings !== 'object') {
+                this.logger.warn(`Invalid auto-copy settings: ${settings}`);
+                return;
+            }
+
+            if (settings.mode !== undefined) {
+                if (!AUTO_COPY_MODES.includes(settings.mode)) {
+                    this.logger.warn(`Invalid auto-copy mode: ${settings.mode}`);
+                    return;
+                }
+                this.deps.store.set('autoCopyMode', settings.mode);
+            }
+
+            if (settings.format !== undefined) {
+                if (!AUTO_COPY_FORMATS.includes(settings.format)) {
+                    this.logger.warn(`Invalid auto-copy format: ${settings.format}`);
+                    return;
+                }
+                this.deps.store.set('autoCopyFormat', settings.format);
+            }
+
+            this.logger.log('Auto-copy settings updated:', settings);
+        } catch (error) {
+            this.logger.error('Error setting auto-copy settings:', {
+                error: (error as Error).message,
+                requestedSettings: settings,
+            });
+        }
+    }
+}
@@ -11,6 +11,8 @@
 import { BrowserWindow, IpcMainEvent, IpcMainInvokeEvent } from 'electron';
 import type { IpcHandlerDependencies } from './types';
 import type { Logger } from '../../types';
+import { publishAppEvent } from '../../utils/events';
+import type { AppEventName, AppEventPayloads } from '../../../shared/types/events';
 
 /**
  * Abstract base class for IPC handlers.
@@ -19,6 +21,7 @@ import type { Logger } from '../../types';
  * - Dependency injection via constructor
  * - `getWindowFromEvent()` helper for safely extracting window from IPC events
  * - `broadcastToAllWindows()` helper for sending messages to all windows
+ * - `publishEvent()` helper for typed app events that late windows can replay
  * - `handleError()` helper for consistent error logging
  */
 export abstract class BaseIpcHandler {
@@ -105,6 +108,20 @@ export abstract class BaseIpcHandler {
         });
     }
 
+    /**
+     * Publish a typed app event and send it to all open windows.
+     *
+     * The event is kept in the app event bus so windows opened later can
+     * catch up on it.
+     *
+     * @param name - Event name, which is also the IPC channel it is sent on
+     * @param payload - Event payload
+     */
+    protected publishEvent<K extends AppEventName>(name: K, payload: AppEventPayloads[K]): void {
+        publishAppEvent(name, payload);
+        this.broadcastToAllWindows(name, payload);
+    }
+
     /**
      * Log an error with consistent formatting and context.
      *
@@ -0,0 +1,91 @@
+/**
+ * Capabilities IPC Handler.
+ *
+ * Handles IPC channels for the capability report:
+ * - capabilities:get-report - Returns which optional features work on this
+ *   platform and build, so the UI can hide or disable unsupported actions
+ *
+ * @module ipc/CapabilitiesIpcHandler
+ */
+
+import { ipcMain, Notification, safeStorage } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../utils/constants';
+import type { CapabilityReport, CapabilityStatus } from '../../../shared/types/capabilities';
+
+/**
+ * Build a capability status.
+ * @param available - Whether the feature works
+ * @param reason - Shown when it does not
+ */
+function status(available: boolean, reason: string): CapabilityStatus {
+    return available ? { available } : { available, reason };
+}
+
+/**
+ * Handler for capability report IPC channels.
+ */
+export class CapabilitiesIpcHandler extends BaseIpcHandler {
+    /**
+     * Register capability IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.CAPABILITIES_GET_REPORT, (): CapabilityReport => this.getReport());
+    }
+
+    /**
+     * Work out which optional features are available. Each probe is isolated
+     * so one failing check only marks that feature unavailable.
+     * @returns The capability report
+     */
+    getReport(): CapabilityReport {
+        const probe = (name: string, check: () => boolean): boolean => {
+            try {
+                return check();
+            } catch (error) {
+                this.handleError(`checking ${name} support`, error);
+                return false;
+            }
+        };
+
+        return {
+            platform: process.platform,
+            versions: {
+                electron: process.versions.electron ?? '',
+                chrome: process.versions.chrome ?? '',
+            },
+            capabilities: {
+                globalHotkeys: status(
+                    probe('global hotkey', () => this.deps.hotkeyManager?.areGlobalHotkeysSupported() ?? false),
+                    'Global hotkeys are not available on Linux (Wayland limitations)'
+                ),
+                notifications: status(
+                    probe('notification', () => Notification.isSupported()),
+                    'System notifications are not supported on this system'
+                ),
+                keychain: status(
+                    probe('keychain', () => safeStorage.isEncryptionAvailable()),
+                    'No OS keychain is available; saved tokens are stored unencrypted'
+                ),
+                // Electron has a tray icon on every platform the app ships for
+                tray: { available: true },
+                autoUpdate: status(
+                    probe('auto-update', () => this.deps.updateManager?.isSupported() ?? false),
+                    'Automatic updates are not available for this install type'
+                ),
+                // Overlay mode needs a translucent window too
+                windowOpacity: status(
+                    probe('window opacity', () => this.deps.windowManager.isWindowOpacitySupported()),
+                    'Window opacity and overlay mode are not supported on Linux'
+                ),
+            },
+        };
+    }
+
+    /**
+     * Unregister capability IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.CAPABILITIES_GET_REPORT);
+    }
+}
@@ -0,0 +1,97 @@
+/**
+ * Card IPC Handler.
+ *
+ * Handles IPC channels for pinned answer cards:
+ * - cards:pin - Pin the latest Gemini answer into a floating card
+ * - cards:copy - Copy a card's answer (sent from the card window)
+ * - cards:close - Close a card (sent from the card window)
+ *
+ * @module ipc/CardIpcHandler
+ */
+
+import { ipcMain, type IpcMainEvent } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import type { IpcHandlerDependencies } from './types';
+import { IPC_CHANNELS } from '../../../shared/constants/ipc-channels';
+import { showToast } from '../../utils/toast';
+import CardManager from '../cardManager';
+
+/**
+ * Handler for pinned answer card IPC channels.
+ */
+export class CardIpcHandler extends BaseIpcHandler {
+    private readonly cardManager: CardManager;
+
+    /** Bound listener for pin-answer-triggered events (for removal) */
+    private readonly onPinAnswerTriggered = (): void => {
+        this.pinLastAnswer().catch((error) => this.handleError('pinning answer (menu)', error));
+    };
+
+    /**
+     * @param deps - Handler dependencies
+     * @param cardManager - Optional card manager (for testing)
+     */
+    constructor(deps: IpcHandlerDependencies, cardManager?: CardManager) {
+        super(deps);
+        this.cardManager = cardManager ?? new CardManager();
+    }
+
+    /**
+     * Register card IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.on(IPC_CHANNELS.CARDS_PIN, () => {
+            this.pinLastAnswer().catch((error) => this.handleError('pinning answer', error));
+        });
+
+        ipcMain.on(IPC_CHANNELS.CARDS_COPY, (event: IpcMainEvent) => {
+            if (!this.cardManager.copyCard(event.sender)) {
+                this.logger.warn('Card copy requested from unknown sender');
+            }
+        });
+
+        ipcMain.on(IPC_CHANNELS.CARDS_CLOSE, (event: IpcMainEvent) => {
+            if (!this.cardManager.closeCard(event.sender)) {
+                this.logger.warn('Card close requested from unknown sender');
+            }
+        });
+
+        // Native application menu
+        this.deps.windowManager.on('pin-answer-triggered', this.onPinAnswerTriggered);
+    }
+
+    /**
+     * Unregister card IPC handlers and close any open cards.
+     */
+    unregister(): void {
+        ipcMain.removeAllListeners(IPC_CHANNELS.CARDS_PIN);
+        ipcMain.removeAllListeners(IPC_CHANNELS.CARDS_COPY);
+        ipcMain.removeAllListeners(IPC_CHANNELS.CARDS_CLOSE);
+        this.deps.windowManager.removeListener('pin-answer-triggered', this.onPinAnswerTriggered);
+        this.cardManager.closeAll();
+    }
+
+    /**
+     * Pin the latest answer in the main window into a new card.
+     */
+    async pinLastAnswer(): Promise<void> {
+        if (!this.deps.exportManager) {
+            this.logger.error('ExportManager not initialized');
+            return;
+        }
+
+        const mainWindow = this.deps.windowManager.getMainWindow();
+        if (!mainWindow || mainWindow.isDestroyed()) {
+            this.logger.warn('Cannot pin answer: main window not found or destroyed');
+            return;
+        }
+
+        const response = await this.deps.exportManager.getLastResponse(mainWindow.webContents);
+        if (!response) {
+            showToast(mainWindow, { type: 'info', message: 'No answer to pin' });
+            return;
+        }
+
+        this.cardManager.createCard({ html: response.html, markdown: response.markdown });
+    }
+}
@@ -0,0 +1,66 @@
+/**
+ * Close To Tray IPC Handler.
+ *
+ * Handles IPC channels for the close-to-tray setting:
+ * - close-to-tray:get - Returns whether closing the main window hides it to the tray
+ * - close-to-tray:set - Sets and persists the close-to-tray setting
+ *
+ * @module ipc/CloseToTrayIpcHandler
+ */
+
+import { ipcMain } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../utils/constants';
+
+/**
+ * Handler for close-to-tray IPC channels.
+ */
+export class CloseToTrayIpcHandler extends BaseIpcHandler {
+    /**
+     * Register close-to-tray IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.CLOSE_TO_TRAY_GET, (): boolean => {
+            try {
+                return this.deps.store.get('closeToTray') ?? true;
+            } catch (error) {
+                this.handleError('getting close to tray', error);
+                return true;
+            }
+        });
+
+        ipcMain.on(IPC_CHANNELS.CLOSE_TO_TRAY_SET, (_event, enabled: boolean) => {
+            try {
+                if (typeof enabled !== 'boolean') {
+                    this.logger.warn(`Invalid closeToTray value: ${enabled}`);
+                    return;
+                }
+
+                this.deps.store.set('closeToTray', enabled);
+                this.deps.windowManager.setCloseToTray(enabled);
+                this.logger.log(`Close to tray ${enabled ? 'enabled' : 'disabled'}`);
+            } catch (error) {
+                this.handleError('setting close to tray', error);
+            }
+        });
+    }
+
+    /**
+     * Apply the saved close-to-tray setting to the main window.
+     */
+    initialize(): void {
+        try {
+            this.deps.windowManager.setCloseToTray(this.deps.store.get('closeToTray') ?? true);
+        } catch (error) {
+            this.handleError('initializing close to tray', error);
+        }
+    }
+
+    /**
+     * Unregister close-to-tray IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.CLOSE_TO_TRAY_GET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.CLOSE_TO_TRAY_SET);
+    }
+}
@@ -0,0 +1,76 @@
+/**
+ * Compact Mode IPC Handler.
+ *
+ * Handles IPC channels for compact mode, which docks a main window as a
+ * narrow chat strip at the edge of its monitor:
+ * - compact-mode:get - Returns whether the calling window is in compact mode
+ * - compact-mode:toggle - Enters or leaves compact mode for the calling window
+ *
+ * Also sends windowManager compact-mode-changed events to the affected window
+ * so its titlebar can slim down. Compact mode is per window, so the event is
+ * not broadcast.
+ *
+ * @module ipc/CompactModeIpcHandler
+ */
+
+import { ipcMain, type BrowserWindow } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../utils/constants';
+
+/**
+ * Handler for compact mode IPC channels.
+ */
+export class CompactModeIpcHandler extends BaseIpcHandler {
+    private readonly onCompactModeChanged = (enabled: boolean, win: BrowserWindow) => {
+        if (!win.isDestroyed()) {
+            win.webContents.send(IPC_CHANNELS.COMPACT_MODE_CHANGED, { enabled });
+        }
+    };
+
+    /**
+     * Register compact mode IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.COMPACT_MODE_GET, (event): { enabled: boolean } => {
+            try {
+                const win = this.getWindowFromEvent(event);
+                return { enabled: win ? this.deps.windowManager.isCompactMode(win) : false };
+            } catch (error) {
+                this.handleError('getting compact mode', error);
+                return { enabled: false };
+            }
+        });
+
+        ipcMain.on(IPC_CHANNELS.COMPACT_MODE_TOGGLE, (event) => {
+            try {
+                const win = this.getWindowFromEvent(event);
+                if (win) this.deps.windowManager.toggleCompactMode(win);
+            } catch (error) {
+                this.handleError('toggling compact mode', error);
+            }
+        });
+
+        this.deps.windowManager.on('compact-mode-changed', this.onCompactModeChanged);
+    }
+
+    /**
+     * Apply the saved sidebar preference.
+     */
+    initialize(): void {
+        try {
+            const hideSidebar = this.deps.store.get('compactModeHideSidebar') ?? true;
+            this.deps.windowManager.setCompactModeHidesSidebar(hideSidebar);
+        } catch (error) {
+            this.handleError('initializing compact mode', error);
+        }
+    }
+
+    /**
+     * Unregister compact mode IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.COMPACT_MODE_GET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.COMPACT_MODE_TOGGLE);
+        this.deps.windowManager.removeListener('compact-mode-changed', this.onCompactModeChanged);
+    }
+}
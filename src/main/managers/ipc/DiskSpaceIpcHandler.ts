@@ -0,0 +1,111 @@
+/**
+ * Disk Space IPC Handler.
+ *
+ * Handles IPC channels for the low disk space guard:
+ * - disk-space:get-status - Returns free space on the app data volume and
+ *   whether it is below the threshold
+ *
+ * Also polls the app data volume, and when free space crosses the
+ * lowDiskSpaceThresholdMb threshold broadcasts disk-space:changed, shows a
+ * titlebar warning and a toast. Exports, screenshots and model downloads
+ * refuse to write below the threshold, and background update checks pause.
+ *
+ * @module ipc/DiskSpaceIpcHandler
+ */
+
+import { app, ipcMain } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../utils/constants';
+import {
+    checkDiskSpace,
+    getDiskSpaceStatus,
+    onDiskSpaceChanged,
+    setLowDiskSpaceThreshold,
+} from '../../utils/diskSpace';
+import { removeTitlebarWidget, setTitlebarWidget, TITLEBAR_WIDGET_ORDER } from '../../utils/titlebarWidgets';
+import { showToast } from '../../utils/toast';
+import { DEFAULT_LOW_DISK_SPACE_MB, type DiskSpaceStatus } from '../../../shared/types/disk-space';
+
+/** How often free space is checked */
+export const DISK_SPACE_CHECK_INTERVAL_MS = 60_000;
+
+/**
+ * Handler for disk space IPC channels.
+ */
+export class DiskSpaceIpcHandler extends BaseIpcHandler {
+    private unsubscribe: (() => void) | null = null;
+    private checkTimer: ReturnType<typeof setInterval> | null = null;
+
+    /**
+     * Register disk space IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.DISK_SPACE_GET_STATUS, (): DiskSpaceStatus => getDiskSpaceStatus());
+
+        this.unsubscribe = onDiskSpaceChanged((status) => this.onDiskSpaceChanged(status));
+    }
+
+    /**
+     * Apply the saved threshold and start checking free space.
+     */
+    initialize(): void {
+        try {
+            setLowDiskSpaceThreshold(this.deps.store.get('lowDiskSpaceThresholdMb') ?? DEFAULT_LOW_DISK_SPACE_MB);
+        } catch (error) {
+            this.handleError('initializing disk space threshold', error);
+        }
+
+        const check = async () => {
+            try {
+                await checkDiskSpace(app.getPath('userData'));
+            } catch (error) {
+                this.handleError('checking disk space', error);
+            }
+        };
+        void check();
+        this.checkTimer = setInterval(check, DISK_SPACE_CHECK_INTERVAL_MS);
+    }
+
+    /**
+     * Unregister disk space IPC handlers and stop checking.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.DISK_SPACE_GET_STATUS);
+        this.unsubscribe?.();
+        this.unsubscribe = null;
+        if (this.checkTimer) {
+            clearInterval(this.checkTimer);
+            this.checkTimer = null;
+        }
+    }
+
+    /**
+     * Tell the windows that free space crossed the threshold.
+     */
+    private onDiskSpaceChanged(status: DiskSpaceStatus): void {
+        this.publishEvent(IPC_CHANNELS.DISK_SPACE_CHANGED, status);
+
+        if (!status.low) {
+            removeTitlebarWidget('low-disk-space');
+            return;
+        }
+
+        const freeMb = Math.floor((status.freeBytes ?? 0) / (1024 * 1024));
+        setTitlebarWidget({
+            id: 'low-disk-space',
+            label: 'Low disk space',
+            tooltip: `${freeMb} MB free; exports and downloads are paused until space is freed`,
+            tone: 'warning',
+            order: TITLEBAR_WIDGET_ORDER.lowDiskSpace,
+        });
+
+        const mainWindow = this.deps.windowManager.getMainWindow();
+        if (mainWindow) {
+            showToast(mainWindow, {
+                type: 'warning',
+                title: 'Low disk space',
+                message: `Only ${freeMb} MB free. Exports and downloads are paused until you free up space.`,
+            });
+        }
+    }
+}
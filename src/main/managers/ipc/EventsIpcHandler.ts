@@ -0,0 +1,43 @@
+/**
+ * Events IPC Handler.
+ *
+ * Handles IPC channels for the app event bus:
+ * - events:get-recent - Returns the kept events so a window that opened
+ *   late can catch up
+ *
+ * The events themselves are sent by the handlers that publish them.
+ *
+ * @module ipc/EventsIpcHandler
+ */
+
+import { ipcMain } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../utils/constants';
+import { getRecentAppEvents } from '../../utils/events';
+import type { AppEvent } from '../../../shared/types/events';
+
+/**
+ * Handler for app event bus IPC channels.
+ */
+export class EventsIpcHandler extends BaseIpcHandler {
+    /**
+     * Register event bus IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.EVENTS_GET_RECENT, (_event, since: unknown): AppEvent[] => {
+            try {
+                return getRecentAppEvents(typeof since === 'number' && Number.isFinite(since) ? since : 0);
+            } catch (error) {
+                this.handleError('getting recent events', error);
+                return [];
+            }
+        });
+    }
+
+    /**
+     * Unregister event bus IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.EVENTS_GET_RECENT);
+    }
+}
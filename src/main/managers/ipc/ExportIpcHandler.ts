@@ -1,6 +1,31 @@
+/**
+ * Export IPC Handler.
+ *
+ * Handles IPC channels for structured chat export:
+ * - export-chat:pdf / export-chat:markdown - Export the current chat
+ * - export-chat:confluence - Publish the current chat as a Confluence page
+ * - export-chat:canvas-markdown - Export the open Canvas document to Markdown
+ * - export-chat:research-report - Export the open Deep Research report to PDF or Markdown
+ * - export-chat:filename-template:get/set - Export filename template setting
+ * - export-chat:confluence-settings:get/set - Confluence connection and target settings
+ * - export-chat:cancel - Cancel a running export job (progress is sent on export-chat:progress)
+ *
+ * @module ipc/ExportIpcHandler
+ */
+
 import { ipcMain, type IpcMainEvent } from 'electron';
 import { BaseIpcHandler } from './BaseIpcHandler';
+import type { UserPreferences } from './types';
 import { IPC_CHANNELS } from '../../../shared/constants/ipc-channels';
+import { DEFAULT_EXPORT_FILENAME_TEMPLATE, isValidExportFilenameTemplate } from '../../utils/exportFilename';
+import { validateConfluenceSettings } from '../../utils/confluence';
+import { decryptSecret, encryptSecret } from '../../utils/secrets';
+import { RESEARCH_REPORT_FORMATS, type ResearchReportFormat } from '../../../shared/types/export';
+import {
+    DEFAULT_CONFLUENCE_SETTINGS,
+    type ConfluenceSettings,
+    type ConfluenceTarget,
+} from '../../../shared/types/confluence';
 
 export class ExportIpcHandler extends BaseIpcHandler {
     register(): void {
@@ -13,6 +38,38 @@ export class ExportIpcHandler extends BaseIpcHandler {
             this._handleExportMarkdown(event);
         });
 
+        ipcMain.on(IPC_CHANNELS.EXPORT_CHAT_CONFLUENCE, (event: IpcMainEvent, target?: ConfluenceTarget) => {
+            this._handleExportConfluence(event, target);
+        });
+
+        ipcMain.on(IPC_CHANNELS.EXPORT_CANVAS_MARKDOWN, (event: IpcMainEvent) => {
+            this._handleExportCanvasMarkdown(event);
+        });
+
+        ipcMain.on(IPC_CHANNELS.EXPORT_RESEARCH_REPORT, (event: IpcMainEvent, format: ResearchReportFormat) => {
+            this._handleExportResearchReport(event, format);
+        });
+
+        ipcMain.handle(IPC_CHANNELS.EXPORT_CONFLUENCE_SETTINGS_GET, (): ConfluenceSettings => {
+            return this._getConfluenceSettings();
+        });
+
+        ipcMain.on(IPC_CHANNELS.EXPORT_CONFLUENCE_SETTINGS_SET, (_event, settings: ConfluenceSettings) => {
+            this._handleSetConfluenceSettings(settings);
+        });
+
+        ipcMain.handle(IPC_CHANNELS.EXPORT_FILENAME_TEMPLATE_GET, (): string => {
+            return this._getFilenameTemplate();
+        });
+
+        ipcMain.on(IPC_CHANNELS.EXPORT_FILENAME_TEMPLATE_SET, (_event, template: string) => {
+            this._handleSetFilenameTemplate(template);
+        });
+
+        ipcMain.on(IPC_CHANNELS.EXPORT_CANCEL, (_event, jobId: string) => {
+            this._handleCancel(jobId);
+        });
+
         // Window Event Listeners
         this.deps.windowManager.on('print-to-pdf-triggered', () => {
             this.logger.log('Export to PDF triggered via WindowManager event');
@@ -22,7 +79,7 @@ export class ExportIpcHandler extends BaseIpcHandler {
             }
             const win = this.deps.windowManager.getMainWindow();
             if (win && !win.isDestroyed()) {
-                this.deps.exportManager.exportToPdf(win.webContents).catch((err) => {
+                this.deps.exportManager.exportToPdf(win.webContents, this._getFilenameTemplate()).catch((err) => {
                     this.handleError('exportToPdf (local)', err);
                 });
             } else {
@@ -38,20 +95,202 @@ export class ExportIpcHandler extends BaseIpcHandler {
             }
             const win = this.deps.windowManager.getMainWindow();
             if (win && !win.isDestroyed()) {
-                this.deps.exportManager.exportToMarkdown(win.webContents).catch((err) => {
+                this.deps.exportManager.exportToMarkdown(win.webContents, this._getFilenameTemplate()).catch((err) => {
                     this.handleError('exportToMarkdown (local)', err);
                 });
             } else {
                 this.logger.warn('Cannot export to Markdown: Main window not found or destroyed');
             }
         });
+
+        this.deps.windowManager.on('export-confluence-triggered', () => {
+            this.logger.log('Export to Confluence triggered via WindowManager event');
+            if (!this.deps.exportManager) {
+                this.logger.error('ExportManager not initialized');
+                return;
+            }
+            const win = this.deps.windowManager.getMainWindow();
+            if (win && !win.isDestroyed()) {
+                this.deps.exportManager
+                    .exportToConfluence(win.webContents, this._getConfluenceSettings())
+                    .catch((err) => {
+                        this.handleError('exportToConfluence (local)', err);
+                    });
+            } else {
+                this.logger.warn('Cannot export to Confluence: Main window not found or destroyed');
+            }
+        });
+
+        this.deps.windowManager.on('export-canvas-triggered', () => {
+            this.logger.log('Export Canvas to Markdown triggered via WindowManager event');
+            if (!this.deps.exportManager) {
+                this.logger.error('ExportManager not initialized');
+                return;
+            }
+            const win = this.deps.windowManager.getMainWindow();
+            if (win && !win.isDestroyed()) {
+                this.deps.exportManager
+                    .exportCanvasToMarkdown(win.webContents, this._getFilenameTemplate())
+                    .catch((err) => {
+                        this.handleError('exportCanvasToMarkdown (local)', err);
+                    });
+            } else {
+                this.logger.warn('Cannot export Canvas: Main window not found or destroyed');
+            }
+        });
+
+        this.deps.windowManager.on('export-research-report-triggered', (format: ResearchReportFormat) => {
+            this.logger.log(`Export research report to ${format} triggered via WindowManager event`);
+            if (!this.deps.exportManager) {
+                this.logger.error('ExportManager not initialized');
+                return;
+            }
+            const win = this.deps.windowManager.getMainWindow();
+            if (win && !win.isDestroyed()) {
+                this.deps.exportManager
+                    .exportResearchReport(win.webContents, format, this._getFilenameTemplate())
+                    .catch((err) => {
+                        this.handleError('exportResearchReport (local)', err);
+                    });
+            } else {
+                this.logger.warn('Cannot export research report: Main window not found or destroyed');
+            }
+        });
     }
 
     unregister(): void {
         ipcMain.removeAllListeners(IPC_CHANNELS.EXPORT_CHAT_PDF);
         ipcMain.removeAllListeners(IPC_CHANNELS.EXPORT_CHAT_MARKDOWN);
+        ipcMain.removeAllListeners(IPC_CHANNELS.EXPORT_CHAT_CONFLUENCE);
+        ipcMain.removeAllListeners(IPC_CHANNELS.EXPORT_CANVAS_MARKDOWN);
+        ipcMain.removeAllListeners(IPC_CHANNELS.EXPORT_RESEARCH_REPORT);
+        ipcMain.removeHandler(IPC_CHANNELS.EXPORT_CONFLUENCE_SETTINGS_GET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.EXPORT_CONFLUENCE_SETTINGS_SET);
+        ipcMain.removeHandler(IPC_CHANNELS.EXPORT_FILENAME_TEMPLATE_GET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.EXPORT_FILENAME_TEMPLATE_SET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.EXPORT_CANCEL);
         this.deps.windowManager.removeAllListeners('print-to-pdf-triggered');
         this.deps.windowManager.removeAllListeners('export-markdown-triggered');
+        this.deps.windowManager.removeAllListeners('export-confluence-triggered');
+        this.deps.windowManager.removeAllListeners('export-canvas-triggered');
+        this.deps.windowManager.removeAllListeners('export-research-report-triggered');
+    }
+
+    /**
+     * Get the persisted export filename template, falling back to the default.
+     * @returns The filename template
+     */
+    private _getFilenameTemplate(): string {
+        try {
+            const template = this.deps.store.get('exportFilenameTemplate');
+            return isValidExportFilenameTemplate(template) ? template : DEFAULT_EXPORT_FILENAME_TEMPLATE;
+        } catch (error) {
+            this.logger.error('Error getting export filename template:', error);
+            return DEFAULT_EXPORT_FILENAME_TEMPLATE;
+        }
+    }
+
+    /**
+     * Handle export-chat:filename-template:set request.
+     * @param template - The new filename template
+     */
+    private _handleSetFilenameTemplate(template: string): void {
+        try {
+            if (!isValidExportFilenameTemplate(template)) {
+                this.logger.warn(`Invalid export filename template: ${template}`);
+                return;
+            }
+
+            this.deps.store.set('exportFilenameTemplate', template);
+            this.logger.log(`Export filename template set to: ${template}`);
+        } catch (error) {
+            this.logger.error('Error setting export filename template:', {
+                error: (error as Error).message,
+                requestedTemplate: template,
+            });
+        }
+    }
+
+    /**
+     * Get the saved Confluence settings.
+     * @returns Confluence settings with the API token decrypted
+     */
+    private _getConfluenceSettings(): ConfluenceSettings {
+        try {
+            const store = this.deps.store;
+            return {
+                baseUrl: store.get('confluenceBaseUrl') ?? DEFAULT_CONFLUENCE_SETTINGS.baseUrl,
+                email: store.get('confluenceEmail') ?? DEFAULT_CONFLUENCE_SETTINGS.email,
+                apiToken: decryptSecret(store.get('confluenceApiToken') ?? ''),
+                spaceKey: store.get('confluenceSpaceKey') ?? DEFAULT_CONFLUENCE_SETTINGS.spaceKey,
+                parentId: store.get('confluenceParentId') ?? DEFAULT_CONFLUENCE_SETTINGS.parentId,
+            };
+        } catch (error) {
+            this.logger.error('Error getting Confluence settings:', error);
+            return { ...DEFAULT_CONFLUENCE_SETTINGS };
+        }
+    }
+
+    /**
+     * Handle export-chat:confluence-settings:set request.
+     * @param settings - New Confluence settings
+     */
+    private _handleSetConfluenceSettings(settings: ConfluenceSettings): void {
+        try {
+            if (!settings || typeof settings !== 'object') {
+                this.logger.warn(`Invalid Confluence settings: ${settings}`);
+                return;
+            }
+
+            const next: ConfluenceSettings = {
+                baseUrl: String(settings.baseUrl ?? '').trim(),
+                email: String(settings.email ?? '').trim(),
+                apiToken: String(settings.apiToken ?? ''),
+                spaceKey: String(settings.spaceKey ?? '').trim(),
+                parentId: String(settings.parentId ?? '').trim(),
+            };
+            const problem = validateConfluenceSettings(next);
+            if (problem) {
+                this.logger.warn(problem);
+                return;
+            }
+
+            const store = this.deps.store;
+            const values: Partial<UserPreferences> = {
+                confluenceBaseUrl: next.baseUrl,
+                confluenceEmail: next.email,
+                confluenceSpaceKey: next.spaceKey,
+                confluenceParentId: next.parentId,
+            };
+            // Encrypting can give new ciphertext for the same token; only save it if it changed
+            if (decryptSecret(store.get('confluenceApiToken') ?? '') !== next.apiToken) {
+                values.confluenceApiToken = encryptSecret(next.apiToken);
+            }
+            store.setMany(values);
+
+            // Never log credentials
+            this.logger.log(`Confluence settings updated: ${next.baseUrl} space ${next.spaceKey}`);
+        } catch (error) {
+            this.logger.error('Error setting Confluence settings:', { error: (error as Error).message });
+        }
+    }
+
+    /**
+     * Handle export-chat:cancel request.
+     * @param jobId - ID of the export job to cancel
+     */
+    private _handleCancel(jobId: string): void {
+        if (typeof jobId !== 'string') {
+            this.logger.warn(`Invalid export job ID: ${jobId}`);
+            return;
+        }
+        if (!this.deps.exportManager) {
+            this.logger.error('ExportManager not initialized');
+            return;
+        }
+        if (!this.deps.exportManager.cancelExport(jobId)) {
+            this.logger.warn(`Export job not found or already cancelled: ${jobId}`);
+        }
     }
 
     private _handleExportPdf(event: IpcMainEvent): void {
@@ -60,19 +299,73 @@ export class ExportIpcHandler extends BaseIpcHandler {
             this.logger.error('ExportManager not initialized');
             return;
         }
-        this.deps.exportManager.exportToPdf(event.sender).catch((err) => {
+        this.deps.exportManager.exportToPdf(event.sender, this._getFilenameTemplate()).catch((err) => {
             this.handleError('exportToPdf', err);
         });
     }
 
+    /**
+     * Handle export-chat:confluence request.
+     * @param target - Optional space key and parent page ID overriding the saved settings
+     */
+    private _handleExportConfluence(event: IpcMainEvent, target?: ConfluenceTarget): void {
+        this.logger.log('Export to Confluence triggered via IPC');
+        if (!this.deps.exportManager) {
+            this.logger.error('ExportManager not initialized');
+            return;
+        }
+
+        const settings = this._getConfluenceSettings();
+        if (target && typeof target === 'object') {
+            if (typeof target.spaceKey === 'string' && target.spaceKey) settings.spaceKey = target.spaceKey;
+            if (typeof target.parentId === 'string') settings.parentId = target.parentId;
+        }
+
+        this.deps.exportManager.exportToConfluence(event.sender, settings).catch((err) => {
+            this.handleError('exportToConfluence', err);
+        });
+    }
+
     private _handleExportMarkdown(event: IpcMainEvent): void {
         this.logger.log('Export to Markdown triggered via IPC');
         if (!this.deps.exportManager) {
             this.logger.error('ExportManager not initialized');
             return;
         }
-        this.deps.exportManager.exportToMarkdown(event.sender).catch((err) => {
+        this.deps.exportManager.exportToMarkdown(event.sender, this._getFilenameTemplate()).catch((err) => {
             this.handleError('exportToMarkdown', err);
         });
     }
+
+    private _handleExportCanvasMarkdown(event: IpcMainEvent): void {
+        this.logger.log('Export Canvas to Markdown triggered via IPC');
+        if (!this.deps.exportManager) {
+            this.logger.error('ExportManager not initialized');
+            return;
+        }
+        this.deps.exportManager.exportCanvasToMarkdown(event.sender, this._getFilenameTemplate()).catch((err) => {
+            this.handleError('exportCanvasToMarkdown', err);
+        });
+    }
+
+    /**
+     * Handle export-chat:research-report request.
+     * @param format - 'pdf' or 'markdown'
+     */
+    private _handleExportResearchReport(event: IpcMainEvent, format: ResearchReportFormat): void {
+        if (!RESEARCH_REPORT_FORMATS.includes(format)) {
+            this.logger.warn(`Invalid research report format: ${format}`);
+            return;
+        }
+        this.logger.log(`Export research report to ${format} triggered via IPC`);
+        if (!this.deps.exportManager) {
+            this.logger.error('ExportManager not initialized');
+            return;
+        }
+        this.deps.exportManager
+            .exportResearchReport(event.sender, format, this._getFilenameTemplate())
+            .catch((err) => {
+                this.handleError('exportResearchReport', err);
+            });
+    }
 }
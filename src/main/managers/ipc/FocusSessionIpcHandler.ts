@@ -0,0 +1,238 @@
+/**
+ * Focus Session IPC Handler.
+ *
+ * Handles IPC channels for focus sessions:
+ * - focus-session:get-status - Returns whether a session is running and when it ends
+ * - focus-session:toggle - Starts a session of focusSessionMinutes, or ends the running one
+ *
+ * Also starts and ends sessions from View › Focus Session and the
+ * `focus-session` instance command, broadcasts focus-session:changed, shows
+ * the time left in the titlebar and logs each answer. When a session ends,
+ * the user is offered to save the log as Markdown.
+ *
+ * @module ipc/FocusSessionIpcHandler
+ */
+
+import { app, dialog, ipcMain } from 'electron';
+import * as fs from 'fs/promises';
+import * as path from 'path';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../utils/constants';
+import { ensureDiskSpace, isDiskFullError } from '../../utils/diskSpace';
+import { buildExportFilename } from '../../utils/exportFilename';
+import {
+    buildFocusSessionMarkdown,
+    endFocusSession,
+    FOCUS_SESSION_LOG_FILENAME_TEMPLATE,
+    getFocusSessionStatus,
+    isFocusSessionActive,
+    onFocusSessionChanged,
+    recordFocusSessionEntry,
+    startFocusSession,
+    type FocusSession,
+} from '../../utils/focusSession';
+import { removeTitlebarWidget, setTitlebarWidget, TITLEBAR_WIDGET_ORDER } from '../../utils/titlebarWidgets';
+import { showToast } from '../../utils/toast';
+import { DEFAULT_FOCUS_SESSION_MINUTES, type FocusSessionStatus } from '../../../shared/types/focus-session';
+
+/** How often the time left in the titlebar is updated */
+export const FOCUS_SESSION_TICK_MS = 60_000;
+
+/**
+ * Handler for focus session IPC channels.
+ */
+export class FocusSessionIpcHandler extends BaseIpcHandler {
+    private unsubscribe: (() => void) | null = null;
+    private endTimer: ReturnType<typeof setTimeout> | null = null;
+    private tickTimer: ReturnType<typeof setInterval> | null = null;
+
+    private readonly onToggleTriggered = () => this.toggle();
+    private readonly onStartTriggered = (minutes?: number) => this.start(minutes);
+
+    /**
+     * Register focus session IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.FOCUS_SESSION_GET_STATUS, (): FocusSessionStatus => getFocusSessionStatus());
+        ipcMain.on(IPC_CHANNELS.FOCUS_SESSION_TOGGLE, () => this.toggle());
+
+        this.deps.windowManager.on('focus-session-toggle-triggered', this.onToggleTriggered);
+        this.deps.windowManager.on('focus-session-start-triggered', this.onStartTriggered);
+        this.unsubscribe = onFocusSessionChanged((status) => this.onFocusSessionChanged(status));
+    }
+
+    /**
+     * Unregister focus session IPC handlers and stop the timers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.FOCUS_SESSION_GET_STATUS);
+        ipcMain.removeAllListeners(IPC_CHANNELS.FOCUS_SESSION_TOGGLE);
+        this.deps.windowManager.removeListener('focus-session-toggle-triggered', this.onToggleTriggered);
+        this.deps.windowManager.removeListener('focus-session-start-triggered', this.onStartTriggered);
+        this.unsubscribe?.();
+        this.unsubscribe = null;
+        this.clearTimers();
+    }
+
+    /**
+     * Start a focus session, or end the running one.
+     */
+    toggle(): void {
+        if (isFocusSessionActive()) {
+            void this.end();
+        } else {
+            this.start();
+        }
+    }
+
+    /**
+     * Start a focus session, replacing any running one.
+     * @param minutes - Session length; defaults to the focusSessionMinutes setting
+     */
+    start(minutes?: number): void {
+        const length = minutes ?? this.deps.store.get('focusSessionMinutes') ?? DEFAULT_FOCUS_SESSION_MINUTES;
+        this.clearTimers();
+        const status = startFocusSession(length);
+
+        this.endTimer = setTimeout(() => void this.end(), status.endsAt! - status.startedAt!);
+        this.tickTimer = setInterval(() => this.updateWidget(), FOCUS_SESSION_TICK_MS);
+
+        const mainWindow = this.deps.windowManager.getMainWindow();
+        if (mainWindow) {
+            showToast(mainWindow, {
+                type: 'info',
+                title: 'Focus session started',
+                message: `Notifications are paused until ${new Date(status.endsAt!).toLocaleTimeString()}.`,
+            });
+        }
+    }
+
+    /**
+     * End the running focus session and offer to save its log.
+     */
+    async end(): Promise<void> {
+        this.clearTimers();
+        const session = endFocusSession();
+        if (!session) return;
+
+        try {
+            await this.offerSessionLog(session, Date.now());
+        } catch (error) {
+            this.handleError('saving focus session log', error);
+        }
+    }
+
+    /**
+     * Log the latest answer in the running focus session.
+     */
+    async onResponseComplete(): Promise<void> {
+        if (!isFocusSessionActive() || !this.deps.exportManager) return;
+
+        const mainWindow = this.deps.windowManager.getMainWindow();
+        if (!mainWindow || mainWindow.isDestroyed()) return;
+
+        try {
+            const response = await this.deps.exportManager.getLastResponse(mainWindow.webContents);
+            if (!response) return;
+
+            recordFocusSessionEntry({
+                time: Date.now(),
+                prompt: response.prompt ?? '',
+                answer: response.markdown,
+                modelName: response.modelName,
+            });
+            this.updateWidget();
+        } catch (error) {
+            this.handleError('logging focus session answer', error);
+        }
+    }
+
+    /**
+     * Tell the windows that a session started or ended.
+     */
+    private onFocusSessionChanged(status: FocusSessionStatus): void {
+        this.publishEvent(IPC_CHANNELS.FOCUS_SESSION_CHANGED, status);
+        this.updateWidget();
+    }
+
+    /**
+     * Show the time left in the titlebar while a session runs.
+     */
+    private updateWidget(): void {
+        const status = getFocusSessionStatus();
+        if (!status.active) {
+            removeTitlebarWidget('focus-session');
+            return;
+        }
+
+        const minutesLeft = Math.max(1, Math.ceil((status.endsAt! - Date.now()) / 60_000));
+        const answers = `${status.entryCount} ${status.entryCount === 1 ? 'answer' : 'answers'} logged`;
+        setTitlebarWidget({
+            id: 'focus-session',
+            label: `Focus · ${minutesLeft} min`,
+            tooltip: `Focus session until ${new Date(status.endsAt!).toLocaleTimeString()}; ${answers}`,
+            tone: 'info',
+            order: TITLEBAR_WIDGET_ORDER.focusSession,
+        });
+    }
+
+    /**
+     * Ask whether to save the session log, then save it where the user chooses.
+     */
+    private async offerSessionLog(session: FocusSession, endedAt: number): Promise<void> {
+        const mainWindow = this.deps.windowManager.getMainWindow();
+        if (!mainWindow) return;
+
+        if (session.entries.length === 0) {
+            showToast(mainWindow, { type: 'info', message: 'Focus session ended' });
+            return;
+        }
+
+        const count = session.entries.length;
+        const { response } = await dialog.showMessageBox(mainWindow, {
+            type: 'info',
+            buttons: ['Save Log…', 'Close'],
+            defaultId: 0,
+            cancelId: 1,
+            message: 'Focus session ended',
+            detail: `${count} ${count === 1 ? 'answer was' : 'answers were'} logged. Save the prompts and answers?`,
+        });
+        if (response !== 0) return;
+
+        const { filePath, canceled } = await dialog.showSaveDialog(mainWindow, {
+            title: 'Save Focus Session Log',
+            defaultPath: path.join(
+                app.getPath('downloads'),
+                buildExportFilename(FOCUS_SESSION_LOG_FILENAME_TEMPLATE, {
+                    title: '',
+                    timestamp: new Date(session.startedAt),
+                    format: 'md',
+                })
+            ),
+            filters: [{ name: 'Markdown Files', extensions: ['md'] }],
+        });
+        if (canceled || !filePath) return;
+
+        const markdown = buildFocusSessionMarkdown(session, endedAt);
+        try {
+            await ensureDiskSpace(path.dirname(filePath), Buffer.byteLength(markdown));
+            await fs.writeFile(filePath, markdown);
+        } catch (error) {
+            if (!isDiskFullError(error)) throw error;
+            showToast(mainWindow, { type: 'error', message: 'Not enough disk space to save the session log' });
+            return;
+        }
+        showToast(mainWindow, { type: 'success', message: 'Focus session log saved' });
+        this.logger.log(`Focus session log saved to ${filePath}`);
+    }
+
+    /**
+     * Stop the session end and titlebar update timers.
+     */
+    private clearTimers(): void {
+        if (this.endTimer) clearTimeout(this.endTimer);
+        if (this.tickTimer) clearInterval(this.tickTimer);
+        this.endTimer = null;
+        this.tickTimer = null;
+    }
+}
@@ -0,0 +1,168 @@
+/**
+ * Gemini View IPC Handler.
+ *
+ * Handles IPC channels that act on the embedded Gemini view:
+ * - gemini:reload - Reloads Gemini, optionally bypassing the HTTP cache
+ * - gemini:go-back / gemini:go-forward - Navigates Gemini's history
+ * - gemini:go-home - Navigates Gemini to its home page
+ * - gemini:print - Opens the print dialog for the Gemini conversation
+ * - gemini:reload-user-styles - Re-applies user.css to Gemini
+ * - gemini:get-navigation-state - Returns whether back/forward are possible
+ * - gemini:get-location - Returns Gemini's URL, title and conversation ID
+ * - gemini:get-allowed-hosts / gemini:set-allowed-hosts - Hosts Gemini may navigate to
+ *   instead of opening them in the system browser (one per line)
+ *
+ * Also broadcasts windowManager gemini-navigation-changed events so the
+ * titlebar can enable or disable its navigation buttons, and
+ * gemini-location-changed events so it can show the conversation title.
+ *
+ * @module ipc/GeminiViewIpcHandler
+ */
+
+import { ipcMain } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../utils/constants';
+import { parseAllowedHosts } from '../../utils/allowedHosts';
+import type { GeminiLocation, GeminiNavigationState } from '../../../shared/types/navigation';
+
+/**
+ * Handler for Gemini view IPC channels.
+ */
+export class GeminiViewIpcHandler extends BaseIpcHandler {
+    private readonly onNavigationChanged = (state: GeminiNavigationState) =>
+        this.publishEvent(IPC_CHANNELS.GEMINI_NAVIGATION_CHANGED, state);
+
+    private readonly onLocationChanged = (location: GeminiLocation) =>
+        this.publishEvent(IPC_CHANNELS.GEMINI_LOCATION_CHANGED, location);
+
+    /**
+     * Register Gemini view IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.on(IPC_CHANNELS.GEMINI_RELOAD, (_event, ignoreCache: unknown) => {
+            try {
+                this.deps.windowManager.reloadGemini(ignoreCache === true);
+            } catch (error) {
+                this.handleError('reloading Gemini', error);
+            }
+        });
+
+        ipcMain.on(IPC_CHANNELS.GEMINI_GO_BACK, () => {
+            try {
+                this.deps.windowManager.goBackGemini();
+            } catch (error) {
+                this.handleError('navigating Gemini back', error);
+            }
+        });
+
+        ipcMain.on(IPC_CHANNELS.GEMINI_GO_FORWARD, () => {
+            try {
+                this.deps.windowManager.goForwardGemini();
+            } catch (error) {
+                this.handleError('navigating Gemini forward', error);
+            }
+        });
+
+        ipcMain.on(IPC_CHANNELS.GEMINI_GO_HOME, () => {
+            try {
+                this.deps.windowManager.goHomeGemini();
+            } catch (error) {
+                this.handleError('navigating Gemini home', error);
+            }
+        });
+
+        ipcMain.on(IPC_CHANNELS.GEMINI_PRINT, () => {
+            try {
+                this.deps.windowManager.printGemini();
+            } catch (error) {
+                this.handleError('printing Gemini', error);
+            }
+        });
+
+        ipcMain.on(IPC_CHANNELS.GEMINI_RELOAD_USER_STYLES, async () => {
+            try {
+                await this.deps.windowManager.reloadUserStyles();
+            } catch (error) {
+                this.handleError('reloading user styles', error);
+            }
+        });
+
+        ipcMain.handle(IPC_CHANNELS.GEMINI_GET_NAVIGATION_STATE, (): GeminiNavigationState => {
+            try {
+                return this.deps.windowManager.getGeminiNavigationState();
+            } catch (error) {
+                this.handleError('getting Gemini navigation state', error);
+                return { canGoBack: false, canGoForward: false };
+            }
+        });
+
+        ipcMain.handle(IPC_CHANNELS.GEMINI_GET_LOCATION, async (): Promise<GeminiLocation> => {
+            try {
+                return await this.deps.windowManager.getGeminiLocation();
+            } catch (error) {
+                this.handleError('getting Gemini location', error);
+                return { url: '', title: '', conversationId: null };
+            }
+        });
+
+        ipcMain.handle(IPC_CHANNELS.GEMINI_GET_ALLOWED_HOSTS, (): string => {
+            return this._getAllowedHosts();
+        });
+
+        ipcMain.on(IPC_CHANNELS.GEMINI_SET_ALLOWED_HOSTS, (_event, value: unknown) => {
+            try {
+                if (typeof value !== 'string') {
+                    this.logger.warn('Invalid allowed hosts:', value);
+                    return;
+                }
+
+                const hosts = parseAllowedHosts(value);
+                this.deps.store.set('navigationAllowList', hosts.join('\n'));
+                this.deps.windowManager.setAllowedHosts(hosts);
+            } catch (error) {
+                this.handleError('setting allowed hosts', error);
+            }
+        });
+
+        this.deps.windowManager.on('gemini-navigation-changed', this.onNavigationChanged);
+        this.deps.windowManager.on('gemini-location-changed', this.onLocationChanged);
+
+        try {
+            this.deps.windowManager.setAllowedHosts(parseAllowedHosts(this._getAllowedHosts()));
+        } catch (error) {
+            this.handleError('applying allowed hosts', error);
+        }
+    }
+
+    /**
+     * Unregister Gemini view IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeAllListeners(IPC_CHANNELS.GEMINI_RELOAD);
+        ipcMain.removeAllListeners(IPC_CHANNELS.GEMINI_GO_BACK);
+        ipcMain.removeAllListeners(IPC_CHANNELS.GEMINI_GO_FORWARD);
+        ipcMain.removeAllListeners(IPC_CHANNELS.GEMINI_GO_HOME);
+        ipcMain.removeAllListeners(IPC_CHANNELS.GEMINI_PRINT);
+        ipcMain.removeAllListeners(IPC_CHANNELS.GEMINI_RELOAD_USER_STYLES);
+        ipcMain.removeHandler(IPC_CHANNELS.GEMINI_GET_NAVIGATION_STATE);
+        ipcMain.removeHandler(IPC_CHANNELS.GEMINI_GET_LOCATION);
+        ipcMain.removeHandler(IPC_CHANNELS.GEMINI_GET_ALLOWED_HOSTS);
+        ipcMain.removeAllListeners(IPC_CHANNELS.GEMINI_SET_ALLOWED_HOSTS);
+        this.deps.windowManager.removeListener('gemini-navigation-changed', this.onNavigationChanged);
+        this.deps.windowManager.removeListener('gemini-location-changed', this.onLocationChanged);
+    }
+
+    /**
+     * Get the saved allow-list.
+     * @returns Hosts, one per line
+     */
+    private _getAllowedHosts(): string {
+        try {
+            const value = this.deps.store.get('navigationAllowList');
+            return typeof value === 'string' ? value : '';
+        } catch (error) {
+            this.logger.error('Error getting allowed hosts:', error);
+            return '';
+        }
+    }
+}
@@ -295,7 +295,7 @@ export class HotkeyIpcHandler extends BaseIpcHandler {
      */
     private _broadcastIndividualChange(): void {
         const settings = this._getIndividualSettings();
-        this.broadcastToAllWindows(IPC_CHANNELS.HOTKEYS_INDIVIDUAL_CHANGED, settings);
+        this.publishEvent(IPC_CHANNELS.HOTKEYS_INDIVIDUAL_CHANGED, settings);
     }
 
     /**
@@ -303,6 +303,6 @@ export class HotkeyIpcHandler extends BaseIpcHandler {
      */
     private _broadcastAcceleratorChange(): void {
         const accelerators = this._getAccelerators();
-        this.broadcastToAllWindows(IPC_CHANNELS.HOTKEYS_ACCELERATOR_CHANGED, accelerators);
+        this.publishEvent(IPC_CHANNELS.HOTKEYS_ACCELERATOR_CHANGED, accelerators);
     }
 }
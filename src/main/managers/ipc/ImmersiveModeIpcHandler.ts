@@ -0,0 +1,57 @@
+/**
+ * Immersive Mode IPC Handler.
+ *
+ * Handles IPC channels for distraction-free immersive mode:
+ * - immersive-mode:get - Returns whether immersive mode is on
+ * - immersive-mode:toggle - Enters or leaves immersive mode
+ *
+ * Also broadcasts windowManager immersive-mode-changed events so the main
+ * window can hide or show its titlebar.
+ *
+ * @module ipc/ImmersiveModeIpcHandler
+ */
+
+import { ipcMain } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../utils/constants';
+
+/**
+ * Handler for immersive mode IPC channels.
+ */
+export class ImmersiveModeIpcHandler extends BaseIpcHandler {
+    private readonly onImmersiveModeChanged = (enabled: boolean) =>
+        this.publishEvent(IPC_CHANNELS.IMMERSIVE_MODE_CHANGED, { enabled });
+
+    /**
+     * Register immersive mode IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.IMMERSIVE_MODE_GET, (): { enabled: boolean } => {
+            try {
+                return { enabled: this.deps.windowManager.isImmersiveMode() };
+            } catch (error) {
+                this.handleError('getting immersive mode', error);
+                return { enabled: false };
+            }
+        });
+
+        ipcMain.on(IPC_CHANNELS.IMMERSIVE_MODE_TOGGLE, () => {
+            try {
+                this.deps.windowManager.toggleImmersiveMode();
+            } catch (error) {
+                this.handleError('toggling immersive mode', error);
+            }
+        });
+
+        this.deps.windowManager.on('immersive-mode-changed', this.onImmersiveModeChanged);
+    }
+
+    /**
+     * Unregister immersive mode IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.IMMERSIVE_MODE_GET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.IMMERSIVE_MODE_TOGGLE);
+        this.deps.windowManager.removeListener('immersive-mode-changed', this.onImmersiveModeChanged);
+    }
+}
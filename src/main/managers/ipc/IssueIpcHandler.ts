@@ -0,0 +1,226 @@
+/**
+ * Issue IPC Handler.
+ *
+ * Handles IPC channels for filing issues from answers:
+ * - issues:draft-request - Draft an issue from the selected text or the latest answer
+ * - issues:draft - Draft pushed to the main window for review (main → renderer)
+ * - issues:create - Create the reviewed issue and return its URL
+ * - issues:settings:get/set - GitHub or Jira connection settings
+ *
+ * @module ipc/IssueIpcHandler
+ */
+
+import { ipcMain, session, type WebContents } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import type { UserPreferences } from './types';
+import { IPC_CHANNELS } from '../../../shared/constants/ipc-channels';
+import { showToast } from '../../utils/toast';
+import { findGeminiFrame } from '../../utils/geminiFrame';
+import { decryptSecret, encryptSecret } from '../../utils/secrets';
+import { createIssue, draftIssue, validateIssueTrackerSettings } from '../../utils/issues';
+import {
+    DEFAULT_ISSUE_TRACKER_SETTINGS,
+    type IssueCreateResult,
+    type IssueDraft,
+    type IssueTracker,
+    type IssueTrackerSettings,
+} from '../../../shared/types/issues';
+
+/**
+ * Handler for issue creation IPC channels.
+ */
+export class IssueIpcHandler extends BaseIpcHandler {
+    /** Bound listener for create-issue-triggered events (for removal) */
+    private readonly onCreateIssueTriggered = (): void => {
+        this.draftFromGemini().catch((error) => this.handleError('drafting issue (menu)', error));
+    };
+
+    /**
+     * Register issue IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.on(IPC_CHANNELS.ISSUES_DRAFT_REQUEST, () => {
+            this.draftFromGemini().catch((error) => this.handleError('drafting issue', error));
+        });
+
+        ipcMain.handle(IPC_CHANNELS.ISSUES_CREATE, (_event, draft: IssueDraft): Promise<IssueCreateResult> => {
+            return this.create(draft);
+        });
+
+        ipcMain.handle(IPC_CHANNELS.ISSUES_SETTINGS_GET, (): IssueTrackerSettings => {
+            return this._getSettings();
+        });
+
+        ipcMain.on(IPC_CHANNELS.ISSUES_SETTINGS_SET, (_event, settings: IssueTrackerSettings) => {
+            this._handleSetSettings(settings);
+        });
+
+        // Native application menu
+        this.deps.windowManager.on('create-issue-triggered', this.onCreateIssueTriggered);
+    }
+
+    /**
+     * Unregister issue IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeAllListeners(IPC_CHANNELS.ISSUES_DRAFT_REQUEST);
+        ipcMain.removeHandler(IPC_CHANNELS.ISSUES_CREATE);
+        ipcMain.removeHandler(IPC_CHANNELS.ISSUES_SETTINGS_GET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.ISSUES_SETTINGS_SET);
+        this.deps.windowManager.removeListener('create-issue-triggered', this.onCreateIssueTriggered);
+    }
+
+    /**
+     * Draft an issue from the text selected in Gemini, or from the latest
+     * answer when nothing is selected, and send it to the main window for review.
+     */
+    async draftFromGemini(): Promise<void> {
+        if (!this.deps.exportManager) {
+            this.logger.error('ExportManager not initialized');
+            return;
+        }
+
+        const mainWindow = this.deps.windowManager.getMainWindow();
+        if (!mainWindow || mainWindow.isDestroyed()) {
+            this.logger.warn('Cannot create issue: main window not found or destroyed');
+            return;
+        }
+
+        const settings = this._getSettings();
+        const problem = validateIssueTrackerSettings(settings, true);
+        if (problem) {
+            showToast(mainWindow, { type: 'error', message: `Cannot create issue: ${problem}` });
+            return;
+        }
+
+        let markdown = await this._getSelectedText(mainWindow.webContents);
+        if (!markdown) {
+            markdown = (await this.deps.exportManager.getLastResponse(mainWindow.webContents))?.markdown ?? '';
+        }
+        if (!markdown.trim()) {
+            showToast(mainWindow, { type: 'info', message: 'No answer or selection to create an issue from' });
+            return;
+        }
+
+        const draft: IssueDraft = { tracker: settings.tracker, ...draftIssue(markdown) };
+        mainWindow.webContents.send(IPC_CHANNELS.ISSUES_DRAFT, draft);
+    }
+
+    /**
+     * Create a reviewed issue in the configured tracker.
+     * @param draft - Title and description as confirmed in the review dialog
+     */
+    async create(draft: IssueDraft): Promise<IssueCreateResult> {
+        if (!draft || typeof draft.title !== 'string' || !draft.title.trim() || typeof draft.body !== 'string') {
+            this.logger.warn('Invalid issue draft');
+            return { success: false, error: 'An issue needs a title' };
+        }
+
+        const settings = this._getSettings();
+        const problem = validateIssueTrackerSettings(settings, true);
+        if (problem) {
+            return { success: false, error: problem };
+        }
+
+        try {
+            const url = await createIssue(session.defaultSession, settings, {
+                title: draft.title.trim(),
+                body: draft.body,
+            });
+            this.logger.log(`Issue created: ${url}`);
+            return { success: true, url };
+        } catch (error) {
+            const message = (error as Error).message;
+            this.logger.error('Failed to create issue:', { error: message });
+            return { success: false, error: message };
+        }
+    }
+
+    /**
+     * Get the text selected in the Gemini frame, if any.
+     */
+    private async _getSelectedText(webContents: WebContents): Promise<string> {
+        const frame = findGeminiFrame(webContents);
+        if (!frame) return '';
+
+        try {
+            const selection = await frame.executeJavaScript('window.getSelection()?.toString() ?? ""');
+            return typeof selection === 'string' ? selection.trim() : '';
+        } catch (error) {
+            this.logger.warn('Could not read the Gemini selection:', error);
+            return '';
+        }
+    }
+
+    /**
+     * Get the saved issue tracker settings.
+     * @returns Settings with the tokens decrypted
+     */
+    private _getSettings(): IssueTrackerSettings {
+        try {
+            const store = this.deps.store;
+            return {
+                tracker: (store.get('issueTracker') as IssueTracker) ?? DEFAULT_ISSUE_TRACKER_SETTINGS.tracker,
+                githubRepository: store.get('githubRepository') ?? DEFAULT_ISSUE_TRACKER_SETTINGS.githubRepository,
+                githubToken: decryptSecret(store.get('githubToken') ?? ''),
+                jiraBaseUrl: store.get('jiraBaseUrl') ?? DEFAULT_ISSUE_TRACKER_SETTINGS.jiraBaseUrl,
+                jiraEmail: store.get('jiraEmail') ?? DEFAULT_ISSUE_TRACKER_SETTINGS.jiraEmail,
+                jiraApiToken: decryptSecret(store.get('jiraApiToken') ?? ''),
+                jiraProjectKey: store.get('jiraProjectKey') ?? DEFAULT_ISSUE_TRACKER_SETTINGS.jiraProjectKey,
+            };
+        } catch (error) {
+            this.logger.error('Error getting issue tracker settings:', error);
+            return { ...DEFAULT_ISSUE_TRACKER_SETTINGS };
+        }
+    }
+
+    /**
+     * Handle issues:settings:set request.
+     * @param settings - New issue tracker settings
+     */
+    private _handleSetSettings(settings: IssueTrackerSettings): void {
+        try {
+            if (!settings || typeof settings !== 'object') {
+                this.logger.warn(`Invalid issue tracker settings: ${settings}`);
+                return;
+            }
+
+            const next: IssueTrackerSettings = {
+                tracker: settings.tracker,
+                githubRepository: String(settings.githubRepository ?? '').trim(),
+                githubToken: String(settings.githubToken ?? ''),
+                jiraBaseUrl: String(settings.jiraBaseUrl ?? '').trim(),
+                jiraEmail: String(settings.jiraEmail ?? '').trim(),
+                jiraApiToken: String(settings.jiraApiToken ?? ''),
+                jiraProjectKey: String(settings.jiraProjectKey ?? '').trim(),
+            };
+            const problem = validateIssueTrackerSettings(next);
+            if (problem) {
+                this.logger.warn(problem);
+                return;
+            }
+
+            const store = this.deps.store;
+            const values: Partial<UserPreferences> = {
+                issueTracker: next.tracker,
+                githubRepository: next.githubRepository,
+                jiraBaseUrl: next.jiraBaseUrl,
+                jiraEmail: next.jiraEmail,
+                jiraProjectKey: next.jiraProjectKey,
+            };
+            // Encrypting can give new ciphertext for the same token; only save tokens that changed
+            if (decryptSecret(store.get('githubToken') ?? '') !== next.githubToken) {
+                values.githubToken = encryptSecret(next.githubToken);
+            }
+            if (decryptSecret(store.get('jiraApiToken') ?? '') !== next.jiraApiToken) {
+                values.jiraApiToken = encryptSecret(next.jiraApiToken);
+            }
+            store.setMany(values);
+
+            // Never log credentials
+            this.logger.log(`Issue tracker settings updated: ${next.tracker}`);
+        } catch (error) {
+            this.logger.error('Error setting issue tracker settings:', { error: (error as Error).message });
+        }
+    }
+}
@@ -0,0 +1,172 @@
+/**
+ * Gemini Language IPC Handler.
+ *
+ * Handles IPC channels for the Gemini language override and the spell checker:
+ * - gemini-language:get - Returns the language tag ('' follows the system locale)
+ * - gemini-language:set - Validates, saves and applies the language tag
+ * - spell-check:get - Returns the spell checker settings and available dictionaries
+ * - spell-check:set-enabled - Turns the spell checker on or off
+ * - spell-check:set-languages - Validates, saves and applies the languages to check
+ *
+ * Also applies the saved language and spell checker settings on startup.
+ *
+ * @module ipc/LanguageIpcHandler
+ */
+
+import { ipcMain, session } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../utils/constants';
+import { applyGeminiLanguage, applySpellCheck, parseSpellCheckLanguages } from '../../utils/language';
+import { isLanguageTag, MAX_SPELL_CHECK_LANGUAGES, type SpellCheckSettings } from '../../../shared/types/language';
+
+/**
+ * Handler for Gemini language IPC channels.
+ */
+export class LanguageIpcHandler extends BaseIpcHandler {
+    /**
+     * Register language IPC handlers with ipcMain and apply the saved language.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.GEMINI_LANGUAGE_GET, (): string => {
+            return this._getLanguage();
+        });
+
+        ipcMain.on(IPC_CHANNELS.GEMINI_LANGUAGE_SET, (_event, tag: string) => {
+            this._handleSetLanguage(tag);
+        });
+
+        ipcMain.handle(IPC_CHANNELS.SPELL_CHECK_GET, (): SpellCheckSettings => {
+            return this._getSpellCheckSettings();
+        });
+
+        ipcMain.on(IPC_CHANNELS.SPELL_CHECK_SET_ENABLED, (_event, enabled: boolean) => {
+            this._handleSetSpellCheckEnabled(enabled);
+        });
+
+        ipcMain.on(IPC_CHANNELS.SPELL_CHECK_SET_LANGUAGES, (_event, languages: string[]) => {
+            this._handleSetSpellCheckLanguages(languages);
+        });
+
+        this._apply(this._getLanguage());
+    }
+
+    /**
+     * Unregister language IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.GEMINI_LANGUAGE_GET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.GEMINI_LANGUAGE_SET);
+        ipcMain.removeHandler(IPC_CHANNELS.SPELL_CHECK_GET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.SPELL_CHECK_SET_ENABLED);
+        ipcMain.removeAllListeners(IPC_CHANNELS.SPELL_CHECK_SET_LANGUAGES);
+    }
+
+    /**
+     * Get the saved language tag, falling back to the system locale for invalid values.
+     * @returns Language tag, or '' for the system locale
+     */
+    private _getLanguage(): string {
+        try {
+            const tag = this.deps.store.get('geminiLanguage');
+            return isLanguageTag(tag) ? tag : '';
+        } catch (error) {
+            this.logger.error('Error getting Gemini language:', error);
+            return '';
+        }
+    }
+
+    /**
+     * Handle gemini-language:set request.
+     * @param tag - New language tag, or '' for the system locale
+     */
+    private _handleSetLanguage(tag: string): void {
+        try {
+            if (!isLanguageTag(tag)) {
+                this.logger.warn(`Invalid Gemini language: ${tag}`);
+                return;
+            }
+
+            this.deps.store.set('geminiLanguage', tag);
+            this._apply(tag);
+        } catch (error) {
+            this.logger.error('Error setting Gemini language:', {
+                error: (error as Error).message,
+                requestedLanguage: tag,
+            });
+        }
+    }
+
+    /**
+     * Get the spell checker settings, dropping invalid saved languages.
+     * @returns Spell checker settings with the dictionaries available on this platform
+     */
+    private _getSpellCheckSettings(): SpellCheckSettings {
+        try {
+            const languages = parseSpellCheckLanguages(this.deps.store.get('spellCheckLanguages') ?? '');
+            return {
+                enabled: this.deps.store.get('spellCheckEnabled') ?? true,
+                languages: languages.filter(isLanguageTag).slice(0, MAX_SPELL_CHECK_LANGUAGES),
+                available: session.defaultSession.availableSpellCheckerLanguages,
+            };
+        } catch (error) {
+            this.logger.error('Error getting spell check settings:', error);
+            return { enabled: true, languages: [], available: [] };
+        }
+    }
+
+    /**
+     * Handle spell-check:set-enabled request.
+     * @param enabled - Whether to check spelling
+     */
+    private _handleSetSpellCheckEnabled(enabled: boolean): void {
+        try {
+            if (typeof enabled !== 'boolean') {
+                this.logger.warn(`Invalid spellCheckEnabled value: ${enabled}`);
+                return;
+            }
+
+            this.deps.store.set('spellCheckEnabled', enabled);
+            this._apply(this._getLanguage());
+        } catch (error) {
+            this.handleError('setting spell check enabled', error);
+        }
+    }
+
+    /**
+     * Handle spell-check:set-languages request.
+     * @param languages - Language tags to check; empty follows the Gemini language
+     */
+    private _handleSetSpellCheckLanguages(languages: string[]): void {
+        try {
+            if (
+                !Array.isArray(languages) ||
+                languages.length > MAX_SPELL_CHECK_LANGUAGES ||
+                !languages.every((tag) => tag !== '' && isLanguageTag(tag))
+            ) {
+                this.logger.warn(`Invalid spell check languages: ${JSON.stringify(languages)}`);
+                return;
+            }
+
+            this.deps.store.set('spellCheckLanguages', [...new Set(languages)].join('\n'));
+            this._apply(this._getLanguage());
+        } catch (error) {
+            this.handleError('setting spell check languages', error);
+        }
+    }
+
+    /**
+     * Apply the language and spell checker settings to the default session.
+     */
+    private _apply(tag: string): void {
+        try {
+            const acceptLanguage = applyGeminiLanguage(session.defaultSession, tag);
+            this.logger.log(`Gemini language: ${acceptLanguage ?? 'system default'}`);
+
+            const settings = this._getSpellCheckSettings();
+            const languages = applySpellCheck(session.defaultSession, settings, tag);
+            this.logger.log(`Spell check: ${settings.enabled ? languages.join(', ') || 'system' : 'off'}`);
+        } catch (error) {
+            this.handleError('applying Gemini language', error);
+        }
+    }
+}
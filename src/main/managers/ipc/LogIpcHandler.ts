@@ -0,0 +1,53 @@
+/**
+ * Log IPC Handler.
+ *
+ * Handles IPC channels for runtime logging verbosity:
+ * - log:get-level - Returns the active log level spec
+ * - log:set-level - Applies a log level spec (e.g. 'debug' or 'warn,ExportManager=debug')
+ *
+ * @module ipc/LogIpcHandler
+ */
+
+import { ipcMain } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../../shared/constants/ipc-channels';
+import { getLogLevel, setLogLevel } from '../../utils/logger';
+
+/**
+ * Handler for logging IPC channels.
+ */
+export class LogIpcHandler extends BaseIpcHandler {
+    /**
+     * Register log IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.LOG_GET_LEVEL, (): string => {
+            return getLogLevel();
+        });
+
+        ipcMain.on(IPC_CHANNELS.LOG_SET_LEVEL, (_event, spec: string) => {
+            this._handleSetLevel(spec);
+        });
+    }
+
+    /**
+     * Unregister log IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.LOG_GET_LEVEL);
+        ipcMain.removeAllListeners(IPC_CHANNELS.LOG_SET_LEVEL);
+    }
+
+    /**
+     * Handle log:set-level request.
+     * @param spec - Log level spec
+     */
+    private _handleSetLevel(spec: string): void {
+        if (!setLogLevel(spec)) {
+            this.logger.warn(`Invalid log level: ${spec}`);
+            return;
+        }
+        // Logged as a warning so the change is visible at any level
+        this.logger.warn(`Log level set to: ${spec}`);
+    }
+}
@@ -0,0 +1,102 @@
+/**
+ * Overlay IPC Handler.
+ *
+ * Handles IPC channels for window opacity and overlay mode, where the main
+ * window floats translucently above other apps and lets clicks through:
+ * - window-opacity:get - Returns the main window opacity
+ * - window-opacity:set - Sets the main window opacity
+ * - overlay-mode:get - Returns whether overlay mode is on
+ * - overlay-mode:toggle - Enters or leaves overlay mode
+ *
+ * Also persists and broadcasts windowManager window-opacity-changed events,
+ * and broadcasts overlay-mode-changed events. Overlay mode itself is not
+ * saved, so the app never starts with a window that ignores clicks.
+ *
+ * @module ipc/OverlayIpcHandler
+ */
+
+import { ipcMain } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../utils/constants';
+
+/**
+ * Handler for window opacity and overlay mode IPC channels.
+ */
+export class OverlayIpcHandler extends BaseIpcHandler {
+    private readonly onWindowOpacityChanged = (opacity: number) => {
+        try {
+            this.deps.store.set('windowOpacity', opacity);
+            this.publishEvent(IPC_CHANNELS.WINDOW_OPACITY_CHANGED, { opacity });
+        } catch (error) {
+            this.handleError('saving window opacity', error);
+        }
+    };
+
+    private readonly onOverlayModeChanged = (enabled: boolean) =>
+        this.publishEvent(IPC_CHANNELS.OVERLAY_MODE_CHANGED, { enabled });
+
+    /**
+     * Register overlay IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.WINDOW_OPACITY_GET, (): { opacity: number } => {
+            try {
+                return { opacity: this.deps.windowManager.getWindowOpacity() };
+            } catch (error) {
+                this.handleError('getting window opacity', error);
+                return { opacity: 1 };
+            }
+        });
+
+        ipcMain.on(IPC_CHANNELS.WINDOW_OPACITY_SET, (_event, opacity: number) => {
+            try {
+                this.deps.windowManager.setWindowOpacity(opacity);
+            } catch (error) {
+                this.handleError('setting window opacity', error);
+            }
+        });
+
+        ipcMain.handle(IPC_CHANNELS.OVERLAY_MODE_GET, (): { enabled: boolean } => {
+            try {
+                return { enabled: this.deps.windowManager.isOverlayMode() };
+            } catch (error) {
+                this.handleError('getting overlay mode', error);
+                return { enabled: false };
+            }
+        });
+
+        ipcMain.on(IPC_CHANNELS.OVERLAY_MODE_TOGGLE, () => {
+            try {
+                this.deps.windowManager.toggleOverlayMode();
+            } catch (error) {
+                this.handleError('toggling overlay mode', error);
+            }
+        });
+
+        this.deps.windowManager.on('window-opacity-changed', this.onWindowOpacityChanged);
+        this.deps.windowManager.on('overlay-mode-changed', this.onOverlayModeChanged);
+    }
+
+    /**
+     * Apply the saved window opacity.
+     */
+    initialize(): void {
+        try {
+            this.deps.windowManager.setWindowOpacity(this.deps.store.get('windowOpacity') ?? 1);
+        } catch (error) {
+            this.handleError('initializing window opacity', error);
+        }
+    }
+
+    /**
+     * Unregister overlay IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.WINDOW_OPACITY_GET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.WINDOW_OPACITY_SET);
+        ipcMain.removeHandler(IPC_CHANNELS.OVERLAY_MODE_GET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.OVERLAY_MODE_TOGGLE);
+        this.deps.windowManager.removeListener('window-opacity-changed', this.onWindowOpacityChanged);
+        this.deps.windowManager.removeListener('overlay-mode-changed', this.onOverlayModeChanged);
+    }
+}
@@ -0,0 +1,131 @@
+/**
+ * Proxy IPC Handler.
+ *
+ * Handles IPC channels for the upstream proxy:
+ * - proxy:get-settings - Returns the saved proxy settings
+ * - proxy:set-settings - Validates, saves and applies proxy settings
+ * - proxy:test - Checks that Gemini is reachable through the current proxy
+ *
+ * Also applies the saved settings on startup and answers proxy
+ * authentication challenges with the saved credentials.
+ *
+ * @module ipc/ProxyIpcHandler
+ */
+
+import { app, ipcMain, session } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import type { UserPreferences } from './types';
+import { IPC_CHANNELS } from '../../utils/constants';
+import {
+    applyProxySettings,
+    decryptProxyPassword,
+    encryptProxyPassword,
+    testProxyConnection,
+    validateProxySettings,
+} from '../../utils/proxy';
+import { createProxyLoginListener, readProxySettings } from '../../utils/networkSettings';
+import { DEFAULT_PROXY_SETTINGS, type ProxySettings, type ProxyTestResult } from '../../../shared/types/proxy';
+
+/**
+ * Handler for upstream proxy IPC channels.
+ */
+export class ProxyIpcHandler extends BaseIpcHandler {
+    /** Listener for proxy authentication challenges (for removal) */
+    private readonly onLogin = createProxyLoginListener(this.deps.store);
+
+    /**
+     * Register proxy IPC handlers with ipcMain and apply the saved settings.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.PROXY_GET_SETTINGS, (): ProxySettings => {
+            return this._getSettings();
+        });
+
+        ipcMain.on(IPC_CHANNELS.PROXY_SET_SETTINGS, (_event, settings: ProxySettings) => {
+            this._handleSetSettings(settings);
+        });
+
+        ipcMain.handle(IPC_CHANNELS.PROXY_TEST, (): Promise<ProxyTestResult> => {
+            return testProxyConnection(session.defaultSession);
+        });
+
+        app.on('login', this.onLogin);
+
+        this._apply(this._getSettings());
+    }
+
+    /**
+     * Unregister proxy IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.PROXY_GET_SETTINGS);
+        ipcMain.removeAllListeners(IPC_CHANNELS.PROXY_SET_SETTINGS);
+        ipcMain.removeHandler(IPC_CHANNELS.PROXY_TEST);
+        app.removeListener('login', this.onLogin);
+    }
+
+    /**
+     * Get the saved proxy settings, falling back to defaults for invalid values.
+     * @returns Proxy settings with the password decrypted
+     */
+    private _getSettings(): ProxySettings {
+        try {
+            return readProxySettings(this.deps.store);
+        } catch (error) {
+            this.logger.error('Error getting proxy settings:', error);
+            return { ...DEFAULT_PROXY_SETTINGS };
+        }
+    }
+
+    /**
+     * Handle proxy:set-settings request.
+     * @param settings - New proxy settings
+     */
+    private _handleSetSettings(settings: ProxySettings): void {
+        try {
+            if (!settings || typeof settings !== 'object') {
+                this.logger.warn(`Invalid proxy settings: ${settings}`);
+                return;
+            }
+
+            const next: ProxySettings = { ...DEFAULT_PROXY_SETTINGS, ...settings, host: (settings.host ?? '').trim() };
+            const problem = validateProxySettings(next);
+            if (problem) {
+                this.logger.warn(problem);
+                return;
+            }
+
+            // One save and one history entry, so a single undo restores the previous proxy
+            const values: Partial<UserPreferences> = {
+                proxyMode: next.mode,
+                proxyHost: next.host,
+                proxyPort: next.port,
+                proxyUsername: next.username,
+                proxyBypass: next.bypass,
+            };
+            // Encrypting can give new ciphertext for the same password; don't log that as a change
+            if (decryptProxyPassword(this.deps.store.get('proxyPassword') ?? '') !== next.password) {
+                values.proxyPassword = encryptProxyPassword(next.password);
+            }
+            this.deps.store.setMany(values);
+
+            // Never log credentials
+            this.logger.log(`Proxy settings updated: ${next.mode} ${next.host}:${next.port}`);
+            this._apply(next);
+        } catch (error) {
+            this.logger.error('Error setting proxy settings:', {
+                error: (error as Error).message,
+                requestedMode: settings?.mode,
+            });
+        }
+    }
+
+    /**
+     * Apply proxy settings to the default session.
+     */
+    private _apply(settings: ProxySettings): void {
+        applyProxySettings(session.defaultSession, settings).catch((error) =>
+            this.handleError('applying proxy settings', error)
+        );
+    }
+}
@@ -5,16 +5,27 @@
  * - quick-chat:submit - Submits text from Quick Chat to main window
  * - quick-chat:hide - Hides the Quick Chat window
  * - quick-chat:cancel - Cancels and hides Quick Chat
+ * - quick-chat:translate - Wraps text/clipboard in a translation prompt and submits it
+ * - quick-chat:translate-language:get - Returns the remembered target language
  * - gemini:ready - Triggers text injection into Gemini iframe
  *
+ * Also submits prompts from windowManager ask-triggered events (the `ask`
+ * instance command) through the same flow.
+ *
  * @module ipc/QuickChatIpcHandler
  */
 
-import { ipcMain } from 'electron';
+import { ipcMain, clipboard } from 'electron';
 import { BaseIpcHandler } from './BaseIpcHandler';
 import { IPC_CHANNELS, isGeminiDomain } from '../../utils/constants';
 import { GEMINI_APP_URL } from '../../../shared/constants/index';
 import { InjectionScriptBuilder, InjectionResult } from '../../utils/injectionScript';
+import {
+    buildTranslationPrompt,
+    DEFAULT_TRANSLATE_LANGUAGE,
+    isTranslateLanguage,
+    type TranslateLanguage,
+} from '../../../shared/types/translation';
 
 /**
  * Handler for Quick Chat related IPC channels.
@@ -48,6 +59,21 @@ export class QuickChatIpcHandler extends BaseIpcHandler {
         ipcMain.on(IPC_CHANNELS.QUICK_CHAT_CANCEL, () => {
             this._handleCancel();
         });
+
+        // Prompts sent to the running instance by other processes
+        this.deps.windowManager.on('ask-triggered', (text: string) => {
+            this._handleSubmit(text);
+        });
+
+        // Translate text (or clipboard) via Gemini
+        ipcMain.on(IPC_CHANNELS.QUICK_CHAT_TRANSLATE, (_event, text: string, language: TranslateLanguage) => {
+            this._handleTranslate(text, language);
+        });
+
+        // Get remembered translation target language
+        ipcMain.handle(IPC_CHANNELS.QUICK_CHAT_TRANSLATE_LANGUAGE_GET, (): TranslateLanguage => {
+            return this._getTranslateLanguage();
+        });
     }
 
     /**
@@ -67,6 +93,9 @@ export class QuickChatIpcHandler extends BaseIpcHandler {
             // Send navigation request to renderer (React app will reload iframe)
             const mainWindow = this.deps.windowManager.getMainWindow();
             if (mainWindow) {
+                // Let listeners (e.g. auto-copy) know the next answer belongs to Quick Chat
+                this.deps.windowManager.emit('quick-chat-submitted');
+
                 this.logger.log('Sending gemini:navigate to renderer');
                 mainWindow.webContents.send(IPC_CHANNELS.GEMINI_NAVIGATE, {
                     url: GEMINI_APP_URL,
@@ -80,6 +109,50 @@ export class QuickChatIpcHandler extends BaseIpcHandler {
         }
     }
 
+    /**
+     * Handle quick-chat:translate request.
+     * Wraps the given text (or clipboard contents when empty) in a translation
+     * prompt and submits it through the normal Quick Chat flow.
+     * @param text - Text to translate; empty to use the clipboard
+     * @param language - Target language
+     */
+    private _handleTranslate(text: string, language: TranslateLanguage): void {
+        try {
+            if (!isTranslateLanguage(language)) {
+                this.logger.warn(`Invalid translation language: ${language}`);
+                return;
+            }
+
+            // Remember the chosen language for next time
+            this.deps.store.set('translateTargetLanguage', language);
+
+            const source = typeof text === 'string' && text.trim() ? text : clipboard.readText();
+            if (!source.trim()) {
+                this.logger.warn('Nothing to translate: input and clipboard are empty');
+                return;
+            }
+
+            this.logger.log(`Translating ${source.length} characters to ${language}`);
+            this._handleSubmit(buildTranslationPrompt(source, language));
+        } catch (error) {
+            this.handleError('handling quick chat translate', error);
+        }
+    }
+
+    /**
+     * Get the remembered translation target language.
+     * @returns Stored language, or the default if unset/invalid
+     */
+    private _getTranslateLanguage(): TranslateLanguage {
+        try {
+            const stored = this.deps.store.get('translateTargetLanguage');
+            return isTranslateLanguage(stored) ? stored : DEFAULT_TRANSLATE_LANGUAGE;
+        } catch (error) {
+            this.logger.error('Error getting translation language:', error);
+            return DEFAULT_TRANSLATE_LANGUAGE;
+        }
+    }
+
     /**
      * Handle gemini:ready request.
      * Injects text into the Gemini iframe.
@@ -0,0 +1,124 @@
+/**
+ * Screenshot IPC Handler.
+ *
+ * Handles IPC channels for capturing the Gemini view as an image:
+ * - screenshot:capture - Capture the visible Gemini view or the latest answer
+ *   to a PNG file or the clipboard
+ *
+ * @module ipc/ScreenshotIpcHandler
+ */
+
+import { app, clipboard, dialog, ipcMain } from 'electron';
+import * as fs from 'fs/promises';
+import * as path from 'path';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../../shared/constants/ipc-channels';
+import { showToast } from '../../utils/toast';
+import { findGeminiFrame } from '../../utils/geminiFrame';
+import { buildExportFilename } from '../../utils/exportFilename';
+import { ensureDiskSpace, isDiskFullError } from '../../utils/diskSpace';
+import {
+    GEMINI_IFRAME_RECT_SCRIPT,
+    LAST_RESPONSE_RECT_SCRIPT,
+    SCREENSHOT_FILENAME_TEMPLATE,
+    isValidScreenshotOptions,
+    toCaptureRect,
+} from '../../utils/screenshot';
+import type { ScreenshotOptions } from '../../../shared/types/screenshot';
+
+/**
+ * Handler for screenshot IPC channels.
+ */
+export class ScreenshotIpcHandler extends BaseIpcHandler {
+    /** Bound listener for screenshot-triggered events (for removal) */
+    private readonly onScreenshotTriggered = (options: ScreenshotOptions): void => {
+        this.capture(options).catch((error) => this.handleError('capturing screenshot (menu)', error));
+    };
+
+    /**
+     * Register screenshot IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.on(IPC_CHANNELS.SCREENSHOT_CAPTURE, (_event, options: unknown) => {
+            if (!isValidScreenshotOptions(options)) {
+                this.logger.warn(`Invalid screenshot options: ${JSON.stringify(options)}`);
+                return;
+            }
+            this.capture(options).catch((error) => this.handleError('capturing screenshot', error));
+        });
+
+        // Native application menu
+        this.deps.windowManager.on('screenshot-triggered', this.onScreenshotTriggered);
+    }
+
+    /**
+     * Unregister screenshot IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeAllListeners(IPC_CHANNELS.SCREENSHOT_CAPTURE);
+        this.deps.windowManager.removeListener('screenshot-triggered', this.onScreenshotTriggered);
+    }
+
+    /**
+     * Capture the Gemini view, or only the latest answer, to a file or the clipboard.
+     *
+     * Only what is on screen can be captured, so a long answer is cut off at
+     * the edge of the view.
+     *
+     * @param options - What to capture and where to put it
+     */
+    async capture(options: ScreenshotOptions): Promise<void> {
+        const mainWindow = this.deps.windowManager.getMainWindow();
+        if (!mainWindow || mainWindow.isDestroyed()) {
+            this.logger.warn('Cannot capture screenshot: main window not found or destroyed');
+            return;
+        }
+
+        const webContents = mainWindow.webContents;
+        const frame = findGeminiFrame(webContents);
+        const frameRect = frame ? await webContents.executeJavaScript(GEMINI_IFRAME_RECT_SCRIPT) : null;
+        if (!frame || !frameRect) {
+            showToast(mainWindow, { type: 'error', message: 'Cannot capture screenshot: Gemini is not loaded' });
+            return;
+        }
+
+        const responseRect =
+            options.region === 'response' ? await frame.executeJavaScript(LAST_RESPONSE_RECT_SCRIPT) : undefined;
+        const rect = responseRect === null ? null : toCaptureRect(frameRect, responseRect);
+        if (!rect) {
+            showToast(mainWindow, { type: 'info', message: 'No answer to capture' });
+            return;
+        }
+
+        const image = await webContents.capturePage(rect);
+
+        if (options.target === 'clipboard') {
+            clipboard.writeImage(image);
+            showToast(mainWindow, { type: 'success', message: 'Screenshot copied to clipboard' });
+            return;
+        }
+
+        const { filePath, canceled } = await dialog.showSaveDialog(mainWindow, {
+            title: 'Save Screenshot',
+            defaultPath: path.join(
+                app.getPath('downloads'),
+                buildExportFilename(SCREENSHOT_FILENAME_TEMPLATE, { title: '', timestamp: new Date(), format: 'png' })
+            ),
+            filters: [{ name: 'PNG Images', extensions: ['png'] }],
+        });
+        if (canceled || !filePath) return;
+
+        const png = image.toPNG();
+        try {
+            await ensureDiskSpace(path.dirname(filePath), png.length);
+            await fs.writeFile(filePath, png);
+        } catch (error) {
+            if (!isDiskFullError(error)) throw error;
+            this.logger.warn(`Screenshot not saved: ${(error as Error).message}`);
+            showToast(mainWindow, { type: 'error', message: 'Not enough disk space to save the screenshot' });
+            return;
+        }
+        showToast(mainWindow, { type: 'success', message: 'Screenshot saved' });
+        this.logger.log(`Screenshot saved to ${filePath}`);
+    }
+}
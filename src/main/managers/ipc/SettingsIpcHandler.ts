@@ -0,0 +1,247 @@
+/**
+ * Settings IPC Handler.
+ *
+ * Handles IPC channels for settings maintenance:
+ * - settings:reset - Restore defaults for a section ('hotkeys', 'appearance' or 'all'),
+ *   writing a backup of the current settings first
+ * - settings:get-history - Return the logged settings changes, newest first
+ * - settings:undo - Undo the most recent n changes
+ *
+ * @module ipc/SettingsIpcHandler
+ */
+
+import { ipcMain, nativeTheme } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import type { UserPreferences } from './types';
+import { IPC_CHANNELS } from '../../utils/constants';
+import { DEFAULT_ACCELERATORS } from '../../../shared/types/hotkeys';
+import {
+    SETTINGS_RESET_SECTIONS,
+    type SettingsResetResult,
+    type SettingsResetSection,
+} from '../../../shared/types/settings-reset';
+import type { SettingsChange, SettingsUndoResult } from '../../../shared/types/settings-history';
+
+type PreferenceKey = keyof UserPreferences & string;
+
+/**
+ * Keys covered by each section (other than 'all').
+ */
+const SECTION_KEYS: Record<Exclude<SettingsResetSection, 'all'>, PreferenceKey[]> = {
+    hotkeys: [
+        'hotkeyAlwaysOnTop',
+        'hotkeyBossKey',
+        'hotkeyQuickChat',
+        'hotkeyPrintToPdf',
+        'acceleratorAlwaysOnTop',
+        'acceleratorBossKey',
+        'acceleratorQuickChat',
+        'acceleratorPrintToPdf',
+    ],
+    appearance: ['theme', 'alwaysOnTop', 'zoomLevel'],
+};
+
+/**
+ * Keys that record state rather than user choices and are never reset.
+ */
+const STATE_KEYS: PreferenceKey[] = [
+    'textPredictionModelStatus',
+    // Resetting it would show every changelog entry ever shipped
+    'whatsNewLastVersion',
+    'windowX',
+    'windowY',
+    'windowWidth',
+    'windowHeight',
+    'windowMaximized',
+    'windowFullScreen',
+];
+
+/**
+ * Keys whose values are hidden in the settings history sent to the renderer.
+ */
+const SECRET_KEYS: PreferenceKey[] = [
+    'proxyPassword',
+    'confluenceApiToken',
+    'githubToken',
+    'jiraApiToken',
+    // The webhook URL is the credential
+    'shareWebhookUrl',
+];
+
+/** Shown instead of secret values in the settings history */
+const REDACTED = '••••••••';
+
+/**
+ * Hide secret values in a change, and the changes saved with it, sent to the renderer.
+ */
+function redactChange(change: SettingsChange): SettingsChange {
+    const batch = change.batch?.map(redactChange);
+    if (!SECRET_KEYS.includes(change.key as PreferenceKey)) return batch ? { ...change, batch } : change;
+
+    const redacted: SettingsChange = { timestamp: change.timestamp, key: change.key };
+    if ('previous' in change) redacted.previous = change.previous ? REDACTED : change.previous;
+    if ('value' in change) redacted.value = change.value ? REDACTED : change.value;
+    if (batch) redacted.batch = batch;
+    return redacted;
+}
+
+/**
+ * Handler for settings maintenance IPC channels.
+ */
+export class SettingsIpcHandler extends BaseIpcHandler {
+    /**
+     * Register settings IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.SETTINGS_RESET, (_event, section: SettingsResetSection): SettingsResetResult => {
+            return this._handleReset(section);
+        });
+
+        ipcMain.handle(IPC_CHANNELS.SETTINGS_GET_HISTORY, (): SettingsChange[] => {
+            try {
+                return this.deps.store.getHistory().reverse().map(redactChange);
+            } catch (error) {
+                this.handleError('reading settings history', error);
+                return [];
+            }
+        });
+
+        ipcMain.handle(IPC_CHANNELS.SETTINGS_UNDO, (_event, count: number): SettingsUndoResult => {
+            return this._handleUndo(count);
+        });
+    }
+
+    /**
+     * Unregister settings IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.SETTINGS_RESET);
+        ipcMain.removeHandler(IPC_CHANNELS.SETTINGS_GET_HISTORY);
+        ipcMain.removeHandler(IPC_CHANNELS.SETTINGS_UNDO);
+    }
+
+    /**
+     * Handle settings:reset request.
+     * @param section - Section to reset
+     * @returns Whether the reset succeeded and where the backup was written
+     */
+    private _handleReset(section: SettingsResetSection): SettingsResetResult {
+        if (!SETTINGS_RESET_SECTIONS.includes(section)) {
+            this.logger.warn(`Invalid settings reset section: ${section}`);
+            return { success: false, backupPath: null, error: `Unknown settings section: ${section}` };
+        }
+
+        try {
+            const backupPath = this.deps.store.backup();
+            const keys = this._getSectionKeys(section);
+            const saved = this.deps.store.resetKeys(keys);
+
+            if (section === 'hotkeys' || section === 'all') this._applyHotkeys();
+            if (section === 'appearance' || section === 'all') this._applyAppearance();
+
+            this.logger.log(`Settings reset: ${section} (${keys.length} keys, backup: ${backupPath ?? 'none'})`);
+            return saved
+                ? { success: true, backupPath }
+                : { success: false, backupPath, error: 'Defaults were applied but could not be saved' };
+        } catch (error) {
+            this.logger.error('Error resetting settings:', {
+                error: (error as Error).message,
+                requestedSection: section,
+            });
+            return { success: false, backupPath: null, error: (error as Error).message };
+        }
+    }
+
+    /**
+     * Handle settings:undo request.
+     * Hotkey and appearance settings are applied at once; the rest may need a restart.
+     * @param count - Number of changes to undo
+     * @returns The undone changes and whether they were saved
+     */
+    private _handleUndo(count: number): SettingsUndoResult {
+        if (!Number.isInteger(count) || count < 1) {
+            this.logger.warn(`Invalid settings undo count: ${count}`);
+            return {
+                success: false,
+                undone: [],
+                restartRecommended: false,
+                error: 'Count must be a positive integer',
+            };
+        }
+
+        try {
+            const { undone, saved } = this.deps.store.undo(count);
+            const keys = undone
+                .flatMap((change) => [change, ...(change.batch ?? [])])
+                .map((change) => change.key as PreferenceKey);
+
+            const hotkeys = keys.some((key) => SECTION_KEYS.hotkeys.includes(key));
+            const appearance = keys.some((key) => SECTION_KEYS.appearance.includes(key));
+            if (hotkeys) this._applyHotkeys();
+            if (appearance) this._applyAppearance();
+
+            const restartRecommended = keys.some(
+                (key) => !SECTION_KEYS.hotkeys.includes(key) && !SECTION_KEYS.appearance.includes(key)
+            );
+            const result = { undone: undone.map(redactChange), restartRecommended };
+            return saved
+                ? { success: true, ...result }
+                : { success: false, ...result, error: 'Previous values were applied but could not be saved' };
+        } catch (error) {
+            this.logger.error('Error undoing settings changes:', {
+                error: (error as Error).message,
+                count,
+            });
+            return { success: false, undone: [], restartRecommended: false, error: (error as Error).message };
+        }
+    }
+
+    /**
+     * Get the preference keys covered by a section.
+     */
+    private _getSectionKeys(section: SettingsResetSection): PreferenceKey[] {
+        if (section !== 'all') return SECTION_KEYS[section];
+
+        return (Object.keys(this.deps.store.getAll()) as PreferenceKey[]).filter((key) => !STATE_KEYS.includes(key));
+    }
+
+    /**
+     * Push the (reset) hotkey settings to HotkeyManager and open windows.
+     */
+    private _applyHotkeys(): void {
+        const store = this.deps.store;
+        const individual = {
+            alwaysOnTop: store.get('hotkeyAlwaysOnTop') ?? true,
+            bossKey: store.get('hotkeyBossKey') ?? true,
+            quickChat: store.get('hotkeyQuickChat') ?? true,
+            printToPdf: store.get('hotkeyPrintToPdf') ?? true,
+        };
+        const accelerators = {
+            alwaysOnTop: store.get('acceleratorAlwaysOnTop') ?? DEFAULT_ACCELERATORS.alwaysOnTop,
+            bossKey: store.get('acceleratorBossKey') ?? DEFAULT_ACCELERATORS.bossKey,
+            quickChat: store.get('acceleratorQuickChat') ?? DEFAULT_ACCELERATORS.quickChat,
+            printToPdf: store.get('acceleratorPrintToPdf') ?? DEFAULT_ACCELERATORS.printToPdf,
+        };
+
+        this.deps.hotkeyManager?.updateAllSettings(individual);
+        this.deps.hotkeyManager?.updateAllAccelerators(accelerators);
+        this.publishEvent(IPC_CHANNELS.HOTKEYS_INDIVIDUAL_CHANGED, individual);
+        this.publishEvent(IPC_CHANNELS.HOTKEYS_ACCELERATOR_CHANGED, accelerators);
+    }
+
+    /**
+     * Apply the (reset) appearance settings. Always-on-top and zoom go through
+     * WindowManager, whose change events are persisted and broadcast by their handlers.
+     */
+    private _applyAppearance(): void {
+        const theme = this.deps.store.get('theme') ?? 'system';
+        nativeTheme.themeSource = theme;
+        this.publishEvent(IPC_CHANNELS.THEME_CHANGED, {
+            preference: theme,
+            effectiveTheme: nativeTheme.shouldUseDarkColors ? 'dark' : 'light',
+        });
+
+        this.deps.windowManager.setAlwaysOnTop(this.deps.store.get('alwaysOnTop') ?? false);
+        this.deps.windowManager.setZoomLevel(this.deps.store.get('zoomLevel') ?? 100);
+    }
+}
@@ -0,0 +1,248 @@
+/**
+ * Share IPC Handler.
+ *
+ * Handles IPC channels for posting the latest answer to a chat channel:
+ * - share:preview-request - Redact the latest answer and send it back for review
+ * - share:preview - Redacted answer pushed to the main window (main → renderer)
+ * - share:post - Post the reviewed answer to the configured webhook
+ * - share:settings:get/set - Slack or Teams webhook settings
+ * - share:qr - Show the open conversation (or its link) as a QR code
+ *
+ * @module ipc/ShareIpcHandler
+ */
+
+import { ipcMain, session } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import type { IpcHandlerDependencies, UserPreferences } from './types';
+import QrCodeManager from '../qrCodeManager';
+import { IPC_CHANNELS } from '../../../shared/constants/ipc-channels';
+import { showToast } from '../../utils/toast';
+import { decryptSecret, encryptSecret } from '../../utils/secrets';
+import { QR_CODE_MAX_BYTES } from '../../utils/qrCode';
+import { postToWebhook, redactSecrets, SHARE_SERVICE_LABELS, validateShareSettings } from '../../utils/share';
+import {
+    DEFAULT_SHARE_SETTINGS,
+    type SharePreview,
+    type ShareService,
+    type ShareSettings,
+} from '../../../shared/types/share';
+
+/**
+ * Handler for answer sharing IPC channels.
+ */
+export class ShareIpcHandler extends BaseIpcHandler {
+    private readonly qrCodeManager: QrCodeManager;
+
+    /** Bound listener for share-answer-triggered events (for removal) */
+    private readonly onShareAnswerTriggered = (): void => {
+        this.previewLastAnswer().catch((error) => this.handleError('previewing share (menu)', error));
+    };
+
+    /** Bound listener for share-qr-triggered events (for removal) */
+    private readonly onShareQrTriggered = (): void => {
+        this.shareAsQr().catch((error) => this.handleError('sharing as QR code (menu)', error));
+    };
+
+    /**
+     * @param deps - Handler dependencies
+     * @param qrCodeManager - Optional QR code manager (for testing)
+     */
+    constructor(deps: IpcHandlerDependencies, qrCodeManager?: QrCodeManager) {
+        super(deps);
+        this.qrCodeManager = qrCodeManager ?? new QrCodeManager();
+    }
+
+    /**
+     * Register share IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.on(IPC_CHANNELS.SHARE_PREVIEW_REQUEST, () => {
+            this.previewLastAnswer().catch((error) => this.handleError('previewing share', error));
+        });
+
+        ipcMain.on(IPC_CHANNELS.SHARE_POST, (_event, markdown: unknown) => {
+            this.postAnswer(markdown).catch((error) => this.handleError('posting share', error));
+        });
+
+        ipcMain.handle(IPC_CHANNELS.SHARE_SETTINGS_GET, (): ShareSettings => {
+            return this._getSettings();
+        });
+
+        ipcMain.on(IPC_CHANNELS.SHARE_SETTINGS_SET, (_event, settings: ShareSettings) => {
+            this._handleSetSettings(settings);
+        });
+
+        ipcMain.on(IPC_CHANNELS.SHARE_QR, () => {
+            this.shareAsQr().catch((error) => this.handleError('sharing as QR code', error));
+        });
+
+        // Native application menu
+        this.deps.windowManager.on('share-answer-triggered', this.onShareAnswerTriggered);
+        this.deps.windowManager.on('share-qr-triggered', this.onShareQrTriggered);
+    }
+
+    /**
+     * Unregister share IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeAllListeners(IPC_CHANNELS.SHARE_PREVIEW_REQUEST);
+        ipcMain.removeAllListeners(IPC_CHANNELS.SHARE_POST);
+        ipcMain.removeHandler(IPC_CHANNELS.SHARE_SETTINGS_GET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.SHARE_SETTINGS_SET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.SHARE_QR);
+        this.deps.windowManager.removeListener('share-answer-triggered', this.onShareAnswerTriggered);
+        this.deps.windowManager.removeListener('share-qr-triggered', this.onShareQrTriggered);
+        this.qrCodeManager.close();
+    }
+
+    /**
+     * Redact the latest answer and send it to the main window for review.
+     */
+    async previewLastAnswer(): Promise<void> {
+        if (!this.deps.exportManager) {
+            this.logger.error('ExportManager not initialized');
+            return;
+        }
+
+        const mainWindow = this.deps.windowManager.getMainWindow();
+        if (!mainWindow || mainWindow.isDestroyed()) {
+            this.logger.warn('Cannot share answer: main window not found or destroyed');
+            return;
+        }
+
+        const settings = this._getSettings();
+        const problem = validateShareSettings(settings, true);
+        if (problem) {
+            showToast(mainWindow, { type: 'error', message: `Cannot share: ${problem}` });
+            return;
+        }
+
+        const response = await this.deps.exportManager.getLastResponse(mainWindow.webContents);
+        if (!response) {
+            showToast(mainWindow, { type: 'info', message: 'No answer to share' });
+            return;
+        }
+
+        const { text, count } = redactSecrets(response.markdown);
+        const preview: SharePreview = { service: settings.service, markdown: text, redactions: count };
+        mainWindow.webContents.send(IPC_CHANNELS.SHARE_PREVIEW, preview);
+    }
+
+    /**
+     * Post a reviewed answer. The redaction pass runs again so text edited
+     * in the preview cannot reintroduce a secret.
+     * @param markdown - Answer as confirmed in the preview
+     */
+    async postAnswer(markdown: unknown): Promise<void> {
+        if (typeof markdown !== 'string' || !markdown.trim()) {
+            this.logger.warn(`Invalid share message: ${typeof markdown}`);
+            return;
+        }
+
+        const mainWindow = this.deps.windowManager.getMainWindow();
+        const settings = this._getSettings();
+        const label = SHARE_SERVICE_LABELS[settings.service];
+        const problem = validateShareSettings(settings, true);
+        if (problem) {
+            this.logger.warn(problem);
+            return;
+        }
+
+        try {
+            await postToWebhook(session.defaultSession, settings, redactSecrets(markdown).text);
+            this.logger.log(`Answer posted to ${label}`);
+            if (mainWindow) showToast(mainWindow, { type: 'success', message: `Answer posted to ${label}` });
+        } catch (error) {
+            this.logger.error(`Failed to post answer to ${label}:`, { error: (error as Error).message });
+            if (mainWindow) showToast(mainWindow, { type: 'error', message: `Failed to post answer to ${label}` });
+        }
+    }
+
+    /**
+     * Show the open conversation as a QR code. A conversation too long for a
+     * QR code is shared as its link instead, which opens it on a signed-in phone.
+     */
+    async shareAsQr(): Promise<void> {
+        if (!this.deps.exportManager) {
+            this.logger.error('ExportManager not initialized');
+            return;
+        }
+
+        const mainWindow = this.deps.windowManager.getMainWindow();
+        if (!mainWindow || mainWindow.isDestroyed()) {
+            this.logger.warn('Cannot share as QR code: main window not found or destroyed');
+            return;
+        }
+
+        const markdown = await this.deps.exportManager.getConversationMarkdown(mainWindow.webContents);
+        if (markdown && Buffer.byteLength(markdown, 'utf8') <= QR_CODE_MAX_BYTES) {
+            this.qrCodeManager.show(markdown, 'Conversation as Markdown');
+            return;
+        }
+
+        const location = await this.deps.windowManager.getGeminiLocation();
+        if (!location.conversationId) {
+            showToast(mainWindow, { type: 'info', message: 'No conversation to share' });
+            return;
+        }
+
+        this.qrCodeManager.show(location.url, location.url);
+        if (markdown) {
+            showToast(mainWindow, {
+                type: 'info',
+                message: 'Conversation is too long for a QR code; sharing its link',
+            });
+        }
+    }
+
+    /**
+     * Get the saved share settings.
+     * @returns Share settings with the webhook URL decrypted
+     */
+    private _getSettings(): ShareSettings {
+        try {
+            const store = this.deps.store;
+            return {
+                service: (store.get('shareService') as ShareService) ?? DEFAULT_SHARE_SETTINGS.service,
+                webhookUrl: decryptSecret(store.get('shareWebhookUrl') ?? ''),
+            };
+        } catch (error) {
+            this.logger.error('Error getting share settings:', error);
+            return { ...DEFAULT_SHARE_SETTINGS };
+        }
+    }
+
+    /**
+     * Handle share:settings:set request.
+     * @param settings - New share settings
+     */
+    private _handleSetSettings(settings: ShareSettings): void {
+        try {
+            if (!settings || typeof settings !== 'object') {
+                this.logger.warn(`Invalid share settings: ${settings}`);
+                return;
+            }
+
+            const next: ShareSettings = {
+                service: settings.service,
+                webhookUrl: String(settings.webhookUrl ?? '').trim(),
+            };
+            const problem = validateShareSettings(next);
+            if (problem) {
+                this.logger.warn(problem);
+                return;
+            }
+
+            // The webhook URL is the credential: anyone holding it can post to the channel
+            const values: Partial<UserPreferences> = { shareService: next.service };
+            // Encrypting can give new ciphertext for the same URL; only save it if it changed
+            if (decryptSecret(this.deps.store.get('shareWebhookUrl') ?? '') !== next.webhookUrl) {
+                values.shareWebhookUrl = encryptSecret(next.webhookUrl);
+            }
+            this.deps.store.setMany(values);
+            this.logger.log(`Share settings updated: ${next.service}`);
+        } catch (error) {
+            this.logger.error('Error setting share settings:', { error: (error as Error).message });
+        }
+    }
+}
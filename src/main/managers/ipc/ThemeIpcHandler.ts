@@ -106,7 +106,7 @@ export class ThemeIpcHandler extends BaseIpcHandler {
      * @param effectiveTheme - The resolved effective theme
      */
     private _broadcastThemeChange(preference: ThemePreference, effectiveTheme: 'light' | 'dark'): void {
-        this.broadcastToAllWindows(IPC_CHANNELS.THEME_CHANGED, {
+        this.publishEvent(IPC_CHANNELS.THEME_CHANGED, {
             preference,
             effectiveTheme,
         });
@@ -0,0 +1,152 @@
+/**
+ * Titlebar IPC Handler.
+ *
+ * Handles IPC channels for the titlebar status indicators, height and effect:
+ * - titlebar:get-state - Returns the widgets to show
+ * - titlebar:get-height - Returns the titlebar height
+ * - titlebar:set-height - Sets and persists the titlebar height
+ * - titlebar:get-effect - Returns the titlebar effect and the supported effects
+ * - titlebar:set-effect - Sets and persists the titlebar effect
+ *
+ * Also broadcasts titlebar widget registry changes as
+ * titlebar:state-changed so titlebars update without polling, and
+ * windowManager titlebar-height-changed and titlebar-effect-changed events
+ * as titlebar:height-changed and titlebar:effect-changed.
+ *
+ * @module ipc/TitlebarIpcHandler
+ */
+
+import { ipcMain } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../utils/constants';
+import { getTitlebarState, onTitlebarStateChanged } from '../../utils/titlebarWidgets';
+import {
+    DEFAULT_TITLEBAR_HEIGHT,
+    type TitlebarEffect,
+    type TitlebarEffectState,
+    type TitlebarState,
+} from '../../../shared/types/titlebar';
+
+/**
+ * Handler for titlebar IPC channels.
+ */
+export class TitlebarIpcHandler extends BaseIpcHandler {
+    private unsubscribe: (() => void) | null = null;
+
+    private readonly onTitlebarHeightChanged = (height: number) => {
+        try {
+            this.deps.store.set('titlebarHeight', height);
+            this.publishEvent(IPC_CHANNELS.TITLEBAR_HEIGHT_CHANGED, { height });
+        } catch (error) {
+            this.handleError('saving titlebar height', error);
+        }
+    };
+
+    private readonly onTitlebarEffectChanged = (effect: TitlebarEffect) => {
+        try {
+            this.deps.store.set('titlebarEffect', effect);
+            this.publishEvent(IPC_CHANNELS.TITLEBAR_EFFECT_CHANGED, this.getEffectState());
+        } catch (error) {
+            this.handleError('saving titlebar effect', error);
+        }
+    };
+
+    /**
+     * Register titlebar IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.TITLEBAR_GET_STATE, (): TitlebarState => {
+            try {
+                return getTitlebarState();
+            } catch (error) {
+                this.handleError('getting titlebar state', error);
+                return { widgets: [] };
+            }
+        });
+
+        ipcMain.handle(IPC_CHANNELS.TITLEBAR_GET_HEIGHT, (): number => {
+            try {
+                return this.deps.windowManager.getTitlebarHeight();
+            } catch (error) {
+                this.handleError('getting titlebar height', error);
+                return DEFAULT_TITLEBAR_HEIGHT;
+            }
+        });
+
+        ipcMain.handle(IPC_CHANNELS.TITLEBAR_SET_HEIGHT, (_event, height: number): boolean => {
+            try {
+                return this.deps.windowManager.setTitlebarHeight(height);
+            } catch (error) {
+                this.handleError('setting titlebar height', error);
+                return false;
+            }
+        });
+
+        ipcMain.handle(IPC_CHANNELS.TITLEBAR_GET_EFFECT, (): TitlebarEffectState => {
+            try {
+                return this.getEffectState();
+            } catch (error) {
+                this.handleError('getting titlebar effect', error);
+                return { effect: 'none', supported: ['none'] };
+            }
+        });
+
+        ipcMain.handle(IPC_CHANNELS.TITLEBAR_SET_EFFECT, (_event, effect: TitlebarEffect): boolean => {
+            try {
+                return this.deps.windowManager.setTitlebarEffect(effect);
+            } catch (error) {
+                this.handleError('setting titlebar effect', error);
+                return false;
+            }
+        });
+
+        this.unsubscribe = onTitlebarStateChanged((state) =>
+            this.publishEvent(IPC_CHANNELS.TITLEBAR_STATE_CHANGED, state)
+        );
+        this.deps.windowManager.on('titlebar-height-changed', this.onTitlebarHeightChanged);
+        this.deps.windowManager.on('titlebar-effect-changed', this.onTitlebarEffectChanged);
+    }
+
+    /**
+     * Apply the saved titlebar height and effect.
+     */
+    initialize(): void {
+        try {
+            this.deps.windowManager.setTitlebarHeight(this.deps.store.get('titlebarHeight') ?? DEFAULT_TITLEBAR_HEIGHT);
+        } catch (error) {
+            this.handleError('initializing titlebar height', error);
+        }
+
+        try {
+            const effect = (this.deps.store.get('titlebarEffect') as TitlebarEffect) ?? 'none';
+            this.deps.windowManager.setTitlebarEffect(effect);
+        } catch (error) {
+            this.handleError('initializing titlebar effect', error);
+        }
+    }
+
+    /**
+     * Get the titlebar effect in use and the effects this platform supports.
+     */
+    private getEffectState(): TitlebarEffectState {
+        return {
+            effect: this.deps.windowManager.getTitlebarEffect(),
+            supported: this.deps.windowManager.getSupportedTitlebarEffects(),
+        };
+    }
+
+    /**
+     * Unregister titlebar IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.TITLEBAR_GET_STATE);
+        ipcMain.removeHandler(IPC_CHANNELS.TITLEBAR_GET_HEIGHT);
+        ipcMain.removeHandler(IPC_CHANNELS.TITLEBAR_SET_HEIGHT);
+        ipcMain.removeHandler(IPC_CHANNELS.TITLEBAR_GET_EFFECT);
+        ipcMain.removeHandler(IPC_CHANNELS.TITLEBAR_SET_EFFECT);
+        this.deps.windowManager.removeListener('titlebar-height-changed', this.onTitlebarHeightChanged);
+        this.deps.windowManager.removeListener('titlebar-effect-changed', this.onTitlebarEffectChanged);
+        this.unsubscribe?.();
+        this.unsubscribe = null;
+    }
+}
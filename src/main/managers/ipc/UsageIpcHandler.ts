@@ -0,0 +1,362 @@
+/**
+ * Usage IPC Handler.
+ *
+ * Handles IPC channels for foreground time tracking:
+ * - usage:get-settings - Returns whether tracking and idle detection are on
+ * - usage:set-settings - Updates tracking and/or idle detection
+ * - usage:get-report - Returns active time today, this week and per day
+ * - usage:get-limits - Returns the daily limits and whether they are reached
+ * - usage:set-limits - Updates the daily limits (unless set by policy)
+ * - usage:get-quota - Returns the estimated Gemini prompt quota left today
+ * - usage:set-quota - Updates the Gemini plan used for the estimate
+ *
+ * Broadcasts usage:limit-changed when a daily limit is reached or cleared and
+ * usage:quota-changed when the estimate changes. Tracking runs while it is
+ * enabled or any limit is set. While a Gemini plan is chosen, the prompts left
+ * are shown in the titlebar and the user is warned as the quota runs out.
+ *
+ * @module ipc/UsageIpcHandler
+ */
+
+import { ipcMain } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import type { IpcHandlerDependencies } from './types';
+import { IPC_CHANNELS } from '../../utils/constants';
+import UsageManager, { loadUsagePolicy } from '../usageManager';
+import { getGeminiQuotaStatus, isGeminiQuotaPlan } from '../../utils/geminiQuota';
+import { removeTitlebarWidget, setTitlebarWidget, TITLEBAR_WIDGET_ORDER } from '../../utils/titlebarWidgets';
+import { showToast } from '../../utils/toast';
+import type {
+    GeminiQuotaSettings,
+    GeminiQuotaStatus,
+    UsageLimits,
+    UsageLimitStatus,
+    UsageReport,
+    UsageSettings,
+} from '../../../shared/types/usage';
+
+/** How often the quota estimate is refreshed, so it resets after midnight */
+export const GEMINI_QUOTA_TICK_MS = 60_000;
+
+const QUOTA_WIDGET_TONES = { ok: 'info', warning: 'warning', exhausted: 'error' } as const;
+
+/**
+ * Handler for usage tracking IPC channels.
+ */
+export class UsageIpcHandler extends BaseIpcHandler {
+    private readonly usageManager: UsageManager;
+    private quotaTimer: ReturnType<typeof setInterval> | null = null;
+    private lastQuotaLevel: GeminiQuotaStatus['level'] | null = null;
+
+    private readonly onLimitChanged = (status: UsageLimitStatus): void => {
+        this.publishEvent(IPC_CHANNELS.USAGE_LIMIT_CHANGED, status);
+    };
+
+    /**
+     * @param deps - Handler dependencies
+     * @param usageManager - Optional usage manager (for testing)
+     */
+    constructor(deps: IpcHandlerDependencies, usageManager?: UsageManager) {
+        super(deps);
+        this.usageManager = usageManager ?? new UsageManager();
+    }
+
+    /**
+     * Register usage IPC handlers with ipcMain and start tracking if enabled.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.USAGE_GET_SETTINGS, (): UsageSettings => {
+            return this._getSettings();
+        });
+
+        ipcMain.on(IPC_CHANNELS.USAGE_SET_SETTINGS, (_event, settings: Partial<UsageSettings>) => {
+            this._handleSetSettings(settings);
+        });
+
+        ipcMain.handle(IPC_CHANNELS.USAGE_GET_REPORT, (): UsageReport => {
+            return this.usageManager.getReport();
+        });
+
+        ipcMain.handle(IPC_CHANNELS.USAGE_GET_LIMITS, (): UsageLimitStatus => {
+            return this.usageManager.getLimitStatus();
+        });
+
+        ipcMain.on(IPC_CHANNELS.USAGE_SET_LIMITS, (_event, limits: UsageLimits) => {
+            this._handleSetLimits(limits);
+        });
+
+        ipcMain.handle(IPC_CHANNELS.USAGE_GET_QUOTA, (): GeminiQuotaStatus => {
+            return this._getQuotaStatus();
+        });
+
+        ipcMain.on(IPC_CHANNELS.USAGE_SET_QUOTA, (_event, settings: GeminiQuotaSettings) => {
+            this._handleSetQuota(settings);
+        });
+
+        this.usageManager.on('limit-changed', this.onLimitChanged);
+        this._applyLimits();
+        this._syncTracking();
+        this._syncQuota();
+    }
+
+    /**
+     * Count a completed prompt towards the daily prompt limit and the quota estimate.
+     */
+    recordPrompt(): void {
+        try {
+            const quotaOn = this._getQuotaSettings().plan !== 'off';
+            if (!this.usageManager.hasLimits() && !quotaOn) return;
+
+            this.usageManager.recordPrompt();
+            if (quotaOn) this._updateQuota();
+        } catch (error) {
+            this.handleError('recording prompt', error);
+        }
+    }
+
+    /**
+     * Unregister usage IPC handlers and stop tracking.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.USAGE_GET_SETTINGS);
+        ipcMain.removeAllListeners(IPC_CHANNELS.USAGE_SET_SETTINGS);
+        ipcMain.removeHandler(IPC_CHANNELS.USAGE_GET_REPORT);
+        ipcMain.removeHandler(IPC_CHANNELS.USAGE_GET_LIMITS);
+        ipcMain.removeAllListeners(IPC_CHANNELS.USAGE_SET_LIMITS);
+        ipcMain.removeHandler(IPC_CHANNELS.USAGE_GET_QUOTA);
+        ipcMain.removeAllListeners(IPC_CHANNELS.USAGE_SET_QUOTA);
+        this.usageManager.removeListener('limit-changed', this.onLimitChanged);
+        this.usageManager.stop();
+        this._stopQuotaTimer();
+    }
+
+    /**
+     * Get the current usage tracking settings.
+     * @returns Usage settings
+     */
+    private _getSettings(): UsageSettings {
+        try {
+            return {
+                enabled: this.deps.store.get('usageTrackingEnabled') ?? false,
+                idleDetection: this.deps.store.get('usageIdleDetection') ?? true,
+            };
+        } catch (error) {
+            this.logger.error('Error getting usage settings:', error);
+            return { enabled: false, idleDetection: true };
+        }
+    }
+
+    /**
+     * Handle usage:set-settings request.
+     * @param settings - Partial settings to update
+     */
+    private _handleSetSettings(settings: Partial<UsageSettings>): void {
+        try {
+            if (!settings || typeof settings !== 'object') {
+                this.logger.warn(`Invalid usage settings: ${settings}`);
+                return;
+            }
+
+            for (const [key, value] of Object.entries(settings)) {
+                if (typeof value !== 'boolean') {
+                    this.logger.warn(`Invalid usage setting ${key}: ${value}`);
+                    return;
+                }
+            }
+
+            if (settings.enabled !== undefined) this.deps.store.set('usageTrackingEnabled', settings.enabled);
+            if (settings.idleDetection !== undefined) this.deps.store.set('usageIdleDetection', settings.idleDetection);
+
+            this._syncTracking();
+            this.logger.log('Usage settings updated:', settings);
+        } catch (error) {
+            this.logger.error('Error setting usage settings:', {
+                error: (error as Error).message,
+                requestedSettings: settings,
+            });
+        }
+    }
+
+    /**
+     * Handle usage:set-limits request.
+     * @param limits - New daily limits
+     */
+    private _handleSetLimits(limits: UsageLimits): void {
+        try {
+            if (this.usageManager.getLimitStatus().enforcedByPolicy) {
+                this.logger.warn('Usage limits are set by policy and cannot be changed');
+                return;
+            }
+
+            if (!limits || typeof limits !== 'object') {
+                this.logger.warn(`Invalid usage limits: ${limits}`);
+                return;
+            }
+
+            for (const [key, value] of Object.entries(limits)) {
+                if (typeof value !== 'number' || !Number.isInteger(value) || value < 0) {
+                    this.logger.warn(`Invalid usage limit ${key}: ${value}`);
+                    return;
+                }
+            }
+
+            this.deps.store.set('usageDailyLimitMinutes', limits.dailyMinutes);
+            this.deps.store.set('usagePromptLimit', limits.dailyPrompts);
+            this._applyLimits();
+            this._syncTracking();
+            this.logger.log('Usage limits updated:', limits);
+        } catch (error) {
+            this.logger.error('Error setting usage limits:', {
+                error: (error as Error).message,
+                requestedLimits: limits,
+            });
+        }
+    }
+
+    /**
+     * Apply the policy limits if present, otherwise the user's own.
+     */
+    private _applyLimits(): void {
+        try {
+            const policy = loadUsagePolicy();
+            if (policy) {
+                this.usageManager.setLimits(policy, true);
+                return;
+            }
+
+            this.usageManager.setLimits({
+                dailyMinutes: this.deps.store.get('usageDailyLimitMinutes') ?? 0,
+                dailyPrompts: this.deps.store.get('usagePromptLimit') ?? 0,
+            });
+        } catch (error) {
+            this.handleError('applying usage limits', error);
+        }
+    }
+
+    /**
+     * Get the Gemini plan used for the quota estimate.
+     * @returns Quota settings
+     */
+    private _getQuotaSettings(): GeminiQuotaSettings {
+        const plan = this.deps.store.get('geminiQuotaPlan');
+        return {
+            plan: isGeminiQuotaPlan(plan) ? plan : 'off',
+            customDailyPrompts: this.deps.store.get('geminiQuotaCustomPrompts') ?? 0,
+        };
+    }
+
+    /**
+     * Estimate the Gemini quota left from today's prompts.
+     * @returns Quota estimate
+     */
+    private _getQuotaStatus(): GeminiQuotaStatus {
+        const settings = this._getQuotaSettings();
+        try {
+            return getGeminiQuotaStatus(settings, this.usageManager.getReport().todayPrompts);
+        } catch (error) {
+            this.logger.error('Error getting Gemini quota estimate:', error);
+            return getGeminiQuotaStatus(settings, 0);
+        }
+    }
+
+    /**
+     * Handle usage:set-quota request.
+     * @param settings - New quota settings
+     */
+    private _handleSetQuota(settings: GeminiQuotaSettings): void {
+        try {
+            if (!settings || typeof settings !== 'object' || !isGeminiQuotaPlan(settings.plan)) {
+                this.logger.warn(`Invalid Gemini quota settings: ${JSON.stringify(settings)}`);
+                return;
+            }
+
+            const { customDailyPrompts } = settings;
+            if (!Number.isInteger(customDailyPrompts) || customDailyPrompts < 0) {
+                this.logger.warn(`Invalid custom Gemini quota: ${customDailyPrompts}`);
+                return;
+            }
+
+            this.deps.store.set('geminiQuotaPlan', settings.plan);
+            this.deps.store.set('geminiQuotaCustomPrompts', customDailyPrompts);
+            this.lastQuotaLevel = null;
+            this._syncQuota();
+            this.logger.log('Gemini quota settings updated:', settings);
+        } catch (error) {
+            this.logger.error('Error setting Gemini quota settings:', {
+                error: (error as Error).message,
+                requestedSettings: settings,
+            });
+        }
+    }
+
+    /**
+     * Refresh the estimate while a plan is chosen, and clear it otherwise.
+     */
+    private _syncQuota(): void {
+        this._stopQuotaTimer();
+        if (this._getQuotaSettings().plan !== 'off') {
+            this.quotaTimer = setInterval(() => this._updateQuota(), GEMINI_QUOTA_TICK_MS);
+        }
+        this._updateQuota();
+    }
+
+    /**
+     * Publish the estimate, show it in the titlebar and warn once per level as the quota runs out.
+     */
+    private _updateQuota(): void {
+        const status = this._getQuotaStatus();
+        this.publishEvent(IPC_CHANNELS.USAGE_QUOTA_CHANGED, status);
+
+        if (status.dailyPrompts === 0) {
+            removeTitlebarWidget('gemini-quota');
+            this.lastQuotaLevel = null;
+            return;
+        }
+
+        setTitlebarWidget({
+            id: 'gemini-quota',
+            label: `~${status.remaining} ${status.remaining === 1 ? 'prompt' : 'prompts'} left`,
+            tooltip:
+                `${status.used} of ${status.dailyPrompts} daily Gemini prompts used from this app. ` +
+                'Estimate only: Google does not report quota usage.',
+            tone: QUOTA_WIDGET_TONES[status.level],
+            order: TITLEBAR_WIDGET_ORDER.geminiQuota,
+        });
+
+        const previous = this.lastQuotaLevel;
+        this.lastQuotaLevel = status.level;
+        // Don't warn on startup or after a settings change, only as prompts are sent
+        if (previous === null || status.level === previous || status.level === 'ok') return;
+
+        const mainWindow = this.deps.windowManager.getMainWindow();
+        if (!mainWindow) return;
+        showToast(mainWindow, {
+            type: 'warning',
+            title: status.level === 'exhausted' ? 'Gemini quota likely used up' : 'Gemini quota running low',
+            message:
+                status.level === 'exhausted'
+                    ? `You have sent about ${status.used} prompts today, your plan's estimated daily quota.`
+                    : `About ${status.remaining} of ${status.dailyPrompts} daily prompts left.`,
+        });
+    }
+
+    /**
+     * Stop refreshing the quota estimate.
+     */
+    private _stopQuotaTimer(): void {
+        if (this.quotaTimer) clearInterval(this.quotaTimer);
+        this.quotaTimer = null;
+    }
+
+    /**
+     * Run the tracker while tracking is enabled or a limit needs enforcing.
+     */
+    private _syncTracking(): void {
+        const { enabled, idleDetection } = this._getSettings();
+        if (enabled || this.usageManager.hasLimits()) {
+            this.usageManager.start(idleDetection);
+        } else {
+            this.usageManager.stop();
+        }
+    }
+}
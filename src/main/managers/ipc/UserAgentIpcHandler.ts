@@ -0,0 +1,100 @@
+/**
+ * User-Agent IPC Handler.
+ *
+ * Handles IPC channels for the User-Agent override:
+ * - user-agent:get-settings - Returns the preset and custom string
+ * - user-agent:set-settings - Validates, saves and applies User-Agent settings
+ *
+ * Also applies the saved settings on startup.
+ *
+ * @module ipc/UserAgentIpcHandler
+ */
+
+import { ipcMain, session } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../utils/constants';
+import { applyUserAgent, validateUserAgentSettings } from '../../utils/userAgent';
+import { readUserAgentSettings } from '../../utils/networkSettings';
+import type { UserAgentSettings } from '../../../shared/types/user-agent';
+
+/**
+ * Handler for User-Agent IPC channels.
+ */
+export class UserAgentIpcHandler extends BaseIpcHandler {
+    /**
+     * Register User-Agent IPC handlers with ipcMain and apply the saved settings.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.USER_AGENT_GET_SETTINGS, (): UserAgentSettings => {
+            return this._getSettings();
+        });
+
+        ipcMain.on(IPC_CHANNELS.USER_AGENT_SET_SETTINGS, (_event, settings: UserAgentSettings) => {
+            this._handleSetSettings(settings);
+        });
+
+        this._apply(this._getSettings());
+    }
+
+    /**
+     * Unregister User-Agent IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.USER_AGENT_GET_SETTINGS);
+        ipcMain.removeAllListeners(IPC_CHANNELS.USER_AGENT_SET_SETTINGS);
+    }
+
+    /**
+     * Get the saved User-Agent settings, falling back to defaults for invalid values.
+     * @returns User-Agent settings
+     */
+    private _getSettings(): UserAgentSettings {
+        try {
+            return readUserAgentSettings(this.deps.store);
+        } catch (error) {
+            this.logger.error('Error getting User-Agent settings:', error);
+            return { preset: 'default', custom: '' };
+        }
+    }
+
+    /**
+     * Handle user-agent:set-settings request.
+     * @param settings - New User-Agent settings
+     */
+    private _handleSetSettings(settings: UserAgentSettings): void {
+        try {
+            if (!settings || typeof settings !== 'object') {
+                this.logger.warn(`Invalid User-Agent settings: ${settings}`);
+                return;
+            }
+
+            const next: UserAgentSettings = { preset: settings.preset, custom: (settings.custom ?? '').trim() };
+            const problem = validateUserAgentSettings(next);
+            if (problem) {
+                this.logger.warn(problem);
+                return;
+            }
+
+            this.deps.store.set('userAgentPreset', next.preset);
+            this.deps.store.set('userAgentCustom', next.custom);
+            this._apply(next);
+        } catch (error) {
+            this.logger.error('Error setting User-Agent settings:', {
+                error: (error as Error).message,
+                requestedPreset: settings?.preset,
+            });
+        }
+    }
+
+    /**
+     * Apply User-Agent settings to the default session.
+     */
+    private _apply(settings: UserAgentSettings): void {
+        try {
+            const userAgent = applyUserAgent(session.defaultSession, settings);
+            this.logger.log(`User-Agent (${settings.preset}): ${userAgent}`);
+        } catch (error) {
+            this.handleError('applying User-Agent', error);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+/**
+ * Userscript IPC Handler.
+ *
+ * Runs userscripts from the `scripts` folder in the Gemini page and handles
+ * IPC channels for managing them:
+ * - userscripts:list - List scripts with their metadata and enabled state
+ * - userscripts:set-enabled - Enable or disable a script
+ * - userscripts:open-folder - Open the scripts folder, creating it if needed
+ *
+ * Scripts are matched when the Gemini frame loads and again when Gemini
+ * navigates within the page. They are off until enabled in Options, and none
+ * run in safe mode.
+ *
+ * @module ipc/UserscriptIpcHandler
+ */
+
+import { ipcMain, shell, type BrowserWindow } from 'electron';
+import * as fs from 'fs/promises';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../../shared/constants/ipc-channels';
+import { findGeminiFrame } from '../../utils/geminiFrame';
+import { isSafeMode } from '../../utils/safeMode';
+import {
+    getUserscriptsDir,
+    isValidUserscriptId,
+    loadUserscripts,
+    matchesUrl,
+    parseEnabledUserscripts,
+    wrapUserscript,
+} from '../../utils/userscripts';
+import type { UserscriptInfo } from '../../../shared/types/userscripts';
+
+/**
+ * Handler for userscript IPC channels.
+ */
+export class UserscriptIpcHandler extends BaseIpcHandler {
+    /** Bound listener for gemini-frame-loaded events (for removal) */
+    private readonly onGeminiFrameLoaded = (win?: BrowserWindow): void => {
+        this.runUserscripts(win).catch((error) => this.handleError('running userscripts', error));
+    };
+
+    /** Bound listener for gemini-location-changed events (for removal) */
+    private readonly onGeminiLocationChanged = (): void => {
+        // Gemini navigates without reloading, so a script may now match; each runs once per document
+        this.runUserscripts().catch((error) => this.handleError('running userscripts', error));
+    };
+
+    /**
+     * Register userscript IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.USERSCRIPTS_LIST, () => this.listUserscripts());
+
+        ipcMain.on(IPC_CHANNELS.USERSCRIPTS_SET_ENABLED, (_event, id: unknown, enabled: unknown) => {
+            if (!isValidUserscriptId(id) || typeof enabled !== 'boolean') {
+                this.logger.warn(`Invalid userscript toggle: ${JSON.stringify({ id, enabled })}`);
+                return;
+            }
+            this.setUserscriptEnabled(id, enabled);
+        });
+
+        ipcMain.on(IPC_CHANNELS.USERSCRIPTS_OPEN_FOLDER, () => {
+            this.openUserscriptsFolder().catch((error) => this.handleError('opening userscripts folder', error));
+        });
+
+        this.deps.windowManager.on('gemini-frame-loaded', this.onGeminiFrameLoaded);
+        this.deps.windowManager.on('gemini-location-changed', this.onGeminiLocationChanged);
+    }
+
+    /**
+     * Unregister userscript IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.USERSCRIPTS_LIST);
+        ipcMain.removeAllListeners(IPC_CHANNELS.USERSCRIPTS_SET_ENABLED);
+        ipcMain.removeAllListeners(IPC_CHANNELS.USERSCRIPTS_OPEN_FOLDER);
+        this.deps.windowManager.removeListener('gemini-frame-loaded', this.onGeminiFrameLoaded);
+        this.deps.windowManager.removeListener('gemini-location-changed', this.onGeminiLocationChanged);
+    }
+
+    /**
+     * List the userscripts in the scripts folder.
+     */
+    async listUserscripts(): Promise<UserscriptInfo[]> {
+        try {
+            const enabled = this.getEnabledIds();
+            const scripts = await loadUserscripts();
+            return scripts.map(({ source: _source, ...info }) => ({ ...info, enabled: enabled.includes(info.id) }));
+        } catch (error) {
+            this.handleError('listing userscripts', error);
+            return [];
+        }
+    }
+
+    /**
+     * Enable or disable a userscript. Takes effect the next time Gemini loads or navigates.
+     */
+    setUserscriptEnabled(id: string, enabled: boolean): void {
+        const ids = this.getEnabledIds().filter((existing) => existing !== id);
+        if (enabled) ids.push(id);
+        this.deps.store.set('enabledUserscripts', ids.join('\n'));
+        this.logger.log(`Userscript ${enabled ? 'enabled' : 'disabled'}: ${id}`);
+    }
+
+    /**
+     * Run the enabled userscripts whose @match patterns fit the Gemini frame's URL.
+     * Scripts that already ran in the frame's document are skipped by their wrapper.
+     *
+     * @param mainWindow - Main window whose Gemini frame to run them in; defaults to the focused one
+     */
+    async runUserscripts(mainWindow = this.deps.windowManager.getMainWindow()): Promise<void> {
+        if (isSafeMode()) return;
+
+        const enabled = this.getEnabledIds();
+        if (enabled.length === 0) return;
+
+        const frame = mainWindow && !mainWindow.isDestroyed() ? findGeminiFrame(mainWindow.webContents) : null;
+        if (!frame) return;
+
+        const url = frame.url;
+        const scripts = (await loadUserscripts()).filter(
+            (script) => enabled.includes(script.id) && script.matches.some((pattern) => matchesUrl(pattern, url))
+        );
+
+        for (const script of scripts) {
+            this.logger.log(`Running userscript: ${script.id}`);
+            await frame
+                .executeJavaScript(wrapUserscript(script))
+                .catch((error) => this.handleError('running userscript', error, { id: script.id }));
+        }
+    }
+
+    /**
+     * Open the scripts folder in the file manager, creating it first if needed.
+     */
+    private async openUserscriptsFolder(): Promise<void> {
+        const dir = getUserscriptsDir();
+        await fs.mkdir(dir, { recursive: true });
+        const error = await shell.openPath(dir);
+        if (error) {
+            this.logger.warn(`Failed to open userscripts folder: ${error}`);
+        }
+    }
+
+    private getEnabledIds(): string[] {
+        return parseEnabledUserscripts(this.deps.store.get('enabledUserscripts') ?? '');
+    }
+}
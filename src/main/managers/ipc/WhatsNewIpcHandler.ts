@@ -0,0 +1,76 @@
+/**
+ * What's New IPC Handler.
+ *
+ * Handles IPC channels for release notes shown after an upgrade:
+ * - whats-new:get - Returns changelog entries and required actions since the
+ *   last acknowledged version
+ * - whats-new:acknowledge - Marks the running version's notes as read
+ *
+ * @module ipc/WhatsNewIpcHandler
+ */
+
+import { app, ipcMain } from 'electron';
+import { BaseIpcHandler } from './BaseIpcHandler';
+import { IPC_CHANNELS } from '../../utils/constants';
+import { getWhatsNew } from '../../utils/whatsNew';
+import type { WhatsNew } from '../../../shared/types/whats-new';
+
+/**
+ * Starting version for installs that predate release note tracking.
+ */
+const UNTRACKED_VERSION = '0.0.0';
+
+/**
+ * Handler for what's new IPC channels.
+ */
+export class WhatsNewIpcHandler extends BaseIpcHandler {
+    /**
+     * Register what's new IPC handlers with ipcMain.
+     */
+    register(): void {
+        ipcMain.handle(IPC_CHANNELS.WHATS_NEW_GET, (): WhatsNew => {
+            const currentVersion = app.getVersion();
+            try {
+                return getWhatsNew(this.deps.store.get('whatsNewLastVersion') || null, currentVersion);
+            } catch (error) {
+                this.handleError('getting what is new', error);
+                return { currentVersion, previousVersion: null, entries: [], actions: [] };
+            }
+        });
+
+        ipcMain.on(IPC_CHANNELS.WHATS_NEW_ACKNOWLEDGE, () => {
+            try {
+                const version = app.getVersion();
+                this.deps.store.set('whatsNewLastVersion', version);
+                this.logger.log(`Release notes for ${version} acknowledged`);
+            } catch (error) {
+                this.handleError('acknowledging what is new', error);
+            }
+        });
+    }
+
+    /**
+     * Record a starting version the first time this runs. A fresh install
+     * starts at the running version, so it is not shown notes for earlier
+     * releases. An install from before release notes were tracked (it already
+     * has saved window state) starts at 0.0.0, so every shipped entry is shown.
+     */
+    initialize(): void {
+        try {
+            if (this.deps.store.get('whatsNewLastVersion')) return;
+
+            const upgraded = this.deps.store.get('windowWidth') !== undefined;
+            this.deps.store.set('whatsNewLastVersion', upgraded ? UNTRACKED_VERSION : app.getVersion());
+        } catch (error) {
+            this.handleError('initializing what is new', error);
+        }
+    }
+
+    /**
+     * Unregister what's new IPC handlers.
+     */
+    unregister(): void {
+        ipcMain.removeHandler(IPC_CHANNELS.WHATS_NEW_GET);
+        ipcMain.removeAllListeners(IPC_CHANNELS.WHATS_NEW_ACKNOWLEDGE);
+    }
+}
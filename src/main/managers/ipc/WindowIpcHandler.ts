@@ -2,14 +2,20 @@
  * Window IPC Handler.
  *
  * Handles window control IPC channels for minimize, maximize, close,
- * show, and isMaximized operations.
+ * show, isMaximized, fullscreen, new window and move-to-display operations.
+ *
+ * Also restores the main window's saved size, position and
+ * maximized/fullscreen state, saves windowManager window-state-changed
+ * events, and tells each window when it enters or leaves fullscreen.
  *
  * @module ipc/WindowIpcHandler
  */
 
-import { ipcMain } from 'electron';
+import { ipcMain, type BrowserWindow } from 'electron';
 import { BaseIpcHandler } from './BaseIpcHandler';
 import { IPC_CHANNELS } from '../../utils/constants';
+import { isWindowBounds, type WindowState } from '../../utils/windowState';
+import type { DisplayInfo } from '../../../shared/types/displays';
 
 /**
  * Handler for window control IPC operations.
@@ -20,6 +26,11 @@ import { IPC_CHANNELS } from '../../utils/constants';
  * - `window-close` - Closes the calling window
  * - `window-show` - Restores the window from tray (via windowManager)
  * - `window-is-maximized` - Returns whether the calling window is maximized
+ * - `window-new` - Opens another main window (via windowManager)
+ * - `window-toggle-fullscreen` - Toggles fullscreen on the calling window (via windowManager)
+ * - `window-is-fullscreen` - Returns whether the calling window is fullscreen
+ * - `window-get-displays` - Returns the connected displays, marking the calling window's
+ * - `window-move-to-display` - Moves the calling window to a display (via windowManager)
  */
 export class WindowIpcHandler extends BaseIpcHandler {
     /**
@@ -96,5 +107,116 @@ export class WindowIpcHandler extends BaseIpcHandler {
                 return false;
             }
         });
+
+        // Open another main window
+        ipcMain.on(IPC_CHANNELS.WINDOW_NEW, () => {
+            try {
+                this.deps.windowManager.createAdditionalMainWindow();
+            } catch (error) {
+                this.logger.error('Error opening new window:', error);
+            }
+        });
+
+        // Toggle fullscreen
+        ipcMain.on(IPC_CHANNELS.WINDOW_TOGGLE_FULLSCREEN, (event) => {
+            const win = this.getWindowFromEvent(event);
+            if (!win) return;
+
+            try {
+                this.deps.windowManager.toggleFullScreen(win);
+            } catch (error) {
+                this.handleError('toggling fullscreen', error);
+            }
+        });
+
+        // Check if window is fullscreen
+        ipcMain.handle(IPC_CHANNELS.WINDOW_IS_FULLSCREEN, (event): boolean => {
+            const win = this.getWindowFromEvent(event);
+            if (!win || win.isDestroyed()) return false;
+
+            try {
+                return win.isFullScreen();
+            } catch (error) {
+                this.handleError('checking fullscreen state', error);
+                return false;
+            }
+        });
+
+        // List connected displays
+        ipcMain.handle(IPC_CHANNELS.WINDOW_GET_DISPLAYS, (event): DisplayInfo[] => {
+            try {
+                return this.deps.windowManager.getDisplays(this.getWindowFromEvent(event) ?? undefined);
+            } catch (error) {
+                this.handleError('listing displays', error);
+                return [];
+            }
+        });
+
+        // Move window to another display
+        ipcMain.on(IPC_CHANNELS.WINDOW_MOVE_TO_DISPLAY, (event, index: unknown) => {
+            const win = this.getWindowFromEvent(event);
+            if (!win || !Number.isInteger(index)) return;
+
+            try {
+                this.deps.windowManager.moveToDisplay(index as number, win);
+            } catch (error) {
+                this.handleError('moving window to display', error);
+            }
+        });
+
+        // Persist main window state
+        this.deps.windowManager.on('window-state-changed', (state: WindowState) => {
+            this._handleWindowStateChanged(state);
+        });
+
+        // Tell the window so its titlebar can adapt; fullscreen is per window, so this is not broadcast
+        this.deps.windowManager.on('fullscreen-changed', (enabled: boolean, win: BrowserWindow) => {
+            if (!win.isDestroyed()) {
+                win.webContents.send(IPC_CHANNELS.WINDOW_FULLSCREEN_CHANGED, { enabled });
+            }
+        });
+    }
+
+    /**
+     * Hand the saved main window state to windowManager.
+     * Called during handler initialization, before the main window is created.
+     */
+    initialize(): void {
+        try {
+            const { store } = this.deps;
+            const bounds = {
+                x: store.get('windowX'),
+                y: store.get('windowY'),
+                width: store.get('windowWidth'),
+                height: store.get('windowHeight'),
+            };
+            if (!isWindowBounds(bounds)) return;
+
+            this.deps.windowManager.setSavedWindowState({
+                bounds,
+                isMaximized: store.get('windowMaximized') === true,
+                isFullScreen: store.get('windowFullScreen') === true,
+            });
+        } catch (error) {
+            this.handleError('restoring window state', error);
+        }
+    }
+
+    /**
+     * Save the main window state.
+     * @param state - New window state
+     */
+    private _handleWindowStateChanged(state: WindowState): void {
+        try {
+            const { store } = this.deps;
+            store.set('windowX', state.bounds.x);
+            store.set('windowY', state.bounds.y);
+            store.set('windowWidth', state.bounds.width);
+            store.set('windowHeight', state.bounds.height);
+            store.set('windowMaximized', state.isMaximized);
+            store.set('windowFullScreen', state.isFullScreen);
+        } catch (error) {
+            this.handleError('saving window state', error);
+        }
     }
 }
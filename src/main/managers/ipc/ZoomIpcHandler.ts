@@ -5,6 +5,8 @@
  * - zoom:get-level - Returns current zoom level from windowManager
  * - zoom:zoom-in - Increases zoom level
  * - zoom:zoom-out - Decreases zoom level
+ * - zoom:set-level - Sets a specific zoom level
+ * - zoom:reset - Resets zoom to 100%
  *
  * Also subscribes to windowManager zoom-level-changed events for
  * persistence and broadcasting to all windows.
@@ -42,6 +44,16 @@ export class ZoomIpcHandler extends BaseIpcHandler {
             return this._handleZoomOut();
         });
 
+        // Set zoom level
+        ipcMain.handle(IPC_CHANNELS.ZOOM_SET_LEVEL, (_event, level: unknown): number => {
+            return this._handleSetZoomLevel(level);
+        });
+
+        // Reset zoom
+        ipcMain.handle(IPC_CHANNELS.ZOOM_RESET, (): number => {
+            return this._handleResetZoom();
+        });
+
         // Subscribe to windowManager zoom level changes
         this.deps.windowManager.on('zoom-level-changed', (level: number) => {
             this._handleZoomLevelChanged(level);
@@ -107,6 +119,40 @@ export class ZoomIpcHandler extends BaseIpcHandler {
         }
     }
 
+    /**
+     * Handle zoom:set-level request.
+     * @param level - Requested zoom level percentage
+     * @returns Applied zoom level (snapped to the nearest step)
+     */
+    private _handleSetZoomLevel(level: unknown): number {
+        try {
+            if (typeof level !== 'number' || !Number.isFinite(level)) {
+                this.logger.warn(`Invalid zoom level: ${level}`);
+                return this.deps.windowManager.getZoomLevel();
+            }
+
+            this.deps.windowManager.setZoomLevel(level);
+            return this.deps.windowManager.getZoomLevel();
+        } catch (error) {
+            this.logger.error('Error setting zoom level:', error);
+            return this.deps.windowManager.getZoomLevel();
+        }
+    }
+
+    /**
+     * Handle zoom:reset request.
+     * @returns New zoom level (100)
+     */
+    private _handleResetZoom(): number {
+        try {
+            this.deps.windowManager.resetZoom();
+            return this.deps.windowManager.getZoomLevel();
+        } catch (error) {
+            this.logger.error('Error resetting zoom:', error);
+            return this.deps.windowManager.getZoomLevel();
+        }
+    }
+
     /**
      * Handle zoom level changes from WindowManager.
      * Persists the zoom level to store and broadcasts to all windows.
@@ -118,7 +164,7 @@ export class ZoomIpcHandler extends BaseIpcHandler {
             this.deps.store.set('zoomLevel', level);
 
             // Broadcast to all windows
-            this.broadcastToAllWindows(IPC_CHANNELS.ZOOM_LEVEL_CHANGED, level);
+            this.publishEvent(IPC_CHANNELS.ZOOM_LEVEL_CHANGED, level);
 
             this.logger.log(`Zoom level changed to: ${level}% (persisted and broadcast)`);
         } catch (error) {
@@ -18,6 +18,17 @@ export { WindowIpcHandler } from './WindowIpcHandler';
 export { ThemeIpcHandler } from './ThemeIpcHandler';
 export { ZoomIpcHandler } from './ZoomIpcHandler';
 export { AlwaysOnTopIpcHandler } from './AlwaysOnTopIpcHandler';
+export { ImmersiveModeIpcHandler } from './ImmersiveModeIpcHandler';
+export { CompactModeIpcHandler } from './CompactModeIpcHandler';
+export { OverlayIpcHandler } from './OverlayIpcHandler';
+export { CloseToTrayIpcHandler } from './CloseToTrayIpcHandler';
+export { CapabilitiesIpcHandler } from './CapabilitiesIpcHandler';
+export { WhatsNewIpcHandler } from './WhatsNewIpcHandler';
+export { DiskSpaceIpcHandler } from './DiskSpaceIpcHandler';
+export { FocusSessionIpcHandler } from './FocusSessionIpcHandler';
+export { LanguageIpcHandler } from './LanguageIpcHandler';
+export { TitlebarIpcHandler } from './TitlebarIpcHandler';
+export { EventsIpcHandler } from './EventsIpcHandler';
 export { HotkeyIpcHandler } from './HotkeyIpcHandler';
 export { AppIpcHandler } from './AppIpcHandler';
 export { AutoUpdateIpcHandler } from './AutoUpdateIpcHandler';
@@ -25,3 +36,15 @@ export { QuickChatIpcHandler } from './QuickChatIpcHandler';
 export { TextPredictionIpcHandler } from './TextPredictionIpcHandler';
 export { ResponseNotificationIpcHandler } from './ResponseNotificationIpcHandler';
 export { ExportIpcHandler } from './ExportIpcHandler';
+export { AutoCopyIpcHandler } from './AutoCopyIpcHandler';
+export { CardIpcHandler } from './CardIpcHandler';
+export { LogIpcHandler } from './LogIpcHandler';
+export { SettingsIpcHandler } from './SettingsIpcHandler';
+export { ProxyIpcHandler } from './ProxyIpcHandler';
+export { UsageIpcHandler } from './UsageIpcHandler';
+export { UserAgentIpcHandler } from './UserAgentIpcHandler';
+export { GeminiViewIpcHandler } from './GeminiViewIpcHandler';
+export { ShareIpcHandler } from './ShareIpcHandler';
+export { IssueIpcHandler } from './IssueIpcHandler';
+export { ScreenshotIpcHandler } from './ScreenshotIpcHandler';
+export { UserscriptIpcHandler } from './UserscriptIpcHandler';
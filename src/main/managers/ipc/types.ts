@@ -41,6 +41,79 @@ export interface UserPreferences extends Record<string, unknown> {
     zoomLevel: number;
     // Response notification settings
     responseNotificationsEnabled: boolean;
+    // Export settings
+    exportFilenameTemplate: string;
+    confluenceBaseUrl: string;
+    confluenceEmail: string;
+    confluenceApiToken: string;
+    confluenceSpaceKey: string;
+    confluenceParentId: string;
+    // Answer sharing (webhook URL stored encrypted when the OS keychain is available)
+    shareService: string;
+    shareWebhookUrl: string;
+    // Issue creation (tokens stored encrypted when the OS keychain is available)
+    issueTracker: string;
+    githubRepository: string;
+    githubToken: string;
+    jiraBaseUrl: string;
+    jiraEmail: string;
+    jiraApiToken: string;
+    jiraProjectKey: string;
+    // Userscripts (enabled script file names, one per line)
+    enabledUserscripts: string;
+    // Quick Chat translate settings
+    translateTargetLanguage: string;
+    // Answer auto-copy settings
+    autoCopyMode: string;
+    autoCopyFormat: string;
+    // Upstream proxy settings
+    proxyMode: string;
+    proxyHost: string;
+    proxyPort: number;
+    proxyUsername: string;
+    proxyPassword: string;
+    proxyBypass: string;
+    // Usage tracking settings
+    usageTrackingEnabled: boolean;
+    usageIdleDetection: boolean;
+    usageDailyLimitMinutes: number;
+    usagePromptLimit: number;
+    // Gemini plan for the daily quota estimate (one of GEMINI_QUOTA_PLANS) and the 'custom' plan's prompts
+    geminiQuotaPlan: string;
+    geminiQuotaCustomPrompts: number;
+    // User-Agent override settings
+    userAgentPreset: string;
+    userAgentCustom: string;
+    // Gemini language override ('' follows the system locale)
+    geminiLanguage: string;
+    // Spell checker for the prompt box, and the languages it checks (one tag per line; empty follows geminiLanguage)
+    spellCheckEnabled: boolean;
+    spellCheckLanguages: string;
+    // Extra hosts the Gemini view may navigate to, one per line
+    navigationAllowList: string;
+    // Hide Gemini's sidebar while a window is in compact mode
+    compactModeHideSidebar: boolean;
+    // Hide the main window to the tray when it is closed, instead of closing it
+    closeToTray: boolean;
+    // Opacity of the main windows (MIN_WINDOW_OPACITY-1)
+    windowOpacity: number;
+    // Last version whose release notes the user acknowledged ('' until first launch)
+    whatsNewLastVersion: string;
+    // Height of the main window titlebar (MIN_TITLEBAR_HEIGHT-MAX_TITLEBAR_HEIGHT logical pixels)
+    titlebarHeight: number;
+    // Native material behind the titlebar (one of TITLEBAR_EFFECTS; unsupported effects fall back to 'none')
+    titlebarEffect: string;
+    // Free space (MB) below which exports and downloads are refused; 0 turns the guard off
+    lowDiskSpaceThresholdMb: number;
+    // Length of a focus session started from the menu (MIN_FOCUS_SESSION_MINUTES-MAX_FOCUS_SESSION_MINUTES)
+    focusSessionMinutes: number;
+    // Main window state, saved as it changes (unset until the window first moves or resizes)
+    windowX?: number;
+    windowY?: number;
+    windowWidth?: number;
+    windowHeight?: number;
+    windowMaximized?: boolean;
+    windowFullScreen?: boolean;
 }
 
 /**
@@ -25,10 +25,60 @@ import {
     TextPredictionIpcHandler,
     ResponseNotificationIpcHandler,
     ExportIpcHandler,
+    AutoCopyIpcHandler,
+    CardIpcHandler,
+    LogIpcHandler,
+    SettingsIpcHandler,
+    ProxyIpcHandler,
+    UsageIpcHandler,
+    UserAgentIpcHandler,
+    GeminiViewIpcHandler,
+    ShareIpcHandler,
+    IssueIpcHandler,
+    ScreenshotIpcHandler,
+    UserscriptIpcHandler,
+    ImmersiveModeIpcHandler,
+    CompactModeIpcHandler,
+    OverlayIpcHandler,
+    CloseToTrayIpcHandler,
+    CapabilitiesIpcHandler,
+    WhatsNewIpcHandler,
+    DiskSpaceIpcHandler,
+    FocusSessionIpcHandler,
+    LanguageIpcHandler,
+    TitlebarIpcHandler,
+    EventsIpcHandler,
     IpcHandlerDependencies,
 } from './ipc/index';
 import SettingsStore from '../store';
 import { createLogger } from '../utils/logger';
+import { DEFAULT_EXPORT_FILENAME_TEMPLATE } from '../utils/exportFilename';
+import { DEFAULT_ALLOWED_HOSTS } from '../utils/allowedHosts';
+import { DEFAULT_TRANSLATE_LANGUAGE } from '../../shared/types/translation';
+import { SETTINGS_HISTORY_LIMIT } from '../../shared/types/settings-history';
+import { MIN_WINDOW_OPACITY } from '../../shared/types/overlay';
+import {
+    DEFAULT_TITLEBAR_HEIGHT,
+    MAX_TITLEBAR_HEIGHT,
+    MIN_TITLEBAR_HEIGHT,
+    TITLEBAR_EFFECTS,
+} from '../../shared/types/titlebar';
+import { DEFAULT_LOW_DISK_SPACE_MB } from '../../shared/types/disk-space';
+import {
+    DEFAULT_FOCUS_SESSION_MINUTES,
+    MAX_FOCUS_SESSION_MINUTES,
+    MIN_FOCUS_SESSION_MINUTES,
+} from '../../shared/types/focus-session';
+import { AUTO_COPY_MODES, AUTO_COPY_FORMATS } from '../../shared/types/auto-copy';
+import { DEFAULT_PROXY_SETTINGS, PROXY_MODES } from '../../shared/types/proxy';
+import { DEFAULT_CONFLUENCE_SETTINGS } from '../../shared/types/confluence';
+import { DEFAULT_SHARE_SETTINGS, SHARE_SERVICES } from '../../shared/types/share';
+import { DEFAULT_ISSUE_TRACKER_SETTINGS, ISSUE_TRACKERS } from '../../shared/types/issues';
+import { USER_AGENT_PRESETS } from '../../shared/types/user-agent';
+import { GEMINI_QUOTA_PLANS } from '../../shared/types/usage';
+import { ZOOM_LEVEL_STEPS } from './windowManager';
+import type { SettingsSchema } from '../utils/settingsSchema';
+import { ENV_OVERRIDE_PREFIX } from '../utils/envOverrides';
 import type WindowManager from './windowManager';
 import type HotkeyManager from './hotkeyManager';
 import type UpdateManager from './updateManager';
@@ -65,8 +115,155 @@ interface UserPreferences extends Record<string, unknown> {
     zoomLevel: number;
     // Response notification settings
     responseNotificationsEnabled: boolean;
+    // Export settings
+    exportFilenameTemplate: string;
+    confluenceBaseUrl: string;
+    confluenceEmail: string;
+    confluenceApiToken: string;
+    confluenceSpaceKey: string;
+    confluenceParentId: string;
+    // Answer sharing (webhook URL stored encrypted when the OS keychain is available)
+    shareService: string;
+    shareWebhookUrl: string;
+    // Issue creation (tokens stored encrypted when the OS keychain is available)
+    issueTracker: string;
+    githubRepository: string;
+    githubToken: string;
+    jiraBaseUrl: string;
+    jiraEmail: string;
+    jiraApiToken: string;
+    jiraProjectKey: string;
+    // Userscripts (enabled script file names, one per line)
+    enabledUserscripts: string;
+    // Quick Chat translate settings
+    translateTargetLanguage: string;
+    // Answer auto-copy settings
+    autoCopyMode: string;
+    autoCopyFormat: string;
+    // Upstream proxy settings
+    proxyMode: string;
+    proxyHost: string;
+    proxyPort: number;
+    proxyUsername: string;
+    proxyPassword: string;
+    proxyBypass: string;
+    // Usage tracking settings
+    usageTrackingEnabled: boolean;
+    usageIdleDetection: boolean;
+    usageDailyLimitMinutes: number;
+    usagePromptLimit: number;
+    // Gemini plan for the daily quota estimate (one of GEMINI_QUOTA_PLANS) and the 'custom' plan's prompts
+    geminiQuotaPlan: string;
+    geminiQuotaCustomPrompts: number;
+    // User-Agent override settings
+    userAgentPreset: string;
+    userAgentCustom: string;
+    // Gemini language override ('' follows the system locale)
+    geminiLanguage: string;
+    // Spell checker for the prompt box, and the languages it checks (one tag per line; empty follows geminiLanguage)
+    spellCheckEnabled: boolean;
+    spellCheckLanguages: string;
+    // Extra hosts the Gemini view may navigate to, one per line
+    navigationAllowList: string;
+    // Hide Gemini's sidebar while a window is in compact mode
+    compactModeHideSidebar: boolean;
+    // Hide the main window to the tray when it is closed, instead of closing it
+    closeToTray: boolean;
+    // Opacity of the main windows (MIN_WINDOW_OPACITY-1)
+    windowOpacity: number;
+    // Last version whose release notes the user acknowledged ('' until first launch)
+    whatsNewLastVersion: string;
+    // Height of the main window titlebar (MIN_TITLEBAR_HEIGHT-MAX_TITLEBAR_HEIGHT logical pixels)
+    titlebarHeight: number;
+    // Native material behind the titlebar (one of TITLEBAR_EFFECTS; unsupported effects fall back to 'none')
+    titlebarEffect: string;
+    // Free space (MB) below which exports and downloads are refused; 0 turns the guard off
+    lowDiskSpaceThresholdMb: number;
+    // Length of a focus session started from the menu (MIN_FOCUS_SESSION_MINUTES-MAX_FOCUS_SESSION_MINUTES)
+    focusSessionMinutes: number;
+    // Main window state, saved as it changes (unset until the window first moves or resizes)
+    windowX?: number;
+    windowY?: number;
+    windowWidth?: number;
+    windowHeight?: number;
+    windowMaximized?: boolean;
+    windowFullScreen?: boolean;
 }
 
+/**
+ * Schema the user preferences file is validated against on load.
+ */
+export const USER_PREFERENCES_SCHEMA: SettingsSchema = {
+    theme: { type: 'string', enum: ['light', 'dark', 'system'] },
+    alwaysOnTop: { type: 'boolean' },
+    hotkeyAlwaysOnTop: { type: 'boolean' },
+    hotkeyBossKey: { type: 'boolean' },
+    hotkeyQuickChat: { type: 'boolean' },
+    hotkeyPrintToPdf: { type: 'boolean' },
+    acceleratorAlwaysOnTop: { type: 'string' },
+    acceleratorBossKey: { type: 'string' },
+    acceleratorQuickChat: { type: 'string' },
+    acceleratorPrintToPdf: { type: 'string' },
+    autoUpdateEnabled: { type: 'boolean' },
+    textPredictionEnabled: { type: 'boolean' },
+    textPredictionGpuEnabled: { type: 'boolean' },
+    textPredictionModelStatus: { type: 'string' },
+    textPredictionModelId: { type: 'string' },
+    zoomLevel: { type: 'number', min: ZOOM_LEVEL_STEPS[0], max: ZOOM_LEVEL_STEPS[ZOOM_LEVEL_STEPS.length - 1] },
+    responseNotificationsEnabled: { type: 'boolean' },
+    exportFilenameTemplate: { type: 'string' },
+    confluenceBaseUrl: { type: 'string' },
+    confluenceEmail: { type: 'string' },
+    confluenceApiToken: { type: 'string' },
+    confluenceSpaceKey: { type: 'string' },
+    confluenceParentId: { type: 'string' },
+    shareService: { type: 'string', enum: SHARE_SERVICES },
+    shareWebhookUrl: { type: 'string' },
+    issueTracker: { type: 'string', enum: ISSUE_TRACKERS },
+    githubRepository: { type: 'string' },
+    githubToken: { type: 'string' },
+    jiraBaseUrl: { type: 'string' },
+    jiraEmail: { type: 'string' },
+    jiraApiToken: { type: 'string' },
+    jiraProjectKey: { type: 'string' },
+    enabledUserscripts: { type: 'string' },
+    translateTargetLanguage: { type: 'string' },
+    autoCopyMode: { type: 'string', enum: AUTO_COPY_MODES },
+    autoCopyFormat: { type: 'string', enum: AUTO_COPY_FORMATS },
+    proxyMode: { type: 'string', enum: PROXY_MODES },
+    proxyHost: { type: 'string' },
+    proxyPort: { type: 'number', min: 1, max: 65535 },
+    proxyUsername: { type: 'string' },
+    proxyPassword: { type: 'string' },
+    proxyBypass: { type: 'string' },
+    usageTrackingEnabled: { type: 'boolean' },
+    usageIdleDetection: { type: 'boolean' },
+    usageDailyLimitMinutes: { type: 'number', min: 0 },
+    usagePromptLimit: { type: 'number', min: 0 },
+    geminiQuotaPlan: { type: 'string', enum: GEMINI_QUOTA_PLANS },
+    geminiQuotaCustomPrompts: { type: 'number', min: 0 },
+    userAgentPreset: { type: 'string', enum: USER_AGENT_PRESETS },
+    userAgentCustom: { type: 'string' },
+    geminiLanguage: { type: 'string' },
+    spellCheckEnabled: { type: 'boolean' },
+    spellCheckLanguages: { type: 'string' },
+    navigationAllowList: { type: 'string' },
+    compactModeHideSidebar: { type: 'boolean' },
+    closeToTray: { type: 'boolean' },
+    windowOpacity: { type: 'number', min: MIN_WINDOW_OPACITY, max: 1 },
+    whatsNewLastVersion: { type: 'string' },
+    titlebarHeight: { type: 'number', min: MIN_TITLEBAR_HEIGHT, max: MAX_TITLEBAR_HEIGHT },
+    titlebarEffect: { type: 'string', enum: TITLEBAR_EFFECTS },
+    lowDiskSpaceThresholdMb: { type: 'number', min: 0 },
+    focusSessionMinutes: { type: 'number', min: MIN_FOCUS_SESSION_MINUTES, max: MAX_FOCUS_SESSION_MINUTES },
+    windowX: { type: 'number' },
+    windowY: { type: 'number' },
+    windowWidth: { type: 'number', min: 1 },
+    windowHeight: { type: 'number', min: 1 },
+    windowMaximized: { type: 'boolean' },
+    windowFullScreen: { type: 'boolean' },
+};
+
 /**
  * Manages IPC communication between main and renderer processes.
  * Orchestrates domain-specific handlers for all IPC channels.
@@ -75,6 +272,9 @@ export default class IpcManager {
     private readonly handlers: BaseIpcHandler[] = [];
     private readonly textPredictionHandler: TextPredictionIpcHandler;
     private readonly responseNotificationHandler: ResponseNotificationIpcHandler;
+    private readonly autoCopyHandler: AutoCopyIpcHandler;
+    private readonly usageHandler: UsageIpcHandler;
+    private readonly focusSessionHandler: FocusSessionIpcHandler;
     private readonly logger: Logger;
     /** Settings store exposed for integration tests */
     public readonly store: SettingsStore<UserPreferences>;
@@ -100,7 +300,7 @@ export default class IpcManager {
         store?: SettingsStore<UserPreferences>,
         logger?: Logger
     ) {
-        /* v8 ignore next 16 -- production fallback, tests always inject dependencies */
+        /* v8 ignore next 72 -- production fallback, tests always inject dependencies */
         const actualStore =
             store ||
             new SettingsStore<UserPreferences>({
@@ -119,6 +319,72 @@ export default class IpcManager {
                     textPredictionModelId: 'qwen3-0.6b',
                     zoomLevel: 100,
                     responseNotificationsEnabled: true,
+                    exportFilenameTemplate: DEFAULT_EXPORT_FILENAME_TEMPLATE,
+                    confluenceBaseUrl: DEFAULT_CONFLUENCE_SETTINGS.baseUrl,
+                    confluenceEmail: DEFAULT_CONFLUENCE_SETTINGS.email,
+                    confluenceApiToken: DEFAULT_CONFLUENCE_SETTINGS.apiToken,
+                    confluenceSpaceKey: DEFAULT_CONFLUENCE_SETTINGS.spaceKey,
+                    confluenceParentId: DEFAULT_CONFLUENCE_SETTINGS.parentId,
+                    shareService: DEFAULT_SHARE_SETTINGS.service,
+                    shareWebhookUrl: DEFAULT_SHARE_SETTINGS.webhookUrl,
+                    issueTracker: DEFAULT_ISSUE_TRACKER_SETTINGS.tracker,
+                    githubRepository: DEFAULT_ISSUE_TRACKER_SETTINGS.githubRepository,
+                    githubToken: DEFAULT_ISSUE_TRACKER_SETTINGS.githubToken,
+                    jiraBaseUrl: DEFAULT_ISSUE_TRACKER_SETTINGS.jiraBaseUrl,
+                    jiraEmail: DEFAULT_ISSUE_TRACKER_SETTINGS.jiraEmail,
+                    jiraApiToken: DEFAULT_ISSUE_TRACKER_SETTINGS.jiraApiToken,
+                    jiraProjectKey: DEFAULT_ISSUE_TRACKER_SETTINGS.jiraProjectKey,
+                    enabledUserscripts: '',
+                    translateTargetLanguage: DEFAULT_TRANSLATE_LANGUAGE,
+                    autoCopyMode: 'off',
+                    autoCopyFormat: 'markdown',
+                    proxyMode: DEFAULT_PROXY_SETTINGS.mode,
+                    proxyHost: DEFAULT_PROXY_SETTINGS.host,
+                    proxyPort: DEFAULT_PROXY_SETTINGS.port,
+                    proxyUsername: DEFAULT_PROXY_SETTINGS.username,
+                    proxyPassword: DEFAULT_PROXY_SETTINGS.password,
+                    proxyBypass: DEFAULT_PROXY_SETTINGS.bypass,
+                    usageTrackingEnabled: false,
+                    usageIdleDetection: true,
+                    usageDailyLimitMinutes: 0,
+                    usagePromptLimit: 0,
+                    geminiQuotaPlan: 'off',
+                    geminiQuotaCustomPrompts: 0,
+                    userAgentPreset: 'default',
+                    userAgentCustom: '',
+                    geminiLanguage: '',
+                    spellCheckEnabled: true,
+                    spellCheckLanguages: '',
+                    navigationAllowList: DEFAULT_ALLOWED_HOSTS,
+                    compactModeHideSidebar: true,
+                    closeToTray: true,
+                    windowOpacity: 1,
+                    whatsNewLastVersion: '',
+                    titlebarHeight: DEFAULT_TITLEBAR_HEIGHT,
+                    titlebarEffect: 'none',
+                    lowDiskSpaceThresholdMb: DEFAULT_LOW_DISK_SPACE_MB,
+                    focusSessionMinutes: DEFAULT_FOCUS_SESSION_MINUTES,
+                },
+                schema: USER_PREFERENCES_SCHEMA,
+                envPrefix: ENV_OVERRIDE_PREFIX,
+                history: {
+                    limit: SETTINGS_HISTORY_LIMIT,
+                    excludeKeys: [
+                        'windowX',
+                        'windowY',
+                        'windowWidth',
+                        'windowHeight',
+                        'windowMaximized',
+                        'windowFullScreen',
+                        'textPredictionModelStatus',
+                        'whatsNewLastVersion',
+                        // Secrets: the log is plain JSON and would keep old tokens after they are rotated
+                        'githubToken',
+                        'jiraApiToken',
+                        'confluenceApiToken',
+                        'proxyPassword',
+                        'shareWebhookUrl',
+                    ],
                 },
             });
         /* v8 ignore next -- production fallback, tests always inject logger */
@@ -143,6 +409,15 @@ export default class IpcManager {
         // Create ResponseNotificationIpcHandler (we need reference for setNotificationManager)
         this.responseNotificationHandler = new ResponseNotificationIpcHandler(handlerDeps);
 
+        // Create AutoCopyIpcHandler (we need reference for onResponseComplete)
+        this.autoCopyHandler = new AutoCopyIpcHandler(handlerDeps);
+
+        // Create UsageIpcHandler (we need reference for onResponseComplete)
+        this.usageHandler = new UsageIpcHandler(handlerDeps);
+
+        // Create FocusSessionIpcHandler (we need reference for onResponseComplete)
+        this.focusSessionHandler = new FocusSessionIpcHandler(handlerDeps);
+
         // Instantiate all handlers
         this.handlers = [
             // Phase 1 handlers
@@ -163,6 +438,52 @@ export default class IpcManager {
             this.responseNotificationHandler,
             // Export handler
             new ExportIpcHandler(handlerDeps),
+            // Answer auto-copy handler
+            this.autoCopyHandler,
+            // Pinned answer cards handler
+            new CardIpcHandler(handlerDeps),
+            // Runtime log level handler
+            new LogIpcHandler(handlerDeps),
+            // Settings reset handler
+            new SettingsIpcHandler(handlerDeps),
+            // Upstream proxy handler
+            new ProxyIpcHandler(handlerDeps),
+            // Usage time tracking and limits handler
+            this.usageHandler,
+            // User-Agent override handler
+            new UserAgentIpcHandler(handlerDeps),
+            // Gemini view reload handler
+            new GeminiViewIpcHandler(handlerDeps),
+            // Answer sharing handler
+            new ShareIpcHandler(handlerDeps),
+            // Issue creation handler
+            new IssueIpcHandler(handlerDeps),
+            // Screenshot capture handler
+            new ScreenshotIpcHandler(handlerDeps),
+            // Userscripts handler
+            new UserscriptIpcHandler(handlerDeps),
+            // Immersive mode handler
+            new ImmersiveModeIpcHandler(handlerDeps),
+            // Compact mode handler
+            new CompactModeIpcHandler(handlerDeps),
+            // Window opacity and overlay mode handler
+            new OverlayIpcHandler(handlerDeps),
+            // Close to tray setting handler
+            new CloseToTrayIpcHandler(handlerDeps),
+            // Capability report handler
+            new CapabilitiesIpcHandler(handlerDeps),
+            // Release notes after an upgrade
+            new WhatsNewIpcHandler(handlerDeps),
+            // Low disk space guard
+            new DiskSpaceIpcHandler(handlerDeps),
+            // Focus session timer and log
+            this.focusSessionHandler,
+            // Gemini language override handler
+            new LanguageIpcHandler(handlerDeps),
+            // Titlebar status indicator and height handler
+            new TitlebarIpcHandler(handlerDeps),
+            // App event replay handler
+            new EventsIpcHandler(handlerDeps),
         ];
 
         this.logger.log('Initialized');
@@ -207,6 +528,17 @@ export default class IpcManager {
         this.logger.log(`NotificationManager ${manager ? 'injected' : 'cleared'}`);
     }
 
+    /**
+     * Notify handlers that a Gemini response has completed.
+     * Counts the prompt towards usage limits and the quota estimate, logs it in a running focus session
+     * and delegates to AutoCopyIpcHandler.onResponseComplete().
+     */
+    async onResponseComplete(): Promise<void> {
+        this.usageHandler.recordPrompt();
+        await this.focusSessionHandler.onResponseComplete();
+        await this.autoCopyHandler.onResponseComplete();
+    }
+
     /**
      * Clean up all IPC handlers.
      * Calls unregister() on all handlers that implement it.
@@ -14,6 +14,7 @@ import { existsSync } from 'fs';
 // Types are imported for TypeScript but the actual module is loaded dynamically
 import type { Llama, LlamaModel, LlamaContext, LlamaCompletion } from 'node-llama-cpp';
 import { createLogger } from '../utils/logger';
+import { ensureDiskSpace } from '../utils/diskSpace';
 
 const logger = createLogger('[LlmManager]');
 
@@ -217,7 +218,7 @@ export default class LlmManager {
      *
      * @param onProgress - Callback for progress updates (0-100)
      * @param modelId - Model to download (defaults to current model)
-     * @throws Error if download fails
+     * @throws Error if download fails, or DiskFullError if the model would leave too little free space
      */
     async downloadModel(onProgress?: DownloadProgressCallback, modelId: string = this.currentModelId): Promise<void> {
         // Guard against concurrent downloads
@@ -246,6 +247,9 @@ export default class LlmManager {
         this.abortController = new AbortController();
 
         try {
+            // Fail with a clear message instead of an IO error partway through the download
+            await ensureDiskSpace(app.getPath('userData'), config.sizeBytes);
+
             // Dynamic import for ESM module using helper to prevent transpilation
             const { createModelDownloader } = await importNodeLlamaCpp();
 
@@ -3,6 +3,7 @@ import WindowManager from './windowManager';
 import type HotkeyManager from './hotkeyManager';
 import { GOOGLE_SIGNIN_URL, GITHUB_ISSUES_URL } from '../utils/constants';
 import { isApplicationHotkey, type HotkeyId } from '../types';
+import { OVERLAY_MODE_ACCELERATOR } from '../../shared/types/overlay';
 
 // Runtime platform check (evaluated on each call for testability)
 const isMac = () => process.platform === 'darwin';
@@ -84,6 +85,7 @@ export default class MenuManager {
     /**
      * Sets up context menu for all web contents.
      * Pre-builds the menu for faster display and updates enabled states dynamically.
+     * Right-clicking a misspelled word shows its spelling suggestions above the edit items.
      */
     setupContextMenu(): void {
         // Pre-build the context menu once
@@ -93,18 +95,84 @@ export default class MenuManager {
             contents.on('context-menu', (_, params) => {
                 // Update enabled states based on current context
                 this.updateContextMenuState(params);
+
+                if (params.misspelledWord) {
+                    Menu.buildFromTemplate(this.buildSpellingMenuTemplate(contents, params)).popup();
+                    return;
+                }
+
                 // Show the pre-built menu
                 this.cachedContextMenu?.popup();
             });
         });
     }
 
+    /**
+     * Builds the context menu for a misspelled word: its suggestions, an
+     * "Add to Dictionary" item and the standard edit items.
+     * @param contents - Web contents the menu was opened in
+     * @param params - Context menu parameters from Electron
+     * @returns Menu template
+     */
+    private buildSpellingMenuTemplate(
+        contents: Electron.WebContents,
+        params: Electron.ContextMenuParams
+    ): MenuItemConstructorOptions[] {
+        const suggestions: MenuItemConstructorOptions[] =
+            params.dictionarySuggestions.length > 0
+                ? params.dictionarySuggestions.map((suggestion) => ({
+                      label: suggestion,
+                      click: () => contents.replaceMisspelling(suggestion),
+                  }))
+                : [{ label: 'No Suggestions', enabled: false }];
+
+        return [
+            ...suggestions,
+            { type: 'separator' },
+            {
+                label: 'Add to Dictionary',
+                click: () => contents.session.addWordToSpellCheckerDictionary(params.misspelledWord),
+            },
+            { type: 'separator' },
+            ...this.buildEditMenuTemplate().map((item) =>
+                item.id ? { ...item, enabled: this.contextMenuItemEnabled(item.id) } : item
+            ),
+        ];
+    }
+
+    /**
+     * Whether a cached context menu item is enabled in the current context.
+     * @param id - Context menu item ID
+     */
+    private contextMenuItemEnabled(id: string): boolean {
+        return this.contextMenuItems.find((entry) => entry.id === id)?.item.enabled ?? true;
+    }
+
     /**
      * Builds and caches the context menu with menu item references.
      * @returns Pre-built Menu instance
      */
     private buildCachedContextMenu(): Menu {
-        const template: MenuItemConstructorOptions[] = [
+        const menu = Menu.buildFromTemplate(this.buildEditMenuTemplate());
+
+        // Cache references to menu items for fast state updates
+        this.contextMenuItems = [
+            { id: 'cut', item: menu.getMenuItemById('cut')! },
+            { id: 'copy', item: menu.getMenuItemById('copy')! },
+            { id: 'paste', item: menu.getMenuItemById('paste')! },
+            { id: 'delete', item: menu.getMenuItemById('delete')! },
+            { id: 'selectAll', item: menu.getMenuItemById('selectAll')! },
+        ].filter((entry) => entry.item !== null);
+
+        return menu;
+    }
+
+    /**
+     * Builds the template for the standard text editing context menu items.
+     * @returns Menu template
+     */
+    private buildEditMenuTemplate(): MenuItemConstructorOptions[] {
+        return [
             {
                 id: 'cut',
                 role: 'cut',
@@ -131,19 +199,6 @@ export default class MenuManager {
                 accelerator: 'CmdOrCtrl+A',
             },
         ];
-
-        const menu = Menu.buildFromTemplate(template);
-
-        // Cache references to menu items for fast state updates
-        this.contextMenuItems = [
-            { id: 'cut', item: menu.getMenuItemById('cut')! },
-            { id: 'copy', item: menu.getMenuItemById('copy')! },
-            { id: 'paste', item: menu.getMenuItemById('paste')! },
-            { id: 'delete', item: menu.getMenuItemById('delete')! },
-            { id: 'selectAll', item: menu.getMenuItemById('selectAll')! },
-        ].filter((entry) => entry.item !== null);
-
-        return menu;
     }
 
     /**
@@ -238,6 +293,12 @@ export default class MenuManager {
                         }
                     },
                 },
+                {
+                    label: 'Toggle Gemini DevTools',
+                    id: 'menu-debug-gemini-devtools',
+                    accelerator: 'CmdOrCtrl+Alt+Shift+I',
+                    click: () => this.windowManager.toggleGeminiDevTools(),
+                },
             ],
         };
     }
@@ -278,8 +339,9 @@ export default class MenuManager {
             submenu: [
                 {
                     label: 'New Window',
+                    id: 'menu-file-newwindow',
                     accelerator: 'CmdOrCtrl+Shift+N',
-                    visible: false, // Hidden until functionality is implemented
+                    click: () => this.windowManager.createAdditionalMainWindow(),
                 },
                 {
                     label: 'Export as PDF',
@@ -306,6 +368,97 @@ export default class MenuManager {
                         }
                     },
                 },
+                {
+                    label: 'Export to Confluence',
+                    id: 'menu-view-export-confluence',
+                    click: () => {
+                        const win = this.windowManager.getMainWindow();
+                        if (win && !win.isDestroyed()) {
+                            this.windowManager.emit('export-confluence-triggered');
+                        }
+                    },
+                },
+                {
+                    label: 'Export Canvas as Markdown',
+                    id: 'menu-file-export-canvas',
+                    click: () => {
+                        const win = this.windowManager.getMainWindow();
+                        if (win && !win.isDestroyed()) {
+                            this.windowManager.emit('export-canvas-triggered');
+                        }
+                    },
+                },
+                {
+                    label: 'Export Research Report as PDF',
+                    id: 'menu-file-export-report-pdf',
+                    click: () => {
+                        const win = this.windowManager.getMainWindow();
+                        if (win && !win.isDestroyed()) {
+                            this.windowManager.emit('export-research-report-triggered', 'pdf');
+                        }
+                    },
+                },
+                {
+                    label: 'Export Research Report as Markdown',
+                    id: 'menu-file-export-report-md',
+                    click: () => {
+                        const win = this.windowManager.getMainWindow();
+                        if (win && !win.isDestroyed()) {
+                            this.windowManager.emit('export-research-report-triggered', 'markdown');
+                        }
+                    },
+                },
+                {
+                    label: 'Pin Last Answer',
+                    id: 'menu-file-pin-answer',
+                    click: () => {
+                        this.windowManager.emit('pin-answer-triggered');
+                    },
+                },
+                {
+                    label: 'Share Last Answer...',
+                    id: 'menu-file-share-answer',
+                    click: () => {
+                        this.windowManager.emit('share-answer-triggered');
+                    },
+                },
+                {
+                    label: 'Share as QR Code...',
+                    id: 'menu-file-share-qr',
+                    click: () => {
+                        this.windowManager.emit('share-qr-triggered');
+                    },
+                },
+                {
+                    label: 'Create Issue from Answer...',
+                    id: 'menu-file-create-issue',
+                    click: () => {
+                        this.windowManager.emit('create-issue-triggered');
+                    },
+                },
+                {
+                    label: 'Print...',
+                    id: 'menu-file-print',
+                    accelerator: 'CmdOrCtrl+P',
+                    click: () => this.windowManager.printGemini(),
+                },
+                {
+                    label: 'Save Screenshot...',
+                    id: 'menu-file-screenshot-save',
+                    click: () => this.windowManager.emit('screenshot-triggered', { target: 'file', region: 'view' }),
+                },
+                {
+                    label: 'Copy Screenshot',
+                    id: 'menu-file-screenshot-copy',
+                    click: () =>
+                        this.windowManager.emit('screenshot-triggered', { target: 'clipboard', region: 'view' }),
+                },
+                {
+                    label: 'Copy Last Answer as Image',
+                    id: 'menu-file-screenshot-answer',
+                    click: () =>
+                        this.windowManager.emit('screenshot-triggered', { target: 'clipboard', region: 'response' }),
+                },
                 { type: 'separator' },
                 {
                     label: 'Sign in to Google',
@@ -357,6 +510,14 @@ export default class MenuManager {
                         this.windowManager.zoomOut();
                     },
                 },
+                {
+                    label: 'Reset Zoom',
+                    id: 'menu-view-zoom-reset',
+                    accelerator: 'CmdOrCtrl+0',
+                    click: () => {
+                        this.windowManager.resetZoom();
+                    },
+                },
                 { type: 'separator' },
                 {
                     label: 'Always On Top',
@@ -368,7 +529,61 @@ export default class MenuManager {
                         this.windowManager.setAlwaysOnTop(menuItem.checked);
                     },
                 },
-                { role: 'togglefullscreen', id: 'menu-view-fullscreen' },
+                {
+                    label: 'Toggle Full Screen',
+                    id: 'menu-view-fullscreen',
+                    accelerator: isMac() ? 'Ctrl+Cmd+F' : 'F11',
+                    click: () => {
+                        this.windowManager.toggleFullScreen();
+                    },
+                },
+                {
+                    label: 'Immersive Mode',
+                    id: 'menu-view-immersive',
+                    accelerator: 'Shift+F11',
+                    click: () => {
+                        this.windowManager.toggleImmersiveMode();
+                    },
+                },
+                {
+                    label: 'Compact Mode',
+                    id: 'menu-view-compact',
+                    accelerator: 'CmdOrCtrl+Shift+M',
+                    click: () => {
+                        this.windowManager.toggleCompactMode();
+                    },
+                },
+                {
+                    label: 'Overlay Mode',
+                    id: 'menu-view-overlay',
+                    accelerator: OVERLAY_MODE_ACCELERATOR,
+                    enabled: this.windowManager.isWindowOpacitySupported(),
+                    click: () => {
+                        this.windowManager.toggleOverlayMode();
+                    },
+                },
+                {
+                    label: 'Move to Next Display',
+                    id: 'menu-view-move-to-next-display',
+                    click: () => {
+                        this.windowManager.moveToNextDisplay();
+                    },
+                },
+                {
+                    label: 'Focus Session',
+                    id: 'menu-view-focus-session',
+                    click: () => {
+                        this.windowManager.emit('focus-session-toggle-triggered');
+                    },
+                },
+                { type: 'separator' },
+                {
+                    label: 'Reload User Styles',
+                    id: 'menu-view-reload-user-styles',
+                    click: () => {
+                        this.windowManager.reloadUserStyles();
+                    },
+                },
             ],
         };
     }
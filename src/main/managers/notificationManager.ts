@@ -10,6 +10,7 @@ import * as fs from 'fs';
 import { createLogger } from '../utils/logger';
 import { APP_NAME, isLinux } from '../utils/constants';
 import { getNotificationIconPath } from '../utils/paths';
+import { isFocusSessionActive } from '../utils/focusSession';
 import type SettingsStore from '../store';
 import type BadgeManager from './badgeManager';
 
@@ -30,6 +31,7 @@ export interface NotificationSettings extends Record<string, unknown> {
  * - Shows taskbar badge (via BadgeManager) when unfocused
  * - Clears badge when window regains focus
  * - Respects user setting for enabling/disabling notifications
+ * - Stays quiet during a focus session
  *
  * @class NotificationManager
  */
@@ -138,6 +140,11 @@ export default class NotificationManager {
             return;
         }
 
+        if (isFocusSessionActive()) {
+            logger.log('Response complete, but a focus session is running - no notification');
+            return;
+        }
+
         // Check if window is focused
         if (this._isWindowFocused) {
             logger.log('Response complete, but window is focused - no notification');
@@ -0,0 +1,130 @@
+/**
+ * QR Code Manager for the Electron main process.
+ * Shows a QR code in a small popup window so content can be scanned onto a phone.
+ *
+ * @module QrCodeManager
+ */
+
+import { BrowserWindow } from 'electron';
+import { createLogger } from '../utils/logger';
+import { encodeQrCode, qrCodeToSvg } from '../utils/qrCode';
+
+const logger = createLogger('[QrCodeManager]');
+
+/** Popup size; large enough for a version 40 code to stay scannable */
+const QR_WINDOW_SIZE = 560;
+
+/** QR pages are static: no scripts, no remote content, inline styles only */
+const QR_CONTENT_SECURITY_POLICY = "default-src 'none'; style-src 'unsafe-inline'";
+
+/**
+ * Escape text for an HTML document.
+ */
+function escapeHtml(text: string): string {
+    return text.replace(/&/g, '&amp;').replace(/</g, '&lt;').replace(/>/g, '&gt;').replace(/"/g, '&quot;');
+}
+
+/**
+ * Manages the QR code popup.
+ *
+ * The popup is a regular BrowserWindow loading a static HTML document whose CSP
+ * blocks all scripts and remote content. The code is encoded in process, so the
+ * shared content never leaves the machine. Showing a new code replaces the open one.
+ *
+ * @class QrCodeManager
+ */
+export default class QrCodeManager {
+    private window: BrowserWindow | null = null;
+
+    /**
+     * Show text as a QR code.
+     * @param text - Text to encode
+     * @param caption - Description shown under the code
+     * @throws If the text does not fit in a QR code
+     */
+    show(text: string, caption: string): void {
+        const svg = qrCodeToSvg(encodeQrCode(text));
+
+        this.close();
+        const win = new BrowserWindow({
+            width: QR_WINDOW_SIZE,
+            height: QR_WINDOW_SIZE + 60,
+            minWidth: 240,
+            minHeight: 280,
+            alwaysOnTop: true,
+            minimizable: false,
+            maximizable: false,
+            fullscreenable: false,
+            autoHideMenuBar: true,
+            show: false,
+            title: 'Scan with Your Phone',
+            webPreferences: {
+                contextIsolation: true,
+                nodeIntegration: false,
+                sandbox: true,
+            },
+        });
+        this.window = win;
+
+        win.once('ready-to-show', () => win.show());
+        win.on('closed', () => {
+            if (this.window === win) this.window = null;
+        });
+        win.webContents.on('will-navigate', (event) => event.preventDefault());
+        win.webContents.setWindowOpenHandler(() => ({ action: 'deny' }));
+
+        win.loadURL(`data:text/html;charset=utf-8,${encodeURIComponent(this.generateQrHtml(svg, caption))}`).catch(
+            (error) => logger.error('Failed to load QR code:', error)
+        );
+
+        logger.log(`QR code shown (${new TextEncoder().encode(text).length} bytes)`);
+    }
+
+    /**
+     * Close the QR code popup if it is open.
+     */
+    close(): void {
+        if (this.window && !this.window.isDestroyed()) this.window.destroy();
+        this.window = null;
+    }
+
+    /**
+     * @returns True if the QR code popup is open
+     */
+    isOpen(): boolean {
+        return this.window !== null && !this.window.isDestroyed();
+    }
+
+    /**
+     * Wraps the QR code SVG in a page that scales it to the window.
+     */
+    private generateQrHtml(svg: string, caption: string): string {
+        return `
+        <!DOCTYPE html>
+        <html>
+        <head>
+            <meta charset="UTF-8">
+            <meta http-equiv="Content-Security-Policy" content="${QR_CONTENT_SECURITY_POLICY}">
+            <style>
+                html, body { height: 100%; margin: 0; }
+                body {
+                    display: flex;
+                    flex-direction: column;
+                    align-items: center;
+                    background: #fff;
+                    font: 13px -apple-system, BlinkMacSystemFont, 'Segoe UI', sans-serif;
+                    color: #24292e;
+                }
+                .code { flex: 1; min-height: 0; width: 100%; display: flex; justify-content: center; }
+                .code svg { height: 100%; max-width: 100%; }
+                .caption { padding: 8px 16px 12px; text-align: center; word-break: break-all; }
+            </style>
+        </head>
+        <body>
+            <div class="code">${svg}</div>
+            <div class="caption">${escapeHtml(caption)}</div>
+        </body>
+        </html>
+        `;
+    }
+}
@@ -18,6 +18,8 @@ import { app, BrowserWindow } from 'electron';
 import type { UpdateInfo, AppUpdater } from 'electron-updater';
 import log from 'electron-log';
 import { createLogger } from '../utils/logger';
+import { isDiskSpaceLow } from '../utils/diskSpace';
+import { isFocusSessionActive } from '../utils/focusSession';
 import type SettingsStore from '../store';
 import type BadgeManager from './badgeManager';
 import type TrayManager from './trayManager';
@@ -189,6 +191,14 @@ export default class UpdateManager {
         return false;
     }
 
+    /**
+     * Check if auto-updates work for this platform and install type,
+     * regardless of the user's setting.
+     */
+    isSupported(): boolean {
+        return !this.updatesDisabled;
+    }
+
     /**
      * Check if auto-updates are enabled.
      */
@@ -228,6 +238,18 @@ export default class UpdateManager {
             return;
         }
 
+        // Updates download automatically, so background checks wait for free space
+        if (!manual && isDiskSpaceLow()) {
+            logger.log('Update check skipped - low disk space');
+            return;
+        }
+
+        // The updater notifies when an update is ready, so background checks wait for the session to end
+        if (!manual && isFocusSessionActive()) {
+            logger.log('Update check skipped - focus session running');
+            return;
+        }
+
         try {
             logger.log(manual ? 'Manual update check...' : 'Checking for updates...');
             const updater = await this.ensureAutoUpdater();
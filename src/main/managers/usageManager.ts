@@ -0,0 +1,373 @@
+/**
+ * Usage Manager for the Electron main process.
+ * Records how long an app window is in the foreground each day, for screen-time
+ * awareness or billing research time. Data never leaves the machine.
+ *
+ * Optional daily limits (active minutes and/or prompts) block the app until the
+ * next day once reached. Limits from a `usage-policy.json` file in the user data
+ * directory take precedence over the user's own and cannot be changed in the app.
+ * The policy and the usage data are read with plain fs rather than SettingsStore,
+ * so launching with --safe-mode neither lifts the policy nor resets the counts.
+ *
+ * @module UsageManager
+ */
+
+import { EventEmitter } from 'events';
+import * as fs from 'fs';
+import * as path from 'path';
+import { app, BrowserWindow, powerMonitor } from 'electron';
+import { isDataDirReadOnly } from '../utils/dataDirLock';
+import { createLogger } from '../utils/logger';
+import {
+    NO_USAGE_LIMITS,
+    type UsageDay,
+    type UsageLimits,
+    type UsageLimitStatus,
+    type UsageReport,
+} from '../../shared/types/usage';
+
+const logger = createLogger('[UsageManager]');
+
+/** How often focus time is committed and idle state checked */
+export const USAGE_TICK_MS = 60 * 1000;
+
+/** Idle time after which counting stops (idle detection on) */
+export const USAGE_IDLE_THRESHOLD_SECONDS = 5 * 60;
+
+/** Days of history kept */
+const RETENTION_DAYS = 90;
+
+/** Days included in the report */
+const REPORT_DAYS = 14;
+
+/**
+ * Persisted usage data.
+ */
+interface UsageData {
+    /** Active seconds keyed by local date (YYYY-MM-DD) */
+    days: Record<string, number>;
+    /** Prompts keyed by local date (YYYY-MM-DD) */
+    prompts: Record<string, number>;
+}
+
+/**
+ * Key-value access to the usage data.
+ */
+export interface UsageDataStore {
+    get<K extends keyof UsageData>(key: K): UsageData[K] | undefined;
+    set<K extends keyof UsageData>(key: K, value: UsageData[K]): void;
+}
+
+/**
+ * Options for UsageManager (mainly for testing).
+ */
+export interface UsageManagerOptions {
+    store?: UsageDataStore;
+    /** Clock, in milliseconds */
+    now?: () => number;
+}
+
+/**
+ * Normalize raw limits, treating anything other than a positive number as no limit.
+ */
+export function normalizeUsageLimits(raw: Partial<Record<keyof UsageLimits, unknown>>): UsageLimits {
+    const toLimit = (value: unknown): number =>
+        typeof value === 'number' && Number.isFinite(value) && value > 0 ? Math.floor(value) : 0;
+    return { dailyMinutes: toLimit(raw.dailyMinutes), dailyPrompts: toLimit(raw.dailyPrompts) };
+}
+
+/**
+ * Read a JSON object from a file.
+ * @returns The object, or null if the file is missing, unreadable or not an object
+ */
+function readJsonObject(filePath: string, fileSystem: typeof fs): Record<string, unknown> | null {
+    try {
+        const parsed: unknown = JSON.parse(fileSystem.readFileSync(filePath, 'utf-8'));
+        return parsed && typeof parsed === 'object' && !Array.isArray(parsed)
+            ? (parsed as Record<string, unknown>)
+            : null;
+    } catch (error) {
+        if ((error as NodeJS.ErrnoException).code !== 'ENOENT') {
+            logger.warn(`Ignoring unreadable ${path.basename(filePath)}: ${(error as Error).message}`);
+        }
+        return null;
+    }
+}
+
+/**
+ * Keep only the numeric entries of a per-day count map.
+ */
+function toDailyCounts(value: unknown): Record<string, number> {
+    if (!value || typeof value !== 'object') return {};
+    return Object.fromEntries(
+        Object.entries(value).filter((entry): entry is [string, number] => typeof entry[1] === 'number')
+    );
+}
+
+/**
+ * Load usage limits from `usage-policy.json` in the user data directory.
+ * @param userDataPath - Directory holding the policy file
+ * @param fileSystem - File system (for testing)
+ * @returns The policy limits, or null if no limit is set by policy
+ */
+export function loadUsagePolicy(
+    userDataPath: string = app.getPath('userData'),
+    fileSystem: typeof fs = fs
+): UsageLimits | null {
+    const policy = readJsonObject(path.join(userDataPath, 'usage-policy.json'), fileSystem);
+    const limits = normalizeUsageLimits(policy ?? NO_USAGE_LIMITS);
+    return limits.dailyMinutes > 0 || limits.dailyPrompts > 0 ? limits : null;
+}
+
+/**
+ * Usage data kept in `usage.json` in the user data directory.
+ * Changes are written straight away, except while another instance owns the
+ * data directory.
+ */
+export class UsageDataFile implements UsageDataStore {
+    private data: UsageData;
+
+    /**
+     * @param filePath - Path of the usage data file
+     * @param fileSystem - File system (for testing)
+     */
+    constructor(
+        private readonly filePath: string,
+        private readonly fileSystem: typeof fs = fs
+    ) {
+        const saved = readJsonObject(filePath, fileSystem);
+        this.data = { days: toDailyCounts(saved?.days), prompts: toDailyCounts(saved?.prompts) };
+    }
+
+    get<K extends keyof UsageData>(key: K): UsageData[K] {
+        return this.data[key];
+    }
+
+    set<K extends keyof UsageData>(key: K, value: UsageData[K]): void {
+        this.data = { ...this.data, [key]: value };
+        if (isDataDirReadOnly()) return;
+
+        try {
+            this.fileSystem.writeFileSync(this.filePath, JSON.stringify(this.data, null, 2), 'utf-8');
+        } catch (error) {
+            logger.error('Failed to save usage data:', error);
+        }
+    }
+}
+
+/**
+ * Format a timestamp as a local YYYY-MM-DD date.
+ */
+export function toLocalDate(timestamp: number): string {
+    const date = new Date(timestamp);
+    const month = String(date.getMonth() + 1).padStart(2, '0');
+    const day = String(date.getDate()).padStart(2, '0');
+    return `${date.getFullYear()}-${month}-${day}`;
+}
+
+/**
+ * Tracks foreground focus time per day.
+ *
+ * Time counts while any app window is focused. With idle detection on, time
+ * stops counting once the system has been idle for USAGE_IDLE_THRESHOLD_SECONDS,
+ * and the idle stretch itself is not credited.
+ *
+ * Emits 'limit-changed' with a UsageLimitStatus whenever the blocked state flips.
+ */
+export default class UsageManager extends EventEmitter {
+    private readonly store: UsageDataStore;
+    private readonly now: () => number;
+
+    /** Start of the current uncommitted active stretch, or null when not counting */
+    private activeSince: number | null = null;
+    private idleDetection = true;
+    private timer: ReturnType<typeof setInterval> | null = null;
+    private limits: UsageLimits = { ...NO_USAGE_LIMITS };
+    private enforcedByPolicy = false;
+    private blocked = false;
+
+    private readonly onFocus = (): void => {
+        if (this.activeSince === null) this.activeSince = this.now();
+    };
+
+    private readonly onBlur = (): void => {
+        this.commit(this.now());
+        this.activeSince = null;
+    };
+
+    constructor(options: UsageManagerOptions = {}) {
+        super();
+        /* v8 ignore next -- production fallback, tests inject a store */
+        this.store = options.store ?? new UsageDataFile(path.join(app.getPath('userData'), 'usage.json'));
+        this.now = options.now ?? Date.now;
+    }
+
+    /**
+     * Start tracking.
+     * @param idleDetection - Whether idle time is excluded
+     */
+    start(idleDetection: boolean): void {
+        this.idleDetection = idleDetection;
+        if (this.timer) return;
+
+        app.on('browser-window-focus', this.onFocus);
+        app.on('browser-window-blur', this.onBlur);
+        powerMonitor.on('lock-screen', this.onBlur);
+        powerMonitor.on('suspend', this.onBlur);
+        this.timer = setInterval(() => this.tick(), USAGE_TICK_MS);
+
+        if (BrowserWindow.getFocusedWindow()) this.onFocus();
+        logger.log('Usage tracking started');
+    }
+
+    /**
+     * Stop tracking, committing any pending time.
+     */
+    stop(): void {
+        if (!this.timer) return;
+
+        this.onBlur();
+        clearInterval(this.timer);
+        this.timer = null;
+        app.removeListener('browser-window-focus', this.onFocus);
+        app.removeListener('browser-window-blur', this.onBlur);
+        powerMonitor.removeListener('lock-screen', this.onBlur);
+        powerMonitor.removeListener('suspend', this.onBlur);
+        logger.log('Usage tracking stopped');
+    }
+
+    /**
+     * Enable or disable idle detection while running.
+     */
+    setIdleDetection(enabled: boolean): void {
+        this.idleDetection = enabled;
+    }
+
+    /**
+     * Commit pending time and re-evaluate focus and idle state.
+     */
+    tick(): void {
+        const now = this.now();
+        const idleSeconds = this.idleDetection ? powerMonitor.getSystemIdleTime() : 0;
+        const idle = idleSeconds >= USAGE_IDLE_THRESHOLD_SECONDS;
+
+        // Don't credit the idle stretch
+        this.commit(idle ? now - idleSeconds * 1000 : now);
+        this.activeSince = BrowserWindow.getFocusedWindow() && !idle ? now : null;
+        this.checkLimits();
+    }
+
+    /**
+     * Count a completed prompt towards today's total.
+     */
+    recordPrompt(): void {
+        const prompts = { ...(this.store.get('prompts') ?? {}) };
+        const date = toLocalDate(this.now());
+        prompts[date] = (prompts[date] ?? 0) + 1;
+        this.store.set('prompts', this.prune(prompts, this.now()));
+        this.checkLimits();
+    }
+
+    /**
+     * Set the daily limits.
+     * @param limits - Limits to apply (0 = no limit)
+     * @param enforcedByPolicy - Whether the limits come from a policy file
+     */
+    setLimits(limits: UsageLimits, enforcedByPolicy = false): void {
+        this.limits = normalizeUsageLimits(limits);
+        this.enforcedByPolicy = enforcedByPolicy;
+        this.checkLimits();
+    }
+
+    /**
+     * Whether any daily limit is set.
+     */
+    hasLimits(): boolean {
+        return this.limits.dailyMinutes > 0 || this.limits.dailyPrompts > 0;
+    }
+
+    /**
+     * Get the current limit status, based on today's usage.
+     */
+    getLimitStatus(): UsageLimitStatus {
+        const { todaySeconds, todayPrompts } = this.getReport();
+        let reason: UsageLimitStatus['reason'] = null;
+        if (this.limits.dailyMinutes > 0 && todaySeconds >= this.limits.dailyMinutes * 60) {
+            reason = 'time';
+        } else if (this.limits.dailyPrompts > 0 && todayPrompts >= this.limits.dailyPrompts) {
+            reason = 'prompts';
+        }
+
+        return {
+            blocked: reason !== null,
+            reason,
+            limits: { ...this.limits },
+            enforcedByPolicy: this.enforcedByPolicy,
+        };
+    }
+
+    /**
+     * Build a usage report, including the current uncommitted stretch.
+     */
+    getReport(): UsageReport {
+        const now = this.now();
+        const days = { ...(this.store.get('days') ?? {}) };
+        if (this.activeSince !== null) {
+            const today = toLocalDate(now);
+            days[today] = (days[today] ?? 0) + Math.round((now - this.activeSince) / 1000);
+        }
+
+        const recent: UsageDay[] = [];
+        for (let offset = REPORT_DAYS - 1; offset >= 0; offset--) {
+            const date = toLocalDate(now - offset * 24 * 60 * 60 * 1000);
+            recent.push({ date, seconds: days[date] ?? 0 });
+        }
+
+        // getDay(): 0 = Sunday; weeks start on Monday
+        const daysSinceMonday = (new Date(now).getDay() + 6) % 7;
+        const weekSeconds = recent.slice(recent.length - 1 - daysSinceMonday).reduce((sum, d) => sum + d.seconds, 0);
+
+        const todayPrompts = (this.store.get('prompts') ?? {})[toLocalDate(now)] ?? 0;
+
+        return { todaySeconds: recent[recent.length - 1].seconds, weekSeconds, todayPrompts, days: recent };
+    }
+
+    /**
+     * Emit 'limit-changed' if the blocked state has flipped (including at midnight).
+     */
+    private checkLimits(): void {
+        const status = this.getLimitStatus();
+        if (status.blocked === this.blocked) return;
+
+        this.blocked = status.blocked;
+        logger.log(status.blocked ? `Daily ${status.reason} limit reached` : 'Daily limit cleared');
+        this.emit('limit-changed', status);
+    }
+
+    /**
+     * Credit the current stretch up to `until` and restart it there.
+     */
+    private commit(until: number): void {
+        if (this.activeSince === null) return;
+
+        const seconds = Math.round((until - this.activeSince) / 1000);
+        this.activeSince = until;
+        if (seconds <= 0) return;
+
+        const days = { ...(this.store.get('days') ?? {}) };
+        const date = toLocalDate(until);
+        days[date] = (days[date] ?? 0) + seconds;
+        this.store.set('days', this.prune(days, until));
+    }
+
+    /**
+     * Drop per-day entries beyond the retention window.
+     */
+    private prune(byDate: Record<string, number>, now: number): Record<string, number> {
+        const oldest = toLocalDate(now - RETENTION_DAYS * 24 * 60 * 60 * 1000);
+        for (const key of Object.keys(byDate)) {
+            if (key < oldest) delete byDate[key];
+        }
+        return byDate;
+    }
+}
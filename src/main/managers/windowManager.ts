@@ -5,12 +5,41 @@
  * This class delegates window-specific logic to dedicated window classes
  * and provides a unified interface for window management.
  *
+ * Besides the first main window, which owns the tray and the saved window
+ * state, File › New Window opens additional main windows, each with its own
+ * Gemini view. Gemini actions (reload, navigate, print, export, ...) apply to
+ * the main window the user focused last.
+ *
  * @module WindowManager
  */
 
-import { BrowserWindow } from 'electron';
+import { BrowserWindow, screen, type Event, type Input, type Rectangle } from 'electron';
 import { EventEmitter } from 'events';
+import { release } from 'os';
 import { createLogger } from '../utils/logger';
+import { findGeminiFrame, getGeminiConversationId } from '../utils/geminiFrame';
+import { isSafeMode } from '../utils/safeMode';
+import { showToast } from '../utils/toast';
+import { buildUserStylesScript, readUserStyles } from '../utils/userStyles';
+import { buildCompactModeCss, COMPACT_MODE_SHEET_NAME, getCompactModeBounds } from '../utils/compactMode';
+import { applyTitlebarEffect, getSupportedTitlebarEffects } from '../utils/titlebarEffects';
+import { GEMINI_APP_URL, isLinux, isMacOS } from '../utils/constants';
+import {
+    centerInWorkArea,
+    fitWindowBounds,
+    WINDOW_STATE_SAVE_DELAY_MS,
+    type WindowState,
+} from '../utils/windowState';
+import type { DisplayInfo } from '../../shared/types/displays';
+import type { GeminiLocation, GeminiNavigationState } from '../../shared/types/navigation';
+import { MIN_WINDOW_OPACITY, OVERLAY_MODE_OPACITY } from '../../shared/types/overlay';
+import {
+    DEFAULT_TITLEBAR_HEIGHT,
+    MAX_TITLEBAR_HEIGHT,
+    MIN_TITLEBAR_HEIGHT,
+    TITLEBAR_EFFECTS,
+    type TitlebarEffect,
+} from '../../shared/types/titlebar';
 import MainWindow from '../windows/mainWindow';
 import AuthWindow from '../windows/authWindow';
 import OptionsWindow from '../windows/optionsWindow';
@@ -24,13 +53,51 @@ const logger = createLogger('[WindowManager]');
  */
 export const ZOOM_LEVEL_STEPS = [50, 67, 75, 80, 90, 100, 110, 125, 150, 175, 200] as const;
 
+/** How far a new main window is offset from the focused one, in pixels */
+const NEW_WINDOW_OFFSET = 30;
+
+/** Left inset and height of the macOS traffic lights, in pixels */
+const TRAFFIC_LIGHT_X = 12;
+const TRAFFIC_LIGHT_HEIGHT = 16;
+
 export default class WindowManager extends EventEmitter {
     readonly isDev: boolean;
     private mainWindow: MainWindow;
+    /** Main windows opened with New Window, by BrowserWindow ID */
+    private _additionalWindows = new Map<number, MainWindow>();
+    /** Main window the user focused last */
+    private _activeMainWindow: MainWindow;
     private optionsWindow: OptionsWindow;
     private authWindow: AuthWindow;
     private quickChatWindow: QuickChatWindow;
     private _zoomLevel: number = 100;
+    /** Last emitted Gemini location, to skip duplicate events */
+    private _lastGeminiLocation: string | null = null;
+    /** Window whose titlebar is hidden and that is fullscreen (immersive mode) */
+    private _immersiveWindow: BrowserWindow | null = null;
+    /** Extra hosts the Gemini view may navigate to, for windows opened later */
+    private _allowedHosts: string[] = [];
+    /** Windows in compact mode, with the bounds and maximized state to restore */
+    private _compactWindows = new Map<BrowserWindow, { bounds: Rectangle; isMaximized: boolean }>();
+    /** Whether compact mode hides Gemini's sidebar */
+    private _compactModeHidesSidebar = true;
+    /** Opacity of every main window (MIN_WINDOW_OPACITY-1) */
+    private _windowOpacity = 1;
+    /** Window that is translucent, on top and click-through (overlay mode) */
+    private _overlayWindow: BrowserWindow | null = null;
+    /** Always-on-top state of the overlay window before it entered overlay mode */
+    private _overlayWasAlwaysOnTop = false;
+    /** Height of the main window titlebar in logical pixels */
+    private _titlebarHeight = DEFAULT_TITLEBAR_HEIGHT;
+    /** Titlebar effect the user chose, which may be unsupported on this platform */
+    private _titlebarEffect: TitlebarEffect = 'none';
+    /** Scale factor of the display each main window was last on */
+    private _scaleFactors = new Map<BrowserWindow, number>();
+    /** Whether the display-metrics-changed listener is registered */
+    private _listeningForDisplayChanges = false;
+    /** Main window state to restore when the main window is created */
+    private _windowState: WindowState | null = null;
+    private _windowStateTimer: ReturnType<typeof setTimeout> | null = null;
 
     /**
      * Creates a new WindowManager instance.
@@ -42,6 +109,7 @@ export default class WindowManager extends EventEmitter {
 
         // Initialize window instances
         this.mainWindow = new MainWindow(isDev);
+        this._activeMainWindow = this.mainWindow;
         this.optionsWindow = new OptionsWindow(isDev);
         this.authWindow = new AuthWindow(isDev);
         this.quickChatWindow = new QuickChatWindow(isDev);
@@ -79,8 +147,16 @@ export default class WindowManager extends EventEmitter {
         logger.debug('createMainWindow() called');
         try {
             logger.debug('About to call mainWindow.create()');
+            const isNew = this.mainWindow.getWindow() === null;
             const win = this.mainWindow.create();
             logger.debug('mainWindow.create() returned, window:', win ? 'exists' : 'null');
+
+            // Reapply the saved zoom to re-created windows (e.g. after close on macOS)
+            if (isNew) {
+                this._restoreWindowState(win);
+                this._trackWindowState(win);
+                this._setupMainWindow(win, this.mainWindow);
+            }
             return win;
         } catch (error) {
             logger.error('CRITICAL: Failed to create main window:', error);
@@ -88,6 +164,220 @@ export default class WindowManager extends EventEmitter {
         }
     }
 
+    /**
+     * Open another main window with its own Gemini view, e.g. to keep two
+     * conversations on two monitors. It is placed a little below and to the
+     * right of the focused main window and closes instead of hiding to tray.
+     * @returns The new window
+     */
+    createAdditionalMainWindow(): BrowserWindow {
+        const source = this.getMainWindow();
+        const mainWindow = new MainWindow(this.isDev, false);
+        mainWindow.setAuthWindowCallback((url) => this.createAuthWindow(url));
+        mainWindow.setAllowedHosts(this._allowedHosts);
+
+        const win = mainWindow.create();
+        if (source && !source.isDestroyed()) {
+            const bounds = source.getNormalBounds();
+            const workAreas = screen.getAllDisplays().map((display) => display.workArea);
+            win.setBounds(
+                fitWindowBounds(
+                    { ...bounds, x: bounds.x + NEW_WINDOW_OFFSET, y: bounds.y + NEW_WINDOW_OFFSET },
+                    workAreas,
+                    screen.getPrimaryDisplay().workArea,
+                    this._titlebarHeight
+                )
+            );
+        }
+
+        const id = win.id;
+        this._additionalWindows.set(id, mainWindow);
+        this._activeMainWindow = mainWindow;
+        this._setupMainWindow(win, mainWindow);
+        win.on('closed', () => {
+            this._additionalWindows.delete(id);
+            if (this._activeMainWindow === mainWindow) {
+                this._activeMainWindow = this.mainWindow;
+            }
+        });
+
+        logger.log(`Opened main window ${id} (${this._additionalWindows.size + 1} open)`);
+        return win;
+    }
+
+    /**
+     * Wire up a new main window: zoom, fullscreen and immersive mode keys,
+     * fullscreen changes, and Gemini navigation events so titlebar
+     * back/forward stay current.
+     * @private
+     */
+    private _setupMainWindow(win: BrowserWindow, mainWindow: MainWindow): void {
+        win.webContents.once('did-finish-load', () => this._applyZoomToWindow(win));
+        this._trackScaleFactor(win);
+        if (this._windowOpacity < 1) win.setOpacity(this._windowOpacity);
+        this._applyTitlebarHeight(win);
+        if (this.getTitlebarEffect() !== 'none') applyTitlebarEffect(win, this.getTitlebarEffect());
+        win.webContents.on('before-input-event', (event, input) => this._handleFullScreenKey(win, event, input));
+        // Also fired for macOS native fullscreen (green button, Ctrl+Cmd+F)
+        win.on('enter-full-screen', () => this.emit('fullscreen-changed', true, win));
+        win.on('leave-full-screen', () => {
+            if (this._immersiveWindow === win) this.setImmersiveMode(false);
+            this.emit('fullscreen-changed', false, win);
+        });
+        win.on('closed', () => {
+            if (this._immersiveWindow === win) this._immersiveWindow = null;
+            if (this._overlayWindow === win) {
+                this._overlayWindow = null;
+                this.emit('overlay-mode-changed', false);
+            }
+            this._compactWindows.delete(win);
+            this._scaleFactors.delete(win);
+        });
+        win.on('focus', () => {
+            if (this._activeMainWindow === mainWindow) return;
+            this._activeMainWindow = mainWindow;
+            this._emitGeminiNavigationChanged();
+            this._emitGeminiLocationChanged();
+        });
+        win.webContents.on('did-frame-navigate', () => {
+            this._emitGeminiNavigationChanged();
+            this._emitGeminiLocationChanged();
+        });
+        win.webContents.on('did-navigate-in-page', () => {
+            this._emitGeminiNavigationChanged();
+            this._emitGeminiLocationChanged();
+        });
+        win.webContents.on('did-frame-finish-load', (_event, isMainFrame, frameProcessId, frameRoutingId) => {
+            if (isMainFrame) return;
+            // Frames inside Gemini (embeds, widgets) finish loading too; only the Gemini frame counts
+            const geminiFrame = findGeminiFrame(win.webContents);
+            if (geminiFrame?.processId !== frameProcessId || geminiFrame.routingId !== frameRoutingId) return;
+
+            this.applyUserStyles(win).catch((error) => logger.error('Failed to apply user styles:', error));
+            if (this._compactWindows.has(win)) {
+                this._applyCompactStyles(win, true);
+            }
+            this.emit('gemini-frame-loaded', win);
+            this._emitGeminiLocationChanged();
+        });
+    }
+
+    /**
+     * Watch for a main window ending up on a display with a different scale
+     * factor, either by being moved there or by the display's scaling changing.
+     * @private
+     */
+    private _trackScaleFactor(win: BrowserWindow): void {
+        this._scaleFactors.set(win, screen.getDisplayMatching(win.getBounds()).scaleFactor);
+        // Only fired once a move ends, so the bounds are not reapplied mid-drag (macOS and Windows)
+        win.on('moved', () => this._checkScaleFactor(win));
+
+        if (this._listeningForDisplayChanges) return;
+        this._listeningForDisplayChanges = true;
+        screen.on('display-metrics-changed', (_event, _display, changedMetrics) => {
+            if (!changedMetrics.includes('scaleFactor')) return;
+            for (const target of this._scaleFactors.keys()) {
+                this._checkScaleFactor(target);
+            }
+        });
+    }
+
+    /**
+     * Reapply zoom and bounds when a main window's scale factor changed.
+     * Chromium can drop the zoom factor and leave the page laid out for the
+     * old scale factor, so the Gemini view is mis-sized until the next resize.
+     * @private
+     */
+    private _checkScaleFactor(win: BrowserWindow): void {
+        if (win.isDestroyed()) return;
+        const previous = this._scaleFactors.get(win);
+        const scaleFactor = screen.getDisplayMatching(win.getBounds()).scaleFactor;
+        if (scaleFactor === previous) return;
+
+        this._scaleFactors.set(win, scaleFactor);
+        this._applyZoomToWindow(win);
+        if (!win.isMaximized() && !win.isFullScreen()) {
+            win.setBounds(win.getBounds());
+        }
+        logger.log(`Scale factor changed from ${previous} to ${scaleFactor}`);
+    }
+
+    /**
+     * Set the main window state to restore when the main window is created.
+     * Call before createMainWindow.
+     * @param state - Saved state, or null for the default size and position
+     */
+    setSavedWindowState(state: WindowState | null): void {
+        this._windowState = state;
+    }
+
+    /**
+     * Apply the saved state to a new main window before it is shown.
+     */
+    private _restoreWindowState(win: BrowserWindow): void {
+        const state = this._windowState;
+        if (!state) return;
+
+        // A saved monitor that is gone is replaced by the primary display
+        const workAreas = screen.getAllDisplays().map((display) => display.workArea);
+        this._setBoundsAcrossDisplays(
+            win,
+            fitWindowBounds(state.bounds, workAreas, screen.getPrimaryDisplay().workArea, this._titlebarHeight)
+        );
+
+        // Maximizing or entering fullscreen would show the window before its content is ready
+        if (state.isFullScreen || state.isMaximized) {
+            win.once('show', () => {
+                if (state.isFullScreen) {
+                    win.setFullScreen(true);
+                } else {
+                    win.maximize();
+                }
+            });
+        }
+    }
+
+    /**
+     * Emit window-state-changed after the main window moves, resizes or
+     * changes maximized/fullscreen state, and when it closes.
+     */
+    private _trackWindowState(win: BrowserWindow): void {
+        const scheduleSave = () => {
+            if (this._windowStateTimer) clearTimeout(this._windowStateTimer);
+            this._windowStateTimer = setTimeout(() => this._saveWindowState(win), WINDOW_STATE_SAVE_DELAY_MS);
+        };
+
+        win.on('resize', scheduleSave);
+        win.on('move', scheduleSave);
+        win.on('maximize', scheduleSave);
+        win.on('unmaximize', scheduleSave);
+        win.on('enter-full-screen', scheduleSave);
+        win.on('leave-full-screen', scheduleSave);
+        win.on('close', () => {
+            if (this._windowStateTimer) clearTimeout(this._windowStateTimer);
+            this._windowStateTimer = null;
+            this._saveWindowState(win);
+        });
+    }
+
+    /**
+     * Record the main window's current state and emit window-state-changed.
+     */
+    private _saveWindowState(win: BrowserWindow): void {
+        // Minimized windows report off-screen bounds on Windows; compact mode is not restored
+        if (win.isDestroyed() || win.isMinimized() || this._compactWindows.has(win)) return;
+
+        const state: WindowState = {
+            bounds: win.getNormalBounds(),
+            isMaximized: win.isMaximized(),
+            // Immersive mode is not restored, so neither is its fullscreen
+            isFullScreen: win.isFullScreen() && this._immersiveWindow !== win,
+        };
+        // Re-created windows (e.g. after close on macOS) restore the latest state
+        this._windowState = state;
+        this.emit('window-state-changed', state);
+    }
+
     /**
      * Create or focus the options window.
      * @param tab - Optional tab to open ('settings' or 'about')
@@ -103,15 +393,25 @@ export default class WindowManager extends EventEmitter {
     }
 
     /**
-     * Get the main window instance.
+     * Get the main window the user focused last.
      * @returns The main window or null
      */
     getMainWindow(): BrowserWindow | null {
-        return this.mainWindow.getWindow();
+        return this._activeMainWindow.getWindow() ?? this.getMainWindows()[0] ?? null;
     }
 
     /**
-     * Get the MainWindow class instance (for event subscription).
+     * Get all open main windows, the first main window first.
+     * @returns The main windows
+     */
+    getMainWindows(): BrowserWindow[] {
+        return [this.mainWindow, ...this._additionalWindows.values()]
+            .map((mainWindow) => mainWindow.getWindow())
+            .filter((win): win is BrowserWindow => win !== null && !win.isDestroyed());
+    }
+
+    /**
+     * Get the first MainWindow class instance (for event subscription).
      * @returns The MainWindow instance
      */
     getMainWindowInstance(): MainWindow {
@@ -133,9 +433,11 @@ export default class WindowManager extends EventEmitter {
     }
 
     /**
-     * Restore the main window from tray.
+     * Restore the main window from tray. Also leaves overlay mode, so the
+     * tray icon always brings back a window that takes clicks.
      */
     restoreFromTray(): void {
+        this.setOverlayMode(false);
         this.mainWindow.restoreFromTray();
     }
 
@@ -198,6 +500,14 @@ export default class WindowManager extends EventEmitter {
         this.mainWindow.setQuitting(state);
     }
 
+    /**
+     * Set whether closing the main window hides it to the tray instead of closing it.
+     * @param enabled - Whether to close to tray
+     */
+    setCloseToTray(enabled: boolean): void {
+        this.mainWindow.setCloseToTray(enabled);
+    }
+
     /**
      * Set the always-on-top state for the main window.
      * @param enabled - Whether to enable always-on-top
@@ -206,6 +516,18 @@ export default class WindowManager extends EventEmitter {
         this.mainWindow.setAlwaysOnTop(enabled);
     }
 
+    /**
+     * Set the extra hosts the Gemini view may navigate to.
+     * @param hosts - Parsed allow-list
+     */
+    setAllowedHosts(hosts: string[]): void {
+        this._allowedHosts = hosts;
+        this.mainWindow.setAllowedHosts(hosts);
+        for (const mainWindow of this._additionalWindows.values()) {
+            mainWindow.setAllowedHosts(hosts);
+        }
+    }
+
     /**
      * Get the current always-on-top state.
      * @returns True if always-on-top is enabled
@@ -214,6 +536,696 @@ export default class WindowManager extends EventEmitter {
         return this.mainWindow.isAlwaysOnTop();
     }
 
+    /**
+     * Enter or leave immersive mode: the focused main window goes fullscreen
+     * and the renderer hides the titlebar so Gemini fills the screen. Esc or
+     * F11 leaves immersive mode.
+     * @param enabled - Whether to enable immersive mode
+     */
+    setImmersiveMode(enabled: boolean): void {
+        const win = enabled ? this.getMainWindow() : this._immersiveWindow;
+        if (!win || win.isDestroyed() || enabled === this.isImmersiveMode()) {
+            return;
+        }
+
+        this._immersiveWindow = enabled ? win : null;
+        win.setFullScreen(enabled);
+        logger.log(`Immersive mode ${enabled ? 'entered' : 'left'}`);
+        this.emit('immersive-mode-changed', enabled);
+    }
+
+    /**
+     * Toggle immersive mode.
+     */
+    toggleImmersiveMode(): void {
+        this.setImmersiveMode(!this.isImmersiveMode());
+    }
+
+    /**
+     * Get whether immersive mode is on.
+     * @returns True if the titlebar is hidden and the window is fullscreen
+     */
+    isImmersiveMode(): boolean {
+        return this._immersiveWindow !== null;
+    }
+
+    /**
+     * Leave immersive mode on Esc or F11, and otherwise toggle fullscreen on
+     * F11 (Windows/Linux; macOS uses the native shortcut). Handled here rather
+     * than in the renderer so the keys work while focus is inside the Gemini frame.
+     * @private
+     */
+    private _handleFullScreenKey(win: BrowserWindow, event: Event, input: Input): void {
+        if (input.type !== 'keyDown') return;
+        if (this.isImmersiveMode()) {
+            if (input.key === 'Escape' || input.key === 'F11') {
+                event.preventDefault();
+                this.setImmersiveMode(false);
+            }
+            return;
+        }
+
+        if (input.key === 'F11' && !isMacOS && !input.control && !input.alt && !input.shift && !input.meta) {
+            event.preventDefault();
+            this.toggleFullScreen(win);
+        }
+    }
+
+    /**
+     * Toggle fullscreen, keeping the titlebar. Leaves immersive or compact
+     * mode instead when the window is in one.
+     * @param win - Main window; defaults to the focused one
+     */
+    toggleFullScreen(win = this.getMainWindow()): void {
+        if (!win || win.isDestroyed()) return;
+
+        if (this._immersiveWindow === win) {
+            this.setImmersiveMode(false);
+            return;
+        }
+        if (this._compactWindows.has(win)) {
+            this.setCompactMode(false, win);
+        }
+        win.setFullScreen(!win.isFullScreen());
+    }
+
+    /**
+     * Get whether a window is fullscreen.
+     * @param win - Main window; defaults to the focused one
+     * @returns True if the window is fullscreen
+     */
+    isFullScreen(win = this.getMainWindow()): boolean {
+        return !!win && !win.isDestroyed() && win.isFullScreen();
+    }
+
+    /**
+     * Get the height of the main window titlebar.
+     * @returns Height in logical pixels
+     */
+    getTitlebarHeight(): number {
+        return this._titlebarHeight;
+    }
+
+    /**
+     * Set the height of the main window titlebar. The renderer lays out the
+     * titlebar and Gemini view from this value, so open windows update live.
+     * @param height - Height from MIN_TITLEBAR_HEIGHT to MAX_TITLEBAR_HEIGHT logical pixels
+     * @returns False if the value is not a number in range
+     */
+    setTitlebarHeight(height: number): boolean {
+        if (
+            typeof height !== 'number' ||
+            !Number.isInteger(height) ||
+            height < MIN_TITLEBAR_HEIGHT ||
+            height > MAX_TITLEBAR_HEIGHT
+        ) {
+            logger.warn(`Invalid titlebar height: ${height}`);
+            return false;
+        }
+        if (height === this._titlebarHeight) return true;
+
+        this._titlebarHeight = height;
+        for (const win of this.getMainWindows()) {
+            if (!win.isDestroyed()) this._applyTitlebarHeight(win);
+        }
+        logger.log(`Titlebar height set to: ${height}px`);
+        this.emit('titlebar-height-changed', height);
+        return true;
+    }
+
+    /**
+     * Keep the macOS traffic lights vertically centered in the titlebar.
+     * @private
+     */
+    private _applyTitlebarHeight(win: BrowserWindow): void {
+        if (!isMacOS) return;
+        win.setWindowButtonPosition(
+            this._titlebarHeight === DEFAULT_TITLEBAR_HEIGHT
+                ? null
+                : { x: TRAFFIC_LIGHT_X, y: Math.round((this._titlebarHeight - TRAFFIC_LIGHT_HEIGHT) / 2) }
+        );
+    }
+
+    /**
+     * Get the titlebar effects this platform supports.
+     * @returns Supported effects, always including 'none'
+     */
+    getSupportedTitlebarEffects(): TitlebarEffect[] {
+        return getSupportedTitlebarEffects(process.platform, release());
+    }
+
+    /**
+     * Get the titlebar effect applied to the main windows.
+     * @returns The chosen effect, or 'none' if this platform does not support it
+     */
+    getTitlebarEffect(): TitlebarEffect {
+        return this.getSupportedTitlebarEffects().includes(this._titlebarEffect) ? this._titlebarEffect : 'none';
+    }
+
+    /**
+     * Set the native material shown behind the titlebar of every main window.
+     * The choice is kept even where it is unsupported, so a settings file
+     * shared between machines keeps it, and falls back to 'none' here.
+     * @param effect - One of TITLEBAR_EFFECTS
+     * @returns False if the value is not a known effect
+     */
+    setTitlebarEffect(effect: TitlebarEffect): boolean {
+        if (!TITLEBAR_EFFECTS.includes(effect)) {
+            logger.warn(`Invalid titlebar effect: ${effect}`);
+            return false;
+        }
+        if (effect === this._titlebarEffect) return true;
+
+        const previous = this.getTitlebarEffect();
+        this._titlebarEffect = effect;
+        const applied = this.getTitlebarEffect();
+        if (applied !== previous) {
+            for (const win of this.getMainWindows()) {
+                if (!win.isDestroyed()) applyTitlebarEffect(win, applied);
+            }
+        }
+        logger.log(`Titlebar effect set to: ${effect}${applied === effect ? '' : ' (unsupported, using none)'}`);
+        this.emit('titlebar-effect-changed', effect);
+        return true;
+    }
+
+    /**
+     * Whether window opacity (and so overlay mode) works on this platform.
+     * Electron ignores setOpacity on Linux.
+     * @returns True on Windows and macOS
+     */
+    isWindowOpacitySupported(): boolean {
+        return !isLinux;
+    }
+
+    /**
+     * Get the opacity of the main windows.
+     * @returns Opacity from MIN_WINDOW_OPACITY to 1
+     */
+    getWindowOpacity(): number {
+        return this._windowOpacity;
+    }
+
+    /**
+     * Set the opacity of every main window. Values are clamped so the window
+     * never becomes invisible.
+     * @param opacity - Opacity from MIN_WINDOW_OPACITY to 1
+     */
+    setWindowOpacity(opacity: number): void {
+        if (typeof opacity !== 'number' || !Number.isFinite(opacity)) {
+            logger.warn(`Invalid window opacity: ${opacity}`);
+            return;
+        }
+
+        const clamped = Math.min(1, Math.max(MIN_WINDOW_OPACITY, opacity));
+        if (clamped === this._windowOpacity) return;
+
+        this._windowOpacity = clamped;
+        for (const win of this.getMainWindows()) {
+            if (!win.isDestroyed()) win.setOpacity(this._getOpacityFor(win));
+        }
+        logger.log(`Window opacity set to: ${clamped}`);
+        this.emit('window-opacity-changed', clamped);
+    }
+
+    /**
+     * Opacity to apply to a main window: overlay mode is never fully opaque.
+     * @private
+     */
+    private _getOpacityFor(win: BrowserWindow): number {
+        return this._overlayWindow === win ? Math.min(this._windowOpacity, OVERLAY_MODE_OPACITY) : this._windowOpacity;
+    }
+
+    /**
+     * Enter or leave overlay mode: the focused main window turns translucent,
+     * stays on top and lets mouse clicks through to the apps beneath, while
+     * keeping keyboard focus so you can keep typing into Gemini. Leave it with
+     * the shortcut or by clicking the tray icon.
+     * @param enabled - Whether to enable overlay mode
+     */
+    setOverlayMode(enabled: boolean): void {
+        const win = enabled ? this.getMainWindow() : this._overlayWindow;
+        if (!win || win.isDestroyed() || enabled === this.isOverlayMode()) {
+            return;
+        }
+        if (enabled && !this.isWindowOpacitySupported()) {
+            logger.warn('Overlay mode is not supported on this platform');
+            return;
+        }
+
+        if (enabled) {
+            this._overlayWasAlwaysOnTop = win.isAlwaysOnTop();
+            this._overlayWindow = win;
+            // forward keeps hover effects working under the cursor (Windows/macOS)
+            win.setIgnoreMouseEvents(true, { forward: true });
+            win.setAlwaysOnTop(true, 'floating');
+        } else {
+            this._overlayWindow = null;
+            win.setIgnoreMouseEvents(false);
+            win.setAlwaysOnTop(this._overlayWasAlwaysOnTop);
+        }
+        win.setOpacity(this._getOpacityFor(win));
+        logger.log(`Overlay mode ${enabled ? 'entered' : 'left'}`);
+        this.emit('overlay-mode-changed', enabled);
+    }
+
+    /**
+     * Toggle overlay mode.
+     */
+    toggleOverlayMode(): void {
+        this.setOverlayMode(!this.isOverlayMode());
+    }
+
+    /**
+     * Get whether overlay mode is on.
+     * @returns True if a main window is translucent and click-through
+     */
+    isOverlayMode(): boolean {
+        return this._overlayWindow !== null;
+    }
+
+    /**
+     * Enter or leave compact mode: the window becomes a narrow, full-height
+     * strip at the right edge of its monitor, optionally without Gemini's
+     * sidebar. Leaving restores the previous size, position and maximized state.
+     * @param enabled - Whether to enable compact mode
+     * @param win - Main window; defaults to the focused one
+     */
+    setCompactMode(enabled: boolean, win = this.getMainWindow()): void {
+        if (!win || win.isDestroyed() || enabled === this._compactWindows.has(win)) {
+            return;
+        }
+
+        if (enabled) {
+            if (win.isFullScreen() || this._immersiveWindow === win) {
+                logger.warn('Cannot enter compact mode: window is fullscreen');
+                return;
+            }
+            const bounds = win.getNormalBounds();
+            this._compactWindows.set(win, { bounds, isMaximized: win.isMaximized() });
+            if (win.isMaximized()) win.unmaximize();
+            win.setBounds(getCompactModeBounds(screen.getDisplayMatching(bounds).workArea));
+        } else {
+            const saved = this._compactWindows.get(win)!;
+            this._compactWindows.delete(win);
+            win.setBounds(saved.bounds);
+            if (saved.isMaximized) win.maximize();
+        }
+
+        this._applyCompactStyles(win, enabled);
+        logger.log(`Compact mode ${enabled ? 'entered' : 'left'}`);
+        this.emit('compact-mode-changed', enabled, win);
+    }
+
+    /**
+     * Toggle compact mode.
+     * @param win - Main window; defaults to the focused one
+     */
+    toggleCompactMode(win = this.getMainWindow()): void {
+        if (win) this.setCompactMode(!this._compactWindows.has(win), win);
+    }
+
+    /**
+     * Get whether a window is in compact mode.
+     * @param win - Main window; defaults to the focused one
+     * @returns True if the window is in compact mode
+     */
+    isCompactMode(win = this.getMainWindow()): boolean {
+        return win !== null && this._compactWindows.has(win);
+    }
+
+    /**
+     * Set whether compact mode hides Gemini's sidebar.
+     * @param enabled - Whether to hide the sidebar
+     */
+    setCompactModeHidesSidebar(enabled: boolean): void {
+        this._compactModeHidesSidebar = enabled;
+        for (const win of this._compactWindows.keys()) {
+            this._applyCompactStyles(win, true);
+        }
+    }
+
+    /**
+     * Add or remove the compact mode CSS in a window's Gemini frame.
+     * @private
+     */
+    private _applyCompactStyles(win: BrowserWindow, enabled: boolean): void {
+        const frame = win.isDestroyed() ? null : findGeminiFrame(win.webContents);
+        if (!frame) return;
+
+        const css = enabled ? buildCompactModeCss(this._compactModeHidesSidebar) : '';
+        frame.executeJavaScript(buildUserStylesScript(css, COMPACT_MODE_SHEET_NAME)).catch((error) => {
+            logger.error('Failed to apply compact mode styles:', error);
+        });
+    }
+
+    /**
+     * List the connected displays, marking the one a main window is on.
+     * @param win - Main window; defaults to the focused one
+     * @returns Displays in the order used by moveToDisplay
+     */
+    getDisplays(win = this.getMainWindow()): DisplayInfo[] {
+        const primaryId = screen.getPrimaryDisplay().id;
+        const currentId = win && !win.isDestroyed() ? screen.getDisplayMatching(win.getBounds()).id : null;
+        return screen.getAllDisplays().map((display, index) => ({
+            index,
+            label: `Display ${index + 1} (${display.size.width}×${display.size.height})`,
+            workArea: { ...display.workArea },
+            scaleFactor: display.scaleFactor,
+            primary: display.id === primaryId,
+            current: display.id === currentId,
+        }));
+    }
+
+    /**
+     * Move a main window to another display, centered in its work area.
+     * Maximized and compact windows stay maximized or compact on the new display.
+     * @param index - Display index, as listed by getDisplays
+     * @param win - Main window; defaults to the focused one
+     * @returns False if the display or window is unavailable, or the window is fullscreen
+     */
+    moveToDisplay(index: number, win = this.getMainWindow()): boolean {
+        const target = screen.getAllDisplays()[index];
+        if (!target) {
+            logger.warn(`Cannot move window: no display ${index}`);
+            return false;
+        }
+        if (!win || win.isDestroyed()) {
+            logger.warn('Cannot move window: main window unavailable');
+            return false;
+        }
+        if (win.isFullScreen() || this._immersiveWindow === win) {
+            logger.warn('Cannot move window: window is fullscreen');
+            return false;
+        }
+
+        const current = screen.getDisplayMatching(win.getBounds());
+        if (current.id === target.id) return true;
+
+        const compact = this._compactWindows.get(win);
+        if (compact) {
+            compact.bounds = centerInWorkArea(compact.bounds, target.workArea);
+            this._setBoundsAcrossDisplays(win, getCompactModeBounds(target.workArea));
+        } else {
+            const wasMaximized = win.isMaximized();
+            if (wasMaximized) win.unmaximize();
+            const bounds = centerInWorkArea(win.getNormalBounds(), target.workArea);
+            this._setBoundsAcrossDisplays(win, bounds);
+            if (wasMaximized) win.maximize();
+        }
+
+        logger.log(`Moved window to display ${index + 1}`);
+        return true;
+    }
+
+    /**
+     * Move a main window to the next display, wrapping around to the first.
+     * @param win - Main window; defaults to the focused one
+     */
+    moveToNextDisplay(win = this.getMainWindow()): void {
+        if (!win || win.isDestroyed()) return;
+        const displays = screen.getAllDisplays();
+        if (displays.length < 2) return;
+
+        const currentId = screen.getDisplayMatching(win.getBounds()).id;
+        const currentIndex = displays.findIndex((display) => display.id === currentId);
+        this.moveToDisplay((currentIndex + 1) % displays.length, win);
+    }
+
+    /**
+     * Set a window's bounds, possibly on another display. Windows resizes a window by the old
+     * scale factor when it crosses to a monitor with a different one, so the
+     * bounds are applied again once the window is on the new monitor.
+     * @private
+     */
+    private _setBoundsAcrossDisplays(win: BrowserWindow, bounds: Rectangle): void {
+        const from = screen.getDisplayMatching(win.getBounds());
+        win.setBounds(bounds);
+        if (screen.getDisplayMatching(bounds).scaleFactor !== from.scaleFactor) {
+            win.setBounds(bounds);
+        }
+    }
+
+    /**
+     * Reload the embedded Gemini view.
+     *
+     * A normal reload reloads only the Gemini frame so the app shell (toasts,
+     * overlays) keeps its state. Electron can only bypass the HTTP cache for a
+     * whole page, so a hard reload reloads the shell together with Gemini.
+     * Falls back to reloading the window when the Gemini frame is not loaded.
+     *
+     * @param ignoreCache - Bypass the HTTP cache (hard reload)
+     */
+    reloadGemini(ignoreCache = false): void {
+        const win = this.getMainWindow();
+        if (!win || win.isDestroyed()) {
+            logger.warn('Cannot reload Gemini: main window unavailable');
+            return;
+        }
+
+        if (ignoreCache) {
+            win.webContents.reloadIgnoringCache();
+            logger.log('Hard reloaded main window');
+            return;
+        }
+
+        const frame = findGeminiFrame(win.webContents);
+        if (frame) {
+            frame.reload();
+            logger.log('Reloaded Gemini frame');
+        } else {
+            win.webContents.reload();
+            logger.log('Gemini frame not found, reloaded main window');
+        }
+    }
+
+    /**
+     * Get whether the Gemini view can navigate back or forward.
+     *
+     * Gemini's in-app routing happens inside the iframe, which shares the
+     * window's session history, so the window history reflects Gemini's.
+     */
+    getGeminiNavigationState(): GeminiNavigationState {
+        const win = this.getMainWindow();
+        if (!win || win.isDestroyed()) {
+            return { canGoBack: false, canGoForward: false };
+        }
+
+        const history = win.webContents.navigationHistory;
+        return { canGoBack: history.canGoBack(), canGoForward: history.canGoForward() };
+    }
+
+    /**
+     * Get the Gemini view's URL, title and conversation.
+     *
+     * The title is the Gemini frame's document title, which Gemini sets to
+     * the conversation title once a conversation has loaded.
+     */
+    async getGeminiLocation(): Promise<GeminiLocation> {
+        const win = this.getMainWindow();
+        const frame = win && !win.isDestroyed() ? findGeminiFrame(win.webContents) : null;
+        if (!frame) {
+            return { url: '', title: '', conversationId: null };
+        }
+
+        const url = frame.url;
+        const title: unknown = await frame.executeJavaScript('document.title').catch(() => '');
+        return {
+            url,
+            title: typeof title === 'string' ? title.trim() : '',
+            conversationId: getGeminiConversationId(url) ?? null,
+        };
+    }
+
+    /**
+     * Navigate the Gemini view back one history entry.
+     */
+    goBackGemini(): void {
+        const win = this.getMainWindow();
+        if (!win || win.isDestroyed() || !win.webContents.navigationHistory.canGoBack()) {
+            return;
+        }
+        win.webContents.navigationHistory.goBack();
+    }
+
+    /**
+     * Navigate the Gemini view forward one history entry.
+     */
+    goForwardGemini(): void {
+        const win = this.getMainWindow();
+        if (!win || win.isDestroyed() || !win.webContents.navigationHistory.canGoForward()) {
+            return;
+        }
+        win.webContents.navigationHistory.goForward();
+    }
+
+    /**
+     * Navigate the Gemini view to the Gemini home page (a new chat).
+     * Falls back to reloading the window when the Gemini frame is not loaded,
+     * since the shell loads Gemini's home page on startup.
+     */
+    goHomeGemini(): void {
+        const win = this.getMainWindow();
+        if (!win || win.isDestroyed()) {
+            logger.warn('Cannot navigate Gemini home: main window unavailable');
+            return;
+        }
+
+        const frame = findGeminiFrame(win.webContents);
+        if (!frame) {
+            win.webContents.reload();
+            logger.log('Gemini frame not found, reloaded main window');
+            return;
+        }
+
+        frame.executeJavaScript(`window.location.assign(${JSON.stringify(GEMINI_APP_URL)})`).catch((error) => {
+            logger.error('Failed to navigate Gemini home:', error);
+        });
+    }
+
+    /**
+     * Navigate the Gemini view to a Gemini URL, e.g. a conversation.
+     *
+     * @param url - Gemini URL; callers validate it
+     */
+    navigateGemini(url: string): void {
+        const win = this.getMainWindow();
+        const frame = win && !win.isDestroyed() ? findGeminiFrame(win.webContents) : null;
+        if (!frame) {
+            logger.warn('Cannot navigate Gemini: Gemini frame not loaded');
+            return;
+        }
+
+        frame.executeJavaScript(`window.location.assign(${JSON.stringify(url)})`).catch((error) => {
+            logger.error('Failed to navigate Gemini:', error);
+        });
+    }
+
+    /**
+     * Open the native print dialog for the Gemini conversation.
+     *
+     * Printing the window would print the app shell around Gemini, so the
+     * print is started from inside the Gemini frame, which prints only its
+     * document. The OS print dialog also offers saving as PDF.
+     */
+    printGemini(): void {
+        const win = this.getMainWindow();
+        if (!win || win.isDestroyed()) {
+            logger.warn('Cannot print Gemini: main window unavailable');
+            return;
+        }
+
+        const frame = findGeminiFrame(win.webContents);
+        if (!frame) {
+            logger.warn('Cannot print Gemini: Gemini frame not loaded');
+            return;
+        }
+
+        // Chromium only opens the print dialog for a user gesture
+        frame.executeJavaScript('window.print()', true).catch((error) => {
+            logger.error('Failed to print Gemini:', error);
+        });
+    }
+
+    /**
+     * Apply the user stylesheet (user.css in the data directory) to the Gemini frame.
+     * The file is read on every call, so edits apply on the next load or reload.
+     * User styles are a customization, so they are skipped in safe mode.
+     *
+     * @param win - Main window to apply the styles to; defaults to the focused one
+     * @returns Whether the styles were applied
+     */
+    async applyUserStyles(win = this.getMainWindow()): Promise<boolean> {
+        if (isSafeMode()) return false;
+
+        const frame = win && !win.isDestroyed() ? findGeminiFrame(win.webContents) : null;
+        if (!frame) return false;
+
+        const css = await readUserStyles();
+        await frame.executeJavaScript(buildUserStylesScript(css));
+        return true;
+    }
+
+    /**
+     * Re-read the user stylesheet and apply it without reloading Gemini.
+     */
+    async reloadUserStyles(): Promise<void> {
+        const win = this.getMainWindow();
+        if (!win || win.isDestroyed()) {
+            logger.warn('Cannot reload user styles: main window unavailable');
+            return;
+        }
+
+        if (isSafeMode()) {
+            showToast(win, { type: 'info', message: 'User styles are disabled in safe mode' });
+            return;
+        }
+
+        try {
+            if (await this.applyUserStyles()) {
+                showToast(win, { type: 'success', message: 'User styles reloaded' });
+            } else {
+                showToast(win, { type: 'error', message: 'Cannot reload user styles: Gemini is not loaded' });
+            }
+        } catch (error) {
+            logger.error('Failed to reload user styles:', error);
+            showToast(win, { type: 'error', message: `Failed to reload user styles: ${(error as Error).message}` });
+        }
+    }
+
+    /**
+     * Toggle developer tools targeting the Gemini frame.
+     *
+     * The window's own DevTools open on the React shell. Inspecting a point
+     * inside the iframe instead attaches the Elements panel and console to
+     * Gemini's document, which is where injected scripts and export selectors run.
+     */
+    toggleGeminiDevTools(): void {
+        const win = this.getMainWindow();
+        if (!win || win.isDestroyed()) {
+            logger.warn('Cannot open Gemini DevTools: main window unavailable');
+            return;
+        }
+
+        if (win.webContents.isDevToolsOpened()) {
+            win.webContents.closeDevTools();
+            return;
+        }
+
+        if (!findGeminiFrame(win.webContents)) {
+            logger.warn('Cannot open Gemini DevTools: Gemini frame not loaded');
+            return;
+        }
+
+        // The Gemini iframe fills the content area below the titlebar, so its centre is inside Gemini
+        const [width, height] = win.getContentSize();
+        win.webContents.inspectElement(Math.round(width / 2), Math.round(height / 2));
+        logger.log('Opened DevTools for the Gemini frame');
+    }
+
+    /**
+     * Emit the current Gemini navigation state.
+     * @private
+     */
+    private _emitGeminiNavigationChanged(): void {
+        this.emit('gemini-navigation-changed', this.getGeminiNavigationState());
+    }
+
+    /**
+     * Emit the current Gemini location if it changed since the last event.
+     * @private
+     */
+    private _emitGeminiLocationChanged(): void {
+        this.getGeminiLocation()
+            .then((location) => {
+                const key = JSON.stringify(location);
+                if (key === this._lastGeminiLocation) return;
+                this._lastGeminiLocation = key;
+                this.emit('gemini-location-changed', location);
+            })
+            .catch((error) => logger.error('Failed to get Gemini location:', error));
+    }
+
     /**
      * Get the current zoom level percentage.
      * @returns The zoom level as a percentage (e.g., 100 for 100%)
@@ -265,25 +1277,29 @@ export default class WindowManager extends EventEmitter {
     }
 
     /**
-     * Apply the current zoom level to the main window's webContents.
-     * Silently returns if window is unavailable.
+     * Apply the current zoom level to a main window's webContents, or to all
+     * main windows. Silently returns if no window is available.
+     * @param win - Main window to zoom; defaults to all main windows
      * @private
      */
-    private _applyZoomToWindow(): void {
-        const win = this.getMainWindow();
-        if (!win || win.isDestroyed()) {
+    private _applyZoomToWindow(win?: BrowserWindow): void {
+        const windows = win ? [win] : this.getMainWindows();
+        if (windows.length === 0 || windows.some((target) => target.isDestroyed())) {
             logger.warn('Cannot apply zoom: main window unavailable');
             return;
         }
 
-        try {
-            // WebContents.setZoomFactor uses a multiplier (1.0 = 100%)
-            const zoomFactor = this._zoomLevel / 100;
-            win.webContents.setZoomFactor(zoomFactor);
-            logger.log(`Zoom applied: ${this._zoomLevel}% (factor: ${zoomFactor})`);
-        } catch (error) {
-            logger.error('Failed to apply zoom to window:', error);
+        // WebContents.setZoomFactor uses a multiplier (1.0 = 100%)
+        const zoomFactor = this._zoomLevel / 100;
+        for (const target of windows) {
+            try {
+                target.webContents.setZoomFactor(zoomFactor);
+            } catch (error) {
+                logger.error('Failed to apply zoom to window:', error);
+                return;
+            }
         }
+        logger.log(`Zoom applied: ${this._zoomLevel}% (factor: ${zoomFactor})`);
     }
 
     /**
@@ -354,6 +1370,13 @@ export default class WindowManager extends EventEmitter {
         // else: already at min, do nothing
     }
 
+    /**
+     * Reset zoom to 100%.
+     */
+    resetZoom(): void {
+        this.setZoomLevel(100);
+    }
+
     /**
      * Initialize zoom level from a stored value.
      * Called during app initialization to restore persisted zoom.
@@ -13,13 +13,33 @@
  * });
  * store.set('theme', 'dark');
  * const theme = store.get('theme'); // 'dark'
+ *
+ * Stores created with an `envPrefix` also read overrides from the environment
+ * (see utils/envOverrides). Precedence, highest first: environment variable,
+ * settings file, defaults. Safe mode ignores both the environment and the file.
+ *
+ * Stores created with a `history` option log every change to
+ * `<configName>.history.json`, and undo() restores the previous values.
+ * setMany() logs all of its changes as one entry, undone together.
  */
 
 import { app } from 'electron';
 import * as path from 'path';
 import * as fs from 'fs';
 import { createLogger } from './utils/logger';
+import { isDataDirReadOnly } from './utils/dataDirLock';
+import { isSafeMode } from './utils/safeMode';
+import {
+    validateSettings,
+    parseErrorToIssue,
+    formatValidationIssue,
+    type SettingsSchema,
+    type SettingsFieldSchema,
+    type SettingsValidationIssue,
+} from './utils/settingsSchema';
+import { readEnvOverrides } from './utils/envOverrides';
 import type { SettingsStoreOptions } from './types';
+import type { SettingsChange } from '../shared/types/settings-history';
 
 const logger = createLogger('[SettingsStore]');
 
@@ -81,7 +101,17 @@ export default class SettingsStore<T extends Record<string, unknown> = Record<st
     readonly _path: string;
     readonly _defaults: Partial<T>;
     readonly _fs: typeof fs;
+    readonly _schema: SettingsSchema | undefined;
     _data: T;
+    /** Problems found in the settings file on load */
+    _validationIssues: SettingsValidationIssue[] = [];
+    /** Values forced by environment variables; never persisted */
+    _envOverrides: Partial<T> = {};
+    /** Change log settings, when enabled */
+    readonly _historyOptions: SettingsStoreOptions['history'];
+    readonly _historyPath: string;
+    /** Logged changes, oldest first */
+    _history: SettingsChange[] = [];
 
     /**
      * Creates a new SettingsStore instance.
@@ -98,27 +128,48 @@ export default class SettingsStore<T extends Record<string, unknown> = Record<st
         this._defaults = (opts.defaults as Partial<T>) || {};
         /* v8 ignore next -- production fallback, tests always inject mock fs */
         this._fs = opts.fs || fs;
+        this._schema = opts.schema;
         this._data = this._loadData();
+        this._historyOptions = opts.history;
+        this._historyPath = path.join(userDataPath, opts.configName + '.history.json');
+        if (this._historyOptions) {
+            this._history = this._loadHistory();
+        }
+        if (opts.envPrefix) {
+            this._envOverrides = this._readEnvOverrides(opts.envPrefix);
+        }
 
         logger.log(`Initialized at: ${this._path}`);
     }
 
     /**
      * Load data from the settings file.
-     * Falls back to defaults if file doesn't exist or is corrupted.
+     * Falls back to defaults if file doesn't exist or is corrupted, and drops
+     * values that fail schema validation. Problems are kept in _validationIssues.
      * @private
      * @returns Parsed settings data or defaults
      */
     private _loadData(): T {
+        // Safe mode ignores saved settings entirely
+        if (isSafeMode()) {
+            logger.log('Safe mode: using default settings');
+            return { ...this._defaults } as T;
+        }
+
+        let fileContent = '';
         try {
-            const fileContent = this._fs.readFileSync(this._path, 'utf-8');
+            fileContent = this._fs.readFileSync(this._path, 'utf-8');
             const parsed = JSON.parse(fileContent) as T;
             logger.log('Loaded existing settings');
-            return deepMerge(this._defaults as T, parsed);
+            return deepMerge(this._defaults as T, this._dropInvalid(parsed, fileContent));
         } catch (error) {
             const err = error as NodeJS.ErrnoException;
             if (err.code === 'ENOENT') {
                 logger.log('No existing settings file, using defaults');
+            } else if (err instanceof SyntaxError) {
+                const issue = parseErrorToIssue(err, fileContent);
+                this._validationIssues = [issue];
+                logger.error(`Invalid settings file ${this._path}: ${formatValidationIssue(issue)}`);
             } else {
                 logger.error('Error reading settings file:', {
                     error: err.message,
@@ -130,12 +181,183 @@ export default class SettingsStore<T extends Record<string, unknown> = Record<st
         }
     }
 
+    /**
+     * Load the change log. A missing or unreadable log starts empty.
+     * @private
+     */
+    private _loadHistory(): SettingsChange[] {
+        if (isSafeMode()) return [];
+
+        try {
+            const parsed: unknown = JSON.parse(this._fs.readFileSync(this._historyPath, 'utf-8'));
+            if (!Array.isArray(parsed)) return [];
+            const entries = parsed.filter(
+                (entry): entry is SettingsChange =>
+                    typeof entry?.key === 'string' && typeof entry?.timestamp === 'number'
+            );
+            const history = entries.flatMap((entry) => this._withoutExcludedKeys(entry));
+            if (JSON.stringify(history) !== JSON.stringify(entries)) {
+                // Logged before the keys were excluded (e.g. secrets); drop them from disk too
+                this._saveHistory(history);
+            }
+            return history;
+        } catch (error) {
+            if ((error as NodeJS.ErrnoException).code !== 'ENOENT') {
+                logger.warn(`Ignoring unreadable settings history: ${(error as Error).message}`);
+            }
+            return [];
+        }
+    }
+
+    /**
+     * An entry with the changes to excluded keys removed; empty if none are left.
+     * @private
+     */
+    private _withoutExcludedKeys(entry: SettingsChange): SettingsChange[] {
+        const excluded = this._historyOptions?.excludeKeys ?? [];
+        const [first, ...rest] = [entry, ...(entry.batch ?? [])].filter((change) => !excluded.includes(change.key));
+        if (!first) return [];
+
+        const head: SettingsChange = { ...first };
+        delete head.batch;
+        return [rest.length > 0 ? { ...head, batch: rest } : head];
+    }
+
+    /**
+     * Write the change log on its own, when the settings themselves are unchanged.
+     * @private
+     */
+    private _saveHistory(history: SettingsChange[]): void {
+        if (isDataDirReadOnly()) return;
+
+        try {
+            this._fs.writeFileSync(this._historyPath, JSON.stringify(history, null, 2), 'utf-8');
+        } catch (error) {
+            logger.warn(`Failed to rewrite settings history: ${(error as Error).message}`);
+        }
+    }
+
+    /**
+     * Describe a change for the log, or null if history is off, the key is
+     * excluded or the value is unchanged. Call before updating _data.
+     * @private
+     */
+    private _describeChange(key: keyof T, value: unknown, timestamp: number): SettingsChange | null {
+        const options = this._historyOptions;
+        if (!options || options.excludeKeys?.includes(String(key))) return null;
+
+        const change: SettingsChange = { timestamp, key: String(key) };
+        if (key in this._data) change.previous = this._data[key];
+        if (value !== undefined) change.value = value;
+        if (JSON.stringify(change.previous) === JSON.stringify(change.value)) return null;
+        return change;
+    }
+
+    /**
+     * Add an entry to the log, dropping the oldest beyond the limit.
+     * @private
+     */
+    private _pushChange(change: SettingsChange): void {
+        this._history.push(change);
+        const limit = this._historyOptions?.limit ?? 0;
+        if (this._history.length > limit) {
+            this._history.splice(0, this._history.length - limit);
+        }
+    }
+
+    /**
+     * Log a change, unless history is off, the key is excluded or the value is unchanged.
+     * Call before updating _data.
+     * @private
+     */
+    private _recordChange(key: keyof T, value: unknown, timestamp: number): void {
+        const change = this._describeChange(key, value, timestamp);
+        if (change) this._pushChange(change);
+    }
+
+    /**
+     * Remove values that fail schema validation so their defaults are used.
+     * @private
+     */
+    private _dropInvalid(parsed: T, source: string): T {
+        if (!this._schema) return parsed;
+
+        this._validationIssues = validateSettings(parsed, this._schema, source);
+        if (this._validationIssues.length === 0) return parsed;
+
+        // Not an object at all: nothing to keep
+        if (this._validationIssues.some((issue) => issue.key === null)) return {} as T;
+
+        const valid = { ...parsed };
+        for (const issue of this._validationIssues) {
+            logger.warn(`Invalid setting in ${this._path}: ${formatValidationIssue(issue)}`);
+            delete valid[issue.key as keyof T];
+        }
+        return valid;
+    }
+
+    /**
+     * Read overrides for every known setting from the environment.
+     * The type of each setting comes from the schema, or else from its default.
+     * @private
+     */
+    private _readEnvOverrides(prefix: string): Partial<T> {
+        // Safe mode runs on defaults only, so a bad override can be recovered from too
+        if (isSafeMode()) {
+            logger.log('Safe mode: ignoring environment overrides');
+            return {};
+        }
+
+        const types: Record<string, SettingsFieldSchema['type']> = {};
+        for (const [key, value] of Object.entries(this._defaults)) {
+            const type = typeof value;
+            if (type === 'boolean' || type === 'number' || type === 'string') types[key] = type;
+        }
+        for (const [key, field] of Object.entries(this._schema ?? {})) {
+            types[key] = field.type;
+        }
+
+        const { overrides, invalid } = readEnvOverrides(types, process.env, prefix);
+        for (const name of invalid) {
+            logger.warn(`Ignoring invalid value for environment override ${name}`);
+        }
+        if (this._schema) {
+            for (const issue of validateSettings(overrides, this._schema)) {
+                logger.warn(`Ignoring environment override: ${issue.message}`);
+                delete overrides[issue.key as string];
+            }
+        }
+
+        const keys = Object.keys(overrides);
+        if (keys.length > 0) {
+            logger.log(`Settings overridden by environment: ${keys.join(', ')}`);
+        }
+        return overrides as Partial<T>;
+    }
+
+    /**
+     * Keys whose values are forced by environment variables.
+     * @returns Overridden setting keys
+     */
+    getOverriddenKeys(): (keyof T)[] {
+        return Object.keys(this._envOverrides) as (keyof T)[];
+    }
+
+    /**
+     * Problems found when loading the settings file (empty if it was valid).
+     * @returns Validation issues
+     */
+    getValidationIssues(): SettingsValidationIssue[] {
+        return [...this._validationIssues];
+    }
+
     /**
      * Get a setting value by key.
      * @param key - The setting key
      * @returns The setting value, or undefined if not found
      */
     get<K extends keyof T>(key: K): T[K] | undefined {
+        if (key in this._envOverrides) return this._envOverrides[key];
         return this._data ? this._data[key] : undefined;
     }
 
@@ -146,18 +368,58 @@ export default class SettingsStore<T extends Record<string, unknown> = Record<st
      * @returns True if save succeeded, false otherwise
      */
     set<K extends keyof T>(key: K, val: T[K]): boolean {
+        if (key in this._envOverrides) {
+            logger.warn(`${String(key)} is overridden by an environment variable; the saved value has no effect`);
+        }
+        this._recordChange(key, val, Date.now());
         this._data[key] = val;
         return this._saveData();
     }
 
+    /**
+     * Set several values, persist once and log them as one history entry.
+     * @param values - Settings to store
+     * @returns True if save succeeded, false otherwise
+     */
+    setMany(values: Partial<T>): boolean {
+        const now = Date.now();
+        const changes: SettingsChange[] = [];
+        for (const [key, val] of Object.entries(values) as [keyof T, T[keyof T]][]) {
+            if (key in this._envOverrides) {
+                logger.warn(`${String(key)} is overridden by an environment variable; the saved value has no effect`);
+            }
+            const change = this._describeChange(key, val, now);
+            if (change) changes.push(change);
+            this._data[key] = val;
+        }
+
+        const [first, ...rest] = changes;
+        if (first) this._pushChange(rest.length > 0 ? { ...first, batch: rest } : first);
+        return this._saveData();
+    }
+
     /**
      * Save current data to disk.
      * @private
      * @returns True if save succeeded, false otherwise
      */
     private _saveData(): boolean {
+        // Another instance owns the data directory; keep changes in memory only
+        if (isDataDirReadOnly()) {
+            logger.warn(`Data directory is read-only, not saving: ${this._path}`);
+            return false;
+        }
+
+        // Safe mode never touches the settings files
+        if (isSafeMode()) {
+            return false;
+        }
+
         try {
             this._fs.writeFileSync(this._path, JSON.stringify(this._data, null, 2), 'utf-8');
+            if (this._historyOptions) {
+                this._fs.writeFileSync(this._historyPath, JSON.stringify(this._history, null, 2), 'utf-8');
+            }
             return true;
         } catch (error) {
             const err = error as NodeJS.ErrnoException;
@@ -176,7 +438,48 @@ export default class SettingsStore<T extends Record<string, unknown> = Record<st
      * @returns All current settings
      */
     getAll(): T {
-        return { ...this._data };
+        return { ...this._data, ...this._envOverrides };
+    }
+
+    /**
+     * Restore the defaults for the given keys and persist once.
+     * Keys without a default are removed.
+     * @param keys - Setting keys to reset
+     * @returns True if save succeeded, false otherwise
+     */
+    resetKeys(keys: (keyof T)[]): boolean {
+        const now = Date.now();
+        for (const key of keys) {
+            this._recordChange(key, this._defaults[key], now);
+            if (key in this._defaults) {
+                this._data[key] = this._defaults[key] as T[keyof T];
+            } else {
+                delete this._data[key];
+            }
+        }
+        return this._saveData();
+    }
+
+    /**
+     * Write a timestamped copy of the current settings next to the settings file.
+     * @returns Path of the backup file, or null if it could not be written
+     */
+    backup(): string | null {
+        if (isDataDirReadOnly() || isSafeMode()) return null;
+
+        const stamp = new Date().toISOString().replace(/[:.]/g, '-');
+        const backupPath = this._path.replace(/\.json$/, `.backup-${stamp}.json`);
+        try {
+            this._fs.writeFileSync(backupPath, JSON.stringify(this._data, null, 2), 'utf-8');
+            logger.log(`Settings backed up to: ${backupPath}`);
+            return backupPath;
+        } catch (error) {
+            logger.error('Failed to back up settings:', {
+                error: (error as Error).message,
+                path: backupPath,
+            });
+            return null;
+        }
     }
 
     /**
@@ -184,7 +487,41 @@ export default class SettingsStore<T extends Record<string, unknown> = Record<st
      * @returns True if save succeeded, false otherwise
      */
     reset(): boolean {
+        const now = Date.now();
+        for (const key of new Set([...Object.keys(this._data), ...Object.keys(this._defaults)])) {
+            this._recordChange(key as keyof T, this._defaults[key as keyof T], now);
+        }
         this._data = { ...this._defaults } as T;
         return this._saveData();
     }
+
+    /**
+     * Logged changes, oldest first (empty unless the store keeps history).
+     * @returns Copy of the change log
+     */
+    getHistory(): SettingsChange[] {
+        return [...this._history];
+    }
+
+    /**
+     * Undo the most recent changes, restoring their previous values, and persist.
+     * Undone changes are removed from the log.
+     * @param count - Number of changes to undo
+     * @returns The undone changes, newest first, and whether the save succeeded
+     */
+    undo(count = 1): { undone: SettingsChange[]; saved: boolean } {
+        const undone = this._history.splice(Math.max(0, this._history.length - count)).reverse();
+        if (undone.length === 0) return { undone, saved: true };
+
+        for (const change of undone.flatMap((entry) => [entry, ...(entry.batch ?? [])])) {
+            const key = change.key as keyof T;
+            if ('previous' in change) {
+                this._data[key] = change.previous as T[keyof T];
+            } else {
+                delete this._data[key];
+            }
+        }
+        logger.log(`Undid ${undone.length} settings change(s): ${undone.map((change) => change.key).join(', ')}`);
+        return { undone, saved: this._saveData() };
+    }
 }
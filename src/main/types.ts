@@ -62,6 +62,17 @@ export interface SettingsStoreOptions {
     defaults?: Record<string, unknown>;
     /** File system module (for testing) */
     fs?: typeof import('fs');
+    /** Schema to validate the settings file against on load */
+    schema?: import('./utils/settingsSchema').SettingsSchema;
+    /** Read overrides from environment variables with this prefix (see utils/envOverrides) */
+    envPrefix?: string;
+    /** Keep a log of changes in `<configName>.history.json` so they can be undone */
+    history?: {
+        /** Most changes kept; older ones are dropped */
+        limit: number;
+        /** Keys that are not logged: state rather than user choices, and secrets */
+        excludeKeys?: string[];
+    };
 }
 
 /**
@@ -34,6 +34,12 @@ export const mockLogger = {
  */
 export const createLogger = vi.fn().mockReturnValue(mockLogger);
 
+/**
+ * Mock log level controls.
+ */
+export const setLogLevel = vi.fn().mockReturnValue(true);
+export const getLogLevel = vi.fn().mockReturnValue('');
+
 /**
  * Resets all mock functions. Call in beforeEach if needed.
  */
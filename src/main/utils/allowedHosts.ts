@@ -0,0 +1,42 @@
+/**
+ * Navigation allow-list for the Gemini view.
+ *
+ * Links that would navigate the Gemini iframe away from Gemini (e.g. citation
+ * links) open in the system browser instead. Gemini and Google sign-in are
+ * always allowed; the user can allow more sites in Options, as one host per
+ * line. A host also allows its subdomains.
+ *
+ * @module allowedHosts
+ */
+
+/** Default allow-list: Google's cookie consent page, shown before Gemini in some regions */
+export const DEFAULT_ALLOWED_HOSTS = 'consent.google.com';
+
+/** Hostnames accepted in the allow-list */
+const HOSTNAME_PATTERN = /^([a-z0-9-]+\.)+[a-z0-9-]+$/;
+
+/**
+ * Parse the stored allow-list into hostnames.
+ * Entries may be separated by newlines, commas or spaces; invalid entries are dropped.
+ *
+ * @param value - Stored allow-list
+ * @returns Lowercase hostnames without duplicates
+ */
+export function parseAllowedHosts(value: string): string[] {
+    const hosts = value
+        .split(/[\s,]+/)
+        .map((host) => host.trim().toLowerCase())
+        .filter((host) => HOSTNAME_PATTERN.test(host));
+    return [...new Set(hosts)];
+}
+
+/**
+ * Check whether a hostname is on the allow-list, directly or as a subdomain.
+ *
+ * @param hostname - Hostname to check
+ * @param hosts - Parsed allow-list
+ */
+export function isAllowedHost(hostname: string, hosts: readonly string[]): boolean {
+    const host = hostname.toLowerCase();
+    return hosts.some((allowed) => host === allowed || host.endsWith(`.${allowed}`));
+}
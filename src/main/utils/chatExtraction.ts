@@ -2,6 +2,21 @@
  * Chat Extraction Script
  *
  * Injected into the Gemini iframe to extract the conversation as a structured JSON object.
+ *
+ * Answers carry the name of the model that wrote them (e.g. "2.5 Flash") when
+ * Gemini shows a model badge on the answer. Without a badge, only the latest
+ * answer is labelled, with the model currently selected in the model picker.
+ *
+ * The open Canvas document (Gemini's side panel for long-form documents and
+ * code) is not part of the conversation turns, so it is extracted separately,
+ * with the labels of its earlier versions when the version history is shown.
+ *
+ * A Deep Research report opens in a panel of its own, like a Canvas. It is
+ * extracted as one structured document with its list of sources, so exports can
+ * keep its headings, tables and citations instead of flattening it into a turn.
+ *
+ * Conversations with a Gem (a custom Gemini bot, served under /gem/) carry the
+ * Gem's name as shown in the conversation header.
  */
 
 export const CHAT_EXTRACTION_SCRIPT = `
@@ -14,6 +29,28 @@ export const CHAT_EXTRACTION_SCRIPT = `
             modelResponse: ['.model-response', 'model-response', '.markdown'],
             modelResponseContent: ['.message-content', '.markdown', '.model-response-text'],
             title: ['.conversation-title', 'span.conversation-title'],
+            modelBadge: ['[data-test-id="model-name"]', '.model-name', '.model-badge'],
+            modelPicker: [
+                '[data-test-id="bard-mode-menu-button"]',
+                'bard-mode-switcher button',
+                '.input-area-switch-label'
+            ],
+            canvas: ['immersive-panel', '.immersive-panel', 'immersive-editor'],
+            canvasTitle: ['[data-test-id="immersive-title"]', '.immersive-title', '.title-text'],
+            canvasContent: ['.ProseMirror', '.immersive-editor-content', '.markdown', '.view-lines'],
+            canvasVersions: ['[data-test-id="version-history-item"]', '.version-history-item'],
+            researchReport: [
+                'deep-research-immersive-panel',
+                '[data-test-id="deep-research-report"]',
+                '.deep-research-report'
+            ],
+            researchReportContent: ['.markdown', 'message-content', '.ProseMirror'],
+            researchSources: [
+                '[data-test-id="source-list"] a[href]',
+                'deep-research-source-list a[href]',
+                '.source-list a[href]'
+            ],
+            gemName: ['[data-test-id="bot-name"]', '.bot-name', '.gem-name'],
             codeBlocks: 'pre',
             tables: 'table'
         };
@@ -26,14 +63,16 @@ export const CHAT_EXTRACTION_SCRIPT = `
             return { elements: [], selector: null };
         };
 
-        const findFirstElement = (selList) => {
+        const findFirstElement = (selList, root = document) => {
             for (const sel of selList) {
-                const el = document.querySelector(sel);
+                const el = root.querySelector(sel);
                 if (el) return el;
             }
             return null;
         };
 
+        const labelOf = (el) => (el ? el.innerText.replace(/\s+/g, ' ').trim() : '');
+
         const { elements: turns, selector: turnSelector } = findElements(selectors.turns);
         console.log('[Extraction] Found turns:', turns.length, 'using selector:', turnSelector);
         
@@ -43,6 +82,7 @@ export const CHAT_EXTRACTION_SCRIPT = `
             let userText = '';
             let modelText = '';
             let modelHtml = '';
+            const modelName = labelOf(findFirstElement(selectors.modelBadge, turn));
 
             for (const sel of selectors.userQuery) {
                 const el = turn.querySelector(sel);
@@ -74,11 +114,45 @@ export const CHAT_EXTRACTION_SCRIPT = `
             }
 
             if (userText) conversation.push({ role: 'user', text: userText });
-            if (modelText) conversation.push({ role: 'model', text: modelText, html: modelHtml });
+            if (modelText) conversation.push({ role: 'model', text: modelText, html: modelHtml, modelName });
         });
 
+        const lastModelTurn = conversation.filter((turn) => turn.role === 'model').pop();
+        if (lastModelTurn && !lastModelTurn.modelName) {
+            lastModelTurn.modelName = labelOf(findFirstElement(selectors.modelPicker));
+        }
+
         console.log('[Extraction] Final conversation turns captured:', conversation.length);
 
+        let report = null;
+        const reportEl = findFirstElement(selectors.researchReport);
+        if (reportEl) {
+            const contentEl = findFirstElement(selectors.researchReportContent, reportEl) || reportEl;
+            const { elements: sourceEls } = findElements(selectors.researchSources);
+            report = {
+                title: labelOf(findFirstElement(selectors.canvasTitle, reportEl)),
+                text: contentEl.innerText.trim(),
+                html: contentEl.innerHTML,
+                sources: Array.from(sourceEls).map((a) => ({ title: labelOf(a), url: a.href }))
+            };
+        }
+
+        let canvas = null;
+        const canvasEl = findFirstElement(selectors.canvas);
+        // The report panel is an immersive panel too; it is not a Canvas
+        if (canvasEl && !(reportEl && (canvasEl.contains(reportEl) || reportEl.contains(canvasEl)))) {
+            const contentEl = findFirstElement(selectors.canvasContent, canvasEl) || canvasEl;
+            const { elements: versionEls } = findElements(selectors.canvasVersions);
+            canvas = {
+                title: labelOf(findFirstElement(selectors.canvasTitle, canvasEl)),
+                text: contentEl.innerText.trim(),
+                html: contentEl.innerHTML,
+                versions: Array.from(versionEls).map(labelOf).filter(Boolean)
+            };
+        }
+
+        const gem = window.location.pathname.startsWith('/gem/') ? labelOf(findFirstElement(selectors.gemName)) : '';
+
         // Try to find the conversation title from the DOM first
         const titleEl = findFirstElement(selectors.title);
         let extractedTitle = titleEl ? titleEl.innerText.trim() : '';
@@ -92,6 +166,9 @@ export const CHAT_EXTRACTION_SCRIPT = `
             title: extractedTitle || 'Untitled Conversation',
             timestamp: new Date().toISOString(),
             conversation,
+            canvas,
+            report,
+            gem,
             diagnostics: {
                 turnSelector,
                 totalTurns: turns.length,
@@ -0,0 +1,39 @@
+/**
+ * Compact mode.
+ *
+ * Compact mode turns a main window into a narrow, full-height strip at the
+ * right edge of its monitor, for keeping Gemini docked next to other work.
+ * Gemini's conversation sidebar can be hidden to leave the strip to the chat.
+ *
+ * @module compactMode
+ */
+
+import type { Rectangle } from 'electron';
+import { GEMINI_SIDEBAR_SELECTORS } from './geminiSelectors';
+
+/** Width of a window in compact mode, in pixels */
+export const COMPACT_MODE_WIDTH = 420;
+
+/** Window property holding the compact mode stylesheet in the Gemini frame */
+export const COMPACT_MODE_SHEET_NAME = '__geminiDesktopCompactStyles';
+
+/**
+ * Get the bounds of a compact window on a monitor.
+ *
+ * @param workArea - Work area of the window's monitor
+ * @returns A full-height strip at the right edge of the work area
+ */
+export function getCompactModeBounds(workArea: Rectangle): Rectangle {
+    const width = Math.min(COMPACT_MODE_WIDTH, workArea.width);
+    return { x: workArea.x + workArea.width - width, y: workArea.y, width, height: workArea.height };
+}
+
+/**
+ * Build the CSS added to the Gemini frame in compact mode.
+ *
+ * @param hideSidebar - Whether to hide Gemini's conversation sidebar
+ * @returns The CSS, empty when nothing is hidden
+ */
+export function buildCompactModeCss(hideSidebar: boolean): string {
+    return hideSidebar ? `${GEMINI_SIDEBAR_SELECTORS.join(',\n')} {\n    display: none !important;\n}\n` : '';
+}
@@ -0,0 +1,155 @@
+/**
+ * Confluence export helpers.
+ *
+ * Converts export HTML into Confluence storage format (XHTML plus Confluence
+ * macros) and publishes it through the Confluence REST API. A page with the
+ * same title in the target space is updated instead of duplicated. Requests
+ * go through the default session, so they use the configured upstream proxy.
+ *
+ * @module confluence
+ */
+
+import type { Session } from 'electron';
+import type { ConfluenceSettings } from '../../shared/types/confluence';
+
+/** How long a single Confluence request may take */
+export const CONFLUENCE_REQUEST_TIMEOUT_MS = 30000;
+
+/** Confluence space keys are letters, digits and a few symbols (personal spaces start with ~) */
+const SPACE_KEY_PATTERN = /^~?[A-Za-z0-9_-]+$/;
+
+/** HTML void elements, which must be self-closed in XHTML */
+const VOID_ELEMENT_PATTERN = /<(area|br|col|hr|img|input|wbr)\b([^>]*?)\s*\/?>/gi;
+
+/** A fenced code block as rendered by marked, with an optional language class */
+const CODE_BLOCK_PATTERN = /<pre>\s*<code(?:\s+class="language-([\w+#.-]+)")?>([\s\S]*?)<\/code>\s*<\/pre>/gi;
+
+/**
+ * Check Confluence settings.
+ * @param settings - Settings to check
+ * @param complete - Also require everything needed to publish
+ * @returns A description of the problem, or null if valid
+ */
+export function validateConfluenceSettings(settings: ConfluenceSettings, complete = false): string | null {
+    if (complete && !settings.baseUrl) return 'Confluence URL is not set';
+    if (settings.baseUrl) {
+        let url: URL;
+        try {
+            url = new URL(settings.baseUrl);
+        } catch {
+            return `Invalid Confluence URL: ${settings.baseUrl}`;
+        }
+        if (url.protocol !== 'https:') return 'Confluence URL must use https';
+    }
+
+    if (complete && !settings.spaceKey) return 'Confluence space key is not set';
+    if (settings.spaceKey && !SPACE_KEY_PATTERN.test(settings.spaceKey)) {
+        return `Invalid Confluence space key: ${settings.spaceKey}`;
+    }
+    if (settings.parentId && !/^\d+$/.test(settings.parentId)) {
+        return `Invalid Confluence parent page ID: ${settings.parentId}`;
+    }
+    if (complete && !settings.apiToken) return 'Confluence API token is not set';
+    return null;
+}
+
+/**
+ * Decode the entities marked uses when escaping code.
+ */
+function decodeEntities(text: string): string {
+    return text
+        .replace(/&lt;/g, '<')
+        .replace(/&gt;/g, '>')
+        .replace(/&quot;/g, '"')
+        .replace(/&#39;/g, "'")
+        .replace(/&amp;/g, '&');
+}
+
+/**
+ * Build a Confluence code macro. CDATA cannot contain "]]>", so that
+ * sequence is split across two CDATA sections.
+ */
+function buildCodeMacro(code: string, language?: string): string {
+    const parameter = language ? `<ac:parameter ac:name="language">${language}</ac:parameter>` : '';
+    const body = code.replace(/\]\]>/g, ']]]]><![CDATA[>');
+    return (
+        `<ac:structured-macro ac:name="code">${parameter}` +
+        `<ac:plain-text-body><![CDATA[${body}]]></ac:plain-text-body></ac:structured-macro>`
+    );
+}
+
+/**
+ * Convert HTML rendered by marked into Confluence storage format.
+ * Code blocks become code macros and void elements are self-closed.
+ */
+export function toConfluenceStorage(html: string): string {
+    // Code is still entity-escaped here, so markup fixes never touch it
+    return html
+        .replace(VOID_ELEMENT_PATTERN, '<$1$2 />')
+        .replace(/&nbsp;/g, '&#160;')
+        .replace(CODE_BLOCK_PATTERN, (_match, language: string | undefined, code: string) =>
+            buildCodeMacro(decodeEntities(code).replace(/\n$/, ''), language)
+        );
+}
+
+/**
+ * Authorization header: Basic auth with email and API token on Cloud,
+ * or a bearer personal access token on Server/Data Center.
+ */
+function getAuthorization(settings: ConfluenceSettings): string {
+    if (!settings.email) return `Bearer ${settings.apiToken}`;
+    return `Basic ${Buffer.from(`${settings.email}:${settings.apiToken}`).toString('base64')}`;
+}
+
+/**
+ * Create or update a Confluence page.
+ * @param targetSession - Session to send requests through
+ * @param settings - Connection and target settings (must be complete)
+ * @param title - Page title; an existing page with this title in the space is updated
+ * @param storage - Page body in storage format
+ * @returns URL of the published page
+ */
+export async function publishConfluencePage(
+    targetSession: Session,
+    settings: ConfluenceSettings,
+    title: string,
+    storage: string
+): Promise<string> {
+    const baseUrl = settings.baseUrl.replace(/\/+$/, '');
+    const api = `${baseUrl}/rest/api/content`;
+
+    const request = async (url: string, init: { method?: string; body?: string } = {}) => {
+        const response = await targetSession.fetch(url, {
+            ...init,
+            headers: {
+                Authorization: getAuthorization(settings),
+                Accept: 'application/json',
+                'Content-Type': 'application/json',
+            },
+            signal: AbortSignal.timeout(CONFLUENCE_REQUEST_TIMEOUT_MS),
+        });
+        if (!response.ok) {
+            throw new Error(`Confluence request failed: ${response.status} ${response.statusText}`);
+        }
+        return response.json();
+    };
+
+    const query = new URLSearchParams({ spaceKey: settings.spaceKey, title, expand: 'version' });
+    const existing = (await request(`${api}?${query}`)).results?.[0];
+
+    const page = {
+        type: 'page',
+        title,
+        space: { key: settings.spaceKey },
+        ...(settings.parentId ? { ancestors: [{ id: settings.parentId }] } : {}),
+        body: { storage: { value: storage, representation: 'storage' } },
+    };
+    const published = existing
+        ? await request(`${api}/${existing.id}`, {
+              method: 'PUT',
+              body: JSON.stringify({ ...page, id: existing.id, version: { number: existing.version.number + 1 } }),
+          })
+        : await request(api, { method: 'POST', body: JSON.stringify(page) });
+
+    return `${published._links?.base ?? baseUrl}${published._links?.webui ?? ''}`;
+}
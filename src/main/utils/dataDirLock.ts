@@ -0,0 +1,179 @@
+/**
+ * Data directory lock.
+ *
+ * Electron's single-instance lock only covers instances started by the same
+ * OS user. When two users (or a portable copy) point at the same data
+ * directory, both instances would write the same settings files. This module
+ * guards the directory with a lock file; the instance that fails to acquire it
+ * runs in read-only mode and never writes settings to disk.
+ *
+ * @module dataDirLock
+ */
+
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { createLogger } from './logger';
+
+const logger = createLogger('[DataDirLock]');
+
+/** Lock file name inside the data directory */
+export const DATA_DIR_LOCK_FILENAME = 'data-dir.lock';
+
+/**
+ * How long an empty or unreadable lock file is treated as held. The owner is
+ * written just after the file is created, so another instance starting at the
+ * same moment can read it before the owner is in it.
+ */
+const UNREADABLE_LOCK_GRACE_MS = 10_000;
+
+/**
+ * Identity of the process holding the lock.
+ */
+export interface DataDirLockOwner {
+    pid: number;
+    hostname: string;
+    username: string;
+    createdAt: string;
+}
+
+/**
+ * Raised (and surfaced to the user) when the data directory is locked by another instance.
+ */
+export class DataDirLockedError extends Error {
+    readonly code = 'DATA_DIR_LOCKED';
+
+    constructor(
+        readonly dataDir: string,
+        readonly owner: DataDirLockOwner | null
+    ) {
+        const holder = owner ? `${owner.username}@${owner.hostname} (pid ${owner.pid})` : 'another instance';
+        super(`The data directory ${dataDir} is in use by ${holder}.`);
+        this.name = 'DataDirLockedError';
+    }
+}
+
+/**
+ * Result of trying to acquire the lock.
+ */
+export type DataDirLockResult = { acquired: true } | { acquired: false; error: DataDirLockedError };
+
+/** Path of the lock we hold, if any */
+let heldLockPath: string | null = null;
+
+/** Whether this instance must not write to the data directory */
+let readOnly = false;
+
+function readOwner(lockPath: string): DataDirLockOwner | null {
+    try {
+        return JSON.parse(fs.readFileSync(lockPath, 'utf-8')) as DataDirLockOwner;
+    } catch {
+        return null;
+    }
+}
+
+/**
+ * A lock is stale if its owner ran on this machine and is no longer alive.
+ * Locks from other hosts cannot be checked and are always respected. A lock
+ * without a readable owner is stale once it is older than the grace period.
+ */
+function isStale(owner: DataDirLockOwner | null, lockPath: string): boolean {
+    if (!owner || typeof owner.pid !== 'number') {
+        try {
+            return Date.now() - fs.statSync(lockPath).mtimeMs > UNREADABLE_LOCK_GRACE_MS;
+        } catch {
+            // Removed in the meantime; retrying will recreate it
+            return true;
+        }
+    }
+    if (owner.hostname !== os.hostname()) return false;
+    if (owner.pid === process.pid) return true;
+
+    try {
+        process.kill(owner.pid, 0);
+        return false;
+    } catch (error) {
+        // EPERM means the process exists but belongs to another user
+        return (error as NodeJS.ErrnoException).code === 'ESRCH';
+    }
+}
+
+function writeLock(lockPath: string): void {
+    const owner: DataDirLockOwner = {
+        pid: process.pid,
+        hostname: os.hostname(),
+        username: os.userInfo().username,
+        createdAt: new Date().toISOString(),
+    };
+    // 'wx' fails with EEXIST if another process created the file first
+    fs.writeFileSync(lockPath, JSON.stringify(owner, null, 2), { encoding: 'utf-8', flag: 'wx' });
+}
+
+/**
+ * Acquire the data directory lock, switching to read-only mode if it is held elsewhere.
+ *
+ * @param dataDir - Data directory to lock
+ * @returns Whether the lock was acquired, with the error to surface if not
+ */
+export function acquireDataDirLock(dataDir: string): DataDirLockResult {
+    const lockPath = path.join(dataDir, DATA_DIR_LOCK_FILENAME);
+
+    for (let attempt = 0; attempt < 2; attempt++) {
+        try {
+            fs.mkdirSync(dataDir, { recursive: true });
+            writeLock(lockPath);
+            heldLockPath = lockPath;
+            readOnly = false;
+            logger.log(`Acquired data directory lock: ${lockPath}`);
+            return { acquired: true };
+        } catch (error) {
+            if ((error as NodeJS.ErrnoException).code !== 'EEXIST') {
+                // Can't create the lock at all (e.g. read-only media); don't block startup on it
+                logger.error('Failed to create data directory lock:', error);
+                return { acquired: true };
+            }
+
+            const owner = readOwner(lockPath);
+            if (attempt === 0 && isStale(owner, lockPath)) {
+                logger.warn('Removing stale data directory lock', owner);
+                try {
+                    fs.unlinkSync(lockPath);
+                } catch {
+                    // Another instance may have removed it first; retry anyway
+                }
+                continue;
+            }
+
+            readOnly = true;
+            const lockedError = new DataDirLockedError(dataDir, owner);
+            logger.warn(`${lockedError.message} Running in read-only mode.`);
+            return { acquired: false, error: lockedError };
+        }
+    }
+
+    /* v8 ignore next 2 -- loop always returns */
+    readOnly = true;
+    return { acquired: false, error: new DataDirLockedError(dataDir, null) };
+}
+
+/**
+ * Release the lock if this instance holds it.
+ */
+export function releaseDataDirLock(): void {
+    if (!heldLockPath) return;
+
+    try {
+        fs.unlinkSync(heldLockPath);
+        logger.log('Released data directory lock');
+    } catch (error) {
+        logger.error('Failed to release data directory lock:', error);
+    }
+    heldLockPath = null;
+}
+
+/**
+ * @returns True if another instance holds the data directory lock
+ */
+export function isDataDirReadOnly(): boolean {
+    return readOnly;
+}
@@ -0,0 +1,155 @@
+/**
+ * Low disk space guard.
+ *
+ * Writes that can be large (exports, screenshots, model downloads) call
+ * ensureDiskSpace() first, so running out of space fails up front with a
+ * DiskFullError that says so, rather than with an IO error halfway through
+ * a file. A monitor polls the app data volume and reports when free space
+ * drops below the threshold, so background jobs can pause.
+ *
+ * @module diskSpace
+ */
+
+import { EventEmitter } from 'events';
+import * as fs from 'fs/promises';
+import { createLogger } from './logger';
+import { DEFAULT_LOW_DISK_SPACE_MB, DISK_FULL_ERROR_CODE, type DiskSpaceStatus } from '../../shared/types/disk-space';
+
+const logger = createLogger('[DiskSpace]');
+
+const BYTES_PER_MB = 1024 * 1024;
+
+let thresholdBytes = DEFAULT_LOW_DISK_SPACE_MB * BYTES_PER_MB;
+let status: DiskSpaceStatus = { freeBytes: null, thresholdBytes, low: false };
+const emitter = new EventEmitter();
+
+/**
+ * Thrown instead of writing when free space is below the threshold.
+ */
+export class DiskFullError extends Error {
+    readonly code = DISK_FULL_ERROR_CODE;
+
+    constructor(
+        readonly freeBytes: number,
+        readonly requiredBytes: number
+    ) {
+        super(
+            `Not enough disk space: ${formatMb(freeBytes)} free, at least ${formatMb(requiredBytes)} needed. ` +
+                'Free up space and try again.'
+        );
+        this.name = 'DiskFullError';
+    }
+}
+
+/**
+ * Format bytes as whole megabytes.
+ */
+function formatMb(bytes: number): string {
+    return `${Math.floor(bytes / BYTES_PER_MB)} MB`;
+}
+
+/**
+ * Check whether an error means the disk is full, either refused by the guard
+ * or reported by the OS (ENOSPC).
+ */
+export function isDiskFullError(error: unknown): boolean {
+    const code = (error as { code?: unknown } | null)?.code;
+    return code === DISK_FULL_ERROR_CODE || code === 'ENOSPC';
+}
+
+/**
+ * Set the free space below which writes are refused.
+ * @param mb - Threshold in MB; 0 turns the guard off
+ */
+export function setLowDiskSpaceThreshold(mb: number): void {
+    thresholdBytes = Number.isFinite(mb) && mb > 0 ? Math.round(mb * BYTES_PER_MB) : 0;
+}
+
+/**
+ * Get free space on the volume holding a path.
+ * @param dir - Existing directory on the volume
+ * @returns Bytes available to the app, or null if the OS could not tell
+ */
+export async function getFreeDiskSpace(dir: string): Promise<number | null> {
+    try {
+        const stats = await fs.statfs(dir);
+        return stats.bavail * stats.bsize;
+    } catch (error) {
+        logger.warn(`Could not read free space for ${dir}:`, error);
+        return null;
+    }
+}
+
+/**
+ * Refuse a write that would leave less than the threshold free.
+ * Passes when free space cannot be read, so an unsupported filesystem never
+ * blocks writes.
+ *
+ * @param dir - Directory the write goes to
+ * @param bytes - Expected size of the write, if known
+ * @throws DiskFullError if free space minus the write is below the threshold
+ */
+export async function ensureDiskSpace(dir: string, bytes = 0): Promise<void> {
+    if (thresholdBytes === 0) return;
+
+    const freeBytes = await getFreeDiskSpace(dir);
+    if (freeBytes === null) return;
+
+    const requiredBytes = thresholdBytes + bytes;
+    if (freeBytes < requiredBytes) {
+        throw new DiskFullError(freeBytes, requiredBytes);
+    }
+}
+
+/**
+ * Get the last free space reading of the monitored volume.
+ */
+export function getDiskSpaceStatus(): DiskSpaceStatus {
+    return status;
+}
+
+/**
+ * Whether the monitored volume was below the threshold at the last check.
+ * Background jobs skip their work while this is true.
+ */
+export function isDiskSpaceLow(): boolean {
+    return status.low;
+}
+
+/**
+ * Read free space on a volume and update the status, notifying listeners
+ * when it crosses the threshold.
+ * @param dir - Directory on the monitored volume
+ * @returns The new status
+ */
+export async function checkDiskSpace(dir: string): Promise<DiskSpaceStatus> {
+    const freeBytes = await getFreeDiskSpace(dir);
+    const low = freeBytes !== null && thresholdBytes > 0 && freeBytes < thresholdBytes;
+    const changed = low !== status.low;
+
+    status = { freeBytes, thresholdBytes, low };
+    if (changed) {
+        logger.warn(low ? `Low disk space: ${formatMb(freeBytes!)} free` : 'Disk space recovered');
+        emitter.emit('changed', status);
+    }
+    return status;
+}
+
+/**
+ * Subscribe to the monitored volume crossing the threshold.
+ *
+ * @param listener - Called with the new status
+ * @returns Function that unsubscribes
+ */
+export function onDiskSpaceChanged(listener: (status: DiskSpaceStatus) => void): () => void {
+    emitter.on('changed', listener);
+    return () => emitter.off('changed', listener);
+}
+
+/**
+ * Reset the threshold and status without emitting a change.
+ */
+export function resetDiskSpace(): void {
+    thresholdBytes = DEFAULT_LOW_DISK_SPACE_MB * BYTES_PER_MB;
+    status = { freeBytes: null, thresholdBytes, low: false };
+}
@@ -0,0 +1,74 @@
+/**
+ * Environment variable overrides for settings.
+ *
+ * Every setting can be forced from the environment, which is useful for kiosks
+ * and CI-driven E2E tests. The variable name is the prefix followed by the
+ * setting key in SCREAMING_SNAKE_CASE, e.g. zoomLevel -> GEMINI_DESKTOP_ZOOM_LEVEL.
+ *
+ * Precedence (highest first): environment variable, settings file, built-in default.
+ * Overridden values are never written back to the settings file.
+ *
+ * @module envOverrides
+ */
+
+import type { SettingsFieldSchema } from './settingsSchema';
+
+/** Prefix for all settings environment variables */
+export const ENV_OVERRIDE_PREFIX = 'GEMINI_DESKTOP_';
+
+/**
+ * Convert a setting key to its environment variable name.
+ * @example toEnvVarName('hotkeyBossKey') // 'GEMINI_DESKTOP_HOTKEY_BOSS_KEY'
+ */
+export function toEnvVarName(key: string, prefix = ENV_OVERRIDE_PREFIX): string {
+    return prefix + key.replace(/([a-z0-9])([A-Z])/g, '$1_$2').toUpperCase();
+}
+
+/**
+ * Parse an environment variable value as the given type.
+ * @returns The parsed value, or undefined if it is not valid for the type
+ */
+export function parseEnvValue(raw: string, type: SettingsFieldSchema['type']): string | number | boolean | undefined {
+    if (type === 'boolean') {
+        if (/^(true|1|yes|on)$/i.test(raw)) return true;
+        if (/^(false|0|no|off)$/i.test(raw)) return false;
+        return undefined;
+    }
+    if (type === 'number') {
+        const value = Number(raw);
+        return raw.trim() !== '' && Number.isFinite(value) ? value : undefined;
+    }
+    return raw;
+}
+
+/**
+ * Read overrides for the given settings from the environment.
+ *
+ * @param types - Type of each setting that can be overridden
+ * @param env - Environment to read (defaults to process.env)
+ * @param prefix - Variable name prefix
+ * @returns Parsed overrides, and the variables that could not be parsed
+ */
+export function readEnvOverrides(
+    types: Record<string, SettingsFieldSchema['type']>,
+    env: NodeJS.ProcessEnv = process.env,
+    prefix = ENV_OVERRIDE_PREFIX
+): { overrides: Record<string, unknown>; invalid: string[] } {
+    const overrides: Record<string, unknown> = {};
+    const invalid: string[] = [];
+
+    for (const [key, type] of Object.entries(types)) {
+        const name = toEnvVarName(key, prefix);
+        const raw = env[name];
+        if (raw === undefined) continue;
+
+        const value = parseEnvValue(raw, type);
+        if (value === undefined) {
+            invalid.push(name);
+        } else {
+            overrides[key] = value;
+        }
+    }
+
+    return { overrides, invalid };
+}
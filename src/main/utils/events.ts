@@ -0,0 +1,69 @@
+/**
+ * App event bus.
+ *
+ * State changes that every window cares about (theme, zoom, Gemini location,
+ * titlebar indicators, ...) are published here with a typed payload instead
+ * of each module choosing its own event names. The last events are kept so a
+ * window that opens later, such as Quick Chat, can catch up on what it missed.
+ *
+ * IPC handlers publish through `BaseIpcHandler.publishEvent()`, which also
+ * sends the event to all windows.
+ *
+ * @module events
+ */
+
+import { EventEmitter } from 'events';
+import type { AppEvent, AppEventName, AppEventPayloads } from '../../shared/types/events';
+
+/** How many events are kept for late subscribers */
+export const EVENT_REPLAY_LIMIT = 50;
+
+const recent: AppEvent[] = [];
+const emitter = new EventEmitter();
+let lastSeq = 0;
+
+/**
+ * Publish an event to all subscribers and keep it for replay.
+ *
+ * @param name - Event name
+ * @param payload - Event payload
+ * @returns The published event
+ */
+export function publishAppEvent<K extends AppEventName>(name: K, payload: AppEventPayloads[K]): AppEvent {
+    const event = { seq: ++lastSeq, name, payload, timestamp: Date.now() } as AppEvent;
+
+    recent.push(event);
+    if (recent.length > EVENT_REPLAY_LIMIT) {
+        recent.shift();
+    }
+
+    emitter.emit('event', event);
+    return event;
+}
+
+/**
+ * Subscribe to all events published from now on.
+ *
+ * @param listener - Called with each event
+ * @returns Function that unsubscribes
+ */
+export function onAppEvent(listener: (event: AppEvent) => void): () => void {
+    emitter.on('event', listener);
+    return () => emitter.off('event', listener);
+}
+
+/**
+ * Get the kept events, oldest first.
+ *
+ * @param since - Only return events with a higher `seq` than this
+ */
+export function getRecentAppEvents(since = 0): AppEvent[] {
+    return recent.filter((event) => event.seq > since);
+}
+
+/**
+ * Forget the kept events. Sequence numbers keep increasing.
+ */
+export function clearAppEvents(): void {
+    recent.length = 0;
+}
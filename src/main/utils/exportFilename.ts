@@ -0,0 +1,154 @@
+/**
+ * Export filename template utilities.
+ *
+ * Expands user-defined filename templates for chat exports and sanitizes
+ * the result so it is a valid filename on the target platform.
+ *
+ * Supported tokens:
+ * - {title}  - Chat title (whitespace collapsed to underscores)
+ * - {date}   - Export date as YYYY-MM-DD
+ * - {time}   - Export time as HH-mm-ss
+ * - {id}     - Gemini conversation ID (empty when unavailable)
+ * - {gem}    - Name of the Gem the chat belongs to (empty for plain Gemini chats)
+ * - {format} - Export format (e.g. 'md', 'pdf')
+ *
+ * @module exportFilename
+ */
+
+/**
+ * Default template. Matches the pre-template behaviour (title only).
+ */
+export const DEFAULT_EXPORT_FILENAME_TEMPLATE = '{title}';
+
+/**
+ * Maximum filename length (excluding extension).
+ * Most filesystems cap names at 255 bytes; leave room for the extension
+ * and multi-byte characters.
+ */
+export const MAX_EXPORT_FILENAME_LENGTH = 200;
+
+/**
+ * Maximum accepted template length.
+ */
+export const MAX_EXPORT_FILENAME_TEMPLATE_LENGTH = 256;
+
+/**
+ * Fallback name used when the expanded template sanitizes to nothing.
+ */
+const FALLBACK_FILENAME = 'gemini-chat';
+
+/**
+ * Characters that are illegal in filenames, per platform.
+ * Control characters (0x00-0x1F) are stripped on every platform.
+ */
+const ILLEGAL_CHARACTERS: Record<string, RegExp> = {
+    win32: /[<>:"/\\|?*]/g,
+    darwin: /[/:]/g,
+    linux: /[/]/g,
+};
+
+/**
+ * Windows reserved device names (case-insensitive, with or without extension).
+ */
+const WINDOWS_RESERVED_NAMES = /^(con|prn|aux|nul|com[0-9]|lpt[0-9])(\..*)?$/i;
+
+/**
+ * Values substituted into a filename template.
+ */
+export interface ExportFilenameValues {
+    /** Chat title */
+    title: string;
+    /** Export timestamp (Date or ISO string) */
+    timestamp: Date | string;
+    /** Gemini conversation ID, if known */
+    id?: string;
+    /** Gem name, if the chat belongs to a Gem */
+    gem?: string;
+    /** Export format / file extension without the dot */
+    format: string;
+}
+
+/**
+ * Pad a number to two digits.
+ */
+function pad2(value: number): string {
+    return String(value).padStart(2, '0');
+}
+
+/**
+ * Check whether a template string is acceptable for storage.
+ *
+ * @param template - Candidate template
+ * @returns True if the template is a non-empty string within the length limit
+ */
+export function isValidExportFilenameTemplate(template: unknown): template is string {
+    return (
+        typeof template === 'string' &&
+        template.trim().length > 0 &&
+        template.length <= MAX_EXPORT_FILENAME_TEMPLATE_LENGTH
+    );
+}
+
+/**
+ * Sanitize a filename for the given platform.
+ *
+ * @param name - Raw filename (without extension)
+ * @param platform - Target platform (defaults to the current platform)
+ * @returns A filename safe to use on the platform
+ */
+export function sanitizeFilename(name: string, platform: NodeJS.Platform = process.platform): string {
+    const illegal = ILLEGAL_CHARACTERS[platform] ?? ILLEGAL_CHARACTERS.linux!;
+
+    // eslint-disable-next-line no-control-regex
+    let sanitized = name.replace(/[\x00-\x1f\x7f]/g, '').replace(illegal, '-');
+
+    if (platform === 'win32') {
+        // Windows silently drops trailing dots and spaces
+        sanitized = sanitized.replace(/[. ]+$/, '');
+        if (WINDOWS_RESERVED_NAMES.test(sanitized)) {
+            sanitized = `_${sanitized}`;
+        }
+    }
+
+    // Leading dots would create hidden files on POSIX systems
+    sanitized = sanitized.replace(/^\.+/, '').trim();
+
+    if (sanitized.length > MAX_EXPORT_FILENAME_LENGTH) {
+        sanitized = sanitized.slice(0, MAX_EXPORT_FILENAME_LENGTH).trim();
+    }
+
+    return sanitized || FALLBACK_FILENAME;
+}
+
+/**
+ * Expand a filename template and sanitize the result.
+ *
+ * Unknown tokens are left as-is (and sanitized like any other text).
+ *
+ * @param template - Filename template, e.g. '{date}_{title}'
+ * @param values - Token values
+ * @param platform - Target platform (defaults to the current platform)
+ * @returns Sanitized filename including the format extension
+ */
+export function buildExportFilename(
+    template: string,
+    values: ExportFilenameValues,
+    platform: NodeJS.Platform = process.platform
+): string {
+    const date = values.timestamp instanceof Date ? values.timestamp : new Date(values.timestamp);
+    const validDate = isNaN(date.getTime()) ? new Date() : date;
+
+    const tokens: Record<string, string> = {
+        title: values.title.trim().replace(/\s+/g, '_'),
+        date: `${validDate.getFullYear()}-${pad2(validDate.getMonth() + 1)}-${pad2(validDate.getDate())}`,
+        time: `${pad2(validDate.getHours())}-${pad2(validDate.getMinutes())}-${pad2(validDate.getSeconds())}`,
+        id: values.id ?? '',
+        gem: (values.gem ?? '').trim().replace(/\s+/g, '_'),
+        format: values.format,
+    };
+
+    const source = isValidExportFilenameTemplate(template) ? template : DEFAULT_EXPORT_FILENAME_TEMPLATE;
+    const expanded = source.replace(/\{(\w+)\}/g, (match, token: string) => tokens[token] ?? match);
+
+    return `${sanitizeFilename(expanded, platform)}.${values.format}`;
+}
@@ -0,0 +1,112 @@
+/**
+ * Shared HTML styling for rendered chat exports.
+ *
+ * Used by the PDF export document and by pinned answer cards so both
+ * render Gemini answers the same way.
+ *
+ * @module exportHtml
+ */
+
+/**
+ * Stylesheet for export HTML documents.
+ */
+export const EXPORT_DOCUMENT_CSS = `
+body {
+    font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, Helvetica, Arial, sans-serif;
+    line-height: 1.6;
+    color: #333;
+    max-width: 800px;
+    margin: 0 auto;
+    padding: 40px;
+    background: #fff;
+}
+.header {
+    text-align: center;
+    border-bottom: 2px solid #eee;
+    margin-bottom: 40px;
+    padding-bottom: 20px;
+}
+.title {
+    font-size: 28px;
+    font-weight: bold;
+    margin: 0;
+    color: #1a1a1b;
+}
+.timestamp {
+    font-size: 14px;
+    color: #666;
+    margin-top: 8px;
+}
+.chat-turn {
+    margin-bottom: 40px;
+    page-break-inside: avoid;
+}
+.role-header {
+    font-size: 18px;
+    font-weight: 600;
+    margin-bottom: 12px;
+    padding-bottom: 4px;
+    border-bottom: 1px solid #f0f0f0;
+}
+.user-role { color: #1a73e8; }
+.model-role { color: #1e1e1e; }
+.content {
+    font-size: 15px;
+    overflow-wrap: break-word;
+}
+pre {
+    background: #f6f8fa;
+    padding: 16px;
+    border-radius: 8px;
+    overflow-x: auto;
+    font-family: inherit;
+    border: 1px solid #e1e4e8;
+}
+code {
+    font-family: ui-monospace, SFMono-Regular, SF Mono, Menlo, Consolas, Liberation Mono, monospace;
+    font-size: 85%;
+    background: rgba(175, 184, 193, 0.2);
+    padding: 0.2em 0.4em;
+    border-radius: 6px;
+}
+pre code {
+    background: none;
+    padding: 0;
+    font-size: 13px;
+}
+table {
+    border-collapse: collapse;
+    width: 100%;
+    margin: 16px 0;
+}
+th, td {
+    border: 1px solid #dfe2e5;
+    padding: 8px 12px;
+    text-align: left;
+}
+th { background-color: #f6f8fa; }
+tr:nth-child(even) { background-color: #fafbfc; }
+blockquote {
+    margin: 0 0 16px;
+    padding: 0 1em;
+    color: #6a737d;
+    border-left: 0.25em solid #dfe2e5;
+}
+img { max-width: 100%; }
+.file-references {
+    border-top: 2px solid #eee;
+    padding-top: 20px;
+    font-size: 14px;
+}
+.file-references h2 {
+    font-size: 18px;
+    margin: 0 0 8px;
+}
+.file-references a {
+    color: #1a73e8;
+    word-break: break-all;
+}
+@media print {
+    body { padding: 0; }
+    .chat-turn { page-break-inside: avoid; border-bottom: none; }
+}`;
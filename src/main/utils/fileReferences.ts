@@ -0,0 +1,103 @@
+/**
+ * Local file reference detection for chat exports.
+ *
+ * Finds local file paths and file:// URLs mentioned in Gemini answers so
+ * exports can link to them and list every referenced file per conversation.
+ * Links in exports are plain file:// URLs for the viewer of the exported file
+ * to open; the app itself never opens them.
+ *
+ * Detected forms:
+ * - file:// URLs
+ * - Windows drive paths (C:\Users\me\notes.txt)
+ * - Absolute POSIX paths with an extension (/home/me/notes.md)
+ * - Home-relative paths (~/notes/todo.md)
+ *
+ * @module fileReferences
+ */
+
+import * as os from 'os';
+
+/**
+ * A local file mentioned in an answer.
+ */
+export interface FileReference {
+    /** The path or URL as written in the answer */
+    text: string;
+    /** file:// URL for the reference */
+    url: string;
+}
+
+/**
+ * Path patterns. The lookbehind keeps URL paths (https://host/a/b.txt) and
+ * relative paths (src/a/b.ts) from matching as absolute paths.
+ */
+const FILE_REFERENCE_PATTERN = new RegExp(
+    [
+        String.raw`file:\/\/[^\s<>"'\x60)\]]+`,
+        String.raw`(?<![\w/\\])[A-Za-z]:\\(?:[^\\\s<>:"|?*\x60]+\\)*[^\\\s<>:"|?*\x60]+`,
+        String.raw`(?<![\w/.:~-])(?:~|\/[\w.-]+)(?:\/[\w.-]+)*\/[\w-][\w.-]*\.[A-Za-z0-9]+`,
+    ].join('|'),
+    'g'
+);
+
+/** Trailing punctuation that usually ends a sentence rather than a path */
+const TRAILING_PUNCTUATION = /[.,;:!?]+$/;
+
+/**
+ * Convert a detected path to a file:// URL.
+ * @param reference - Path or file:// URL
+ * @param homeDir - Home directory used to expand `~`
+ */
+export function toFileUrl(reference: string, homeDir: string = os.homedir()): string {
+    if (reference.startsWith('file://')) return reference;
+
+    let filePath = reference.startsWith('~/') ? homeDir.replace(/[\\/]+$/, '') + reference.slice(1) : reference;
+    filePath = filePath.replace(/\\/g, '/');
+    if (!filePath.startsWith('/')) filePath = '/' + filePath;
+
+    return 'file://' + encodeURI(filePath).replace(/[?#]/g, encodeURIComponent);
+}
+
+/**
+ * Find local file references in text, in order of first appearance.
+ * @param text - Answer text
+ * @param homeDir - Home directory used to expand `~`
+ */
+export function findFileReferences(text: string, homeDir?: string): FileReference[] {
+    const seen = new Set<string>();
+    const references: FileReference[] = [];
+
+    for (const match of text.matchAll(FILE_REFERENCE_PATTERN)) {
+        const reference = match[0].replace(TRAILING_PUNCTUATION, '');
+        if (seen.has(reference)) continue;
+
+        seen.add(reference);
+        references.push({ text: reference, url: toFileUrl(reference, homeDir) });
+    }
+
+    return references;
+}
+
+/**
+ * Build the Markdown "Referenced files" index appended to exports.
+ * @returns The section, or an empty string if there are no references
+ */
+export function buildFileReferenceIndexMarkdown(references: FileReference[]): string {
+    if (references.length === 0) return '';
+
+    const items = references.map(({ text, url }) => `- [${text.replace(/([[\]])/g, '\\$1')}](<${url}>)`);
+    return `## Referenced files\n\n${items.join('\n')}\n`;
+}
+
+/**
+ * Build the HTML "Referenced files" index appended to PDF exports.
+ * @returns The section, or an empty string if there are no references
+ */
+export function buildFileReferenceIndexHtml(references: FileReference[]): string {
+    if (references.length === 0) return '';
+
+    const escape = (value: string) =>
+        value.replace(/&/g, '&amp;').replace(/</g, '&lt;').replace(/>/g, '&gt;').replace(/"/g, '&quot;');
+    const items = references.map(({ text, url }) => `<li><a href="${escape(url)}">${escape(text)}</a></li>`);
+    return `<div class="file-references"><h2>Referenced files</h2><ul>${items.join('')}</ul></div>`;
+}
@@ -0,0 +1,153 @@
+/**
+ * Focus sessions.
+ *
+ * A focus session is a timed block of work. While one runs, response
+ * notifications, badges and auto-copy notifications are held back and
+ * background update checks pause; callers check isFocusSessionActive().
+ * Each answer is logged with its prompt, and when the session ends the log
+ * can be saved as Markdown.
+ *
+ * @module focusSession
+ */
+
+import { EventEmitter } from 'events';
+import { createLogger } from './logger';
+import {
+    MAX_FOCUS_SESSION_MINUTES,
+    MIN_FOCUS_SESSION_MINUTES,
+    type FocusSessionEntry,
+    type FocusSessionStatus,
+} from '../../shared/types/focus-session';
+
+const logger = createLogger('[FocusSession]');
+
+const MS_PER_MINUTE = 60 * 1000;
+
+/** Default filename of a saved session log */
+export const FOCUS_SESSION_LOG_FILENAME_TEMPLATE = 'gemini-focus-session_{date}_{time}';
+
+/**
+ * A focus session and what was logged during it.
+ */
+export interface FocusSession {
+    startedAt: number;
+    endsAt: number;
+    entries: FocusSessionEntry[];
+}
+
+let current: FocusSession | null = null;
+const emitter = new EventEmitter();
+
+/**
+ * Start a focus session, replacing any running one.
+ *
+ * @param minutes - Session length, clamped to the allowed range
+ * @param now - Start time (ms since epoch)
+ * @returns The new status
+ */
+export function startFocusSession(minutes: number, now = Date.now()): FocusSessionStatus {
+    const length = Math.min(Math.max(Math.round(minutes), MIN_FOCUS_SESSION_MINUTES), MAX_FOCUS_SESSION_MINUTES);
+    current = { startedAt: now, endsAt: now + length * MS_PER_MINUTE, entries: [] };
+    logger.log(`Focus session started for ${length} minutes`);
+
+    const status = getFocusSessionStatus();
+    emitter.emit('changed', status);
+    return status;
+}
+
+/**
+ * End the running focus session.
+ *
+ * @returns The session that ended, or null if none was running
+ */
+export function endFocusSession(): FocusSession | null {
+    const session = current;
+    if (!session) return null;
+
+    current = null;
+    logger.log(`Focus session ended with ${session.entries.length} answers logged`);
+    emitter.emit('changed', getFocusSessionStatus());
+    return session;
+}
+
+/**
+ * Check whether a focus session is running.
+ */
+export function isFocusSessionActive(): boolean {
+    return current !== null;
+}
+
+/**
+ * Get the current focus session state.
+ */
+export function getFocusSessionStatus(): FocusSessionStatus {
+    return {
+        active: current !== null,
+        startedAt: current?.startedAt ?? null,
+        endsAt: current?.endsAt ?? null,
+        entryCount: current?.entries.length ?? 0,
+    };
+}
+
+/**
+ * Log an answer in the running focus session. Does nothing without one.
+ */
+export function recordFocusSessionEntry(entry: FocusSessionEntry): void {
+    current?.entries.push(entry);
+}
+
+/**
+ * Subscribe to focus sessions starting and ending.
+ *
+ * @returns Unsubscribe function
+ */
+export function onFocusSessionChanged(listener: (status: FocusSessionStatus) => void): () => void {
+    emitter.on('changed', listener);
+    return () => emitter.off('changed', listener);
+}
+
+/**
+ * Drop the running session without emitting a change.
+ */
+export function resetFocusSession(): void {
+    current = null;
+}
+
+/**
+ * Build the Markdown log of a focus session.
+ *
+ * @param session - The session, with its logged answers
+ * @param endedAt - When the session ended (ms since epoch)
+ * @returns Markdown with one section per prompt
+ */
+export function buildFocusSessionMarkdown(session: FocusSession, endedAt: number): string {
+    const minutes = Math.max(1, Math.round((endedAt - session.startedAt) / MS_PER_MINUTE));
+    const lines = [
+        '# Focus Session',
+        '',
+        `*${new Date(session.startedAt).toLocaleString()} – ${new Date(endedAt).toLocaleTimeString()} ` +
+            `(${minutes} min, ${session.entries.length} ${session.entries.length === 1 ? 'answer' : 'answers'})*`,
+    ];
+
+    if (session.entries.length === 0) {
+        lines.push('', 'No answers were logged during this session.');
+    }
+
+    for (const entry of session.entries) {
+        const heading = entry.modelName ? `Gemini (${entry.modelName})` : 'Gemini';
+        lines.push(
+            '',
+            `## ${new Date(entry.time).toLocaleTimeString()}`,
+            '',
+            '### You',
+            '',
+            entry.prompt || '*Prompt not available*',
+            '',
+            `### ${heading}`,
+            '',
+            entry.answer
+        );
+    }
+
+    return lines.join('\n') + '\n';
+}
@@ -0,0 +1,38 @@
+/**
+ * Lookup of the embedded Gemini frame.
+ *
+ * The main window hosts a React shell with Gemini in a child iframe, so
+ * actions on "the Gemini view" (reload, navigation) target that frame rather
+ * than the window's webContents.
+ *
+ * @module geminiFrame
+ */
+
+import type { WebContents, WebFrameMain } from 'electron';
+import { isGeminiDomain } from './geminiSelectors';
+
+/**
+ * Find the Gemini iframe among the main frame's children.
+ * @param webContents - Main window webContents
+ * @returns The Gemini frame, or null if it is not loaded (e.g. offline overlay shown)
+ */
+export function findGeminiFrame(webContents: WebContents): WebFrameMain | null {
+    return webContents.mainFrame.frames.find((frame) => isGeminiDomain(frame.url)) ?? null;
+}
+
+/**
+ * Extract the conversation ID from a Gemini URL.
+ * e.g. https://gemini.google.com/app/abc123 -> 'abc123'
+ * @returns The ID, or undefined for pages that are not a conversation
+ */
+export function getGeminiConversationId(url: string): string | undefined {
+    try {
+        const segments = new URL(url).pathname.split('/').filter(Boolean);
+        if (segments.length >= 2 && (segments[0] === 'app' || segments[0] === 'gem')) {
+            return segments[segments.length - 1];
+        }
+    } catch {
+        // Fall through to undefined
+    }
+    return undefined;
+}
@@ -0,0 +1,53 @@
+/**
+ * Gemini quota estimate.
+ *
+ * Google does not tell the app how much of a plan's daily prompt quota is
+ * used, so the estimate counts the prompts sent from the app today against
+ * the plan's published quota (or the user's own number). Prompts sent from
+ * other devices are not counted.
+ *
+ * @module geminiQuota
+ */
+
+import {
+    GEMINI_PLAN_DAILY_PROMPTS,
+    GEMINI_QUOTA_PLANS,
+    GEMINI_QUOTA_WARNING_RATIO,
+    type GeminiQuotaPlan,
+    type GeminiQuotaSettings,
+    type GeminiQuotaStatus,
+} from '../../shared/types/usage';
+
+/**
+ * Check that a value is a known Gemini quota plan.
+ * @param value - Value to check
+ */
+export function isGeminiQuotaPlan(value: unknown): value is GeminiQuotaPlan {
+    return GEMINI_QUOTA_PLANS.includes(value as GeminiQuotaPlan);
+}
+
+/**
+ * Estimate how much of the Gemini daily quota is left.
+ * @param settings - Plan, and the daily prompts for the 'custom' plan
+ * @param used - Prompts sent today
+ * @returns The estimate; dailyPrompts is 0 when the plan is 'off' or the custom quota is unset
+ */
+export function getGeminiQuotaStatus(settings: GeminiQuotaSettings, used: number): GeminiQuotaStatus {
+    const { plan, customDailyPrompts } = settings;
+    let dailyPrompts = 0;
+    if (plan === 'custom') {
+        dailyPrompts = Math.max(0, Math.floor(customDailyPrompts));
+    } else if (plan !== 'off') {
+        dailyPrompts = GEMINI_PLAN_DAILY_PROMPTS[plan];
+    }
+    const remaining = Math.max(0, dailyPrompts - used);
+
+    let level: GeminiQuotaStatus['level'] = 'ok';
+    if (dailyPrompts > 0 && remaining === 0) {
+        level = 'exhausted';
+    } else if (dailyPrompts > 0 && used >= dailyPrompts * GEMINI_QUOTA_WARNING_RATIO) {
+        level = 'warning';
+    }
+
+    return { plan, customDailyPrompts, dailyPrompts, used, remaining, level };
+}
@@ -164,6 +164,22 @@ export const GeminiSelectors = {
          */
         description: 'Conversation title element',
     },
+
+    /**
+     * Conversation list sidebar configuration.
+     * Hidden in compact mode to leave the narrow window to the chat.
+     */
+    sidebar: {
+        /**
+         * CSS selectors for the sidebar; all matches are hidden.
+         */
+        selectors: ['bard-sidenav', 'side-navigation-v2', 'mat-sidenav'] as const,
+
+        /**
+         * Description for logging/debugging.
+         */
+        description: 'Conversation list sidebar',
+    },
 } as const;
 
 /**
@@ -241,3 +257,4 @@ export const GEMINI_MICROPHONE_BUTTON_SELECTORS = GeminiSelectors.microphoneButt
 export const GEMINI_ERROR_TOAST_SELECTORS = GeminiSelectors.errorToast.selectors;
 export const GEMINI_MICROPHONE_ERROR_TEXT = GeminiSelectors.errorToast.microphoneErrorText;
 export const GEMINI_CONVERSATION_TITLE_SELECTORS = GeminiSelectors.conversationTitle.selectors;
+export const GEMINI_SIDEBAR_SELECTORS = GeminiSelectors.sidebar.selectors;
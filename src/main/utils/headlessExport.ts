@@ -0,0 +1,110 @@
+/**
+ * Headless export command line.
+ *
+ * `gemini-desktop export --conversation <url> --format md --out <path>`
+ * exports one conversation without opening any window, for cron-driven
+ * archival on machines without anyone at the GUI. It uses the app's signed-in
+ * session, so sign in from the app once before scheduling exports.
+ *
+ * @module headlessExport
+ */
+
+import type { Session } from 'electron';
+import * as path from 'path';
+import type ExportManager from '../managers/exportManager';
+import type { UserPreferences } from '../managers/ipc/types';
+import type SettingsStore from '../store';
+import { isGeminiDomain } from './geminiSelectors';
+import { applySavedNetworkSettings } from './networkSettings';
+
+/** Subcommand that starts a headless export */
+export const HEADLESS_EXPORT_COMMAND = 'export';
+
+/** Usage shown when the arguments are invalid */
+export const HEADLESS_EXPORT_USAGE = 'Usage: gemini-desktop export --conversation <url> --format <md|pdf> --out <path>';
+
+/**
+ * Options of a headless export.
+ */
+export interface HeadlessExportOptions {
+    /** Gemini conversation URL */
+    conversationUrl: string;
+    format: 'markdown' | 'pdf';
+    /** Absolute destination path */
+    outPath: string;
+}
+
+/** Accepted --format values */
+const FORMATS: Record<string, HeadlessExportOptions['format']> = {
+    md: 'markdown',
+    markdown: 'markdown',
+    pdf: 'pdf',
+};
+
+/**
+ * Read the value of a `--name value` or `--name=value` flag.
+ */
+export function getFlag(args: readonly string[], name: string): string | undefined {
+    for (let i = 0; i < args.length; i++) {
+        const arg = args[i]!;
+        if (arg === `--${name}`) return args[i + 1];
+        if (arg.startsWith(`--${name}=`)) return arg.slice(name.length + 3);
+    }
+    return undefined;
+}
+
+/**
+ * Parse a headless export from the command line.
+ *
+ * `export` is only the subcommand as the first argument, so a flag value such
+ * as `--profile export` still opens the app.
+ *
+ * @param argv - Command-line arguments (defaults to process.argv)
+ * @param cwd - Directory relative output paths resolve against
+ * @param defaultApp - Whether argv[1] is the app path, as when run with `electron .`
+ * @returns null if the export subcommand was not given, the options if they are
+ *   valid, or an error message
+ */
+export function parseHeadlessExportArgs(
+    argv: readonly string[] = process.argv,
+    cwd: string = process.cwd(),
+    defaultApp: boolean = process.defaultApp === true
+): HeadlessExportOptions | { error: string } | null {
+    // argv[0] is the executable; in development argv[1] is the app path
+    const commandIndex = defaultApp ? 2 : 1;
+    if (argv[commandIndex] !== HEADLESS_EXPORT_COMMAND) return null;
+
+    const args = argv.slice(commandIndex + 1);
+    const conversationUrl = getFlag(args, 'conversation');
+    const format = FORMATS[(getFlag(args, 'format') ?? 'md').toLowerCase()];
+    const out = getFlag(args, 'out');
+
+    if (!conversationUrl || !isGeminiDomain(conversationUrl)) {
+        return { error: '--conversation must be a Gemini conversation URL' };
+    }
+    if (!format) return { error: '--format must be md or pdf' };
+    if (!out) return { error: '--out is required' };
+
+    return { conversationUrl, format, outPath: path.resolve(cwd, out) };
+}
+
+/**
+ * Export a conversation as a headless export would.
+ *
+ * The proxy and User-Agent IPC handlers are never registered in a headless
+ * run, so the saved settings are applied here before the conversation loads.
+ *
+ * @param options - Parsed export options
+ * @param store - User preferences with the saved proxy and User-Agent
+ * @param exportManager - Export manager that loads and writes the conversation
+ * @param targetSession - Session the export window uses
+ */
+export async function runHeadlessExportJob(
+    options: HeadlessExportOptions,
+    store: Pick<SettingsStore<UserPreferences>, 'get'>,
+    exportManager: Pick<ExportManager, 'exportConversationToFile'>,
+    targetSession: Session
+): Promise<void> {
+    await applySavedNetworkSettings(store, targetSession);
+    await exportManager.exportConversationToFile(options.conversationUrl, options.format, options.outPath);
+}
@@ -0,0 +1,303 @@
+/**
+ * Instance command protocol.
+ *
+ * The running instance accepts commands over a local socket (a UNIX domain
+ * socket, or a named pipe on Windows) so helper tools can drive it. Each
+ * request is one line of JSON and gets one line of JSON back:
+ *
+ * ```
+ * → {"v":1,"command":"ask","text":"Summarize my clipboard"}
+ * ← {"ok":true}
+ * ```
+ *
+ * Commands:
+ * - `focus` - Show and focus the main window
+ * - `new-window` - Open another main window
+ * - `toggle-compact-mode` - Dock the main window as a narrow strip, or undock it
+ * - `move-to-display` (`display`) - Move the main window to a monitor, numbered from 1
+ * - `focus-session` (`minutes`, optional) - Start a focus session, by default as long as
+ *   the focusSessionMinutes setting
+ * - `ask` (`text`) - Submit a prompt in a new chat, like Quick Chat
+ * - `navigate` (`url`) - Open a Gemini URL, e.g. a conversation
+ * - `export` (`format`: `pdf` | `markdown`) - Export the open conversation,
+ *   asking where to save it
+ *
+ * Starting a second instance with `--ask <text>`, `--navigate <url>`,
+ * `--export <md|pdf>`, `--move-to-display <n>`, `--focus-session <minutes>`, `--new-window` or
+ * `--toggle-compact-mode` forwards the same commands
+ * through Electron's single instance handoff; without them the second instance focuses the first.
+ *
+ * The socket lives in the data directory, so each profile has its own and
+ * only the OS user who owns the data directory can connect.
+ *
+ * @module instanceCommands
+ */
+
+import * as crypto from 'crypto';
+import * as fs from 'fs';
+import * as net from 'net';
+import * as os from 'os';
+import * as path from 'path';
+import { isGeminiDomain } from './geminiSelectors';
+import { getFlag } from './headlessExport';
+import { MAX_FOCUS_SESSION_MINUTES, MIN_FOCUS_SESSION_MINUTES } from '../../shared/types/focus-session';
+
+/** Protocol version expected in the `v` field of requests */
+export const INSTANCE_COMMAND_PROTOCOL_VERSION = 1;
+
+/** Socket file name in the data directory (not used on Windows) */
+export const INSTANCE_COMMAND_SOCKET_FILENAME = 'command.sock';
+
+/** Longest request line accepted, in characters */
+export const MAX_INSTANCE_COMMAND_LENGTH = 64 * 1024;
+
+/** Longest UNIX socket path that works on all platforms (macOS allows 103 bytes) */
+const MAX_SOCKET_PATH_LENGTH = 100;
+
+/**
+ * A command for the running instance.
+ */
+export type InstanceCommand =
+    | { command: 'focus' }
+    | { command: 'new-window' }
+    | { command: 'toggle-compact-mode' }
+    | { command: 'move-to-display'; display: number }
+    | { command: 'focus-session'; minutes?: number }
+    | { command: 'ask'; text: string }
+    | { command: 'navigate'; url: string }
+    | { command: 'export'; format: 'pdf' | 'markdown' };
+
+/**
+ * Reply to a request.
+ */
+export type InstanceCommandResponse = { ok: true } | { ok: false; error: string };
+
+/** Accepted export formats */
+const EXPORT_FORMATS: Record<string, 'pdf' | 'markdown'> = {
+    md: 'markdown',
+    markdown: 'markdown',
+    pdf: 'pdf',
+};
+
+/**
+ * Get the socket path for a data directory.
+ *
+ * @param userDataPath - The app's data directory
+ * @param platform - Platform to build the path for (defaults to process.platform)
+ * @returns Named pipe path on Windows, otherwise a socket path
+ */
+export function getInstanceCommandSocketPath(userDataPath: string, platform: string = process.platform): string {
+    const id = crypto.createHash('sha256').update(userDataPath).digest('hex').slice(0, 16);
+    if (platform === 'win32') {
+        return `\\\\.\\pipe\\gemini-desktop-${id}`;
+    }
+
+    const socketPath = path.join(userDataPath, INSTANCE_COMMAND_SOCKET_FILENAME);
+    return socketPath.length <= MAX_SOCKET_PATH_LENGTH
+        ? socketPath
+        : path.join(os.tmpdir(), `gemini-desktop-${id}.sock`);
+}
+
+/**
+ * Validate a decoded request.
+ *
+ * @param value - Decoded JSON request
+ * @returns The command, or an error message
+ */
+export function parseInstanceCommand(value: unknown): InstanceCommand | { error: string } {
+    if (!value || typeof value !== 'object') return { error: 'Request must be a JSON object' };
+
+    const request = value as Record<string, unknown>;
+    if (request.v !== INSTANCE_COMMAND_PROTOCOL_VERSION) {
+        return { error: `Unsupported protocol version; expected "v": ${INSTANCE_COMMAND_PROTOCOL_VERSION}` };
+    }
+
+    switch (request.command) {
+        case 'focus':
+            return { command: 'focus' };
+        case 'new-window':
+            return { command: 'new-window' };
+        case 'toggle-compact-mode':
+            return { command: 'toggle-compact-mode' };
+        case 'move-to-display':
+            if (typeof request.display !== 'number' || !Number.isInteger(request.display) || request.display < 1) {
+                return { error: 'move-to-display requires a "display" number from 1' };
+            }
+            return { command: 'move-to-display', display: request.display };
+        case 'focus-session': {
+            if (request.minutes === undefined) return { command: 'focus-session' };
+            const { minutes } = request;
+            if (
+                typeof minutes !== 'number' ||
+                !Number.isInteger(minutes) ||
+                minutes < MIN_FOCUS_SESSION_MINUTES ||
+                minutes > MAX_FOCUS_SESSION_MINUTES
+            ) {
+                const range = `${MIN_FOCUS_SESSION_MINUTES} to ${MAX_FOCUS_SESSION_MINUTES}`;
+                return { error: `focus-session "minutes" must be a whole number from ${range}` };
+            }
+            return { command: 'focus-session', minutes };
+        }
+        case 'ask':
+            if (typeof request.text !== 'string' || !request.text.trim()) {
+                return { error: 'ask requires a non-empty "text"' };
+            }
+            return { command: 'ask', text: request.text };
+        case 'navigate': {
+            const url = typeof request.url === 'string' ? request.url : '';
+            if (!url.startsWith('https://') || !isGeminiDomain(url)) {
+                return { error: 'navigate requires a Gemini "url"' };
+            }
+            return { command: 'navigate', url };
+        }
+        case 'export': {
+            const format = typeof request.format === 'string' ? EXPORT_FORMATS[request.format] : undefined;
+            if (!format) return { error: 'export requires a "format" of pdf or markdown' };
+            return { command: 'export', format };
+        }
+        default:
+            return { error: `Unknown command: ${String(request.command)}` };
+    }
+}
+
+/**
+ * Read a command from a second instance's command line.
+ *
+ * @param argv - Command-line arguments of the second instance
+ * @returns The command (focus when none was given), or an error message
+ */
+export function parseInstanceCommandArgs(argv: readonly string[]): InstanceCommand | { error: string } {
+    const text = getFlag(argv, 'ask');
+    if (text !== undefined) {
+        return parseInstanceCommand({ v: INSTANCE_COMMAND_PROTOCOL_VERSION, command: 'ask', text });
+    }
+
+    const url = getFlag(argv, 'navigate');
+    if (url !== undefined) {
+        return parseInstanceCommand({ v: INSTANCE_COMMAND_PROTOCOL_VERSION, command: 'navigate', url });
+    }
+
+    const format = getFlag(argv, 'export');
+    if (format !== undefined) {
+        return parseInstanceCommand({ v: INSTANCE_COMMAND_PROTOCOL_VERSION, command: 'export', format });
+    }
+
+    const display = getFlag(argv, 'move-to-display');
+    if (display !== undefined) {
+        return parseInstanceCommand({
+            v: INSTANCE_COMMAND_PROTOCOL_VERSION,
+            command: 'move-to-display',
+            display: Number(display),
+        });
+    }
+
+    const minutes = getFlag(argv, 'focus-session');
+    if (minutes !== undefined) {
+        return parseInstanceCommand({
+            v: INSTANCE_COMMAND_PROTOCOL_VERSION,
+            command: 'focus-session',
+            minutes: Number(minutes),
+        });
+    }
+
+    if (argv.includes('--new-window')) {
+        return { command: 'new-window' };
+    }
+
+    if (argv.includes('--toggle-compact-mode')) {
+        return { command: 'toggle-compact-mode' };
+    }
+
+    return { command: 'focus' };
+}
+
+/**
+ * Handle one request line.
+ *
+ * @param line - JSON request
+ * @param execute - Runs a valid command; a thrown error is sent back to the client
+ * @returns The reply
+ */
+export async function handleInstanceCommandLine(
+    line: string,
+    execute: (command: InstanceCommand) => void | Promise<void>
+): Promise<InstanceCommandResponse> {
+    let value: unknown;
+    try {
+        value = JSON.parse(line);
+    } catch {
+        return { ok: false, error: 'Request is not valid JSON' };
+    }
+
+    const command = parseInstanceCommand(value);
+    if ('error' in command) return { ok: false, error: command.error };
+
+    try {
+        await execute(command);
+        return { ok: true };
+    } catch (error) {
+        return { ok: false, error: (error as Error).message };
+    }
+}
+
+/**
+ * Start accepting commands on the socket.
+ *
+ * Only call this while holding the single instance lock and the data
+ * directory lock: a socket file left behind by a crashed instance is removed
+ * first. The socket is created under a 0o177 umask so it is never accessible
+ * to other users, not even briefly.
+ *
+ * @param socketPath - Path from getInstanceCommandSocketPath
+ * @param execute - Runs a valid command
+ * @returns The listening server; close it on quit
+ */
+export function startInstanceCommandServer(
+    socketPath: string,
+    execute: (command: InstanceCommand) => void | Promise<void>
+): net.Server {
+    const isPipe = socketPath.startsWith('\\\\.\\pipe\\');
+    if (!isPipe) {
+        fs.rmSync(socketPath, { force: true });
+    }
+
+    const server = net.createServer((socket) => {
+        let buffer = '';
+        let rejected = false;
+        // Requests on one connection are handled and answered in order
+        let pending = Promise.resolve();
+
+        socket.setEncoding('utf8');
+        socket.on('data', (chunk: string) => {
+            if (rejected) return;
+            buffer += chunk;
+
+            let newline: number;
+            while ((newline = buffer.indexOf('\n')) !== -1) {
+                const line = buffer.slice(0, newline);
+                buffer = buffer.slice(newline + 1);
+                pending = pending.then(async () => {
+                    const response = await handleInstanceCommandLine(line, execute);
+                    if (!socket.destroyed) socket.write(`${JSON.stringify(response)}\n`);
+                });
+            }
+
+            if (buffer.length > MAX_INSTANCE_COMMAND_LENGTH) {
+                rejected = true;
+                buffer = '';
+                socket.end(`${JSON.stringify({ ok: false, error: 'Request is too long' })}\n`);
+            }
+        });
+        // A client that disconnects early is not an error for the server
+        socket.on('error', () => {});
+    });
+
+    // The socket file is created synchronously by listen(), so the umask only needs to cover that call
+    const previousUmask = isPipe ? null : process.umask(0o177);
+    try {
+        server.listen(socketPath);
+    } finally {
+        if (previousUmask !== null) process.umask(previousUmask);
+    }
+    return server;
+}
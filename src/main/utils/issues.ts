@@ -0,0 +1,196 @@
+/**
+ * Issue tracker helpers.
+ *
+ * Drafts an issue title and description from an answer and creates it as a
+ * GitHub issue or a Jira ticket through their REST APIs. Jira descriptions are
+ * converted from Markdown to Jira wiki markup. Requests go through the default
+ * session, so they use the configured upstream proxy.
+ *
+ * @module issues
+ */
+
+import type { Session } from 'electron';
+import { ISSUE_TRACKERS, type IssueDraft, type IssueTrackerSettings } from '../../shared/types/issues';
+
+/** How long creating an issue may take */
+export const ISSUE_REQUEST_TIMEOUT_MS = 30000;
+
+/** GitHub REST API root */
+const GITHUB_API_URL = 'https://api.github.com';
+
+/** Issue type used for new Jira issues; every default Jira project scheme has it */
+export const JIRA_ISSUE_TYPE = 'Task';
+
+/** Longest drafted title; trackers accept more, but long titles are unreadable */
+const MAX_TITLE_LENGTH = 80;
+
+/** GitHub 'owner/name' repository reference */
+const REPOSITORY_PATTERN = /^[\w.-]+\/[\w.-]+$/;
+
+/** Jira project keys start with a letter and contain letters, digits and underscores */
+const PROJECT_KEY_PATTERN = /^[A-Za-z][A-Za-z0-9_]*$/;
+
+/**
+ * Check issue tracker settings for the selected tracker.
+ * @param settings - Settings to check
+ * @param complete - Also require everything needed to create an issue
+ * @returns A description of the problem, or null if valid
+ */
+export function validateIssueTrackerSettings(settings: IssueTrackerSettings, complete = false): string | null {
+    if (!ISSUE_TRACKERS.includes(settings.tracker)) return `Unknown issue tracker: ${settings.tracker}`;
+
+    if (settings.tracker === 'github') {
+        if (complete && !settings.githubRepository) return 'GitHub repository is not set';
+        if (settings.githubRepository && !REPOSITORY_PATTERN.test(settings.githubRepository)) {
+            return `Invalid GitHub repository (expected owner/name): ${settings.githubRepository}`;
+        }
+        if (complete && !settings.githubToken) return 'GitHub token is not set';
+        return null;
+    }
+
+    if (complete && !settings.jiraBaseUrl) return 'Jira URL is not set';
+    if (settings.jiraBaseUrl) {
+        let url: URL;
+        try {
+            url = new URL(settings.jiraBaseUrl);
+        } catch {
+            return `Invalid Jira URL: ${settings.jiraBaseUrl}`;
+        }
+        if (url.protocol !== 'https:') return 'Jira URL must use https';
+    }
+    if (complete && !settings.jiraProjectKey) return 'Jira project key is not set';
+    if (settings.jiraProjectKey && !PROJECT_KEY_PATTERN.test(settings.jiraProjectKey)) {
+        return `Invalid Jira project key: ${settings.jiraProjectKey}`;
+    }
+    if (complete && !settings.jiraApiToken) return 'Jira API token is not set';
+    return null;
+}
+
+/**
+ * Strip inline Markdown from a line, for use as a plain-text title.
+ */
+function toPlainText(line: string): string {
+    return line
+        .replace(/^\s*(?:#{1,6}\s+|[-*+]\s+|\d+\.\s+|>\s*)/, '')
+        .replace(/!?\[([^\]]*)\]\([^)]*\)/g, '$1')
+        .replace(/(\*\*|__|~~|`)(.+?)\1/g, '$2')
+        .replace(/(^|\W)([*_])(.+?)\2(?=\W|$)/g, '$1$3')
+        .trim();
+}
+
+/**
+ * Draft an issue from Markdown: the first heading (or first line) becomes the
+ * title and the full text the description.
+ */
+export function draftIssue(markdown: string): Pick<IssueDraft, 'title' | 'body'> {
+    const body = markdown.trim();
+    const lines = body
+        .replace(/```[\s\S]*?```/g, '')
+        .split('\n')
+        .filter((line) => line.trim());
+    const source = lines.find((line) => /^\s*#{1,6}\s/.test(line)) ?? lines[0] ?? '';
+
+    let title = toPlainText(source);
+    if (title.length > MAX_TITLE_LENGTH) {
+        title = `${title.slice(0, MAX_TITLE_LENGTH - 1).trimEnd()}…`;
+    }
+    return { title, body };
+}
+
+/**
+ * Jira nests list items by repeating the bullet; Markdown nests them by indenting.
+ */
+function toJiraBullet(_match: string, indent: string): string {
+    return `${'*'.repeat(Math.ceil(indent.length / 4) + 1)} `;
+}
+
+/**
+ * Convert Markdown to Jira wiki markup. Code blocks keep their content verbatim.
+ */
+export function toJiraWiki(markdown: string): string {
+    return markdown
+        .split(/(```[\w+#.-]*\n[\s\S]*?```)/)
+        .map((part, index) => {
+            if (index % 2 === 1) {
+                const [, language, code] = part.match(/^```([\w+#.-]*)\n([\s\S]*?)\n?```$/) ?? [];
+                return language ? `{code:${language}}\n${code}\n{code}` : `{code}\n${code}\n{code}`;
+            }
+            return part
+                .replace(/^(#{1,6})\s+(.+)$/gm, (_m, hashes: string, text: string) => `h${hashes.length}. ${text}`)
+                .replace(/\*\*(.+?)\*\*/g, '*$1*')
+                .replace(/`([^`\n]+)`/g, '{{$1}}')
+                .replace(/!?\[([^\]]*)\]\(([^)\s]+)\)/g, '[$1|$2]')
+                .replace(/^(\s*)[-+*]\s+/gm, toJiraBullet);
+        })
+        .join('');
+}
+
+/**
+ * Send a JSON request and return the parsed response, throwing on HTTP errors.
+ */
+async function requestJson(
+    targetSession: Session,
+    url: string,
+    headers: Record<string, string>,
+    body: unknown,
+    label: string
+): Promise<Record<string, unknown>> {
+    const response = await targetSession.fetch(url, {
+        method: 'POST',
+        headers: { Accept: 'application/json', 'Content-Type': 'application/json', ...headers },
+        body: JSON.stringify(body),
+        signal: AbortSignal.timeout(ISSUE_REQUEST_TIMEOUT_MS),
+    });
+    if (!response.ok) {
+        throw new Error(`${label} request failed: ${response.status} ${response.statusText}`);
+    }
+    return response.json();
+}
+
+/**
+ * Create an issue in the selected tracker.
+ * @param targetSession - Session to send requests through
+ * @param settings - Tracker settings (must be complete)
+ * @param draft - Reviewed title and Markdown description
+ * @returns Web URL of the created issue
+ */
+export async function createIssue(
+    targetSession: Session,
+    settings: IssueTrackerSettings,
+    draft: Pick<IssueDraft, 'title' | 'body'>
+): Promise<string> {
+    if (settings.tracker === 'github') {
+        const created = await requestJson(
+            targetSession,
+            `${GITHUB_API_URL}/repos/${settings.githubRepository}/issues`,
+            {
+                Accept: 'application/vnd.github+json',
+                Authorization: `Bearer ${settings.githubToken}`,
+                'X-GitHub-Api-Version': '2022-11-28',
+            },
+            { title: draft.title, body: draft.body },
+            'GitHub'
+        );
+        return String(created.html_url);
+    }
+
+    const baseUrl = settings.jiraBaseUrl.replace(/\/+$/, '');
+    const authorization = settings.jiraEmail
+        ? `Basic ${Buffer.from(`${settings.jiraEmail}:${settings.jiraApiToken}`).toString('base64')}`
+        : `Bearer ${settings.jiraApiToken}`;
+    const created = await requestJson(
+        targetSession,
+        `${baseUrl}/rest/api/2/issue`,
+        { Authorization: authorization },
+        {
+            fields: {
+                project: { key: settings.jiraProjectKey },
+                summary: draft.title,
+                description: toJiraWiki(draft.body),
+                issuetype: { name: JIRA_ISSUE_TYPE },
+            },
+        },
+        'Jira'
+    );
+    return `${baseUrl}/browse/${created.key}`;
+}
@@ -0,0 +1,104 @@
+/**
+ * Gemini language override.
+ *
+ * Gemini picks its UI language from the Accept-Language header, which
+ * Electron derives from the OS locale. These helpers replace the header on
+ * requests to Google, so users whose OS locale differs from their preferred
+ * language see Gemini in the language they chose, and point the spell checker
+ * at the languages the user writes prompts in (the Gemini language unless
+ * they picked others).
+ *
+ * @module language
+ */
+
+import type { Session } from 'electron';
+import type { SpellCheckSettings } from '../../shared/types/language';
+
+/** Requests the Accept-Language override applies to */
+const GOOGLE_URLS = ['*://*.google.com/*'];
+
+/** Spell checker languages in effect before the first override */
+let originalSpellCheckerLanguages: string[] | null = null;
+
+/**
+ * Build an Accept-Language header value for a language tag, falling back to
+ * the bare language for regional tags, e.g. 'pt-BR' -> 'pt-BR,pt;q=0.9'.
+ * @param tag - BCP 47 language tag
+ */
+export function buildAcceptLanguage(tag: string): string {
+    const language = tag.split('-')[0]!;
+    return language === tag ? tag : `${tag},${language};q=0.9`;
+}
+
+/**
+ * Pick the spell checker language for a tag: the tag itself if the spell
+ * checker has a dictionary for it, otherwise the bare language.
+ * @returns The language, or null if no dictionary is available
+ */
+export function resolveSpellCheckerLanguage(tag: string, available: string[]): string | null {
+    const language = tag.split('-')[0]!;
+    return [tag, language].find((candidate) => available.includes(candidate)) ?? null;
+}
+
+/**
+ * Parse the spellCheckLanguages preference (one language tag per line).
+ * @returns Language tags in the order they were saved
+ */
+export function parseSpellCheckLanguages(value: string): string[] {
+    return value
+        .split('\n')
+        .map((tag) => tag.trim())
+        .filter(Boolean);
+}
+
+/**
+ * Apply a language override to a session. Pages pick up the new
+ * Accept-Language on their next navigation.
+ *
+ * @param targetSession - Session hosting Gemini
+ * @param tag - BCP 47 language tag, or '' to follow the system locale
+ * @returns The Accept-Language now sent to Google, or null for the system default
+ */
+export function applyGeminiLanguage(targetSession: Session, tag: string): string | null {
+    if (!tag) {
+        targetSession.webRequest.onBeforeSendHeaders(null);
+        return null;
+    }
+
+    const acceptLanguage = buildAcceptLanguage(tag);
+    targetSession.webRequest.onBeforeSendHeaders({ urls: GOOGLE_URLS }, (details, callback) => {
+        callback({ requestHeaders: { ...details.requestHeaders, 'Accept-Language': acceptLanguage } });
+    });
+    return acceptLanguage;
+}
+
+/**
+ * Apply the spell checker settings to a session. With no languages chosen
+ * the spell checker follows the Gemini language, then the system default.
+ * Languages without a dictionary are skipped.
+ *
+ * @param targetSession - Session hosting Gemini
+ * @param settings - Whether to check spelling, and the languages to check
+ * @param geminiLanguage - Gemini language tag, or '' for the system locale
+ * @returns The spell checker languages now in effect
+ */
+export function applySpellCheck(
+    targetSession: Session,
+    settings: Pick<SpellCheckSettings, 'enabled' | 'languages'>,
+    geminiLanguage: string
+): string[] {
+    originalSpellCheckerLanguages ??= targetSession.getSpellCheckerLanguages();
+    targetSession.setSpellCheckerEnabled(settings.enabled);
+
+    const wanted = settings.languages.length > 0 ? settings.languages : [geminiLanguage].filter(Boolean);
+    const available = targetSession.availableSpellCheckerLanguages;
+    const resolved = wanted
+        .map((tag) => resolveSpellCheckerLanguage(tag, available))
+        .filter((language, index, all): language is string => language !== null && all.indexOf(language) === index);
+
+    // macOS uses the system spell checker, which has no language list and
+    // detects the language on its own
+    const languages = resolved.length > 0 ? resolved : originalSpellCheckerLanguages;
+    targetSession.setSpellCheckerLanguages(languages);
+    return languages;
+}
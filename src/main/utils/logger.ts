@@ -22,6 +22,109 @@ function isProduction(): boolean {
     }
 }
 
+/**
+ * Log levels, from least to most verbose.
+ * 'info' covers logger.log().
+ */
+export const LOG_LEVELS = ['error', 'warn', 'info', 'debug'] as const;
+export type LogLevel = (typeof LOG_LEVELS)[number];
+
+/**
+ * Parsed log level filter.
+ */
+export interface LogLevelFilter {
+    /** Level for all modules without a target override (null = build default) */
+    level: LogLevel | null;
+    /** Per-module levels keyed by lowercase module name (prefix without brackets) */
+    targets: Record<string, LogLevel>;
+}
+
+/** Active filter, changed at runtime via setLogLevel() */
+let activeFilter: LogLevelFilter = { level: null, targets: {} };
+
+function isLogLevel(value: string): value is LogLevel {
+    return (LOG_LEVELS as readonly string[]).includes(value);
+}
+
+/**
+ * Normalize a logger prefix or target name for matching: '[ExportManager]' -> 'exportmanager'.
+ */
+function toTargetName(name: string): string {
+    return name.replace(/^\[|\]$/g, '').trim().toLowerCase();
+}
+
+/**
+ * Parse a log level spec such as 'debug' or 'warn,ExportManager=debug,IpcManager=info'.
+ *
+ * @param spec - Comma-separated default level and/or module=level pairs
+ * @returns The parsed filter, or null if any part is invalid
+ */
+export function parseLogLevelSpec(spec: string): LogLevelFilter | null {
+    const filter: LogLevelFilter = { level: null, targets: {} };
+    const parts = spec
+        .split(',')
+        .map((part) => part.trim())
+        .filter(Boolean);
+    if (parts.length === 0) return null;
+
+    for (const part of parts) {
+        const [target, level] = part.includes('=') ? part.split('=', 2) : [null, part];
+        const normalizedLevel = level!.trim().toLowerCase();
+        if (!isLogLevel(normalizedLevel)) return null;
+
+        if (target === null) {
+            filter.level = normalizedLevel;
+        } else {
+            const name = toTargetName(target);
+            if (!name) return null;
+            filter.targets[name] = normalizedLevel;
+        }
+    }
+
+    return filter;
+}
+
+/**
+ * Change logging verbosity at runtime.
+ *
+ * @param spec - Level spec, see {@link parseLogLevelSpec}
+ * @returns True if the spec was valid and applied
+ */
+export function setLogLevel(spec: string): boolean {
+    const filter = typeof spec === 'string' ? parseLogLevelSpec(spec) : null;
+    if (!filter) return false;
+    activeFilter = filter;
+    return true;
+}
+
+/**
+ * Get the active log level spec.
+ *
+ * @returns Spec string, e.g. 'info,exportmanager=debug' (default level omitted if unset)
+ */
+export function getLogLevel(): string {
+    const parts = activeFilter.level ? [activeFilter.level] : [];
+    for (const [target, level] of Object.entries(activeFilter.targets)) {
+        parts.push(`${target}=${level}`);
+    }
+    return parts.join(',');
+}
+
+/**
+ * Restore the build default (debug in development, info in production).
+ */
+export function resetLogLevel(): void {
+    activeFilter = { level: null, targets: {} };
+}
+
+/**
+ * Check whether a message at the given level should be written for a module.
+ */
+function isLevelEnabled(target: string, level: LogLevel): boolean {
+    const threshold = activeFilter.targets[target] ?? activeFilter.level ?? (isProduction() ? 'info' : 'debug');
+    return LOG_LEVELS.indexOf(level) <= LOG_LEVELS.indexOf(threshold);
+}
+
 /**
  * Creates a logger instance with a consistent prefix.
  *
@@ -31,10 +134,12 @@ function isProduction(): boolean {
  * @example
  * const logger = createLogger('[MyModule]');
  * logger.log('Hello world'); // [MyModule] Hello world
- * logger.debug('Debug info'); // Only logs in development mode
+ * logger.debug('Debug info'); // Only logs in development mode (or with a debug log level)
  * logger.error('Something failed'); // [MyModule] Something failed
  */
 export function createLogger(prefix: string): Logger {
+    const target = toTargetName(prefix);
+
     /**
      * Safely writes to console, catching EPIPE errors that occur
      * when stdout/stderr is closed during app reload on Windows.
@@ -56,6 +161,7 @@ export function createLogger(prefix: string): Logger {
          * @param args - Additional arguments
          */
         log(message: string, ...args: unknown[]): void {
+            if (!isLevelEnabled(target, 'info')) return;
             safeWrite('log', message, args);
         },
 
@@ -65,6 +171,7 @@ export function createLogger(prefix: string): Logger {
          * @param args - Additional arguments
          */
         error(message: string, ...args: unknown[]): void {
+            if (!isLevelEnabled(target, 'error')) return;
             safeWrite('error', message, args);
         },
 
@@ -74,18 +181,18 @@ export function createLogger(prefix: string): Logger {
          * @param args - Additional arguments
          */
         warn(message: string, ...args: unknown[]): void {
+            if (!isLevelEnabled(target, 'warn')) return;
             safeWrite('warn', message, args);
         },
 
         /**
-         * Log a debug message (only in development mode).
-         * In production, this is a no-op with minimal overhead.
+         * Log a debug message (development mode, or when the log level is 'debug').
+         * Otherwise this is a no-op with minimal overhead.
          * @param message - Message to log
          * @param args - Additional arguments
          */
         debug(message: string, ...args: unknown[]): void {
-            // Early exit in production - minimal overhead (just a function call + boolean check)
-            if (isProduction()) return;
+            if (!isLevelEnabled(target, 'debug')) return;
             safeWrite('log', `[DEBUG] ${message}`, args);
         },
     };
@@ -0,0 +1,81 @@
+/**
+ * Saved network settings.
+ *
+ * Reads the proxy and User-Agent settings from the user preferences and
+ * applies them to a session, and answers proxy authentication with the saved
+ * credentials. The proxy and User-Agent IPC handlers do this on startup; the
+ * headless export does it itself because it runs without them.
+ *
+ * @module networkSettings
+ */
+
+import type { Session } from 'electron';
+import type SettingsStore from '../store';
+import type { UserPreferences } from '../managers/ipc/types';
+import { applyProxySettings, decryptProxyPassword } from './proxy';
+import { applyUserAgent } from './userAgent';
+import { DEFAULT_PROXY_SETTINGS, PROXY_MODES, type ProxyMode, type ProxySettings } from '../../shared/types/proxy';
+import { USER_AGENT_PRESETS, type UserAgentPreset, type UserAgentSettings } from '../../shared/types/user-agent';
+
+/** Store the settings are read from */
+type PreferencesReader = Pick<SettingsStore<UserPreferences>, 'get'>;
+
+/**
+ * Read the saved proxy settings, falling back to defaults for invalid values.
+ * @returns Proxy settings with the password decrypted
+ */
+export function readProxySettings(store: PreferencesReader): ProxySettings {
+    const mode = store.get('proxyMode') as ProxyMode;
+    return {
+        mode: PROXY_MODES.includes(mode) ? mode : DEFAULT_PROXY_SETTINGS.mode,
+        host: store.get('proxyHost') ?? DEFAULT_PROXY_SETTINGS.host,
+        port: store.get('proxyPort') ?? DEFAULT_PROXY_SETTINGS.port,
+        username: store.get('proxyUsername') ?? DEFAULT_PROXY_SETTINGS.username,
+        password: decryptProxyPassword(store.get('proxyPassword') ?? ''),
+        bypass: store.get('proxyBypass') ?? DEFAULT_PROXY_SETTINGS.bypass,
+    };
+}
+
+/**
+ * Read the saved User-Agent settings, falling back to defaults for invalid values.
+ */
+export function readUserAgentSettings(store: PreferencesReader): UserAgentSettings {
+    const preset = store.get('userAgentPreset') as UserAgentPreset;
+    return {
+        preset: USER_AGENT_PRESETS.includes(preset) ? preset : 'default',
+        custom: store.get('userAgentCustom') ?? '',
+    };
+}
+
+/**
+ * Create an app `login` listener that answers proxy authentication challenges
+ * with the saved credentials. Other challenges are left to Electron.
+ */
+export function createProxyLoginListener(
+    store: PreferencesReader
+): (
+    event: Electron.Event,
+    webContents: Electron.WebContents | null,
+    details: Electron.AuthenticationResponseDetails,
+    authInfo: Electron.AuthInfo,
+    callback: (username?: string, password?: string) => void
+) => void {
+    return (event, _webContents, _details, authInfo, callback) => {
+        if (!authInfo.isProxy) return;
+
+        const { username, password } = readProxySettings(store);
+        if (!username) return;
+
+        event.preventDefault();
+        callback(username, password);
+    };
+}
+
+/**
+ * Apply the saved proxy and User-Agent to a session.
+ * @returns The User-Agent now in effect
+ */
+export async function applySavedNetworkSettings(store: PreferencesReader, targetSession: Session): Promise<string> {
+    await applyProxySettings(targetSession, readProxySettings(store));
+    return applyUserAgent(targetSession, readUserAgentSettings(store));
+}
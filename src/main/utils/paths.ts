@@ -19,6 +19,16 @@ export function getPreloadPath(): string {
     return path.join(__dirname, '../../preload/preload.cjs');
 }
 
+/**
+ * Get the pinned answer card preload script path.
+ * Resolves to compiled CJS in dist-electron directory.
+ *
+ * @returns Absolute path to cardPreload.cjs
+ */
+export function getCardPreloadPath(): string {
+    return path.join(__dirname, '../../preload/cardPreload.cjs');
+}
+
 /**
  * Get path to a file in the dist directory.
  *
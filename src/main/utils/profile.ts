@@ -0,0 +1,80 @@
+/**
+ * Config profiles.
+ *
+ * Starting the app with `--profile <name>` (or `--profile=<name>`) uses a
+ * separate data directory for that profile: its own settings, user styles,
+ * userscripts, downloaded models and Google sign-in. This lets entirely
+ * separate app configurations coexist for the same OS user, e.g. `work` and
+ * `personal`. Profiles are unrelated to Google account profiles; each app
+ * profile can be signed in to a different account. Without the flag the
+ * default data directory is used.
+ *
+ * Each profile is its own single instance, so different profiles can run at
+ * the same time.
+ *
+ * @module profile
+ */
+
+import * as path from 'path';
+
+/** Command-line switch that selects a profile */
+export const PROFILE_SWITCH = '--profile';
+
+/** Folder in the default data directory that holds the profiles */
+export const PROFILES_DIRNAME = 'profiles';
+
+/** Maximum length of a profile name */
+export const MAX_PROFILE_NAME_LENGTH = 64;
+
+/** Profile names are used as folder names, so only allow portable characters */
+const PROFILE_NAME_PATTERN = /^[a-z0-9][a-z0-9_-]*$/i;
+
+/**
+ * Check whether a profile name is valid.
+ *
+ * @param name - Profile name
+ * @returns True for letters, digits, '-' and '_', not starting with '-' or '_'
+ */
+export function isProfileName(name: string): boolean {
+    return name.length <= MAX_PROFILE_NAME_LENGTH && PROFILE_NAME_PATTERN.test(name);
+}
+
+/**
+ * Parse the profile from the command line.
+ *
+ * @param argv - Command-line arguments (defaults to process.argv)
+ * @returns null if no profile was given, the lowercase profile name if it is
+ *   valid, or an error message
+ */
+export function parseProfileArg(argv: readonly string[] = process.argv): { name: string } | { error: string } | null {
+    for (let i = 1; i < argv.length; i++) {
+        const arg = argv[i]!;
+        let name: string | undefined;
+        if (arg === PROFILE_SWITCH) {
+            name = argv[i + 1] ?? '';
+        } else if (arg.startsWith(`${PROFILE_SWITCH}=`)) {
+            name = arg.slice(PROFILE_SWITCH.length + 1);
+        } else {
+            continue;
+        }
+
+        if (!isProfileName(name)) {
+            const rule = `use up to ${MAX_PROFILE_NAME_LENGTH} letters, digits, '-' or '_'`;
+            return { error: `Invalid profile name "${name}": ${rule}` };
+        }
+        // Lowercase so the same profile is used on case-insensitive file systems
+        return { name: name.toLowerCase() };
+    }
+    return null;
+}
+
+/**
+ * Get the data directory of a profile.
+ *
+ * @param defaultDataPath - The default data directory (app.getPath('userData'))
+ * @param name - Valid profile name
+ * @returns Absolute path of the profile's data directory
+ */
+export function getProfileDataPath(defaultDataPath: string, name: string): string {
+    return path.join(defaultDataPath, PROFILES_DIRNAME, name);
+}
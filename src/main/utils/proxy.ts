@@ -0,0 +1,91 @@
+/**
+ * Upstream proxy helpers.
+ *
+ * All windows and main-process network requests use the default session, so
+ * setting its proxy config routes the Gemini webview, updates and exports
+ * through the same upstream HTTP or SOCKS5 proxy.
+ *
+ * @module proxy
+ */
+
+import type { Session } from 'electron';
+import { PROXY_MODES, type ProxySettings, type ProxyTestResult } from '../../shared/types/proxy';
+import { GEMINI_APP_URL } from './constants';
+import { decryptSecret, encryptSecret } from './secrets';
+
+/** How long the connection test waits before giving up */
+export const PROXY_TEST_TIMEOUT_MS = 10000;
+
+/**
+ * Check proxy settings.
+ * @returns A description of the problem, or null if valid
+ */
+export function validateProxySettings(settings: ProxySettings): string | null {
+    if (!PROXY_MODES.includes(settings.mode)) return `Unknown proxy mode: ${settings.mode}`;
+    if (settings.mode === 'system' || settings.mode === 'direct') return null;
+
+    if (!settings.host || /[\s/@]/.test(settings.host)) return `Invalid proxy host: ${settings.host}`;
+    if (!Number.isInteger(settings.port) || settings.port < 1 || settings.port > 65535) {
+        return `Invalid proxy port: ${settings.port}`;
+    }
+    return null;
+}
+
+/**
+ * Convert proxy settings into an Electron proxy config.
+ */
+export function toProxyConfig(settings: ProxySettings): Electron.ProxyConfig {
+    if (settings.mode === 'system' || settings.mode === 'direct') {
+        return { mode: settings.mode };
+    }
+
+    const scheme = settings.mode === 'socks5' ? 'socks5' : 'http';
+    return {
+        mode: 'fixed_servers',
+        proxyRules: `${scheme}://${settings.host}:${settings.port}`,
+        // Chromium expects ';' or ',' separated rules; normalize whitespace from the settings field
+        proxyBypassRules: settings.bypass
+            .split(/[,;\s]+/)
+            .filter(Boolean)
+            .join(','),
+    };
+}
+
+/**
+ * Apply proxy settings to a session and drop connections made through the old proxy.
+ */
+export async function applyProxySettings(targetSession: Session, settings: ProxySettings): Promise<void> {
+    await targetSession.setProxy(toProxyConfig(settings));
+    await targetSession.closeAllConnections();
+}
+
+/**
+ * Encrypt a proxy password for storage, when the OS keychain is available.
+ */
+export function encryptProxyPassword(password: string): string {
+    return encryptSecret(password);
+}
+
+/**
+ * Decrypt a stored proxy password. Plain-text values are returned as-is.
+ */
+export function decryptProxyPassword(stored: string): string {
+    return decryptSecret(stored);
+}
+
+/**
+ * Check that a request through the session's current proxy reaches Gemini.
+ */
+export async function testProxyConnection(targetSession: Session): Promise<ProxyTestResult> {
+    const started = Date.now();
+    try {
+        const response = await targetSession.fetch(GEMINI_APP_URL, {
+            method: 'HEAD',
+            redirect: 'manual',
+            signal: AbortSignal.timeout(PROXY_TEST_TIMEOUT_MS),
+        });
+        return { success: true, statusCode: response.status, latencyMs: Date.now() - started };
+    } catch (error) {
+        return { success: false, error: (error as Error).message };
+    }
+}
@@ -0,0 +1,473 @@
+/**
+ * QR code encoder.
+ *
+ * Encodes text as a byte-mode QR code (ISO/IEC 18004, error correction level
+ * M, versions 1–40) entirely in process, so nothing shared as a QR code is
+ * sent to a QR service. Follows the reference algorithm: pick the smallest
+ * version that fits, add Reed–Solomon error correction, place the codewords
+ * around the function patterns and apply the mask with the lowest penalty.
+ *
+ * @module qrCode
+ */
+
+/** Error correction codewords per block for level M, indexed by version */
+const ECC_CODEWORDS_PER_BLOCK = [
+    -1, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28, 28, 28, 28, 28, 28, 28,
+    28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+];
+
+/** Error correction blocks for level M, indexed by version */
+const NUM_ERROR_CORRECTION_BLOCKS = [
+    -1, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21, 23, 25, 26, 28, 29, 31,
+    33, 35, 37, 38, 40, 43, 45, 47, 49,
+];
+
+/** Format bits identifying error correction level M */
+const ECC_LEVEL_M_FORMAT_BITS = 0;
+
+const MIN_VERSION = 1;
+const MAX_VERSION = 40;
+
+/** Penalty weights used to choose the mask */
+const PENALTY_N1 = 3;
+const PENALTY_N2 = 3;
+const PENALTY_N3 = 40;
+const PENALTY_N4 = 10;
+
+/**
+ * Largest number of UTF-8 bytes a QR code can hold at level M (version 40).
+ */
+export const QR_CODE_MAX_BYTES = 2331;
+
+/**
+ * Get bit i of x.
+ */
+function getBit(x: number, i: number): boolean {
+    return ((x >>> i) & 1) !== 0;
+}
+
+/**
+ * Modules available for data and error correction in a version.
+ */
+function getNumRawDataModules(version: number): number {
+    let result = (16 * version + 128) * version + 64;
+    if (version >= 2) {
+        const numAlign = Math.floor(version / 7) + 2;
+        result -= (25 * numAlign - 10) * numAlign - 55;
+        if (version >= 7) result -= 36;
+    }
+    return result;
+}
+
+/**
+ * Data codewords (excluding error correction) a version holds at level M.
+ */
+function getNumDataCodewords(version: number): number {
+    return (
+        Math.floor(getNumRawDataModules(version) / 8) -
+        ECC_CODEWORDS_PER_BLOCK[version]! * NUM_ERROR_CORRECTION_BLOCKS[version]!
+    );
+}
+
+/**
+ * Multiply two elements of GF(2^8) modulo x^8 + x^4 + x^3 + x^2 + 1.
+ */
+function gfMultiply(x: number, y: number): number {
+    let z = 0;
+    for (let i = 7; i >= 0; i--) {
+        z = (z << 1) ^ ((z >>> 7) * 0x11d);
+        z ^= ((y >>> i) & 1) * x;
+    }
+    return z & 0xff;
+}
+
+/**
+ * Reed–Solomon generator polynomial of the given degree, highest term omitted.
+ */
+function reedSolomonDivisor(degree: number): number[] {
+    const result = new Array<number>(degree).fill(0);
+    result[degree - 1] = 1;
+    let root = 1;
+    for (let i = 0; i < degree; i++) {
+        for (let j = 0; j < result.length; j++) {
+            result[j] = gfMultiply(result[j]!, root);
+            if (j + 1 < result.length) result[j] = result[j]! ^ result[j + 1]!;
+        }
+        root = gfMultiply(root, 0x02);
+    }
+    return result;
+}
+
+/**
+ * Reed–Solomon error correction codewords for a block of data.
+ * @param data - Data codewords
+ * @param degree - Number of error correction codewords
+ */
+export function reedSolomonRemainder(data: readonly number[], degree: number): number[] {
+    const divisor = reedSolomonDivisor(degree);
+    const result = new Array<number>(degree).fill(0);
+    for (const byte of data) {
+        const factor = byte ^ result.shift()!;
+        result.push(0);
+        divisor.forEach((coefficient, i) => {
+            result[i] = result[i]! ^ gfMultiply(coefficient, factor);
+        });
+    }
+    return result;
+}
+
+/**
+ * Build the data codewords: byte mode header, the bytes, terminator and padding.
+ */
+function buildDataCodewords(bytes: Uint8Array, version: number): number[] {
+    const bits: number[] = [];
+    const append = (value: number, length: number) => {
+        for (let i = length - 1; i >= 0; i--) bits.push((value >>> i) & 1);
+    };
+
+    append(0b0100, 4);
+    append(bytes.length, version <= 9 ? 8 : 16);
+    for (const byte of bytes) append(byte, 8);
+
+    const capacity = getNumDataCodewords(version) * 8;
+    append(0, Math.min(4, capacity - bits.length));
+    append(0, (8 - (bits.length % 8)) % 8);
+    for (let pad = 0xec; bits.length < capacity; pad ^= 0xec ^ 0x11) append(pad, 8);
+
+    const codewords: number[] = [];
+    for (let i = 0; i < bits.length; i += 8) {
+        codewords.push(bits.slice(i, i + 8).reduce((byte, bit) => (byte << 1) | bit, 0));
+    }
+    return codewords;
+}
+
+/**
+ * Split the data into blocks, add error correction to each and interleave them.
+ */
+function addEccAndInterleave(data: readonly number[], version: number): number[] {
+    const numBlocks = NUM_ERROR_CORRECTION_BLOCKS[version]!;
+    const blockEccLength = ECC_CODEWORDS_PER_BLOCK[version]!;
+    const rawCodewords = Math.floor(getNumRawDataModules(version) / 8);
+    const numShortBlocks = numBlocks - (rawCodewords % numBlocks);
+    const shortBlockLength = Math.floor(rawCodewords / numBlocks);
+
+    const blocks: number[][] = [];
+    for (let i = 0, offset = 0; i < numBlocks; i++) {
+        const length = shortBlockLength - blockEccLength + (i < numShortBlocks ? 0 : 1);
+        const block = data.slice(offset, offset + length);
+        offset += length;
+        const ecc = reedSolomonRemainder(block, blockEccLength);
+        // Short blocks get a placeholder so every block has the same length
+        if (i < numShortBlocks) block.push(0);
+        blocks.push([...block, ...ecc]);
+    }
+
+    const result: number[] = [];
+    for (let i = 0; i < blocks[0]!.length; i++) {
+        blocks.forEach((block, j) => {
+            if (i !== shortBlockLength - blockEccLength || j >= numShortBlocks) result.push(block[i]!);
+        });
+    }
+    return result;
+}
+
+/**
+ * Module grid under construction.
+ */
+class QrMatrix {
+    readonly size: number;
+    readonly modules: boolean[][];
+    private readonly version: number;
+    private readonly isFunction: boolean[][];
+
+    constructor(version: number) {
+        this.version = version;
+        this.size = version * 4 + 17;
+        this.modules = Array.from({ length: this.size }, () => new Array<boolean>(this.size).fill(false));
+        this.isFunction = Array.from({ length: this.size }, () => new Array<boolean>(this.size).fill(false));
+        this.drawFunctionPatterns();
+    }
+
+    private setFunctionModule(x: number, y: number, dark: boolean): void {
+        this.modules[y]![x] = dark;
+        this.isFunction[y]![x] = true;
+    }
+
+    private drawFunctionPatterns(): void {
+        for (let i = 0; i < this.size; i++) {
+            this.setFunctionModule(6, i, i % 2 === 0);
+            this.setFunctionModule(i, 6, i % 2 === 0);
+        }
+
+        this.drawFinderPattern(3, 3);
+        this.drawFinderPattern(this.size - 4, 3);
+        this.drawFinderPattern(3, this.size - 4);
+
+        const positions = this.getAlignmentPatternPositions();
+        const last = positions.length - 1;
+        positions.forEach((x, i) => {
+            positions.forEach((y, j) => {
+                // Skip the three corners taken by finder patterns
+                if ((i === 0 && j === 0) || (i === 0 && j === last) || (i === last && j === 0)) return;
+                this.drawAlignmentPattern(x, y);
+            });
+        });
+
+        // Reserve the format areas; the real bits are drawn with the mask
+        this.drawFormatBits(0);
+        this.drawVersion();
+    }
+
+    private drawFinderPattern(x: number, y: number): void {
+        for (let dy = -4; dy <= 4; dy++) {
+            for (let dx = -4; dx <= 4; dx++) {
+                const distance = Math.max(Math.abs(dx), Math.abs(dy));
+                const xx = x + dx;
+                const yy = y + dy;
+                if (xx >= 0 && xx < this.size && yy >= 0 && yy < this.size) {
+                    this.setFunctionModule(xx, yy, distance !== 2 && distance !== 4);
+                }
+            }
+        }
+    }
+
+    private drawAlignmentPattern(x: number, y: number): void {
+        for (let dy = -2; dy <= 2; dy++) {
+            for (let dx = -2; dx <= 2; dx++) {
+                this.setFunctionModule(x + dx, y + dy, Math.max(Math.abs(dx), Math.abs(dy)) !== 1);
+            }
+        }
+    }
+
+    private getAlignmentPatternPositions(): number[] {
+        if (this.version === 1) return [];
+        const numAlign = Math.floor(this.version / 7) + 2;
+        const step = Math.floor((this.version * 8 + numAlign * 3 + 5) / (numAlign * 4 - 4)) * 2;
+        const result = [6];
+        for (let position = this.size - 7; result.length < numAlign; position -= step) {
+            result.splice(1, 0, position);
+        }
+        return result;
+    }
+
+    /**
+     * Draw both copies of the format bits (error correction level and mask).
+     */
+    drawFormatBits(mask: number): void {
+        const data = (ECC_LEVEL_M_FORMAT_BITS << 3) | mask;
+        let remainder = data;
+        for (let i = 0; i < 10; i++) remainder = (remainder << 1) ^ ((remainder >>> 9) * 0x537);
+        const bits = ((data << 10) | remainder) ^ 0x5412;
+
+        for (let i = 0; i <= 5; i++) this.setFunctionModule(8, i, getBit(bits, i));
+        this.setFunctionModule(8, 7, getBit(bits, 6));
+        this.setFunctionModule(8, 8, getBit(bits, 7));
+        this.setFunctionModule(7, 8, getBit(bits, 8));
+        for (let i = 9; i < 15; i++) this.setFunctionModule(14 - i, 8, getBit(bits, i));
+
+        for (let i = 0; i < 8; i++) this.setFunctionModule(this.size - 1 - i, 8, getBit(bits, i));
+        for (let i = 8; i < 15; i++) this.setFunctionModule(8, this.size - 15 + i, getBit(bits, i));
+        // Always dark
+        this.setFunctionModule(8, this.size - 8, true);
+    }
+
+    private drawVersion(): void {
+        if (this.version < 7) return;
+
+        let remainder = this.version;
+        for (let i = 0; i < 12; i++) remainder = (remainder << 1) ^ ((remainder >>> 11) * 0x1f25);
+        const bits = (this.version << 12) | remainder;
+
+        for (let i = 0; i < 18; i++) {
+            const dark = getBit(bits, i);
+            const a = this.size - 11 + (i % 3);
+            const b = Math.floor(i / 3);
+            this.setFunctionModule(a, b, dark);
+            this.setFunctionModule(b, a, dark);
+        }
+    }
+
+    /**
+     * Place the codewords in the zigzag order, skipping function modules.
+     */
+    drawCodewords(codewords: readonly number[]): void {
+        let i = 0;
+        for (let right = this.size - 1; right >= 1; right -= 2) {
+            if (right === 6) right = 5;
+            for (let vertical = 0; vertical < this.size; vertical++) {
+                for (let j = 0; j < 2; j++) {
+                    const x = right - j;
+                    const upward = ((right + 1) & 2) === 0;
+                    const y = upward ? this.size - 1 - vertical : vertical;
+                    if (!this.isFunction[y]![x] && i < codewords.length * 8) {
+                        this.modules[y]![x] = getBit(codewords[i >>> 3]!, 7 - (i & 7));
+                        i++;
+                    }
+                }
+            }
+        }
+    }
+
+    /**
+     * Flip the data modules selected by a mask; applying it twice undoes it.
+     */
+    applyMask(mask: number): void {
+        for (let y = 0; y < this.size; y++) {
+            for (let x = 0; x < this.size; x++) {
+                if (!this.isFunction[y]![x] && isMasked(mask, x, y)) {
+                    this.modules[y]![x] = !this.modules[y]![x];
+                }
+            }
+        }
+    }
+
+    /**
+     * Penalty for patterns that make a code hard to scan; lower is better.
+     */
+    getPenaltyScore(): number {
+        let result = 0;
+        const size = this.size;
+
+        for (let line = 0; line < 2; line++) {
+            const at = (a: number, b: number) => (line === 0 ? this.modules[a]![b]! : this.modules[b]![a]!);
+            for (let a = 0; a < size; a++) {
+                let runColor = false;
+                let runLength = 0;
+                const runHistory = [0, 0, 0, 0, 0, 0, 0];
+                for (let b = 0; b < size; b++) {
+                    if (at(a, b) === runColor) {
+                        runLength++;
+                        if (runLength === 5) result += PENALTY_N1;
+                        else if (runLength > 5) result++;
+                    } else {
+                        this.addRunToHistory(runLength, runHistory);
+                        if (!runColor) result += this.countFinderLikePatterns(runHistory) * PENALTY_N3;
+                        runColor = at(a, b);
+                        runLength = 1;
+                    }
+                }
+                if (runColor) {
+                    this.addRunToHistory(runLength, runHistory);
+                    runLength = 0;
+                }
+                // The light border after the last run
+                this.addRunToHistory(runLength + size, runHistory);
+                result += this.countFinderLikePatterns(runHistory) * PENALTY_N3;
+            }
+        }
+
+        for (let y = 0; y < size - 1; y++) {
+            for (let x = 0; x < size - 1; x++) {
+                const color = this.modules[y]![x];
+                if (
+                    color === this.modules[y]![x + 1] &&
+                    color === this.modules[y + 1]![x] &&
+                    color === this.modules[y + 1]![x + 1]
+                ) {
+                    result += PENALTY_N2;
+                }
+            }
+        }
+
+        const dark = this.modules.reduce((sum, row) => sum + row.filter(Boolean).length, 0);
+        const total = size * size;
+        const k = Math.ceil(Math.abs(dark * 20 - total * 10) / total) - 1;
+        return result + k * PENALTY_N4;
+    }
+
+    private addRunToHistory(runLength: number, runHistory: number[]): void {
+        // The light border before the first run
+        if (runHistory[0] === 0) runLength += this.size;
+        runHistory.pop();
+        runHistory.unshift(runLength);
+    }
+
+    private countFinderLikePatterns(runHistory: readonly number[]): number {
+        const [before = 0, n = 0, b = 0, c = 0, d = 0, e = 0, after = 0] = runHistory;
+        const core = n > 0 && b === n && c === n * 3 && d === n && e === n;
+        return (core && before >= n * 4 && after >= n ? 1 : 0) + (core && after >= n * 4 && before >= n ? 1 : 0);
+    }
+}
+
+/**
+ * Whether a mask pattern flips the module at (x, y).
+ */
+function isMasked(mask: number, x: number, y: number): boolean {
+    switch (mask) {
+        case 0:
+            return (x + y) % 2 === 0;
+        case 1:
+            return y % 2 === 0;
+        case 2:
+            return x % 3 === 0;
+        case 3:
+            return (x + y) % 3 === 0;
+        case 4:
+            return (Math.floor(x / 3) + Math.floor(y / 2)) % 2 === 0;
+        case 5:
+            return ((x * y) % 2) + ((x * y) % 3) === 0;
+        case 6:
+            return (((x * y) % 2) + ((x * y) % 3)) % 2 === 0;
+        default:
+            return (((x + y) % 2) + ((x * y) % 3)) % 2 === 0;
+    }
+}
+
+/**
+ * Encode text as a QR code.
+ * @param text - Text to encode, as UTF-8
+ * @returns Rows of modules, true for dark, without the quiet zone
+ * @throws If the text is longer than QR_CODE_MAX_BYTES
+ */
+export function encodeQrCode(text: string): boolean[][] {
+    const bytes = new TextEncoder().encode(text);
+
+    let version = MIN_VERSION;
+    for (; version <= MAX_VERSION; version++) {
+        const headerBits = 4 + (version <= 9 ? 8 : 16);
+        if (headerBits + bytes.length * 8 <= getNumDataCodewords(version) * 8) break;
+    }
+    if (version > MAX_VERSION) {
+        throw new Error(`Text is too long for a QR code (${bytes.length} of at most ${QR_CODE_MAX_BYTES} bytes)`);
+    }
+
+    const matrix = new QrMatrix(version);
+    matrix.drawCodewords(addEccAndInterleave(buildDataCodewords(bytes, version), version));
+
+    let bestMask = 0;
+    let bestPenalty = Infinity;
+    for (let mask = 0; mask < 8; mask++) {
+        matrix.applyMask(mask);
+        matrix.drawFormatBits(mask);
+        const penalty = matrix.getPenaltyScore();
+        if (penalty < bestPenalty) {
+            bestMask = mask;
+            bestPenalty = penalty;
+        }
+        matrix.applyMask(mask);
+    }
+    matrix.applyMask(bestMask);
+    matrix.drawFormatBits(bestMask);
+
+    return matrix.modules;
+}
+
+/**
+ * Render QR code modules as an SVG image.
+ * @param modules - Rows of modules from encodeQrCode
+ * @param border - Quiet zone in modules (the standard asks for 4)
+ * @returns SVG markup
+ */
+export function qrCodeToSvg(modules: readonly (readonly boolean[])[], border = 4): string {
+    const size = modules.length + border * 2;
+    const path: string[] = [];
+    modules.forEach((row, y) => {
+        row.forEach((dark, x) => {
+            if (dark) path.push(`M${x + border},${y + border}h1v1h-1z`);
+        });
+    });
+    return (
+        `<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 ${size} ${size}" shape-rendering="crispEdges">` +
+        `<rect width="100%" height="100%" fill="#fff"/><path d="${path.join('')}" fill="#000"/></svg>`
+    );
+}
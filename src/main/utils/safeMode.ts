@@ -0,0 +1,23 @@
+/**
+ * Safe mode.
+ *
+ * Starting the app with `--safe-mode` runs it with default settings held in
+ * memory only (nothing is read from or written to the settings files) and
+ * with user customizations disabled. This lets users check whether a
+ * customization or a bad setting is causing problems such as a white screen.
+ *
+ * @module safeMode
+ */
+
+/** Command-line switch that enables safe mode */
+export const SAFE_MODE_SWITCH = '--safe-mode';
+
+/**
+ * Check whether the app was started in safe mode.
+ *
+ * @param argv - Command-line arguments (defaults to process.argv)
+ * @returns True if `--safe-mode` was passed
+ */
+export function isSafeMode(argv: readonly string[] = process.argv): boolean {
+    return argv.includes(SAFE_MODE_SWITCH);
+}
@@ -0,0 +1,73 @@
+/**
+ * Screenshot helpers.
+ *
+ * Works out which part of the main window to capture. Gemini runs in an
+ * iframe, so rectangles measured inside the Gemini document are offset by the
+ * iframe's position in the app shell before capturing.
+ *
+ * @module screenshot
+ */
+
+import type { Rectangle } from 'electron';
+import { SCREENSHOT_REGIONS, SCREENSHOT_TARGETS, type ScreenshotOptions } from '../../shared/types/screenshot';
+
+/** Filename template for saved screenshots (see exportFilename) */
+export const SCREENSHOT_FILENAME_TEMPLATE = 'gemini-screenshot_{date}_{time}';
+
+/**
+ * Script run in the app shell: the Gemini iframe's rectangle in the window.
+ */
+export const GEMINI_IFRAME_RECT_SCRIPT = `
+(() => {
+    const iframe = document.querySelector('[data-testid="gemini-iframe"]');
+    if (!iframe) return null;
+    const { x, y, width, height } = iframe.getBoundingClientRect();
+    return { x, y, width, height };
+})()
+`;
+
+/**
+ * Script run in the Gemini frame: scrolls the latest answer into view and
+ * returns its rectangle in the frame. Uses the same selectors as chat extraction.
+ */
+export const LAST_RESPONSE_RECT_SCRIPT = `
+(() => {
+    for (const selector of ['.model-response', 'model-response', '.markdown']) {
+        const responses = document.querySelectorAll(selector);
+        if (responses.length === 0) continue;
+        const last = responses[responses.length - 1];
+        last.scrollIntoView({ block: 'nearest' });
+        const { x, y, width, height } = last.getBoundingClientRect();
+        return { x, y, width, height };
+    }
+    return null;
+})()
+`;
+
+/**
+ * Check screenshot options received over IPC.
+ */
+export function isValidScreenshotOptions(options: unknown): options is ScreenshotOptions {
+    if (!options || typeof options !== 'object') return false;
+    const { target, region } = options as Partial<ScreenshotOptions>;
+    return SCREENSHOT_TARGETS.includes(target!) && SCREENSHOT_REGIONS.includes(region!);
+}
+
+/**
+ * Convert a rectangle measured inside the Gemini frame to window coordinates,
+ * clipped to the visible frame.
+ * @param frameRect - Gemini iframe rectangle in the window
+ * @param innerRect - Rectangle inside the Gemini frame; omit for the whole frame
+ * @returns Whole-pixel rectangle to capture, or null if nothing is visible
+ */
+export function toCaptureRect(frameRect: Rectangle, innerRect?: Rectangle): Rectangle | null {
+    const rect = innerRect ? { ...innerRect, x: frameRect.x + innerRect.x, y: frameRect.y + innerRect.y } : frameRect;
+
+    const left = Math.max(Math.floor(rect.x), Math.floor(frameRect.x));
+    const top = Math.max(Math.floor(rect.y), Math.floor(frameRect.y));
+    const right = Math.min(Math.ceil(rect.x + rect.width), Math.ceil(frameRect.x + frameRect.width));
+    const bottom = Math.min(Math.ceil(rect.y + rect.height), Math.ceil(frameRect.y + frameRect.height));
+
+    if (right <= left || bottom <= top) return null;
+    return { x: left, y: top, width: right - left, height: bottom - top };
+}
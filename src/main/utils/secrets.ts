@@ -0,0 +1,34 @@
+/**
+ * Secret storage helpers.
+ *
+ * Encrypts credentials kept in the settings file with the OS keychain
+ * (Electron safeStorage) when it is available.
+ *
+ * @module secrets
+ */
+
+import { safeStorage } from 'electron';
+
+/** Prefix marking a value encrypted with safeStorage */
+const ENCRYPTED_PREFIX = 'enc:';
+
+/**
+ * Encrypt a secret for storage, when the OS keychain is available.
+ */
+export function encryptSecret(secret: string): string {
+    if (!secret || !safeStorage.isEncryptionAvailable()) return secret;
+    return ENCRYPTED_PREFIX + safeStorage.encryptString(secret).toString('base64');
+}
+
+/**
+ * Decrypt a stored secret. Plain-text values are returned as-is.
+ */
+export function decryptSecret(stored: string): string {
+    if (!stored.startsWith(ENCRYPTED_PREFIX)) return stored;
+    try {
+        return safeStorage.decryptString(Buffer.from(stored.slice(ENCRYPTED_PREFIX.length), 'base64'));
+    } catch {
+        // Keychain changed or unavailable; the user has to enter the secret again
+        return '';
+    }
+}
@@ -6,9 +6,131 @@
 
 import type { Session, App } from 'electron';
 import { createLogger } from './logger';
+import SettingsStore from '../store';
 
 const logger = createLogger('[SecurityManager]');
 
+/**
+ * Per-path override of the header policy.
+ * Fields that are set replace the base policy's values for matching requests.
+ */
+export interface HeaderPolicyOverride {
+    /** URL path prefix this override applies to (e.g. '/_/BardChatUi/') */
+    pathPrefix: string;
+    strip?: string[];
+    passThrough?: string[];
+    stripFrameAncestors?: boolean;
+}
+
+/**
+ * Response header policy for Gemini-related domains.
+ *
+ * Loaded from `header-policy.json` in the user data directory, so it can be
+ * tuned without rebuilding the app. Header names are case-insensitive.
+ */
+export interface HeaderPolicy {
+    /** Response headers to remove entirely */
+    strip: string[];
+    /** Response headers to leave untouched, even if listed in `strip` or a CSP header */
+    passThrough: string[];
+    /** Remove the frame-ancestors directive from Content-Security-Policy headers */
+    stripFrameAncestors: boolean;
+    /** Per-path overrides; the longest matching prefix wins */
+    overrides: HeaderPolicyOverride[];
+}
+
+/**
+ * Default policy: the minimum needed to embed Gemini in an iframe.
+ */
+export const DEFAULT_HEADER_POLICY: HeaderPolicy = {
+    strip: ['x-frame-options'],
+    passThrough: [],
+    stripFrameAncestors: true,
+    overrides: [],
+};
+
+const CSP_HEADER = 'content-security-policy';
+
+function toHeaderList(value: unknown): string[] | undefined {
+    if (!Array.isArray(value)) return undefined;
+    return value.filter((item): item is string => typeof item === 'string').map((item) => item.toLowerCase());
+}
+
+/**
+ * Validate a raw (user-edited) policy, falling back to defaults for invalid fields.
+ *
+ * @param raw - Parsed policy object
+ * @returns A well-formed header policy
+ */
+export function normalizeHeaderPolicy(raw: unknown): HeaderPolicy {
+    const source = (raw && typeof raw === 'object' ? raw : {}) as Record<string, unknown>;
+
+    const overrides = Array.isArray(source.overrides)
+        ? source.overrides
+              .filter(
+                  (item): item is Record<string, unknown> =>
+                      !!item && typeof item === 'object' && typeof item.pathPrefix === 'string'
+              )
+              .map((item) => ({
+                  pathPrefix: item.pathPrefix as string,
+                  strip: toHeaderList(item.strip),
+                  passThrough: toHeaderList(item.passThrough),
+                  stripFrameAncestors:
+                      typeof item.stripFrameAncestors === 'boolean' ? item.stripFrameAncestors : undefined,
+              }))
+        : [];
+
+    return {
+        strip: toHeaderList(source.strip) ?? DEFAULT_HEADER_POLICY.strip,
+        passThrough: toHeaderList(source.passThrough) ?? DEFAULT_HEADER_POLICY.passThrough,
+        stripFrameAncestors:
+            typeof source.stripFrameAncestors === 'boolean'
+                ? source.stripFrameAncestors
+                : DEFAULT_HEADER_POLICY.stripFrameAncestors,
+        overrides,
+    };
+}
+
+/**
+ * Load the header policy from `header-policy.json` in the user data directory.
+ * Missing or invalid files fall back to {@link DEFAULT_HEADER_POLICY}.
+ *
+ * @returns The effective header policy
+ */
+export function loadHeaderPolicy(): HeaderPolicy {
+    const store = new SettingsStore<Record<string, unknown>>({
+        configName: 'header-policy',
+        defaults: { ...DEFAULT_HEADER_POLICY },
+    });
+    return normalizeHeaderPolicy(store.getAll());
+}
+
+/**
+ * Resolve the policy for a request URL by applying the longest matching path override.
+ *
+ * @param policy - Base policy
+ * @param url - Request URL
+ * @returns Effective policy fields for the URL
+ */
+export function resolveHeaderPolicy(policy: HeaderPolicy, url: string | undefined): Omit<HeaderPolicy, 'overrides'> {
+    let pathname = '';
+    try {
+        pathname = url ? new URL(url).pathname : '';
+    } catch {
+        // Unparseable URL: only the base policy applies
+    }
+
+    const override = policy.overrides
+        .filter((candidate) => pathname.startsWith(candidate.pathPrefix))
+        .sort((a, b) => b.pathPrefix.length - a.pathPrefix.length)[0];
+
+    return {
+        strip: override?.strip ?? policy.strip,
+        passThrough: override?.passThrough ?? policy.passThrough,
+        stripFrameAncestors: override?.stripFrameAncestors ?? policy.stripFrameAncestors,
+    };
+}
+
 /**
  * Strip security headers that prevent iframe embedding.
  * This is the key to making custom HTML menus work over external content.
@@ -16,8 +138,9 @@ const logger = createLogger('[SecurityManager]');
  * SECURITY: Only strips headers for Gemini domains to minimize attack surface.
  *
  * @param session - The default session
+ * @param policy - Which headers to strip or pass through (defaults to {@link DEFAULT_HEADER_POLICY})
  */
-export function setupHeaderStripping(session: Session): void {
+export function setupHeaderStripping(session: Session, policy: HeaderPolicy = DEFAULT_HEADER_POLICY): void {
     // Only modify headers for Gemini-related domains
     const allowedUrls = [
         '*://gemini.google.com/*',
@@ -33,21 +156,21 @@ export function setupHeaderStripping(session: Session): void {
 
     session.webRequest.onHeadersReceived({ urls: allowedUrls }, (details, callback) => {
         const responseHeaders = { ...details.responseHeaders };
+        const { strip, passThrough, stripFrameAncestors } = resolveHeaderPolicy(policy, details.url);
 
-        // Remove X-Frame-Options header (case-insensitive)
-        delete responseHeaders['x-frame-options'];
-        delete responseHeaders['X-Frame-Options'];
+        // Header names are matched case-insensitively
+        for (const name of Object.keys(responseHeaders)) {
+            const lowerName = name.toLowerCase();
+            if (passThrough.includes(lowerName)) continue;
 
-        // Remove frame-ancestors from CSP if present
-        if (responseHeaders['content-security-policy']) {
-            responseHeaders['content-security-policy'] = responseHeaders['content-security-policy'].map((csp) =>
-                csp.replace(/frame-ancestors[^;]*(;|$)/gi, '')
-            );
-        }
-        if (responseHeaders['Content-Security-Policy']) {
-            responseHeaders['Content-Security-Policy'] = responseHeaders['Content-Security-Policy'].map((csp) =>
-                csp.replace(/frame-ancestors[^;]*(;|$)/gi, '')
-            );
+            if (strip.includes(lowerName)) {
+                delete responseHeaders[name];
+            } else if (lowerName === CSP_HEADER && stripFrameAncestors) {
+                // Remove frame-ancestors from CSP if present
+                responseHeaders[name] = responseHeaders[name]!.map((csp) =>
+                    csp.replace(/frame-ancestors[^;]*(;|$)/gi, '')
+                );
+            }
         }
 
         callback({ responseHeaders });
@@ -0,0 +1,118 @@
+/**
+ * Settings file schema validation.
+ *
+ * Checks values loaded from a settings JSON file against a flat schema and
+ * reports precise, user-facing errors (key, expected value, line in the file)
+ * instead of silently falling back to defaults.
+ *
+ * @module settingsSchema
+ */
+
+/**
+ * Expected shape of a single setting.
+ */
+export interface SettingsFieldSchema {
+    type: 'boolean' | 'string' | 'number';
+    /** Allowed values */
+    enum?: readonly (string | number)[];
+    /** Inclusive bounds for numbers */
+    min?: number;
+    max?: number;
+}
+
+/**
+ * Schema for a settings file, keyed by setting name. Keys not in the schema are not checked.
+ */
+export type SettingsSchema = Record<string, SettingsFieldSchema>;
+
+/**
+ * A problem found in a settings file.
+ */
+export interface SettingsValidationIssue {
+    /** Offending setting, or null when the file itself could not be parsed */
+    key: string | null;
+    /** What is wrong, e.g. "theme must be one of light|dark|system" */
+    message: string;
+    /** 1-based line in the settings file, if known */
+    line: number | null;
+}
+
+/**
+ * Find the line of a top-level key in JSON source.
+ * @returns 1-based line number, or null if the key is not found
+ */
+function findKeyLine(source: string, key: string): number | null {
+    const index = source.indexOf(JSON.stringify(key));
+    if (index === -1) return null;
+    return source.slice(0, index).split('\n').length;
+}
+
+/**
+ * Describe the expected value of a field.
+ */
+function describeExpected(field: SettingsFieldSchema): string {
+    if (field.enum) return `be one of ${field.enum.join('|')}`;
+    if (field.type === 'number' && field.min !== undefined && field.max !== undefined) {
+        return `be a number between ${field.min} and ${field.max}`;
+    }
+    return `be a ${field.type}`;
+}
+
+function isValid(value: unknown, field: SettingsFieldSchema): boolean {
+    if (typeof value !== field.type) return false;
+    if (field.type === 'number' && !Number.isFinite(value)) return false;
+    if (field.enum && !field.enum.includes(value as string | number)) return false;
+    if (field.min !== undefined && (value as number) < field.min) return false;
+    if (field.max !== undefined && (value as number) > field.max) return false;
+    return true;
+}
+
+/**
+ * Validate parsed settings against a schema.
+ *
+ * @param data - Parsed settings file contents
+ * @param schema - Expected settings
+ * @param source - Raw file contents, used to locate errors
+ * @returns Issues found (empty if valid)
+ */
+export function validateSettings(data: unknown, schema: SettingsSchema, source = ''): SettingsValidationIssue[] {
+    if (typeof data !== 'object' || data === null || Array.isArray(data)) {
+        return [{ key: null, message: 'settings file must contain a JSON object', line: 1 }];
+    }
+
+    const issues: SettingsValidationIssue[] = [];
+    for (const [key, field] of Object.entries(schema)) {
+        if (!(key in data)) continue;
+
+        const value = (data as Record<string, unknown>)[key];
+        if (!isValid(value, field)) {
+            issues.push({
+                key,
+                message: `${key} must ${describeExpected(field)} (got ${JSON.stringify(value)})`,
+                line: findKeyLine(source, key),
+            });
+        }
+    }
+    return issues;
+}
+
+/**
+ * Convert a JSON.parse error into an issue with the line of the syntax error.
+ *
+ * @param error - Error thrown by JSON.parse
+ * @param source - Raw file contents
+ */
+export function parseErrorToIssue(error: Error, source: string): SettingsValidationIssue {
+    const position = /position (\d+)/.exec(error.message);
+    // Truncated files report "Unexpected end of JSON input" without a position
+    const offset = position ? Number(position[1]) : /end of JSON/.test(error.message) ? source.length : null;
+    const line = offset === null ? null : source.slice(0, offset).split('\n').length;
+    return { key: null, message: `invalid JSON: ${error.message}`, line };
+}
+
+/**
+ * Format an issue for display, e.g. "theme must be one of light|dark|system (got "blue") at line 3".
+ */
+export function formatValidationIssue(issue: SettingsValidationIssue): string {
+    return issue.line === null ? issue.message : `${issue.message} at line ${issue.line}`;
+}
@@ -0,0 +1,80 @@
+/**
+ * Test event stream.
+ *
+ * Starting the app with `--test-ipc` writes lifecycle events to stdout as one
+ * JSON object per line, prefixed with `[test-event] ` so they can be told
+ * apart from log output. External E2E frameworks can wait on these events
+ * (window created, Gemini loaded, export done) instead of polling screenshots.
+ *
+ * Events carry a sequence number but no timestamps, so runs of the same
+ * scenario produce the same stream.
+ *
+ * @module testEvents
+ */
+
+/** Command-line switch that enables the test event stream */
+export const TEST_IPC_SWITCH = '--test-ipc';
+
+/** Prefix of every test event line */
+export const TEST_EVENT_PREFIX = '[test-event] ';
+
+/**
+ * A single test event as written to stdout.
+ */
+export interface TestEvent {
+    /** Position in the stream, starting at 1 */
+    seq: number;
+    /** Event name, e.g. 'main-window-created' */
+    event: string;
+    /** Event details, if any */
+    data?: unknown;
+}
+
+/** Writes a line to the stream, or null while the stream is disabled */
+let writeLine: ((line: string) => void) | null = null;
+
+/** Sequence number of the last event written */
+let seq = 0;
+
+/**
+ * Check whether the app was started with the test event stream.
+ *
+ * @param argv - Command-line arguments (defaults to process.argv)
+ * @returns True if `--test-ipc` was passed
+ */
+export function isTestIpcMode(argv: readonly string[] = process.argv): boolean {
+    return argv.includes(TEST_IPC_SWITCH);
+}
+
+/**
+ * Start writing test events.
+ * @param write - Line writer (defaults to stdout)
+ */
+export function enableTestEvents(write: (line: string) => void = (line) => process.stdout.write(line)): void {
+    writeLine = write;
+    seq = 0;
+}
+
+/**
+ * Stop writing test events.
+ */
+export function disableTestEvents(): void {
+    writeLine = null;
+}
+
+/**
+ * Write a test event. Does nothing unless the stream is enabled.
+ *
+ * @param event - Event name
+ * @param data - Event details, if any
+ */
+export function emitTestEvent(event: string, data?: unknown): void {
+    if (!writeLine) return;
+
+    const payload: TestEvent = data === undefined ? { seq: ++seq, event } : { seq: ++seq, event, data };
+    try {
+        writeLine(`${TEST_EVENT_PREFIX}${JSON.stringify(payload)}\n`);
+    } catch {
+        // A closed stdout must not take the app down
+    }
+}
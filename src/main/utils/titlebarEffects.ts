@@ -0,0 +1,53 @@
+/**
+ * Titlebar effects.
+ *
+ * Native materials that show through the transparent titlebar so the custom
+ * chrome looks like the platform's own: mica or acrylic on Windows 11 and
+ * vibrancy on macOS. Anywhere else, or on older Windows builds, only 'none'
+ * is supported and the titlebar stays opaque.
+ *
+ * @module titlebarEffects
+ */
+
+import type { BrowserWindow } from 'electron';
+import { BASE_WINDOW_CONFIG } from './constants';
+import type { TitlebarEffect } from '../../shared/types/titlebar';
+
+/** First Windows build with background materials (Windows 11 22H2) */
+export const BACKGROUND_MATERIAL_MIN_BUILD = 22621;
+
+/** Window background while an effect is on, so the material shows through */
+const TRANSPARENT_BACKGROUND = '#00000000';
+
+/**
+ * Get the titlebar effects a platform supports.
+ *
+ * @param platform - Node platform
+ * @param release - OS release, e.g. '10.0.22631' on Windows
+ * @returns Supported effects, always including 'none'
+ */
+export function getSupportedTitlebarEffects(platform: NodeJS.Platform, release: string): TitlebarEffect[] {
+    if (platform === 'darwin') {
+        return ['none', 'vibrancy'];
+    }
+    if (platform === 'win32' && Number(release.split('.')[2]) >= BACKGROUND_MATERIAL_MIN_BUILD) {
+        return ['none', 'mica', 'acrylic'];
+    }
+    return ['none'];
+}
+
+/**
+ * Apply a titlebar effect to a window. The effect must be supported on this
+ * platform (see getSupportedTitlebarEffects).
+ *
+ * @param win - Main window
+ * @param effect - Effect to apply, or 'none' to restore the opaque background
+ */
+export function applyTitlebarEffect(win: BrowserWindow, effect: TitlebarEffect): void {
+    if (process.platform === 'darwin') {
+        win.setVibrancy(effect === 'vibrancy' ? 'titlebar' : null);
+    } else if (process.platform === 'win32') {
+        win.setBackgroundMaterial(effect === 'mica' || effect === 'acrylic' ? effect : 'none');
+    }
+    win.setBackgroundColor(effect === 'none' ? BASE_WINDOW_CONFIG.backgroundColor! : TRANSPARENT_BACKGROUND);
+}
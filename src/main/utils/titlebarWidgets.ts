@@ -0,0 +1,77 @@
+/**
+ * Titlebar widget registry.
+ *
+ * Main process modules publish status indicators for the titlebar here
+ * instead of each adding its own IPC channel and titlebar component. The
+ * TitlebarIpcHandler sends every change to the renderers, which render the
+ * widgets generically.
+ *
+ * @module titlebarWidgets
+ */
+
+import { EventEmitter } from 'events';
+import type { TitlebarState, TitlebarWidget } from '../../shared/types/titlebar';
+
+/** Order of the built-in widgets */
+export const TITLEBAR_WIDGET_ORDER = {
+    focusSession: 5,
+    offline: 10,
+    readOnly: 20,
+    lowDiskSpace: 25,
+    exports: 30,
+    geminiQuota: 35,
+    profile: 40,
+} as const;
+
+const widgets = new Map<string, TitlebarWidget>();
+const emitter = new EventEmitter();
+
+/**
+ * Get the widgets to show, sorted by order.
+ */
+export function getTitlebarState(): TitlebarState {
+    return { widgets: [...widgets.values()].sort((a, b) => a.order - b.order) };
+}
+
+/**
+ * Show a widget, or replace the widget with the same ID.
+ * Emits a change only when the widget differs from the one shown.
+ *
+ * @param widget - Widget to show
+ */
+export function setTitlebarWidget(widget: TitlebarWidget): void {
+    const current = widgets.get(widget.id);
+    if (current && JSON.stringify(current) === JSON.stringify(widget)) return;
+
+    widgets.set(widget.id, widget);
+    emitter.emit('changed', getTitlebarState());
+}
+
+/**
+ * Remove a widget.
+ *
+ * @param id - Widget ID
+ */
+export function removeTitlebarWidget(id: string): void {
+    if (widgets.delete(id)) {
+        emitter.emit('changed', getTitlebarState());
+    }
+}
+
+/**
+ * Subscribe to widget changes.
+ *
+ * @param listener - Called with the new state
+ * @returns Function that unsubscribes
+ */
+export function onTitlebarStateChanged(listener: (state: TitlebarState) => void): () => void {
+    emitter.on('changed', listener);
+    return () => emitter.off('changed', listener);
+}
+
+/**
+ * Remove all widgets without emitting a change.
+ */
+export function clearTitlebarWidgets(): void {
+    widgets.clear();
+}
@@ -0,0 +1,93 @@
+/**
+ * User-Agent overrides.
+ *
+ * Google sometimes serves a degraded experience to Electron's default
+ * User-Agent (which names the app and Electron). These helpers resolve a
+ * preset or custom string and apply it to the default session, which covers
+ * the Gemini window and main-process requests.
+ *
+ * @module userAgent
+ */
+
+import { webContents, type Session } from 'electron';
+import {
+    MAX_USER_AGENT_LENGTH,
+    USER_AGENT_PRESETS,
+    type UserAgentPreset,
+    type UserAgentSettings,
+} from '../../shared/types/user-agent';
+
+/** Platform token for each Chrome preset */
+const PLATFORM_TOKENS: Record<Exclude<UserAgentPreset, 'default' | 'custom'>, string> = {
+    'chrome-windows': 'Windows NT 10.0; Win64; x64',
+    'chrome-macos': 'Macintosh; Intel Mac OS X 10_15_7',
+    'chrome-linux': 'X11; Linux x86_64',
+};
+
+/** Electron's User-Agent, captured before the first override */
+let originalUserAgent: string | null = null;
+
+/**
+ * Build the User-Agent of Chrome stable on an OS.
+ * Uses the bundled Chromium's major version in Chrome's reduced UA format, so
+ * presets stay current as Electron is upgraded.
+ *
+ * @param preset - Chrome preset
+ * @param chromeVersion - Chromium version (defaults to the bundled one)
+ */
+export function buildChromeUserAgent(
+    preset: keyof typeof PLATFORM_TOKENS,
+    chromeVersion: string = process.versions.chrome
+): string {
+    const major = chromeVersion.split('.')[0];
+    const platform = PLATFORM_TOKENS[preset];
+    return `Mozilla/5.0 (${platform}) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/${major}.0.0.0 Safari/537.36`;
+}
+
+/**
+ * Check User-Agent settings.
+ * @returns A description of the problem, or null if valid
+ */
+export function validateUserAgentSettings(settings: UserAgentSettings): string | null {
+    if (!USER_AGENT_PRESETS.includes(settings.preset)) return `Unknown User-Agent preset: ${settings.preset}`;
+    if (settings.preset !== 'custom') return null;
+
+    const custom = settings.custom.trim();
+    if (!custom) return 'Custom User-Agent is empty';
+    if (custom.length > MAX_USER_AGENT_LENGTH) return 'Custom User-Agent is too long';
+    // Header values cannot contain control characters
+    if (/[\x00-\x1f\x7f]/.test(custom)) return 'Custom User-Agent contains control characters';
+    return null;
+}
+
+/**
+ * Resolve the User-Agent string for settings.
+ * @returns The User-Agent, or null to use Electron's default
+ */
+export function resolveUserAgent(settings: UserAgentSettings): string | null {
+    switch (settings.preset) {
+        case 'default':
+            return null;
+        case 'custom':
+            return settings.custom.trim() || null;
+        default:
+            return buildChromeUserAgent(settings.preset);
+    }
+}
+
+/**
+ * Apply User-Agent settings to a session and to existing web contents.
+ * Pages pick up the new User-Agent on their next navigation.
+ *
+ * @returns The User-Agent now in effect
+ */
+export function applyUserAgent(targetSession: Session, settings: UserAgentSettings): string {
+    originalUserAgent ??= targetSession.getUserAgent();
+    const userAgent = resolveUserAgent(settings) ?? originalUserAgent;
+
+    targetSession.setUserAgent(userAgent);
+    for (const contents of webContents.getAllWebContents()) {
+        if (contents.session === targetSession) contents.setUserAgent(userAgent);
+    }
+    return userAgent;
+}
@@ -0,0 +1,60 @@
+/**
+ * User styles.
+ *
+ * Users can restyle Gemini (hide the sidebar, widen the chat column, apply a
+ * theme) with CSS in `user.css` in the app's data directory. The CSS is added
+ * to the Gemini frame as a constructed stylesheet: Gemini's Content Security
+ * Policy blocks inline <style> elements but not stylesheets built through the CSSOM.
+ *
+ * @module userStyles
+ */
+
+import * as fs from 'fs/promises';
+import * as path from 'path';
+import { app } from 'electron';
+
+/** Name of the user stylesheet in the app's data directory */
+export const USER_STYLES_FILENAME = 'user.css';
+
+/**
+ * Get the user stylesheet path.
+ * @returns Absolute path to user.css in the app's data directory
+ */
+export function getUserStylesPath(): string {
+    return path.join(app.getPath('userData'), USER_STYLES_FILENAME);
+}
+
+/**
+ * Read the user stylesheet.
+ * @param filePath - Stylesheet path (defaults to user.css in the data directory)
+ * @returns The CSS, or an empty string if there is no stylesheet
+ */
+export async function readUserStyles(filePath: string = getUserStylesPath()): Promise<string> {
+    try {
+        return await fs.readFile(filePath, 'utf-8');
+    } catch (error) {
+        if ((error as NodeJS.ErrnoException).code === 'ENOENT') return '';
+        throw error;
+    }
+}
+
+/**
+ * Build the script that applies CSS to the Gemini document. Running it again
+ * replaces the previous CSS, so reloading picks up edits and removals.
+ * @param css - User CSS
+ * @param sheetName - Window property holding the stylesheet; each name is a separate stylesheet
+ */
+export function buildUserStylesScript(css: string, sheetName = '__geminiDesktopUserStyles'): string {
+    const sheetRef = `window[${JSON.stringify(sheetName)}]`;
+    return `
+(() => {
+    let sheet = ${sheetRef};
+    if (!sheet) {
+        sheet = new CSSStyleSheet();
+        ${sheetRef} = sheet;
+        document.adoptedStyleSheets = [...document.adoptedStyleSheets, sheet];
+    }
+    sheet.replaceSync(${JSON.stringify(css)});
+})()
+`;
+}
@@ -0,0 +1,140 @@
+/**
+ * Userscripts.
+ *
+ * Loads JavaScript files from the `scripts` folder in the app's data
+ * directory. Each file may start with a metadata block in the format other
+ * userscript managers use:
+ *
+ * ```js
+ * // ==UserScript==
+ * // @name        Wide chat
+ * // @description Widen the chat column
+ * // @match       https://gemini.google.com/app*
+ * // ==/UserScript==
+ * ```
+ *
+ * Without @match a script runs on every Gemini page. Gemini is a single-page
+ * app, so scripts are matched when the page loads and again on each in-page
+ * navigation, and each script runs at most once per document.
+ *
+ * @run-at is not supported: scripts are injected from the main process once
+ * the Gemini frame has loaded, so they can never run before Gemini's own
+ * scripts (document-start), and document-end and document-idle would be the
+ * same. A script that needs to wait for an element should observe the page.
+ *
+ * @module userscripts
+ */
+
+import * as fs from 'fs/promises';
+import * as path from 'path';
+import { app } from 'electron';
+import type { UserscriptInfo } from '../../shared/types/userscripts';
+
+/** Name of the userscripts folder in the app's data directory */
+export const USERSCRIPTS_DIRNAME = 'scripts';
+
+/** Pattern used when a script has no @match */
+const DEFAULT_MATCH = 'https://gemini.google.com/*';
+
+/** Page global holding the IDs of the scripts that ran in the current document */
+const USERSCRIPTS_RAN_KEY = '__geminiDesktopUserscripts';
+
+/** Userscript file names: no path separators, .js extension */
+const USERSCRIPT_ID_PATTERN = /^[^/\\]+\.js$/;
+
+/**
+ * A loaded userscript.
+ */
+export interface Userscript extends Omit<UserscriptInfo, 'enabled'> {
+    source: string;
+}
+
+/**
+ * Get the userscripts folder.
+ * @returns Absolute path to the scripts folder in the app's data directory
+ */
+export function getUserscriptsDir(): string {
+    return path.join(app.getPath('userData'), USERSCRIPTS_DIRNAME);
+}
+
+/**
+ * Check a userscript ID (file name) received over IPC.
+ */
+export function isValidUserscriptId(id: unknown): id is string {
+    return typeof id === 'string' && USERSCRIPT_ID_PATTERN.test(id);
+}
+
+/**
+ * Parse a userscript's metadata block.
+ * @param id - File name
+ * @param source - Script source
+ */
+export function parseUserscript(id: string, source: string): Userscript {
+    const script: Userscript = { id, name: id, description: '', matches: [], source };
+
+    const block = source.match(/\/\/\s*==UserScript==([\s\S]*?)\/\/\s*==\/UserScript==/);
+    for (const [, key, value] of block?.[1].matchAll(/^\s*\/\/\s*@([\w-]+)\s+(.+?)\s*$/gm) ?? []) {
+        if (key === 'name') script.name = value;
+        else if (key === 'description') script.description = value;
+        else if (key === 'match') script.matches.push(value);
+    }
+
+    if (script.matches.length === 0) script.matches.push(DEFAULT_MATCH);
+    return script;
+}
+
+/**
+ * Check a URL against a @match pattern, where '*' matches any characters.
+ */
+export function matchesUrl(pattern: string, url: string): boolean {
+    const regex = pattern
+        .split('*')
+        .map((part) => part.replace(/[.+?^${}()|[\]\\]/g, '\\$&'))
+        .join('.*');
+    return new RegExp(`^${regex}$`).test(url);
+}
+
+/**
+ * Load all userscripts, sorted by file name.
+ * @param dir - Scripts folder (defaults to the folder in the data directory)
+ * @returns The scripts, or an empty list if the folder does not exist
+ */
+export async function loadUserscripts(dir: string = getUserscriptsDir()): Promise<Userscript[]> {
+    let files: string[];
+    try {
+        files = await fs.readdir(dir);
+    } catch (error) {
+        if ((error as NodeJS.ErrnoException).code === 'ENOENT') return [];
+        throw error;
+    }
+
+    const ids = files.filter((file) => USERSCRIPT_ID_PATTERN.test(file)).sort();
+    return Promise.all(ids.map(async (id) => parseUserscript(id, await fs.readFile(path.join(dir, id), 'utf-8'))));
+}
+
+/**
+ * Wrap a script so its variables stay out of Gemini's globals, it runs once
+ * per document however often it is injected, and its errors are logged with
+ * its name instead of stopping other scripts.
+ */
+export function wrapUserscript(script: Userscript): string {
+    // The wrapper declares no variables, since the script's own `var`s share its scope
+    const key = JSON.stringify(USERSCRIPTS_RAN_KEY);
+    const id = JSON.stringify(script.id);
+    return `(() => {
+if (window[${key}]?.has(${id})) return;
+(window[${key}] ??= new Set()).add(${id});
+try {
+${script.source}
+} catch (error) {
+    console.error(${JSON.stringify(`[Userscript] ${script.name}:`)}, error);
+}
+})()`;
+}
+
+/**
+ * Parse the enabledUserscripts preference (one file name per line).
+ */
+export function parseEnabledUserscripts(value: string): string[] {
+    return value.split('\n').filter(isValidUserscriptId);
+}
@@ -0,0 +1,76 @@
+/**
+ * What's new after an upgrade.
+ *
+ * The changelog ships with the app. At startup the running version is
+ * compared with the last version whose notes the user acknowledged, and the
+ * entries in between (with any actions they require) are shown once.
+ *
+ * @module whatsNew
+ */
+
+import type { ChangelogEntry, WhatsNew } from '../../shared/types/whats-new';
+
+/**
+ * Release notes, newest first. Add an entry when cutting a release; entries
+ * for versions above the running one are ignored until that version ships.
+ */
+export const CHANGELOG: ChangelogEntry[] = [
+    {
+        version: '0.6.0',
+        highlights: [
+            'Compact mode docks a narrow chat strip at the edge of the screen (View › Compact Mode)',
+            'Overlay mode keeps Gemini translucent and on top while clicks go to the window beneath',
+            'F11 toggles full screen; Shift+F11 still enters immersive mode',
+            'Closing the window to the tray can be turned off in Options › Window',
+            'Options › History lists recent settings changes and can undo them',
+        ],
+    },
+];
+
+/**
+ * Compare two dotted version numbers. Pre-release suffixes are ignored.
+ * @returns Negative if a < b, positive if a > b, 0 if equal
+ */
+export function compareVersions(a: string, b: string): number {
+    const parse = (version: string) => version.split('-')[0]!.split('.').map((part) => parseInt(part, 10) || 0);
+    const left = parse(a);
+    const right = parse(b);
+
+    for (let i = 0; i < Math.max(left.length, right.length); i++) {
+        const diff = (left[i] ?? 0) - (right[i] ?? 0);
+        if (diff !== 0) return diff;
+    }
+    return 0;
+}
+
+/**
+ * Work out what changed between two versions.
+ *
+ * @param previousVersion - Last acknowledged version, or null on a fresh install (nothing is shown)
+ * @param currentVersion - Running version
+ * @param changelog - Release notes (defaults to CHANGELOG)
+ * @returns Entries after previousVersion up to currentVersion, newest first
+ */
+export function getWhatsNew(
+    previousVersion: string | null,
+    currentVersion: string,
+    changelog: ChangelogEntry[] = CHANGELOG
+): WhatsNew {
+    const entries =
+        previousVersion === null
+            ? []
+            : changelog
+                  .filter(
+                      (entry) =>
+                          compareVersions(entry.version, previousVersion) > 0 &&
+                          compareVersions(entry.version, currentVersion) <= 0
+                  )
+                  .sort((a, b) => compareVersions(b.version, a.version));
+
+    return {
+        currentVersion,
+        previousVersion,
+        entries,
+        actions: [...entries].reverse().flatMap((entry) => entry.actions ?? []),
+    };
+}
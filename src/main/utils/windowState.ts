@@ -0,0 +1,108 @@
+/**
+ * Main window state persistence.
+ *
+ * The main window's size, position and maximized/fullscreen state are saved
+ * as they change and restored on the next launch. A saved position is only
+ * reused when the window would still be reachable on a connected monitor;
+ * otherwise the window keeps its size and is centered on the primary monitor.
+ *
+ * @module windowState
+ */
+
+import type { Rectangle } from 'electron';
+import { DEFAULT_TITLEBAR_HEIGHT } from '../../shared/types/titlebar';
+
+/**
+ * Saved state of the main window.
+ */
+export interface WindowState {
+    /** Bounds of the window when not maximized or fullscreen */
+    bounds: Rectangle;
+    isMaximized: boolean;
+    isFullScreen: boolean;
+}
+
+/** Delay before saving after the window stops moving or resizing */
+export const WINDOW_STATE_SAVE_DELAY_MS = 500;
+
+/** Width of the titlebar that must be on a monitor to drag the window */
+const MIN_VISIBLE_WIDTH = 100;
+
+/**
+ * Check whether a value is a usable window rectangle.
+ */
+export function isWindowBounds(value: unknown): value is Rectangle {
+    if (!value || typeof value !== 'object') return false;
+    const { x, y, width, height } = value as Record<string, unknown>;
+    return (
+        [x, y, width, height].every((n) => typeof n === 'number' && Number.isFinite(n)) &&
+        (width as number) > 0 &&
+        (height as number) > 0
+    );
+}
+
+/**
+ * Get the width of the overlap between the window's titlebar and a work area.
+ */
+function titlebarOverlap(bounds: Rectangle, workArea: Rectangle, titlebarHeight: number): number {
+    const overlapsVertically = bounds.y < workArea.y + workArea.height && bounds.y + titlebarHeight > workArea.y;
+    if (!overlapsVertically) return 0;
+    return Math.min(bounds.x + bounds.width, workArea.x + workArea.width) - Math.max(bounds.x, workArea.x);
+}
+
+/**
+ * Center a window size in a work area, shrinking it to fit.
+ *
+ * @param size - Window width and height
+ * @param workArea - Work area to center in
+ * @returns Bounds centered in the work area
+ */
+export function centerInWorkArea(size: Pick<Rectangle, 'width' | 'height'>, workArea: Rectangle): Rectangle {
+    const width = Math.min(size.width, workArea.width);
+    const height = Math.min(size.height, workArea.height);
+    return {
+        x: workArea.x + Math.round((workArea.width - width) / 2),
+        y: workArea.y + Math.round((workArea.height - height) / 2),
+        width,
+        height,
+    };
+}
+
+/**
+ * Fit saved bounds to the connected monitors.
+ *
+ * @param bounds - Saved window bounds
+ * @param workAreas - Work areas of the connected displays
+ * @param primaryWorkArea - Work area of the primary display
+ * @param titlebarHeight - Height of the top strip of the window that must be on a monitor
+ * @returns The saved bounds when enough of the titlebar is on a monitor (shrunk to fit it),
+ *   otherwise the saved size centered on the primary display
+ */
+export function fitWindowBounds(
+    bounds: Rectangle,
+    workAreas: readonly Rectangle[],
+    primaryWorkArea: Rectangle,
+    titlebarHeight = DEFAULT_TITLEBAR_HEIGHT
+): Rectangle {
+    let best: Rectangle | null = null;
+    let bestOverlap = 0;
+    for (const workArea of workAreas) {
+        const overlap = titlebarOverlap(bounds, workArea, titlebarHeight);
+        if (overlap > bestOverlap) {
+            best = workArea;
+            bestOverlap = overlap;
+        }
+    }
+
+    if (!best || bestOverlap < MIN_VISIBLE_WIDTH) {
+        return centerInWorkArea(bounds, primaryWorkArea);
+    }
+
+    return {
+        // Keep the titlebar below the top of the monitor so the window can be dragged
+        x: bounds.x,
+        y: Math.max(bounds.y, best.y),
+        width: Math.min(bounds.width, best.width),
+        height: Math.min(bounds.height, best.height),
+    };
+}
@@ -3,9 +3,10 @@
  *
  * Handles:
  * - Main window creation with custom titlebar
- * - Close-to-tray behavior
+ * - Close-to-tray behavior (first window only, when enabled; windows opened with New Window close normally)
  * - Navigation security (blocking external URLs)
- * - Window open handler (OAuth interception, external links)
+ * - Opening links that would take the Gemini view away from Gemini in the system browser
+ * - Window open handler (OAuth interception, external links, local file links)
  *
  * @module MainWindow
  */
@@ -23,6 +24,7 @@ import {
     GEMINI_RESPONSE_API_PATTERN,
 } from '../utils/constants';
 import { getIconPath, getDistHtmlPath } from '../utils/paths';
+import { isAllowedHost } from '../utils/allowedHosts';
 
 /**
  * Main application window.
@@ -32,9 +34,15 @@ export default class MainWindow extends BaseWindow {
     protected readonly windowConfig: BrowserWindowConstructorOptions;
     protected readonly htmlFile = 'index.html';
 
+    /** Whether this is the first main window, which owns the tray and response detection */
+    private readonly isPrimary: boolean;
+
     /** Whether the app is quitting (vs closing to tray) */
     private isQuitting = false;
 
+    /** Whether closing the window hides it to the tray */
+    private closeToTray = true;
+
     /** Callback to create auth window for OAuth flows */
     private createAuthWindowCallback?: (url: string) => void;
 
@@ -44,6 +52,9 @@ export default class MainWindow extends BaseWindow {
     /** Callback to close auth window when closing main window */
     private closeAuthWindowCallback?: () => void;
 
+    /** Extra hosts the Gemini view may navigate to, besides Gemini and sign-in */
+    private allowedHosts: string[] = [];
+
     /** Debounce cooldown in milliseconds for response-complete events */
     private static readonly RESPONSE_DEBOUNCE_MS = 1000;
 
@@ -59,9 +70,11 @@ export default class MainWindow extends BaseWindow {
     /**
      * Creates a new MainWindow instance.
      * @param isDev - Whether running in development mode
+     * @param isPrimary - False for windows opened with New Window
      */
-    constructor(isDev: boolean) {
+    constructor(isDev: boolean, isPrimary = true) {
         super(isDev, '[MainWindow]');
+        this.isPrimary = isPrimary;
         this.windowConfig = {
             ...MAIN_WINDOW_CONFIG,
             title: 'Gemini Desktop',
@@ -88,6 +101,14 @@ export default class MainWindow extends BaseWindow {
         this.closeOptionsWindowCallback = callback;
     }
 
+    /**
+     * Set the extra hosts the Gemini view may navigate to.
+     * @param hosts - Parsed allow-list (see allowedHosts.ts)
+     */
+    setAllowedHosts(hosts: string[]): void {
+        this.allowedHosts = hosts;
+    }
+
     /**
      * Set callback for closing auth window when main window closes.
      * @param callback - Function to close auth window
@@ -129,7 +150,10 @@ export default class MainWindow extends BaseWindow {
         this.setupNavigationHandler();
         this.setupCloseHandler();
         this.setupCrashHandlers();
-        this.setupResponseDetection();
+        // The webRequest listener is per session, so only the first window can own it
+        if (this.isPrimary) {
+            this.setupResponseDetection();
+        }
 
         return win;
     }
@@ -234,6 +258,30 @@ export default class MainWindow extends BaseWindow {
                 event.preventDefault();
             }
         });
+
+        // Links that would take the Gemini iframe away from Gemini open in the system browser.
+        // Only the iframe itself is checked; frames embedded by Gemini navigate freely.
+        this.window.webContents.on('will-frame-navigate', (details) => {
+            const frame = details.frame;
+            if (details.isMainFrame || !frame?.parent || frame.parent.parent) return;
+
+            let hostname: string;
+            try {
+                const urlObj = new URL(details.url);
+                if (urlObj.protocol !== 'http:' && urlObj.protocol !== 'https:') return;
+                hostname = urlObj.hostname;
+            } catch {
+                return;
+            }
+
+            if (isInternalDomain(hostname) || isOAuthDomain(hostname) || isAllowedHost(hostname, this.allowedHosts)) {
+                return;
+            }
+
+            this.logger.log('Opening link in system browser:', details.url);
+            details.preventDefault();
+            shell.openExternal(details.url);
+        });
     }
 
     /**
@@ -311,7 +359,7 @@ export default class MainWindow extends BaseWindow {
 
         // Close to tray behavior
         this.window.on('close', (event) => {
-            if (!this.isQuitting) {
+            if (!this.isQuitting && this.isPrimary && this.closeToTray) {
                 event.preventDefault();
                 this.hideToTray();
             }
@@ -373,6 +421,14 @@ export default class MainWindow extends BaseWindow {
         this.isQuitting = state;
     }
 
+    /**
+     * Set whether closing the window hides it to the tray instead of closing it.
+     * @param enabled - Whether to close to tray
+     */
+    setCloseToTray(enabled: boolean): void {
+        this.closeToTray = enabled;
+    }
+
     /**
      * Minimize the main window.
      */
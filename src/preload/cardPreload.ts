@@ -0,0 +1,32 @@
+/**
+ * Pinned Answer Card Preload Script
+ *
+ * Wires the card's Copy and Close buttons to the main process. Card pages
+ * are static HTML with scripts disabled, so this is the only code that runs
+ * in them and nothing is exposed to the page.
+ *
+ * @module CardPreload
+ */
+
+import { ipcRenderer } from 'electron';
+
+/**
+ * IPC channels used by answer cards.
+ *
+ * IMPORTANT: Inlined for the same reason as in preload.ts (sandboxed preloads
+ * cannot 'require' local modules). Keep in sync with src/shared/constants/ipc-channels.ts.
+ */
+const IPC_CHANNELS = {
+    CARDS_COPY: 'cards:copy',
+    CARDS_CLOSE: 'cards:close',
+} as const;
+
+window.addEventListener('DOMContentLoaded', () => {
+    document.getElementById('card-copy')?.addEventListener('click', () => {
+        ipcRenderer.send(IPC_CHANNELS.CARDS_COPY);
+    });
+
+    document.getElementById('card-close')?.addEventListener('click', () => {
+        ipcRenderer.send(IPC_CHANNELS.CARDS_CLOSE);
+    });
+});
@@ -16,7 +16,31 @@
  */
 
 import { contextBridge, ipcRenderer } from 'electron';
-import type { ElectronAPI } from '../shared/types';
+import type {
+    ConfluenceSettings,
+    ConfluenceTarget,
+    ElectronAPI,
+    ExportProgress,
+    GeminiLocation,
+    GeminiNavigationState,
+    GeminiQuotaSettings,
+    GeminiQuotaStatus,
+    IssueDraft,
+    IssueTrackerSettings,
+    ScreenshotOptions,
+    SettingsResetSection,
+    SharePreview,
+    ShareSettings,
+    ProxySettings,
+    TitlebarEffect,
+    TitlebarEffectState,
+    TitlebarState,
+    ResearchReportFormat,
+    UsageLimits,
+    UsageLimitStatus,
+    UsageSettings,
+    UserAgentSettings,
+} from '../shared/types';
 /**
  * IPC channel names used for main process <-> renderer communication.
  *
@@ -34,6 +58,12 @@ export const IPC_CHANNELS = {
     WINDOW_CLOSE: 'window-close',
     WINDOW_SHOW: 'window-show',
     WINDOW_IS_MAXIMIZED: 'window-is-maximized',
+    WINDOW_NEW: 'window-new',
+    WINDOW_TOGGLE_FULLSCREEN: 'window-toggle-fullscreen',
+    WINDOW_IS_FULLSCREEN: 'window-is-fullscreen',
+    WINDOW_FULLSCREEN_CHANGED: 'window-fullscreen-changed',
+    WINDOW_GET_DISPLAYS: 'window-get-displays',
+    WINDOW_MOVE_TO_DISPLAY: 'window-move-to-display',
 
     // Theme
     THEME_GET: 'theme:get',
@@ -43,26 +73,84 @@ export const IPC_CHANNELS = {
     // App
     OPEN_OPTIONS: 'open-options-window',
     OPEN_GOOGLE_SIGNIN: 'open-google-signin',
+    APP_QUIT: 'app:quit',
+    CLOSE_TO_TRAY_GET: 'close-to-tray:get',
+    CLOSE_TO_TRAY_SET: 'close-to-tray:set',
+    CAPABILITIES_GET_REPORT: 'capabilities:get-report',
+    WHATS_NEW_GET: 'whats-new:get',
+    WHATS_NEW_ACKNOWLEDGE: 'whats-new:acknowledge',
+    DISK_SPACE_GET_STATUS: 'disk-space:get-status',
+    DISK_SPACE_CHANGED: 'disk-space:changed',
+    FOCUS_SESSION_GET_STATUS: 'focus-session:get-status',
+    FOCUS_SESSION_TOGGLE: 'focus-session:toggle',
+    FOCUS_SESSION_CHANGED: 'focus-session:changed',
 
     // Quick Chat
     QUICK_CHAT_SUBMIT: 'quick-chat:submit',
     QUICK_CHAT_HIDE: 'quick-chat:hide',
     QUICK_CHAT_CANCEL: 'quick-chat:cancel',
     QUICK_CHAT_EXECUTE: 'quick-chat:execute',
+    QUICK_CHAT_TRANSLATE: 'quick-chat:translate',
+    QUICK_CHAT_TRANSLATE_LANGUAGE_GET: 'quick-chat:translate-language:get',
 
     // Gemini Iframe Navigation (for Quick Chat integration)
     GEMINI_NAVIGATE: 'gemini:navigate',
     GEMINI_READY: 'gemini:ready',
+    GEMINI_RELOAD: 'gemini:reload',
+    GEMINI_GO_BACK: 'gemini:go-back',
+    GEMINI_GO_FORWARD: 'gemini:go-forward',
+    GEMINI_GO_HOME: 'gemini:go-home',
+    GEMINI_PRINT: 'gemini:print',
+    GEMINI_RELOAD_USER_STYLES: 'gemini:reload-user-styles',
+    GEMINI_GET_NAVIGATION_STATE: 'gemini:get-navigation-state',
+    GEMINI_NAVIGATION_CHANGED: 'gemini:navigation-changed',
+    GEMINI_GET_LOCATION: 'gemini:get-location',
+    GEMINI_LOCATION_CHANGED: 'gemini:location-changed',
+    GEMINI_GET_ALLOWED_HOSTS: 'gemini:get-allowed-hosts',
+    GEMINI_SET_ALLOWED_HOSTS: 'gemini:set-allowed-hosts',
 
     // Always On Top
     ALWAYS_ON_TOP_GET: 'always-on-top:get',
     ALWAYS_ON_TOP_SET: 'always-on-top:set',
     ALWAYS_ON_TOP_CHANGED: 'always-on-top:changed',
 
+    // Immersive Mode
+    IMMERSIVE_MODE_GET: 'immersive-mode:get',
+    IMMERSIVE_MODE_TOGGLE: 'immersive-mode:toggle',
+    IMMERSIVE_MODE_CHANGED: 'immersive-mode:changed',
+
+    // Compact Mode
+    COMPACT_MODE_GET: 'compact-mode:get',
+    COMPACT_MODE_TOGGLE: 'compact-mode:toggle',
+    COMPACT_MODE_CHANGED: 'compact-mode:changed',
+
+    // Window opacity and overlay mode
+    WINDOW_OPACITY_GET: 'window-opacity:get',
+    WINDOW_OPACITY_SET: 'window-opacity:set',
+    WINDOW_OPACITY_CHANGED: 'window-opacity:changed',
+    OVERLAY_MODE_GET: 'overlay-mode:get',
+    OVERLAY_MODE_TOGGLE: 'overlay-mode:toggle',
+    OVERLAY_MODE_CHANGED: 'overlay-mode:changed',
+
+    // Titlebar
+    TITLEBAR_GET_STATE: 'titlebar:get-state',
+    TITLEBAR_STATE_CHANGED: 'titlebar:state-changed',
+    TITLEBAR_GET_HEIGHT: 'titlebar:get-height',
+    TITLEBAR_SET_HEIGHT: 'titlebar:set-height',
+    TITLEBAR_HEIGHT_CHANGED: 'titlebar:height-changed',
+    TITLEBAR_GET_EFFECT: 'titlebar:get-effect',
+    TITLEBAR_SET_EFFECT: 'titlebar:set-effect',
+    TITLEBAR_EFFECT_CHANGED: 'titlebar:effect-changed',
+
+    // App events
+    EVENTS_GET_RECENT: 'events:get-recent',
+
     // Zoom
     ZOOM_GET_LEVEL: 'zoom:get-level',
     ZOOM_IN: 'zoom:zoom-in',
     ZOOM_OUT: 'zoom:zoom-out',
+    ZOOM_SET_LEVEL: 'zoom:set-level',
+    ZOOM_RESET: 'zoom:reset',
     ZOOM_LEVEL_CHANGED: 'zoom:level-changed',
 
     // Individual Hotkeys
@@ -121,9 +209,88 @@ export const IPC_CHANNELS = {
     RESPONSE_NOTIFICATIONS_GET_ENABLED: 'response-notifications:get-enabled',
     RESPONSE_NOTIFICATIONS_SET_ENABLED: 'response-notifications:set-enabled',
 
+    // Auto-Copy (answer-to-clipboard)
+    AUTO_COPY_GET_SETTINGS: 'auto-copy:get-settings',
+    AUTO_COPY_SET_SETTINGS: 'auto-copy:set-settings',
+
     // Chat Export (Structured)
     EXPORT_CHAT_PDF: 'export-chat:pdf',
     EXPORT_CHAT_MARKDOWN: 'export-chat:markdown',
+    EXPORT_CHAT_CONFLUENCE: 'export-chat:confluence',
+    EXPORT_CANVAS_MARKDOWN: 'export-chat:canvas-markdown',
+    EXPORT_RESEARCH_REPORT: 'export-chat:research-report',
+    EXPORT_FILENAME_TEMPLATE_GET: 'export-chat:filename-template:get',
+    EXPORT_FILENAME_TEMPLATE_SET: 'export-chat:filename-template:set',
+    EXPORT_CONFLUENCE_SETTINGS_GET: 'export-chat:confluence-settings:get',
+    EXPORT_CONFLUENCE_SETTINGS_SET: 'export-chat:confluence-settings:set',
+    EXPORT_PROGRESS: 'export-chat:progress',
+    EXPORT_CANCEL: 'export-chat:cancel',
+
+    // Logging
+    LOG_GET_LEVEL: 'log:get-level',
+    LOG_SET_LEVEL: 'log:set-level',
+
+    // Pinned answer cards
+    CARDS_PIN: 'cards:pin',
+    CARDS_COPY: 'cards:copy',
+    CARDS_CLOSE: 'cards:close',
+
+    // Answer sharing (Slack/Teams)
+    SHARE_PREVIEW_REQUEST: 'share:preview-request',
+    SHARE_PREVIEW: 'share:preview',
+    SHARE_POST: 'share:post',
+    SHARE_SETTINGS_GET: 'share:settings:get',
+    SHARE_SETTINGS_SET: 'share:settings:set',
+    SHARE_QR: 'share:qr',
+
+    // Issue creation (GitHub/Jira)
+    ISSUES_DRAFT_REQUEST: 'issues:draft-request',
+    ISSUES_DRAFT: 'issues:draft',
+    ISSUES_CREATE: 'issues:create',
+    ISSUES_SETTINGS_GET: 'issues:settings:get',
+    ISSUES_SETTINGS_SET: 'issues:settings:set',
+
+    // Screenshots
+    SCREENSHOT_CAPTURE: 'screenshot:capture',
+
+    // Userscripts
+    USERSCRIPTS_LIST: 'userscripts:list',
+    USERSCRIPTS_SET_ENABLED: 'userscripts:set-enabled',
+    USERSCRIPTS_OPEN_FOLDER: 'userscripts:open-folder',
+
+    // Settings maintenance
+    SETTINGS_RESET: 'settings:reset',
+    SETTINGS_GET_HISTORY: 'settings:get-history',
+    SETTINGS_UNDO: 'settings:undo',
+
+    // Upstream proxy
+    PROXY_GET_SETTINGS: 'proxy:get-settings',
+    PROXY_SET_SETTINGS: 'proxy:set-settings',
+    PROXY_TEST: 'proxy:test',
+
+    // Usage tracking
+    USAGE_GET_SETTINGS: 'usage:get-settings',
+    USAGE_SET_SETTINGS: 'usage:set-settings',
+    USAGE_GET_REPORT: 'usage:get-report',
+    USAGE_GET_LIMITS: 'usage:get-limits',
+    USAGE_SET_LIMITS: 'usage:set-limits',
+    USAGE_LIMIT_CHANGED: 'usage:limit-changed',
+    USAGE_GET_QUOTA: 'usage:get-quota',
+    USAGE_SET_QUOTA: 'usage:set-quota',
+    USAGE_QUOTA_CHANGED: 'usage:quota-changed',
+
+    // User-Agent override
+    USER_AGENT_GET_SETTINGS: 'user-agent:get-settings',
+    USER_AGENT_SET_SETTINGS: 'user-agent:set-settings',
+
+    // Gemini language override
+    GEMINI_LANGUAGE_GET: 'gemini-language:get',
+    GEMINI_LANGUAGE_SET: 'gemini-language:set',
+
+    // Spell checker
+    SPELL_CHECK_GET: 'spell-check:get',
+    SPELL_CHECK_SET_ENABLED: 'spell-check:set-enabled',
+    SPELL_CHECK_SET_LANGUAGES: 'spell-check:set-languages',
 } as const;
 
 // Expose window control APIs to renderer
@@ -159,6 +326,49 @@ const electronAPI: ElectronAPI = {
      */
     isMaximized: () => ipcRenderer.invoke(IPC_CHANNELS.WINDOW_IS_MAXIMIZED),
 
+    /**
+     * Open another main window with its own Gemini view.
+     */
+    openNewWindow: () => ipcRenderer.send(IPC_CHANNELS.WINDOW_NEW),
+
+    /**
+     * Toggle fullscreen on this window. Leaves immersive mode if it is on.
+     */
+    toggleFullScreen: () => ipcRenderer.send(IPC_CHANNELS.WINDOW_TOGGLE_FULLSCREEN),
+
+    /**
+     * Check if this window is fullscreen.
+     * @returns Promise resolving to true if fullscreen
+     */
+    isFullScreen: () => ipcRenderer.invoke(IPC_CHANNELS.WINDOW_IS_FULLSCREEN),
+
+    /**
+     * Subscribe to this window entering or leaving fullscreen, including macOS native fullscreen.
+     * @param callback - Function called with { enabled: boolean } when fullscreen changes
+     * @returns Cleanup function to unsubscribe
+     */
+    onFullScreenChanged: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, data: Parameters<typeof callback>[0]) =>
+            callback(data);
+        ipcRenderer.on(IPC_CHANNELS.WINDOW_FULLSCREEN_CHANGED, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.WINDOW_FULLSCREEN_CHANGED, subscription);
+        };
+    },
+
+    /**
+     * List the connected displays, marking the one this window is on.
+     * @returns Promise resolving to the displays
+     */
+    getDisplays: () => ipcRenderer.invoke(IPC_CHANNELS.WINDOW_GET_DISPLAYS),
+
+    /**
+     * Move this window to another display.
+     * @param index - Display index, as listed by getDisplays
+     */
+    moveToDisplay: (index) => ipcRenderer.send(IPC_CHANNELS.WINDOW_MOVE_TO_DISPLAY, index),
+
     /**
      * Open the options/settings window.
      * @param tab - Optional tab to open ('settings' or 'about')
@@ -172,6 +382,74 @@ const electronAPI: ElectronAPI = {
      */
     openGoogleSignIn: () => ipcRenderer.invoke(IPC_CHANNELS.OPEN_GOOGLE_SIGNIN),
 
+    /**
+     * Quit the app, even when closing the main window would hide it to the tray.
+     */
+    quitApp: () => ipcRenderer.send(IPC_CHANNELS.APP_QUIT),
+
+    /**
+     * Get whether closing the main window hides it to the tray.
+     * @returns Promise resolving to true when close to tray is enabled
+     */
+    getCloseToTray: () => ipcRenderer.invoke(IPC_CHANNELS.CLOSE_TO_TRAY_GET),
+
+    /**
+     * Set whether closing the main window hides it to the tray.
+     * @param enabled - Whether to close to tray
+     */
+    setCloseToTray: (enabled: boolean) => ipcRenderer.send(IPC_CHANNELS.CLOSE_TO_TRAY_SET, enabled),
+
+    /**
+     * Get which optional features (global hotkeys, notifications, keychain, tray,
+     * auto-update) work on this platform and build.
+     * @returns Promise resolving to the capability report
+     */
+    getCapabilityReport: () => ipcRenderer.invoke(IPC_CHANNELS.CAPABILITIES_GET_REPORT),
+
+    /**
+     * Get release notes and required actions since the last acknowledged version.
+     * @returns Promise resolving to what's new (no entries when there is nothing to show)
+     */
+    getWhatsNew: () => ipcRenderer.invoke(IPC_CHANNELS.WHATS_NEW_GET),
+
+    /**
+     * Mark the running version's release notes as read.
+     */
+    acknowledgeWhatsNew: () => ipcRenderer.send(IPC_CHANNELS.WHATS_NEW_ACKNOWLEDGE),
+
+    /**
+     * Get free space on the app data volume and whether it is below the
+     * low disk space threshold (exports and downloads are refused below it).
+     * @returns Promise resolving to the disk space status
+     */
+    getDiskSpaceStatus: () => ipcRenderer.invoke(IPC_CHANNELS.DISK_SPACE_GET_STATUS),
+
+    /**
+     * Get whether a focus session is running, when it ends and how many answers it logged.
+     * @returns Promise resolving to the focus session status
+     */
+    getFocusSessionStatus: () => ipcRenderer.invoke(IPC_CHANNELS.FOCUS_SESSION_GET_STATUS),
+
+    /**
+     * Start a focus session of the configured length, or end the running one.
+     */
+    toggleFocusSession: () => ipcRenderer.send(IPC_CHANNELS.FOCUS_SESSION_TOGGLE),
+
+    /**
+     * Subscribe to focus sessions starting and ending.
+     * @param callback - Function called with the new status
+     * @returns Cleanup function to unsubscribe
+     */
+    onFocusSessionChanged: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, status: Parameters<typeof callback>[0]) =>
+            callback(status);
+        ipcRenderer.on(IPC_CHANNELS.FOCUS_SESSION_CHANGED, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.FOCUS_SESSION_CHANGED, subscription);
+        };
+    },
+
     // =========================================================================
     // Platform Detection
     // Enables cross-platform conditional rendering
@@ -243,6 +521,21 @@ const electronAPI: ElectronAPI = {
      */
     cancelQuickChat: () => ipcRenderer.send(IPC_CHANNELS.QUICK_CHAT_CANCEL),
 
+    /**
+     * Translate text to the given language via Gemini.
+     * Uses the clipboard contents when text is empty.
+     * The chosen language is remembered for next time.
+     * @param text - Text to translate (empty string for clipboard)
+     * @param language - Target language
+     */
+    translateQuickChat: (text, language) => ipcRenderer.send(IPC_CHANNELS.QUICK_CHAT_TRANSLATE, text, language),
+
+    /**
+     * Get the last used translation target language.
+     * @returns Promise resolving to the language name
+     */
+    getTranslateLanguage: () => ipcRenderer.invoke(IPC_CHANNELS.QUICK_CHAT_TRANSLATE_LANGUAGE_GET),
+
     /**
      * Subscribe to quick chat execute events (main window receives this).
      * @param callback - Function to call with the prompt text
@@ -284,6 +577,75 @@ const electronAPI: ElectronAPI = {
      */
     signalGeminiReady: (text: string) => ipcRenderer.send(IPC_CHANNELS.GEMINI_READY, text),
 
+    /**
+     * Reload the embedded Gemini view.
+     * @param ignoreCache - Bypass the HTTP cache (hard reload)
+     */
+    reloadGemini: (ignoreCache = false) => ipcRenderer.send(IPC_CHANNELS.GEMINI_RELOAD, ignoreCache),
+
+    /**
+     * Navigate the embedded Gemini view back one history entry.
+     */
+    goBackGemini: () => ipcRenderer.send(IPC_CHANNELS.GEMINI_GO_BACK),
+
+    /**
+     * Navigate the embedded Gemini view forward one history entry.
+     */
+    goForwardGemini: () => ipcRenderer.send(IPC_CHANNELS.GEMINI_GO_FORWARD),
+
+    /**
+     * Navigate the embedded Gemini view to the Gemini home page.
+     */
+    goHomeGemini: () => ipcRenderer.send(IPC_CHANNELS.GEMINI_GO_HOME),
+
+    /**
+     * Open the native print dialog for the Gemini conversation.
+     */
+    printGemini: () => ipcRenderer.send(IPC_CHANNELS.GEMINI_PRINT),
+
+    /**
+     * Re-apply the user stylesheet (user.css in the data directory) to Gemini.
+     */
+    reloadUserStyles: () => ipcRenderer.send(IPC_CHANNELS.GEMINI_RELOAD_USER_STYLES),
+
+    /**
+     * Get whether the Gemini view can navigate back or forward.
+     * @returns Promise resolving to the navigation state
+     */
+    getGeminiNavigationState: () => ipcRenderer.invoke(IPC_CHANNELS.GEMINI_GET_NAVIGATION_STATE),
+
+    /**
+     * Subscribe to Gemini navigation state changes.
+     * @param callback - Function called with the new navigation state
+     * @returns Cleanup function to unsubscribe
+     */
+    onGeminiNavigationChanged: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, state: GeminiNavigationState) => callback(state);
+        ipcRenderer.on(IPC_CHANNELS.GEMINI_NAVIGATION_CHANGED, subscription);
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.GEMINI_NAVIGATION_CHANGED, subscription);
+        };
+    },
+
+    /**
+     * Get the Gemini view's URL, title and conversation ID.
+     * @returns Promise resolving to the location
+     */
+    getGeminiLocation: () => ipcRenderer.invoke(IPC_CHANNELS.GEMINI_GET_LOCATION),
+
+    /**
+     * Subscribe to Gemini navigation, with the new URL and title.
+     * @param callback - Function called with the new location
+     * @returns Cleanup function to unsubscribe
+     */
+    onGeminiLocationChanged: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, location: GeminiLocation) => callback(location);
+        ipcRenderer.on(IPC_CHANNELS.GEMINI_LOCATION_CHANGED, subscription);
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.GEMINI_LOCATION_CHANGED, subscription);
+        };
+    },
+
     // =========================================================================
     // Individual Hotkeys API
     // =========================================================================
@@ -404,6 +766,213 @@ const electronAPI: ElectronAPI = {
         };
     },
 
+    // =========================================================================
+    // Immersive Mode API
+    // =========================================================================
+
+    /**
+     * Get whether immersive mode (fullscreen without the titlebar) is on.
+     * @returns Promise resolving to { enabled: boolean }
+     */
+    getImmersiveMode: () => ipcRenderer.invoke(IPC_CHANNELS.IMMERSIVE_MODE_GET),
+
+    /**
+     * Enter or leave immersive mode. Esc or F11 also leaves it.
+     */
+    toggleImmersiveMode: () => ipcRenderer.send(IPC_CHANNELS.IMMERSIVE_MODE_TOGGLE),
+
+    /**
+     * Subscribe to immersive mode changes.
+     * @param callback - Function called with { enabled: boolean } when immersive mode changes
+     * @returns Cleanup function to unsubscribe
+     */
+    onImmersiveModeChanged: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, data: Parameters<typeof callback>[0]) =>
+            callback(data);
+        ipcRenderer.on(IPC_CHANNELS.IMMERSIVE_MODE_CHANGED, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.IMMERSIVE_MODE_CHANGED, subscription);
+        };
+    },
+
+    // =========================================================================
+    // Compact Mode API
+    // =========================================================================
+
+    /**
+     * Get whether this window is in compact mode (a narrow strip docked at the screen edge).
+     * @returns Promise resolving to { enabled: boolean }
+     */
+    getCompactMode: () => ipcRenderer.invoke(IPC_CHANNELS.COMPACT_MODE_GET),
+
+    /**
+     * Enter or leave compact mode for this window.
+     */
+    toggleCompactMode: () => ipcRenderer.send(IPC_CHANNELS.COMPACT_MODE_TOGGLE),
+
+    /**
+     * Subscribe to compact mode changes for this window.
+     * @param callback - Function called with { enabled: boolean } when compact mode changes
+     * @returns Cleanup function to unsubscribe
+     */
+    onCompactModeChanged: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, data: Parameters<typeof callback>[0]) =>
+            callback(data);
+        ipcRenderer.on(IPC_CHANNELS.COMPACT_MODE_CHANGED, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.COMPACT_MODE_CHANGED, subscription);
+        };
+    },
+
+    // =========================================================================
+    // Window Opacity and Overlay Mode API
+    // =========================================================================
+
+    /**
+     * Get the main window opacity.
+     * @returns Promise resolving to { opacity: number }
+     */
+    getWindowOpacity: () => ipcRenderer.invoke(IPC_CHANNELS.WINDOW_OPACITY_GET),
+
+    /**
+     * Set the main window opacity. Ignored on Linux.
+     * @param opacity - Opacity from MIN_WINDOW_OPACITY to 1
+     */
+    setWindowOpacity: (opacity: number) => ipcRenderer.send(IPC_CHANNELS.WINDOW_OPACITY_SET, opacity),
+
+    /**
+     * Subscribe to window opacity changes.
+     * @param callback - Function called with { opacity: number } when the opacity changes
+     * @returns Cleanup function to unsubscribe
+     */
+    onWindowOpacityChanged: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, data: Parameters<typeof callback>[0]) =>
+            callback(data);
+        ipcRenderer.on(IPC_CHANNELS.WINDOW_OPACITY_CHANGED, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.WINDOW_OPACITY_CHANGED, subscription);
+        };
+    },
+
+    /**
+     * Get whether overlay mode (translucent, on top and click-through) is on.
+     * @returns Promise resolving to { enabled: boolean }
+     */
+    getOverlayMode: () => ipcRenderer.invoke(IPC_CHANNELS.OVERLAY_MODE_GET),
+
+    /**
+     * Enter or leave overlay mode. Clicking the tray icon also leaves it.
+     */
+    toggleOverlayMode: () => ipcRenderer.send(IPC_CHANNELS.OVERLAY_MODE_TOGGLE),
+
+    /**
+     * Subscribe to overlay mode changes.
+     * @param callback - Function called with { enabled: boolean } when overlay mode changes
+     * @returns Cleanup function to unsubscribe
+     */
+    onOverlayModeChanged: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, data: Parameters<typeof callback>[0]) =>
+            callback(data);
+        ipcRenderer.on(IPC_CHANNELS.OVERLAY_MODE_CHANGED, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.OVERLAY_MODE_CHANGED, subscription);
+        };
+    },
+
+    // =========================================================================
+    // Titlebar API
+    // =========================================================================
+
+    /**
+     * Get the status indicators to show in the titlebar.
+     * @returns Promise resolving to the widgets, sorted by order
+     */
+    getTitlebarState: () => ipcRenderer.invoke(IPC_CHANNELS.TITLEBAR_GET_STATE),
+
+    /**
+     * Subscribe to titlebar indicator changes.
+     * @param callback - Function called with the new state
+     * @returns Cleanup function to unsubscribe
+     */
+    onTitlebarStateChanged: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, state: TitlebarState) => callback(state);
+        ipcRenderer.on(IPC_CHANNELS.TITLEBAR_STATE_CHANGED, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.TITLEBAR_STATE_CHANGED, subscription);
+        };
+    },
+
+    /**
+     * Get the titlebar height.
+     * @returns Promise resolving to the height in logical pixels
+     */
+    getTitlebarHeight: () => ipcRenderer.invoke(IPC_CHANNELS.TITLEBAR_GET_HEIGHT),
+
+    /**
+     * Set and persist the titlebar height; every main window updates live.
+     * @param height - Height from MIN_TITLEBAR_HEIGHT to MAX_TITLEBAR_HEIGHT logical pixels
+     * @returns Promise resolving to false if the height was out of range
+     */
+    setTitlebarHeight: (height: number) => ipcRenderer.invoke(IPC_CHANNELS.TITLEBAR_SET_HEIGHT, height),
+
+    /**
+     * Subscribe to titlebar height changes.
+     * @param callback - Function called with the new height
+     * @returns Cleanup function to unsubscribe
+     */
+    onTitlebarHeightChanged: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, data: { height: number }) => callback(data);
+        ipcRenderer.on(IPC_CHANNELS.TITLEBAR_HEIGHT_CHANGED, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.TITLEBAR_HEIGHT_CHANGED, subscription);
+        };
+    },
+
+    /**
+     * Get the titlebar effect in use and the effects this platform supports.
+     * @returns Promise resolving to the effect state
+     */
+    getTitlebarEffect: () => ipcRenderer.invoke(IPC_CHANNELS.TITLEBAR_GET_EFFECT),
+
+    /**
+     * Set and persist the native material shown behind the titlebar.
+     * @param effect - One of TITLEBAR_EFFECTS; unsupported effects fall back to 'none'
+     * @returns Promise resolving to false if the effect is unknown
+     */
+    setTitlebarEffect: (effect: TitlebarEffect) => ipcRenderer.invoke(IPC_CHANNELS.TITLEBAR_SET_EFFECT, effect),
+
+    /**
+     * Subscribe to titlebar effect changes.
+     * @param callback - Function called with the new effect state
+     * @returns Cleanup function to unsubscribe
+     */
+    onTitlebarEffectChanged: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, data: TitlebarEffectState) => callback(data);
+        ipcRenderer.on(IPC_CHANNELS.TITLEBAR_EFFECT_CHANGED, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.TITLEBAR_EFFECT_CHANGED, subscription);
+        };
+    },
+
+    // =========================================================================
+    // App Events API
+    // =========================================================================
+
+    /**
+     * Get the recent state-change events, so a window that opened late can
+     * catch up on what it missed.
+     * @param since - Only return events with a higher seq than this
+     * @returns Promise resolving to the events, oldest first
+     */
+    getRecentEvents: (since) => ipcRenderer.invoke(IPC_CHANNELS.EVENTS_GET_RECENT, since),
+
     // =========================================================================
     // Zoom API
     // Window zoom level control
@@ -427,6 +996,19 @@ const electronAPI: ElectronAPI = {
      */
     zoomOut: () => ipcRenderer.invoke(IPC_CHANNELS.ZOOM_OUT),
 
+    /**
+     * Set the zoom level (snapped to the nearest step).
+     * @param level - Zoom level percentage (50-200)
+     * @returns Promise resolving to the applied zoom level
+     */
+    setZoomLevel: (level: number) => ipcRenderer.invoke(IPC_CHANNELS.ZOOM_SET_LEVEL, level),
+
+    /**
+     * Reset zoom to 100%.
+     * @returns Promise resolving to the new zoom level
+     */
+    resetZoom: () => ipcRenderer.invoke(IPC_CHANNELS.ZOOM_RESET),
+
     /**
      * Subscribe to zoom level change events.
      * @param callback - Function called with new zoom level percentage
@@ -736,6 +1318,22 @@ const electronAPI: ElectronAPI = {
     setResponseNotificationsEnabled: (enabled: boolean) =>
         ipcRenderer.send(IPC_CHANNELS.RESPONSE_NOTIFICATIONS_SET_ENABLED, enabled),
 
+    // =========================================================================
+    // Auto-Copy API
+    // =========================================================================
+
+    /**
+     * Get the answer auto-copy settings.
+     * @returns Promise resolving to mode and format
+     */
+    getAutoCopySettings: () => ipcRenderer.invoke(IPC_CHANNELS.AUTO_COPY_GET_SETTINGS),
+
+    /**
+     * Update the answer auto-copy settings.
+     * @param settings - Partial settings (mode and/or format)
+     */
+    setAutoCopySettings: (settings) => ipcRenderer.send(IPC_CHANNELS.AUTO_COPY_SET_SETTINGS, settings),
+
     // =========================================================================
     // Chat Export API (Structured)
     // =========================================================================
@@ -749,6 +1347,411 @@ const electronAPI: ElectronAPI = {
      * Export the current chat to a Markdown file.
      */
     exportChatToMarkdown: () => ipcRenderer.send(IPC_CHANNELS.EXPORT_CHAT_MARKDOWN),
+
+    /**
+     * Publish the current chat as a Confluence page.
+     * @param target - Optional space key and parent page ID overriding the saved settings
+     */
+    exportChatToConfluence: (target?: ConfluenceTarget) =>
+        ipcRenderer.send(IPC_CHANNELS.EXPORT_CHAT_CONFLUENCE, target),
+
+    /**
+     * Export the open Canvas document to a Markdown file.
+     */
+    exportCanvasToMarkdown: () => ipcRenderer.send(IPC_CHANNELS.EXPORT_CANVAS_MARKDOWN),
+
+    /**
+     * Export the open Deep Research report, keeping its sections and sources.
+     * @param format - 'pdf' or 'markdown'
+     */
+    exportResearchReport: (format: ResearchReportFormat) =>
+        ipcRenderer.send(IPC_CHANNELS.EXPORT_RESEARCH_REPORT, format),
+
+    /**
+     * Get the Confluence export settings.
+     * @returns Promise resolving to the settings
+     */
+    getConfluenceSettings: () => ipcRenderer.invoke(IPC_CHANNELS.EXPORT_CONFLUENCE_SETTINGS_GET),
+
+    /**
+     * Set the Confluence export settings. The API token is stored encrypted when the OS keychain is available.
+     * @param settings - The new settings
+     */
+    setConfluenceSettings: (settings: ConfluenceSettings) =>
+        ipcRenderer.send(IPC_CHANNELS.EXPORT_CONFLUENCE_SETTINGS_SET, settings),
+
+    /**
+     * Get the export filename template.
+     * @returns Promise resolving to the template string
+     */
+    getExportFilenameTemplate: () => ipcRenderer.invoke(IPC_CHANNELS.EXPORT_FILENAME_TEMPLATE_GET),
+
+    /**
+     * Set the export filename template.
+     * Supports {title}, {date}, {time}, {id}, {gem} and {format} tokens.
+     * @param template - The new template
+     */
+    setExportFilenameTemplate: (template: string) =>
+        ipcRenderer.send(IPC_CHANNELS.EXPORT_FILENAME_TEMPLATE_SET, template),
+
+    /**
+     * Cancel a running export job.
+     * @param jobId - Job ID from an export progress event
+     */
+    cancelExport: (jobId: string) => ipcRenderer.send(IPC_CHANNELS.EXPORT_CANCEL, jobId),
+
+    /**
+     * Subscribe to export progress events.
+     * @param callback - Function called with progress updates
+     * @returns Cleanup function to unsubscribe
+     */
+    onExportProgress: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, progress: ExportProgress) => callback(progress);
+        ipcRenderer.on(IPC_CHANNELS.EXPORT_PROGRESS, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.EXPORT_PROGRESS, subscription);
+        };
+    },
+
+    // =========================================================================
+    // Logging
+    // =========================================================================
+
+    /**
+     * Get the active main-process log level spec.
+     * @returns Spec string, e.g. 'info,exportmanager=debug'
+     */
+    getLogLevel: () => ipcRenderer.invoke(IPC_CHANNELS.LOG_GET_LEVEL),
+
+    /**
+     * Change main-process logging verbosity at runtime.
+     * @param spec - Level ('error' | 'warn' | 'info' | 'debug'), optionally with module=level targets
+     */
+    setLogLevel: (spec: string) => ipcRenderer.send(IPC_CHANNELS.LOG_SET_LEVEL, spec),
+
+    // =========================================================================
+    // Pinned Answer Cards
+    // =========================================================================
+
+    /**
+     * Pin the latest Gemini answer into a floating always-on-top card.
+     */
+    pinLastAnswer: () => ipcRenderer.send(IPC_CHANNELS.CARDS_PIN),
+
+    // =========================================================================
+    // Answer Sharing
+    // =========================================================================
+
+    /**
+     * Redact the latest Gemini answer for review; the result arrives via onSharePreview.
+     */
+    requestSharePreview: () => ipcRenderer.send(IPC_CHANNELS.SHARE_PREVIEW_REQUEST),
+
+    /**
+     * Subscribe to redacted answers ready for review before posting.
+     * @param callback - Function called with the preview
+     * @returns Cleanup function to unsubscribe
+     */
+    onSharePreview: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, preview: SharePreview) => callback(preview);
+        ipcRenderer.on(IPC_CHANNELS.SHARE_PREVIEW, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.SHARE_PREVIEW, subscription);
+        };
+    },
+
+    /**
+     * Post a reviewed answer to the configured Slack or Teams webhook.
+     * @param markdown - Answer as confirmed in the preview
+     */
+    postShare: (markdown: string) => ipcRenderer.send(IPC_CHANNELS.SHARE_POST, markdown),
+
+    /**
+     * Get the answer sharing settings.
+     * @returns Promise resolving to the service and webhook URL
+     */
+    getShareSettings: () => ipcRenderer.invoke(IPC_CHANNELS.SHARE_SETTINGS_GET),
+
+    /**
+     * Set the answer sharing settings. The webhook URL is stored encrypted when the OS keychain is available.
+     * @param settings - The new settings
+     */
+    setShareSettings: (settings: ShareSettings) => ipcRenderer.send(IPC_CHANNELS.SHARE_SETTINGS_SET, settings),
+
+    /**
+     * Show the open conversation, or its link when too long, as a QR code in a popup window.
+     */
+    shareAsQr: () => ipcRenderer.send(IPC_CHANNELS.SHARE_QR),
+
+    // =========================================================================
+    // Issue Creation
+    // =========================================================================
+
+    /**
+     * Draft an issue from the selected text or the latest answer; the draft arrives via onIssueDraft.
+     */
+    requestIssueDraft: () => ipcRenderer.send(IPC_CHANNELS.ISSUES_DRAFT_REQUEST),
+
+    /**
+     * Subscribe to issue drafts ready for review.
+     * @param callback - Function called with the draft
+     * @returns Cleanup function to unsubscribe
+     */
+    onIssueDraft: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, draft: IssueDraft) => callback(draft);
+        ipcRenderer.on(IPC_CHANNELS.ISSUES_DRAFT, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.ISSUES_DRAFT, subscription);
+        };
+    },
+
+    /**
+     * Create a reviewed issue in the configured GitHub repository or Jira project.
+     * @param draft - Title and description as confirmed in the review dialog
+     * @returns Result with the created issue's URL
+     */
+    createIssue: (draft: IssueDraft) => ipcRenderer.invoke(IPC_CHANNELS.ISSUES_CREATE, draft),
+
+    /**
+     * Get the issue tracker settings.
+     * @returns Promise resolving to the settings
+     */
+    getIssueTrackerSettings: () => ipcRenderer.invoke(IPC_CHANNELS.ISSUES_SETTINGS_GET),
+
+    /**
+     * Set the issue tracker settings. Tokens are stored encrypted when the OS keychain is available.
+     * @param settings - The new settings
+     */
+    setIssueTrackerSettings: (settings: IssueTrackerSettings) =>
+        ipcRenderer.send(IPC_CHANNELS.ISSUES_SETTINGS_SET, settings),
+
+    // =========================================================================
+    // Screenshots
+    // =========================================================================
+
+    /**
+     * Capture the Gemini view or the latest answer as an image.
+     * @param options - Region to capture and whether to save it or copy it
+     */
+    captureScreenshot: (options: ScreenshotOptions) => ipcRenderer.send(IPC_CHANNELS.SCREENSHOT_CAPTURE, options),
+
+    // =========================================================================
+    // Userscripts
+    // =========================================================================
+
+    /**
+     * List the userscripts in the scripts folder.
+     * @returns Scripts with their metadata and enabled state
+     */
+    listUserscripts: () => ipcRenderer.invoke(IPC_CHANNELS.USERSCRIPTS_LIST),
+
+    /**
+     * Enable or disable a userscript. Takes effect the next time Gemini loads.
+     * @param id - Script file name
+     * @param enabled - Whether the script should run
+     */
+    setUserscriptEnabled: (id: string, enabled: boolean) =>
+        ipcRenderer.send(IPC_CHANNELS.USERSCRIPTS_SET_ENABLED, id, enabled),
+
+    /**
+     * Open the userscripts folder in the file manager.
+     */
+    openUserscriptsFolder: () => ipcRenderer.send(IPC_CHANNELS.USERSCRIPTS_OPEN_FOLDER),
+
+    // =========================================================================
+    // Settings Maintenance
+    // =========================================================================
+
+    /**
+     * Restore default settings for a section, backing up the current settings first.
+     * @param section - 'hotkeys', 'appearance' or 'all'
+     * @returns Result with the backup file path
+     */
+    resetSettings: (section: SettingsResetSection) => ipcRenderer.invoke(IPC_CHANNELS.SETTINGS_RESET, section),
+
+    /**
+     * Get the logged settings changes, newest first. Secret values are hidden.
+     * @returns Recorded changes
+     */
+    getSettingsHistory: () => ipcRenderer.invoke(IPC_CHANNELS.SETTINGS_GET_HISTORY),
+
+    /**
+     * Undo the most recent settings changes, restoring their previous values.
+     * @param count - Number of changes to undo
+     * @returns The undone changes
+     */
+    undoSettingsChange: (count: number) => ipcRenderer.invoke(IPC_CHANNELS.SETTINGS_UNDO, count),
+
+    // =========================================================================
+    // Upstream Proxy
+    // =========================================================================
+
+    /**
+     * Get the upstream proxy settings.
+     * @returns Proxy mode, server, credentials and bypass list
+     */
+    getProxySettings: () => ipcRenderer.invoke(IPC_CHANNELS.PROXY_GET_SETTINGS),
+
+    /**
+     * Save and apply upstream proxy settings.
+     * @param settings - Proxy mode, server, credentials and bypass list
+     */
+    setProxySettings: (settings: ProxySettings) => ipcRenderer.send(IPC_CHANNELS.PROXY_SET_SETTINGS, settings),
+
+    /**
+     * Check that Gemini is reachable through the current proxy settings.
+     * @returns Result with status code and latency, or the connection error
+     */
+    testProxyConnection: () => ipcRenderer.invoke(IPC_CHANNELS.PROXY_TEST),
+
+    // =========================================================================
+    // Usage Tracking
+    // =========================================================================
+
+    /**
+     * Get usage tracking settings.
+     * @returns Whether tracking and idle detection are enabled
+     */
+    getUsageSettings: () => ipcRenderer.invoke(IPC_CHANNELS.USAGE_GET_SETTINGS),
+
+    /**
+     * Update usage tracking settings.
+     * @param settings - Partial settings to update
+     */
+    setUsageSettings: (settings: Partial<UsageSettings>) => ipcRenderer.send(IPC_CHANNELS.USAGE_SET_SETTINGS, settings),
+
+    /**
+     * Get active time spent in the app.
+     * @returns Seconds today, this week and for each of the last 14 days
+     */
+    getUsageReport: () => ipcRenderer.invoke(IPC_CHANNELS.USAGE_GET_REPORT),
+
+    /**
+     * Get the daily usage limits and whether they are currently reached.
+     * @returns Limit status
+     */
+    getUsageLimitStatus: () => ipcRenderer.invoke(IPC_CHANNELS.USAGE_GET_LIMITS),
+
+    /**
+     * Set the daily usage limits (ignored when enforced by policy).
+     * @param limits - Minutes and prompts per day, 0 for no limit
+     */
+    setUsageLimits: (limits: UsageLimits) => ipcRenderer.send(IPC_CHANNELS.USAGE_SET_LIMITS, limits),
+
+    /**
+     * Subscribe to the daily limit being reached or cleared.
+     * @param callback - Called with the new limit status
+     * @returns Cleanup function to unsubscribe
+     */
+    onUsageLimitChanged: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, status: UsageLimitStatus) => callback(status);
+        ipcRenderer.on(IPC_CHANNELS.USAGE_LIMIT_CHANGED, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.USAGE_LIMIT_CHANGED, subscription);
+        };
+    },
+
+    /**
+     * Get the estimated Gemini prompt quota left today.
+     * @returns Plan, daily quota, prompts used and remaining
+     */
+    getGeminiQuotaStatus: () => ipcRenderer.invoke(IPC_CHANNELS.USAGE_GET_QUOTA),
+
+    /**
+     * Set the Gemini plan used for the quota estimate.
+     * @param settings - Plan, and the daily prompts for the 'custom' plan
+     */
+    setGeminiQuota: (settings: GeminiQuotaSettings) => ipcRenderer.send(IPC_CHANNELS.USAGE_SET_QUOTA, settings),
+
+    /**
+     * Subscribe to changes of the Gemini quota estimate.
+     * @param callback - Called with the new estimate
+     * @returns Cleanup function to unsubscribe
+     */
+    onGeminiQuotaChanged: (callback) => {
+        const subscription = (_event: Electron.IpcRendererEvent, status: GeminiQuotaStatus) => callback(status);
+        ipcRenderer.on(IPC_CHANNELS.USAGE_QUOTA_CHANGED, subscription);
+
+        return () => {
+            ipcRenderer.removeListener(IPC_CHANNELS.USAGE_QUOTA_CHANGED, subscription);
+        };
+    },
+
+    // =========================================================================
+    // User-Agent
+    // =========================================================================
+
+    /**
+     * Get the User-Agent override settings.
+     * @returns Preset and custom User-Agent string
+     */
+    getUserAgentSettings: () => ipcRenderer.invoke(IPC_CHANNELS.USER_AGENT_GET_SETTINGS),
+
+    /**
+     * Save and apply User-Agent override settings.
+     * @param settings - Preset and custom User-Agent string
+     */
+    setUserAgentSettings: (settings: UserAgentSettings) =>
+        ipcRenderer.send(IPC_CHANNELS.USER_AGENT_SET_SETTINGS, settings),
+
+    // =========================================================================
+    // Gemini Language
+    // =========================================================================
+
+    /**
+     * Get the language Gemini is shown in.
+     * @returns BCP 47 language tag, or '' when following the system locale
+     */
+    getGeminiLanguage: () => ipcRenderer.invoke(IPC_CHANNELS.GEMINI_LANGUAGE_GET),
+
+    /**
+     * Save and apply the language Gemini is shown in.
+     * @param tag - BCP 47 language tag, or '' to follow the system locale
+     */
+    setGeminiLanguage: (tag: string) => ipcRenderer.send(IPC_CHANNELS.GEMINI_LANGUAGE_SET, tag),
+
+    // =========================================================================
+    // Spell Check
+    // =========================================================================
+
+    /**
+     * Get the spell checker settings and the dictionaries available.
+     * @returns Whether spelling is checked, the chosen languages and the available ones
+     */
+    getSpellCheckSettings: () => ipcRenderer.invoke(IPC_CHANNELS.SPELL_CHECK_GET),
+
+    /**
+     * Turn the spell checker on or off.
+     * @param enabled - Whether to underline misspelled words
+     */
+    setSpellCheckEnabled: (enabled: boolean) => ipcRenderer.send(IPC_CHANNELS.SPELL_CHECK_SET_ENABLED, enabled),
+
+    /**
+     * Save and apply the languages the spell checker checks.
+     * @param languages - BCP 47 language tags; empty follows the Gemini language
+     */
+    setSpellCheckLanguages: (languages: string[]) =>
+        ipcRenderer.send(IPC_CHANNELS.SPELL_CHECK_SET_LANGUAGES, languages),
+
+    // =========================================================================
+    // Link Handling
+    // =========================================================================
+
+    /**
+     * Get the extra hosts Gemini may navigate to; other links open in the system browser.
+     * @returns Hosts, one per line
+     */
+    getAllowedHosts: () => ipcRenderer.invoke(IPC_CHANNELS.GEMINI_GET_ALLOWED_HOSTS),
+
+    /**
+     * Save and apply the extra hosts Gemini may navigate to.
+     * @param hosts - Hosts separated by newlines, commas or spaces
+     */
+    setAllowedHosts: (hosts: string) => ipcRenderer.send(IPC_CHANNELS.GEMINI_SET_ALLOWED_HOSTS, hosts),
 };
 
 contextBridge.exposeInMainWorld('electronAPI', electronAPI);
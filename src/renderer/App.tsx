@@ -1,11 +1,27 @@
 import { useEffect } from 'react';
 
-import { MainLayout, OfflineOverlay, GeminiErrorBoundary } from './components';
+import {
+    MainLayout,
+    OfflineOverlay,
+    GeminiErrorBoundary,
+    UsageLimitScreen,
+    SharePreviewDialog,
+    IssueDraftDialog,
+    WhatsNewDialog,
+} from './components';
 import { ThemeProvider } from './context/ThemeContext';
 import { ToastProvider, useToast } from './context/ToastContext';
 import { UpdateToastProvider } from './context/UpdateToastContext';
 import { LinuxHotkeyNotice } from './components/toast';
-import { useGeminiIframe, useQuickChatNavigation } from './hooks';
+import {
+    useGeminiIframe,
+    useQuickChatNavigation,
+    useExportProgress,
+    useUsageLimit,
+    useSharePreview,
+    useIssueDraft,
+    useWhatsNew,
+} from './hooks';
 import { GEMINI_APP_URL } from './utils/constants';
 import './App.css';
 
@@ -24,6 +40,21 @@ import './App.css';
  * - Shows progress overlay during PDF generation
  * - Listens for print progress IPC events
  *
+ * Export Progress:
+ * - Shows a cancellable progress toast while a chat export is running
+ *
+ * Usage Limits:
+ * - Replaces the iframe with a block screen once a daily limit is reached
+ *
+ * Answer Sharing:
+ * - Shows the redacted answer for review before it is posted to Slack or Teams
+ *
+ * Issue Creation:
+ * - Shows the drafted GitHub/Jira issue for review and links to it once created
+ *
+ * What's New:
+ * - Shows release notes and required actions once after an upgrade
+ *
  * Dev Mode Toast Testing:
  * - Exposes __toast global for console testing (dev mode only)
  */
@@ -35,6 +66,11 @@ function AppContent() {
     const { isLoading, error, isOnline, handleLoad, handleError, retry } = useGeminiIframe();
     const { iframeKey, handleIframeLoad } = useQuickChatNavigation(handleLoad);
     const { showToast, showSuccess, showError, showInfo, showWarning, dismissAll } = useToast();
+    useExportProgress();
+    const usageLimit = useUsageLimit();
+    const share = useSharePreview();
+    const issue = useIssueDraft();
+    const { whatsNew, acknowledge } = useWhatsNew();
 
     // Expose toast helpers globally for console testing (dev mode and testing)
     useEffect(() => {
@@ -60,9 +96,35 @@ function AppContent() {
     // This handles cases where navigator.onLine is true but Gemini is unreachable
     const showOfflineOverlay = !isOnline || !!error;
 
+    // Unmount Gemini entirely while blocked so nothing (including Quick Chat) can reach it
+    if (usageLimit?.blocked) {
+        return (
+            <MainLayout>
+                <UsageLimitScreen status={usageLimit} />
+            </MainLayout>
+        );
+    }
+
     return (
         <MainLayout>
             {showOfflineOverlay && <OfflineOverlay onRetry={retry} />}
+            {share.preview && (
+                <SharePreviewDialog
+                    key={share.preview.markdown}
+                    preview={share.preview}
+                    onSend={share.send}
+                    onCancel={share.cancel}
+                />
+            )}
+            {issue.draft && (
+                <IssueDraftDialog
+                    key={`${issue.draft.title}\n${issue.draft.body}`}
+                    draft={issue.draft}
+                    onCreate={issue.create}
+                    onClose={issue.close}
+                />
+            )}
+            {whatsNew && <WhatsNewDialog whatsNew={whatsNew} onAcknowledge={acknowledge} />}
             <GeminiErrorBoundary>
                 <div className="webview-container" data-testid="webview-container">
                     {isLoading && !showOfflineOverlay && (
@@ -0,0 +1,63 @@
+import { describe, it, expect, vi } from 'vitest';
+import { render, screen, fireEvent } from '@testing-library/react';
+import { IssueDraftDialog } from './IssueDraftDialog';
+import type { IssueDraft } from '../../../shared/types/issues';
+
+const draft = (overrides: Partial<IssueDraft> = {}): IssueDraft => ({
+    tracker: 'github',
+    title: 'Retry failed uploads',
+    body: '## Retry failed uploads',
+    ...overrides,
+});
+
+describe('IssueDraftDialog', () => {
+    it('shows the pre-filled title and description', () => {
+        render(<IssueDraftDialog draft={draft({ tracker: 'jira' })} onCreate={vi.fn()} onClose={vi.fn()} />);
+
+        expect(screen.getByText('Create Jira issue')).toBeInTheDocument();
+        expect(screen.getByTestId('issue-draft-title-input')).toHaveValue('Retry failed uploads');
+        expect(screen.getByTestId('issue-draft-body')).toHaveValue('## Retry failed uploads');
+    });
+
+    it('creates the edited issue and links to it', async () => {
+        const onCreate = vi.fn().mockResolvedValue({ success: true, url: 'https://github.com/o/r/issues/1' });
+        render(<IssueDraftDialog draft={draft()} onCreate={onCreate} onClose={vi.fn()} />);
+
+        fireEvent.change(screen.getByTestId('issue-draft-title-input'), { target: { value: 'Edited' } });
+        fireEvent.click(screen.getByTestId('issue-draft-create'));
+
+        expect(onCreate).toHaveBeenCalledWith({ tracker: 'github', title: 'Edited', body: '## Retry failed uploads' });
+        const link = await screen.findByTestId('issue-draft-link');
+        expect(link).toHaveAttribute('href', 'https://github.com/o/r/issues/1');
+        expect(link).toHaveAttribute('target', '_blank');
+    });
+
+    it('shows the error and keeps the draft when creation fails', async () => {
+        const onCreate = vi.fn().mockResolvedValue({ success: false, error: 'GitHub token is not set' });
+        render(<IssueDraftDialog draft={draft()} onCreate={onCreate} onClose={vi.fn()} />);
+
+        fireEvent.click(screen.getByTestId('issue-draft-create'));
+
+        expect(await screen.findByTestId('issue-draft-error')).toHaveTextContent('GitHub token is not set');
+        expect(screen.getByTestId('issue-draft-title-input')).toHaveValue('Retry failed uploads');
+    });
+
+    it('cannot create an issue without a title', () => {
+        render(<IssueDraftDialog draft={draft()} onCreate={vi.fn()} onClose={vi.fn()} />);
+
+        fireEvent.change(screen.getByTestId('issue-draft-title-input'), { target: { value: '  ' } });
+
+        expect(screen.getByTestId('issue-draft-create')).toBeDisabled();
+    });
+
+    it('closes without creating', () => {
+        const onCreate = vi.fn();
+        const onClose = vi.fn();
+        render(<IssueDraftDialog draft={draft()} onCreate={onCreate} onClose={onClose} />);
+
+        fireEvent.click(screen.getByTestId('issue-draft-cancel'));
+
+        expect(onClose).toHaveBeenCalled();
+        expect(onCreate).not.toHaveBeenCalled();
+    });
+});
@@ -0,0 +1,118 @@
+import { useState } from 'react';
+import type { IssueCreateResult, IssueDraft } from '../../../shared/types/issues';
+import './IssueDraftDialog.css';
+
+/**
+ * Props for the IssueDraftDialog component.
+ */
+export interface IssueDraftDialogProps {
+    draft: IssueDraft;
+    /** Called with the reviewed draft; resolves with the created issue */
+    onCreate: (draft: IssueDraft) => Promise<IssueCreateResult>;
+    onClose: () => void;
+}
+
+const TRACKER_LABELS: Record<IssueDraft['tracker'], string> = {
+    github: 'GitHub',
+    jira: 'Jira',
+};
+
+/**
+ * Review step before filing an answer as a GitHub issue or Jira ticket.
+ * The title and description are pre-filled and editable; once created,
+ * the dialog links to the new issue.
+ */
+export function IssueDraftDialog({ draft, onCreate, onClose }: IssueDraftDialogProps) {
+    const [title, setTitle] = useState(draft.title);
+    const [body, setBody] = useState(draft.body);
+    const [creating, setCreating] = useState(false);
+    const [result, setResult] = useState<IssueCreateResult | null>(null);
+    const label = TRACKER_LABELS[draft.tracker];
+
+    const handleCreate = async () => {
+        setCreating(true);
+        setResult(null);
+        try {
+            setResult(await onCreate({ tracker: draft.tracker, title, body }));
+        } catch (error) {
+            setResult({ success: false, error: (error as Error).message });
+        } finally {
+            setCreating(false);
+        }
+    };
+
+    if (result?.success) {
+        return (
+            <div className="issue-draft-backdrop" data-testid="issue-draft">
+                <div className="issue-draft" role="dialog" aria-modal="true" aria-labelledby="issue-draft-title">
+                    <h2 id="issue-draft-title" className="issue-draft__title">
+                        {label} issue created
+                    </h2>
+                    <a
+                        className="issue-draft__link"
+                        href={result.url}
+                        target="_blank"
+                        rel="noreferrer"
+                        data-testid="issue-draft-link"
+                    >
+                        {result.url}
+                    </a>
+                    <div className="issue-draft__actions">
+                        <button type="button" className="issue-draft__button" onClick={onClose}>
+                            Close
+                        </button>
+                    </div>
+                </div>
+            </div>
+        );
+    }
+
+    return (
+        <div className="issue-draft-backdrop" data-testid="issue-draft">
+            <div className="issue-draft" role="dialog" aria-modal="true" aria-labelledby="issue-draft-title">
+                <h2 id="issue-draft-title" className="issue-draft__title">
+                    Create {label} issue
+                </h2>
+                <input
+                    className="issue-draft__input"
+                    type="text"
+                    placeholder="Title"
+                    value={title}
+                    onChange={(e) => setTitle(e.target.value)}
+                    data-testid="issue-draft-title-input"
+                />
+                <textarea
+                    className="issue-draft__input issue-draft__body"
+                    value={body}
+                    spellCheck={false}
+                    onChange={(e) => setBody(e.target.value)}
+                    data-testid="issue-draft-body"
+                />
+                {result?.error && (
+                    <p className="issue-draft__error" role="alert" data-testid="issue-draft-error">
+                        {result.error}
+                    </p>
+                )}
+                <div className="issue-draft__actions">
+                    <button
+                        type="button"
+                        className="issue-draft__button"
+                        onClick={onClose}
+                        data-testid="issue-draft-cancel"
+                    >
+                        Cancel
+                    </button>
+                    <button
+                        type="button"
+                        className="issue-draft__button issue-draft__button--primary"
+                        disabled={creating || !title.trim()}
+                        onClick={handleCreate}
+                        data-testid="issue-draft-create"
+                    >
+                        {creating ? 'Creating...' : 'Create'}
+                    </button>
+                </div>
+            </div>
+        </div>
+    );
+}
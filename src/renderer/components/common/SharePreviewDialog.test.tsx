@@ -0,0 +1,56 @@
+import { describe, it, expect, vi } from 'vitest';
+import { render, screen, fireEvent } from '@testing-library/react';
+import { SharePreviewDialog } from './SharePreviewDialog';
+import type { SharePreview } from '../../../shared/types/share';
+
+const preview = (overrides: Partial<SharePreview> = {}): SharePreview => ({
+    service: 'slack',
+    markdown: 'Use password=[REDACTED]',
+    redactions: 1,
+    ...overrides,
+});
+
+describe('SharePreviewDialog', () => {
+    it('shows the redacted answer and how much was redacted', () => {
+        render(<SharePreviewDialog preview={preview()} onSend={vi.fn()} onCancel={vi.fn()} />);
+
+        expect(screen.getByText('Share to Slack')).toBeInTheDocument();
+        expect(screen.getByTestId('share-preview-text')).toHaveValue('Use password=[REDACTED]');
+        expect(screen.getByTestId('share-preview-redactions')).toHaveTextContent('Redacted 1 value that looked like');
+    });
+
+    it('says when nothing was redacted', () => {
+        render(<SharePreviewDialog preview={preview({ redactions: 0 })} onSend={vi.fn()} onCancel={vi.fn()} />);
+
+        expect(screen.getByTestId('share-preview-redactions')).toHaveTextContent('Nothing was redacted.');
+    });
+
+    it('sends the edited answer', () => {
+        const onSend = vi.fn();
+        render(<SharePreviewDialog preview={preview({ service: 'teams' })} onSend={onSend} onCancel={vi.fn()} />);
+
+        fireEvent.change(screen.getByTestId('share-preview-text'), { target: { value: 'Edited' } });
+        fireEvent.click(screen.getByText('Send to Teams'));
+
+        expect(onSend).toHaveBeenCalledWith('Edited');
+    });
+
+    it('cannot send an empty message', () => {
+        render(<SharePreviewDialog preview={preview()} onSend={vi.fn()} onCancel={vi.fn()} />);
+
+        fireEvent.change(screen.getByTestId('share-preview-text'), { target: { value: '  ' } });
+
+        expect(screen.getByTestId('share-preview-send')).toBeDisabled();
+    });
+
+    it('cancels without sending', () => {
+        const onSend = vi.fn();
+        const onCancel = vi.fn();
+        render(<SharePreviewDialog preview={preview()} onSend={onSend} onCancel={onCancel} />);
+
+        fireEvent.click(screen.getByTestId('share-preview-cancel'));
+
+        expect(onCancel).toHaveBeenCalled();
+        expect(onSend).not.toHaveBeenCalled();
+    });
+});
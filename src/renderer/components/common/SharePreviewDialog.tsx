@@ -0,0 +1,72 @@
+import { useState } from 'react';
+import type { SharePreview } from '../../../shared/types/share';
+import './SharePreviewDialog.css';
+
+/**
+ * Props for the SharePreviewDialog component.
+ */
+export interface SharePreviewDialogProps {
+    preview: SharePreview;
+    /** Called with the (possibly edited) message when the user confirms */
+    onSend: (markdown: string) => void;
+    onCancel: () => void;
+}
+
+const SERVICE_LABELS: Record<SharePreview['service'], string> = {
+    slack: 'Slack',
+    teams: 'Teams',
+};
+
+/**
+ * Review step before posting an answer to Slack or Teams.
+ * Shows the redacted answer in an editable field so anything the
+ * redaction pass missed can be removed before it leaves the machine.
+ */
+export function SharePreviewDialog({ preview, onSend, onCancel }: SharePreviewDialogProps) {
+    const [markdown, setMarkdown] = useState(preview.markdown);
+    const label = SERVICE_LABELS[preview.service];
+    const values = preview.redactions === 1 ? 'value' : 'values';
+    const redactionNote =
+        preview.redactions === 0
+            ? 'Nothing was redacted.'
+            : `Redacted ${preview.redactions} ${values} that looked like secrets or email addresses.`;
+
+    return (
+        <div className="share-preview-backdrop" data-testid="share-preview">
+            <div className="share-preview" role="dialog" aria-modal="true" aria-labelledby="share-preview-title">
+                <h2 id="share-preview-title" className="share-preview__title">
+                    Share to {label}
+                </h2>
+                <p className="share-preview__hint" data-testid="share-preview-redactions">
+                    {redactionNote} Review before sending.
+                </p>
+                <textarea
+                    className="share-preview__text"
+                    value={markdown}
+                    spellCheck={false}
+                    onChange={(e) => setMarkdown(e.target.value)}
+                    data-testid="share-preview-text"
+                />
+                <div className="share-preview__actions">
+                    <button
+                        type="button"
+                        className="share-preview__button"
+                        onClick={onCancel}
+                        data-testid="share-preview-cancel"
+                    >
+                        Cancel
+                    </button>
+                    <button
+                        type="button"
+                        className="share-preview__button share-preview__button--primary"
+                        disabled={!markdown.trim()}
+                        onClick={() => onSend(markdown)}
+                        data-testid="share-preview-send"
+                    >
+                        Send to {label}
+                    </button>
+                </div>
+            </div>
+        </div>
+    );
+}
@@ -0,0 +1,33 @@
+import { describe, it, expect } from 'vitest';
+import { render, screen } from '@testing-library/react';
+import { UsageLimitScreen } from './UsageLimitScreen';
+import type { UsageLimitStatus } from '../../../shared/types/usage';
+
+const status = (overrides: Partial<UsageLimitStatus> = {}): UsageLimitStatus => ({
+    blocked: true,
+    reason: 'time',
+    limits: { dailyMinutes: 60, dailyPrompts: 25 },
+    enforcedByPolicy: false,
+    ...overrides,
+});
+
+describe('UsageLimitScreen', () => {
+    it('explains the time limit', () => {
+        render(<UsageLimitScreen status={status()} />);
+        expect(screen.getByText('Daily Limit Reached')).toBeInTheDocument();
+        expect(screen.getByText(/used Gemini for 60 minutes today/)).toBeInTheDocument();
+    });
+
+    it('explains the prompt limit', () => {
+        render(<UsageLimitScreen status={status({ reason: 'prompts' })} />);
+        expect(screen.getByText(/used all 25 prompts for today/)).toBeInTheDocument();
+    });
+
+    it('mentions the administrator only when enforced by policy', () => {
+        const { rerender } = render(<UsageLimitScreen status={status()} />);
+        expect(screen.queryByTestId('usage-limit-policy')).not.toBeInTheDocument();
+
+        rerender(<UsageLimitScreen status={status({ enforcedByPolicy: true })} />);
+        expect(screen.getByTestId('usage-limit-policy')).toBeInTheDocument();
+    });
+});
@@ -0,0 +1,42 @@
+import type { UsageLimitStatus } from '../../../shared/types/usage';
+import './UsageLimitScreen.css';
+
+/**
+ * Props for the UsageLimitScreen component.
+ */
+export interface UsageLimitScreenProps {
+    status: UsageLimitStatus;
+}
+
+/**
+ * Full-window screen shown in place of Gemini once a daily usage limit is reached.
+ * It stays until the limit clears at the start of the next day.
+ */
+export function UsageLimitScreen({ status }: UsageLimitScreenProps) {
+    const { reason, limits, enforcedByPolicy } = status;
+    const detail =
+        reason === 'prompts'
+            ? `You have used all ${limits.dailyPrompts} prompts for today.`
+            : `You have used Gemini for ${limits.dailyMinutes} minutes today.`;
+
+    return (
+        <div className="usage-limit-screen" data-testid="usage-limit-screen" role="alert">
+            <div className="usage-limit-content">
+                <svg className="usage-limit-icon" viewBox="0 0 24 24" fill="none" aria-hidden="true">
+                    <circle cx="12" cy="12" r="9" stroke="currentColor" strokeWidth="2" />
+                    <path d="M12 7V12L15 14" stroke="currentColor" strokeWidth="2" strokeLinecap="round" />
+                </svg>
+
+                <div className="usage-limit-message">
+                    <h1>Daily Limit Reached</h1>
+                    <p>{detail} Gemini will be available again tomorrow.</p>
+                    {enforcedByPolicy && (
+                        <p className="usage-limit-policy" data-testid="usage-limit-policy">
+                            This limit is set by your administrator.
+                        </p>
+                    )}
+                </div>
+            </div>
+        </div>
+    );
+}
@@ -0,0 +1,57 @@
+import { describe, it, expect, vi } from 'vitest';
+import { render, screen, fireEvent } from '@testing-library/react';
+import { WhatsNewDialog } from './WhatsNewDialog';
+import type { WhatsNew } from '../../../shared/types/whats-new';
+
+const whatsNew = (overrides: Partial<WhatsNew> = {}): WhatsNew => ({
+    currentVersion: '0.7.0',
+    previousVersion: '0.5.1',
+    entries: [
+        { version: '0.7.0', highlights: ['New cookie store'] },
+        { version: '0.6.0', highlights: ['Compact mode', 'Overlay mode'] },
+    ],
+    actions: [],
+    ...overrides,
+});
+
+describe('WhatsNewDialog', () => {
+    it('lists the highlights of each version', () => {
+        render(<WhatsNewDialog whatsNew={whatsNew()} onAcknowledge={vi.fn()} />);
+
+        expect(screen.getByText("What's new in 0.7.0")).toBeInTheDocument();
+        expect(screen.getAllByTestId('whats-new-entry')).toHaveLength(2);
+        expect(screen.getByText('Overlay mode')).toBeInTheDocument();
+        expect(screen.queryByTestId('whats-new-actions')).not.toBeInTheDocument();
+    });
+
+    it('offers buttons for required actions', () => {
+        render(
+            <WhatsNewDialog
+                whatsNew={whatsNew({
+                    actions: [
+                        { kind: 'sign-in', message: 'Sign in to Google again' },
+                        { kind: 'open-options', message: 'Check your proxy settings' },
+                        { kind: 'info', message: 'Nothing to do' },
+                    ],
+                })}
+                onAcknowledge={vi.fn()}
+            />
+        );
+
+        expect(screen.getByText('Nothing to do')).toBeInTheDocument();
+        fireEvent.click(screen.getByTestId('whats-new-sign-in'));
+        fireEvent.click(screen.getByTestId('whats-new-open-options'));
+
+        expect(window.electronAPI.openGoogleSignIn).toHaveBeenCalled();
+        expect(window.electronAPI.openOptions).toHaveBeenCalled();
+    });
+
+    it('acknowledges when dismissed', () => {
+        const onAcknowledge = vi.fn();
+        render(<WhatsNewDialog whatsNew={whatsNew()} onAcknowledge={onAcknowledge} />);
+
+        fireEvent.click(screen.getByTestId('whats-new-acknowledge'));
+
+        expect(onAcknowledge).toHaveBeenCalled();
+    });
+});
@@ -0,0 +1,90 @@
+import type { MigrationAction, WhatsNew } from '../../../shared/types/whats-new';
+import './WhatsNewDialog.css';
+
+/**
+ * Props for the WhatsNewDialog component.
+ */
+export interface WhatsNewDialogProps {
+    whatsNew: WhatsNew;
+    /** Called when the user has read the notes */
+    onAcknowledge: () => void;
+}
+
+/**
+ * Button shown next to an action, if it has one.
+ */
+function ActionButton({ action }: { action: MigrationAction }) {
+    if (action.kind === 'sign-in') {
+        return (
+            <button
+                type="button"
+                className="whats-new__button"
+                onClick={() => window.electronAPI?.openGoogleSignIn()}
+                data-testid="whats-new-sign-in"
+            >
+                Sign in
+            </button>
+        );
+    }
+    if (action.kind === 'open-options') {
+        return (
+            <button
+                type="button"
+                className="whats-new__button"
+                onClick={() => window.electronAPI?.openOptions()}
+                data-testid="whats-new-open-options"
+            >
+                Open Options
+            </button>
+        );
+    }
+    return null;
+}
+
+/**
+ * Release notes shown once after an upgrade, with any actions the
+ * upgrade needs (such as signing in again) listed first.
+ */
+export function WhatsNewDialog({ whatsNew, onAcknowledge }: WhatsNewDialogProps) {
+    return (
+        <div className="whats-new-backdrop" data-testid="whats-new">
+            <div className="whats-new" role="dialog" aria-modal="true" aria-labelledby="whats-new-title">
+                <h2 id="whats-new-title" className="whats-new__title">
+                    What&apos;s new in {whatsNew.currentVersion}
+                </h2>
+                {whatsNew.actions.length > 0 && (
+                    <ul className="whats-new__actions-list" data-testid="whats-new-actions">
+                        {whatsNew.actions.map((action, index) => (
+                            <li key={index} className="whats-new__action">
+                                <span>{action.message}</span>
+                                <ActionButton action={action} />
+                            </li>
+                        ))}
+                    </ul>
+                )}
+                <div className="whats-new__entries">
+                    {whatsNew.entries.map((entry) => (
+                        <section key={entry.version} data-testid="whats-new-entry">
+                            <h3 className="whats-new__version">{entry.version}</h3>
+                            <ul className="whats-new__highlights">
+                                {entry.highlights.map((highlight) => (
+                                    <li key={highlight}>{highlight}</li>
+                                ))}
+                            </ul>
+                        </section>
+                    ))}
+                </div>
+                <div className="whats-new__footer">
+                    <button
+                        type="button"
+                        className="whats-new__button whats-new__button--primary"
+                        onClick={onAcknowledge}
+                        data-testid="whats-new-acknowledge"
+                    >
+                        Got it
+                    </button>
+                </div>
+            </div>
+        </div>
+    );
+}
@@ -12,3 +12,7 @@ export { MainLayout } from './layout/MainLayout';
 export { ErrorBoundary } from './ErrorBoundary';
 export { GeminiErrorBoundary } from './GeminiErrorBoundary';
 export { OfflineOverlay } from './common/OfflineOverlay';
+export { UsageLimitScreen } from './common/UsageLimitScreen';
+export { SharePreviewDialog } from './common/SharePreviewDialog';
+export { IssueDraftDialog } from './common/IssueDraftDialog';
+export { WhatsNewDialog } from './common/WhatsNewDialog';
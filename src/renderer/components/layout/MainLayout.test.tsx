@@ -3,8 +3,9 @@
  */
 
 import { describe, it, expect } from 'vitest';
-import { render, screen } from '@testing-library/react';
+import { act, render, screen, waitFor } from '@testing-library/react';
 import { MainLayout } from './MainLayout';
+import { mockElectronAPI } from '../../../../tests/unit/renderer/test/setup';
 
 describe('MainLayout', () => {
     describe('structure', () => {
@@ -22,6 +23,28 @@ describe('MainLayout', () => {
             expect(titlebar).toBeInTheDocument();
         });
 
+        it('hides the Titlebar in immersive mode', async () => {
+            render(<MainLayout />);
+            await waitFor(() => expect(mockElectronAPI.onImmersiveModeChanged).toHaveBeenCalled());
+
+            const callback = mockElectronAPI.onImmersiveModeChanged.mock.calls.at(-1)![0];
+            act(() => callback({ enabled: true }));
+
+            expect(document.querySelector('header.titlebar')).not.toBeInTheDocument();
+            expect(document.querySelector('main.main-content')).toBeInTheDocument();
+        });
+
+        it('marks the layout as compact in compact mode', async () => {
+            render(<MainLayout />);
+            await waitFor(() => expect(mockElectronAPI.onCompactModeChanged).toHaveBeenCalled());
+
+            const callback = mockElectronAPI.onCompactModeChanged.mock.calls.at(-1)![0];
+            act(() => callback({ enabled: true }));
+
+            expect(screen.getByTestId('main-layout')).toHaveClass('main-layout--compact');
+            expect(document.querySelector('header.titlebar')).toBeInTheDocument();
+        });
+
         it('renders main content area', () => {
             render(<MainLayout />);
 
@@ -1,5 +1,7 @@
 import { ReactNode } from 'react';
 import { Titlebar } from '../titlebar';
+import { useImmersiveMode } from '../../hooks/useImmersiveMode';
+import { useCompactMode } from '../../hooks/useCompactMode';
 import './layout.css';
 
 interface MainLayoutProps {
@@ -10,16 +12,19 @@ interface MainLayoutProps {
  * Main application layout component.
  *
  * Provides the structure with:
- * - Custom titlebar at the top
+ * - Custom titlebar at the top, hidden in immersive mode and slimmed in compact mode
  * - Content area below for the webview or other content
  *
  * This component handles the overall app structure and ensures
  * proper sizing for the embedded webview.
  */
 export function MainLayout({ children }: MainLayoutProps) {
+    const immersive = useImmersiveMode();
+    const compact = useCompactMode();
+
     return (
-        <div className="main-layout" data-testid="main-layout">
-            <Titlebar />
+        <div className={compact ? 'main-layout main-layout--compact' : 'main-layout'} data-testid="main-layout">
+            {!immersive && <Titlebar />}
             <main className="main-content">{children}</main>
         </div>
     );
@@ -0,0 +1,84 @@
+/**
+ * AutoCopySettings Component
+ *
+ * Toggles for copying completed Gemini answers to the clipboard automatically.
+ * Quick Chat answers can be copied on their own, or every completed answer.
+ *
+ * @module AutoCopySettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import { CapsuleToggle } from '../common/CapsuleToggle';
+import type { AutoCopySettings as AutoCopySettingsType } from '../../../shared/types/auto-copy';
+
+/** Defaults (match main process defaults) */
+const DEFAULT_SETTINGS: AutoCopySettingsType = { mode: 'off', format: 'markdown' };
+
+/**
+ * AutoCopySettings component.
+ * Renders toggles for answer-to-clipboard automation.
+ */
+export const AutoCopySettings = memo(function AutoCopySettings() {
+    const [settings, setSettings] = useState<AutoCopySettingsType>(DEFAULT_SETTINGS);
+    const [loading, setLoading] = useState(true);
+
+    // Load initial state from main process
+    useEffect(() => {
+        const loadState = async () => {
+            try {
+                const loaded = await window.electronAPI?.getAutoCopySettings();
+                setSettings(loaded ?? DEFAULT_SETTINGS);
+            } catch (error) {
+                console.error('Failed to load auto-copy settings:', error);
+            } finally {
+                setLoading(false);
+            }
+        };
+
+        loadState();
+    }, []);
+
+    // Apply a partial update locally and persist it
+    const update = useCallback((partial: Partial<AutoCopySettingsType>) => {
+        setSettings((prev) => ({ ...prev, ...partial }));
+        window.electronAPI?.setAutoCopySettings(partial);
+    }, []);
+
+    if (loading) {
+        return (
+            <div className="auto-copy-settings loading" data-testid="auto-copy-settings-loading">
+                Loading...
+            </div>
+        );
+    }
+
+    return (
+        <div className="auto-copy-settings" data-testid="auto-copy-settings">
+            <CapsuleToggle
+                checked={settings.mode !== 'off'}
+                onChange={(checked) => update({ mode: checked ? 'quick-chat' : 'off' })}
+                label="Copy Quick Chat Answers"
+                description="Copy answers to Quick Chat prompts to the clipboard when they finish"
+                testId="auto-copy-quick-chat-toggle"
+            />
+            <CapsuleToggle
+                checked={settings.mode === 'all'}
+                onChange={(checked) => update({ mode: checked ? 'all' : 'quick-chat' })}
+                label="Copy All Answers"
+                description="Copy every completed answer, not just Quick Chat ones"
+                disabled={settings.mode === 'off'}
+                testId="auto-copy-all-toggle"
+            />
+            <CapsuleToggle
+                checked={settings.format === 'markdown'}
+                onChange={(checked) => update({ format: checked ? 'markdown' : 'text' })}
+                label="Copy as Markdown"
+                description="Keep formatting as Markdown instead of plain text"
+                disabled={settings.mode === 'off'}
+                testId="auto-copy-markdown-toggle"
+            />
+        </div>
+    );
+});
+
+export default AutoCopySettings;
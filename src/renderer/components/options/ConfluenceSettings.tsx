@@ -0,0 +1,140 @@
+/**
+ * ConfluenceSettings Component
+ *
+ * Form for publishing exported chats to Confluence: site URL, credentials
+ * and the space (and optional parent page) new pages are created in.
+ *
+ * @module ConfluenceSettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import {
+    DEFAULT_CONFLUENCE_SETTINGS,
+    type ConfluenceSettings as ConfluenceSettingsType,
+} from '../../../shared/types/confluence';
+import './ConfluenceSettings.css';
+
+/**
+ * ConfluenceSettings component.
+ * Renders the Confluence export form.
+ */
+export const ConfluenceSettings = memo(function ConfluenceSettings() {
+    const [settings, setSettings] = useState<ConfluenceSettingsType>(DEFAULT_CONFLUENCE_SETTINGS);
+    const [loading, setLoading] = useState(true);
+    const [dirty, setDirty] = useState(false);
+    const [status, setStatus] = useState<string | null>(null);
+
+    // Load initial state from main process
+    useEffect(() => {
+        const loadState = async () => {
+            try {
+                const loaded = await window.electronAPI?.getConfluenceSettings();
+                setSettings(loaded ?? DEFAULT_CONFLUENCE_SETTINGS);
+            } catch (error) {
+                console.error('Failed to load Confluence settings:', error);
+            } finally {
+                setLoading(false);
+            }
+        };
+
+        loadState();
+    }, []);
+
+    const update = useCallback((partial: Partial<ConfluenceSettingsType>) => {
+        setSettings((prev) => ({ ...prev, ...partial }));
+        setDirty(true);
+        setStatus(null);
+    }, []);
+
+    const isValid =
+        (settings.baseUrl.trim() === '' || settings.baseUrl.trim().startsWith('https://')) &&
+        /^\d*$/.test(settings.parentId.trim());
+
+    const handleSave = useCallback(() => {
+        window.electronAPI?.setConfluenceSettings(settings);
+        setDirty(false);
+        setStatus('Confluence settings saved.');
+    }, [settings]);
+
+    if (loading) {
+        return (
+            <div className="confluence-settings loading" data-testid="confluence-settings-loading">
+                Loading...
+            </div>
+        );
+    }
+
+    return (
+        <div className="confluence-settings" data-testid="confluence-settings">
+            <label className="confluence-settings__label" htmlFor="confluence-url">
+                Confluence
+            </label>
+            <input
+                id="confluence-url"
+                className="confluence-settings__input"
+                type="url"
+                placeholder="https://example.atlassian.net/wiki"
+                value={settings.baseUrl}
+                spellCheck={false}
+                onChange={(e) => update({ baseUrl: e.target.value })}
+                data-testid="confluence-url-input"
+            />
+            <div className="confluence-settings__row">
+                <input
+                    className="confluence-settings__input"
+                    type="email"
+                    placeholder="Email (Cloud only)"
+                    value={settings.email}
+                    autoComplete="off"
+                    onChange={(e) => update({ email: e.target.value })}
+                    data-testid="confluence-email-input"
+                />
+                <input
+                    className="confluence-settings__input"
+                    type="password"
+                    placeholder="API or personal access token"
+                    value={settings.apiToken}
+                    autoComplete="off"
+                    onChange={(e) => update({ apiToken: e.target.value })}
+                    data-testid="confluence-token-input"
+                />
+            </div>
+            <div className="confluence-settings__row">
+                <input
+                    className="confluence-settings__input"
+                    type="text"
+                    placeholder="Space key"
+                    value={settings.spaceKey}
+                    spellCheck={false}
+                    onChange={(e) => update({ spaceKey: e.target.value })}
+                    data-testid="confluence-space-input"
+                />
+                <input
+                    className="confluence-settings__input"
+                    type="text"
+                    inputMode="numeric"
+                    placeholder="Parent page ID (optional)"
+                    value={settings.parentId}
+                    onChange={(e) => update({ parentId: e.target.value })}
+                    data-testid="confluence-parent-input"
+                />
+            </div>
+            <div className="confluence-settings__row">
+                <button
+                    type="button"
+                    className="confluence-settings__button"
+                    disabled={!dirty || !isValid}
+                    onClick={handleSave}
+                    data-testid="confluence-save-button"
+                >
+                    Save
+                </button>
+            </div>
+            <span className="confluence-settings__hint" data-testid="confluence-status">
+                {status ?? 'Use File › Export to Confluence. A page with the same title is updated.'}
+            </span>
+        </div>
+    );
+});
+
+export default ConfluenceSettings;
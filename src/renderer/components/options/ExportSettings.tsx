@@ -0,0 +1,106 @@
+/**
+ * ExportSettings Component
+ *
+ * Text input for the chat export filename template.
+ * The template supports {title}, {date}, {time}, {id}, {gem} and {format} tokens
+ * and is sanitized for the current platform in the main process.
+ *
+ * @module ExportSettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import './ExportSettings.css';
+
+/** Default template (matches main process default) */
+const DEFAULT_TEMPLATE = '{title}';
+
+/** Tokens shown as a hint below the input */
+const TEMPLATE_TOKENS = ['{title}', '{date}', '{time}', '{id}', '{gem}', '{format}'];
+
+/**
+ * ExportSettings component.
+ * Renders the filename template input for chat exports.
+ */
+export const ExportSettings = memo(function ExportSettings() {
+    const [template, setTemplate] = useState(DEFAULT_TEMPLATE);
+    const [savedTemplate, setSavedTemplate] = useState(DEFAULT_TEMPLATE);
+    const [loading, setLoading] = useState(true);
+
+    // Load initial state from main process
+    useEffect(() => {
+        const loadState = async () => {
+            try {
+                const value = await window.electronAPI?.getExportFilenameTemplate();
+                setTemplate(value || DEFAULT_TEMPLATE);
+                setSavedTemplate(value || DEFAULT_TEMPLATE);
+            } catch (error) {
+                console.error('Failed to load export filename template:', error);
+            } finally {
+                setLoading(false);
+            }
+        };
+
+        loadState();
+    }, []);
+
+    // Persist the template when editing finishes
+    const commit = useCallback(() => {
+        const trimmed = template.trim();
+        if (!trimmed) {
+            // Empty templates are not allowed; revert to the last saved value
+            setTemplate(savedTemplate);
+            return;
+        }
+        if (trimmed === savedTemplate) return;
+
+        try {
+            window.electronAPI?.setExportFilenameTemplate(trimmed);
+            setSavedTemplate(trimmed);
+            setTemplate(trimmed);
+        } catch (error) {
+            console.error('Failed to set export filename template:', error);
+            setTemplate(savedTemplate);
+        }
+    }, [template, savedTemplate]);
+
+    const handleKeyDown = useCallback(
+        (event: React.KeyboardEvent<HTMLInputElement>) => {
+            if (event.key === 'Enter') {
+                commit();
+            } else if (event.key === 'Escape') {
+                setTemplate(savedTemplate);
+            }
+        },
+        [commit, savedTemplate]
+    );
+
+    if (loading) {
+        return (
+            <div className="export-settings loading" data-testid="export-settings-loading">
+                Loading...
+            </div>
+        );
+    }
+
+    return (
+        <div className="export-settings" data-testid="export-settings">
+            <label className="export-settings__label" htmlFor="export-filename-template">
+                Filename Template
+            </label>
+            <input
+                id="export-filename-template"
+                className="export-settings__input"
+                type="text"
+                value={template}
+                spellCheck={false}
+                onChange={(e) => setTemplate(e.target.value)}
+                onBlur={commit}
+                onKeyDown={handleKeyDown}
+                data-testid="export-filename-template-input"
+            />
+            <span className="export-settings__hint">Available tokens: {TEMPLATE_TOKENS.join(' ')}</span>
+        </div>
+    );
+});
+
+export default ExportSettings;
@@ -0,0 +1,103 @@
+/**
+ * GeminiQuotaSettings Component
+ *
+ * Select for the user's Gemini plan, so the app can estimate how many prompts
+ * of the plan's daily quota are left. Google does not expose quota usage, so
+ * the estimate only counts prompts sent from this app; the 'Custom' plan lets
+ * the user enter their own daily number when the published quotas change.
+ *
+ * @module GeminiQuotaSettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import {
+    GEMINI_PLAN_DAILY_PROMPTS,
+    GEMINI_QUOTA_PLANS,
+    type GeminiQuotaPlan,
+    type GeminiQuotaStatus,
+} from '../../../shared/types/usage';
+import './UsageSettings.css';
+
+/** Labels for each plan */
+const PLAN_LABELS: Record<GeminiQuotaPlan, string> = {
+    off: 'Off',
+    free: `Free (${GEMINI_PLAN_DAILY_PROMPTS.free}/day)`,
+    pro: `Google AI Pro (${GEMINI_PLAN_DAILY_PROMPTS.pro}/day)`,
+    ultra: `Google AI Ultra (${GEMINI_PLAN_DAILY_PROMPTS.ultra}/day)`,
+    custom: 'Custom',
+};
+
+/**
+ * GeminiQuotaSettings component.
+ * Renders the plan select, the custom quota input and today's estimate.
+ */
+export const GeminiQuotaSettings = memo(function GeminiQuotaSettings() {
+    const [status, setStatus] = useState<GeminiQuotaStatus | null>(null);
+
+    // Load the estimate and follow changes from the main process
+    useEffect(() => {
+        window.electronAPI
+            ?.getGeminiQuotaStatus()
+            .then(setStatus)
+            .catch((error) => console.error('Failed to load Gemini quota estimate:', error));
+
+        return window.electronAPI?.onGeminiQuotaChanged(setStatus);
+    }, []);
+
+    // Persist a change; the main process broadcasts the new estimate
+    const update = useCallback(
+        (plan: GeminiQuotaPlan, customDailyPrompts: number) => {
+            if (!status) return;
+            setStatus({ ...status, plan, customDailyPrompts });
+            window.electronAPI?.setGeminiQuota({ plan, customDailyPrompts });
+        },
+        [status]
+    );
+
+    if (!status) return null;
+
+    let hint = 'Counts prompts sent from this app against the daily quota of your Gemini plan.';
+    if (status.dailyPrompts > 0) {
+        hint =
+            `About ${status.remaining} of ${status.dailyPrompts} prompts left today. ` +
+            'Prompts sent from other devices are not counted.';
+    }
+
+    return (
+        <div className="usage-settings__limits" data-testid="gemini-quota-settings">
+            <label className="usage-settings__limit">
+                <span>Gemini plan quota</span>
+                <select
+                    value={status.plan}
+                    onChange={(e) => update(e.target.value as GeminiQuotaPlan, status.customDailyPrompts)}
+                    data-testid="gemini-quota-plan-select"
+                >
+                    {GEMINI_QUOTA_PLANS.map((plan) => (
+                        <option key={plan} value={plan}>
+                            {PLAN_LABELS[plan]}
+                        </option>
+                    ))}
+                </select>
+            </label>
+            {status.plan === 'custom' && (
+                <label className="usage-settings__limit">
+                    <span>Daily prompts</span>
+                    <input
+                        className="usage-settings__input"
+                        type="number"
+                        min={0}
+                        placeholder="None"
+                        value={status.customDailyPrompts || ''}
+                        onChange={(e) => update('custom', Math.max(0, Math.floor(Number(e.target.value) || 0)))}
+                        data-testid="gemini-quota-custom-input"
+                    />
+                </label>
+            )}
+            <p className="usage-settings__summary" data-testid="gemini-quota-hint">
+                {hint}
+            </p>
+        </div>
+    );
+});
+
+export default GeminiQuotaSettings;
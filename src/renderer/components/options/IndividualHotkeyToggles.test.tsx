@@ -0,0 +1,51 @@
+/**
+ * Unit tests for IndividualHotkeyToggles component.
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, waitFor } from '@testing-library/react';
+import { IndividualHotkeyToggles } from './IndividualHotkeyToggles';
+import { mockElectronAPI } from '../../../../tests/unit/renderer/test/setup';
+import { DEFAULT_ACCELERATORS } from '../../../shared/types/hotkeys';
+
+vi.mock('../../context/IndividualHotkeysContext', async (importOriginal) => ({
+    ...(await importOriginal<typeof import('../../context/IndividualHotkeysContext')>()),
+    useIndividualHotkeys: () => ({
+        settings: { alwaysOnTop: true, bossKey: true, quickChat: true, printToPdf: true },
+        accelerators: DEFAULT_ACCELERATORS,
+        setEnabled: vi.fn(),
+        setAccelerator: vi.fn(),
+    }),
+}));
+
+describe('IndividualHotkeyToggles', () => {
+    beforeEach(() => {
+        vi.clearAllMocks();
+    });
+
+    it('disables global hotkeys the platform cannot register', async () => {
+        const report = await mockElectronAPI.getCapabilityReport();
+        mockElectronAPI.getCapabilityReport.mockResolvedValueOnce({
+            ...report,
+            capabilities: {
+                ...report.capabilities,
+                globalHotkeys: { available: false, reason: 'Not on this platform' },
+            },
+        });
+
+        render(<IndividualHotkeyToggles />);
+
+        await waitFor(() => {
+            expect(screen.getByTestId('hotkey-toggle-quickChat-switch')).toBeDisabled();
+        });
+        expect(screen.getByTestId('hotkey-row-quickChat')).toHaveTextContent('Not on this platform');
+        expect(screen.getByTestId('hotkey-toggle-alwaysOnTop-switch')).not.toBeDisabled();
+    });
+
+    it('leaves hotkeys enabled when they are supported', async () => {
+        render(<IndividualHotkeyToggles />);
+
+        await waitFor(() => expect(mockElectronAPI.getCapabilityReport).toHaveBeenCalled());
+        expect(screen.getByTestId('hotkey-toggle-quickChat-switch')).not.toBeDisabled();
+    });
+});
@@ -9,6 +9,9 @@
  * - **Boss Key**: Minimize window
  * - **Quick Chat**: Open quick chat overlay
  *
+ * Global hotkeys are disabled, with the reason shown, where the platform
+ * cannot register them.
+ *
  * @module IndividualHotkeyToggles
  */
 
@@ -16,6 +19,8 @@ import { memo } from 'react';
 import { CapsuleToggle } from '../common/CapsuleToggle';
 import { useIndividualHotkeys, HotkeyId, DEFAULT_ACCELERATORS } from '../../context/IndividualHotkeysContext';
 import { HotkeyAcceleratorInput } from './HotkeyAcceleratorInput';
+import { useCapabilities } from '../../hooks/useCapabilities';
+import { isGlobalHotkey } from '../../../shared/types/hotkeys';
 import './individualHotkeyToggles.css';
 
 // ============================================================================
@@ -69,35 +74,43 @@ const HOTKEY_CONFIGS: HotkeyConfig[] = [
  */
 export const IndividualHotkeyToggles = memo(function IndividualHotkeyToggles() {
     const { settings, accelerators, setEnabled, setAccelerator } = useIndividualHotkeys();
+    const globalHotkeys = useCapabilities()?.capabilities.globalHotkeys;
 
     return (
         <div className="individual-hotkey-toggles" data-testid="individual-hotkey-toggles">
-            {HOTKEY_CONFIGS.map((config) => (
-                <div key={config.id} className="hotkey-row" data-testid={`hotkey-row-${config.id}`}>
-                    <div className="hotkey-label-wrapper">
-                        <span className="hotkey-label">{config.label}</span>
-                        <span className="hotkey-description">{config.description}</span>
-                    </div>
-                    <div className="hotkey-accelerator-wrapper">
-                        <HotkeyAcceleratorInput
-                            hotkeyId={config.id}
-                            currentAccelerator={accelerators[config.id]}
-                            disabled={!settings[config.id]}
-                            onAcceleratorChange={setAccelerator}
-                            defaultAccelerator={DEFAULT_ACCELERATORS[config.id]}
-                        />
-                    </div>
-                    <div className="hotkey-toggle-wrapper">
-                        <CapsuleToggle
-                            checked={settings[config.id]}
-                            onChange={(enabled) => setEnabled(config.id, enabled)}
-                            label=""
-                            description=""
-                            testId={`hotkey-toggle-${config.id}`}
-                        />
+            {HOTKEY_CONFIGS.map((config) => {
+                const unsupported = isGlobalHotkey(config.id) && globalHotkeys?.available === false;
+
+                return (
+                    <div key={config.id} className="hotkey-row" data-testid={`hotkey-row-${config.id}`}>
+                        <div className="hotkey-label-wrapper">
+                            <span className="hotkey-label">{config.label}</span>
+                            <span className="hotkey-description">
+                                {unsupported ? globalHotkeys?.reason : config.description}
+                            </span>
+                        </div>
+                        <div className="hotkey-accelerator-wrapper">
+                            <HotkeyAcceleratorInput
+                                hotkeyId={config.id}
+                                currentAccelerator={accelerators[config.id]}
+                                disabled={unsupported || !settings[config.id]}
+                                onAcceleratorChange={setAccelerator}
+                                defaultAccelerator={DEFAULT_ACCELERATORS[config.id]}
+                            />
+                        </div>
+                        <div className="hotkey-toggle-wrapper">
+                            <CapsuleToggle
+                                checked={settings[config.id]}
+                                onChange={(enabled) => setEnabled(config.id, enabled)}
+                                label=""
+                                description=""
+                                disabled={unsupported}
+                                testId={`hotkey-toggle-${config.id}`}
+                            />
+                        </div>
                     </div>
-                </div>
-            ))}
+                );
+            })}
         </div>
     );
 });
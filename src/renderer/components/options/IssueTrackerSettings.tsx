@@ -0,0 +1,174 @@
+/**
+ * IssueTrackerSettings Component
+ *
+ * Form for filing answers as issues: the tracker (GitHub or Jira) and the
+ * repository or project with the credentials to create issues in it.
+ *
+ * @module IssueTrackerSettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import {
+    DEFAULT_ISSUE_TRACKER_SETTINGS,
+    type IssueTracker,
+    type IssueTrackerSettings as IssueTrackerSettingsType,
+} from '../../../shared/types/issues';
+import './IssueTrackerSettings.css';
+
+/** Tracker options shown in the select */
+const TRACKER_OPTIONS: { value: IssueTracker; label: string }[] = [
+    { value: 'github', label: 'GitHub' },
+    { value: 'jira', label: 'Jira' },
+];
+
+/**
+ * IssueTrackerSettings component.
+ * Renders the issue tracker form.
+ */
+export const IssueTrackerSettings = memo(function IssueTrackerSettings() {
+    const [settings, setSettings] = useState<IssueTrackerSettingsType>(DEFAULT_ISSUE_TRACKER_SETTINGS);
+    const [loading, setLoading] = useState(true);
+    const [dirty, setDirty] = useState(false);
+    const [status, setStatus] = useState<string | null>(null);
+
+    // Load initial state from main process
+    useEffect(() => {
+        const loadState = async () => {
+            try {
+                const loaded = await window.electronAPI?.getIssueTrackerSettings();
+                setSettings(loaded ?? DEFAULT_ISSUE_TRACKER_SETTINGS);
+            } catch (error) {
+                console.error('Failed to load issue tracker settings:', error);
+            } finally {
+                setLoading(false);
+            }
+        };
+
+        loadState();
+    }, []);
+
+    const update = useCallback((partial: Partial<IssueTrackerSettingsType>) => {
+        setSettings((prev) => ({ ...prev, ...partial }));
+        setDirty(true);
+        setStatus(null);
+    }, []);
+
+    const isValid =
+        settings.tracker === 'github'
+            ? settings.githubRepository.trim() === '' || /^[\w.-]+\/[\w.-]+$/.test(settings.githubRepository.trim())
+            : settings.jiraBaseUrl.trim() === '' || settings.jiraBaseUrl.trim().startsWith('https://');
+
+    const handleSave = useCallback(() => {
+        window.electronAPI?.setIssueTrackerSettings(settings);
+        setDirty(false);
+        setStatus('Issue tracker settings saved.');
+    }, [settings]);
+
+    if (loading) {
+        return (
+            <div className="issue-tracker-settings loading" data-testid="issue-tracker-settings-loading">
+                Loading...
+            </div>
+        );
+    }
+
+    return (
+        <div className="issue-tracker-settings" data-testid="issue-tracker-settings">
+            <label className="issue-tracker-settings__label" htmlFor="issue-tracker">
+                Create issues
+            </label>
+            <div className="issue-tracker-settings__row">
+                <select
+                    id="issue-tracker"
+                    className="issue-tracker-settings__input issue-tracker-settings__tracker"
+                    value={settings.tracker}
+                    onChange={(e) => update({ tracker: e.target.value as IssueTracker })}
+                    data-testid="issue-tracker-select"
+                >
+                    {TRACKER_OPTIONS.map(({ value, label }) => (
+                        <option key={value} value={value}>
+                            {label}
+                        </option>
+                    ))}
+                </select>
+                {settings.tracker === 'github' ? (
+                    <>
+                        <input
+                            className="issue-tracker-settings__input"
+                            type="text"
+                            placeholder="owner/repository"
+                            value={settings.githubRepository}
+                            onChange={(e) => update({ githubRepository: e.target.value })}
+                            data-testid="issue-github-repository-input"
+                        />
+                        <input
+                            className="issue-tracker-settings__input"
+                            type="password"
+                            placeholder="Personal access token"
+                            value={settings.githubToken}
+                            autoComplete="off"
+                            onChange={(e) => update({ githubToken: e.target.value })}
+                            data-testid="issue-github-token-input"
+                        />
+                    </>
+                ) : (
+                    <>
+                        <input
+                            className="issue-tracker-settings__input"
+                            type="url"
+                            placeholder="https://example.atlassian.net"
+                            value={settings.jiraBaseUrl}
+                            onChange={(e) => update({ jiraBaseUrl: e.target.value })}
+                            data-testid="issue-jira-url-input"
+                        />
+                        <input
+                            className="issue-tracker-settings__input issue-tracker-settings__key"
+                            type="text"
+                            placeholder="Project key"
+                            value={settings.jiraProjectKey}
+                            onChange={(e) => update({ jiraProjectKey: e.target.value })}
+                            data-testid="issue-jira-project-input"
+                        />
+                    </>
+                )}
+            </div>
+            {settings.tracker === 'jira' && (
+                <div className="issue-tracker-settings__row">
+                    <input
+                        className="issue-tracker-settings__input"
+                        type="email"
+                        placeholder="Email (Jira Cloud)"
+                        value={settings.jiraEmail}
+                        onChange={(e) => update({ jiraEmail: e.target.value })}
+                        data-testid="issue-jira-email-input"
+                    />
+                    <input
+                        className="issue-tracker-settings__input"
+                        type="password"
+                        placeholder="API token"
+                        value={settings.jiraApiToken}
+                        autoComplete="off"
+                        onChange={(e) => update({ jiraApiToken: e.target.value })}
+                        data-testid="issue-jira-token-input"
+                    />
+                </div>
+            )}
+            <div className="issue-tracker-settings__row issue-tracker-settings__footer">
+                <span className="issue-tracker-settings__hint" data-testid="issue-tracker-status">
+                    {status ?? 'Use File › Create Issue from Answer. Selected text is used when there is any.'}
+                </span>
+                <button
+                    type="button"
+                    className="issue-tracker-settings__button"
+                    disabled={!dirty || !isValid}
+                    onClick={handleSave}
+                    data-testid="issue-tracker-save-button"
+                >
+                    Save
+                </button>
+            </div>
+        </div>
+    );
+});
+
+export default IssueTrackerSettings;
@@ -0,0 +1,79 @@
+/**
+ * LanguageSettings Component
+ *
+ * Select for the language Gemini is shown in, for users whose OS locale
+ * differs from their preferred language. Sets the Accept-Language sent to
+ * Google, and the spell checker language unless other languages are chosen
+ * in SpellCheckSettings. Applied to pages on their next navigation.
+ *
+ * @module LanguageSettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import { GEMINI_LANGUAGES } from '../../../shared/types/language';
+import './LanguageSettings.css';
+
+/**
+ * LanguageSettings component.
+ * Renders the Gemini language select.
+ */
+export const LanguageSettings = memo(function LanguageSettings() {
+    const [language, setLanguage] = useState('');
+    const [loading, setLoading] = useState(true);
+
+    // Load initial state from main process
+    useEffect(() => {
+        const loadState = async () => {
+            try {
+                const loaded = await window.electronAPI?.getGeminiLanguage();
+                setLanguage(loaded ?? '');
+            } catch (error) {
+                console.error('Failed to load Gemini language:', error);
+            } finally {
+                setLoading(false);
+            }
+        };
+
+        loadState();
+    }, []);
+
+    const handleChange = useCallback((tag: string) => {
+        setLanguage(tag);
+        window.electronAPI?.setGeminiLanguage(tag);
+    }, []);
+
+    if (loading) {
+        return (
+            <div className="language-settings loading" data-testid="language-settings-loading">
+                Loading...
+            </div>
+        );
+    }
+
+    return (
+        <div className="language-settings" data-testid="language-settings">
+            <label className="language-settings__label" htmlFor="gemini-language">
+                Gemini language
+            </label>
+            <select
+                id="gemini-language"
+                className="language-settings__input"
+                value={language}
+                onChange={(e) => handleChange(e.target.value)}
+                data-testid="gemini-language-select"
+            >
+                <option value="">System default</option>
+                {GEMINI_LANGUAGES.map(({ tag, label }) => (
+                    <option key={tag} value={tag}>
+                        {label}
+                    </option>
+                ))}
+            </select>
+            <span className="language-settings__hint">
+                Also the spell check language unless you pick others. Takes effect the next time a page loads.
+            </span>
+        </div>
+    );
+});
+
+export default LanguageSettings;
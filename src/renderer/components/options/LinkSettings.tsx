@@ -0,0 +1,74 @@
+/**
+ * LinkSettings Component
+ *
+ * Allow-list of sites that may open inside the Gemini view. Links to any
+ * other site, such as citation links, open in the system browser so the
+ * conversation is never replaced. Gemini and Google sign-in are always
+ * allowed.
+ *
+ * @module LinkSettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import './LinkSettings.css';
+
+/**
+ * LinkSettings component.
+ * Renders the allowed hosts textarea, saved when it loses focus.
+ */
+export const LinkSettings = memo(function LinkSettings() {
+    const [hosts, setHosts] = useState('');
+    const [loading, setLoading] = useState(true);
+
+    // Load initial state from main process
+    useEffect(() => {
+        const loadState = async () => {
+            try {
+                const loaded = await window.electronAPI?.getAllowedHosts();
+                setHosts(loaded ?? '');
+            } catch (error) {
+                console.error('Failed to load allowed hosts:', error);
+            } finally {
+                setLoading(false);
+            }
+        };
+
+        loadState();
+    }, []);
+
+    const handleBlur = useCallback(() => {
+        window.electronAPI?.setAllowedHosts(hosts);
+    }, [hosts]);
+
+    if (loading) {
+        return (
+            <div className="link-settings loading" data-testid="link-settings-loading">
+                Loading...
+            </div>
+        );
+    }
+
+    return (
+        <div className="link-settings" data-testid="link-settings">
+            <label className="link-settings__label" htmlFor="allowed-hosts">
+                Sites that open inside the app
+            </label>
+            <textarea
+                id="allowed-hosts"
+                className="link-settings__input"
+                rows={3}
+                spellCheck={false}
+                value={hosts}
+                placeholder="example.com"
+                onChange={(e) => setHosts(e.target.value)}
+                onBlur={handleBlur}
+                data-testid="allowed-hosts-input"
+            />
+            <span className="link-settings__hint">
+                One host per line; subdomains are included. Other links open in your default browser.
+            </span>
+        </div>
+    );
+});
+
+export default LinkSettings;
@@ -0,0 +1,69 @@
+/**
+ * OpacitySettings Component
+ *
+ * Slider for the main window opacity, with a hint for overlay mode, which
+ * floats Gemini translucently above other apps and lets clicks through.
+ * Disabled, with the reason shown, on platforms without window opacity.
+ *
+ * @module OpacitySettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import { useCapabilities } from '../../hooks/useCapabilities';
+import { MIN_WINDOW_OPACITY } from '../../../shared/types/overlay';
+import './OpacitySettings.css';
+
+/**
+ * OpacitySettings component.
+ * Renders the window opacity slider.
+ */
+export const OpacitySettings = memo(function OpacitySettings() {
+    const [opacity, setOpacity] = useState(1);
+    const windowOpacity = useCapabilities()?.capabilities.windowOpacity;
+    const unsupported = windowOpacity?.available === false;
+
+    useEffect(() => {
+        window.electronAPI
+            ?.getWindowOpacity()
+            .then((result) => setOpacity(result.opacity))
+            .catch((error) => console.error('Failed to load window opacity:', error));
+
+        return window.electronAPI?.onWindowOpacityChanged((data) => setOpacity(data.opacity));
+    }, []);
+
+    const handleChange = useCallback((value: string) => {
+        const next = Number(value) / 100;
+        setOpacity(next);
+        window.electronAPI?.setWindowOpacity(next);
+    }, []);
+
+    return (
+        <div className="opacity-settings" data-testid="opacity-settings">
+            <label className="opacity-settings__row">
+                <span>Window opacity</span>
+                <input
+                    className="opacity-settings__slider"
+                    type="range"
+                    min={MIN_WINDOW_OPACITY * 100}
+                    max={100}
+                    step={5}
+                    value={Math.round(opacity * 100)}
+                    disabled={unsupported}
+                    onChange={(e) => handleChange(e.target.value)}
+                    data-testid="opacity-slider"
+                />
+                <span className="opacity-settings__value" data-testid="opacity-value">
+                    {Math.round(opacity * 100)}%
+                </span>
+            </label>
+            <p className="opacity-settings__hint" data-testid="opacity-hint">
+                {unsupported
+                    ? windowOpacity?.reason
+                    : 'View › Overlay Mode (Ctrl/Cmd+Shift+O) keeps Gemini translucent and on top while clicks ' +
+                      'go to the window beneath. Press the shortcut again or click the tray icon to leave it.'}
+            </p>
+        </div>
+    );
+});
+
+export default OpacitySettings;
@@ -18,6 +18,25 @@ import { AutoUpdateToggle } from './AutoUpdateToggle';
 import { AboutSection } from './AboutSection';
 import { TextPredictionSettings } from './TextPredictionSettings';
 import { NotificationSettings } from './NotificationSettings';
+import { TraySettings } from './TraySettings';
+import { OpacitySettings } from './OpacitySettings';
+import { TitlebarHeightSettings } from './TitlebarHeightSettings';
+import { TitlebarEffectSettings } from './TitlebarEffectSettings';
+import { ExportSettings } from './ExportSettings';
+import { ConfluenceSettings } from './ConfluenceSettings';
+import { ShareSettings } from './ShareSettings';
+import { IssueTrackerSettings } from './IssueTrackerSettings';
+import { AutoCopySettings } from './AutoCopySettings';
+import { ProxySettings } from './ProxySettings';
+import { UsageSettings } from './UsageSettings';
+import { GeminiQuotaSettings } from './GeminiQuotaSettings';
+import { UserAgentSettings } from './UserAgentSettings';
+import { LanguageSettings } from './LanguageSettings';
+import { SpellCheckSettings } from './SpellCheckSettings';
+import { LinkSettings } from './LinkSettings';
+import { UserscriptSettings } from './UserscriptSettings';
+import { SettingsHistory } from './SettingsHistory';
+import { ResetSettings } from './ResetSettings';
 import './options-window.css';
 
 // ============================================================================
@@ -170,6 +189,14 @@ export function OptionsWindow() {
                                 <ThemeSelector />
                             </OptionsSection>
 
+                            {/* Window Settings */}
+                            <OptionsSection title="Window" testId="options-window">
+                                <TraySettings />
+                                <OpacitySettings />
+                                <TitlebarHeightSettings />
+                                <TitlebarEffectSettings />
+                            </OptionsSection>
+
                             {/* Hotkey Settings */}
                             <OptionsSection title="Hotkey Shortcuts" testId="options-hotkeys">
                                 <IndividualHotkeyToggles />
@@ -189,6 +216,49 @@ export function OptionsWindow() {
                             <OptionsSection title="Notifications" testId="options-notifications">
                                 <NotificationSettings />
                             </OptionsSection>
+
+                            {/* Clipboard Settings */}
+                            <OptionsSection title="Clipboard" testId="options-clipboard">
+                                <AutoCopySettings />
+                            </OptionsSection>
+
+                            {/* Export Settings */}
+                            <OptionsSection title="Export" testId="options-export">
+                                <ExportSettings />
+                                <ConfluenceSettings />
+                                <ShareSettings />
+                                <IssueTrackerSettings />
+                            </OptionsSection>
+
+                            {/* Usage Tracking */}
+                            <OptionsSection title="Usage" testId="options-usage">
+                                <UsageSettings />
+                                <GeminiQuotaSettings />
+                            </OptionsSection>
+
+                            {/* Network Settings */}
+                            <OptionsSection title="Network" testId="options-network">
+                                <ProxySettings />
+                                <UserAgentSettings />
+                                <LanguageSettings />
+                                <SpellCheckSettings />
+                                <LinkSettings />
+                            </OptionsSection>
+
+                            {/* Userscripts */}
+                            <OptionsSection title="Userscripts" testId="options-userscripts">
+                                <UserscriptSettings />
+                            </OptionsSection>
+
+                            {/* Settings Change Log */}
+                            <OptionsSection title="History" testId="options-history">
+                                <SettingsHistory />
+                            </OptionsSection>
+
+                            {/* Restore Defaults */}
+                            <OptionsSection title="Reset" testId="options-reset">
+                                <ResetSettings />
+                            </OptionsSection>
                         </>
                     )}
 
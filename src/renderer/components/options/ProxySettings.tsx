@@ -0,0 +1,198 @@
+/**
+ * ProxySettings Component
+ *
+ * Form for routing all traffic through an upstream HTTP or SOCKS5 proxy,
+ * with optional credentials, a bypass list and a connection test.
+ *
+ * @module ProxySettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import {
+    DEFAULT_PROXY_SETTINGS,
+    type ProxyMode,
+    type ProxySettings as ProxySettingsType,
+} from '../../../shared/types/proxy';
+import './ProxySettings.css';
+
+/** Mode options shown in the select */
+const MODE_OPTIONS: { value: ProxyMode; label: string }[] = [
+    { value: 'system', label: 'System proxy' },
+    { value: 'direct', label: 'No proxy' },
+    { value: 'http', label: 'HTTP' },
+    { value: 'socks5', label: 'SOCKS5' },
+];
+
+/**
+ * ProxySettings component.
+ * Renders the upstream proxy form.
+ */
+export const ProxySettings = memo(function ProxySettings() {
+    const [settings, setSettings] = useState<ProxySettingsType>(DEFAULT_PROXY_SETTINGS);
+    const [loading, setLoading] = useState(true);
+    const [dirty, setDirty] = useState(false);
+    const [testing, setTesting] = useState(false);
+    const [status, setStatus] = useState<string | null>(null);
+
+    // Load initial state from main process
+    useEffect(() => {
+        const loadState = async () => {
+            try {
+                const loaded = await window.electronAPI?.getProxySettings();
+                setSettings(loaded ?? DEFAULT_PROXY_SETTINGS);
+            } catch (error) {
+                console.error('Failed to load proxy settings:', error);
+            } finally {
+                setLoading(false);
+            }
+        };
+
+        loadState();
+    }, []);
+
+    const update = useCallback((partial: Partial<ProxySettingsType>) => {
+        setSettings((prev) => ({ ...prev, ...partial }));
+        setDirty(true);
+        setStatus(null);
+    }, []);
+
+    const usesServer = settings.mode === 'http' || settings.mode === 'socks5';
+    const isValid = !usesServer || (settings.host.trim() !== '' && settings.port >= 1 && settings.port <= 65535);
+
+    const handleSave = useCallback(() => {
+        window.electronAPI?.setProxySettings(settings);
+        setDirty(false);
+        setStatus('Proxy settings saved.');
+    }, [settings]);
+
+    const handleTest = useCallback(async () => {
+        setTesting(true);
+        setStatus('Testing connection...');
+        try {
+            const result = await window.electronAPI?.testProxyConnection();
+            if (!result) return;
+            setStatus(
+                result.success
+                    ? `Connected (HTTP ${result.statusCode}, ${result.latencyMs} ms)`
+                    : `Connection failed: ${result.error}`
+            );
+        } catch (error) {
+            console.error('Failed to test proxy connection:', error);
+            setStatus('Connection test failed.');
+        } finally {
+            setTesting(false);
+        }
+    }, []);
+
+    if (loading) {
+        return (
+            <div className="proxy-settings loading" data-testid="proxy-settings-loading">
+                Loading...
+            </div>
+        );
+    }
+
+    return (
+        <div className="proxy-settings" data-testid="proxy-settings">
+            <label className="proxy-settings__label" htmlFor="proxy-mode">
+                Proxy
+            </label>
+            <select
+                id="proxy-mode"
+                className="proxy-settings__input"
+                value={settings.mode}
+                onChange={(e) => update({ mode: e.target.value as ProxyMode })}
+                data-testid="proxy-mode-select"
+            >
+                {MODE_OPTIONS.map(({ value, label }) => (
+                    <option key={value} value={value}>
+                        {label}
+                    </option>
+                ))}
+            </select>
+
+            {usesServer && (
+                <>
+                    <div className="proxy-settings__row">
+                        <input
+                            className="proxy-settings__input proxy-settings__host"
+                            type="text"
+                            placeholder="Host"
+                            value={settings.host}
+                            spellCheck={false}
+                            onChange={(e) => update({ host: e.target.value })}
+                            data-testid="proxy-host-input"
+                        />
+                        <input
+                            className="proxy-settings__input proxy-settings__port"
+                            type="number"
+                            min={1}
+                            max={65535}
+                            placeholder="Port"
+                            value={settings.port}
+                            onChange={(e) => update({ port: Number(e.target.value) })}
+                            data-testid="proxy-port-input"
+                        />
+                    </div>
+                    <div className="proxy-settings__row">
+                        <input
+                            className="proxy-settings__input"
+                            type="text"
+                            placeholder="Username (optional)"
+                            value={settings.username}
+                            autoComplete="off"
+                            onChange={(e) => update({ username: e.target.value })}
+                            data-testid="proxy-username-input"
+                        />
+                        <input
+                            className="proxy-settings__input"
+                            type="password"
+                            placeholder="Password (optional)"
+                            value={settings.password}
+                            autoComplete="off"
+                            onChange={(e) => update({ password: e.target.value })}
+                            data-testid="proxy-password-input"
+                        />
+                    </div>
+                    <input
+                        className="proxy-settings__input"
+                        type="text"
+                        placeholder="Bypass list, e.g. localhost,*.corp.example"
+                        value={settings.bypass}
+                        spellCheck={false}
+                        onChange={(e) => update({ bypass: e.target.value })}
+                        data-testid="proxy-bypass-input"
+                    />
+                </>
+            )}
+
+            <div className="proxy-settings__row">
+                <button
+                    type="button"
+                    className="proxy-settings__button"
+                    disabled={!dirty || !isValid}
+                    onClick={handleSave}
+                    data-testid="proxy-save-button"
+                >
+                    Save
+                </button>
+                <button
+                    type="button"
+                    className="proxy-settings__button"
+                    disabled={dirty || testing}
+                    onClick={handleTest}
+                    data-testid="proxy-test-button"
+                >
+                    Test Connection
+                </button>
+            </div>
+            {status && (
+                <span className="proxy-settings__hint" data-testid="proxy-status">
+                    {status}
+                </span>
+            )}
+        </div>
+    );
+});
+
+export default ProxySettings;
@@ -0,0 +1,85 @@
+/**
+ * ResetSettings Component
+ *
+ * Buttons to restore default settings for a section (hotkeys, appearance or
+ * everything). The main process writes a backup of the current settings
+ * before resetting, and its path is shown afterwards.
+ *
+ * @module ResetSettings
+ */
+
+import { memo, useState, useCallback } from 'react';
+import type { SettingsResetSection } from '../../../shared/types/settings-reset';
+import './ResetSettings.css';
+
+/** Buttons shown, in order */
+const RESET_OPTIONS: { section: SettingsResetSection; label: string; confirm: string }[] = [
+    { section: 'hotkeys', label: 'Reset Hotkeys', confirm: 'Restore the default hotkey settings?' },
+    {
+        section: 'appearance',
+        label: 'Reset Appearance',
+        confirm: 'Restore the default theme, zoom and window settings?',
+    },
+    { section: 'all', label: 'Reset All Settings', confirm: 'Restore all settings to their defaults?' },
+];
+
+/**
+ * ResetSettings component.
+ * Renders the per-section restore defaults buttons.
+ */
+export const ResetSettings = memo(function ResetSettings() {
+    const [busy, setBusy] = useState(false);
+    const [status, setStatus] = useState<string | null>(null);
+
+    const handleReset = useCallback(async (section: SettingsResetSection, confirmText: string) => {
+        if (!window.confirm(`${confirmText} A backup of your current settings will be saved first.`)) return;
+
+        setBusy(true);
+        try {
+            const result = await window.electronAPI?.resetSettings(section);
+            if (!result) return;
+
+            if (!result.success) {
+                setStatus(`Reset failed: ${result.error ?? 'unknown error'}`);
+            } else if (section === 'all') {
+                // Every section changed; reload so all controls pick up the defaults
+                window.location.reload();
+            } else {
+                setStatus(
+                    result.backupPath ? `Defaults restored. Backup saved to ${result.backupPath}` : 'Defaults restored.'
+                );
+            }
+        } catch (error) {
+            console.error('Failed to reset settings:', error);
+            setStatus('Reset failed.');
+        } finally {
+            setBusy(false);
+        }
+    }, []);
+
+    return (
+        <div className="reset-settings" data-testid="reset-settings">
+            <div className="reset-settings__buttons">
+                {RESET_OPTIONS.map(({ section, label, confirm }) => (
+                    <button
+                        key={section}
+                        type="button"
+                        className="reset-settings__button"
+                        disabled={busy}
+                        onClick={() => handleReset(section, confirm)}
+                        data-testid={`reset-settings-${section}`}
+                    >
+                        {label}
+                    </button>
+                ))}
+            </div>
+            {status && (
+                <span className="reset-settings__status" data-testid="reset-settings-status">
+                    {status}
+                </span>
+            )}
+        </div>
+    );
+});
+
+export default ResetSettings;
@@ -0,0 +1,128 @@
+/**
+ * SettingsHistory Component
+ *
+ * Lists recent settings changes with their old and new values, newest first,
+ * and undoes them. Undoing a change also undoes every change made after it.
+ * Settings saved together (such as the proxy settings) are one entry.
+ *
+ * @module SettingsHistory
+ */
+
+import { Fragment, memo, useState, useCallback, useEffect } from 'react';
+import type { SettingsChange } from '../../../shared/types/settings-history';
+import './SettingsHistory.css';
+
+/**
+ * Format a logged value for display.
+ */
+function formatValue(change: SettingsChange, field: 'previous' | 'value'): string {
+    if (!(field in change)) return '(unset)';
+    return JSON.stringify(change[field]);
+}
+
+/**
+ * SettingsHistory component.
+ * Renders the change log with undo buttons.
+ */
+export const SettingsHistory = memo(function SettingsHistory() {
+    const [changes, setChanges] = useState<SettingsChange[]>([]);
+    const [busy, setBusy] = useState(false);
+    const [status, setStatus] = useState<string | null>(null);
+
+    const loadHistory = useCallback(async () => {
+        try {
+            const history = await window.electronAPI?.getSettingsHistory();
+            if (history) setChanges(history);
+        } catch (error) {
+            console.error('Failed to load settings history:', error);
+        }
+    }, []);
+
+    useEffect(() => {
+        loadHistory();
+    }, [loadHistory]);
+
+    const handleUndo = useCallback(
+        async (count: number) => {
+            const what = count === 1 ? 'the last settings change' : `the last ${count} settings changes`;
+            if (!window.confirm(`Undo ${what}?`)) return;
+
+            setBusy(true);
+            try {
+                const result = await window.electronAPI?.undoSettingsChange(count);
+                if (!result) return;
+
+                if (!result.success) {
+                    setStatus(`Undo failed: ${result.error ?? 'unknown error'}`);
+                } else {
+                    const keys = result.undone
+                        .flatMap((change) => [change, ...(change.batch ?? [])])
+                        .map((change) => change.key)
+                        .join(', ');
+                    setStatus(
+                        result.restartRecommended
+                            ? `Restored ${keys}. Restart Gemini Desktop for every setting to take effect.`
+                            : `Restored ${keys}.`
+                    );
+                }
+                await loadHistory();
+            } catch (error) {
+                console.error('Failed to undo settings change:', error);
+                setStatus('Undo failed.');
+            } finally {
+                setBusy(false);
+            }
+        },
+        [loadHistory]
+    );
+
+    return (
+        <div className="settings-history" data-testid="settings-history">
+            {changes.length === 0 ? (
+                <span className="settings-history__empty">No settings changes recorded yet.</span>
+            ) : (
+                <ul className="settings-history__list">
+                    {changes.map((change, index) => (
+                        <li
+                            key={`${change.timestamp}-${change.key}-${index}`}
+                            className="settings-history__item"
+                            data-testid="settings-history-item"
+                        >
+                            <div className="settings-history__change">
+                                {[change, ...(change.batch ?? [])].map((part) => (
+                                    <Fragment key={part.key}>
+                                        <span className="settings-history__key">{part.key}</span>
+                                        <span className="settings-history__diff">
+                                            <del>{formatValue(part, 'previous')}</del> →{' '}
+                                            <ins>{formatValue(part, 'value')}</ins>
+                                        </span>
+                                    </Fragment>
+                                ))}
+                                <span className="settings-history__time">
+                                    {new Date(change.timestamp).toLocaleString()}
+                                </span>
+                            </div>
+                            <button
+                                type="button"
+                                className="settings-history__undo"
+                                disabled={busy}
+                                onClick={() => handleUndo(index + 1)}
+                                title={index === 0 ? 'Undo this change' : 'Undo this change and every later one'}
+                                data-testid={`settings-history-undo-${index}`}
+                            >
+                                Undo
+                            </button>
+                        </li>
+                    ))}
+                </ul>
+            )}
+            {status && (
+                <span className="settings-history__status" data-testid="settings-history-status">
+                    {status}
+                </span>
+            )}
+        </div>
+    );
+});
+
+export default SettingsHistory;
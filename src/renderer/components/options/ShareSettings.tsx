@@ -0,0 +1,117 @@
+/**
+ * ShareSettings Component
+ *
+ * Form for posting answers to a chat channel: the service (Slack or Teams)
+ * and the channel's incoming webhook URL.
+ *
+ * @module ShareSettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import {
+    DEFAULT_SHARE_SETTINGS,
+    type ShareService,
+    type ShareSettings as ShareSettingsType,
+} from '../../../shared/types/share';
+import './ShareSettings.css';
+
+/** Service options shown in the select */
+const SERVICE_OPTIONS: { value: ShareService; label: string }[] = [
+    { value: 'slack', label: 'Slack' },
+    { value: 'teams', label: 'Microsoft Teams' },
+];
+
+/**
+ * ShareSettings component.
+ * Renders the answer sharing form.
+ */
+export const ShareSettings = memo(function ShareSettings() {
+    const [settings, setSettings] = useState<ShareSettingsType>(DEFAULT_SHARE_SETTINGS);
+    const [loading, setLoading] = useState(true);
+    const [dirty, setDirty] = useState(false);
+    const [status, setStatus] = useState<string | null>(null);
+
+    // Load initial state from main process
+    useEffect(() => {
+        const loadState = async () => {
+            try {
+                const loaded = await window.electronAPI?.getShareSettings();
+                setSettings(loaded ?? DEFAULT_SHARE_SETTINGS);
+            } catch (error) {
+                console.error('Failed to load share settings:', error);
+            } finally {
+                setLoading(false);
+            }
+        };
+
+        loadState();
+    }, []);
+
+    const update = useCallback((partial: Partial<ShareSettingsType>) => {
+        setSettings((prev) => ({ ...prev, ...partial }));
+        setDirty(true);
+        setStatus(null);
+    }, []);
+
+    const isValid = settings.webhookUrl.trim() === '' || settings.webhookUrl.trim().startsWith('https://');
+
+    const handleSave = useCallback(() => {
+        window.electronAPI?.setShareSettings(settings);
+        setDirty(false);
+        setStatus('Share settings saved.');
+    }, [settings]);
+
+    if (loading) {
+        return (
+            <div className="share-settings loading" data-testid="share-settings-loading">
+                Loading...
+            </div>
+        );
+    }
+
+    return (
+        <div className="share-settings" data-testid="share-settings">
+            <label className="share-settings__label" htmlFor="share-service">
+                Share answers
+            </label>
+            <div className="share-settings__row">
+                <select
+                    id="share-service"
+                    className="share-settings__input share-settings__service"
+                    value={settings.service}
+                    onChange={(e) => update({ service: e.target.value as ShareService })}
+                    data-testid="share-service-select"
+                >
+                    {SERVICE_OPTIONS.map(({ value, label }) => (
+                        <option key={value} value={value}>
+                            {label}
+                        </option>
+                    ))}
+                </select>
+                <input
+                    className="share-settings__input"
+                    type="password"
+                    placeholder="Incoming webhook URL"
+                    value={settings.webhookUrl}
+                    autoComplete="off"
+                    onChange={(e) => update({ webhookUrl: e.target.value })}
+                    data-testid="share-webhook-input"
+                />
+                <button
+                    type="button"
+                    className="share-settings__button"
+                    disabled={!dirty || !isValid}
+                    onClick={handleSave}
+                    data-testid="share-save-button"
+                >
+                    Save
+                </button>
+            </div>
+            <span className="share-settings__hint" data-testid="share-status">
+                {status ?? 'Use File › Share Last Answer. You can review the redacted answer before it is posted.'}
+            </span>
+        </div>
+    );
+});
+
+export default ShareSettings;
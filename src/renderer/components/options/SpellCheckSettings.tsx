@@ -0,0 +1,115 @@
+/**
+ * SpellCheckSettings Component
+ *
+ * Toggle for the spell checker in the prompt box and a checkbox per
+ * dictionary, so users who write prompts in more than one language get
+ * suggestions for each. With no languages ticked the spell checker follows
+ * the Gemini language. Misspelled words are underlined as you type and their
+ * suggestions are offered in the right-click menu.
+ *
+ * @module SpellCheckSettings
+ */
+
+import { memo, useState, useEffect, useCallback, useMemo } from 'react';
+import { CapsuleToggle } from '../common/CapsuleToggle';
+import { MAX_SPELL_CHECK_LANGUAGES, type SpellCheckSettings as Settings } from '../../../shared/types/language';
+import './SpellCheckSettings.css';
+
+/**
+ * SpellCheckSettings component.
+ * Renders the spell checker toggle and language checkboxes.
+ */
+export const SpellCheckSettings = memo(function SpellCheckSettings() {
+    const [settings, setSettings] = useState<Settings>({ enabled: true, languages: [], available: [] });
+    const [loading, setLoading] = useState(true);
+
+    // Load initial state from main process
+    useEffect(() => {
+        const loadState = async () => {
+            try {
+                const loaded = await window.electronAPI?.getSpellCheckSettings();
+                if (loaded) {
+                    setSettings(loaded);
+                }
+            } catch (error) {
+                console.error('Failed to load spell check settings:', error);
+            } finally {
+                setLoading(false);
+            }
+        };
+
+        loadState();
+    }, []);
+
+    const languageNames = useMemo(() => new Intl.DisplayNames(undefined, { type: 'language' }), []);
+
+    const handleEnabledChange = useCallback((enabled: boolean) => {
+        setSettings((current) => ({ ...current, enabled }));
+        window.electronAPI?.setSpellCheckEnabled(enabled);
+    }, []);
+
+    const handleLanguageChange = useCallback(
+        (tag: string, checked: boolean) => {
+            const languages = checked
+                ? [...settings.languages, tag]
+                : settings.languages.filter((language) => language !== tag);
+            setSettings((current) => ({ ...current, languages }));
+            window.electronAPI?.setSpellCheckLanguages(languages);
+        },
+        [settings.languages]
+    );
+
+    if (loading) {
+        return (
+            <div className="spell-check-settings loading" data-testid="spell-check-settings-loading">
+                Loading...
+            </div>
+        );
+    }
+
+    const full = settings.languages.length >= MAX_SPELL_CHECK_LANGUAGES;
+
+    return (
+        <div className="spell-check-settings" data-testid="spell-check-settings">
+            <CapsuleToggle
+                checked={settings.enabled}
+                onChange={handleEnabledChange}
+                label="Spell Check"
+                description="Underline misspelled words in prompts and offer suggestions when you right-click them"
+                testId="spell-check-toggle"
+            />
+            {settings.enabled && settings.available.length > 0 && (
+                <>
+                    <div className="spell-check-settings__languages" data-testid="spell-check-languages">
+                        {settings.available.map((tag) => {
+                            const checked = settings.languages.includes(tag);
+                            return (
+                                <label key={tag} className="spell-check-settings__language">
+                                    <input
+                                        type="checkbox"
+                                        checked={checked}
+                                        disabled={!checked && full}
+                                        onChange={(e) => handleLanguageChange(tag, e.target.checked)}
+                                        data-testid={`spell-check-language-${tag}`}
+                                    />
+                                    {languageNames.of(tag) ?? tag}
+                                </label>
+                            );
+                        })}
+                    </div>
+                    <span className="spell-check-settings__hint">
+                        Check up to {MAX_SPELL_CHECK_LANGUAGES} languages. With none ticked, the Gemini language is
+                        checked.
+                    </span>
+                </>
+            )}
+            {settings.enabled && settings.available.length === 0 && (
+                <span className="spell-check-settings__hint">
+                    Languages are detected by the system spell checker on this platform.
+                </span>
+            )}
+        </div>
+    );
+});
+
+export default SpellCheckSettings;
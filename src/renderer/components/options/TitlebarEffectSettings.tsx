@@ -0,0 +1,64 @@
+/**
+ * TitlebarEffectSettings Component
+ *
+ * Select for the native material shown behind the titlebar: mica or acrylic
+ * on Windows 11, vibrancy on macOS. Only the effects this platform supports
+ * are offered; elsewhere the select is disabled and the titlebar stays opaque.
+ *
+ * @module TitlebarEffectSettings
+ */
+
+import { memo, useCallback } from 'react';
+import type { TitlebarEffect } from '../../../shared/types/titlebar';
+import { useTitlebarEffect } from '../../hooks/useTitlebarEffect';
+import './OpacitySettings.css';
+
+/** Labels for each titlebar effect */
+const EFFECT_LABELS: Record<TitlebarEffect, string> = {
+    none: 'None',
+    mica: 'Mica',
+    acrylic: 'Acrylic',
+    vibrancy: 'Vibrancy',
+};
+
+/**
+ * TitlebarEffectSettings component.
+ * Renders the titlebar effect select.
+ */
+export const TitlebarEffectSettings = memo(function TitlebarEffectSettings() {
+    const { effect, supported } = useTitlebarEffect();
+    const unsupported = supported.length < 2;
+
+    const handleChange = useCallback((value: string) => {
+        window.electronAPI?.setTitlebarEffect(value as TitlebarEffect).catch((error) => {
+            console.error('Failed to set titlebar effect:', error);
+        });
+    }, []);
+
+    return (
+        <div className="opacity-settings" data-testid="titlebar-effect-settings">
+            <label className="opacity-settings__row">
+                <span>Titlebar effect</span>
+                <select
+                    value={effect}
+                    disabled={unsupported}
+                    onChange={(e) => handleChange(e.target.value)}
+                    data-testid="titlebar-effect-select"
+                >
+                    {supported.map((option) => (
+                        <option key={option} value={option}>
+                            {EFFECT_LABELS[option]}
+                        </option>
+                    ))}
+                </select>
+            </label>
+            {unsupported && (
+                <p className="opacity-settings__hint" data-testid="titlebar-effect-hint">
+                    Titlebar effects need Windows 11 (22H2 or later) or macOS.
+                </p>
+            )}
+        </div>
+    );
+});
+
+export default TitlebarEffectSettings;
@@ -0,0 +1,57 @@
+/**
+ * TitlebarHeightSettings Component
+ *
+ * Slider for the main window titlebar height, for larger UI scales or
+ * roomier titlebars. Open main windows resize their titlebar as it moves.
+ *
+ * @module TitlebarHeightSettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import { MAX_TITLEBAR_HEIGHT, MIN_TITLEBAR_HEIGHT } from '../../../shared/types/titlebar';
+import { useTitlebarHeight } from '../../hooks/useTitlebarHeight';
+import './OpacitySettings.css';
+
+/**
+ * TitlebarHeightSettings component.
+ * Renders the titlebar height slider.
+ */
+export const TitlebarHeightSettings = memo(function TitlebarHeightSettings() {
+    const saved = useTitlebarHeight();
+    const [height, setHeight] = useState(saved);
+
+    useEffect(() => {
+        setHeight(saved);
+    }, [saved]);
+
+    const handleChange = useCallback((value: string) => {
+        const next = Number(value);
+        setHeight(next);
+        window.electronAPI?.setTitlebarHeight(next).catch((error) => {
+            console.error('Failed to set titlebar height:', error);
+        });
+    }, []);
+
+    return (
+        <div className="opacity-settings" data-testid="titlebar-height-settings">
+            <label className="opacity-settings__row">
+                <span>Titlebar height</span>
+                <input
+                    className="opacity-settings__slider"
+                    type="range"
+                    min={MIN_TITLEBAR_HEIGHT}
+                    max={MAX_TITLEBAR_HEIGHT}
+                    step={1}
+                    value={height}
+                    onChange={(e) => handleChange(e.target.value)}
+                    data-testid="titlebar-height-slider"
+                />
+                <span className="opacity-settings__value" data-testid="titlebar-height-value">
+                    {height}px
+                </span>
+            </label>
+        </div>
+    );
+});
+
+export default TitlebarHeightSettings;
@@ -0,0 +1,65 @@
+/**
+ * TraySettings Component
+ *
+ * Toggle switch for close to tray. When on, closing the main window hides it
+ * to the system tray so conversations stay open; File › Exit and the tray's
+ * Quit item still exit the app.
+ *
+ * @module TraySettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import { CapsuleToggle } from '../common/CapsuleToggle';
+import './TraySettings.css';
+
+/**
+ * TraySettings component.
+ * Renders a toggle switch for the close to tray preference.
+ */
+export const TraySettings = memo(function TraySettings() {
+    const [enabled, setEnabled] = useState(true);
+    const [loading, setLoading] = useState(true);
+
+    // Load initial state from main process
+    useEffect(() => {
+        const loadState = async () => {
+            try {
+                const isEnabled = await window.electronAPI?.getCloseToTray();
+                setEnabled(isEnabled ?? true);
+            } catch (error) {
+                console.error('Failed to load close to tray state:', error);
+            } finally {
+                setLoading(false);
+            }
+        };
+
+        loadState();
+    }, []);
+
+    const handleChange = useCallback((newEnabled: boolean) => {
+        setEnabled(newEnabled);
+        window.electronAPI?.setCloseToTray(newEnabled);
+    }, []);
+
+    if (loading) {
+        return (
+            <div className="tray-settings loading" data-testid="tray-settings-loading">
+                Loading...
+            </div>
+        );
+    }
+
+    return (
+        <div className="tray-settings" data-testid="tray-settings">
+            <CapsuleToggle
+                checked={enabled}
+                onChange={handleChange}
+                label="Close to Tray"
+                description="Keep running in the system tray when the main window is closed; use File › Exit to quit"
+                testId="close-to-tray-toggle"
+            />
+        </div>
+    );
+});
+
+export default TraySettings;
@@ -0,0 +1,150 @@
+/**
+ * UsageSettings Component
+ *
+ * Toggles for tracking time spent with the app in the foreground, a summary
+ * of active time today and this week, and optional daily limits. Data stays
+ * on this machine. Limits set by a policy file are shown read-only.
+ *
+ * @module UsageSettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import { CapsuleToggle } from '../common/CapsuleToggle';
+import {
+    NO_USAGE_LIMITS,
+    type UsageLimits,
+    type UsageReport,
+    type UsageSettings as UsageSettingsType,
+} from '../../../shared/types/usage';
+import './UsageSettings.css';
+
+/** Defaults (match main process defaults) */
+const DEFAULT_SETTINGS: UsageSettingsType = { enabled: false, idleDetection: true };
+
+/**
+ * Format seconds as e.g. "2h 05m" or "12m".
+ */
+function formatDuration(seconds: number): string {
+    const minutes = Math.floor(seconds / 60);
+    const hours = Math.floor(minutes / 60);
+    return hours > 0 ? `${hours}h ${String(minutes % 60).padStart(2, '0')}m` : `${minutes}m`;
+}
+
+/**
+ * UsageSettings component.
+ * Renders usage tracking toggles and the current totals.
+ */
+export const UsageSettings = memo(function UsageSettings() {
+    const [settings, setSettings] = useState<UsageSettingsType>(DEFAULT_SETTINGS);
+    const [report, setReport] = useState<UsageReport | null>(null);
+    const [limits, setLimits] = useState<UsageLimits>(NO_USAGE_LIMITS);
+    const [enforcedByPolicy, setEnforcedByPolicy] = useState(false);
+    const [loading, setLoading] = useState(true);
+
+    // Load initial state from main process
+    useEffect(() => {
+        const loadState = async () => {
+            try {
+                const [loaded, loadedReport, limitStatus] = await Promise.all([
+                    window.electronAPI?.getUsageSettings(),
+                    window.electronAPI?.getUsageReport(),
+                    window.electronAPI?.getUsageLimitStatus(),
+                ]);
+                setSettings(loaded ?? DEFAULT_SETTINGS);
+                setReport(loadedReport ?? null);
+                setLimits(limitStatus?.limits ?? NO_USAGE_LIMITS);
+                setEnforcedByPolicy(limitStatus?.enforcedByPolicy ?? false);
+            } catch (error) {
+                console.error('Failed to load usage settings:', error);
+            } finally {
+                setLoading(false);
+            }
+        };
+
+        loadState();
+    }, []);
+
+    // Apply a partial update locally and persist it
+    const update = useCallback((partial: Partial<UsageSettingsType>) => {
+        setSettings((prev) => ({ ...prev, ...partial }));
+        window.electronAPI?.setUsageSettings(partial);
+    }, []);
+
+    // Persist a limit change; empty or invalid input means no limit
+    const updateLimit = useCallback(
+        (key: keyof UsageLimits, raw: string) => {
+            const next = { ...limits, [key]: Math.max(0, Math.floor(Number(raw) || 0)) };
+            setLimits(next);
+            window.electronAPI?.setUsageLimits(next);
+        },
+        [limits]
+    );
+
+    if (loading) {
+        return (
+            <div className="usage-settings loading" data-testid="usage-settings-loading">
+                Loading...
+            </div>
+        );
+    }
+
+    return (
+        <div className="usage-settings" data-testid="usage-settings">
+            <CapsuleToggle
+                checked={settings.enabled}
+                onChange={(checked) => update({ enabled: checked })}
+                label="Track Active Time"
+                description="Record how long Gemini Desktop is in the foreground each day (stored locally)"
+                testId="usage-tracking-toggle"
+            />
+            <CapsuleToggle
+                checked={settings.idleDetection}
+                onChange={(checked) => update({ idleDetection: checked })}
+                label="Pause When Idle"
+                description="Stop counting after 5 minutes without keyboard or mouse activity"
+                disabled={!settings.enabled}
+                testId="usage-idle-toggle"
+            />
+            {report && (report.weekSeconds > 0 || settings.enabled) && (
+                <p className="usage-settings__summary" data-testid="usage-summary">
+                    Today: {formatDuration(report.todaySeconds)} · This week: {formatDuration(report.weekSeconds)}
+                </p>
+            )}
+            <div className="usage-settings__limits">
+                <label className="usage-settings__limit">
+                    <span>Daily time limit (minutes)</span>
+                    <input
+                        className="usage-settings__input"
+                        type="number"
+                        min={0}
+                        placeholder="None"
+                        value={limits.dailyMinutes || ''}
+                        disabled={enforcedByPolicy}
+                        onChange={(e) => updateLimit('dailyMinutes', e.target.value)}
+                        data-testid="usage-minutes-limit-input"
+                    />
+                </label>
+                <label className="usage-settings__limit">
+                    <span>Daily prompt limit</span>
+                    <input
+                        className="usage-settings__input"
+                        type="number"
+                        min={0}
+                        placeholder="None"
+                        value={limits.dailyPrompts || ''}
+                        disabled={enforcedByPolicy}
+                        onChange={(e) => updateLimit('dailyPrompts', e.target.value)}
+                        data-testid="usage-prompts-limit-input"
+                    />
+                </label>
+                <p className="usage-settings__summary" data-testid="usage-limits-hint">
+                    {enforcedByPolicy
+                        ? 'Limits are set by your administrator.'
+                        : 'Gemini is blocked until tomorrow once a limit is reached.'}
+                </p>
+            </div>
+        </div>
+    );
+});
+
+export default UsageSettings;
@@ -0,0 +1,108 @@
+/**
+ * UserAgentSettings Component
+ *
+ * Select for the User-Agent sent to Google, with Chrome presets for each OS
+ * and a custom string. Applied to pages on their next navigation.
+ *
+ * @module UserAgentSettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import type { UserAgentPreset, UserAgentSettings as UserAgentSettingsType } from '../../../shared/types/user-agent';
+import './UserAgentSettings.css';
+
+/** Defaults (match main process defaults) */
+const DEFAULT_SETTINGS: UserAgentSettingsType = { preset: 'default', custom: '' };
+
+/** Preset options shown in the select */
+const PRESET_OPTIONS: { value: UserAgentPreset; label: string }[] = [
+    { value: 'default', label: 'Default (Electron)' },
+    { value: 'chrome-windows', label: 'Chrome on Windows' },
+    { value: 'chrome-macos', label: 'Chrome on macOS' },
+    { value: 'chrome-linux', label: 'Chrome on Linux' },
+    { value: 'custom', label: 'Custom' },
+];
+
+/**
+ * UserAgentSettings component.
+ * Renders the User-Agent preset select and custom input.
+ */
+export const UserAgentSettings = memo(function UserAgentSettings() {
+    const [settings, setSettings] = useState<UserAgentSettingsType>(DEFAULT_SETTINGS);
+    const [loading, setLoading] = useState(true);
+
+    // Load initial state from main process
+    useEffect(() => {
+        const loadState = async () => {
+            try {
+                const loaded = await window.electronAPI?.getUserAgentSettings();
+                setSettings(loaded ?? DEFAULT_SETTINGS);
+            } catch (error) {
+                console.error('Failed to load User-Agent settings:', error);
+            } finally {
+                setLoading(false);
+            }
+        };
+
+        loadState();
+    }, []);
+
+    const save = useCallback((next: UserAgentSettingsType) => {
+        // A custom preset is only saved once a string has been entered
+        if (next.preset === 'custom' && !next.custom.trim()) return;
+        window.electronAPI?.setUserAgentSettings(next);
+    }, []);
+
+    const handlePresetChange = useCallback(
+        (preset: UserAgentPreset) => {
+            const next = { ...settings, preset };
+            setSettings(next);
+            save(next);
+        },
+        [settings, save]
+    );
+
+    if (loading) {
+        return (
+            <div className="user-agent-settings loading" data-testid="user-agent-settings-loading">
+                Loading...
+            </div>
+        );
+    }
+
+    return (
+        <div className="user-agent-settings" data-testid="user-agent-settings">
+            <label className="user-agent-settings__label" htmlFor="user-agent-preset">
+                User-Agent
+            </label>
+            <select
+                id="user-agent-preset"
+                className="user-agent-settings__input"
+                value={settings.preset}
+                onChange={(e) => handlePresetChange(e.target.value as UserAgentPreset)}
+                data-testid="user-agent-preset-select"
+            >
+                {PRESET_OPTIONS.map(({ value, label }) => (
+                    <option key={value} value={value}>
+                        {label}
+                    </option>
+                ))}
+            </select>
+            {settings.preset === 'custom' && (
+                <input
+                    className="user-agent-settings__input"
+                    type="text"
+                    placeholder="Mozilla/5.0 ..."
+                    value={settings.custom}
+                    spellCheck={false}
+                    onChange={(e) => setSettings((prev) => ({ ...prev, custom: e.target.value }))}
+                    onBlur={() => save(settings)}
+                    data-testid="user-agent-custom-input"
+                />
+            )}
+            <span className="user-agent-settings__hint">Takes effect the next time a page loads.</span>
+        </div>
+    );
+});
+
+export default UserAgentSettings;
@@ -0,0 +1,95 @@
+/**
+ * UserscriptSettings Component
+ *
+ * Lists the userscripts in the scripts folder with a toggle for each.
+ * Scripts are off until enabled here, and changes apply the next time
+ * Gemini loads.
+ *
+ * @module UserscriptSettings
+ */
+
+import { memo, useState, useEffect, useCallback } from 'react';
+import { CapsuleToggle } from '../common/CapsuleToggle';
+import type { UserscriptInfo } from '../../../shared/types/userscripts';
+import './UserscriptSettings.css';
+
+/**
+ * UserscriptSettings component.
+ * Renders a toggle per userscript and a button to open the scripts folder.
+ */
+export const UserscriptSettings = memo(function UserscriptSettings() {
+    const [scripts, setScripts] = useState<UserscriptInfo[]>([]);
+    const [loading, setLoading] = useState(true);
+
+    const loadScripts = useCallback(async () => {
+        try {
+            setScripts((await window.electronAPI?.listUserscripts()) ?? []);
+        } catch (error) {
+            console.error('Failed to load userscripts:', error);
+        } finally {
+            setLoading(false);
+        }
+    }, []);
+
+    // Load initial state from main process
+    useEffect(() => {
+        loadScripts();
+    }, [loadScripts]);
+
+    const toggle = useCallback((id: string, enabled: boolean) => {
+        setScripts((prev) => prev.map((script) => (script.id === id ? { ...script, enabled } : script)));
+        window.electronAPI?.setUserscriptEnabled(id, enabled);
+    }, []);
+
+    if (loading) {
+        return (
+            <div className="userscript-settings loading" data-testid="userscript-settings-loading">
+                Loading...
+            </div>
+        );
+    }
+
+    return (
+        <div className="userscript-settings" data-testid="userscript-settings">
+            {scripts.length === 0 ? (
+                <p className="userscript-settings__hint" data-testid="userscript-settings-empty">
+                    No userscripts yet. Put .js files in the scripts folder to run them in Gemini.
+                </p>
+            ) : (
+                scripts.map((script) => (
+                    <CapsuleToggle
+                        key={script.id}
+                        checked={script.enabled}
+                        onChange={(checked) => toggle(script.id, checked)}
+                        label={script.name}
+                        description={script.description || script.matches.join(', ')}
+                        testId={`userscript-toggle-${script.id}`}
+                    />
+                ))
+            )}
+            <p className="userscript-settings__hint">
+                Changes apply the next time Gemini loads. Userscripts do not run in safe mode.
+            </p>
+            <div className="userscript-settings__buttons">
+                <button
+                    type="button"
+                    className="userscript-settings__button"
+                    onClick={() => window.electronAPI?.openUserscriptsFolder()}
+                    data-testid="userscript-settings-open-folder"
+                >
+                    Open Scripts Folder
+                </button>
+                <button
+                    type="button"
+                    className="userscript-settings__button"
+                    onClick={loadScripts}
+                    data-testid="userscript-settings-refresh"
+                >
+                    Refresh
+                </button>
+            </div>
+        </div>
+    );
+});
+
+export default UserscriptSettings;
@@ -9,3 +9,16 @@ export { ThemeSelector } from './ThemeSelector';
 export { AboutSection } from './AboutSection';
 export { TextPredictionSettings } from './TextPredictionSettings';
 export { NotificationSettings } from './NotificationSettings';
+export { ExportSettings } from './ExportSettings';
+export { ConfluenceSettings } from './ConfluenceSettings';
+export { ShareSettings } from './ShareSettings';
+export { IssueTrackerSettings } from './IssueTrackerSettings';
+export { AutoCopySettings } from './AutoCopySettings';
+export { ProxySettings } from './ProxySettings';
+export { UsageSettings } from './UsageSettings';
+export { UserAgentSettings } from './UserAgentSettings';
+export { UserscriptSettings } from './UserscriptSettings';
+export { SettingsHistory } from './SettingsHistory';
+export { ResetSettings } from './ResetSettings';
+export { TraySettings } from './TraySettings';
+export { OpacitySettings } from './OpacitySettings';
@@ -6,6 +6,7 @@
  * - Input handling
  * - Submit functionality
  * - Keyboard shortcuts (Enter, Escape, Tab)
+ * - Translate action and language picker
  * - Text prediction ghost text (tasks 7.10-7.11)
  *
  * @module QuickChatApp.test
@@ -20,6 +21,7 @@ describe('QuickChatApp', () => {
     const mockSubmitQuickChat = vi.fn();
     const mockCancelQuickChat = vi.fn();
     const mockHideQuickChat = vi.fn();
+    const mockTranslateQuickChat = vi.fn();
     const mockGetTextPredictionStatus = vi.fn();
     const mockPredictText = vi.fn();
     const mockOnTextPredictionStatusChanged = vi.fn();
@@ -41,6 +43,8 @@ describe('QuickChatApp', () => {
             submitQuickChat: mockSubmitQuickChat,
             cancelQuickChat: mockCancelQuickChat,
             hideQuickChat: mockHideQuickChat,
+            translateQuickChat: mockTranslateQuickChat,
+            getTranslateLanguage: vi.fn().mockResolvedValue('Spanish'),
             getTextPredictionStatus: mockGetTextPredictionStatus,
             predictText: mockPredictText,
             onTextPredictionStatusChanged: mockOnTextPredictionStatusChanged,
@@ -212,6 +216,44 @@ describe('QuickChatApp', () => {
         });
     });
 
+    describe('Translate', () => {
+        it('loads the remembered translation language', async () => {
+            render(<QuickChatApp />);
+
+            await waitFor(() => {
+                expect(screen.getByTestId('quick-chat-language')).toHaveValue('Spanish');
+            });
+        });
+
+        it('translates input text with the selected language on button click', async () => {
+            render(<QuickChatApp />);
+
+            await waitFor(() => {
+                expect(screen.getByTestId('quick-chat-language')).toHaveValue('Spanish');
+            });
+
+            fireEvent.change(screen.getByTestId('quick-chat-language'), { target: { value: 'Japanese' } });
+            fireEvent.change(screen.getByTestId('quick-chat-input'), { target: { value: 'Good morning' } });
+            fireEvent.click(screen.getByTestId('quick-chat-translate'));
+
+            expect(mockTranslateQuickChat).toHaveBeenCalledWith('Good morning', 'Japanese');
+            expect(screen.getByTestId('quick-chat-input')).toHaveValue('');
+        });
+
+        it('translates the clipboard when input is empty (Alt+Enter)', async () => {
+            render(<QuickChatApp />);
+
+            await waitFor(() => {
+                expect(screen.getByTestId('quick-chat-language')).toHaveValue('Spanish');
+            });
+
+            fireEvent.keyDown(screen.getByTestId('quick-chat-input'), { key: 'Enter', altKey: true });
+
+            expect(mockTranslateQuickChat).toHaveBeenCalledWith('', 'Spanish');
+            expect(mockSubmitQuickChat).not.toHaveBeenCalled();
+        });
+    });
+
     describe('Without ElectronAPI', () => {
         it('handles missing electronAPI gracefully', () => {
             window.electronAPI = undefined;
@@ -3,14 +3,21 @@
  *
  * A macOS Spotlight-inspired floating input for sending prompts to Gemini.
  * Features glassmorphism styling, auto-focus, and keyboard shortcuts.
+ * Also offers a translate action that wraps the input (or clipboard)
+ * in a translation prompt for the selected language.
  *
  * @module QuickChatApp
  */
 
 import React, { useState, useEffect, useRef, useCallback } from 'react';
-import { SearchIcon, SendIcon } from './Icons';
+import { SearchIcon, SendIcon, TranslateIcon } from './Icons';
 import { QUICK_CHAT_TEST_IDS } from '../../utils/testIds';
 import { createRendererLogger } from '../../utils';
+import {
+    TRANSLATE_LANGUAGES,
+    DEFAULT_TRANSLATE_LANGUAGE,
+    type TranslateLanguage,
+} from '../../../shared/types/translation';
 import './QuickChat.css';
 
 /** Logger for Quick Chat component */
@@ -25,6 +32,7 @@ const PREDICTION_DEBOUNCE_MS = 300;
  */
 function QuickChatApp(): React.ReactElement {
     const [inputValue, setInputValue] = useState('');
+    const [translateLanguage, setTranslateLanguage] = useState<TranslateLanguage>(DEFAULT_TRANSLATE_LANGUAGE);
 
     // Text prediction state
     const [prediction, setPrediction] = useState<string | null>(null);
@@ -65,6 +73,14 @@ function QuickChatApp(): React.ReactElement {
         };
     }, []);
 
+    // Load remembered translation target language
+    useEffect(() => {
+        window.electronAPI
+            ?.getTranslateLanguage()
+            .then((language) => setTranslateLanguage(language))
+            .catch(() => logger.log('Failed to load translation language'));
+    }, []);
+
     // Cleanup timeout on unmount
     useEffect(() => {
         return () => {
@@ -94,13 +110,26 @@ function QuickChatApp(): React.ReactElement {
         }
     }, [inputValue]);
 
+    /**
+     * Handle translate action.
+     * Translates the input text, or the clipboard contents when the input is empty.
+     */
+    const handleTranslate = useCallback(() => {
+        window.electronAPI?.translateQuickChat(inputValue.trim(), translateLanguage);
+        setInputValue('');
+        setPrediction(null);
+    }, [inputValue, translateLanguage]);
+
     /**
      * Handle keyboard events.
-     * Enter = submit, Escape = cancel, Tab = accept prediction
+     * Enter = submit, Alt+Enter = translate, Escape = cancel, Tab = accept prediction
      */
     const handleKeyDown = useCallback(
         (event: React.KeyboardEvent<HTMLInputElement>) => {
-            if (event.key === 'Enter' && !event.shiftKey) {
+            if (event.key === 'Enter' && event.altKey) {
+                event.preventDefault();
+                handleTranslate();
+            } else if (event.key === 'Enter' && !event.shiftKey) {
                 event.preventDefault();
                 handleSubmit();
             } else if (event.key === 'Escape') {
@@ -120,7 +149,7 @@ function QuickChatApp(): React.ReactElement {
                 setPrediction(null);
             }
         },
-        [handleSubmit, prediction, inputValue]
+        [handleSubmit, handleTranslate, prediction, inputValue]
     );
 
     /**
@@ -210,6 +239,29 @@ function QuickChatApp(): React.ReactElement {
                     autoComplete="off"
                     spellCheck={false}
                 />
+                <select
+                    className="quick-chat-language"
+                    value={translateLanguage}
+                    onChange={(e) => setTranslateLanguage(e.target.value as TranslateLanguage)}
+                    data-testid={QUICK_CHAT_TEST_IDS.QUICK_CHAT_LANGUAGE}
+                    aria-label="Translation language"
+                >
+                    {TRANSLATE_LANGUAGES.map((language) => (
+                        <option key={language} value={language}>
+                            {language}
+                        </option>
+                    ))}
+                </select>
+                <button
+                    type="button"
+                    className="quick-chat-submit"
+                    onClick={handleTranslate}
+                    data-testid={QUICK_CHAT_TEST_IDS.QUICK_CHAT_TRANSLATE}
+                    aria-label="Translate input or clipboard"
+                    title="Translate input or clipboard (Alt+Enter)"
+                >
+                    <TranslateIcon />
+                </button>
                 <button
                     type="button"
                     className="quick-chat-submit"
@@ -0,0 +1,63 @@
+/**
+ * Unit tests for NavigationControls component.
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, fireEvent, act } from '@testing-library/react';
+import { NavigationControls } from './NavigationControls';
+
+describe('NavigationControls', () => {
+    beforeEach(() => {
+        vi.clearAllMocks();
+    });
+
+    it('reloads Gemini on click', () => {
+        render(<NavigationControls />);
+
+        fireEvent.click(screen.getByRole('button', { name: /reload gemini/i }));
+
+        expect(window.electronAPI.reloadGemini).toHaveBeenCalledWith(false);
+    });
+
+    it('hard reloads on Shift+click', () => {
+        render(<NavigationControls />);
+
+        fireEvent.click(screen.getByRole('button', { name: /reload gemini/i }), { shiftKey: true });
+
+        expect(window.electronAPI.reloadGemini).toHaveBeenCalledWith(true);
+    });
+
+    it('disables back and forward until there is history', async () => {
+        render(<NavigationControls />);
+
+        await act(async () => {});
+
+        expect(screen.getByRole('button', { name: /go back/i })).toBeDisabled();
+        expect(screen.getByRole('button', { name: /go forward/i })).toBeDisabled();
+    });
+
+    it('enables back and forward when navigation state changes', async () => {
+        let onChanged: (state: { canGoBack: boolean; canGoForward: boolean }) => void = () => {};
+        vi.mocked(window.electronAPI.onGeminiNavigationChanged).mockImplementation((callback) => {
+            onChanged = callback;
+            return () => {};
+        });
+        render(<NavigationControls />);
+        await act(async () => {});
+
+        act(() => onChanged({ canGoBack: true, canGoForward: true }));
+        fireEvent.click(screen.getByRole('button', { name: /go back/i }));
+        fireEvent.click(screen.getByRole('button', { name: /go forward/i }));
+
+        expect(window.electronAPI.goBackGemini).toHaveBeenCalled();
+        expect(window.electronAPI.goForwardGemini).toHaveBeenCalled();
+    });
+
+    it('navigates Gemini home on click', () => {
+        render(<NavigationControls />);
+
+        fireEvent.click(screen.getByRole('button', { name: /gemini home/i }));
+
+        expect(window.electronAPI.goHomeGemini).toHaveBeenCalled();
+    });
+});
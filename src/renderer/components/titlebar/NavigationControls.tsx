@@ -0,0 +1,117 @@
+import { memo, useEffect, useState, type MouseEvent } from 'react';
+import { TITLEBAR_TEST_IDS } from '../../utils/testIds';
+import { createRendererLogger } from '../../utils';
+import type { GeminiNavigationState } from '../../../shared/types/navigation';
+import './titlebar.css';
+
+const logger = createRendererLogger('[NavigationControls]');
+
+const INITIAL_STATE: GeminiNavigationState = { canGoBack: false, canGoForward: false };
+
+/**
+ * Navigation buttons for the embedded Gemini view.
+ *
+ * Back and forward follow Gemini's in-app history and are disabled when there
+ * is nothing to go to. Reload reloads just the Gemini frame; Shift+click does
+ * a hard reload that bypasses the HTTP cache. Home opens a new Gemini chat.
+ *
+ * Memoized to prevent unnecessary re-renders.
+ */
+export const NavigationControls = memo(function NavigationControls() {
+    const [navigation, setNavigation] = useState<GeminiNavigationState>(INITIAL_STATE);
+
+    // Initialize navigation state from main process and subscribe to changes
+    useEffect(() => {
+        window.electronAPI
+            ?.getGeminiNavigationState()
+            .then(setNavigation)
+            .catch((error) => {
+                logger.error('Failed to get Gemini navigation state:', error);
+            });
+
+        const cleanup = window.electronAPI?.onGeminiNavigationChanged(setNavigation);
+
+        return () => {
+            cleanup?.();
+        };
+    }, []);
+
+    const handleReload = (event: MouseEvent<HTMLButtonElement>) => {
+        window.electronAPI?.reloadGemini(event.shiftKey);
+    };
+
+    return (
+        <div className="navigation-controls">
+            <button
+                className="navigation-control-button"
+                onClick={() => window.electronAPI?.goBackGemini()}
+                disabled={!navigation.canGoBack}
+                aria-label="Go back"
+                title="Back"
+                data-testid={TITLEBAR_TEST_IDS.BACK_BUTTON}
+            >
+                <svg width="14" height="14" viewBox="0 0 16 16" fill="none">
+                    <path
+                        d="M10 3L5 8L10 13"
+                        stroke="currentColor"
+                        strokeWidth="1.4"
+                        strokeLinecap="round"
+                        strokeLinejoin="round"
+                    />
+                </svg>
+            </button>
+            <button
+                className="navigation-control-button"
+                onClick={() => window.electronAPI?.goForwardGemini()}
+                disabled={!navigation.canGoForward}
+                aria-label="Go forward"
+                title="Forward"
+                data-testid={TITLEBAR_TEST_IDS.FORWARD_BUTTON}
+            >
+                <svg width="14" height="14" viewBox="0 0 16 16" fill="none">
+                    <path
+                        d="M6 3L11 8L6 13"
+                        stroke="currentColor"
+                        strokeWidth="1.4"
+                        strokeLinecap="round"
+                        strokeLinejoin="round"
+                    />
+                </svg>
+            </button>
+            <button
+                className="navigation-control-button"
+                onClick={handleReload}
+                aria-label="Reload Gemini"
+                title="Reload (Shift+click to bypass cache)"
+                data-testid={TITLEBAR_TEST_IDS.RELOAD_BUTTON}
+            >
+                <svg width="14" height="14" viewBox="0 0 16 16" fill="none">
+                    <path
+                        d="M13.5 8A5.5 5.5 0 1 1 11.9 4.1"
+                        stroke="currentColor"
+                        strokeWidth="1.4"
+                        strokeLinecap="round"
+                    />
+                    <path d="M12.5 1.5V4.5H9.5" stroke="currentColor" strokeWidth="1.4" strokeLinecap="round" />
+                </svg>
+            </button>
+            <button
+                className="navigation-control-button"
+                onClick={() => window.electronAPI?.goHomeGemini()}
+                aria-label="Go to Gemini home"
+                title="Home (new chat)"
+                data-testid={TITLEBAR_TEST_IDS.HOME_BUTTON}
+            >
+                <svg width="14" height="14" viewBox="0 0 16 16" fill="none">
+                    <path
+                        d="M2.5 7.5L8 2.5L13.5 7.5M4 6.5V13.5H12V6.5"
+                        stroke="currentColor"
+                        strokeWidth="1.4"
+                        strokeLinecap="round"
+                        strokeLinejoin="round"
+                    />
+                </svg>
+            </button>
+        </div>
+    );
+});
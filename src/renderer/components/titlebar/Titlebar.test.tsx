@@ -3,7 +3,7 @@
  */
 
 import { describe, it, expect, vi, beforeEach, type Mock } from 'vitest';
-import { render, screen, fireEvent } from '@testing-library/react';
+import { act, render, screen, fireEvent, waitFor } from '@testing-library/react';
 import { Titlebar } from './Titlebar';
 import { setMockPlatform, mockElectronAPI } from '../../../../tests/unit/renderer/test/setup';
 
@@ -151,6 +151,42 @@ describe('Titlebar', () => {
             const header = document.querySelector('header.titlebar');
             expect(header).not.toHaveClass('macos');
         });
+
+        it('applies fullscreen class while the window is fullscreen', async () => {
+            render(<Titlebar />);
+            await waitFor(() => expect(mockElectronAPI.onFullScreenChanged).toHaveBeenCalled());
+
+            const callback = mockElectronAPI.onFullScreenChanged.mock.calls.at(-1)![0];
+            act(() => callback({ enabled: true }));
+            expect(document.querySelector('header.titlebar')).toHaveClass('fullscreen');
+
+            act(() => callback({ enabled: false }));
+            expect(document.querySelector('header.titlebar')).not.toHaveClass('fullscreen');
+        });
+
+        it('follows the titlebar height setting', async () => {
+            mockElectronAPI.getTitlebarHeight.mockResolvedValueOnce(40);
+            render(<Titlebar />);
+
+            const header = document.querySelector('header.titlebar') as HTMLElement;
+            await waitFor(() => expect(header.style.getPropertyValue('--titlebar-height')).toBe('40px'));
+
+            const callback = mockElectronAPI.onTitlebarHeightChanged.mock.calls.at(-1)![0];
+            act(() => callback({ height: 48 }));
+            expect(header.style.getPropertyValue('--titlebar-height')).toBe('48px');
+        });
+
+        it('turns translucent while a titlebar effect is on', async () => {
+            mockElectronAPI.getTitlebarEffect.mockResolvedValueOnce({ effect: 'mica', supported: ['none', 'mica'] });
+            render(<Titlebar />);
+
+            const header = document.querySelector('header.titlebar') as HTMLElement;
+            await waitFor(() => expect(header).toHaveClass('translucent'));
+
+            const callback = mockElectronAPI.onTitlebarEffectChanged.mock.calls.at(-1)![0];
+            act(() => callback({ effect: 'none', supported: ['none', 'mica'] }));
+            expect(header).not.toHaveClass('translucent');
+        });
     });
 
     describe('layout structure', () => {
@@ -169,6 +205,18 @@ describe('Titlebar', () => {
             expect(title).toBeInTheDocument();
             expect(title).toHaveTextContent('Gemini Desktop');
         });
+
+        it('shows the conversation title', async () => {
+            mockElectronAPI.getGeminiLocation.mockResolvedValueOnce({
+                url: 'https://gemini.google.com/app/abc123',
+                title: 'Trip plan',
+                conversationId: 'abc123',
+            });
+
+            render(<Titlebar />);
+
+            expect(await screen.findByText('Trip plan - Gemini Desktop')).toBeInTheDocument();
+        });
     });
 
     describe('menu definitions', () => {
@@ -1,7 +1,14 @@
+import type { CSSProperties } from 'react';
 import { WindowControls } from './WindowControls';
 import { TitlebarMenu } from './TitlebarMenu';
+import { NavigationControls } from './NavigationControls';
+import { TitlebarWidgets } from './TitlebarWidgets';
 import { useMenuDefinitions } from './useMenuDefinitions';
 import { useUpdateToast } from '../../context/UpdateToastContext';
+import { useGeminiLocation } from '../../hooks/useGeminiLocation';
+import { useFullScreen } from '../../hooks/useFullScreen';
+import { useTitlebarHeight } from '../../hooks/useTitlebarHeight';
+import { useTitlebarEffect } from '../../hooks/useTitlebarEffect';
 import type { TitlebarConfig } from '../../types';
 import { TITLEBAR_TEST_IDS } from '../../utils/testIds';
 import { isMacOS } from '../../utils/platform';
@@ -26,9 +33,13 @@ interface TitlebarProps {
  * Features:
  * - Draggable region for moving the window
  * - VS Code-style dropdown menus (Windows/Linux only)
- * - App title display
- * - Window control buttons (minimize, maximize, close)
+ * - Gemini reload button
+ * - App title display, prefixed with the open conversation's title
+ * - Status indicators published by the main process (offline, exports, profile)
+ * - Window control buttons (minimize, maximize, close); maximize is hidden while fullscreen
  * - Update badge indicator when update is pending
+ * - Height from the titlebar height setting, updated live
+ * - Transparent over the native material while a titlebar effect is on
  *
  * Note: The drag region is applied to a dedicated element, not the entire header,
  * to allow menu buttons to receive click events.
@@ -38,6 +49,15 @@ interface TitlebarProps {
 export function Titlebar({ config = {} }: TitlebarProps) {
     const mergedConfig = { ...defaultConfig, ...config };
     const menus = useMenuDefinitions();
+    const location = useGeminiLocation();
+    const fullscreen = useFullScreen();
+    const height = useTitlebarHeight();
+    const { effect } = useTitlebarEffect();
+    const className = ['titlebar', isMacOS() && 'macos', fullscreen && 'fullscreen', effect !== 'none' && 'translucent']
+        .filter(Boolean)
+        .join(' ');
+    const title =
+        location?.conversationId && location.title ? `${location.title} - ${mergedConfig.title}` : mergedConfig.title;
 
     // Get pending update state for badge display
     let hasPendingUpdate = false;
@@ -61,7 +81,11 @@ export function Titlebar({ config = {} }: TitlebarProps) {
     };
 
     return (
-        <header className={`titlebar${isMacOS() ? ' macos' : ''}`} data-testid="titlebar">
+        <header
+            className={className}
+            style={{ '--titlebar-height': `${height}px` } as CSSProperties}
+            data-testid="titlebar"
+        >
             <div className="titlebar-left">
                 {mergedConfig.showIcon && (
                     <div className="titlebar-icon">
@@ -85,6 +109,7 @@ export function Titlebar({ config = {} }: TitlebarProps) {
                     </div>
                 )}
                 <TitlebarMenu menus={menus} />
+                <NavigationControls />
             </div>
             {/* 
                 Drag region - allows window dragging without blocking menu clicks.
@@ -92,9 +117,10 @@ export function Titlebar({ config = {} }: TitlebarProps) {
             */}
             <div className="titlebar-drag-region">
                 <span className="titlebar-title" data-testid="titlebar-title">
-                    {mergedConfig.title}
+                    {title}
                 </span>
             </div>
+            <TitlebarWidgets />
             <WindowControls />
         </header>
     );
@@ -0,0 +1,48 @@
+/**
+ * Unit tests for TitlebarWidgets component.
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, act } from '@testing-library/react';
+import { TitlebarWidgets } from './TitlebarWidgets';
+import type { TitlebarState } from '../../../shared/types/titlebar';
+
+describe('TitlebarWidgets', () => {
+    beforeEach(() => {
+        vi.clearAllMocks();
+    });
+
+    it('renders nothing when there are no widgets', async () => {
+        render(<TitlebarWidgets />);
+        await act(async () => {});
+
+        expect(screen.queryByTestId('titlebar-widgets')).toBeNull();
+    });
+
+    it('renders the widgets from the main process', async () => {
+        vi.mocked(window.electronAPI.getTitlebarState).mockResolvedValueOnce({
+            widgets: [{ id: 'profile', label: 'work', tooltip: 'Profile: work', tone: 'info', order: 40 }],
+        });
+        render(<TitlebarWidgets />);
+        await act(async () => {});
+
+        const widget = screen.getByTestId('titlebar-widget-profile');
+        expect(widget).toHaveTextContent('work');
+        expect(widget).toHaveAttribute('title', 'Profile: work');
+        expect(widget).toHaveClass('titlebar-widget--info');
+    });
+
+    it('updates when the state changes', async () => {
+        let onChanged: (state: TitlebarState) => void = () => {};
+        vi.mocked(window.electronAPI.onTitlebarStateChanged).mockImplementation((callback) => {
+            onChanged = callback;
+            return () => {};
+        });
+        render(<TitlebarWidgets />);
+        await act(async () => {});
+
+        act(() => onChanged({ widgets: [{ id: 'offline', label: 'Offline', tone: 'warning', order: 10 }] }));
+
+        expect(screen.getByTestId('titlebar-widget-offline')).toHaveClass('titlebar-widget--warning');
+    });
+});
@@ -0,0 +1,53 @@
+import { memo, useEffect, useState } from 'react';
+import { createRendererLogger } from '../../utils';
+import type { TitlebarWidget } from '../../../shared/types/titlebar';
+import './titlebar.css';
+
+const logger = createRendererLogger('[TitlebarWidgets]');
+
+/**
+ * Status indicators published by the main process.
+ *
+ * Main process modules add, update and remove indicators through the
+ * titlebar widget registry (e.g. offline, running exports, active profile);
+ * this component only renders whatever is published, so new indicators need
+ * no titlebar changes.
+ *
+ * Memoized to prevent unnecessary re-renders.
+ */
+export const TitlebarWidgets = memo(function TitlebarWidgets() {
+    const [widgets, setWidgets] = useState<TitlebarWidget[]>([]);
+
+    // Initialize widgets from main process and subscribe to changes
+    useEffect(() => {
+        window.electronAPI
+            ?.getTitlebarState()
+            .then((state) => setWidgets(state.widgets))
+            .catch((error) => {
+                logger.error('Failed to get titlebar state:', error);
+            });
+
+        const cleanup = window.electronAPI?.onTitlebarStateChanged((state) => setWidgets(state.widgets));
+
+        return () => {
+            cleanup?.();
+        };
+    }, []);
+
+    if (widgets.length === 0) return null;
+
+    return (
+        <div className="titlebar-widgets" data-testid="titlebar-widgets">
+            {widgets.map((widget) => (
+                <span
+                    key={widget.id}
+                    className={`titlebar-widget titlebar-widget--${widget.tone}`}
+                    title={widget.tooltip}
+                    data-testid={`titlebar-widget-${widget.id}`}
+                >
+                    {widget.label}
+                </span>
+            ))}
+        </div>
+    );
+});
@@ -1,2 +1,4 @@
 export { Titlebar } from './Titlebar';
 export { WindowControls } from './WindowControls';
+export { NavigationControls } from './NavigationControls';
+export { TitlebarWidgets } from './TitlebarWidgets';
@@ -3,7 +3,7 @@
  */
 
 import { describe, it, expect, vi, beforeEach } from 'vitest';
-import { renderHook } from '@testing-library/react';
+import { renderHook, waitFor } from '@testing-library/react';
 import { useMenuDefinitions } from './useMenuDefinitions';
 import { mockElectronAPI } from '../../../../tests/unit/renderer/test/setup';
 
@@ -24,14 +24,18 @@ describe('useMenuDefinitions', () => {
     });
 
     describe('File menu', () => {
-        it('has New Window item (disabled placeholder)', () => {
+        it('has New Window item and action works', () => {
             const { result } = renderHook(() => useMenuDefinitions());
             const fileMenu = result.current[0];
             const newWindowItem = fileMenu.items[0];
 
             expect(newWindowItem).toHaveProperty('label', 'New Window');
-            expect(newWindowItem).toHaveProperty('disabled', true);
             expect(newWindowItem).toHaveProperty('shortcut', 'Ctrl+Shift+N');
+
+            if ('action' in newWindowItem && newWindowItem.action) {
+                newWindowItem.action();
+                expect(mockElectronAPI.openNewWindow).toHaveBeenCalledTimes(1);
+            }
         });
 
         it('has separator after New Window', () => {
@@ -69,11 +73,149 @@ describe('useMenuDefinitions', () => {
             }
         });
 
+        it('has Export to Confluence item and action works', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const confluenceItem = result.current[0].items[4];
+
+            expect(confluenceItem).toHaveProperty('id', 'menu-view-export-confluence');
+            if ('action' in confluenceItem && confluenceItem.action) {
+                confluenceItem.action();
+            }
+            expect(mockElectronAPI.exportChatToConfluence).toHaveBeenCalledTimes(1);
+        });
+
+        it('has Export Canvas as Markdown item and action works', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const canvasItem = result.current[0].items[5];
+
+            expect(canvasItem).toHaveProperty('id', 'menu-file-export-canvas');
+            if ('action' in canvasItem && canvasItem.action) {
+                canvasItem.action();
+            }
+            expect(mockElectronAPI.exportCanvasToMarkdown).toHaveBeenCalledTimes(1);
+        });
+
+        it('has Export Research Report items and actions work', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const pdfItem = result.current[0].items[6];
+            const mdItem = result.current[0].items[7];
+
+            expect(pdfItem).toHaveProperty('id', 'menu-file-export-report-pdf');
+            expect(mdItem).toHaveProperty('id', 'menu-file-export-report-md');
+            if ('action' in pdfItem && pdfItem.action) {
+                pdfItem.action();
+            }
+            if ('action' in mdItem && mdItem.action) {
+                mdItem.action();
+            }
+            expect(mockElectronAPI.exportResearchReport).toHaveBeenNthCalledWith(1, 'pdf');
+            expect(mockElectronAPI.exportResearchReport).toHaveBeenNthCalledWith(2, 'markdown');
+        });
+
+        it('has Pin Last Answer item and action works', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const pinItem = result.current[0].items[8];
+
+            expect(pinItem).toHaveProperty('id', 'menu-file-pin-answer');
+            if ('action' in pinItem && pinItem.action) {
+                pinItem.action();
+            }
+            expect(mockElectronAPI.pinLastAnswer).toHaveBeenCalledTimes(1);
+        });
+
+        it('has Share Last Answer item and action works', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const shareItem = result.current[0].items[9];
+
+            expect(shareItem).toHaveProperty('id', 'menu-file-share-answer');
+            if ('action' in shareItem && shareItem.action) {
+                shareItem.action();
+            }
+            expect(mockElectronAPI.requestSharePreview).toHaveBeenCalledTimes(1);
+        });
+
+        it('has Share as QR Code item and action works', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const qrItem = result.current[0].items[10];
+
+            expect(qrItem).toHaveProperty('id', 'menu-file-share-qr');
+            if ('action' in qrItem && qrItem.action) {
+                qrItem.action();
+            }
+            expect(mockElectronAPI.shareAsQr).toHaveBeenCalledTimes(1);
+        });
+
+        it('has Create Issue from Answer item and action works', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const issueItem = result.current[0].items[11];
+
+            expect(issueItem).toHaveProperty('id', 'menu-file-create-issue');
+            if ('action' in issueItem && issueItem.action) {
+                issueItem.action();
+            }
+            expect(mockElectronAPI.requestIssueDraft).toHaveBeenCalledTimes(1);
+        });
+
+        it('has Print item and action works', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const printItem = result.current[0].items[12];
+
+            expect(printItem).toHaveProperty('id', 'menu-file-print');
+            expect(printItem).toHaveProperty('shortcut', 'Ctrl+P');
+            if ('action' in printItem && printItem.action) {
+                printItem.action();
+            }
+            expect(mockElectronAPI.printGemini).toHaveBeenCalledTimes(1);
+        });
+
+        it('has screenshot items that capture the view or the last answer', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const items = result.current[0].items.slice(13, 16);
+
+            expect(items.map((item) => ('id' in item ? item.id : undefined))).toEqual([
+                'menu-file-screenshot-save',
+                'menu-file-screenshot-copy',
+                'menu-file-screenshot-answer',
+            ]);
+            for (const item of items) {
+                if ('action' in item && item.action) item.action();
+            }
+            expect(mockElectronAPI.captureScreenshot.mock.calls).toEqual([
+                [{ target: 'file', region: 'view' }],
+                [{ target: 'clipboard', region: 'view' }],
+                [{ target: 'clipboard', region: 'response' }],
+            ]);
+        });
+
+        it('disables answer actions outside a conversation', async () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const isDisabled = (id: string) => {
+                const item = result.current[0].items.find((entry) => 'id' in entry && entry.id === id);
+                return item !== undefined && 'disabled' in item && item.disabled === true;
+            };
+
+            expect(isDisabled('menu-view-export-pdf')).toBe(false);
+
+            const callback = mockElectronAPI.onGeminiLocationChanged.mock.calls[0][0];
+            await import('react').then(({ act }) => {
+                act(() => {
+                    callback({ url: 'https://gemini.google.com/app', title: 'Gemini', conversationId: null });
+                });
+            });
+
+            expect(isDisabled('menu-view-export-pdf')).toBe(true);
+            expect(isDisabled('menu-file-share-answer')).toBe(true);
+            expect(isDisabled('menu-file-share-qr')).toBe(true);
+            expect(isDisabled('menu-file-screenshot-answer')).toBe(true);
+            expect(isDisabled('menu-file-screenshot-save')).toBe(false);
+            expect(isDisabled('menu-file-print')).toBe(false);
+        });
+
         it('has separator after export items', () => {
             const { result } = renderHook(() => useMenuDefinitions());
             const fileMenu = result.current[0];
 
-            expect(fileMenu.items[4]).toEqual({ separator: true });
+            expect(fileMenu.items[16]).toEqual({ separator: true });
         });
 
         it('has Sign in to Google item and action works', async () => {
@@ -87,7 +229,7 @@ describe('useMenuDefinitions', () => {
 
             const { result } = renderHook(() => useMenuDefinitions());
             const fileMenu = result.current[0];
-            const signInItem = fileMenu.items[5];
+            const signInItem = fileMenu.items[17];
 
             expect(signInItem).toHaveProperty('label', 'Sign in to Google');
             expect(signInItem).toHaveProperty('action');
@@ -109,7 +251,7 @@ describe('useMenuDefinitions', () => {
         it('has Options item', () => {
             const { result } = renderHook(() => useMenuDefinitions());
             const fileMenu = result.current[0];
-            const optionsItem = fileMenu.items[6];
+            const optionsItem = fileMenu.items[18];
 
             expect(optionsItem).toHaveProperty('label', 'Options');
             expect(optionsItem).toHaveProperty('disabled', false);
@@ -125,19 +267,19 @@ describe('useMenuDefinitions', () => {
             const { result } = renderHook(() => useMenuDefinitions());
             const fileMenu = result.current[0];
 
-            expect(fileMenu.items[7]).toEqual({ separator: true });
+            expect(fileMenu.items[19]).toEqual({ separator: true });
         });
 
-        it('Exit action calls electronAPI.closeWindow()', () => {
+        it('Exit action calls electronAPI.quitApp()', () => {
             const { result } = renderHook(() => useMenuDefinitions());
             const fileMenu = result.current[0];
-            const exitItem = fileMenu.items[8];
+            const exitItem = fileMenu.items[20];
 
             expect(exitItem).toHaveProperty('label', 'Exit');
 
             if ('action' in exitItem && exitItem.action) {
                 exitItem.action();
-                expect(mockElectronAPI.closeWindow).toHaveBeenCalledTimes(1);
+                expect(mockElectronAPI.quitApp).toHaveBeenCalledTimes(1);
             }
         });
     });
@@ -168,19 +310,106 @@ describe('useMenuDefinitions', () => {
             });
         });
 
-        it('Toggle Fullscreen is disabled', () => {
+        it('Toggle Fullscreen action calls electronAPI.toggleFullScreen()', () => {
             const { result } = renderHook(() => useMenuDefinitions());
             const viewMenu = result.current[1];
-            const toggleItem = viewMenu.items[7]; // After Zoom In, Zoom Out, Always On Top and separators
+            const toggleItem = viewMenu.items[8]; // After Zoom In, Zoom Out, Reset Zoom, Always On Top and separators
 
             expect(toggleItem).toHaveProperty('label', 'Toggle Fullscreen');
-            expect(toggleItem).toHaveProperty('disabled', true);
+            expect(toggleItem).not.toHaveProperty('disabled');
+            if ('action' in toggleItem && toggleItem.action) {
+                toggleItem.action();
+            }
+            expect(mockElectronAPI.toggleFullScreen).toHaveBeenCalledTimes(1);
+        });
+
+        it('has Immersive Mode item and action works', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const immersiveItem = result.current[1].items[9];
+
+            expect(immersiveItem).toHaveProperty('id', 'menu-view-immersive');
+            expect(immersiveItem).toHaveProperty('shortcut', 'Shift+F11');
+            if ('action' in immersiveItem && immersiveItem.action) {
+                immersiveItem.action();
+            }
+            expect(mockElectronAPI.toggleImmersiveMode).toHaveBeenCalledTimes(1);
+        });
+
+        it('has Compact Mode item and action works', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const compactItem = result.current[1].items[10];
+
+            expect(compactItem).toHaveProperty('id', 'menu-view-compact');
+            expect(compactItem).toHaveProperty('shortcut', 'Ctrl+Shift+M');
+            if ('action' in compactItem && compactItem.action) {
+                compactItem.action();
+            }
+            expect(mockElectronAPI.toggleCompactMode).toHaveBeenCalledTimes(1);
+        });
+
+        it('has Overlay Mode item and action works', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const overlayItem = result.current[1].items[11];
+
+            expect(overlayItem).toHaveProperty('id', 'menu-view-overlay');
+            expect(overlayItem).toHaveProperty('shortcut', 'Ctrl+Shift+O');
+            expect(overlayItem).toHaveProperty('disabled', false);
+            if ('action' in overlayItem && overlayItem.action) {
+                overlayItem.action();
+            }
+            expect(mockElectronAPI.toggleOverlayMode).toHaveBeenCalledTimes(1);
+        });
+
+        it('has Move to Next Display item that moves to the display after the current one', async () => {
+            mockElectronAPI.getDisplays.mockResolvedValueOnce([
+                { index: 0, current: false },
+                { index: 1, current: true },
+            ]);
+            const { result } = renderHook(() => useMenuDefinitions());
+            const moveItem = result.current[1].items[12];
+
+            expect(moveItem).toHaveProperty('id', 'menu-view-move-to-next-display');
+            if ('action' in moveItem && moveItem.action) {
+                moveItem.action();
+            }
+            await waitFor(() => expect(mockElectronAPI.moveToDisplay).toHaveBeenCalledWith(0));
+        });
+
+        it('has Focus Session item that reflects and toggles the session', async () => {
+            mockElectronAPI.getFocusSessionStatus.mockResolvedValueOnce({
+                active: true,
+                startedAt: 0,
+                endsAt: 1,
+                entryCount: 0,
+            });
+            const { result } = renderHook(() => useMenuDefinitions());
+
+            await waitFor(() => expect(result.current[1].items[13]).toHaveProperty('checked', true));
+            const focusItem = result.current[1].items[13];
+            expect(focusItem).toHaveProperty('id', 'menu-view-focus-session');
+            if ('action' in focusItem && focusItem.action) {
+                focusItem.action();
+            }
+            expect(mockElectronAPI.toggleFocusSession).toHaveBeenCalledTimes(1);
+        });
+
+        it('has Reload User Styles item and action works', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const viewMenu = result.current[1];
+
+            expect(viewMenu.items[14]).toEqual({ separator: true });
+            const reloadStylesItem = viewMenu.items[15];
+            expect(reloadStylesItem).toHaveProperty('id', 'menu-view-reload-user-styles');
+            if ('action' in reloadStylesItem && reloadStylesItem.action) {
+                reloadStylesItem.action();
+            }
+            expect(mockElectronAPI.reloadUserStyles).toHaveBeenCalledTimes(1);
         });
 
         it('has Always On Top item with correct properties', () => {
             const { result } = renderHook(() => useMenuDefinitions());
             const viewMenu = result.current[1];
-            const alwaysOnTopItem = viewMenu.items[5]; // After Reload, separator, Zoom In, Zoom Out, separator
+            const alwaysOnTopItem = viewMenu.items[6]; // After Reload, separator, zoom items, separator
 
             expect(alwaysOnTopItem).toHaveProperty('id', 'menu-view-always-on-top');
             expect(alwaysOnTopItem).toHaveProperty('label', 'Always On Top');
@@ -192,7 +421,7 @@ describe('useMenuDefinitions', () => {
         it('Always On Top action calls setAlwaysOnTop and updates state', async () => {
             const { result, rerender } = renderHook(() => useMenuDefinitions());
             const viewMenu = result.current[1];
-            const alwaysOnTopItem = viewMenu.items[5];
+            const alwaysOnTopItem = viewMenu.items[6];
 
             // Initial state should be false
             expect(alwaysOnTopItem).toHaveProperty('checked', false);
@@ -216,7 +445,7 @@ describe('useMenuDefinitions', () => {
             // After rerender, checked should be true
             rerender();
             const updatedViewMenu = result.current[1];
-            const updatedItem = updatedViewMenu.items[5];
+            const updatedItem = updatedViewMenu.items[6];
             expect(updatedItem).toHaveProperty('checked', true);
         });
 
@@ -227,11 +456,23 @@ describe('useMenuDefinitions', () => {
             expect(viewMenu.items[1]).toEqual({ separator: true });
         });
 
-        it('has separator after Zoom Out', () => {
+        it('has Reset Zoom after Zoom Out', () => {
+            const { result } = renderHook(() => useMenuDefinitions());
+            const resetItem = result.current[1].items[4];
+
+            expect(resetItem).toHaveProperty('id', 'menu-view-zoom-reset');
+            expect(resetItem).toHaveProperty('shortcut', 'Ctrl+0');
+            if ('action' in resetItem && resetItem.action) {
+                resetItem.action();
+            }
+            expect(mockElectronAPI.resetZoom).toHaveBeenCalled();
+        });
+
+        it('has separator after Reset Zoom', () => {
             const { result } = renderHook(() => useMenuDefinitions());
             const viewMenu = result.current[1];
 
-            expect(viewMenu.items[4]).toEqual({ separator: true });
+            expect(viewMenu.items[5]).toEqual({ separator: true });
         });
 
         it('subscribes to always-on-top changes on mount', () => {
@@ -266,7 +507,7 @@ describe('useMenuDefinitions', () => {
 
             // alwaysOnTop state should remain at default (false)
             const viewMenu = result.current[1];
-            const alwaysOnTopItem = viewMenu.items[5];
+            const alwaysOnTopItem = viewMenu.items[6];
             expect(alwaysOnTopItem).toHaveProperty('checked', false);
         });
     });
@@ -1,6 +1,8 @@
 import { useState, useEffect, useCallback } from 'react';
 import type { MenuDefinition } from './menuTypes';
 import { createRendererLogger } from '../../utils';
+import { useGeminiLocation } from '../../hooks/useGeminiLocation';
+import { useCapabilities } from '../../hooks/useCapabilities';
 
 const logger = createRendererLogger('[useMenuDefinitions]');
 
@@ -22,6 +24,13 @@ export function useMenuDefinitions(): MenuDefinition[] {
     const [alwaysOnTop, setAlwaysOnTop] = useState(false);
     const [printToPdfAccelerator, setPrintToPdfAccelerator] = useState<string | undefined>(undefined);
     const [zoomLevel, setZoomLevel] = useState(100);
+    const [focusSessionActive, setFocusSessionActive] = useState(false);
+    const location = useGeminiLocation();
+    const capabilities = useCapabilities();
+
+    // Answer actions need an open conversation; keep them enabled until the location is known
+    const noConversation = location !== null && location.conversationId === null;
+    const noWindowOpacity = capabilities?.capabilities.windowOpacity.available === false;
 
     // Initialize state from main process and subscribe to changes
     useEffect(() => {
@@ -45,6 +54,26 @@ export function useMenuDefinitions(): MenuDefinition[] {
         };
     }, []);
 
+    // Track whether a focus session is running, for the menu check mark
+    useEffect(() => {
+        window.electronAPI
+            ?.getFocusSessionStatus()
+            .then(({ active }) => {
+                setFocusSessionActive(active);
+            })
+            .catch((error) => {
+                logger.error('Failed to get focus session status:', error);
+            });
+
+        const cleanup = window.electronAPI?.onFocusSessionChanged(({ active }) => {
+            setFocusSessionActive(active);
+        });
+
+        return () => {
+            cleanup?.();
+        };
+    }, []);
+
     // Subscribe to hotkey accelerator changes
     useEffect(() => {
         // Get initial state
@@ -111,6 +140,22 @@ export function useMenuDefinitions(): MenuDefinition[] {
         window.electronAPI?.zoomOut();
     }, []);
 
+    const handleZoomReset = useCallback(() => {
+        // Fire and forget - state update will come via onZoomLevelChanged event
+        window.electronAPI?.resetZoom();
+    }, []);
+
+    const moveToNextDisplay = useCallback(() => {
+        const api = window.electronAPI;
+        api?.getDisplays()
+            .then((displays) => {
+                if (displays.length < 2) return;
+                const current = displays.findIndex((display) => display.current);
+                api.moveToDisplay((current + 1) % displays.length);
+            })
+            .catch((error) => logger.error('Failed to list displays:', error));
+    }, []);
+
     return [
         {
             label: 'File',
@@ -119,12 +164,15 @@ export function useMenuDefinitions(): MenuDefinition[] {
                     id: 'menu-file-newwindow',
                     label: 'New Window',
                     shortcut: 'Ctrl+Shift+N',
-                    disabled: true, // Placeholder for future
+                    action: () => {
+                        window.electronAPI?.openNewWindow();
+                    },
                 },
                 { separator: true },
                 {
                     id: 'menu-view-export-pdf',
                     label: 'Export as PDF',
+                    disabled: noConversation,
                     shortcut: formattedPrintToPdfAccelerator || 'Ctrl+Shift+P',
                     action: () => {
                         window.electronAPI?.exportChatToPdf();
@@ -133,10 +181,105 @@ export function useMenuDefinitions(): MenuDefinition[] {
                 {
                     id: 'menu-view-export-markdown',
                     label: 'Export as Markdown',
+                    disabled: noConversation,
                     action: () => {
                         window.electronAPI?.exportChatToMarkdown();
                     },
                 },
+                {
+                    id: 'menu-view-export-confluence',
+                    label: 'Export to Confluence',
+                    disabled: noConversation,
+                    action: () => {
+                        window.electronAPI?.exportChatToConfluence();
+                    },
+                },
+                {
+                    id: 'menu-file-export-canvas',
+                    label: 'Export Canvas as Markdown',
+                    disabled: noConversation,
+                    action: () => {
+                        window.electronAPI?.exportCanvasToMarkdown();
+                    },
+                },
+                {
+                    id: 'menu-file-export-report-pdf',
+                    label: 'Export Research Report as PDF',
+                    disabled: noConversation,
+                    action: () => {
+                        window.electronAPI?.exportResearchReport('pdf');
+                    },
+                },
+                {
+                    id: 'menu-file-export-report-md',
+                    label: 'Export Research Report as Markdown',
+                    disabled: noConversation,
+                    action: () => {
+                        window.electronAPI?.exportResearchReport('markdown');
+                    },
+                },
+                {
+                    id: 'menu-file-pin-answer',
+                    label: 'Pin Last Answer',
+                    disabled: noConversation,
+                    action: () => {
+                        window.electronAPI?.pinLastAnswer();
+                    },
+                },
+                {
+                    id: 'menu-file-share-answer',
+                    label: 'Share Last Answer...',
+                    disabled: noConversation,
+                    action: () => {
+                        window.electronAPI?.requestSharePreview();
+                    },
+                },
+                {
+                    id: 'menu-file-share-qr',
+                    label: 'Share as QR Code...',
+                    disabled: noConversation,
+                    action: () => {
+                        window.electronAPI?.shareAsQr();
+                    },
+                },
+                {
+                    id: 'menu-file-create-issue',
+                    label: 'Create Issue from Answer...',
+                    disabled: noConversation,
+                    action: () => {
+                        window.electronAPI?.requestIssueDraft();
+                    },
+                },
+                {
+                    id: 'menu-file-print',
+                    label: 'Print...',
+                    shortcut: 'Ctrl+P',
+                    action: () => {
+                        window.electronAPI?.printGemini();
+                    },
+                },
+                {
+                    id: 'menu-file-screenshot-save',
+                    label: 'Save Screenshot...',
+                    action: () => {
+                        window.electronAPI?.captureScreenshot({ target: 'file', region: 'view' });
+                    },
+                },
+                {
+                    id: 'menu-file-screenshot-copy',
+                    label: 'Copy Screenshot',
+                    action: () => {
+                        window.electronAPI?.captureScreenshot({ target: 'clipboard', region: 'view' });
+                    },
+                },
+                {
+                    id: 'menu-file-screenshot-answer',
+                    label: 'Copy Last Answer as Image',
+                    disabled: noConversation,
+                    action: () => {
+                        window.electronAPI?.captureScreenshot({ target: 'clipboard', region: 'response' });
+                    },
+                },
                 { separator: true },
                 {
                     id: 'menu-file-signin',
@@ -162,7 +305,7 @@ export function useMenuDefinitions(): MenuDefinition[] {
                     label: 'Exit',
                     shortcut: 'Alt+F4',
                     action: () => {
-                        window.electronAPI?.closeWindow();
+                        window.electronAPI?.quitApp();
                     },
                 },
             ],
@@ -189,6 +332,12 @@ export function useMenuDefinitions(): MenuDefinition[] {
                     shortcut: 'Ctrl+-',
                     action: handleZoomOut,
                 },
+                {
+                    id: 'menu-view-zoom-reset',
+                    label: 'Reset Zoom',
+                    shortcut: 'Ctrl+0',
+                    action: handleZoomReset,
+                },
                 { separator: true },
                 {
                     id: 'menu-view-always-on-top',
@@ -202,7 +351,55 @@ export function useMenuDefinitions(): MenuDefinition[] {
                     id: 'menu-view-fullscreen',
                     label: 'Toggle Fullscreen',
                     shortcut: 'F11',
-                    disabled: true, // Will need IPC for fullscreen toggle
+                    action: () => {
+                        window.electronAPI?.toggleFullScreen();
+                    },
+                },
+                {
+                    id: 'menu-view-immersive',
+                    label: 'Immersive Mode',
+                    shortcut: 'Shift+F11',
+                    action: () => {
+                        window.electronAPI?.toggleImmersiveMode();
+                    },
+                },
+                {
+                    id: 'menu-view-compact',
+                    label: 'Compact Mode',
+                    shortcut: 'Ctrl+Shift+M',
+                    action: () => {
+                        window.electronAPI?.toggleCompactMode();
+                    },
+                },
+                {
+                    id: 'menu-view-overlay',
+                    label: 'Overlay Mode',
+                    shortcut: 'Ctrl+Shift+O',
+                    disabled: noWindowOpacity,
+                    action: () => {
+                        window.electronAPI?.toggleOverlayMode();
+                    },
+                },
+                {
+                    id: 'menu-view-move-to-next-display',
+                    label: 'Move to Next Display',
+                    action: moveToNextDisplay,
+                },
+                {
+                    id: 'menu-view-focus-session',
+                    label: 'Focus Session',
+                    checked: focusSessionActive,
+                    action: () => {
+                        window.electronAPI?.toggleFocusSession();
+                    },
+                },
+                { separator: true },
+                {
+                    id: 'menu-view-reload-user-styles',
+                    label: 'Reload User Styles',
+                    action: () => {
+                        window.electronAPI?.reloadUserStyles();
+                    },
                 },
             ],
         },
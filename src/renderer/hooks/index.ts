@@ -10,3 +10,15 @@ export { useNetworkStatus } from './useNetworkStatus';
 export { useGeminiIframe } from './useGeminiIframe';
 export { useUpdateNotifications } from './useUpdateNotifications';
 export { useQuickChatNavigation } from './useQuickChatNavigation';
+export { useExportProgress } from './useExportProgress';
+export { useUsageLimit } from './useUsageLimit';
+export { useSharePreview } from './useSharePreview';
+export { useIssueDraft } from './useIssueDraft';
+export { useGeminiLocation } from './useGeminiLocation';
+export { useImmersiveMode } from './useImmersiveMode';
+export { useCompactMode } from './useCompactMode';
+export { useCapabilities } from './useCapabilities';
+export { useFullScreen } from './useFullScreen';
+export { useWhatsNew } from './useWhatsNew';
+export { useTitlebarHeight } from './useTitlebarHeight';
+export { useTitlebarEffect } from './useTitlebarEffect';
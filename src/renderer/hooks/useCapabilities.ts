@@ -0,0 +1,28 @@
+/**
+ * Capabilities Hook
+ *
+ * Loads the capability report once so components can hide or disable actions
+ * that do not work on this platform or build.
+ *
+ * @module useCapabilities
+ */
+
+import { useEffect, useState } from 'react';
+import type { CapabilityReport } from '../../shared/types/capabilities';
+
+/**
+ * Hook to read the capability report.
+ * @returns The report, or null until the main process has answered
+ */
+export function useCapabilities(): CapabilityReport | null {
+    const [report, setReport] = useState<CapabilityReport | null>(null);
+
+    useEffect(() => {
+        window.electronAPI
+            ?.getCapabilityReport()
+            .then(setReport)
+            .catch((error) => console.error('Failed to get capability report:', error));
+    }, []);
+
+    return report;
+}
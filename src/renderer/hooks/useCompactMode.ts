@@ -0,0 +1,29 @@
+/**
+ * Compact Mode Hook
+ *
+ * Follows compact mode for this window, in which it is docked as a narrow
+ * strip at the edge of the screen.
+ *
+ * @module useCompactMode
+ */
+
+import { useEffect, useState } from 'react';
+
+/**
+ * Hook to follow compact mode.
+ * @returns True while this window is in compact mode
+ */
+export function useCompactMode(): boolean {
+    const [enabled, setEnabled] = useState(false);
+
+    useEffect(() => {
+        window.electronAPI
+            ?.getCompactMode()
+            .then((state) => setEnabled(state.enabled))
+            .catch((error) => console.error('Failed to get compact mode:', error));
+
+        return window.electronAPI?.onCompactModeChanged((state) => setEnabled(state.enabled));
+    }, []);
+
+    return enabled;
+}
@@ -0,0 +1,82 @@
+/**
+ * Unit tests for useExportProgress hook.
+ *
+ * Tests progress toast display, cancellation and dismissal on terminal stages.
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { renderHook, act } from '@testing-library/react';
+import type { ReactNode } from 'react';
+import { useExportProgress } from './useExportProgress';
+import { ToastProvider, useToast } from '../context/ToastContext';
+import { mockElectronAPI } from '../../../tests/unit/renderer/test/setup';
+import type { ExportProgress } from '../../shared/types/export';
+
+describe('useExportProgress', () => {
+    let emitProgress: (progress: ExportProgress) => void;
+
+    const wrapper = ({ children }: { children: ReactNode }) => <ToastProvider>{children}</ToastProvider>;
+
+    const renderProgressHook = () =>
+        renderHook(
+            () => {
+                useExportProgress();
+                return useToast();
+            },
+            { wrapper }
+        );
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockElectronAPI.onExportProgress.mockImplementation((callback: (progress: ExportProgress) => void) => {
+            emitProgress = callback;
+            return () => {};
+        });
+    });
+
+    it('subscribes to export progress events', () => {
+        renderProgressHook();
+        expect(mockElectronAPI.onExportProgress).toHaveBeenCalled();
+    });
+
+    it('shows a progress toast for a running job', () => {
+        const { result } = renderProgressHook();
+
+        act(() => emitProgress({ jobId: 'job-1', format: 'pdf', stage: 'rendering', percent: 30 }));
+
+        expect(result.current.toasts).toHaveLength(1);
+        expect(result.current.toasts[0]).toMatchObject({
+            id: 'export-job-1',
+            type: 'progress',
+            progress: 30,
+        });
+    });
+
+    it('updates the same toast as progress advances', () => {
+        const { result } = renderProgressHook();
+
+        act(() => emitProgress({ jobId: 'job-1', format: 'pdf', stage: 'rendering', percent: 30 }));
+        act(() => emitProgress({ jobId: 'job-1', format: 'pdf', stage: 'writing', percent: 90 }));
+
+        expect(result.current.toasts).toHaveLength(1);
+        expect(result.current.toasts[0]!.progress).toBe(90);
+    });
+
+    it('cancels the job from the toast action', () => {
+        const { result } = renderProgressHook();
+
+        act(() => emitProgress({ jobId: 'job-2', format: 'markdown', stage: 'writing', percent: 50 }));
+        act(() => result.current.toasts[0]!.actions![0]!.onClick());
+
+        expect(mockElectronAPI.cancelExport).toHaveBeenCalledWith('job-2');
+    });
+
+    it.each(['done', 'cancelled', 'error'] as const)('dismisses the toast when the job is %s', (stage) => {
+        const { result } = renderProgressHook();
+
+        act(() => emitProgress({ jobId: 'job-3', format: 'pdf', stage: 'rendering', percent: 30 }));
+        act(() => emitProgress({ jobId: 'job-3', format: 'pdf', stage, percent: 100 }));
+
+        expect(result.current.toasts).toHaveLength(0);
+    });
+});
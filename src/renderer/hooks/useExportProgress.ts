@@ -0,0 +1,59 @@
+/**
+ * Export Progress Hook
+ *
+ * Subscribes to export progress events from the main process and shows a
+ * progress toast with a Cancel action while an export job is running.
+ * Success/failure toasts are sent by the main process when the job ends.
+ *
+ * @module useExportProgress
+ */
+
+import { useEffect } from 'react';
+import { useToast } from '../context/ToastContext';
+import type { ExportFormat } from '../../shared/types/export';
+
+/** Human-readable labels for export stages */
+const STAGE_LABELS: Record<string, string> = {
+    preparing: 'Preparing document...',
+    rendering: 'Rendering PDF...',
+    writing: 'Writing file...',
+    uploading: 'Uploading page...',
+};
+
+/** Human-readable names for export formats */
+const FORMAT_LABELS: Record<ExportFormat, string> = { pdf: 'PDF', markdown: 'Markdown', confluence: 'Confluence' };
+
+/**
+ * Hook to display export progress toasts.
+ * Must be used inside a ToastProvider.
+ */
+export function useExportProgress(): void {
+    const { showToast, dismissToast } = useToast();
+
+    useEffect(() => {
+        if (!window.electronAPI?.onExportProgress) return undefined;
+
+        return window.electronAPI.onExportProgress((progress) => {
+            const toastId = `export-${progress.jobId}`;
+
+            if (progress.stage === 'done' || progress.stage === 'cancelled' || progress.stage === 'error') {
+                dismissToast(toastId);
+                return;
+            }
+
+            showToast({
+                id: toastId,
+                type: 'progress',
+                title: `Exporting to ${FORMAT_LABELS[progress.format]}`,
+                message: STAGE_LABELS[progress.stage] ?? 'Exporting...',
+                progress: progress.percent,
+                actions: [
+                    {
+                        label: 'Cancel',
+                        onClick: () => window.electronAPI?.cancelExport(progress.jobId),
+                    },
+                ],
+            });
+        });
+    }, [showToast, dismissToast]);
+}
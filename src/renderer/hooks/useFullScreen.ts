@@ -0,0 +1,29 @@
+/**
+ * Fullscreen Hook
+ *
+ * Follows whether this window is fullscreen, including macOS native
+ * fullscreen, so the titlebar can adapt.
+ *
+ * @module useFullScreen
+ */
+
+import { useEffect, useState } from 'react';
+
+/**
+ * Hook to follow fullscreen state.
+ * @returns True while this window is fullscreen
+ */
+export function useFullScreen(): boolean {
+    const [enabled, setEnabled] = useState(false);
+
+    useEffect(() => {
+        window.electronAPI
+            ?.isFullScreen()
+            .then(setEnabled)
+            .catch((error) => console.error('Failed to get fullscreen state:', error));
+
+        return window.electronAPI?.onFullScreenChanged((state) => setEnabled(state.enabled));
+    }, []);
+
+    return enabled;
+}
@@ -0,0 +1,30 @@
+/**
+ * Gemini Location Hook
+ *
+ * Tracks the Gemini view's URL, title and conversation so the titlebar can
+ * show the conversation title and enable actions that need a conversation.
+ *
+ * @module useGeminiLocation
+ */
+
+import { useEffect, useState } from 'react';
+import type { GeminiLocation } from '../../shared/types/navigation';
+
+/**
+ * Hook to follow the Gemini view's location.
+ * @returns The current location, or null until the main process has reported it
+ */
+export function useGeminiLocation(): GeminiLocation | null {
+    const [location, setLocation] = useState<GeminiLocation | null>(null);
+
+    useEffect(() => {
+        window.electronAPI
+            ?.getGeminiLocation()
+            .then(setLocation)
+            .catch((error) => console.error('Failed to get Gemini location:', error));
+
+        return window.electronAPI?.onGeminiLocationChanged(setLocation);
+    }, []);
+
+    return location;
+}
@@ -0,0 +1,29 @@
+/**
+ * Immersive Mode Hook
+ *
+ * Follows immersive mode, in which the main window is fullscreen and the
+ * titlebar is hidden so Gemini fills the screen.
+ *
+ * @module useImmersiveMode
+ */
+
+import { useEffect, useState } from 'react';
+
+/**
+ * Hook to follow immersive mode.
+ * @returns True while immersive mode is on
+ */
+export function useImmersiveMode(): boolean {
+    const [enabled, setEnabled] = useState(false);
+
+    useEffect(() => {
+        window.electronAPI
+            ?.getImmersiveMode()
+            .then((state) => setEnabled(state.enabled))
+            .catch((error) => console.error('Failed to get immersive mode:', error));
+
+        return window.electronAPI?.onImmersiveModeChanged((state) => setEnabled(state.enabled));
+    }, []);
+
+    return enabled;
+}
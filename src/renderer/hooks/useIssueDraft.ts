@@ -0,0 +1,43 @@
+/**
+ * Issue Draft Hook
+ *
+ * Holds the issue the main process drafts from the selected text or the
+ * latest answer, for review before it is created in GitHub or Jira.
+ *
+ * @module useIssueDraft
+ */
+
+import { useCallback, useEffect, useState } from 'react';
+import type { IssueCreateResult, IssueDraft } from '../../shared/types/issues';
+
+/**
+ * Result of the issue draft hook.
+ */
+export interface UseIssueDraftResult {
+    /** The draft awaiting review, or null when no issue is being created */
+    draft: IssueDraft | null;
+    /** Create the reviewed issue */
+    create: (draft: IssueDraft) => Promise<IssueCreateResult>;
+    /** Close the review dialog */
+    close: () => void;
+}
+
+/**
+ * Hook to review issue drafts before they are created.
+ */
+export function useIssueDraft(): UseIssueDraftResult {
+    const [draft, setDraft] = useState<IssueDraft | null>(null);
+
+    useEffect(() => {
+        return window.electronAPI?.onIssueDraft?.(setDraft);
+    }, []);
+
+    const create = useCallback(async (reviewed: IssueDraft): Promise<IssueCreateResult> => {
+        if (!window.electronAPI) return { success: false, error: 'Not available' };
+        return window.electronAPI.createIssue(reviewed);
+    }, []);
+
+    const close = useCallback(() => setDraft(null), []);
+
+    return { draft, create, close };
+}
@@ -0,0 +1,43 @@
+/**
+ * Share Preview Hook
+ *
+ * Holds the redacted answer the main process sends for review before it is
+ * posted to Slack or Teams. The preview is cleared once it is sent or cancelled.
+ *
+ * @module useSharePreview
+ */
+
+import { useCallback, useEffect, useState } from 'react';
+import type { SharePreview } from '../../shared/types/share';
+
+/**
+ * Result of the share preview hook.
+ */
+export interface UseSharePreviewResult {
+    /** The answer awaiting review, or null when nothing is being shared */
+    preview: SharePreview | null;
+    /** Post the reviewed answer and close the preview */
+    send: (markdown: string) => void;
+    /** Close the preview without posting */
+    cancel: () => void;
+}
+
+/**
+ * Hook to review answers before they are shared.
+ */
+export function useSharePreview(): UseSharePreviewResult {
+    const [preview, setPreview] = useState<SharePreview | null>(null);
+
+    useEffect(() => {
+        return window.electronAPI?.onSharePreview?.(setPreview);
+    }, []);
+
+    const send = useCallback((markdown: string) => {
+        window.electronAPI?.postShare(markdown);
+        setPreview(null);
+    }, []);
+
+    const cancel = useCallback(() => setPreview(null), []);
+
+    return { preview, send, cancel };
+}
@@ -0,0 +1,30 @@
+/**
+ * Titlebar Effect Hook
+ *
+ * Follows the native material shown behind the titlebar, so the titlebar
+ * turns transparent while mica, acrylic or vibrancy is in use.
+ *
+ * @module useTitlebarEffect
+ */
+
+import { useEffect, useState } from 'react';
+import type { TitlebarEffectState } from '../../shared/types/titlebar';
+
+/**
+ * Hook to follow the titlebar effect.
+ * @returns Effect in use and the effects this platform supports
+ */
+export function useTitlebarEffect(): TitlebarEffectState {
+    const [state, setState] = useState<TitlebarEffectState>({ effect: 'none', supported: ['none'] });
+
+    useEffect(() => {
+        window.electronAPI
+            ?.getTitlebarEffect()
+            .then(setState)
+            .catch((error) => console.error('Failed to get titlebar effect:', error));
+
+        return window.electronAPI?.onTitlebarEffectChanged(setState);
+    }, []);
+
+    return state;
+}
@@ -0,0 +1,30 @@
+/**
+ * Titlebar Height Hook
+ *
+ * Follows the configured titlebar height so the titlebar, and the Gemini
+ * view laid out beneath it, resize as soon as the setting changes.
+ *
+ * @module useTitlebarHeight
+ */
+
+import { useEffect, useState } from 'react';
+import { DEFAULT_TITLEBAR_HEIGHT } from '../../shared/types/titlebar';
+
+/**
+ * Hook to follow the titlebar height.
+ * @returns Height in logical pixels
+ */
+export function useTitlebarHeight(): number {
+    const [height, setHeight] = useState(DEFAULT_TITLEBAR_HEIGHT);
+
+    useEffect(() => {
+        window.electronAPI
+            ?.getTitlebarHeight()
+            .then(setHeight)
+            .catch((error) => console.error('Failed to get titlebar height:', error));
+
+        return window.electronAPI?.onTitlebarHeightChanged((data) => setHeight(data.height));
+    }, []);
+
+    return height;
+}
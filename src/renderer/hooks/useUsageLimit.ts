@@ -0,0 +1,40 @@
+/**
+ * Usage Limit Hook
+ *
+ * Tracks whether a daily usage limit (time or prompts) has been reached.
+ * The main process broadcasts changes when a limit is hit and again when it
+ * clears at the start of the next day.
+ *
+ * @module useUsageLimit
+ */
+
+import { useEffect, useState } from 'react';
+import type { UsageLimitStatus } from '../../shared/types/usage';
+
+/**
+ * Hook to get the current usage limit status.
+ * @returns The limit status, or null until it has loaded
+ */
+export function useUsageLimit(): UsageLimitStatus | null {
+    const [status, setStatus] = useState<UsageLimitStatus | null>(null);
+
+    useEffect(() => {
+        const api = window.electronAPI;
+        if (!api?.getUsageLimitStatus) return undefined;
+
+        let cancelled = false;
+        api.getUsageLimitStatus()
+            .then((initial) => {
+                if (!cancelled) setStatus(initial);
+            })
+            .catch((error) => console.error('Failed to load usage limit status:', error));
+
+        const unsubscribe = api.onUsageLimitChanged?.(setStatus);
+        return () => {
+            cancelled = true;
+            unsubscribe?.();
+        };
+    }, []);
+
+    return status;
+}
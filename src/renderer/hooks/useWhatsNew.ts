@@ -0,0 +1,33 @@
+/**
+ * What's New Hook
+ *
+ * Loads release notes the user has not acknowledged yet, once per window.
+ *
+ * @module useWhatsNew
+ */
+
+import { useCallback, useEffect, useState } from 'react';
+import type { WhatsNew } from '../../shared/types/whats-new';
+
+/**
+ * Hook to show what changed since the last acknowledged version.
+ * @returns The pending notes (null when there is nothing to show) and a
+ * callback that marks them as read
+ */
+export function useWhatsNew() {
+    const [whatsNew, setWhatsNew] = useState<WhatsNew | null>(null);
+
+    useEffect(() => {
+        window.electronAPI
+            ?.getWhatsNew()
+            .then((result) => setWhatsNew(result.entries.length > 0 ? result : null))
+            .catch((error) => console.error("Failed to get what's new:", error));
+    }, []);
+
+    const acknowledge = useCallback(() => {
+        window.electronAPI?.acknowledgeWhatsNew();
+        setWhatsNew(null);
+    }, []);
+
+    return { whatsNew, acknowledge };
+}
@@ -37,6 +37,10 @@ export const TITLEBAR_TEST_IDS = {
     MINIMIZE_BUTTON: 'minimize-button',
     MAXIMIZE_BUTTON: 'maximize-button',
     CLOSE_BUTTON: 'close-button',
+    BACK_BUTTON: 'back-button',
+    FORWARD_BUTTON: 'forward-button',
+    RELOAD_BUTTON: 'reload-button',
+    HOME_BUTTON: 'home-button',
     APP_ICON: 'app-icon',
     /** Dynamic: Use with menu label, e.g., "File" */
     menuButton: (label: string) => `menu-button-${label}`,
@@ -89,6 +93,8 @@ export const QUICK_CHAT_TEST_IDS = {
     QUICK_CHAT_INPUT: 'quick-chat-input',
     QUICK_CHAT_SUBMIT: 'quick-chat-submit',
     QUICK_CHAT_GHOST_TEXT: 'quick-chat-ghost-text',
+    QUICK_CHAT_TRANSLATE: 'quick-chat-translate',
+    QUICK_CHAT_LANGUAGE: 'quick-chat-language',
 } as const;
 
 // =============================================================================
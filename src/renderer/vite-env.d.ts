@@ -6,8 +6,26 @@ interface Window {
         maximizeWindow: () => void;
         closeWindow: () => void;
         isMaximized: () => Promise<boolean>;
+        openNewWindow: () => void;
+        toggleFullScreen: () => void;
+        isFullScreen: () => Promise<boolean>;
+        onFullScreenChanged: (callback: (data: { enabled: boolean }) => void) => () => void;
+        getDisplays: () => Promise<import('../shared/types/displays').DisplayInfo[]>;
+        moveToDisplay: (index: number) => void;
         openOptions: (tab?: 'settings' | 'about') => void;
         openGoogleSignIn: () => Promise<void>;
+        quitApp: () => void;
+        getCloseToTray: () => Promise<boolean>;
+        setCloseToTray: (enabled: boolean) => void;
+        getCapabilityReport: () => Promise<import('../shared/types/capabilities').CapabilityReport>;
+        getWhatsNew: () => Promise<import('../shared/types/whats-new').WhatsNew>;
+        acknowledgeWhatsNew: () => void;
+        getDiskSpaceStatus: () => Promise<import('../shared/types/disk-space').DiskSpaceStatus>;
+        getFocusSessionStatus: () => Promise<import('../shared/types/focus-session').FocusSessionStatus>;
+        toggleFocusSession: () => void;
+        onFocusSessionChanged: (
+            callback: (status: import('../shared/types/focus-session').FocusSessionStatus) => void
+        ) => () => void;
 
         // Theme API
         getTheme: () => Promise<{
@@ -24,10 +42,26 @@ interface Window {
         hideQuickChat: () => void;
         cancelQuickChat: () => void;
         onQuickChatExecute: (callback: (text: string) => void) => () => void;
+        translateQuickChat: (text: string, language: import('../shared/types/translation').TranslateLanguage) => void;
+        getTranslateLanguage: () => Promise<import('../shared/types/translation').TranslateLanguage>;
 
         // Gemini Iframe Navigation API (for Quick Chat integration)
         onGeminiNavigate: (callback: (data: { url: string; text: string }) => void) => () => void;
         signalGeminiReady: (text: string) => void;
+        reloadGemini: (ignoreCache?: boolean) => void;
+        goBackGemini: () => void;
+        goForwardGemini: () => void;
+        goHomeGemini: () => void;
+        printGemini: () => void;
+        reloadUserStyles: () => void;
+        getGeminiNavigationState: () => Promise<import('../shared/types/navigation').GeminiNavigationState>;
+        onGeminiNavigationChanged: (
+            callback: (state: import('../shared/types/navigation').GeminiNavigationState) => void
+        ) => () => void;
+        getGeminiLocation: () => Promise<import('../shared/types/navigation').GeminiLocation>;
+        onGeminiLocationChanged: (
+            callback: (location: import('../shared/types/navigation').GeminiLocation) => void
+        ) => () => void;
 
         // Individual Hotkeys API
         getIndividualHotkeys: () => Promise<{
@@ -74,10 +108,43 @@ interface Window {
         setAlwaysOnTop: (enabled: boolean) => void;
         onAlwaysOnTopChanged: (callback: (data: { enabled: boolean }) => void) => () => void;
 
+        // Immersive Mode API
+        getImmersiveMode: () => Promise<{ enabled: boolean }>;
+        toggleImmersiveMode: () => void;
+        onImmersiveModeChanged: (callback: (data: { enabled: boolean }) => void) => () => void;
+        getCompactMode: () => Promise<{ enabled: boolean }>;
+        toggleCompactMode: () => void;
+        onCompactModeChanged: (callback: (data: { enabled: boolean }) => void) => () => void;
+        getWindowOpacity: () => Promise<{ opacity: number }>;
+        setWindowOpacity: (opacity: number) => void;
+        onWindowOpacityChanged: (callback: (data: { opacity: number }) => void) => () => void;
+        getOverlayMode: () => Promise<{ enabled: boolean }>;
+        toggleOverlayMode: () => void;
+        onOverlayModeChanged: (callback: (data: { enabled: boolean }) => void) => () => void;
+
+        // Titlebar API
+        getTitlebarState: () => Promise<import('../shared/types/titlebar').TitlebarState>;
+        onTitlebarStateChanged: (
+            callback: (state: import('../shared/types/titlebar').TitlebarState) => void
+        ) => () => void;
+        getTitlebarHeight: () => Promise<number>;
+        setTitlebarHeight: (height: number) => Promise<boolean>;
+        onTitlebarHeightChanged: (callback: (data: { height: number }) => void) => () => void;
+        getTitlebarEffect: () => Promise<import('../shared/types/titlebar').TitlebarEffectState>;
+        setTitlebarEffect: (effect: import('../shared/types/titlebar').TitlebarEffect) => Promise<boolean>;
+        onTitlebarEffectChanged: (
+            callback: (data: import('../shared/types/titlebar').TitlebarEffectState) => void
+        ) => () => void;
+
+        // App Events API
+        getRecentEvents: (since?: number) => Promise<import('../shared/types/events').AppEvent[]>;
+
         // Zoom API
         getZoomLevel: () => Promise<number>;
         zoomIn: () => Promise<number>;
         zoomOut: () => Promise<number>;
+        setZoomLevel: (level: number) => Promise<number>;
+        resetZoom: () => Promise<number>;
         onZoomLevelChanged: (callback: (level: number) => void) => () => void;
 
         // Auto-Update API
@@ -183,9 +250,97 @@ interface Window {
         getResponseNotificationsEnabled: () => Promise<boolean>;
         setResponseNotificationsEnabled: (enabled: boolean) => void;
 
+        // Auto-Copy API
+        getAutoCopySettings: () => Promise<{ mode: 'off' | 'quick-chat' | 'all'; format: 'markdown' | 'text' }>;
+        setAutoCopySettings: (settings: {
+            mode?: 'off' | 'quick-chat' | 'all';
+            format?: 'markdown' | 'text';
+        }) => void;
+
         // Chat Export API (Structured)
         exportChatToPdf: () => void;
         exportChatToMarkdown: () => void;
+        exportChatToConfluence: (target?: import('../shared/types/confluence').ConfluenceTarget) => void;
+        exportCanvasToMarkdown: () => void;
+        exportResearchReport: (format: import('../shared/types/export').ResearchReportFormat) => void;
+        getConfluenceSettings: () => Promise<import('../shared/types/confluence').ConfluenceSettings>;
+        setConfluenceSettings: (settings: import('../shared/types/confluence').ConfluenceSettings) => void;
+        getExportFilenameTemplate: () => Promise<string>;
+        setExportFilenameTemplate: (template: string) => void;
+        cancelExport: (jobId: string) => void;
+        onExportProgress: (callback: (progress: import('../shared/types/export').ExportProgress) => void) => () => void;
+
+        // Logging API
+        getLogLevel: () => Promise<string>;
+        setLogLevel: (spec: string) => void;
+
+        // Pinned Answer Cards API
+        pinLastAnswer: () => void;
+
+        // Answer Sharing API
+        requestSharePreview: () => void;
+        onSharePreview: (callback: (preview: import('../shared/types/share').SharePreview) => void) => () => void;
+        postShare: (markdown: string) => void;
+        getShareSettings: () => Promise<import('../shared/types/share').ShareSettings>;
+        setShareSettings: (settings: import('../shared/types/share').ShareSettings) => void;
+        shareAsQr: () => void;
+
+        // Issue Creation API
+        requestIssueDraft: () => void;
+        onIssueDraft: (callback: (draft: import('../shared/types/issues').IssueDraft) => void) => () => void;
+        createIssue: (
+            draft: import('../shared/types/issues').IssueDraft
+        ) => Promise<import('../shared/types/issues').IssueCreateResult>;
+        getIssueTrackerSettings: () => Promise<import('../shared/types/issues').IssueTrackerSettings>;
+        setIssueTrackerSettings: (settings: import('../shared/types/issues').IssueTrackerSettings) => void;
+
+        // Screenshot API
+        captureScreenshot: (options: import('../shared/types/screenshot').ScreenshotOptions) => void;
+
+        // Userscripts API
+        listUserscripts: () => Promise<import('../shared/types/userscripts').UserscriptInfo[]>;
+        setUserscriptEnabled: (id: string, enabled: boolean) => void;
+        openUserscriptsFolder: () => void;
+
+        // Settings Maintenance API
+        resetSettings: (
+            section: import('../shared/types/settings-reset').SettingsResetSection
+        ) => Promise<import('../shared/types/settings-reset').SettingsResetResult>;
+        getSettingsHistory: () => Promise<import('../shared/types/settings-history').SettingsChange[]>;
+        undoSettingsChange: (count: number) => Promise<import('../shared/types/settings-history').SettingsUndoResult>;
+
+        // Upstream Proxy API
+        getProxySettings: () => Promise<import('../shared/types/proxy').ProxySettings>;
+        setProxySettings: (settings: import('../shared/types/proxy').ProxySettings) => void;
+        testProxyConnection: () => Promise<import('../shared/types/proxy').ProxyTestResult>;
+
+        // Usage Tracking API
+        getUsageSettings: () => Promise<import('../shared/types/usage').UsageSettings>;
+        setUsageSettings: (settings: Partial<import('../shared/types/usage').UsageSettings>) => void;
+        getUsageReport: () => Promise<import('../shared/types/usage').UsageReport>;
+        getUsageLimitStatus: () => Promise<import('../shared/types/usage').UsageLimitStatus>;
+        setUsageLimits: (limits: import('../shared/types/usage').UsageLimits) => void;
+        onUsageLimitChanged: (
+            callback: (status: import('../shared/types/usage').UsageLimitStatus) => void
+        ) => () => void;
+        getGeminiQuotaStatus: () => Promise<import('../shared/types/usage').GeminiQuotaStatus>;
+        setGeminiQuota: (settings: import('../shared/types/usage').GeminiQuotaSettings) => void;
+        onGeminiQuotaChanged: (
+            callback: (status: import('../shared/types/usage').GeminiQuotaStatus) => void
+        ) => () => void;
+
+        // User-Agent API
+        getUserAgentSettings: () => Promise<import('../shared/types/user-agent').UserAgentSettings>;
+        setUserAgentSettings: (settings: import('../shared/types/user-agent').UserAgentSettings) => void;
+        getGeminiLanguage: () => Promise<string>;
+        setGeminiLanguage: (tag: string) => void;
+        getSpellCheckSettings: () => Promise<import('../shared/types/language').SpellCheckSettings>;
+        setSpellCheckEnabled: (enabled: boolean) => void;
+        setSpellCheckLanguages: (languages: string[]) => void;
+
+        // Link Handling API
+        getAllowedHosts: () => Promise<string>;
+        setAllowedHosts: (hosts: string) => void;
 
         platform: string;
         isElectron: boolean;
@@ -18,6 +18,12 @@ export const IPC_CHANNELS = {
     WINDOW_CLOSE: 'window-close',
     WINDOW_SHOW: 'window-show',
     WINDOW_IS_MAXIMIZED: 'window-is-maximized',
+    WINDOW_NEW: 'window-new',
+    WINDOW_TOGGLE_FULLSCREEN: 'window-toggle-fullscreen',
+    WINDOW_IS_FULLSCREEN: 'window-is-fullscreen',
+    WINDOW_FULLSCREEN_CHANGED: 'window-fullscreen-changed',
+    WINDOW_GET_DISPLAYS: 'window-get-displays',
+    WINDOW_MOVE_TO_DISPLAY: 'window-move-to-display',
 
     // Theme
     THEME_GET: 'theme:get',
@@ -27,26 +33,84 @@ export const IPC_CHANNELS = {
     // App
     OPEN_OPTIONS: 'open-options-window',
     OPEN_GOOGLE_SIGNIN: 'open-google-signin',
+    APP_QUIT: 'app:quit',
+    CLOSE_TO_TRAY_GET: 'close-to-tray:get',
+    CLOSE_TO_TRAY_SET: 'close-to-tray:set',
+    CAPABILITIES_GET_REPORT: 'capabilities:get-report',
+    WHATS_NEW_GET: 'whats-new:get',
+    WHATS_NEW_ACKNOWLEDGE: 'whats-new:acknowledge',
+    DISK_SPACE_GET_STATUS: 'disk-space:get-status',
+    DISK_SPACE_CHANGED: 'disk-space:changed',
+    FOCUS_SESSION_GET_STATUS: 'focus-session:get-status',
+    FOCUS_SESSION_TOGGLE: 'focus-session:toggle',
+    FOCUS_SESSION_CHANGED: 'focus-session:changed',
 
     // Quick Chat
     QUICK_CHAT_SUBMIT: 'quick-chat:submit',
     QUICK_CHAT_HIDE: 'quick-chat:hide',
     QUICK_CHAT_CANCEL: 'quick-chat:cancel',
     QUICK_CHAT_EXECUTE: 'quick-chat:execute',
+    QUICK_CHAT_TRANSLATE: 'quick-chat:translate',
+    QUICK_CHAT_TRANSLATE_LANGUAGE_GET: 'quick-chat:translate-language:get',
 
     // Gemini Iframe Navigation (for Quick Chat integration)
     GEMINI_NAVIGATE: 'gemini:navigate',
     GEMINI_READY: 'gemini:ready',
+    GEMINI_RELOAD: 'gemini:reload',
+    GEMINI_GO_BACK: 'gemini:go-back',
+    GEMINI_GO_FORWARD: 'gemini:go-forward',
+    GEMINI_GO_HOME: 'gemini:go-home',
+    GEMINI_PRINT: 'gemini:print',
+    GEMINI_RELOAD_USER_STYLES: 'gemini:reload-user-styles',
+    GEMINI_GET_NAVIGATION_STATE: 'gemini:get-navigation-state',
+    GEMINI_NAVIGATION_CHANGED: 'gemini:navigation-changed',
+    GEMINI_GET_LOCATION: 'gemini:get-location',
+    GEMINI_LOCATION_CHANGED: 'gemini:location-changed',
+    GEMINI_GET_ALLOWED_HOSTS: 'gemini:get-allowed-hosts',
+    GEMINI_SET_ALLOWED_HOSTS: 'gemini:set-allowed-hosts',
 
     // Always On Top
     ALWAYS_ON_TOP_GET: 'always-on-top:get',
     ALWAYS_ON_TOP_SET: 'always-on-top:set',
     ALWAYS_ON_TOP_CHANGED: 'always-on-top:changed',
 
+    // Immersive Mode
+    IMMERSIVE_MODE_GET: 'immersive-mode:get',
+    IMMERSIVE_MODE_TOGGLE: 'immersive-mode:toggle',
+    IMMERSIVE_MODE_CHANGED: 'immersive-mode:changed',
+
+    // Compact Mode
+    COMPACT_MODE_GET: 'compact-mode:get',
+    COMPACT_MODE_TOGGLE: 'compact-mode:toggle',
+    COMPACT_MODE_CHANGED: 'compact-mode:changed',
+
+    // Window opacity and overlay mode
+    WINDOW_OPACITY_GET: 'window-opacity:get',
+    WINDOW_OPACITY_SET: 'window-opacity:set',
+    WINDOW_OPACITY_CHANGED: 'window-opacity:changed',
+    OVERLAY_MODE_GET: 'overlay-mode:get',
+    OVERLAY_MODE_TOGGLE: 'overlay-mode:toggle',
+    OVERLAY_MODE_CHANGED: 'overlay-mode:changed',
+
+    // Titlebar
+    TITLEBAR_GET_STATE: 'titlebar:get-state',
+    TITLEBAR_STATE_CHANGED: 'titlebar:state-changed',
+    TITLEBAR_GET_HEIGHT: 'titlebar:get-height',
+    TITLEBAR_SET_HEIGHT: 'titlebar:set-height',
+    TITLEBAR_HEIGHT_CHANGED: 'titlebar:height-changed',
+    TITLEBAR_GET_EFFECT: 'titlebar:get-effect',
+    TITLEBAR_SET_EFFECT: 'titlebar:set-effect',
+    TITLEBAR_EFFECT_CHANGED: 'titlebar:effect-changed',
+
+    // App events
+    EVENTS_GET_RECENT: 'events:get-recent',
+
     // Zoom
     ZOOM_GET_LEVEL: 'zoom:get-level',
     ZOOM_IN: 'zoom:zoom-in',
     ZOOM_OUT: 'zoom:zoom-out',
+    ZOOM_SET_LEVEL: 'zoom:set-level',
+    ZOOM_RESET: 'zoom:reset',
     ZOOM_LEVEL_CHANGED: 'zoom:level-changed',
 
     // Individual Hotkeys
@@ -78,6 +142,81 @@ export const IPC_CHANNELS = {
 
     EXPORT_CHAT_PDF: 'export-chat:pdf',
     EXPORT_CHAT_MARKDOWN: 'export-chat:markdown',
+    EXPORT_CHAT_CONFLUENCE: 'export-chat:confluence',
+    EXPORT_CANVAS_MARKDOWN: 'export-chat:canvas-markdown',
+    EXPORT_RESEARCH_REPORT: 'export-chat:research-report',
+    EXPORT_FILENAME_TEMPLATE_GET: 'export-chat:filename-template:get',
+    EXPORT_FILENAME_TEMPLATE_SET: 'export-chat:filename-template:set',
+    EXPORT_CONFLUENCE_SETTINGS_GET: 'export-chat:confluence-settings:get',
+    EXPORT_CONFLUENCE_SETTINGS_SET: 'export-chat:confluence-settings:set',
+    EXPORT_PROGRESS: 'export-chat:progress',
+    EXPORT_CANCEL: 'export-chat:cancel',
+
+    // Logging
+    LOG_GET_LEVEL: 'log:get-level',
+    LOG_SET_LEVEL: 'log:set-level',
+
+    // Pinned answer cards
+    CARDS_PIN: 'cards:pin',
+    CARDS_COPY: 'cards:copy',
+    CARDS_CLOSE: 'cards:close',
+
+    // Answer sharing (Slack/Teams)
+    SHARE_PREVIEW_REQUEST: 'share:preview-request',
+    SHARE_PREVIEW: 'share:preview',
+    SHARE_POST: 'share:post',
+    SHARE_SETTINGS_GET: 'share:settings:get',
+    SHARE_SETTINGS_SET: 'share:settings:set',
+    SHARE_QR: 'share:qr',
+
+    // Issue creation (GitHub/Jira)
+    ISSUES_DRAFT_REQUEST: 'issues:draft-request',
+    ISSUES_DRAFT: 'issues:draft',
+    ISSUES_CREATE: 'issues:create',
+    ISSUES_SETTINGS_GET: 'issues:settings:get',
+    ISSUES_SETTINGS_SET: 'issues:settings:set',
+
+    // Screenshots
+    SCREENSHOT_CAPTURE: 'screenshot:capture',
+
+    // Userscripts
+    USERSCRIPTS_LIST: 'userscripts:list',
+    USERSCRIPTS_SET_ENABLED: 'userscripts:set-enabled',
+    USERSCRIPTS_OPEN_FOLDER: 'userscripts:open-folder',
+
+    // Settings maintenance
+    SETTINGS_RESET: 'settings:reset',
+    SETTINGS_GET_HISTORY: 'settings:get-history',
+    SETTINGS_UNDO: 'settings:undo',
+
+    // Upstream proxy
+    PROXY_GET_SETTINGS: 'proxy:get-settings',
+    PROXY_SET_SETTINGS: 'proxy:set-settings',
+    PROXY_TEST: 'proxy:test',
+
+    // Usage tracking
+    USAGE_GET_SETTINGS: 'usage:get-settings',
+    USAGE_SET_SETTINGS: 'usage:set-settings',
+    USAGE_GET_REPORT: 'usage:get-report',
+    USAGE_GET_LIMITS: 'usage:get-limits',
+    USAGE_SET_LIMITS: 'usage:set-limits',
+    USAGE_LIMIT_CHANGED: 'usage:limit-changed',
+    USAGE_GET_QUOTA: 'usage:get-quota',
+    USAGE_SET_QUOTA: 'usage:set-quota',
+    USAGE_QUOTA_CHANGED: 'usage:quota-changed',
+
+    // User-Agent override
+    USER_AGENT_GET_SETTINGS: 'user-agent:get-settings',
+    USER_AGENT_SET_SETTINGS: 'user-agent:set-settings',
+
+    // Gemini language override
+    GEMINI_LANGUAGE_GET: 'gemini-language:get',
+    GEMINI_LANGUAGE_SET: 'gemini-language:set',
+
+    // Spell checker
+    SPELL_CHECK_GET: 'spell-check:get',
+    SPELL_CHECK_SET_ENABLED: 'spell-check:set-enabled',
+    SPELL_CHECK_SET_LANGUAGES: 'spell-check:set-languages',
 
     // Toast (main process → renderer notifications)
     TOAST_SHOW: 'toast:show',
@@ -89,6 +228,10 @@ export const IPC_CHANNELS = {
     RESPONSE_NOTIFICATIONS_GET_ENABLED: 'response-notifications:get-enabled',
     RESPONSE_NOTIFICATIONS_SET_ENABLED: 'response-notifications:set-enabled',
 
+    // Auto-Copy (answer-to-clipboard)
+    AUTO_COPY_GET_SETTINGS: 'auto-copy:get-settings',
+    AUTO_COPY_SET_SETTINGS: 'auto-copy:set-settings',
+
     // Text Prediction (local LLM inference)
     TEXT_PREDICTION_GET_ENABLED: 'text-prediction:get-enabled',
     TEXT_PREDICTION_SET_ENABLED: 'text-prediction:set-enabled',
@@ -0,0 +1,38 @@
+/**
+ * Auto-Copy Types
+ *
+ * Shared types for copying completed answers to the clipboard automatically.
+ */
+
+/**
+ * Which answers are copied automatically.
+ * - 'off': Never copy
+ * - 'quick-chat': Only answers to prompts sent from Quick Chat
+ * - 'all': Every completed answer
+ */
+export type AutoCopyMode = 'off' | 'quick-chat' | 'all';
+
+/**
+ * Clipboard format for copied answers.
+ */
+export type AutoCopyFormat = 'markdown' | 'text';
+
+/**
+ * Auto-copy settings.
+ */
+export interface AutoCopySettings {
+    /** Which answers to copy */
+    mode: AutoCopyMode;
+    /** Clipboard format */
+    format: AutoCopyFormat;
+}
+
+/**
+ * All valid auto-copy modes, for validation.
+ */
+export const AUTO_COPY_MODES: AutoCopyMode[] = ['off', 'quick-chat', 'all'];
+
+/**
+ * All valid auto-copy formats, for validation.
+ */
+export const AUTO_COPY_FORMATS: AutoCopyFormat[] = ['markdown', 'text'];
@@ -0,0 +1,43 @@
+/**
+ * Capability Types
+ *
+ * Shared types for the capability report: which optional features work on
+ * this platform and build, so the UI can hide or disable actions up front
+ * instead of failing when they are clicked.
+ */
+
+/**
+ * Optional features whose availability depends on the platform or build.
+ */
+export const CAPABILITY_IDS = [
+    'globalHotkeys',
+    'notifications',
+    'keychain',
+    'tray',
+    'autoUpdate',
+    'windowOpacity',
+] as const;
+
+export type CapabilityId = (typeof CAPABILITY_IDS)[number];
+
+/**
+ * Whether one feature is available.
+ */
+export interface CapabilityStatus {
+    available: boolean;
+    /** Why the feature is unavailable, for showing next to the disabled action */
+    reason?: string;
+}
+
+/**
+ * Availability of each optional feature, with the runtime versions it was
+ * worked out for.
+ */
+export interface CapabilityReport {
+    platform: string;
+    versions: {
+        electron: string;
+        chrome: string;
+    };
+    capabilities: Record<CapabilityId, CapabilityStatus>;
+}
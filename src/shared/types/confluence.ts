@@ -0,0 +1,40 @@
+/**
+ * Confluence Types
+ *
+ * Shared types for publishing exported chats as Confluence pages.
+ */
+
+/**
+ * Confluence connection and target settings.
+ */
+export interface ConfluenceSettings {
+    /** Site URL, e.g. 'https://example.atlassian.net/wiki' */
+    baseUrl: string;
+    /** Account email for Confluence Cloud; leave empty to use the token as a personal access token */
+    email: string;
+    /** API token (Cloud) or personal access token (Server/Data Center) */
+    apiToken: string;
+    /** Key of the space pages are created in */
+    spaceKey: string;
+    /** Optional ID of the page new pages are created under */
+    parentId: string;
+}
+
+/**
+ * Per-export override of where the page is published.
+ */
+export interface ConfluenceTarget {
+    spaceKey?: string;
+    parentId?: string;
+}
+
+/**
+ * Default Confluence settings (not configured).
+ */
+export const DEFAULT_CONFLUENCE_SETTINGS: ConfluenceSettings = {
+    baseUrl: '',
+    email: '',
+    apiToken: '',
+    spaceKey: '',
+    parentId: '',
+};
@@ -0,0 +1,29 @@
+/**
+ * Disk Space Types
+ *
+ * Shared types for the low disk space guard on exports, downloads and other
+ * writes.
+ */
+
+/**
+ * Default free space (in MB) below which writes are refused and background
+ * jobs pause.
+ */
+export const DEFAULT_LOW_DISK_SPACE_MB = 500;
+
+/**
+ * Error code carried by errors thrown when a write is refused for lack of space.
+ */
+export const DISK_FULL_ERROR_CODE = 'DISK_FULL';
+
+/**
+ * Free space on the app data volume.
+ */
+export interface DiskSpaceStatus {
+    /** Free bytes available to the app, or null if it could not be read */
+    freeBytes: number | null;
+    /** Free space below which the guard kicks in; 0 when the guard is off */
+    thresholdBytes: number;
+    /** Whether free space is below the threshold */
+    low: boolean;
+}
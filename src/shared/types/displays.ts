@@ -0,0 +1,24 @@
+/**
+ * Display Types
+ *
+ * Describes the connected monitors so the renderer can offer to move the
+ * main window to one of them.
+ */
+
+/**
+ * A connected monitor.
+ */
+export interface DisplayInfo {
+    /** Position in the list, used to move a window to this display */
+    index: number;
+    /** Name shown to the user, e.g. "Display 2 (1920×1080)" */
+    label: string;
+    /** Usable area in screen coordinates, excluding taskbars and docks */
+    workArea: { x: number; y: number; width: number; height: number };
+    /** Device pixels per screen pixel */
+    scaleFactor: number;
+    /** Whether this is the primary display */
+    primary: boolean;
+    /** Whether the main window is on this display */
+    current: boolean;
+}
@@ -0,0 +1,57 @@
+/**
+ * App Event Types
+ *
+ * Typed payloads for the state-change events the main process publishes to
+ * every window. Event names are the IPC channels the events are sent on, so
+ * existing `on...Changed` listeners keep working.
+ */
+
+import type { HotkeyAccelerators, IndividualHotkeySettings } from './hotkeys';
+import type { GeminiLocation, GeminiNavigationState } from './navigation';
+import type { ThemeData } from './theme';
+import type { TitlebarEffectState, TitlebarState } from './titlebar';
+import type { DiskSpaceStatus } from './disk-space';
+import type { FocusSessionStatus } from './focus-session';
+import type { GeminiQuotaStatus, UsageLimitStatus } from './usage';
+
+/**
+ * Payload of each app event, keyed by event name.
+ */
+export interface AppEventPayloads {
+    'theme:changed': ThemeData;
+    'always-on-top:changed': { enabled: boolean };
+    'immersive-mode:changed': { enabled: boolean };
+    'window-opacity:changed': { opacity: number };
+    'overlay-mode:changed': { enabled: boolean };
+    'zoom:level-changed': number;
+    'hotkeys:individual:changed': IndividualHotkeySettings;
+    'hotkeys:accelerator:changed': HotkeyAccelerators;
+    'gemini:navigation-changed': GeminiNavigationState;
+    'gemini:location-changed': GeminiLocation;
+    'titlebar:state-changed': TitlebarState;
+    'titlebar:height-changed': { height: number };
+    'titlebar:effect-changed': TitlebarEffectState;
+    'usage:limit-changed': UsageLimitStatus;
+    'usage:quota-changed': GeminiQuotaStatus;
+    'disk-space:changed': DiskSpaceStatus;
+    'focus-session:changed': FocusSessionStatus;
+}
+
+/**
+ * Name of an app event.
+ */
+export type AppEventName = keyof AppEventPayloads;
+
+/**
+ * A published app event.
+ */
+export type AppEvent = {
+    [K in AppEventName]: {
+        /** Increases by one with every event; pass the last one seen to catch up */
+        seq: number;
+        name: K;
+        payload: AppEventPayloads[K];
+        /** When the event was published (ms since epoch) */
+        timestamp: number;
+    };
+}[AppEventName];
@@ -0,0 +1,40 @@
+/**
+ * Export Types
+ *
+ * Shared types for chat export progress reporting across main and renderer processes.
+ */
+
+/**
+ * Export output format.
+ */
+export type ExportFormat = 'pdf' | 'markdown' | 'confluence';
+
+/**
+ * Output format of a Deep Research report export.
+ */
+export type ResearchReportFormat = Extract<ExportFormat, 'pdf' | 'markdown'>;
+
+/**
+ * All supported research report formats, for validation.
+ */
+export const RESEARCH_REPORT_FORMATS: ResearchReportFormat[] = ['pdf', 'markdown'];
+
+/**
+ * Stage of a running export job.
+ * Terminal stages are 'done', 'cancelled' and 'error'.
+ */
+export type ExportStage = 'preparing' | 'rendering' | 'writing' | 'uploading' | 'done' | 'cancelled' | 'error';
+
+/**
+ * Progress payload sent on the export-chat:progress channel.
+ */
+export interface ExportProgress {
+    /** Unique job identifier (pass to cancelExport) */
+    jobId: string;
+    /** Output format of the job */
+    format: ExportFormat;
+    /** Current stage */
+    stage: ExportStage;
+    /** Overall progress percentage (0-100) */
+    percent: number;
+}
@@ -0,0 +1,43 @@
+/**
+ * Focus Session Types
+ *
+ * Shared types for focus sessions: a timed block of work during which
+ * notifications and badges are held back and the prompts and answers are
+ * logged, so the log can be saved when the session ends.
+ */
+
+/** Session length used when none is given, in minutes */
+export const DEFAULT_FOCUS_SESSION_MINUTES = 25;
+
+/** Shortest session, in minutes */
+export const MIN_FOCUS_SESSION_MINUTES = 1;
+
+/** Longest session, in minutes */
+export const MAX_FOCUS_SESSION_MINUTES = 240;
+
+/**
+ * A prompt and the answer to it, logged during a focus session.
+ */
+export interface FocusSessionEntry {
+    /** When the answer finished (ms since epoch) */
+    time: number;
+    /** The prompt, empty if it could not be read */
+    prompt: string;
+    /** The answer as Markdown */
+    answer: string;
+    /** Model that wrote the answer, if known */
+    modelName?: string;
+}
+
+/**
+ * Current focus session state.
+ */
+export interface FocusSessionStatus {
+    active: boolean;
+    /** When the session started (ms since epoch), null when none is active */
+    startedAt: number | null;
+    /** When the session ends (ms since epoch), null when none is active */
+    endsAt: number | null;
+    /** Answers logged so far */
+    entryCount: number;
+}
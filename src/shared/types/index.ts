@@ -13,6 +13,29 @@ export * from './ipc';
 export * from './toast';
 export * from './text-prediction';
 export * from './notifications';
+export * from './translation';
+export * from './auto-copy';
+export * from './export';
+export * from './settings-reset';
+export * from './settings-history';
+export * from './proxy';
+export * from './usage';
+export * from './user-agent';
+export * from './navigation';
+export * from './confluence';
+export * from './share';
+export * from './issues';
+export * from './screenshot';
+export * from './userscripts';
+export * from './language';
+export * from './titlebar';
+export * from './events';
+export * from './capabilities';
+export * from './overlay';
+export * from './whats-new';
+export * from './disk-space';
+export * from './displays';
+export * from './focus-session';
 
 // Re-export ElectronAPI explicitly for easier imports
 export type { ElectronAPI } from './ipc';
@@ -10,6 +10,35 @@ import type { HotkeyId, IndividualHotkeySettings, HotkeyAccelerators, HotkeySett
 import type { UpdateInfo, DownloadProgress } from './updates';
 import type { ToastPayload } from './toast';
 import type { TextPredictionSettings } from './text-prediction';
+import type { TranslateLanguage } from './translation';
+import type { AutoCopySettings } from './auto-copy';
+import type { ExportProgress, ResearchReportFormat } from './export';
+import type { SettingsResetSection, SettingsResetResult } from './settings-reset';
+import type { SettingsChange, SettingsUndoResult } from './settings-history';
+import type { ProxySettings, ProxyTestResult } from './proxy';
+import type {
+    GeminiQuotaSettings,
+    GeminiQuotaStatus,
+    UsageLimits,
+    UsageLimitStatus,
+    UsageReport,
+    UsageSettings,
+} from './usage';
+import type { UserAgentSettings } from './user-agent';
+import type { GeminiLocation, GeminiNavigationState } from './navigation';
+import type { ConfluenceSettings, ConfluenceTarget } from './confluence';
+import type { SharePreview, ShareSettings } from './share';
+import type { IssueCreateResult, IssueDraft, IssueTrackerSettings } from './issues';
+import type { ScreenshotOptions } from './screenshot';
+import type { UserscriptInfo } from './userscripts';
+import type { TitlebarEffect, TitlebarEffectState, TitlebarState } from './titlebar';
+import type { AppEvent } from './events';
+import type { CapabilityReport } from './capabilities';
+import type { WhatsNew } from './whats-new';
+import type { DisplayInfo } from './displays';
+import type { FocusSessionStatus } from './focus-session';
+import type { DiskSpaceStatus } from './disk-space';
+import type { SpellCheckSettings } from './language';
 
 /**
  * Electron API exposed to renderer process via contextBridge.
@@ -37,12 +66,60 @@ export interface ElectronAPI {
     /** Check if the window is currently maximized */
     isMaximized: () => Promise<boolean>;
 
+    /** Open another main window with its own Gemini view */
+    openNewWindow: () => void;
+
+    /** Toggle fullscreen on this window */
+    toggleFullScreen: () => void;
+
+    /** Check if this window is fullscreen */
+    isFullScreen: () => Promise<boolean>;
+
+    /** Listen for this window entering or leaving fullscreen. Returns unsubscribe function. */
+    onFullScreenChanged: (callback: (data: { enabled: boolean }) => void) => () => void;
+
+    /** List the connected displays, marking the one this window is on */
+    getDisplays: () => Promise<DisplayInfo[]>;
+
+    /** Move this window to another display */
+    moveToDisplay: (index: number) => void;
+
     /** Open the options/settings window */
     openOptions: (tab?: 'settings' | 'about') => void;
 
     /** Open Google sign-in window */
     openGoogleSignIn: () => Promise<void>;
 
+    /** Quit the app instead of hiding to the tray */
+    quitApp: () => void;
+
+    /** Get whether closing the main window hides it to the tray */
+    getCloseToTray: () => Promise<boolean>;
+
+    /** Set whether closing the main window hides it to the tray */
+    setCloseToTray: (enabled: boolean) => void;
+
+    /** Get which optional features work on this platform and build */
+    getCapabilityReport: () => Promise<CapabilityReport>;
+
+    /** Get release notes and required actions since the last acknowledged version */
+    getWhatsNew: () => Promise<WhatsNew>;
+
+    /** Mark the running version's release notes as read */
+    acknowledgeWhatsNew: () => void;
+
+    /** Get free space on the app data volume and whether it is below the threshold */
+    getDiskSpaceStatus: () => Promise<DiskSpaceStatus>;
+
+    /** Get whether a focus session is running */
+    getFocusSessionStatus: () => Promise<FocusSessionStatus>;
+
+    /** Start a focus session, or end the running one */
+    toggleFocusSession: () => void;
+
+    /** Listen for focus sessions starting and ending. Returns unsubscribe function. */
+    onFocusSessionChanged: (callback: (status: FocusSessionStatus) => void) => () => void;
+
     // =========================================================================
     // Platform Detection
     // =========================================================================
@@ -79,6 +156,12 @@ export interface ElectronAPI {
     /** Cancel quick chat (hide without submitting) */
     cancelQuickChat: () => void;
 
+    /** Translate text (or the clipboard when empty) to the given language via Gemini */
+    translateQuickChat: (text: string, language: TranslateLanguage) => void;
+
+    /** Get the last used translation target language */
+    getTranslateLanguage: () => Promise<TranslateLanguage>;
+
     /** Listen for quick chat execution. Returns unsubscribe function. */
     onQuickChatExecute: (callback: (text: string) => void) => () => void;
 
@@ -93,6 +176,36 @@ export interface ElectronAPI {
     /** Signal to main process that Gemini iframe is ready for injection */
     signalGeminiReady: (text: string) => void;
 
+    /** Reload the embedded Gemini view, optionally bypassing the HTTP cache */
+    reloadGemini: (ignoreCache?: boolean) => void;
+
+    /** Navigate the embedded Gemini view back */
+    goBackGemini: () => void;
+
+    /** Navigate the embedded Gemini view forward */
+    goForwardGemini: () => void;
+
+    /** Navigate the embedded Gemini view to the Gemini home page */
+    goHomeGemini: () => void;
+
+    /** Open the native print dialog for the Gemini conversation */
+    printGemini: () => void;
+
+    /** Re-apply the user stylesheet (user.css in the data directory) to Gemini */
+    reloadUserStyles: () => void;
+
+    /** Get whether the Gemini view can navigate back or forward */
+    getGeminiNavigationState: () => Promise<GeminiNavigationState>;
+
+    /** Subscribe to Gemini navigation state changes */
+    onGeminiNavigationChanged: (callback: (state: GeminiNavigationState) => void) => () => void;
+
+    /** Get the Gemini view's URL, title and conversation ID */
+    getGeminiLocation: () => Promise<GeminiLocation>;
+
+    /** Subscribe to Gemini navigation, with the new URL and title */
+    onGeminiLocationChanged: (callback: (location: GeminiLocation) => void) => () => void;
+
     // =========================================================================
     // Individual Hotkeys API
     // =========================================================================
@@ -135,6 +248,89 @@ export interface ElectronAPI {
     /** Listen for always on top changes. Returns unsubscribe function. */
     onAlwaysOnTopChanged: (callback: (data: { enabled: boolean }) => void) => () => void;
 
+    // =========================================================================
+    // Immersive Mode API
+    // =========================================================================
+
+    /** Get whether immersive mode (fullscreen without the titlebar) is on */
+    getImmersiveMode: () => Promise<{ enabled: boolean }>;
+
+    /** Enter or leave immersive mode */
+    toggleImmersiveMode: () => void;
+
+    /** Listen for immersive mode changes. Returns unsubscribe function. */
+    onImmersiveModeChanged: (callback: (data: { enabled: boolean }) => void) => () => void;
+
+    // =========================================================================
+    // Compact Mode API
+    // =========================================================================
+
+    /** Get whether this window is in compact mode */
+    getCompactMode: () => Promise<{ enabled: boolean }>;
+
+    /** Enter or leave compact mode for this window */
+    toggleCompactMode: () => void;
+
+    /** Listen for compact mode changes for this window. Returns unsubscribe function. */
+    onCompactModeChanged: (callback: (data: { enabled: boolean }) => void) => () => void;
+
+    // =========================================================================
+    // Window Opacity and Overlay Mode API
+    // =========================================================================
+
+    /** Get the main window opacity */
+    getWindowOpacity: () => Promise<{ opacity: number }>;
+
+    /** Set the main window opacity (MIN_WINDOW_OPACITY-1); ignored on Linux */
+    setWindowOpacity: (opacity: number) => void;
+
+    /** Listen for window opacity changes. Returns unsubscribe function. */
+    onWindowOpacityChanged: (callback: (data: { opacity: number }) => void) => () => void;
+
+    /** Get whether overlay mode (translucent, on top and click-through) is on */
+    getOverlayMode: () => Promise<{ enabled: boolean }>;
+
+    /** Enter or leave overlay mode */
+    toggleOverlayMode: () => void;
+
+    /** Listen for overlay mode changes. Returns unsubscribe function. */
+    onOverlayModeChanged: (callback: (data: { enabled: boolean }) => void) => () => void;
+
+    // =========================================================================
+    // Titlebar API
+    // =========================================================================
+
+    /** Get the status indicators published by the main process */
+    getTitlebarState: () => Promise<TitlebarState>;
+
+    /** Listen for titlebar indicator changes. Returns unsubscribe function. */
+    onTitlebarStateChanged: (callback: (state: TitlebarState) => void) => () => void;
+
+    /** Get the titlebar height in logical pixels */
+    getTitlebarHeight: () => Promise<number>;
+
+    /** Set the titlebar height (MIN_TITLEBAR_HEIGHT-MAX_TITLEBAR_HEIGHT); false if out of range */
+    setTitlebarHeight: (height: number) => Promise<boolean>;
+
+    /** Listen for titlebar height changes. Returns unsubscribe function. */
+    onTitlebarHeightChanged: (callback: (data: { height: number }) => void) => () => void;
+
+    /** Get the titlebar effect in use and the effects this platform supports */
+    getTitlebarEffect: () => Promise<TitlebarEffectState>;
+
+    /** Set the native material behind the titlebar; false if the effect is unknown */
+    setTitlebarEffect: (effect: TitlebarEffect) => Promise<boolean>;
+
+    /** Listen for titlebar effect changes. Returns unsubscribe function. */
+    onTitlebarEffectChanged: (callback: (data: TitlebarEffectState) => void) => () => void;
+
+    // =========================================================================
+    // App Events API
+    // =========================================================================
+
+    /** Get the recent state-change events, optionally only those after `since` */
+    getRecentEvents: (since?: number) => Promise<AppEvent[]>;
+
     // =========================================================================
     // Zoom API
     // =========================================================================
@@ -148,6 +344,12 @@ export interface ElectronAPI {
     /** Decrease zoom level to previous step */
     zoomOut: () => Promise<number>;
 
+    /** Set zoom level percentage, snapped to the nearest step */
+    setZoomLevel: (level: number) => Promise<number>;
+
+    /** Reset zoom level to 100% */
+    resetZoom: () => Promise<number>;
+
     /** Listen for zoom level changes. Returns unsubscribe function. */
     onZoomLevelChanged: (callback: (level: number) => void) => () => void;
 
@@ -258,6 +460,22 @@ export interface ElectronAPI {
      */
     setResponseNotificationsEnabled: (enabled: boolean) => void;
 
+    // =========================================================================
+    // Auto-Copy API
+    // =========================================================================
+
+    /**
+     * Get the answer auto-copy settings.
+     * @returns Promise resolving to mode and format
+     */
+    getAutoCopySettings: () => Promise<AutoCopySettings>;
+
+    /**
+     * Update the answer auto-copy settings.
+     * @param settings - Partial settings (mode and/or format)
+     */
+    setAutoCopySettings: (settings: Partial<AutoCopySettings>) => void;
+
     // =========================================================================
     // Text Prediction API
     // =========================================================================
@@ -332,4 +550,352 @@ export interface ElectronAPI {
      * Export the current chat to a Markdown file.
      */
     exportChatToMarkdown: () => void;
+
+    /**
+     * Publish the current chat as a Confluence page.
+     * @param target - Optional space key and parent page ID overriding the saved settings
+     */
+    exportChatToConfluence: (target?: ConfluenceTarget) => void;
+
+    /**
+     * Export the open Canvas document to a Markdown file.
+     */
+    exportCanvasToMarkdown: () => void;
+
+    /**
+     * Export the open Deep Research report, keeping its sections and sources.
+     * @param format - 'pdf' or 'markdown'
+     */
+    exportResearchReport: (format: ResearchReportFormat) => void;
+
+    /**
+     * Get the Confluence export settings.
+     */
+    getConfluenceSettings: () => Promise<ConfluenceSettings>;
+
+    /**
+     * Set the Confluence export settings.
+     */
+    setConfluenceSettings: (settings: ConfluenceSettings) => void;
+
+    /**
+     * Get the export filename template.
+     * @returns Promise resolving to the template string
+     */
+    getExportFilenameTemplate: () => Promise<string>;
+
+    /**
+     * Set the export filename template.
+     * Supports {title}, {date}, {time}, {id}, {gem} and {format} tokens.
+     * @param template - The new template
+     */
+    setExportFilenameTemplate: (template: string) => void;
+
+    /**
+     * Cancel a running export job.
+     * @param jobId - Job ID from an export progress event
+     */
+    cancelExport: (jobId: string) => void;
+
+    /**
+     * Listen for export progress events.
+     * @param callback - Function called with progress updates
+     * @returns Unsubscribe function
+     */
+    onExportProgress: (callback: (progress: ExportProgress) => void) => () => void;
+
+    // =========================================================================
+    // Logging
+    // =========================================================================
+
+    /**
+     * Get the active main-process log level spec.
+     * @returns Spec string, e.g. 'info,exportmanager=debug' (empty when using the build default)
+     */
+    getLogLevel: () => Promise<string>;
+
+    /**
+     * Change main-process logging verbosity at runtime.
+     * @param spec - Level ('error' | 'warn' | 'info' | 'debug'), optionally with module=level targets
+     */
+    setLogLevel: (spec: string) => void;
+
+    // =========================================================================
+    // Pinned Answer Cards
+    // =========================================================================
+
+    /**
+     * Pin the latest Gemini answer into a floating always-on-top card.
+     */
+    pinLastAnswer: () => void;
+
+    // =========================================================================
+    // Answer Sharing
+    // =========================================================================
+
+    /**
+     * Redact the latest Gemini answer for review; the result arrives via onSharePreview.
+     */
+    requestSharePreview: () => void;
+
+    /**
+     * Subscribe to redacted answers ready for review before posting.
+     * @returns Cleanup function to unsubscribe
+     */
+    onSharePreview: (callback: (preview: SharePreview) => void) => () => void;
+
+    /**
+     * Post a reviewed answer to the configured Slack or Teams webhook.
+     */
+    postShare: (markdown: string) => void;
+
+    /**
+     * Get the answer sharing settings.
+     */
+    getShareSettings: () => Promise<ShareSettings>;
+
+    /**
+     * Set the answer sharing settings.
+     */
+    setShareSettings: (settings: ShareSettings) => void;
+
+    /**
+     * Show the open conversation, or its link when too long, as a QR code.
+     */
+    shareAsQr: () => void;
+
+    // =========================================================================
+    // Issue Creation
+    // =========================================================================
+
+    /**
+     * Draft an issue from the selected text or the latest answer; the draft arrives via onIssueDraft.
+     */
+    requestIssueDraft: () => void;
+
+    /**
+     * Subscribe to issue drafts ready for review.
+     * @returns Cleanup function to unsubscribe
+     */
+    onIssueDraft: (callback: (draft: IssueDraft) => void) => () => void;
+
+    /**
+     * Create a reviewed issue in the configured GitHub repository or Jira project.
+     */
+    createIssue: (draft: IssueDraft) => Promise<IssueCreateResult>;
+
+    /**
+     * Get the issue tracker settings.
+     */
+    getIssueTrackerSettings: () => Promise<IssueTrackerSettings>;
+
+    /**
+     * Set the issue tracker settings.
+     */
+    setIssueTrackerSettings: (settings: IssueTrackerSettings) => void;
+
+    // =========================================================================
+    // Screenshots
+    // =========================================================================
+
+    /**
+     * Capture the Gemini view or the latest answer to a PNG file or the clipboard.
+     */
+    captureScreenshot: (options: ScreenshotOptions) => void;
+
+    // =========================================================================
+    // Userscripts
+    // =========================================================================
+
+    /**
+     * List the userscripts in the scripts folder with their enabled state.
+     */
+    listUserscripts: () => Promise<UserscriptInfo[]>;
+
+    /**
+     * Enable or disable a userscript. Takes effect the next time Gemini loads.
+     */
+    setUserscriptEnabled: (id: string, enabled: boolean) => void;
+
+    /**
+     * Open the userscripts folder in the file manager.
+     */
+    openUserscriptsFolder: () => void;
+
+    // =========================================================================
+    // Settings Maintenance
+    // =========================================================================
+
+    /**
+     * Restore default settings for a section, backing up the current settings first.
+     * @param section - 'hotkeys', 'appearance' or 'all'
+     * @returns Result with the backup file path
+     */
+    resetSettings: (section: SettingsResetSection) => Promise<SettingsResetResult>;
+
+    /**
+     * Get the logged settings changes, newest first. Secret values are hidden.
+     * @returns Recorded changes
+     */
+    getSettingsHistory: () => Promise<SettingsChange[]>;
+
+    /**
+     * Undo the most recent settings changes, restoring their previous values.
+     * @param count - Number of changes to undo
+     * @returns The undone changes
+     */
+    undoSettingsChange: (count: number) => Promise<SettingsUndoResult>;
+
+    // =========================================================================
+    // Upstream Proxy
+    // =========================================================================
+
+    /**
+     * Get the upstream proxy settings.
+     * @returns Proxy mode, server, credentials and bypass list
+     */
+    getProxySettings: () => Promise<ProxySettings>;
+
+    /**
+     * Save and apply upstream proxy settings.
+     * @param settings - Proxy mode, server, credentials and bypass list
+     */
+    setProxySettings: (settings: ProxySettings) => void;
+
+    /**
+     * Check that Gemini is reachable through the current proxy settings.
+     * @returns Result with status code and latency, or the connection error
+     */
+    testProxyConnection: () => Promise<ProxyTestResult>;
+
+    // =========================================================================
+    // Usage Tracking
+    // =========================================================================
+
+    /**
+     * Get usage tracking settings.
+     * @returns Whether tracking and idle detection are enabled
+     */
+    getUsageSettings: () => Promise<UsageSettings>;
+
+    /**
+     * Update usage tracking settings.
+     * @param settings - Partial settings to update
+     */
+    setUsageSettings: (settings: Partial<UsageSettings>) => void;
+
+    /**
+     * Get active time spent in the app.
+     * @returns Seconds today, this week and for each of the last 14 days
+     */
+    getUsageReport: () => Promise<UsageReport>;
+
+    /**
+     * Get the daily usage limits and whether they are currently reached.
+     * @returns Limit status
+     */
+    getUsageLimitStatus: () => Promise<UsageLimitStatus>;
+
+    /**
+     * Set the daily usage limits (ignored when enforced by policy).
+     * @param limits - Minutes and prompts per day, 0 for no limit
+     */
+    setUsageLimits: (limits: UsageLimits) => void;
+
+    /**
+     * Subscribe to the daily limit being reached or cleared.
+     * @param callback - Called with the new limit status
+     * @returns Cleanup function to unsubscribe
+     */
+    onUsageLimitChanged: (callback: (status: UsageLimitStatus) => void) => () => void;
+
+    /**
+     * Get the estimated Gemini prompt quota left today.
+     * @returns Plan, daily quota, prompts used and remaining
+     */
+    getGeminiQuotaStatus: () => Promise<GeminiQuotaStatus>;
+
+    /**
+     * Set the Gemini plan used for the quota estimate.
+     * @param settings - Plan, and the daily prompts for the 'custom' plan
+     */
+    setGeminiQuota: (settings: GeminiQuotaSettings) => void;
+
+    /**
+     * Subscribe to changes of the Gemini quota estimate.
+     * @param callback - Called with the new estimate
+     * @returns Cleanup function to unsubscribe
+     */
+    onGeminiQuotaChanged: (callback: (status: GeminiQuotaStatus) => void) => () => void;
+
+    // =========================================================================
+    // User-Agent
+    // =========================================================================
+
+    /**
+     * Get the User-Agent override settings.
+     * @returns Preset and custom User-Agent string
+     */
+    getUserAgentSettings: () => Promise<UserAgentSettings>;
+
+    /**
+     * Save and apply User-Agent override settings.
+     * @param settings - Preset and custom User-Agent string
+     */
+    setUserAgentSettings: (settings: UserAgentSettings) => void;
+
+    // =========================================================================
+    // Gemini Language
+    // =========================================================================
+
+    /**
+     * Get the language Gemini is shown in.
+     * @returns BCP 47 language tag, or '' when following the system locale
+     */
+    getGeminiLanguage: () => Promise<string>;
+
+    /**
+     * Save and apply the language Gemini is shown in.
+     * @param tag - BCP 47 language tag, or '' to follow the system locale
+     */
+    setGeminiLanguage: (tag: string) => void;
+
+    // =========================================================================
+    // Spell Check
+    // =========================================================================
+
+    /**
+     * Get the spell checker settings and the dictionaries available.
+     * @returns Whether spelling is checked, the chosen languages and the available ones
+     */
+    getSpellCheckSettings: () => Promise<SpellCheckSettings>;
+
+    /**
+     * Turn the spell checker on or off.
+     * @param enabled - Whether to underline misspelled words
+     */
+    setSpellCheckEnabled: (enabled: boolean) => void;
+
+    /**
+     * Save and apply the languages the spell checker checks.
+     * @param languages - BCP 47 language tags; empty follows the Gemini language
+     */
+    setSpellCheckLanguages: (languages: string[]) => void;
+
+    // =========================================================================
+    // Link Handling
+    // =========================================================================
+
+    /**
+     * Get the extra hosts Gemini may navigate to; other links open in the system browser.
+     * @returns Hosts, one per line
+     */
+    getAllowedHosts: () => Promise<string>;
+
+    /**
+     * Save and apply the extra hosts Gemini may navigate to.
+     * Invalid entries are dropped.
+     * @param hosts - Hosts separated by newlines, commas or spaces
+     */
+    setAllowedHosts: (hosts: string) => void;
 }
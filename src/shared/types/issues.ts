@@ -0,0 +1,69 @@
+/**
+ * Issue Tracker Types
+ *
+ * Shared types for filing GitHub issues or Jira tickets from Gemini answers.
+ */
+
+/**
+ * Where new issues are created.
+ * - 'github': GitHub issues in a repository
+ * - 'jira': Jira issues in a project
+ */
+export type IssueTracker = 'github' | 'jira';
+
+/**
+ * Issue tracker connection settings.
+ */
+export interface IssueTrackerSettings {
+    tracker: IssueTracker;
+    /** GitHub repository as 'owner/name' */
+    githubRepository: string;
+    /** GitHub personal access token with permission to create issues */
+    githubToken: string;
+    /** Jira site URL, e.g. 'https://example.atlassian.net' */
+    jiraBaseUrl: string;
+    /** Account email for Jira Cloud; leave empty to use the token as a personal access token */
+    jiraEmail: string;
+    /** API token (Cloud) or personal access token (Server/Data Center) */
+    jiraApiToken: string;
+    /** Key of the project issues are created in */
+    jiraProjectKey: string;
+}
+
+/**
+ * Issue pre-filled from an answer or the selected text, for review before it is created.
+ */
+export interface IssueDraft {
+    tracker: IssueTracker;
+    title: string;
+    /** Issue description as Markdown */
+    body: string;
+}
+
+/**
+ * Result of creating an issue.
+ */
+export interface IssueCreateResult {
+    success: boolean;
+    /** Web URL of the created issue */
+    url?: string;
+    error?: string;
+}
+
+/**
+ * All valid issue trackers, for validation.
+ */
+export const ISSUE_TRACKERS: IssueTracker[] = ['github', 'jira'];
+
+/**
+ * Default issue tracker settings (not configured).
+ */
+export const DEFAULT_ISSUE_TRACKER_SETTINGS: IssueTrackerSettings = {
+    tracker: 'github',
+    githubRepository: '',
+    githubToken: '',
+    jiraBaseUrl: '',
+    jiraEmail: '',
+    jiraApiToken: '',
+    jiraProjectKey: '',
+};
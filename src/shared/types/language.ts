@@ -0,0 +1,65 @@
+/**
+ * Gemini Language Types
+ *
+ * Shared types and constants for overriding the language Gemini is shown in.
+ */
+
+/**
+ * Languages offered in the Options language select, as BCP 47 tags.
+ * An empty tag follows the system locale.
+ */
+export const GEMINI_LANGUAGES: { tag: string; label: string }[] = [
+    { tag: 'en-US', label: 'English (US)' },
+    { tag: 'en-GB', label: 'English (UK)' },
+    { tag: 'es', label: 'Español' },
+    { tag: 'fr', label: 'Français' },
+    { tag: 'de', label: 'Deutsch' },
+    { tag: 'it', label: 'Italiano' },
+    { tag: 'pt-BR', label: 'Português (Brasil)' },
+    { tag: 'nl', label: 'Nederlands' },
+    { tag: 'pl', label: 'Polski' },
+    { tag: 'tr', label: 'Türkçe' },
+    { tag: 'uk', label: 'Українська' },
+    { tag: 'ru', label: 'Русский' },
+    { tag: 'ar', label: 'العربية' },
+    { tag: 'hi', label: 'हिन्दी' },
+    { tag: 'zh-CN', label: '简体中文' },
+    { tag: 'zh-TW', label: '繁體中文' },
+    { tag: 'ja', label: '日本語' },
+    { tag: 'ko', label: '한국어' },
+];
+
+/**
+ * Maximum length of a language tag.
+ */
+export const MAX_LANGUAGE_TAG_LENGTH = 35;
+
+/**
+ * Check that a value is a language tag that is safe to send as a header value,
+ * e.g. 'de' or 'pt-BR'. The empty string (follow the system locale) is accepted.
+ * @param value - Value to check
+ */
+export function isLanguageTag(value: unknown): value is string {
+    return (
+        typeof value === 'string' &&
+        value.length <= MAX_LANGUAGE_TAG_LENGTH &&
+        (value === '' || /^[a-zA-Z]{2,3}(-[a-zA-Z0-9]{1,8})*$/.test(value))
+    );
+}
+
+/**
+ * Maximum number of languages the spell checker checks at once.
+ */
+export const MAX_SPELL_CHECK_LANGUAGES = 5;
+
+/**
+ * Spell checker settings for the prompt box.
+ */
+export interface SpellCheckSettings {
+    /** Whether misspelled words are underlined */
+    enabled: boolean;
+    /** Languages to check, as BCP 47 tags; empty follows the Gemini language */
+    languages: string[];
+    /** Languages the spell checker has dictionaries for; empty on macOS, which uses the system spell checker */
+    available: string[];
+}
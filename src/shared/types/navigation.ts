@@ -0,0 +1,25 @@
+/**
+ * Navigation Types
+ *
+ * Shared types for back/forward navigation of the embedded Gemini view.
+ */
+
+/**
+ * Whether the Gemini view can navigate back or forward.
+ */
+export interface GeminiNavigationState {
+    canGoBack: boolean;
+    canGoForward: boolean;
+}
+
+/**
+ * Where the Gemini view is.
+ */
+export interface GeminiLocation {
+    /** URL of the Gemini frame, or '' when Gemini is not loaded */
+    url: string;
+    /** Document title of the Gemini frame */
+    title: string;
+    /** Conversation ID from the URL, or null outside a conversation (e.g. a new chat) */
+    conversationId: string | null;
+}
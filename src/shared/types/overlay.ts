@@ -0,0 +1,21 @@
+/**
+ * Overlay Types
+ *
+ * Shared constants for window opacity and overlay mode, where the main window
+ * floats translucently above other apps and lets clicks through.
+ */
+
+/**
+ * Lowest window opacity allowed, so the window never becomes invisible.
+ */
+export const MIN_WINDOW_OPACITY = 0.2;
+
+/**
+ * Opacity overlay mode uses while the window opacity setting is higher.
+ */
+export const OVERLAY_MODE_OPACITY = 0.85;
+
+/**
+ * Shortcut that enters and leaves overlay mode.
+ */
+export const OVERLAY_MODE_ACCELERATOR = 'CmdOrCtrl+Shift+O';
@@ -0,0 +1,59 @@
+/**
+ * Proxy Types
+ *
+ * Shared types for routing outbound traffic through an upstream HTTP or SOCKS5 proxy.
+ */
+
+/**
+ * How outbound connections are made.
+ * - 'system': Use the operating system proxy settings
+ * - 'direct': Never use a proxy
+ * - 'http': Use an HTTP proxy
+ * - 'socks5': Use a SOCKS5 proxy
+ */
+export type ProxyMode = 'system' | 'direct' | 'http' | 'socks5';
+
+/**
+ * Upstream proxy settings.
+ */
+export interface ProxySettings {
+    mode: ProxyMode;
+    /** Proxy host name or IP (http/socks5 modes) */
+    host: string;
+    /** Proxy port (http/socks5 modes) */
+    port: number;
+    /** Optional credentials for proxies that require authentication */
+    username: string;
+    password: string;
+    /** Comma-separated hosts that bypass the proxy, e.g. 'localhost,*.corp.example' */
+    bypass: string;
+}
+
+/**
+ * Result of testing the proxy connection.
+ */
+export interface ProxyTestResult {
+    success: boolean;
+    /** HTTP status of the test request, if it completed */
+    statusCode?: number;
+    /** Round-trip time in milliseconds, if it completed */
+    latencyMs?: number;
+    error?: string;
+}
+
+/**
+ * All valid proxy modes, for validation.
+ */
+export const PROXY_MODES: ProxyMode[] = ['system', 'direct', 'http', 'socks5'];
+
+/**
+ * Default proxy settings (use the system proxy).
+ */
+export const DEFAULT_PROXY_SETTINGS: ProxySettings = {
+    mode: 'system',
+    host: '',
+    port: 8080,
+    username: '',
+    password: '',
+    bypass: '',
+};
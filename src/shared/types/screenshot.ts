@@ -0,0 +1,37 @@
+/**
+ * Screenshot Types
+ *
+ * Shared types for capturing the Gemini view as an image.
+ */
+
+/**
+ * Where a screenshot goes.
+ * - 'file': PNG file chosen in a save dialog
+ * - 'clipboard': Image on the system clipboard
+ */
+export type ScreenshotTarget = 'file' | 'clipboard';
+
+/**
+ * What part of the Gemini view is captured.
+ * - 'view': The visible Gemini view
+ * - 'response': Only the latest answer (the part of it that fits in the view)
+ */
+export type ScreenshotRegion = 'view' | 'response';
+
+/**
+ * Options for capturing a screenshot.
+ */
+export interface ScreenshotOptions {
+    target: ScreenshotTarget;
+    region: ScreenshotRegion;
+}
+
+/**
+ * All valid screenshot targets, for validation.
+ */
+export const SCREENSHOT_TARGETS: ScreenshotTarget[] = ['file', 'clipboard'];
+
+/**
+ * All valid screenshot regions, for validation.
+ */
+export const SCREENSHOT_REGIONS: ScreenshotRegion[] = ['view', 'response'];
@@ -0,0 +1,40 @@
+/**
+ * Settings History Types
+ *
+ * Shared types for the log of settings changes and undoing them.
+ */
+
+/**
+ * Most settings changes kept in the history.
+ */
+export const SETTINGS_HISTORY_LIMIT = 100;
+
+/**
+ * One recorded settings change.
+ */
+export interface SettingsChange {
+    /** When the change was made (ms since epoch) */
+    timestamp: number;
+    /** Setting key */
+    key: string;
+    /** Value before the change; absent if the setting was unset */
+    previous?: unknown;
+    /** Value after the change; absent if the setting was removed */
+    value?: unknown;
+    /** Other settings changed in the same save; undone together with this change */
+    batch?: SettingsChange[];
+}
+
+/**
+ * Result of undoing settings changes.
+ */
+export interface SettingsUndoResult {
+    /** Whether the previous values were restored and saved */
+    success: boolean;
+    /** Changes that were undone, newest first */
+    undone: SettingsChange[];
+    /** Some restored settings only take effect after a restart */
+    restartRecommended: boolean;
+    /** Error message when the undo failed */
+    error?: string;
+}
@@ -0,0 +1,30 @@
+/**
+ * Settings Reset Types
+ *
+ * Shared types for restoring settings to their defaults by section.
+ */
+
+/**
+ * Settings section to reset.
+ * - 'hotkeys': Hotkey enabled states and accelerators
+ * - 'appearance': Theme, always-on-top and zoom level
+ * - 'all': Every setting
+ */
+export type SettingsResetSection = 'hotkeys' | 'appearance' | 'all';
+
+/**
+ * All valid reset sections, for validation.
+ */
+export const SETTINGS_RESET_SECTIONS: SettingsResetSection[] = ['hotkeys', 'appearance', 'all'];
+
+/**
+ * Result of a settings reset.
+ */
+export interface SettingsResetResult {
+    /** Whether the defaults were applied and saved */
+    success: boolean;
+    /** Path of the backup written before resetting, if any */
+    backupPath: string | null;
+    /** Error message when the reset failed */
+    error?: string;
+}
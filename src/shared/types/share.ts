@@ -0,0 +1,45 @@
+/**
+ * Share Types
+ *
+ * Shared types for posting the latest Gemini answer to a Slack or Teams channel.
+ */
+
+/**
+ * Chat service the answer is posted to.
+ * - 'slack': Slack incoming webhook
+ * - 'teams': Microsoft Teams incoming webhook or Workflows webhook
+ */
+export type ShareService = 'slack' | 'teams';
+
+/**
+ * Channel webhook settings.
+ */
+export interface ShareSettings {
+    service: ShareService;
+    /** Webhook URL of the channel; empty when sharing is not configured */
+    webhookUrl: string;
+}
+
+/**
+ * Redacted answer shown for review before it is posted.
+ */
+export interface SharePreview {
+    service: ShareService;
+    /** Answer as Markdown, with likely secrets replaced */
+    markdown: string;
+    /** Number of values the redaction pass replaced */
+    redactions: number;
+}
+
+/**
+ * All valid share services, for validation.
+ */
+export const SHARE_SERVICES: ShareService[] = ['slack', 'teams'];
+
+/**
+ * Default share settings (not configured).
+ */
+export const DEFAULT_SHARE_SETTINGS: ShareSettings = {
+    service: 'slack',
+    webhookUrl: '',
+};
@@ -0,0 +1,61 @@
+/**
+ * Titlebar Widget Types
+ *
+ * Shared types for the status indicators the main process shows in the
+ * titlebar (network status, running exports, active profile, read-only data
+ * directory), its height and the native material shown behind it.
+ */
+
+/** Default titlebar height in logical pixels */
+export const DEFAULT_TITLEBAR_HEIGHT = 32;
+
+/** Smallest titlebar height that still fits the menus and window controls */
+export const MIN_TITLEBAR_HEIGHT = 24;
+
+/** Largest titlebar height */
+export const MAX_TITLEBAR_HEIGHT = 64;
+
+/**
+ * Native materials that can show through the titlebar: mica and acrylic on
+ * Windows 11, vibrancy on macOS.
+ */
+export const TITLEBAR_EFFECTS = ['none', 'mica', 'acrylic', 'vibrancy'] as const;
+
+export type TitlebarEffect = (typeof TITLEBAR_EFFECTS)[number];
+
+/**
+ * Titlebar effect in use, and the effects this platform supports.
+ */
+export interface TitlebarEffectState {
+    /** Effect applied to the windows; 'none' when the saved effect is unsupported here */
+    effect: TitlebarEffect;
+    supported: TitlebarEffect[];
+}
+
+/**
+ * How an indicator is styled.
+ */
+export type TitlebarWidgetTone = 'info' | 'warning' | 'error';
+
+/**
+ * A status indicator in the titlebar.
+ */
+export interface TitlebarWidget {
+    /** Unique ID; setting a widget with the same ID replaces it */
+    id: string;
+    /** Short text shown in the titlebar */
+    label: string;
+    /** Longer explanation shown on hover */
+    tooltip?: string;
+    tone: TitlebarWidgetTone;
+    /** Position among the indicators, lowest first */
+    order: number;
+}
+
+/**
+ * Indicators currently shown in the titlebar.
+ */
+export interface TitlebarState {
+    /** Sorted by order */
+    widgets: TitlebarWidget[];
+}
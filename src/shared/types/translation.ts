@@ -0,0 +1,56 @@
+/**
+ * Translation Types
+ *
+ * Shared types and constants for the Quick Chat translate workflow.
+ */
+
+/**
+ * Target languages offered in the Quick Chat language picker.
+ */
+export const TRANSLATE_LANGUAGES = [
+    'English',
+    'Spanish',
+    'French',
+    'German',
+    'Italian',
+    'Portuguese',
+    'Dutch',
+    'Russian',
+    'Ukrainian',
+    'Polish',
+    'Turkish',
+    'Arabic',
+    'Hindi',
+    'Chinese (Simplified)',
+    'Chinese (Traditional)',
+    'Japanese',
+    'Korean',
+] as const;
+
+/**
+ * A supported translation target language.
+ */
+export type TranslateLanguage = (typeof TRANSLATE_LANGUAGES)[number];
+
+/**
+ * Default target language.
+ */
+export const DEFAULT_TRANSLATE_LANGUAGE: TranslateLanguage = 'English';
+
+/**
+ * Type guard for supported translation target languages.
+ * @param value - Value to check
+ */
+export function isTranslateLanguage(value: unknown): value is TranslateLanguage {
+    return typeof value === 'string' && (TRANSLATE_LANGUAGES as readonly string[]).includes(value);
+}
+
+/**
+ * Wrap text in a translation prompt for Gemini.
+ * @param text - Text to translate
+ * @param language - Target language
+ * @returns Prompt text
+ */
+export function buildTranslationPrompt(text: string, language: TranslateLanguage): string {
+    return `Translate the following text to ${language}. Reply with only the translation.\n\n${text.trim()}`;
+}
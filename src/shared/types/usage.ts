@@ -0,0 +1,110 @@
+/**
+ * Usage Types
+ *
+ * Shared types for tracking time spent with the app in the foreground.
+ */
+
+/**
+ * Active time on a single day.
+ */
+export interface UsageDay {
+    /** Local date, YYYY-MM-DD */
+    date: string;
+    /** Seconds an app window was focused (and the user not idle) */
+    seconds: number;
+}
+
+/**
+ * Usage summary for the Options window.
+ */
+export interface UsageReport {
+    /** Active seconds today */
+    todaySeconds: number;
+    /** Active seconds since Monday of the current week */
+    weekSeconds: number;
+    /** Prompts answered today */
+    todayPrompts: number;
+    /** The last 14 days, oldest first */
+    days: UsageDay[];
+}
+
+/**
+ * Usage tracking settings.
+ */
+export interface UsageSettings {
+    /** Whether focus time is recorded */
+    enabled: boolean;
+    /** Stop counting while the user is idle even if a window stays focused */
+    idleDetection: boolean;
+}
+
+/**
+ * Daily usage limits. 0 means no limit.
+ */
+export interface UsageLimits {
+    /** Maximum active minutes per day */
+    dailyMinutes: number;
+    /** Maximum prompts per day */
+    dailyPrompts: number;
+}
+
+/**
+ * Whether usage is currently blocked by a limit.
+ */
+export interface UsageLimitStatus {
+    blocked: boolean;
+    /** Which limit was reached, if blocked */
+    reason: 'time' | 'prompts' | null;
+    limits: UsageLimits;
+    /** True when the limits come from a policy file and cannot be changed in the app */
+    enforcedByPolicy: boolean;
+}
+
+/**
+ * No limits.
+ */
+export const NO_USAGE_LIMITS: UsageLimits = { dailyMinutes: 0, dailyPrompts: 0 };
+
+/**
+ * Gemini plans with a known daily prompt quota. 'off' hides the estimate and
+ * 'custom' uses the user's own number.
+ */
+export const GEMINI_QUOTA_PLANS = ['off', 'free', 'pro', 'ultra', 'custom'] as const;
+
+export type GeminiQuotaPlan = (typeof GEMINI_QUOTA_PLANS)[number];
+
+/**
+ * Daily prompts each plan allows with Gemini's most capable model, as
+ * published by Google. Google does not report usage to the app, so the
+ * remaining count is an estimate; use 'custom' when these numbers change.
+ */
+export const GEMINI_PLAN_DAILY_PROMPTS: Record<Exclude<GeminiQuotaPlan, 'off' | 'custom'>, number> = {
+    free: 5,
+    pro: 100,
+    ultra: 500,
+};
+
+/** Share of the daily quota after which the user is warned */
+export const GEMINI_QUOTA_WARNING_RATIO = 0.8;
+
+/**
+ * Gemini quota estimate settings.
+ */
+export interface GeminiQuotaSettings {
+    plan: GeminiQuotaPlan;
+    /** Daily prompts for the 'custom' plan */
+    customDailyPrompts: number;
+}
+
+/**
+ * Estimated Gemini quota left today.
+ */
+export interface GeminiQuotaStatus extends GeminiQuotaSettings {
+    /** Daily prompts the plan allows; 0 when the estimate is off */
+    dailyPrompts: number;
+    /** Prompts sent today */
+    used: number;
+    remaining: number;
+    /** 'warning' from GEMINI_QUOTA_WARNING_RATIO of the quota, 'exhausted' once it is used up */
+    level: 'ok' | 'warning' | 'exhausted';
+}
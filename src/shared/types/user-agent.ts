@@ -0,0 +1,38 @@
+/**
+ * User-Agent Types
+ *
+ * Shared types for overriding the User-Agent sent to Google.
+ */
+
+/**
+ * User-Agent preset.
+ * - 'default': Electron's own User-Agent
+ * - 'chrome-windows' | 'chrome-macos' | 'chrome-linux': Chrome stable on that OS
+ * - 'custom': The custom string from settings
+ */
+export type UserAgentPreset = 'default' | 'chrome-windows' | 'chrome-macos' | 'chrome-linux' | 'custom';
+
+/**
+ * User-Agent settings.
+ */
+export interface UserAgentSettings {
+    preset: UserAgentPreset;
+    /** Used when preset is 'custom' */
+    custom: string;
+}
+
+/**
+ * All valid User-Agent presets, for validation.
+ */
+export const USER_AGENT_PRESETS: UserAgentPreset[] = [
+    'default',
+    'chrome-windows',
+    'chrome-macos',
+    'chrome-linux',
+    'custom',
+];
+
+/**
+ * Maximum length of a custom User-Agent.
+ */
+export const MAX_USER_AGENT_LENGTH = 512;
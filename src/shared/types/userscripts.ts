@@ -0,0 +1,22 @@
+/**
+ * Userscript Types
+ *
+ * Shared types for userscripts: JavaScript files in the `scripts` folder of
+ * the app's data directory that run in the Gemini page.
+ */
+
+/**
+ * A userscript as shown in Options.
+ */
+export interface UserscriptInfo {
+    /** File name in the scripts folder */
+    id: string;
+    /** @name from the metadata block, or the file name */
+    name: string;
+    /** @description from the metadata block */
+    description: string;
+    /** URL patterns (@match) the script runs on; '*' matches any characters */
+    matches: string[];
+    /** Scripts are off until they are enabled in Options */
+    enabled: boolean;
+}
@@ -0,0 +1,49 @@
+/**
+ * What's New Types
+ *
+ * Shared types for the changelog shown after an upgrade, and the actions
+ * some upgrades need from the user.
+ */
+
+/**
+ * Something the user has to do after upgrading.
+ * - 'sign-in': Sign in to Google again (e.g. after a cookie store change)
+ * - 'open-options': Review a setting in the Options window
+ * - 'info': Nothing to click; the message explains the change
+ */
+export type MigrationActionKind = 'sign-in' | 'open-options' | 'info';
+
+/**
+ * A required user action after an upgrade.
+ */
+export interface MigrationAction {
+    kind: MigrationActionKind;
+    /** What changed and what to do */
+    message: string;
+}
+
+/**
+ * Changes in one released version.
+ */
+export interface ChangelogEntry {
+    /** Version, e.g. '0.6.0' */
+    version: string;
+    /** User-facing changes, one line each */
+    highlights: string[];
+    /** Actions needed after upgrading to this version */
+    actions?: MigrationAction[];
+}
+
+/**
+ * What changed since the version the user last acknowledged.
+ */
+export interface WhatsNew {
+    /** Running version */
+    currentVersion: string;
+    /** Last acknowledged version, or null on a fresh install */
+    previousVersion: string | null;
+    /** Changelog entries newer than previousVersion, newest first (empty if nothing to show) */
+    entries: ChangelogEntry[];
+    /** Actions from all entries, oldest version first */
+    actions: MigrationAction[];
+}
@@ -31,6 +31,7 @@ import { vi } from 'vitest';
 export interface MockStore {
     get: ReturnType<typeof vi.fn>;
     set: ReturnType<typeof vi.fn>;
+    setMany: ReturnType<typeof vi.fn>;
     has: ReturnType<typeof vi.fn>;
     delete: ReturnType<typeof vi.fn>;
     clear: ReturnType<typeof vi.fn>;
@@ -45,9 +46,32 @@ export interface MockWindowManager {
     createOptionsWindow: ReturnType<typeof vi.fn>;
     createAuthWindow: ReturnType<typeof vi.fn>;
     createMainWindow: ReturnType<typeof vi.fn>;
+    createAdditionalMainWindow: ReturnType<typeof vi.fn>;
     createQuickChatWindow: ReturnType<typeof vi.fn>;
     setAlwaysOnTop: ReturnType<typeof vi.fn>;
     isAlwaysOnTop: ReturnType<typeof vi.fn>;
+    toggleImmersiveMode: ReturnType<typeof vi.fn>;
+    isImmersiveMode: ReturnType<typeof vi.fn>;
+    setCompactMode: ReturnType<typeof vi.fn>;
+    toggleCompactMode: ReturnType<typeof vi.fn>;
+    isCompactMode: ReturnType<typeof vi.fn>;
+    getDisplays: ReturnType<typeof vi.fn>;
+    moveToDisplay: ReturnType<typeof vi.fn>;
+    moveToNextDisplay: ReturnType<typeof vi.fn>;
+    setCompactModeHidesSidebar: ReturnType<typeof vi.fn>;
+    isWindowOpacitySupported: ReturnType<typeof vi.fn>;
+    getWindowOpacity: ReturnType<typeof vi.fn>;
+    setWindowOpacity: ReturnType<typeof vi.fn>;
+    getTitlebarHeight: ReturnType<typeof vi.fn>;
+    setTitlebarHeight: ReturnType<typeof vi.fn>;
+    getTitlebarEffect: ReturnType<typeof vi.fn>;
+    setTitlebarEffect: ReturnType<typeof vi.fn>;
+    getSupportedTitlebarEffects: ReturnType<typeof vi.fn>;
+    toggleOverlayMode: ReturnType<typeof vi.fn>;
+    isOverlayMode: ReturnType<typeof vi.fn>;
+    setCloseToTray: ReturnType<typeof vi.fn>;
+    toggleFullScreen: ReturnType<typeof vi.fn>;
+    isFullScreen: ReturnType<typeof vi.fn>;
     on: ReturnType<typeof vi.fn>;
     emit: ReturnType<typeof vi.fn>;
     removeListener: ReturnType<typeof vi.fn>;
@@ -61,12 +85,25 @@ export interface MockWindowManager {
     hideToTray: ReturnType<typeof vi.fn>;
     minimizeMainWindow: ReturnType<typeof vi.fn>;
     setQuitting: ReturnType<typeof vi.fn>;
+    reloadGemini: ReturnType<typeof vi.fn>;
+    goBackGemini: ReturnType<typeof vi.fn>;
+    goForwardGemini: ReturnType<typeof vi.fn>;
+    goHomeGemini: ReturnType<typeof vi.fn>;
+    printGemini: ReturnType<typeof vi.fn>;
+    reloadUserStyles: ReturnType<typeof vi.fn>;
+    toggleGeminiDevTools: ReturnType<typeof vi.fn>;
+    getGeminiNavigationState: ReturnType<typeof vi.fn>;
+    getGeminiLocation: ReturnType<typeof vi.fn>;
+    setAllowedHosts: ReturnType<typeof vi.fn>;
+    navigateGemini: ReturnType<typeof vi.fn>;
     // Zoom control methods
     getZoomLevel: ReturnType<typeof vi.fn>;
     setZoomLevel: ReturnType<typeof vi.fn>;
     zoomIn: ReturnType<typeof vi.fn>;
     zoomOut: ReturnType<typeof vi.fn>;
+    resetZoom: ReturnType<typeof vi.fn>;
     initializeZoomLevel: ReturnType<typeof vi.fn>;
+    setSavedWindowState: ReturnType<typeof vi.fn>;
     applyZoomLevel: ReturnType<typeof vi.fn>;
     _reset: () => void;
 }
@@ -75,6 +112,7 @@ export interface MockWindowManager {
  * Type for a mock UpdateManager with all public methods.
  */
 export interface MockUpdateManager {
+    isSupported: ReturnType<typeof vi.fn>;
     isEnabled: ReturnType<typeof vi.fn>;
     setEnabled: ReturnType<typeof vi.fn>;
     checkForUpdates: ReturnType<typeof vi.fn>;
@@ -99,6 +137,12 @@ export interface MockUpdateManager {
 export interface MockExportManager {
     exportToPdf: ReturnType<typeof vi.fn>;
     exportToMarkdown: ReturnType<typeof vi.fn>;
+    exportToConfluence: ReturnType<typeof vi.fn>;
+    exportCanvasToMarkdown: ReturnType<typeof vi.fn>;
+    exportResearchReport: ReturnType<typeof vi.fn>;
+    getLastResponse: ReturnType<typeof vi.fn>;
+    getConversationMarkdown: ReturnType<typeof vi.fn>;
+    cancelExport: ReturnType<typeof vi.fn>;
     _reset: () => void;
 }
 
@@ -122,6 +166,7 @@ export interface MockHotkeyManager {
     executeHotkeyAction: ReturnType<typeof vi.fn>;
     getGlobalHotkeyActions: ReturnType<typeof vi.fn>;
     getApplicationHotkeyActions: ReturnType<typeof vi.fn>;
+    areGlobalHotkeysSupported: ReturnType<typeof vi.fn>;
     _reset: () => void;
 }
 
@@ -174,9 +219,32 @@ export function createMockWindowManager(overrides?: Partial<Omit<MockWindowManag
         createOptionsWindow: vi.fn(),
         createAuthWindow: vi.fn().mockReturnValue(mockAuthWindow),
         createMainWindow: vi.fn(),
+        createAdditionalMainWindow: vi.fn(),
         createQuickChatWindow: vi.fn(),
         setAlwaysOnTop: vi.fn(),
         isAlwaysOnTop: vi.fn().mockReturnValue(false),
+        toggleImmersiveMode: vi.fn(),
+        isImmersiveMode: vi.fn().mockReturnValue(false),
+        setCompactMode: vi.fn(),
+        toggleCompactMode: vi.fn(),
+        isCompactMode: vi.fn().mockReturnValue(false),
+        getDisplays: vi.fn().mockReturnValue([]),
+        moveToDisplay: vi.fn().mockReturnValue(true),
+        moveToNextDisplay: vi.fn(),
+        setCompactModeHidesSidebar: vi.fn(),
+        isWindowOpacitySupported: vi.fn().mockReturnValue(true),
+        getWindowOpacity: vi.fn().mockReturnValue(1),
+        setWindowOpacity: vi.fn(),
+        getTitlebarHeight: vi.fn().mockReturnValue(32),
+        setTitlebarHeight: vi.fn().mockReturnValue(true),
+        getTitlebarEffect: vi.fn().mockReturnValue('none'),
+        setTitlebarEffect: vi.fn().mockReturnValue(true),
+        getSupportedTitlebarEffects: vi.fn().mockReturnValue(['none']),
+        toggleOverlayMode: vi.fn(),
+        isOverlayMode: vi.fn().mockReturnValue(false),
+        setCloseToTray: vi.fn(),
+        toggleFullScreen: vi.fn(),
+        isFullScreen: vi.fn().mockReturnValue(false),
         on: vi.fn(),
         emit: vi.fn(),
         removeListener: vi.fn(),
@@ -190,12 +258,25 @@ export function createMockWindowManager(overrides?: Partial<Omit<MockWindowManag
         hideToTray: vi.fn(),
         minimizeMainWindow: vi.fn(),
         setQuitting: vi.fn(),
+        reloadGemini: vi.fn(),
+        goBackGemini: vi.fn(),
+        goForwardGemini: vi.fn(),
+        goHomeGemini: vi.fn(),
+        printGemini: vi.fn(),
+        reloadUserStyles: vi.fn().mockResolvedValue(undefined),
+        toggleGeminiDevTools: vi.fn(),
+        getGeminiNavigationState: vi.fn().mockReturnValue({ canGoBack: false, canGoForward: false }),
+        getGeminiLocation: vi.fn().mockResolvedValue({ url: '', title: '', conversationId: null }),
+        setAllowedHosts: vi.fn(),
+        navigateGemini: vi.fn(),
         // Zoom control methods
         getZoomLevel: vi.fn().mockReturnValue(100),
         setZoomLevel: vi.fn(),
         zoomIn: vi.fn(),
         zoomOut: vi.fn(),
+        resetZoom: vi.fn(),
         initializeZoomLevel: vi.fn(),
+        setSavedWindowState: vi.fn(),
         applyZoomLevel: vi.fn(),
         _reset() {
             Object.values(manager).forEach((value) => {
@@ -210,7 +291,7 @@ export function createMockWindowManager(overrides?: Partial<Omit<MockWindowManag
 }
 
 /**
- * Creates a mock Store with get/set/has/delete/clear methods.
+ * Creates a mock Store with get/set/setMany/has/delete/clear methods.
  *
  * The mock store maintains an internal defaults object that is used for `get()` calls.
  * You can override defaults by passing them in, or override specific methods.
@@ -241,6 +322,9 @@ export function createMockStore(
         set: vi.fn((key: string, value: unknown) => {
             store._defaults[key] = value;
         }),
+        setMany: vi.fn((values: Record<string, unknown>) => {
+            Object.assign(store._defaults, values);
+        }),
         has: vi.fn((key: string) => key in store._defaults),
         delete: vi.fn((key: string) => {
             delete store._defaults[key];
@@ -252,6 +336,7 @@ export function createMockStore(
             store._defaults = { ...defaults };
             store.get.mockClear();
             store.set.mockClear();
+            store.setMany.mockClear();
             store.has.mockClear();
             store.delete.mockClear();
             store.clear.mockClear();
@@ -276,6 +361,7 @@ export function createMockStore(
  */
 export function createMockUpdateManager(overrides?: Partial<Omit<MockUpdateManager, '_reset'>>): MockUpdateManager {
     const manager: MockUpdateManager = {
+        isSupported: vi.fn().mockReturnValue(true),
         isEnabled: vi.fn().mockReturnValue(true),
         setEnabled: vi.fn(),
         checkForUpdates: vi.fn().mockResolvedValue(undefined),
@@ -313,9 +399,21 @@ export function createMockExportManager(overrides?: Partial<Omit<MockExportManag
     const manager: MockExportManager = {
         exportToPdf: vi.fn().mockResolvedValue(undefined),
         exportToMarkdown: vi.fn().mockResolvedValue(undefined),
+        exportToConfluence: vi.fn().mockResolvedValue(undefined),
+        exportCanvasToMarkdown: vi.fn().mockResolvedValue(undefined),
+        exportResearchReport: vi.fn().mockResolvedValue(undefined),
+        getLastResponse: vi.fn().mockResolvedValue(null),
+        getConversationMarkdown: vi.fn().mockResolvedValue(null),
+        cancelExport: vi.fn().mockReturnValue(true),
         _reset() {
             manager.exportToPdf.mockClear();
             manager.exportToMarkdown.mockClear();
+            manager.exportToConfluence.mockClear();
+            manager.exportCanvasToMarkdown.mockClear();
+            manager.exportResearchReport.mockClear();
+            manager.getLastResponse.mockClear();
+            manager.getConversationMarkdown.mockClear();
+            manager.cancelExport.mockClear();
         },
         ...overrides,
     };
@@ -365,6 +463,7 @@ export function createMockHotkeyManager(overrides?: Partial<Omit<MockHotkeyManag
         executeHotkeyAction: vi.fn(),
         getGlobalHotkeyActions: vi.fn().mockReturnValue([]),
         getApplicationHotkeyActions: vi.fn().mockReturnValue([]),
+        areGlobalHotkeysSupported: vi.fn().mockReturnValue(true),
         _reset() {
             Object.values(manager).forEach((value) => {
                 if (typeof value === 'function' && value !== manager._reset && 'mockClear' in value) {
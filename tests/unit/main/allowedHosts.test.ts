@@ -0,0 +1,35 @@
+/**
+ * Unit tests for the Gemini navigation allow-list.
+ */
+import { describe, it, expect } from 'vitest';
+import { parseAllowedHosts, isAllowedHost } from '../../../src/main/utils/allowedHosts';
+
+describe('allowedHosts', () => {
+    describe('parseAllowedHosts', () => {
+        it('splits on newlines, commas and spaces', () => {
+            expect(parseAllowedHosts('a.com\nb.org, c.net d.io')).toEqual(['a.com', 'b.org', 'c.net', 'd.io']);
+        });
+
+        it('lowercases and removes duplicates', () => {
+            expect(parseAllowedHosts('Example.COM\nexample.com')).toEqual(['example.com']);
+        });
+
+        it('drops entries that are not hostnames', () => {
+            expect(parseAllowedHosts('https://example.com\nlocalhost\n*.example.com\n\n')).toEqual([]);
+        });
+    });
+
+    describe('isAllowedHost', () => {
+        const hosts = ['example.com'];
+
+        it('matches the host and its subdomains', () => {
+            expect(isAllowedHost('example.com', hosts)).toBe(true);
+            expect(isAllowedHost('Docs.Example.com', hosts)).toBe(true);
+        });
+
+        it('does not match other hosts sharing a suffix', () => {
+            expect(isAllowedHost('notexample.com', hosts)).toBe(false);
+            expect(isAllowedHost('example.com.evil.net', hosts)).toBe(false);
+        });
+    });
+});
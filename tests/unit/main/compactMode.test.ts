@@ -0,0 +1,39 @@
+/**
+ * Unit tests for compact mode.
+ */
+import { describe, it, expect } from 'vitest';
+import { buildCompactModeCss, COMPACT_MODE_WIDTH, getCompactModeBounds } from '../../../src/main/utils/compactMode';
+import { GEMINI_SIDEBAR_SELECTORS } from '../../../src/main/utils/geminiSelectors';
+
+describe('compactMode', () => {
+    it('docks a full-height strip at the right edge of the work area', () => {
+        expect(getCompactModeBounds({ x: 1920, y: 40, width: 2560, height: 1400 })).toEqual({
+            x: 1920 + 2560 - COMPACT_MODE_WIDTH,
+            y: 40,
+            width: COMPACT_MODE_WIDTH,
+            height: 1400,
+        });
+    });
+
+    it('fits narrow work areas', () => {
+        expect(getCompactModeBounds({ x: 0, y: 0, width: 300, height: 600 })).toEqual({
+            x: 0,
+            y: 0,
+            width: 300,
+            height: 600,
+        });
+    });
+
+    it('hides every sidebar selector', () => {
+        const css = buildCompactModeCss(true);
+
+        for (const selector of GEMINI_SIDEBAR_SELECTORS) {
+            expect(css).toContain(selector);
+        }
+        expect(css).toContain('display: none !important');
+    });
+
+    it('adds no CSS when the sidebar is kept', () => {
+        expect(buildCompactModeCss(false)).toBe('');
+    });
+});
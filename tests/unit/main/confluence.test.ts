@@ -0,0 +1,107 @@
+/**
+ * Unit tests for Confluence export helpers.
+ */
+import { describe, it, expect, vi } from 'vitest';
+import {
+    validateConfluenceSettings,
+    toConfluenceStorage,
+    publishConfluencePage,
+} from '../../../src/main/utils/confluence';
+import { DEFAULT_CONFLUENCE_SETTINGS, type ConfluenceSettings } from '../../../src/shared/types/confluence';
+
+const settings: ConfluenceSettings = {
+    baseUrl: 'https://example.atlassian.net/wiki/',
+    email: 'me@example.com',
+    apiToken: 'token',
+    spaceKey: 'TEAM',
+    parentId: '42',
+};
+
+const jsonResponse = (body: unknown) => ({ ok: true, status: 200, statusText: 'OK', json: async () => body });
+
+describe('confluence', () => {
+    describe('validateConfluenceSettings', () => {
+        it('accepts empty settings unless a complete configuration is required', () => {
+            expect(validateConfluenceSettings(DEFAULT_CONFLUENCE_SETTINGS)).toBeNull();
+            expect(validateConfluenceSettings(DEFAULT_CONFLUENCE_SETTINGS, true)).toMatch(/URL/);
+            expect(validateConfluenceSettings(settings, true)).toBeNull();
+        });
+
+        it('rejects malformed values', () => {
+            expect(validateConfluenceSettings({ ...settings, baseUrl: 'http://example.com' })).toMatch(/https/);
+            expect(validateConfluenceSettings({ ...settings, baseUrl: 'not a url' })).toMatch(/URL/);
+            expect(validateConfluenceSettings({ ...settings, spaceKey: 'A B' })).toMatch(/space key/);
+            expect(validateConfluenceSettings({ ...settings, parentId: 'abc' })).toMatch(/parent/);
+            expect(validateConfluenceSettings({ ...settings, apiToken: '' }, true)).toMatch(/token/);
+        });
+    });
+
+    describe('toConfluenceStorage', () => {
+        it('converts code blocks to code macros', () => {
+            const html = '<pre><code class="language-ts">if (a &lt; b) {}\n</code></pre>';
+
+            expect(toConfluenceStorage(html)).toBe(
+                '<ac:structured-macro ac:name="code"><ac:parameter ac:name="language">ts</ac:parameter>' +
+                    '<ac:plain-text-body><![CDATA[if (a < b) {}]]></ac:plain-text-body></ac:structured-macro>'
+            );
+        });
+
+        it('keeps CDATA terminators inside code intact', () => {
+            expect(toConfluenceStorage('<pre><code>a]]&gt;b</code></pre>')).toContain('<![CDATA[a]]]]><![CDATA[>b]]>');
+        });
+
+        it('self-closes void elements and replaces named entities XHTML lacks', () => {
+            expect(toConfluenceStorage('<p>a<br>b&nbsp;c</p><hr><img src="x.png" alt="x">')).toBe(
+                '<p>a<br />b&#160;c</p><hr /><img src="x.png" alt="x" />'
+            );
+        });
+    });
+
+    describe('publishConfluencePage', () => {
+        it('creates a page under the parent when none exists', async () => {
+            const links = { base: 'https://example.atlassian.net/wiki', webui: '/x/1' };
+            const fetch = vi
+                .fn()
+                .mockResolvedValueOnce(jsonResponse({ results: [] }))
+                .mockResolvedValueOnce(jsonResponse({ _links: links }));
+
+            const url = await publishConfluencePage({ fetch } as any, settings, 'Chat', '<p>hi</p>');
+
+            expect(fetch.mock.calls[0][0]).toBe(
+                'https://example.atlassian.net/wiki/rest/api/content?spaceKey=TEAM&title=Chat&expand=version'
+            );
+            const [createUrl, init] = fetch.mock.calls[1];
+            expect(createUrl).toBe('https://example.atlassian.net/wiki/rest/api/content');
+            expect(init.method).toBe('POST');
+            expect(init.headers.Authorization).toBe(`Basic ${Buffer.from('me@example.com:token').toString('base64')}`);
+            expect(JSON.parse(init.body)).toMatchObject({
+                title: 'Chat',
+                space: { key: 'TEAM' },
+                ancestors: [{ id: '42' }],
+                body: { storage: { value: '<p>hi</p>', representation: 'storage' } },
+            });
+            expect(url).toBe('https://example.atlassian.net/wiki/x/1');
+        });
+
+        it('updates an existing page with the next version number', async () => {
+            const fetch = vi
+                .fn()
+                .mockResolvedValueOnce(jsonResponse({ results: [{ id: '7', version: { number: 3 } }] }))
+                .mockResolvedValueOnce(jsonResponse({}));
+
+            await publishConfluencePage({ fetch } as any, { ...settings, email: '' }, 'Chat', '<p>hi</p>');
+
+            const [updateUrl, init] = fetch.mock.calls[1];
+            expect(updateUrl).toBe('https://example.atlassian.net/wiki/rest/api/content/7');
+            expect(init.method).toBe('PUT');
+            expect(init.headers.Authorization).toBe('Bearer token');
+            expect(JSON.parse(init.body).version).toEqual({ number: 4 });
+        });
+
+        it('throws on HTTP errors', async () => {
+            const fetch = vi.fn().mockResolvedValue({ ok: false, status: 401, statusText: 'Unauthorized' });
+
+            await expect(publishConfluencePage({ fetch } as any, settings, 'Chat', '')).rejects.toThrow(/401/);
+        });
+    });
+});
@@ -0,0 +1,99 @@
+/**
+ * Unit tests for the data directory lock.
+ * @module dataDirLock.test
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+
+type LockModule = typeof import('../../../src/main/utils/dataDirLock');
+
+describe('dataDirLock', () => {
+    let dataDir: string;
+    let lock: LockModule;
+
+    const lockPath = () => path.join(dataDir, lock.DATA_DIR_LOCK_FILENAME);
+
+    const writeForeignLock = (owner: Record<string, unknown>) => {
+        fs.writeFileSync(lockPath(), JSON.stringify(owner));
+    };
+
+    beforeEach(async () => {
+        // Lock state is module-level; start every test from a fresh module
+        vi.resetModules();
+        lock = await import('../../../src/main/utils/dataDirLock');
+        dataDir = fs.mkdtempSync(path.join(os.tmpdir(), 'gemini-lock-'));
+    });
+
+    afterEach(() => {
+        lock.releaseDataDirLock();
+        fs.rmSync(dataDir, { recursive: true, force: true });
+    });
+
+    it('acquires the lock and writes the owner', () => {
+        expect(lock.acquireDataDirLock(dataDir)).toEqual({ acquired: true });
+        expect(lock.isDataDirReadOnly()).toBe(false);
+
+        const owner = JSON.parse(fs.readFileSync(lockPath(), 'utf-8'));
+        expect(owner.pid).toBe(process.pid);
+        expect(owner.hostname).toBe(os.hostname());
+    });
+
+    it('removes the lock file on release', () => {
+        lock.acquireDataDirLock(dataDir);
+        lock.releaseDataDirLock();
+        expect(fs.existsSync(lockPath())).toBe(false);
+    });
+
+    it('falls back to read-only mode when another host holds the lock', () => {
+        writeForeignLock({ pid: 1234, hostname: 'other-machine', username: 'alice', createdAt: '' });
+
+        const result = lock.acquireDataDirLock(dataDir);
+
+        expect(result.acquired).toBe(false);
+        if (!result.acquired) {
+            expect(result.error).toBeInstanceOf(lock.DataDirLockedError);
+            expect(result.error.code).toBe('DATA_DIR_LOCKED');
+            expect(result.error.message).toContain('alice@other-machine (pid 1234)');
+        }
+        expect(lock.isDataDirReadOnly()).toBe(true);
+    });
+
+    it('does not remove a lock it does not hold', () => {
+        writeForeignLock({ pid: 1234, hostname: 'other-machine', username: 'alice', createdAt: '' });
+        lock.acquireDataDirLock(dataDir);
+        lock.releaseDataDirLock();
+        expect(fs.existsSync(lockPath())).toBe(true);
+    });
+
+    it('takes over a stale lock from a dead local process', () => {
+        const killSpy = vi.spyOn(process, 'kill').mockImplementation(() => {
+            throw Object.assign(new Error('no such process'), { code: 'ESRCH' });
+        });
+        writeForeignLock({ pid: 999999, hostname: os.hostname(), username: 'bob', createdAt: '' });
+
+        expect(lock.acquireDataDirLock(dataDir)).toEqual({ acquired: true });
+        expect(JSON.parse(fs.readFileSync(lockPath(), 'utf-8')).pid).toBe(process.pid);
+        killSpy.mockRestore();
+    });
+
+    it('takes over a corrupt lock file once it is old', () => {
+        fs.writeFileSync(lockPath(), 'not json');
+        const old = new Date(Date.now() - 60_000);
+        fs.utimesSync(lockPath(), old, old);
+
+        expect(lock.acquireDataDirLock(dataDir)).toEqual({ acquired: true });
+    });
+
+    it('respects an empty lock file that another instance is still writing', () => {
+        fs.writeFileSync(lockPath(), '');
+
+        const result = lock.acquireDataDirLock(dataDir);
+
+        expect(result.acquired).toBe(false);
+        expect(lock.isDataDirReadOnly()).toBe(true);
+        expect(fs.existsSync(lockPath())).toBe(true);
+    });
+});
@@ -0,0 +1,106 @@
+/**
+ * Unit tests for the low disk space guard.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+const { mockStatfs } = vi.hoisted(() => ({ mockStatfs: vi.fn() }));
+
+vi.mock('fs/promises', () => ({ statfs: mockStatfs }));
+vi.mock('../../../src/main/utils/logger');
+
+import {
+    checkDiskSpace,
+    DiskFullError,
+    ensureDiskSpace,
+    getDiskSpaceStatus,
+    isDiskFullError,
+    isDiskSpaceLow,
+    onDiskSpaceChanged,
+    resetDiskSpace,
+    setLowDiskSpaceThreshold,
+} from '../../../src/main/utils/diskSpace';
+
+const MB = 1024 * 1024;
+
+/** Make statfs report this many free megabytes */
+const freeMb = (mb: number) => mockStatfs.mockResolvedValue({ bavail: mb * 256, bsize: 4096 });
+
+describe('diskSpace', () => {
+    beforeEach(() => {
+        vi.clearAllMocks();
+        resetDiskSpace();
+        setLowDiskSpaceThreshold(500);
+    });
+
+    describe('ensureDiskSpace', () => {
+        it('allows writes that leave enough space', async () => {
+            freeMb(1000);
+
+            await expect(ensureDiskSpace('/exports', 100 * MB)).resolves.toBeUndefined();
+        });
+
+        it('refuses writes that would go below the threshold', async () => {
+            freeMb(550);
+
+            const error = await ensureDiskSpace('/exports', 100 * MB).catch((e) => e);
+
+            expect(error).toBeInstanceOf(DiskFullError);
+            expect(error.code).toBe('DISK_FULL');
+            expect(error.message).toBe(
+                'Not enough disk space: 550 MB free, at least 600 MB needed. Free up space and try again.'
+            );
+        });
+
+        it('allows writes when free space cannot be read', async () => {
+            mockStatfs.mockRejectedValue(new Error('ENOSYS'));
+
+            await expect(ensureDiskSpace('/exports', 100 * MB)).resolves.toBeUndefined();
+        });
+
+        it('allows every write when the threshold is 0', async () => {
+            freeMb(1);
+            setLowDiskSpaceThreshold(0);
+
+            await expect(ensureDiskSpace('/exports', 100 * MB)).resolves.toBeUndefined();
+            expect(mockStatfs).not.toHaveBeenCalled();
+        });
+    });
+
+    describe('isDiskFullError', () => {
+        it('recognizes the guard and the OS running out of space', () => {
+            expect(isDiskFullError(new DiskFullError(0, MB))).toBe(true);
+            expect(isDiskFullError(Object.assign(new Error('no space'), { code: 'ENOSPC' }))).toBe(true);
+            expect(isDiskFullError(Object.assign(new Error('denied'), { code: 'EACCES' }))).toBe(false);
+            expect(isDiskFullError(null)).toBe(false);
+        });
+    });
+
+    describe('checkDiskSpace', () => {
+        it('notifies listeners only when the threshold is crossed', async () => {
+            const listener = vi.fn();
+            const unsubscribe = onDiskSpaceChanged(listener);
+
+            freeMb(400);
+            await checkDiskSpace('/data');
+            await checkDiskSpace('/data');
+            expect(isDiskSpaceLow()).toBe(true);
+            expect(listener).toHaveBeenCalledTimes(1);
+            expect(listener).toHaveBeenCalledWith({ freeBytes: 400 * MB, thresholdBytes: 500 * MB, low: true });
+
+            freeMb(2000);
+            await checkDiskSpace('/data');
+            expect(isDiskSpaceLow()).toBe(false);
+            expect(listener).toHaveBeenCalledTimes(2);
+
+            unsubscribe();
+        });
+
+        it('does not report low space when free space cannot be read', async () => {
+            mockStatfs.mockRejectedValue(new Error('ENOSYS'));
+
+            await checkDiskSpace('/data');
+
+            expect(getDiskSpaceStatus()).toEqual({ freeBytes: null, thresholdBytes: 500 * MB, low: false });
+        });
+    });
+});
@@ -0,0 +1,31 @@
+/**
+ * Unit tests for settings environment variable overrides.
+ */
+import { describe, it, expect } from 'vitest';
+import { toEnvVarName, parseEnvValue, readEnvOverrides } from '../../../src/main/utils/envOverrides';
+
+describe('envOverrides', () => {
+    it('maps setting keys to environment variable names', () => {
+        expect(toEnvVarName('zoomLevel')).toBe('GEMINI_DESKTOP_ZOOM_LEVEL');
+        expect(toEnvVarName('hotkeyBossKey')).toBe('GEMINI_DESKTOP_HOTKEY_BOSS_KEY');
+        expect(toEnvVarName('theme')).toBe('GEMINI_DESKTOP_THEME');
+    });
+
+    it('parses values by type', () => {
+        expect(parseEnvValue('yes', 'boolean')).toBe(true);
+        expect(parseEnvValue('0', 'boolean')).toBe(false);
+        expect(parseEnvValue('maybe', 'boolean')).toBeUndefined();
+        expect(parseEnvValue('125', 'number')).toBe(125);
+        expect(parseEnvValue('', 'number')).toBeUndefined();
+        expect(parseEnvValue('dark', 'string')).toBe('dark');
+    });
+
+    it('reads only variables that are set and reports unparseable ones', () => {
+        const env = { GEMINI_DESKTOP_ZOOM_LEVEL: '150', GEMINI_DESKTOP_ALWAYS_ON_TOP: 'sometimes' };
+
+        expect(readEnvOverrides({ zoomLevel: 'number', alwaysOnTop: 'boolean', theme: 'string' }, env)).toEqual({
+            overrides: { zoomLevel: 150 },
+            invalid: ['GEMINI_DESKTOP_ALWAYS_ON_TOP'],
+        });
+    });
+});
@@ -0,0 +1,64 @@
+/**
+ * Unit tests for the app event bus.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+    clearAppEvents,
+    EVENT_REPLAY_LIMIT,
+    getRecentAppEvents,
+    onAppEvent,
+    publishAppEvent,
+} from '../../../src/main/utils/events';
+
+describe('events', () => {
+    beforeEach(() => {
+        clearAppEvents();
+    });
+
+    it('notifies subscribers with the published event', () => {
+        const listener = vi.fn();
+        const unsubscribe = onAppEvent(listener);
+
+        const event = publishAppEvent('zoom:level-changed', 110);
+
+        expect(listener).toHaveBeenCalledWith(event);
+        expect(event).toMatchObject({ name: 'zoom:level-changed', payload: 110 });
+        unsubscribe();
+    });
+
+    it('stops notifying after unsubscribe', () => {
+        const listener = vi.fn();
+        onAppEvent(listener)();
+
+        publishAppEvent('always-on-top:changed', { enabled: true });
+
+        expect(listener).not.toHaveBeenCalled();
+    });
+
+    it('keeps recent events for late subscribers, oldest first', () => {
+        publishAppEvent('zoom:level-changed', 90);
+        publishAppEvent('immersive-mode:changed', { enabled: true });
+
+        expect(getRecentAppEvents().map((event) => event.name)).toEqual([
+            'zoom:level-changed',
+            'immersive-mode:changed',
+        ]);
+    });
+
+    it('returns only events after the given sequence number', () => {
+        const first = publishAppEvent('zoom:level-changed', 90);
+        const second = publishAppEvent('zoom:level-changed', 100);
+
+        expect(getRecentAppEvents(first.seq)).toEqual([second]);
+    });
+
+    it('drops the oldest events beyond the replay limit', () => {
+        for (let level = 0; level <= EVENT_REPLAY_LIMIT; level++) {
+            publishAppEvent('zoom:level-changed', level);
+        }
+
+        const recent = getRecentAppEvents();
+        expect(recent).toHaveLength(EVENT_REPLAY_LIMIT);
+        expect(recent[0]?.payload).toBe(1);
+    });
+});
@@ -0,0 +1,111 @@
+/**
+ * Unit tests for export filename template utilities.
+ * @module exportFilename.test
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+    buildExportFilename,
+    sanitizeFilename,
+    isValidExportFilenameTemplate,
+    DEFAULT_EXPORT_FILENAME_TEMPLATE,
+    MAX_EXPORT_FILENAME_LENGTH,
+} from '../../../src/main/utils/exportFilename';
+
+describe('exportFilename utilities', () => {
+    const timestamp = new Date(2025, 0, 5, 9, 7, 3);
+    const values = { title: 'My Chat Title', timestamp, id: 'abc123', format: 'md' };
+
+    describe('buildExportFilename', () => {
+        it('uses the title only with the default template', () => {
+            expect(buildExportFilename(DEFAULT_EXPORT_FILENAME_TEMPLATE, values, 'linux')).toBe('My_Chat_Title.md');
+        });
+
+        it('expands all supported tokens', () => {
+            const result = buildExportFilename('{date}_{time}_{title}_{id}.{format}', values, 'linux');
+            expect(result).toBe('2025-01-05_09-07-03_My_Chat_Title_abc123.md.md');
+        });
+
+        it('leaves unknown tokens untouched', () => {
+            expect(buildExportFilename('{title}-{unknown}', values, 'linux')).toBe('My_Chat_Title-{unknown}.md');
+        });
+
+        it('substitutes an empty string when id is missing', () => {
+            expect(buildExportFilename('{title}{id}', { ...values, id: undefined }, 'linux')).toBe('My_Chat_Title.md');
+        });
+
+        it('expands the Gem name, empty for plain Gemini chats', () => {
+            expect(buildExportFilename('{gem}-{title}', { ...values, gem: 'Writing Coach' }, 'linux')).toBe(
+                'Writing_Coach-My_Chat_Title.md'
+            );
+            expect(buildExportFilename('{title}{gem}', values, 'linux')).toBe('My_Chat_Title.md');
+        });
+
+        it('accepts ISO string timestamps', () => {
+            const result = buildExportFilename('{date}', { ...values, timestamp: timestamp.toISOString() }, 'linux');
+            expect(result).toBe('2025-01-05.md');
+        });
+
+        it('falls back to the default template for invalid templates', () => {
+            expect(buildExportFilename('   ', values, 'linux')).toBe('My_Chat_Title.md');
+        });
+
+        it('sanitizes characters coming from the title', () => {
+            const result = buildExportFilename('{title}', { ...values, title: 'a/b:c' }, 'win32');
+            expect(result).toBe('a-b-c.md');
+        });
+    });
+
+    describe('sanitizeFilename', () => {
+        it('replaces Windows-illegal characters on win32', () => {
+            expect(sanitizeFilename('a<b>c:d"e/f\\g|h?i*j', 'win32')).toBe('a-b-c-d-e-f-g-h-i-j');
+        });
+
+        it('only replaces slashes and colons on macOS', () => {
+            expect(sanitizeFilename('a/b:c?d', 'darwin')).toBe('a-b-c?d');
+        });
+
+        it('only replaces slashes on Linux', () => {
+            expect(sanitizeFilename('a/b:c?d', 'linux')).toBe('a-b:c?d');
+        });
+
+        it('strips control characters', () => {
+            expect(sanitizeFilename('a\u0000b\u001fc', 'linux')).toBe('abc');
+        });
+
+        it('strips trailing dots and spaces on win32', () => {
+            expect(sanitizeFilename('chat. . ', 'win32')).toBe('chat');
+        });
+
+        it('prefixes Windows reserved device names', () => {
+            expect(sanitizeFilename('CON', 'win32')).toBe('_CON');
+            expect(sanitizeFilename('lpt1', 'win32')).toBe('_lpt1');
+            expect(sanitizeFilename('CON', 'linux')).toBe('CON');
+        });
+
+        it('strips leading dots to avoid hidden files', () => {
+            expect(sanitizeFilename('..chat', 'linux')).toBe('chat');
+        });
+
+        it('truncates long names', () => {
+            expect(sanitizeFilename('x'.repeat(500), 'linux')).toHaveLength(MAX_EXPORT_FILENAME_LENGTH);
+        });
+
+        it('falls back to a default name when nothing remains', () => {
+            expect(sanitizeFilename('...', 'linux')).toBe('gemini-chat');
+        });
+    });
+
+    describe('isValidExportFilenameTemplate', () => {
+        it('accepts non-empty strings', () => {
+            expect(isValidExportFilenameTemplate('{title}')).toBe(true);
+        });
+
+        it('rejects empty, whitespace-only, oversized and non-string values', () => {
+            expect(isValidExportFilenameTemplate('')).toBe(false);
+            expect(isValidExportFilenameTemplate('   ')).toBe(false);
+            expect(isValidExportFilenameTemplate('x'.repeat(1000))).toBe(false);
+            expect(isValidExportFilenameTemplate(42)).toBe(false);
+        });
+    });
+});
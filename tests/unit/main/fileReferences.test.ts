@@ -0,0 +1,73 @@
+/**
+ * Unit tests for local file reference detection.
+ * @module fileReferences.test
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+    findFileReferences,
+    toFileUrl,
+    buildFileReferenceIndexMarkdown,
+    buildFileReferenceIndexHtml,
+} from '../../../src/main/utils/fileReferences';
+
+describe('fileReferences', () => {
+    describe('findFileReferences', () => {
+        const texts = (text: string) => findFileReferences(text, '/home/me').map((ref) => ref.text);
+
+        it('detects absolute POSIX paths with an extension', () => {
+            expect(texts('See /home/me/notes/plan.md for details.')).toEqual(['/home/me/notes/plan.md']);
+        });
+
+        it('detects Windows drive paths', () => {
+            expect(texts('Open C:\\Users\\me\\report.docx, then edit it.')).toEqual(['C:\\Users\\me\\report.docx']);
+        });
+
+        it('detects home-relative paths and file URLs', () => {
+            expect(texts('Copy ~/todo.txt to file:///tmp/out.txt')).toEqual(['~/todo.txt', 'file:///tmp/out.txt']);
+        });
+
+        it('ignores web URLs, relative paths and paths without an extension', () => {
+            expect(texts('https://example.com/a/b.html and src/main/app.ts and /usr/bin and and/or')).toEqual([]);
+        });
+
+        it('lists each reference once in order of appearance', () => {
+            expect(texts('/a/b.md then /c/d.md then /a/b.md')).toEqual(['/a/b.md', '/c/d.md']);
+        });
+    });
+
+    describe('toFileUrl', () => {
+        it('expands the home directory', () => {
+            expect(toFileUrl('~/my notes/a.md', '/home/me')).toBe('file:///home/me/my%20notes/a.md');
+        });
+
+        it('converts Windows paths', () => {
+            expect(toFileUrl('C:\\Users\\me\\a#1.txt')).toBe('file:///C:/Users/me/a%231.txt');
+        });
+
+        it('keeps file URLs unchanged', () => {
+            expect(toFileUrl('file:///tmp/a.txt')).toBe('file:///tmp/a.txt');
+        });
+    });
+
+    describe('index builders', () => {
+        const references = [{ text: '/tmp/<a>.md', url: 'file:///tmp/%3Ca%3E.md' }];
+
+        it('return nothing without references', () => {
+            expect(buildFileReferenceIndexMarkdown([])).toBe('');
+            expect(buildFileReferenceIndexHtml([])).toBe('');
+        });
+
+        it('builds a Markdown link list', () => {
+            expect(buildFileReferenceIndexMarkdown(references)).toBe(
+                '## Referenced files\n\n- [/tmp/<a>.md](<file:///tmp/%3Ca%3E.md>)\n'
+            );
+        });
+
+        it('escapes the HTML link list', () => {
+            expect(buildFileReferenceIndexHtml(references)).toContain(
+                '<a href="file:///tmp/%3Ca%3E.md">/tmp/&lt;a&gt;.md</a>'
+            );
+        });
+    });
+});
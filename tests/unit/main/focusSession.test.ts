@@ -0,0 +1,93 @@
+/**
+ * Unit tests for focus sessions.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+vi.mock('../../../src/main/utils/logger');
+
+import {
+    buildFocusSessionMarkdown,
+    endFocusSession,
+    getFocusSessionStatus,
+    isFocusSessionActive,
+    onFocusSessionChanged,
+    recordFocusSessionEntry,
+    resetFocusSession,
+    startFocusSession,
+} from '../../../src/main/utils/focusSession';
+
+const MINUTE = 60 * 1000;
+
+describe('focusSession', () => {
+    beforeEach(() => {
+        resetFocusSession();
+    });
+
+    it('starts a session of the given length and emits the change', () => {
+        const listener = vi.fn();
+        const unsubscribe = onFocusSessionChanged(listener);
+
+        const status = startFocusSession(25, 1000);
+
+        expect(status).toEqual({ active: true, startedAt: 1000, endsAt: 1000 + 25 * MINUTE, entryCount: 0 });
+        expect(isFocusSessionActive()).toBe(true);
+        expect(listener).toHaveBeenCalledWith(status);
+        unsubscribe();
+    });
+
+    it('clamps the session length', () => {
+        expect(startFocusSession(0, 0).endsAt).toBe(MINUTE);
+        expect(startFocusSession(1000, 0).endsAt).toBe(240 * MINUTE);
+    });
+
+    it('logs answers only while a session runs', () => {
+        const entry = { time: 1, prompt: 'Hi', answer: 'Hello' };
+        recordFocusSessionEntry(entry);
+        startFocusSession(25, 0);
+
+        recordFocusSessionEntry(entry);
+
+        expect(getFocusSessionStatus().entryCount).toBe(1);
+    });
+
+    it('returns the ended session with its log', () => {
+        const listener = vi.fn();
+        onFocusSessionChanged(listener);
+        startFocusSession(25, 0);
+        recordFocusSessionEntry({ time: 1, prompt: 'Hi', answer: 'Hello' });
+
+        const session = endFocusSession();
+
+        expect(session?.entries).toHaveLength(1);
+        expect(isFocusSessionActive()).toBe(false);
+        expect(listener).toHaveBeenLastCalledWith({ active: false, startedAt: null, endsAt: null, entryCount: 0 });
+        expect(endFocusSession()).toBeNull();
+    });
+});
+
+describe('buildFocusSessionMarkdown', () => {
+    it('lists each prompt with its answer', () => {
+        const markdown = buildFocusSessionMarkdown(
+            {
+                startedAt: 0,
+                endsAt: 25 * MINUTE,
+                entries: [
+                    { time: MINUTE, prompt: 'What is 2+2?', answer: '**4**', modelName: '2.5 Pro' },
+                    { time: 2 * MINUTE, prompt: '', answer: 'Done' },
+                ],
+            },
+            20 * MINUTE
+        );
+
+        expect(markdown).toContain('# Focus Session');
+        expect(markdown).toContain('(20 min, 2 answers)');
+        expect(markdown).toContain('### You\n\nWhat is 2+2?\n\n### Gemini (2.5 Pro)\n\n**4**');
+        expect(markdown).toContain('*Prompt not available*');
+    });
+
+    it('says when nothing was logged', () => {
+        const markdown = buildFocusSessionMarkdown({ startedAt: 0, endsAt: MINUTE, entries: [] }, MINUTE);
+
+        expect(markdown).toContain('No answers were logged during this session.');
+    });
+});
@@ -0,0 +1,47 @@
+/**
+ * Unit tests for the Gemini quota estimate.
+ */
+import { describe, it, expect } from 'vitest';
+import { getGeminiQuotaStatus, isGeminiQuotaPlan } from '../../../src/main/utils/geminiQuota';
+import { GEMINI_PLAN_DAILY_PROMPTS } from '../../../src/shared/types/usage';
+
+describe('geminiQuota', () => {
+    it('recognises the known plans', () => {
+        expect(isGeminiQuotaPlan('pro')).toBe(true);
+        expect(isGeminiQuotaPlan('advanced')).toBe(false);
+        expect(isGeminiQuotaPlan(undefined)).toBe(false);
+    });
+
+    it('has no quota when off', () => {
+        expect(getGeminiQuotaStatus({ plan: 'off', customDailyPrompts: 50 }, 10)).toEqual({
+            plan: 'off',
+            customDailyPrompts: 50,
+            dailyPrompts: 0,
+            used: 10,
+            remaining: 0,
+            level: 'ok',
+        });
+    });
+
+    it("uses the plan's published quota", () => {
+        const status = getGeminiQuotaStatus({ plan: 'pro', customDailyPrompts: 0 }, 10);
+
+        expect(status.dailyPrompts).toBe(GEMINI_PLAN_DAILY_PROMPTS.pro);
+        expect(status.remaining).toBe(GEMINI_PLAN_DAILY_PROMPTS.pro - 10);
+        expect(status.level).toBe('ok');
+    });
+
+    it('uses the custom quota', () => {
+        expect(getGeminiQuotaStatus({ plan: 'custom', customDailyPrompts: 20 }, 5).remaining).toBe(15);
+        expect(getGeminiQuotaStatus({ plan: 'custom', customDailyPrompts: 0 }, 5).dailyPrompts).toBe(0);
+    });
+
+    it('warns from 80% of the quota and reports it used up', () => {
+        const custom = { plan: 'custom' as const, customDailyPrompts: 10 };
+
+        expect(getGeminiQuotaStatus(custom, 7).level).toBe('ok');
+        expect(getGeminiQuotaStatus(custom, 8).level).toBe('warning');
+        expect(getGeminiQuotaStatus(custom, 10).level).toBe('exhausted');
+        expect(getGeminiQuotaStatus(custom, 12)).toMatchObject({ remaining: 0, level: 'exhausted' });
+    });
+});
@@ -0,0 +1,100 @@
+/**
+ * Unit tests for headless export argument parsing.
+ * @module headlessExport.test
+ */
+
+import { describe, it, expect, vi } from 'vitest';
+import * as path from 'path';
+
+vi.mock('electron', () => ({
+    safeStorage: { decryptString: vi.fn() },
+    webContents: { getAllWebContents: vi.fn(() => []) },
+}));
+
+import { parseHeadlessExportArgs, runHeadlessExportJob } from '../../../src/main/utils/headlessExport';
+
+describe('parseHeadlessExportArgs', () => {
+    const url = 'https://gemini.google.com/app/abc123';
+    const cwd = path.resolve('/home/user');
+
+    it('is null without the export subcommand', () => {
+        expect(parseHeadlessExportArgs(['gemini-desktop', '--safe-mode'], cwd)).toBeNull();
+    });
+
+    it('ignores export anywhere but the first argument', () => {
+        expect(parseHeadlessExportArgs(['gemini-desktop', '--profile', 'export'], cwd)).toBeNull();
+        expect(parseHeadlessExportArgs(['electron', '.', '--profile', 'export'], cwd, true)).toBeNull();
+    });
+
+    it('parses the flags after the subcommand', () => {
+        expect(
+            parseHeadlessExportArgs(
+                ['gemini-desktop', 'export', '--conversation', url, '--format=pdf', '--out', 'chat.pdf'],
+                cwd
+            )
+        ).toEqual({ conversationUrl: url, format: 'pdf', outPath: path.join(cwd, 'chat.pdf') });
+    });
+
+    it('finds the subcommand after the app path in development', () => {
+        const argv = ['electron', '.', 'export', '--conversation', url, '--out', 'a.md'];
+        expect(parseHeadlessExportArgs(argv, cwd, true)).toMatchObject({
+            format: 'markdown',
+            outPath: path.join(cwd, 'a.md'),
+        });
+    });
+
+    it('reports invalid arguments', () => {
+        expect(
+            parseHeadlessExportArgs(['app', 'export', '--conversation', 'https://example.com', '--out', 'a'], cwd)
+        ).toEqual({ error: '--conversation must be a Gemini conversation URL' });
+        expect(parseHeadlessExportArgs(['app', 'export', '--conversation', url, '--format', 'docx'], cwd)).toEqual({
+            error: '--format must be md or pdf',
+        });
+        expect(parseHeadlessExportArgs(['app', 'export', '--conversation', url], cwd)).toEqual({
+            error: '--out is required',
+        });
+    });
+});
+
+describe('runHeadlessExportJob', () => {
+    const options = {
+        conversationUrl: 'https://gemini.google.com/app/abc123',
+        format: 'markdown' as const,
+        outPath: '/tmp/chat.md',
+    };
+
+    it('applies the saved proxy and User-Agent before loading the conversation', async () => {
+        const calls: string[] = [];
+        const saved: Record<string, unknown> = {
+            proxyMode: 'socks5',
+            proxyHost: 'proxy.corp',
+            proxyPort: 1080,
+            proxyBypass: '',
+            userAgentPreset: 'custom',
+            userAgentCustom: 'Archiver/1.0',
+        };
+        const store = { get: vi.fn((key: string) => saved[key]) };
+        const targetSession = {
+            setProxy: vi.fn(async () => void calls.push('setProxy')),
+            closeAllConnections: vi.fn().mockResolvedValue(undefined),
+            getUserAgent: vi.fn().mockReturnValue('Electron'),
+            setUserAgent: vi.fn(() => void calls.push('setUserAgent')),
+        };
+        const exportManager = {
+            exportConversationToFile: vi.fn(async () => void calls.push('export')),
+        };
+
+        await runHeadlessExportJob(options, store as any, exportManager as any, targetSession as any);
+
+        expect(targetSession.setProxy).toHaveBeenCalledWith(
+            expect.objectContaining({ mode: 'fixed_servers', proxyRules: 'socks5://proxy.corp:1080' })
+        );
+        expect(targetSession.setUserAgent).toHaveBeenCalledWith('Archiver/1.0');
+        expect(exportManager.exportConversationToFile).toHaveBeenCalledWith(
+            options.conversationUrl,
+            'markdown',
+            '/tmp/chat.md'
+        );
+        expect(calls).toEqual(['setProxy', 'setUserAgent', 'export']);
+    });
+});
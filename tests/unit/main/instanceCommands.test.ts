@@ -0,0 +1,204 @@
+/**
+ * Unit tests for the instance command protocol.
+ * @module instanceCommands.test
+ */
+
+import * as fs from 'fs';
+import * as net from 'net';
+import * as os from 'os';
+import * as path from 'path';
+import { describe, it, expect, vi, afterEach } from 'vitest';
+import {
+    getInstanceCommandSocketPath,
+    handleInstanceCommandLine,
+    parseInstanceCommand,
+    parseInstanceCommandArgs,
+    startInstanceCommandServer,
+} from '../../../src/main/utils/instanceCommands';
+
+describe('parseInstanceCommand', () => {
+    it('accepts each command', () => {
+        expect(parseInstanceCommand({ v: 1, command: 'focus' })).toEqual({ command: 'focus' });
+        expect(parseInstanceCommand({ v: 1, command: 'new-window' })).toEqual({ command: 'new-window' });
+        expect(parseInstanceCommand({ v: 1, command: 'toggle-compact-mode' })).toEqual({
+            command: 'toggle-compact-mode',
+        });
+        expect(parseInstanceCommand({ v: 1, command: 'move-to-display', display: 2 })).toEqual({
+            command: 'move-to-display',
+            display: 2,
+        });
+        expect(parseInstanceCommand({ v: 1, command: 'focus-session' })).toEqual({ command: 'focus-session' });
+        expect(parseInstanceCommand({ v: 1, command: 'focus-session', minutes: 50 })).toEqual({
+            command: 'focus-session',
+            minutes: 50,
+        });
+        expect(parseInstanceCommand({ v: 1, command: 'ask', text: 'Hi' })).toEqual({ command: 'ask', text: 'Hi' });
+        expect(parseInstanceCommand({ v: 1, command: 'navigate', url: 'https://gemini.google.com/app/abc' })).toEqual({
+            command: 'navigate',
+            url: 'https://gemini.google.com/app/abc',
+        });
+        expect(parseInstanceCommand({ v: 1, command: 'export', format: 'md' })).toEqual({
+            command: 'export',
+            format: 'markdown',
+        });
+    });
+
+    it('requires the protocol version', () => {
+        expect(parseInstanceCommand({ command: 'focus' })).toHaveProperty('error');
+        expect(parseInstanceCommand({ v: 2, command: 'focus' })).toHaveProperty('error');
+    });
+
+    it('rejects invalid arguments and unknown commands', () => {
+        expect(parseInstanceCommand({ v: 1, command: 'ask', text: '  ' })).toHaveProperty('error');
+        const invalidUrls = ['https://example.com/', 'http://gemini.google.com/', 42];
+        for (const url of invalidUrls) {
+            expect(parseInstanceCommand({ v: 1, command: 'navigate', url })).toHaveProperty('error');
+        }
+        expect(parseInstanceCommand({ v: 1, command: 'export', format: 'docx' })).toHaveProperty('error');
+        for (const minutes of [0, 12.5, 241, '25']) {
+            expect(parseInstanceCommand({ v: 1, command: 'focus-session', minutes })).toHaveProperty('error');
+        }
+        for (const display of [0, 1.5, '2']) {
+            expect(parseInstanceCommand({ v: 1, command: 'move-to-display', display })).toHaveProperty('error');
+        }
+        expect(parseInstanceCommand({ v: 1, command: 'quit' })).toHaveProperty('error');
+        expect(parseInstanceCommand(null)).toHaveProperty('error');
+    });
+});
+
+describe('parseInstanceCommandArgs', () => {
+    it('focuses without a command flag', () => {
+        expect(parseInstanceCommandArgs(['gemini-desktop', '--profile=work'])).toEqual({ command: 'focus' });
+    });
+
+    it('reads --new-window', () => {
+        expect(parseInstanceCommandArgs(['gemini-desktop', '--new-window'])).toEqual({ command: 'new-window' });
+    });
+
+    it('reads --toggle-compact-mode', () => {
+        expect(parseInstanceCommandArgs(['gemini-desktop', '--toggle-compact-mode'])).toEqual({
+            command: 'toggle-compact-mode',
+        });
+    });
+
+    it('reads --move-to-display', () => {
+        expect(parseInstanceCommandArgs(['gemini-desktop', '--move-to-display', '2'])).toEqual({
+            command: 'move-to-display',
+            display: 2,
+        });
+        expect(parseInstanceCommandArgs(['gemini-desktop', '--move-to-display=main'])).toHaveProperty('error');
+    });
+
+    it('reads --focus-session', () => {
+        expect(parseInstanceCommandArgs(['gemini-desktop', '--focus-session=45'])).toEqual({
+            command: 'focus-session',
+            minutes: 45,
+        });
+    });
+
+    it('reads --ask, --navigate and --export', () => {
+        expect(parseInstanceCommandArgs(['gemini-desktop', '--ask', 'Hello'])).toEqual({
+            command: 'ask',
+            text: 'Hello',
+        });
+        expect(parseInstanceCommandArgs(['gemini-desktop', '--navigate=https://gemini.google.com/app/x'])).toEqual({
+            command: 'navigate',
+            url: 'https://gemini.google.com/app/x',
+        });
+        expect(parseInstanceCommandArgs(['gemini-desktop', '--export', 'pdf'])).toEqual({
+            command: 'export',
+            format: 'pdf',
+        });
+    });
+});
+
+describe('getInstanceCommandSocketPath', () => {
+    it('uses a named pipe per data directory on Windows', () => {
+        const work = getInstanceCommandSocketPath('C:\\data\\profiles\\work', 'win32');
+        const home = getInstanceCommandSocketPath('C:\\data\\profiles\\home', 'win32');
+
+        expect(work).toMatch(/^\\\\\.\\pipe\\gemini-desktop-[0-9a-f]{16}$/);
+        expect(work).not.toBe(home);
+    });
+
+    it('uses a socket in the data directory elsewhere', () => {
+        expect(getInstanceCommandSocketPath('/home/me/.config/Gemini Desktop', 'linux')).toBe(
+            path.join('/home/me/.config/Gemini Desktop', 'command.sock')
+        );
+    });
+
+    it('falls back to the temp directory for long data directory paths', () => {
+        const socketPath = getInstanceCommandSocketPath(`/home/me/${'x'.repeat(120)}`, 'darwin');
+
+        expect(socketPath.startsWith(os.tmpdir())).toBe(true);
+    });
+});
+
+describe('handleInstanceCommandLine', () => {
+    it('runs valid commands', async () => {
+        const execute = vi.fn();
+
+        await expect(handleInstanceCommandLine('{"v":1,"command":"focus"}', execute)).resolves.toEqual({ ok: true });
+        expect(execute).toHaveBeenCalledWith({ command: 'focus' });
+    });
+
+    it('reports invalid JSON and failed commands', async () => {
+        const execute = vi.fn(() => {
+            throw new Error('Main window unavailable');
+        });
+
+        await expect(handleInstanceCommandLine('not json', execute)).resolves.toMatchObject({ ok: false });
+        await expect(handleInstanceCommandLine('{"v":1,"command":"focus"}', execute)).resolves.toEqual({
+            ok: false,
+            error: 'Main window unavailable',
+        });
+    });
+});
+
+describe('startInstanceCommandServer', () => {
+    let server: net.Server | null = null;
+
+    afterEach(() => {
+        server?.close();
+        server = null;
+    });
+
+    it('answers requests over the socket in order', async () => {
+        const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'gd-cmd-'));
+        const socketPath = getInstanceCommandSocketPath(dir);
+        const execute = vi.fn();
+        server = startInstanceCommandServer(socketPath, execute);
+        await new Promise((resolve) => server!.once('listening', resolve));
+
+        const replies = await new Promise<string>((resolve, reject) => {
+            let data = '';
+            const client = net.connect(socketPath, () => {
+                client.write('{"v":1,"command":"ask","text":"Hi"}\n{"v":1,"command":"nope"}\n');
+            });
+            client.setEncoding('utf8');
+            client.on('data', (chunk: string) => {
+                data += chunk;
+                if (data.split('\n').length > 2) {
+                    client.end();
+                    resolve(data);
+                }
+            });
+            client.on('error', reject);
+        });
+
+        const lines = replies.trim().split('\n');
+        expect(JSON.parse(lines[0]!)).toEqual({ ok: true });
+        expect(JSON.parse(lines[1]!)).toMatchObject({ ok: false });
+        expect(execute).toHaveBeenCalledWith({ command: 'ask', text: 'Hi' });
+    });
+
+    it.skipIf(process.platform === 'win32')('creates the socket accessible only to the owner', async () => {
+        const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'gd-cmd-'));
+        const socketPath = getInstanceCommandSocketPath(dir);
+
+        server = startInstanceCommandServer(socketPath, vi.fn());
+        await new Promise((resolve) => server!.once('listening', resolve));
+
+        expect(fs.statSync(socketPath).mode & 0o777).toBe(0o600);
+    });
+});
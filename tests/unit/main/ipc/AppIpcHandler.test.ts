@@ -1,7 +1,7 @@
 /**
  * Unit tests for AppIpcHandler.
  *
- * Tests the open-options and open-google-signin IPC handlers.
+ * Tests the open-options, open-google-signin and app:quit IPC handlers.
  */
 import { describe, it, expect, vi, beforeEach } from 'vitest';
 import { AppIpcHandler } from '../../../../src/main/managers/ipc/AppIpcHandler';
@@ -9,7 +9,7 @@ import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/t
 import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
 
 // Mock Electron
-const { mockIpcMain } = vi.hoisted(() => {
+const { mockIpcMain, mockApp } = vi.hoisted(() => {
     const mockIpcMain = {
         on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
             mockIpcMain._listeners.set(channel, listener);
@@ -25,11 +25,12 @@ const { mockIpcMain } = vi.hoisted(() => {
         },
     };
 
-    return { mockIpcMain };
+    return { mockIpcMain, mockApp: { quit: vi.fn() } };
 });
 
 vi.mock('electron', () => ({
     ipcMain: mockIpcMain,
+    app: mockApp,
 }));
 
 describe('AppIpcHandler', () => {
@@ -171,4 +172,14 @@ describe('AppIpcHandler', () => {
             expect(mockLogger.error).toHaveBeenCalledWith('Error opening Google sign-in:', error);
         });
     });
+
+    describe('app:quit handler', () => {
+        it('quits the app', () => {
+            handler.register();
+
+            mockIpcMain._listeners.get('app:quit')!({});
+
+            expect(mockApp.quit).toHaveBeenCalled();
+        });
+    });
 });
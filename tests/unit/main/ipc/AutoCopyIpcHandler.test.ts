@@ -0,0 +1,191 @@
+/**
+ * Unit tests for AutoCopyIpcHandler.
+ *
+ * Tests the auto-copy:get-settings / auto-copy:set-settings IPC handlers
+ * and clipboard copying on response completion.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { AutoCopyIpcHandler } from '../../../../src/main/managers/ipc/AutoCopyIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import {
+    createMockLogger,
+    createMockWindowManager,
+    createMockStore,
+    createMockExportManager,
+} from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain, mockClipboard, mockNotification } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    const mockClipboard = { writeText: vi.fn() };
+
+    const mockNotification = Object.assign(
+        vi.fn().mockImplementation(function () {
+            return { show: vi.fn() };
+        }),
+        { isSupported: vi.fn().mockReturnValue(true) }
+    );
+
+    return { mockIpcMain, mockClipboard, mockNotification };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    clipboard: mockClipboard,
+    Notification: mockNotification,
+}));
+
+describe('AutoCopyIpcHandler', () => {
+    let handler: AutoCopyIpcHandler;
+    let mockStore: ReturnType<typeof createMockStore>;
+    let mockLogger: ReturnType<typeof createMockLogger>;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mockExportManager: ReturnType<typeof createMockExportManager>;
+    let mockMainWindow: {
+        isDestroyed: ReturnType<typeof vi.fn>;
+        isFocused: ReturnType<typeof vi.fn>;
+        webContents: { send: ReturnType<typeof vi.fn> };
+    };
+
+    const createHandler = (settings: Record<string, unknown>) => {
+        mockStore = createMockStore(settings);
+        handler = new AutoCopyIpcHandler({
+            store: mockStore as unknown as IpcHandlerDependencies['store'],
+            logger: mockLogger as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+            exportManager: mockExportManager as unknown as IpcHandlerDependencies['exportManager'],
+        });
+        handler.register();
+    };
+
+    /** Simulate QuickChatIpcHandler emitting quick-chat-submitted */
+    const emitQuickChatSubmitted = () => {
+        const call = (mockWindowManager.on as ReturnType<typeof vi.fn>).mock.calls.find(
+            ([event]) => event === 'quick-chat-submitted'
+        );
+        (call![1] as () => void)();
+    };
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+
+        mockLogger = createMockLogger();
+        mockWindowManager = createMockWindowManager();
+        mockExportManager = createMockExportManager();
+        mockExportManager.getLastResponse.mockResolvedValue({ markdown: '**Answer**', text: 'Answer' });
+
+        mockMainWindow = {
+            isDestroyed: vi.fn().mockReturnValue(false),
+            isFocused: vi.fn().mockReturnValue(true),
+            webContents: { send: vi.fn() },
+        };
+        (mockWindowManager.getMainWindow as ReturnType<typeof vi.fn>).mockReturnValue(mockMainWindow);
+    });
+
+    describe('settings', () => {
+        it('returns stored settings', () => {
+            createHandler({ autoCopyMode: 'all', autoCopyFormat: 'text' });
+            const getHandler = mockIpcMain._handlers.get(IPC_CHANNELS.AUTO_COPY_GET_SETTINGS)!;
+            expect(getHandler()).toEqual({ mode: 'all', format: 'text' });
+        });
+
+        it('falls back to defaults for invalid stored values', () => {
+            createHandler({ autoCopyMode: 'sometimes', autoCopyFormat: 'rtf' });
+            const getHandler = mockIpcMain._handlers.get(IPC_CHANNELS.AUTO_COPY_GET_SETTINGS)!;
+            expect(getHandler()).toEqual({ mode: 'off', format: 'markdown' });
+        });
+
+        it('persists valid partial updates', () => {
+            createHandler({ autoCopyMode: 'off', autoCopyFormat: 'markdown' });
+            const listener = mockIpcMain._listeners.get(IPC_CHANNELS.AUTO_COPY_SET_SETTINGS)!;
+            listener({}, { mode: 'quick-chat' });
+            expect(mockStore.set).toHaveBeenCalledWith('autoCopyMode', 'quick-chat');
+            expect(mockStore.set).not.toHaveBeenCalledWith('autoCopyFormat', expect.anything());
+        });
+
+        it('rejects invalid values', () => {
+            createHandler({ autoCopyMode: 'off', autoCopyFormat: 'markdown' });
+            const listener = mockIpcMain._listeners.get(IPC_CHANNELS.AUTO_COPY_SET_SETTINGS)!;
+            listener({}, { mode: 'always' });
+            listener({}, { format: 'html' });
+            listener({}, null);
+            expect(mockStore.set).not.toHaveBeenCalled();
+        });
+    });
+
+    describe('onResponseComplete', () => {
+        it('does nothing when mode is off', async () => {
+            createHandler({ autoCopyMode: 'off', autoCopyFormat: 'markdown' });
+            await handler.onResponseComplete();
+            expect(mockExportManager.getLastResponse).not.toHaveBeenCalled();
+            expect(mockClipboard.writeText).not.toHaveBeenCalled();
+        });
+
+        it('skips non-Quick Chat answers in quick-chat mode', async () => {
+            createHandler({ autoCopyMode: 'quick-chat', autoCopyFormat: 'markdown' });
+            await handler.onResponseComplete();
+            expect(mockClipboard.writeText).not.toHaveBeenCalled();
+        });
+
+        it('copies the answer following a Quick Chat submission once', async () => {
+            createHandler({ autoCopyMode: 'quick-chat', autoCopyFormat: 'markdown' });
+            emitQuickChatSubmitted();
+
+            await handler.onResponseComplete();
+            await handler.onResponseComplete();
+
+            expect(mockClipboard.writeText).toHaveBeenCalledTimes(1);
+            expect(mockClipboard.writeText).toHaveBeenCalledWith('**Answer**');
+        });
+
+        it('copies every answer as plain text in all mode', async () => {
+            createHandler({ autoCopyMode: 'all', autoCopyFormat: 'text' });
+            await handler.onResponseComplete();
+            expect(mockClipboard.writeText).toHaveBeenCalledWith('Answer');
+        });
+
+        it('shows a toast confirming the copy', async () => {
+            createHandler({ autoCopyMode: 'all', autoCopyFormat: 'markdown' });
+            await handler.onResponseComplete();
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                IPC_CHANNELS.TOAST_SHOW,
+                expect.objectContaining({ type: 'success' })
+            );
+            expect(mockNotification).not.toHaveBeenCalled();
+        });
+
+        it('shows a native notification when the window is unfocused', async () => {
+            mockMainWindow.isFocused.mockReturnValue(false);
+            createHandler({ autoCopyMode: 'all', autoCopyFormat: 'markdown' });
+            await handler.onResponseComplete();
+            expect(mockNotification).toHaveBeenCalledWith(
+                expect.objectContaining({ body: 'Answer copied to clipboard' })
+            );
+        });
+
+        it('does not write to the clipboard when no answer is found', async () => {
+            mockExportManager.getLastResponse.mockResolvedValue(null);
+            createHandler({ autoCopyMode: 'all', autoCopyFormat: 'markdown' });
+            await handler.onResponseComplete();
+            expect(mockClipboard.writeText).not.toHaveBeenCalled();
+        });
+    });
+});
@@ -8,6 +8,7 @@ import { BrowserWindow, IpcMainEvent, IpcMainInvokeEvent } from 'electron';
 import { BaseIpcHandler } from '../../../../src/main/managers/ipc/BaseIpcHandler';
 import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
 import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { clearAppEvents, getRecentAppEvents } from '../../../../src/main/utils/events';
 
 // Mock Electron
 const { mockBrowserWindow } = vi.hoisted(() => {
@@ -42,6 +43,10 @@ class TestIpcHandler extends BaseIpcHandler {
         this.broadcastToAllWindows(channel, data);
     }
 
+    public testPublishEvent(enabled: boolean): void {
+        this.publishEvent('always-on-top:changed', { enabled });
+    }
+
     public testHandleError(operation: string, error: unknown, context?: Record<string, unknown>): void {
         this.handleError(operation, error, context);
     }
@@ -126,6 +131,25 @@ describe('BaseIpcHandler', () => {
         });
     });
 
+    describe('publishEvent', () => {
+        it('keeps the event for replay and sends it to all windows', () => {
+            clearAppEvents();
+            const mockWindow = {
+                id: 1,
+                isDestroyed: vi.fn().mockReturnValue(false),
+                webContents: { send: vi.fn() },
+            };
+            mockBrowserWindow.getAllWindows.mockReturnValue([mockWindow]);
+
+            handler.testPublishEvent(true);
+
+            expect(getRecentAppEvents()).toEqual([
+                expect.objectContaining({ name: 'always-on-top:changed', payload: { enabled: true } }),
+            ]);
+            expect(mockWindow.webContents.send).toHaveBeenCalledWith('always-on-top:changed', { enabled: true });
+        });
+    });
+
     describe('broadcastToAllWindows', () => {
         it('sends message to all windows', () => {
             const mockWindow1 = {
@@ -0,0 +1,106 @@
+/**
+ * Unit tests for CapabilitiesIpcHandler.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { CapabilitiesIpcHandler } from '../../../../src/main/managers/ipc/CapabilitiesIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import {
+    createMockLogger,
+    createMockWindowManager,
+    createMockStore,
+    createMockHotkeyManager,
+    createMockUpdateManager,
+} from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain, mockNotification, mockSafeStorage } = vi.hoisted(() => {
+    const mockIpcMain = {
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+    };
+
+    return {
+        mockIpcMain,
+        mockNotification: { isSupported: vi.fn().mockReturnValue(true) },
+        mockSafeStorage: { isEncryptionAvailable: vi.fn().mockReturnValue(true) },
+    };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    Notification: mockNotification,
+    safeStorage: mockSafeStorage,
+}));
+
+describe('CapabilitiesIpcHandler', () => {
+    let handler: CapabilitiesIpcHandler;
+    let mockHotkeyManager: ReturnType<typeof createMockHotkeyManager>;
+    let mockUpdateManager: ReturnType<typeof createMockUpdateManager>;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._handlers.clear();
+
+        mockHotkeyManager = createMockHotkeyManager();
+        mockUpdateManager = createMockUpdateManager();
+        mockWindowManager = createMockWindowManager();
+        handler = new CapabilitiesIpcHandler({
+            store: createMockStore({}) as unknown as IpcHandlerDependencies['store'],
+            logger: createMockLogger() as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+            hotkeyManager: mockHotkeyManager as unknown as IpcHandlerDependencies['hotkeyManager'],
+            updateManager: mockUpdateManager as unknown as IpcHandlerDependencies['updateManager'],
+        });
+        handler.register();
+    });
+
+    const getReport = () => mockIpcMain._handlers.get(IPC_CHANNELS.CAPABILITIES_GET_REPORT)!();
+
+    it('reports every capability as available when supported', () => {
+        expect(getReport()).toEqual({
+            platform: process.platform,
+            versions: expect.objectContaining({ electron: expect.any(String), chrome: expect.any(String) }),
+            capabilities: {
+                globalHotkeys: { available: true },
+                notifications: { available: true },
+                keychain: { available: true },
+                tray: { available: true },
+                autoUpdate: { available: true },
+                windowOpacity: { available: true },
+            },
+        });
+    });
+
+    it('explains unavailable capabilities', () => {
+        mockHotkeyManager.areGlobalHotkeysSupported.mockReturnValue(false);
+        mockSafeStorage.isEncryptionAvailable.mockReturnValueOnce(false);
+        mockUpdateManager.isSupported.mockReturnValue(false);
+        mockWindowManager.isWindowOpacitySupported.mockReturnValue(false);
+
+        const { capabilities } = getReport();
+
+        expect(capabilities.globalHotkeys).toEqual({ available: false, reason: expect.stringContaining('Linux') });
+        expect(capabilities.keychain).toEqual({ available: false, reason: expect.stringContaining('keychain') });
+        expect(capabilities.autoUpdate.available).toBe(false);
+        expect(capabilities.windowOpacity).toEqual({ available: false, reason: expect.stringContaining('Linux') });
+    });
+
+    it('marks a capability unavailable when its check throws', () => {
+        mockNotification.isSupported.mockImplementationOnce(() => {
+            throw new Error('boom');
+        });
+
+        expect(getReport().capabilities.notifications.available).toBe(false);
+    });
+
+    it('removes its handler on unregister', () => {
+        handler.unregister();
+
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.CAPABILITIES_GET_REPORT);
+    });
+});
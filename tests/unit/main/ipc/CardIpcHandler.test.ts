@@ -0,0 +1,176 @@
+/**
+ * Unit tests for CardIpcHandler.
+ *
+ * Tests pinning the latest answer into a card and the card copy/close
+ * channels sent from card windows.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { CardIpcHandler } from '../../../../src/main/managers/ipc/CardIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import type CardManager from '../../../../src/main/managers/cardManager';
+import {
+    createMockLogger,
+    createMockWindowManager,
+    createMockStore,
+    createMockExportManager,
+} from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn(),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+        },
+    };
+
+    return { mockIpcMain };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+}));
+
+vi.mock('../../../../src/main/managers/cardManager', () => ({
+    default: vi.fn(),
+}));
+
+describe('CardIpcHandler', () => {
+    let handler: CardIpcHandler;
+    let mockLogger: ReturnType<typeof createMockLogger>;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mockExportManager: ReturnType<typeof createMockExportManager>;
+    let mockCardManager: {
+        createCard: ReturnType<typeof vi.fn>;
+        copyCard: ReturnType<typeof vi.fn>;
+        closeCard: ReturnType<typeof vi.fn>;
+        closeAll: ReturnType<typeof vi.fn>;
+    };
+    let mockMainWindow: {
+        isDestroyed: ReturnType<typeof vi.fn>;
+        webContents: { send: ReturnType<typeof vi.fn> };
+    };
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+
+        mockLogger = createMockLogger();
+        mockWindowManager = createMockWindowManager();
+        mockExportManager = createMockExportManager();
+        mockExportManager.getLastResponse.mockResolvedValue({
+            markdown: '**Answer**',
+            text: 'Answer',
+            html: '<p><strong>Answer</strong></p>',
+        });
+        mockCardManager = {
+            createCard: vi.fn().mockReturnValue('card-1'),
+            copyCard: vi.fn().mockReturnValue(true),
+            closeCard: vi.fn().mockReturnValue(true),
+            closeAll: vi.fn(),
+        };
+        mockMainWindow = {
+            isDestroyed: vi.fn().mockReturnValue(false),
+            webContents: { send: vi.fn() },
+        };
+        (mockWindowManager.getMainWindow as ReturnType<typeof vi.fn>).mockReturnValue(mockMainWindow);
+
+        handler = new CardIpcHandler(
+            {
+                store: createMockStore({}) as unknown as IpcHandlerDependencies['store'],
+                logger: mockLogger as unknown as IpcHandlerDependencies['logger'],
+                windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+                exportManager: mockExportManager as unknown as IpcHandlerDependencies['exportManager'],
+            },
+            mockCardManager as unknown as CardManager
+        );
+        handler.register();
+    });
+
+    describe('pinLastAnswer', () => {
+        it('creates a card from the latest answer', async () => {
+            await handler.pinLastAnswer();
+
+            expect(mockExportManager.getLastResponse).toHaveBeenCalledWith(mockMainWindow.webContents);
+            expect(mockCardManager.createCard).toHaveBeenCalledWith({
+                html: '<p><strong>Answer</strong></p>',
+                markdown: '**Answer**',
+            });
+        });
+
+        it('shows a toast when there is no answer', async () => {
+            mockExportManager.getLastResponse.mockResolvedValue(null);
+
+            await handler.pinLastAnswer();
+
+            expect(mockCardManager.createCard).not.toHaveBeenCalled();
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                IPC_CHANNELS.TOAST_SHOW,
+                expect.objectContaining({ type: 'info', message: 'No answer to pin' })
+            );
+        });
+
+        it('does nothing when the main window is destroyed', async () => {
+            mockMainWindow.isDestroyed.mockReturnValue(true);
+
+            await handler.pinLastAnswer();
+
+            expect(mockExportManager.getLastResponse).not.toHaveBeenCalled();
+            expect(mockLogger.warn).toHaveBeenCalled();
+        });
+
+        it('is triggered by the cards:pin channel', async () => {
+            mockIpcMain._listeners.get(IPC_CHANNELS.CARDS_PIN)!();
+
+            await vi.waitFor(() => expect(mockCardManager.createCard).toHaveBeenCalled());
+        });
+
+        it('is triggered by the pin-answer-triggered menu event', async () => {
+            const call = (mockWindowManager.on as ReturnType<typeof vi.fn>).mock.calls.find(
+                ([event]) => event === 'pin-answer-triggered'
+            );
+            (call![1] as () => void)();
+
+            await vi.waitFor(() => expect(mockCardManager.createCard).toHaveBeenCalled());
+        });
+    });
+
+    describe('card actions', () => {
+        it('copies the card hosted by the sender', () => {
+            const sender = {};
+            mockIpcMain._listeners.get(IPC_CHANNELS.CARDS_COPY)!({ sender });
+            expect(mockCardManager.copyCard).toHaveBeenCalledWith(sender);
+        });
+
+        it('closes the card hosted by the sender', () => {
+            const sender = {};
+            mockIpcMain._listeners.get(IPC_CHANNELS.CARDS_CLOSE)!({ sender });
+            expect(mockCardManager.closeCard).toHaveBeenCalledWith(sender);
+        });
+
+        it('warns when the sender is not a card', () => {
+            mockCardManager.closeCard.mockReturnValue(false);
+            mockIpcMain._listeners.get(IPC_CHANNELS.CARDS_CLOSE)!({ sender: {} });
+            expect(mockLogger.warn).toHaveBeenCalledWith('Card close requested from unknown sender');
+        });
+    });
+
+    describe('unregister', () => {
+        it('removes listeners and closes all cards', () => {
+            handler.unregister();
+
+            expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.CARDS_PIN);
+            expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.CARDS_COPY);
+            expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.CARDS_CLOSE);
+            expect(mockWindowManager.removeListener).toHaveBeenCalledWith('pin-answer-triggered', expect.any(Function));
+            expect(mockCardManager.closeAll).toHaveBeenCalled();
+        });
+    });
+});
@@ -0,0 +1,85 @@
+/**
+ * Unit tests for CloseToTrayIpcHandler.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { CloseToTrayIpcHandler } from '../../../../src/main/managers/ipc/CloseToTrayIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    return { mockIpcMain };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+}));
+
+describe('CloseToTrayIpcHandler', () => {
+    let handler: CloseToTrayIpcHandler;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mockStore: ReturnType<typeof createMockStore>;
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+
+        mockWindowManager = createMockWindowManager();
+        mockStore = createMockStore({ closeToTray: false });
+        handler = new CloseToTrayIpcHandler({
+            store: mockStore as unknown as IpcHandlerDependencies['store'],
+            logger: createMockLogger() as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+        });
+        handler.register();
+    });
+
+    it('returns the saved setting', () => {
+        expect(mockIpcMain._handlers.get(IPC_CHANNELS.CLOSE_TO_TRAY_GET)!()).toBe(false);
+    });
+
+    it('saves the setting and applies it to the main window', () => {
+        mockIpcMain._listeners.get(IPC_CHANNELS.CLOSE_TO_TRAY_SET)!({}, true);
+
+        expect(mockStore.set).toHaveBeenCalledWith('closeToTray', true);
+        expect(mockWindowManager.setCloseToTray).toHaveBeenCalledWith(true);
+    });
+
+    it('ignores values that are not booleans', () => {
+        mockIpcMain._listeners.get(IPC_CHANNELS.CLOSE_TO_TRAY_SET)!({}, 'yes');
+
+        expect(mockStore.set).not.toHaveBeenCalled();
+        expect(mockWindowManager.setCloseToTray).not.toHaveBeenCalled();
+    });
+
+    it('applies the saved setting on initialize', () => {
+        handler.initialize();
+
+        expect(mockWindowManager.setCloseToTray).toHaveBeenCalledWith(false);
+    });
+
+    it('removes its listeners on unregister', () => {
+        handler.unregister();
+
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.CLOSE_TO_TRAY_GET);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.CLOSE_TO_TRAY_SET);
+    });
+});
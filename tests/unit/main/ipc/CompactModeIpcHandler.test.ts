@@ -0,0 +1,98 @@
+/**
+ * Unit tests for CompactModeIpcHandler.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { CompactModeIpcHandler } from '../../../../src/main/managers/ipc/CompactModeIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain, mockBrowserWindow } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    return {
+        mockIpcMain,
+        mockBrowserWindow: { getAllWindows: vi.fn().mockReturnValue([]), fromWebContents: vi.fn() },
+    };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    BrowserWindow: mockBrowserWindow,
+}));
+
+describe('CompactModeIpcHandler', () => {
+    let handler: CompactModeIpcHandler;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mockStore: ReturnType<typeof createMockStore>;
+    const win = { isDestroyed: () => false, webContents: { send: vi.fn() } };
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+        mockBrowserWindow.fromWebContents.mockReturnValue(win);
+
+        mockWindowManager = createMockWindowManager();
+        mockStore = createMockStore({ compactModeHideSidebar: false });
+        handler = new CompactModeIpcHandler({
+            store: mockStore as unknown as IpcHandlerDependencies['store'],
+            logger: createMockLogger() as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+        });
+        handler.register();
+    });
+
+    it('returns whether the calling window is in compact mode', () => {
+        mockWindowManager.isCompactMode.mockReturnValue(true);
+
+        expect(mockIpcMain._handlers.get(IPC_CHANNELS.COMPACT_MODE_GET)!({ sender: {} })).toEqual({ enabled: true });
+        expect(mockWindowManager.isCompactMode).toHaveBeenCalledWith(win);
+    });
+
+    it('toggles compact mode for the calling window', () => {
+        mockIpcMain._listeners.get(IPC_CHANNELS.COMPACT_MODE_TOGGLE)!({ sender: {} });
+
+        expect(mockWindowManager.toggleCompactMode).toHaveBeenCalledWith(win);
+    });
+
+    it('sends compact mode changes only to the affected window', () => {
+        const other = { isDestroyed: () => false, webContents: { send: vi.fn() } };
+        mockBrowserWindow.getAllWindows.mockReturnValue([win, other]);
+
+        const listener = mockWindowManager.on.mock.calls.find(([event]) => event === 'compact-mode-changed')![1];
+        listener(true, win);
+
+        expect(win.webContents.send).toHaveBeenCalledWith(IPC_CHANNELS.COMPACT_MODE_CHANGED, { enabled: true });
+        expect(other.webContents.send).not.toHaveBeenCalled();
+    });
+
+    it('applies the saved sidebar preference on initialize', () => {
+        handler.initialize();
+
+        expect(mockWindowManager.setCompactModeHidesSidebar).toHaveBeenCalledWith(false);
+    });
+
+    it('removes its listeners on unregister', () => {
+        handler.unregister();
+
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.COMPACT_MODE_GET);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.COMPACT_MODE_TOGGLE);
+        expect(mockWindowManager.removeListener).toHaveBeenCalledWith('compact-mode-changed', expect.any(Function));
+    });
+});
@@ -0,0 +1,125 @@
+/**
+ * Unit tests for DiskSpaceIpcHandler.
+ */
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { DiskSpaceIpcHandler } from '../../../../src/main/managers/ipc/DiskSpaceIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+import { checkDiskSpace, ensureDiskSpace, resetDiskSpace } from '../../../../src/main/utils/diskSpace';
+import { clearTitlebarWidgets, getTitlebarState } from '../../../../src/main/utils/titlebarWidgets';
+
+// Mock Electron
+const { mockIpcMain, mockBrowserWindow, mockStatfs } = vi.hoisted(() => {
+    const mockIpcMain = {
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+    };
+
+    const mockBrowserWindow = { getAllWindows: vi.fn().mockReturnValue([]) };
+
+    return { mockIpcMain, mockBrowserWindow, mockStatfs: vi.fn() };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    BrowserWindow: mockBrowserWindow,
+    app: { getPath: vi.fn().mockReturnValue('/mock/userData') },
+}));
+
+vi.mock('fs/promises', () => ({ statfs: mockStatfs }));
+
+const MB = 1024 * 1024;
+
+/** Make statfs report this many free megabytes */
+const freeMb = (mb: number) => mockStatfs.mockResolvedValue({ bavail: mb * 256, bsize: 4096 });
+
+describe('DiskSpaceIpcHandler', () => {
+    let handler: DiskSpaceIpcHandler;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mainWindow: { isDestroyed: ReturnType<typeof vi.fn>; webContents: { send: ReturnType<typeof vi.fn> } };
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._handlers.clear();
+        clearTitlebarWidgets();
+        resetDiskSpace();
+        freeMb(10_000);
+
+        mainWindow = { isDestroyed: vi.fn().mockReturnValue(false), webContents: { send: vi.fn() } };
+        mockBrowserWindow.getAllWindows.mockReturnValue([mainWindow]);
+        mockWindowManager = createMockWindowManager();
+        mockWindowManager.getMainWindow.mockReturnValue(mainWindow);
+
+        handler = new DiskSpaceIpcHandler({
+            store: createMockStore({ lowDiskSpaceThresholdMb: 1000 }) as unknown as IpcHandlerDependencies['store'],
+            logger: createMockLogger() as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+        });
+        handler.register();
+    });
+
+    afterEach(() => {
+        handler.unregister();
+    });
+
+    it('applies the saved threshold and checks the app data volume', async () => {
+        freeMb(900);
+        handler.initialize();
+
+        await vi.waitFor(() => expect(mockStatfs).toHaveBeenCalledWith('/mock/userData'));
+        await expect(ensureDiskSpace('/exports')).rejects.toThrow('Not enough disk space');
+    });
+
+    it('returns the last reading', async () => {
+        handler.initialize();
+        await vi.waitFor(() => expect(mockStatfs).toHaveBeenCalled());
+
+        await vi.waitFor(() =>
+            expect(mockIpcMain._handlers.get(IPC_CHANNELS.DISK_SPACE_GET_STATUS)!()).toEqual({
+                freeBytes: 10_000 * MB,
+                thresholdBytes: 1000 * MB,
+                low: false,
+            })
+        );
+    });
+
+    it('warns every window when space runs low, and clears the warning once freed', async () => {
+        freeMb(400);
+        await checkDiskSpace('/mock/userData');
+
+        expect(mainWindow.webContents.send).toHaveBeenCalledWith(
+            IPC_CHANNELS.DISK_SPACE_CHANGED,
+            expect.objectContaining({ low: true, freeBytes: 400 * MB })
+        );
+        expect(mainWindow.webContents.send).toHaveBeenCalledWith(
+            IPC_CHANNELS.TOAST_SHOW,
+            expect.objectContaining({ type: 'warning', title: 'Low disk space' })
+        );
+        expect(getTitlebarState().widgets).toEqual([expect.objectContaining({ id: 'low-disk-space' })]);
+
+        freeMb(5000);
+        await checkDiskSpace('/mock/userData');
+
+        expect(getTitlebarState().widgets).toEqual([]);
+    });
+
+    it('stops checking after unregister', () => {
+        vi.useFakeTimers();
+        try {
+            handler.initialize();
+            handler.unregister();
+            mockStatfs.mockClear();
+
+            vi.advanceTimersByTime(5 * 60_000);
+
+            expect(mockStatfs).not.toHaveBeenCalled();
+            expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.DISK_SPACE_GET_STATUS);
+        } finally {
+            vi.useRealTimers();
+        }
+    });
+});
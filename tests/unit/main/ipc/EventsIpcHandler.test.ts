@@ -0,0 +1,71 @@
+/**
+ * Unit tests for EventsIpcHandler.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { EventsIpcHandler } from '../../../../src/main/managers/ipc/EventsIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+import { clearAppEvents, publishAppEvent } from '../../../../src/main/utils/events';
+
+// Mock Electron
+const { mockIpcMain } = vi.hoisted(() => {
+    const mockIpcMain = {
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+    };
+
+    return { mockIpcMain };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    BrowserWindow: { getAllWindows: vi.fn().mockReturnValue([]) },
+}));
+
+describe('EventsIpcHandler', () => {
+    let handler: EventsIpcHandler;
+
+    const getRecent = (since?: unknown) => mockIpcMain._handlers.get(IPC_CHANNELS.EVENTS_GET_RECENT)!({}, since);
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._handlers.clear();
+        clearAppEvents();
+
+        handler = new EventsIpcHandler({
+            store: createMockStore({}) as unknown as IpcHandlerDependencies['store'],
+            logger: createMockLogger() as unknown as IpcHandlerDependencies['logger'],
+            windowManager: createMockWindowManager() as unknown as IpcHandlerDependencies['windowManager'],
+        });
+        handler.register();
+    });
+
+    it('returns the recent events', () => {
+        const event = publishAppEvent('zoom:level-changed', 120);
+
+        expect(getRecent()).toEqual([event]);
+    });
+
+    it('returns only events after the given sequence number', () => {
+        const first = publishAppEvent('zoom:level-changed', 110);
+        const second = publishAppEvent('zoom:level-changed', 120);
+
+        expect(getRecent(first.seq)).toEqual([second]);
+    });
+
+    it('ignores an invalid sequence number', () => {
+        const event = publishAppEvent('zoom:level-changed', 120);
+
+        expect(getRecent('latest')).toEqual([event]);
+    });
+
+    it('removes its handler on unregister', () => {
+        handler.unregister();
+
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.EVENTS_GET_RECENT);
+    });
+});
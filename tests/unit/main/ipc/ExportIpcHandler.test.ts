@@ -0,0 +1,211 @@
+/**
+ * Unit tests for ExportIpcHandler.
+ *
+ * Tests the export-chat IPC handlers, filename template setting and Confluence settings.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { ExportIpcHandler } from '../../../../src/main/managers/ipc/ExportIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import {
+    createMockLogger,
+    createMockWindowManager,
+    createMockStore,
+    createMockExportManager,
+} from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeAllListeners: vi.fn(),
+        removeHandler: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    return { mockIpcMain };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    BrowserWindow: { getAllWindows: vi.fn().mockReturnValue([]) },
+    safeStorage: { isEncryptionAvailable: vi.fn().mockReturnValue(false) },
+}));
+
+describe('ExportIpcHandler', () => {
+    let handler: ExportIpcHandler;
+    let mockDeps: IpcHandlerDependencies;
+    let mockLogger: ReturnType<typeof createMockLogger>;
+    let mockStore: ReturnType<typeof createMockStore>;
+    let mockExportManager: ReturnType<typeof createMockExportManager>;
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+
+        mockLogger = createMockLogger();
+        mockStore = createMockStore({ exportFilenameTemplate: '{date}_{title}' });
+        mockExportManager = createMockExportManager();
+
+        mockDeps = {
+            store: mockStore as unknown as IpcHandlerDependencies['store'],
+            logger: mockLogger as unknown as IpcHandlerDependencies['logger'],
+            windowManager: createMockWindowManager() as unknown as IpcHandlerDependencies['windowManager'],
+            exportManager: mockExportManager as unknown as IpcHandlerDependencies['exportManager'],
+        };
+
+        handler = new ExportIpcHandler(mockDeps);
+        handler.register();
+    });
+
+    describe('export-chat:filename-template:get', () => {
+        it('returns the stored template', () => {
+            const getHandler = mockIpcMain._handlers.get(IPC_CHANNELS.EXPORT_FILENAME_TEMPLATE_GET)!;
+            expect(getHandler()).toBe('{date}_{title}');
+        });
+
+        it('falls back to the default template when the stored value is invalid', () => {
+            mockStore._defaults.exportFilenameTemplate = '';
+            const getHandler = mockIpcMain._handlers.get(IPC_CHANNELS.EXPORT_FILENAME_TEMPLATE_GET)!;
+            expect(getHandler()).toBe('{title}');
+        });
+    });
+
+    describe('export-chat:filename-template:set', () => {
+        it('persists a valid template', () => {
+            const listener = mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_FILENAME_TEMPLATE_SET)!;
+            listener({}, '{id}-{title}');
+            expect(mockStore.set).toHaveBeenCalledWith('exportFilenameTemplate', '{id}-{title}');
+        });
+
+        it('rejects invalid templates', () => {
+            const listener = mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_FILENAME_TEMPLATE_SET)!;
+            listener({}, '   ');
+            listener({}, 123);
+            expect(mockStore.set).not.toHaveBeenCalled();
+            expect(mockLogger.warn).toHaveBeenCalled();
+        });
+    });
+
+    describe('export requests', () => {
+        it('passes the stored template to exportToMarkdown', () => {
+            const sender = { send: vi.fn() };
+            mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_CHAT_MARKDOWN)!({ sender });
+            expect(mockExportManager.exportToMarkdown).toHaveBeenCalledWith(sender, '{date}_{title}');
+        });
+
+        it('passes the stored template to exportToPdf', () => {
+            const sender = { send: vi.fn() };
+            mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_CHAT_PDF)!({ sender });
+            expect(mockExportManager.exportToPdf).toHaveBeenCalledWith(sender, '{date}_{title}');
+        });
+
+        it('passes the stored template to exportCanvasToMarkdown', () => {
+            const sender = { send: vi.fn() };
+            mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_CANVAS_MARKDOWN)!({ sender });
+            expect(mockExportManager.exportCanvasToMarkdown).toHaveBeenCalledWith(sender, '{date}_{title}');
+        });
+
+        it('passes the format and template to exportResearchReport', () => {
+            const sender = { send: vi.fn() };
+            mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_RESEARCH_REPORT)!({ sender }, 'pdf');
+            expect(mockExportManager.exportResearchReport).toHaveBeenCalledWith(sender, 'pdf', '{date}_{title}');
+        });
+
+        it('ignores unknown research report formats', () => {
+            mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_RESEARCH_REPORT)!({ sender: { send: vi.fn() } }, 'docx');
+            expect(mockExportManager.exportResearchReport).not.toHaveBeenCalled();
+        });
+    });
+
+    describe('Confluence', () => {
+        const confluence = {
+            baseUrl: 'https://example.atlassian.net/wiki',
+            email: 'me@example.com',
+            apiToken: 'token',
+            spaceKey: 'TEAM',
+            parentId: '123',
+        };
+
+        it('persists valid settings', () => {
+            mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_CONFLUENCE_SETTINGS_SET)!({}, confluence);
+
+            expect(mockStore.setMany).toHaveBeenCalledTimes(1);
+            expect(mockStore.setMany).toHaveBeenCalledWith({
+                confluenceBaseUrl: confluence.baseUrl,
+                confluenceEmail: confluence.email,
+                confluenceApiToken: 'token',
+                confluenceSpaceKey: 'TEAM',
+                confluenceParentId: '123',
+            });
+        });
+
+        it('does not save an unchanged API token', () => {
+            mockStore.set('confluenceApiToken', 'token');
+            mockStore.set.mockClear();
+
+            mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_CONFLUENCE_SETTINGS_SET)!({}, confluence);
+
+            expect(mockStore.setMany.mock.calls[0]![0]).not.toHaveProperty('confluenceApiToken');
+        });
+
+        it('rejects settings with an invalid URL', () => {
+            mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_CONFLUENCE_SETTINGS_SET)!({}, {
+                ...confluence,
+                baseUrl: 'http://example.com',
+            });
+
+            expect(mockStore.setMany).not.toHaveBeenCalled();
+            expect(mockLogger.warn).toHaveBeenCalled();
+        });
+
+        it('exports with the saved settings and per-export target overrides', () => {
+            Object.assign(mockStore._defaults, {
+                confluenceBaseUrl: confluence.baseUrl,
+                confluenceEmail: confluence.email,
+                confluenceApiToken: confluence.apiToken,
+                confluenceSpaceKey: confluence.spaceKey,
+                confluenceParentId: confluence.parentId,
+            });
+            const sender = { send: vi.fn() };
+
+            const target = { spaceKey: 'DOCS', parentId: '' };
+
+            mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_CHAT_CONFLUENCE)!({ sender }, target);
+
+            expect(mockExportManager.exportToConfluence).toHaveBeenCalledWith(sender, {
+                ...confluence,
+                spaceKey: 'DOCS',
+                parentId: '',
+            });
+        });
+    });
+
+    describe('export-chat:cancel', () => {
+        it('cancels the requested job', () => {
+            mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_CANCEL)!({}, 'job-1');
+            expect(mockExportManager.cancelExport).toHaveBeenCalledWith('job-1');
+        });
+
+        it('warns when the job is unknown', () => {
+            mockExportManager.cancelExport.mockReturnValue(false);
+            mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_CANCEL)!({}, 'job-2');
+            expect(mockLogger.warn).toHaveBeenCalled();
+        });
+
+        it('ignores non-string job IDs', () => {
+            mockIpcMain._listeners.get(IPC_CHANNELS.EXPORT_CANCEL)!({}, 42);
+            expect(mockExportManager.cancelExport).not.toHaveBeenCalled();
+        });
+    });
+});
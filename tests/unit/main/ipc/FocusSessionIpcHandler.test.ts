@@ -0,0 +1,162 @@
+/**
+ * Unit tests for FocusSessionIpcHandler.
+ */
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { FocusSessionIpcHandler } from '../../../../src/main/managers/ipc/FocusSessionIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import {
+    createMockExportManager,
+    createMockLogger,
+    createMockStore,
+    createMockWindowManager,
+} from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+import { isFocusSessionActive, resetFocusSession } from '../../../../src/main/utils/focusSession';
+import { clearTitlebarWidgets, getTitlebarState } from '../../../../src/main/utils/titlebarWidgets';
+
+const { mockIpcMain, mockBrowserWindow, mockDialog, mockWriteFile } = vi.hoisted(() => {
+    const mockIpcMain = {
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => unknown) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _listeners: new Map<string, (...args: unknown[]) => unknown>(),
+    };
+
+    return {
+        mockIpcMain,
+        mockBrowserWindow: { getAllWindows: vi.fn().mockReturnValue([]) },
+        mockDialog: { showMessageBox: vi.fn(), showSaveDialog: vi.fn() },
+        mockWriteFile: vi.fn(),
+    };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    BrowserWindow: mockBrowserWindow,
+    dialog: mockDialog,
+    app: { getPath: vi.fn().mockReturnValue('/mock/downloads') },
+}));
+
+vi.mock('fs/promises', () => ({ writeFile: mockWriteFile, statfs: vi.fn().mockRejectedValue(new Error('n/a')) }));
+
+describe('FocusSessionIpcHandler', () => {
+    let handler: FocusSessionIpcHandler;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mockExportManager: ReturnType<typeof createMockExportManager>;
+    let mainWindow: { isDestroyed: ReturnType<typeof vi.fn>; webContents: { send: ReturnType<typeof vi.fn> } };
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        vi.useFakeTimers();
+        mockIpcMain._handlers.clear();
+        mockIpcMain._listeners.clear();
+        clearTitlebarWidgets();
+        resetFocusSession();
+
+        mainWindow = { isDestroyed: vi.fn().mockReturnValue(false), webContents: { send: vi.fn() } };
+        mockBrowserWindow.getAllWindows.mockReturnValue([mainWindow]);
+        mockWindowManager = createMockWindowManager();
+        mockWindowManager.getMainWindow.mockReturnValue(mainWindow);
+        mockExportManager = createMockExportManager();
+
+        handler = new FocusSessionIpcHandler({
+            store: createMockStore({ focusSessionMinutes: 30 }) as unknown as IpcHandlerDependencies['store'],
+            logger: createMockLogger() as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+            exportManager: mockExportManager as unknown as IpcHandlerDependencies['exportManager'],
+        });
+        handler.register();
+    });
+
+    afterEach(() => {
+        handler.unregister();
+        vi.useRealTimers();
+    });
+
+    it('starts a session of the configured length and shows it in the titlebar', async () => {
+        mockIpcMain._listeners.get(IPC_CHANNELS.FOCUS_SESSION_TOGGLE)!();
+
+        const status = await mockIpcMain._handlers.get(IPC_CHANNELS.FOCUS_SESSION_GET_STATUS)!();
+        expect(status).toMatchObject({ active: true, entryCount: 0 });
+        expect(mainWindow.webContents.send).toHaveBeenCalledWith(
+            IPC_CHANNELS.FOCUS_SESSION_CHANGED,
+            expect.objectContaining({ active: true })
+        );
+        expect(getTitlebarState().widgets).toEqual([expect.objectContaining({ label: 'Focus · 30 min' })]);
+    });
+
+    it('counts down in the titlebar', () => {
+        handler.start(10);
+
+        vi.advanceTimersByTime(3 * 60_000);
+
+        expect(getTitlebarState().widgets[0]).toHaveProperty('label', 'Focus · 7 min');
+    });
+
+    it('starts a session from the instance command with its own length', () => {
+        const listener = mockWindowManager.on.mock.calls.find(
+            ([event]) => event === 'focus-session-start-triggered'
+        )![1];
+
+        listener(5);
+
+        expect(getTitlebarState().widgets[0]).toHaveProperty('label', 'Focus · 5 min');
+    });
+
+    it('logs answers with their prompts during a session', async () => {
+        mockExportManager.getLastResponse.mockResolvedValue({ markdown: 'Hello', text: 'Hello', prompt: 'Hi' });
+        await handler.onResponseComplete();
+        expect(mockExportManager.getLastResponse).not.toHaveBeenCalled();
+
+        handler.start();
+        await handler.onResponseComplete();
+
+        const status = await mockIpcMain._handlers.get(IPC_CHANNELS.FOCUS_SESSION_GET_STATUS)!();
+        expect(status).toHaveProperty('entryCount', 1);
+    });
+
+    it('ends the session when time is up without asking when nothing was logged', async () => {
+        handler.start(1);
+
+        await vi.advanceTimersByTimeAsync(60_000);
+
+        expect(isFocusSessionActive()).toBe(false);
+        expect(getTitlebarState().widgets).toEqual([]);
+        expect(mockDialog.showMessageBox).not.toHaveBeenCalled();
+    });
+
+    it('offers to save the log when the session ends', async () => {
+        mockExportManager.getLastResponse.mockResolvedValue({ markdown: 'Hello', text: 'Hello', prompt: 'Hi' });
+        mockDialog.showMessageBox.mockResolvedValue({ response: 0 });
+        mockDialog.showSaveDialog.mockResolvedValue({ canceled: false, filePath: '/mock/downloads/log.md' });
+        handler.start();
+        await handler.onResponseComplete();
+
+        await handler.end();
+
+        expect(mockWriteFile).toHaveBeenCalledWith('/mock/downloads/log.md', expect.stringContaining('### You\n\nHi'));
+        expect(mainWindow.webContents.send).toHaveBeenCalledWith(
+            IPC_CHANNELS.TOAST_SHOW,
+            expect.objectContaining({ message: 'Focus session log saved' })
+        );
+    });
+
+    it('does not save the log when the user declines', async () => {
+        mockExportManager.getLastResponse.mockResolvedValue({ markdown: 'Hello', text: 'Hello' });
+        mockDialog.showMessageBox.mockResolvedValue({ response: 1 });
+        handler.start();
+        await handler.onResponseComplete();
+
+        handler.toggle();
+        await vi.runAllTimersAsync();
+
+        expect(mockDialog.showSaveDialog).not.toHaveBeenCalled();
+        expect(isFocusSessionActive()).toBe(false);
+    });
+});
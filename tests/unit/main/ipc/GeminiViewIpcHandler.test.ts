@@ -0,0 +1,172 @@
+/**
+ * Unit tests for GeminiViewIpcHandler.
+ *
+ * Tests the reload, navigation and allowed-hosts channels.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { GeminiViewIpcHandler } from '../../../../src/main/managers/ipc/GeminiViewIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain, mockBrowserWindow } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeAllListeners: vi.fn(),
+        removeHandler: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+    };
+
+    const mockBrowserWindow = { getAllWindows: vi.fn().mockReturnValue([]) };
+
+    return { mockIpcMain, mockBrowserWindow };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    BrowserWindow: mockBrowserWindow,
+}));
+
+describe('GeminiViewIpcHandler', () => {
+    let handler: GeminiViewIpcHandler;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mockStore: ReturnType<typeof createMockStore>;
+
+    const reload = (ignoreCache?: unknown) => mockIpcMain._listeners.get(IPC_CHANNELS.GEMINI_RELOAD)!({}, ignoreCache);
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._listeners.clear();
+        mockIpcMain._handlers.clear();
+
+        mockWindowManager = createMockWindowManager();
+        mockStore = createMockStore({ navigationAllowList: 'consent.google.com' });
+        handler = new GeminiViewIpcHandler({
+            store: mockStore as unknown as IpcHandlerDependencies['store'],
+            logger: createMockLogger() as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+        });
+        handler.register();
+    });
+
+    it('reloads Gemini', () => {
+        reload();
+        expect(mockWindowManager.reloadGemini).toHaveBeenCalledWith(false);
+    });
+
+    it('hard reloads only when ignoreCache is exactly true', () => {
+        reload(true);
+        reload('yes');
+
+        expect(mockWindowManager.reloadGemini).toHaveBeenNthCalledWith(1, true);
+        expect(mockWindowManager.reloadGemini).toHaveBeenNthCalledWith(2, false);
+    });
+
+    it('navigates back, forward and home', () => {
+        mockIpcMain._listeners.get(IPC_CHANNELS.GEMINI_GO_BACK)!({});
+        mockIpcMain._listeners.get(IPC_CHANNELS.GEMINI_GO_FORWARD)!({});
+        mockIpcMain._listeners.get(IPC_CHANNELS.GEMINI_GO_HOME)!({});
+
+        expect(mockWindowManager.goBackGemini).toHaveBeenCalled();
+        expect(mockWindowManager.goForwardGemini).toHaveBeenCalled();
+        expect(mockWindowManager.goHomeGemini).toHaveBeenCalled();
+    });
+
+    it('prints the Gemini conversation', () => {
+        mockIpcMain._listeners.get(IPC_CHANNELS.GEMINI_PRINT)!({});
+
+        expect(mockWindowManager.printGemini).toHaveBeenCalled();
+    });
+
+    it('reloads user styles', () => {
+        mockIpcMain._listeners.get(IPC_CHANNELS.GEMINI_RELOAD_USER_STYLES)!({});
+
+        expect(mockWindowManager.reloadUserStyles).toHaveBeenCalled();
+    });
+
+    it('returns the navigation state', () => {
+        mockWindowManager.getGeminiNavigationState.mockReturnValue({ canGoBack: true, canGoForward: false });
+
+        expect(mockIpcMain._handlers.get(IPC_CHANNELS.GEMINI_GET_NAVIGATION_STATE)!()).toEqual({
+            canGoBack: true,
+            canGoForward: false,
+        });
+    });
+
+    it('broadcasts navigation changes to all windows', () => {
+        const win = { isDestroyed: () => false, webContents: { send: vi.fn() } };
+        mockBrowserWindow.getAllWindows.mockReturnValue([win]);
+
+        const listener = mockWindowManager.on.mock.calls.find(([event]) => event === 'gemini-navigation-changed')![1];
+        listener({ canGoBack: true, canGoForward: true });
+
+        expect(win.webContents.send).toHaveBeenCalledWith(IPC_CHANNELS.GEMINI_NAVIGATION_CHANGED, {
+            canGoBack: true,
+            canGoForward: true,
+        });
+    });
+
+    it('returns the Gemini location', async () => {
+        const location = { url: 'https://gemini.google.com/app/abc123', title: 'Trip plan', conversationId: 'abc123' };
+        mockWindowManager.getGeminiLocation.mockResolvedValue(location);
+
+        await expect(mockIpcMain._handlers.get(IPC_CHANNELS.GEMINI_GET_LOCATION)!()).resolves.toEqual(location);
+    });
+
+    it('broadcasts location changes to all windows', () => {
+        const win = { isDestroyed: () => false, webContents: { send: vi.fn() } };
+        mockBrowserWindow.getAllWindows.mockReturnValue([win]);
+        const location = { url: 'https://gemini.google.com/app', title: 'Gemini', conversationId: null };
+
+        const listener = mockWindowManager.on.mock.calls.find(([event]) => event === 'gemini-location-changed')![1];
+        listener(location);
+
+        expect(win.webContents.send).toHaveBeenCalledWith(IPC_CHANNELS.GEMINI_LOCATION_CHANGED, location);
+    });
+
+    it('applies the saved allowed hosts on register', () => {
+        expect(mockWindowManager.setAllowedHosts).toHaveBeenCalledWith(['consent.google.com']);
+    });
+
+    it('returns the saved allowed hosts', () => {
+        expect(mockIpcMain._handlers.get(IPC_CHANNELS.GEMINI_GET_ALLOWED_HOSTS)!()).toBe('consent.google.com');
+    });
+
+    it('normalizes, saves and applies new allowed hosts', () => {
+        mockIpcMain._listeners.get(IPC_CHANNELS.GEMINI_SET_ALLOWED_HOSTS)!({}, ' Example.com\n\nnot a host!, docs.rs ');
+
+        expect(mockStore.set).toHaveBeenCalledWith('navigationAllowList', 'example.com\ndocs.rs');
+        expect(mockWindowManager.setAllowedHosts).toHaveBeenLastCalledWith(['example.com', 'docs.rs']);
+    });
+
+    it('ignores non-string allowed hosts', () => {
+        mockIpcMain._listeners.get(IPC_CHANNELS.GEMINI_SET_ALLOWED_HOSTS)!({}, ['example.com']);
+
+        expect(mockStore.set).not.toHaveBeenCalled();
+    });
+
+    it('removes its listeners on unregister', () => {
+        handler.unregister();
+
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.GEMINI_RELOAD);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.GEMINI_GO_HOME);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.GEMINI_PRINT);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.GEMINI_RELOAD_USER_STYLES);
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.GEMINI_GET_NAVIGATION_STATE);
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.GEMINI_GET_LOCATION);
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.GEMINI_GET_ALLOWED_HOSTS);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.GEMINI_SET_ALLOWED_HOSTS);
+        expect(mockWindowManager.removeListener).toHaveBeenCalledWith(
+            'gemini-navigation-changed',
+            expect.any(Function)
+        );
+        expect(mockWindowManager.removeListener).toHaveBeenCalledWith('gemini-location-changed', expect.any(Function));
+    });
+});
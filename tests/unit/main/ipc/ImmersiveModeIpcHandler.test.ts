@@ -0,0 +1,83 @@
+/**
+ * Unit tests for ImmersiveModeIpcHandler.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { ImmersiveModeIpcHandler } from '../../../../src/main/managers/ipc/ImmersiveModeIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain, mockBrowserWindow } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    return { mockIpcMain, mockBrowserWindow: { getAllWindows: vi.fn().mockReturnValue([]) } };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    BrowserWindow: mockBrowserWindow,
+}));
+
+describe('ImmersiveModeIpcHandler', () => {
+    let handler: ImmersiveModeIpcHandler;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+
+        mockWindowManager = createMockWindowManager();
+        handler = new ImmersiveModeIpcHandler({
+            store: createMockStore({}) as unknown as IpcHandlerDependencies['store'],
+            logger: createMockLogger() as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+        });
+        handler.register();
+    });
+
+    it('returns whether immersive mode is on', () => {
+        mockWindowManager.isImmersiveMode.mockReturnValue(true);
+
+        expect(mockIpcMain._handlers.get(IPC_CHANNELS.IMMERSIVE_MODE_GET)!()).toEqual({ enabled: true });
+    });
+
+    it('toggles immersive mode', () => {
+        mockIpcMain._listeners.get(IPC_CHANNELS.IMMERSIVE_MODE_TOGGLE)!({});
+
+        expect(mockWindowManager.toggleImmersiveMode).toHaveBeenCalled();
+    });
+
+    it('broadcasts immersive mode changes to all windows', () => {
+        const win = { isDestroyed: () => false, webContents: { send: vi.fn() } };
+        mockBrowserWindow.getAllWindows.mockReturnValue([win]);
+
+        const listener = mockWindowManager.on.mock.calls.find(([event]) => event === 'immersive-mode-changed')![1];
+        listener(true);
+
+        expect(win.webContents.send).toHaveBeenCalledWith(IPC_CHANNELS.IMMERSIVE_MODE_CHANGED, { enabled: true });
+    });
+
+    it('removes its listeners on unregister', () => {
+        handler.unregister();
+
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.IMMERSIVE_MODE_GET);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.IMMERSIVE_MODE_TOGGLE);
+        expect(mockWindowManager.removeListener).toHaveBeenCalledWith('immersive-mode-changed', expect.any(Function));
+    });
+});
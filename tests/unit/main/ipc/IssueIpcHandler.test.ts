@@ -0,0 +1,263 @@
+/**
+ * Unit tests for IssueIpcHandler.
+ *
+ * Tests drafting issues from the Gemini selection or the latest answer,
+ * creating them and the issue tracker settings channels.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { IssueIpcHandler } from '../../../../src/main/managers/ipc/IssueIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import {
+    createMockLogger,
+    createMockWindowManager,
+    createMockStore,
+    createMockExportManager,
+} from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain, mockFetch } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    return { mockIpcMain, mockFetch: vi.fn() };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    session: { defaultSession: { fetch: mockFetch } },
+    safeStorage: {
+        isEncryptionAvailable: vi.fn().mockReturnValue(false),
+        encryptString: vi.fn(),
+        decryptString: vi.fn(),
+    },
+}));
+
+describe('IssueIpcHandler', () => {
+    let handler: IssueIpcHandler;
+    let mockLogger: ReturnType<typeof createMockLogger>;
+    let mockStore: ReturnType<typeof createMockStore>;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mockExportManager: ReturnType<typeof createMockExportManager>;
+    let mockGeminiFrame: { url: string; executeJavaScript: ReturnType<typeof vi.fn> };
+    let mockMainWindow: {
+        isDestroyed: ReturnType<typeof vi.fn>;
+        webContents: { send: ReturnType<typeof vi.fn>; mainFrame: { frames: unknown[] } };
+    };
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+        mockFetch.mockResolvedValue({
+            ok: true,
+            status: 201,
+            statusText: 'Created',
+            json: vi.fn().mockResolvedValue({ html_url: 'https://github.com/octo/app/issues/7' }),
+        });
+
+        mockLogger = createMockLogger();
+        mockStore = createMockStore({
+            issueTracker: 'github',
+            githubRepository: 'octo/app',
+            githubToken: 'ghp_token',
+        });
+        mockWindowManager = createMockWindowManager();
+        mockExportManager = createMockExportManager();
+        mockExportManager.getLastResponse.mockResolvedValue({
+            markdown: '## Retry failed uploads\n\nDetails',
+            text: 'Retry failed uploads\n\nDetails',
+            html: '<h2>Retry failed uploads</h2><p>Details</p>',
+        });
+        mockGeminiFrame = {
+            url: 'https://gemini.google.com/app',
+            executeJavaScript: vi.fn().mockResolvedValue(''),
+        };
+        mockMainWindow = {
+            isDestroyed: vi.fn().mockReturnValue(false),
+            webContents: { send: vi.fn(), mainFrame: { frames: [mockGeminiFrame] } },
+        };
+        (mockWindowManager.getMainWindow as ReturnType<typeof vi.fn>).mockReturnValue(mockMainWindow);
+
+        handler = new IssueIpcHandler({
+            store: mockStore as unknown as IpcHandlerDependencies['store'],
+            logger: mockLogger as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+            exportManager: mockExportManager as unknown as IpcHandlerDependencies['exportManager'],
+        });
+        handler.register();
+    });
+
+    describe('draftFromGemini', () => {
+        it('drafts the issue from the latest answer', async () => {
+            await handler.draftFromGemini();
+
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(IPC_CHANNELS.ISSUES_DRAFT, {
+                tracker: 'github',
+                title: 'Retry failed uploads',
+                body: '## Retry failed uploads\n\nDetails',
+            });
+        });
+
+        it('prefers the text selected in Gemini', async () => {
+            mockGeminiFrame.executeJavaScript.mockResolvedValue('  Selected line\nmore  ');
+
+            await handler.draftFromGemini();
+
+            expect(mockExportManager.getLastResponse).not.toHaveBeenCalled();
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(IPC_CHANNELS.ISSUES_DRAFT, {
+                tracker: 'github',
+                title: 'Selected line',
+                body: 'Selected line\nmore',
+            });
+        });
+
+        it('shows a toast when the tracker is not configured', async () => {
+            mockStore.set('githubToken', '');
+
+            await handler.draftFromGemini();
+
+            expect(mockExportManager.getLastResponse).not.toHaveBeenCalled();
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                IPC_CHANNELS.TOAST_SHOW,
+                expect.objectContaining({ type: 'error', message: 'Cannot create issue: GitHub token is not set' })
+            );
+        });
+
+        it('shows a toast when there is nothing to draft from', async () => {
+            mockExportManager.getLastResponse.mockResolvedValue(null);
+
+            await handler.draftFromGemini();
+
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                IPC_CHANNELS.TOAST_SHOW,
+                expect.objectContaining({ type: 'info', message: 'No answer or selection to create an issue from' })
+            );
+        });
+
+        it('is triggered by the create-issue-triggered menu event', async () => {
+            const call = (mockWindowManager.on as ReturnType<typeof vi.fn>).mock.calls.find(
+                ([event]) => event === 'create-issue-triggered'
+            );
+            (call![1] as () => void)();
+
+            await vi.waitFor(() =>
+                expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                    IPC_CHANNELS.ISSUES_DRAFT,
+                    expect.anything()
+                )
+            );
+        });
+    });
+
+    describe('create', () => {
+        it('creates the reviewed issue and returns its URL', async () => {
+            const result = await mockIpcMain._handlers.get(IPC_CHANNELS.ISSUES_CREATE)!(
+                {},
+                { tracker: 'github', title: ' Edited title ', body: 'Body' }
+            );
+
+            expect(result).toEqual({ success: true, url: 'https://github.com/octo/app/issues/7' });
+            expect(JSON.parse(mockFetch.mock.calls[0][1].body)).toEqual({ title: 'Edited title', body: 'Body' });
+        });
+
+        it('returns the error when the request fails', async () => {
+            mockFetch.mockResolvedValue({ ok: false, status: 404, statusText: 'Not Found' });
+
+            const result = await handler.create({ tracker: 'github', title: 'Bug', body: '' });
+
+            expect(result).toEqual({ success: false, error: 'GitHub request failed: 404 Not Found' });
+        });
+
+        it('rejects a draft without a title', async () => {
+            const result = await handler.create({ tracker: 'github', title: '  ', body: 'Body' });
+
+            expect(result).toEqual({ success: false, error: 'An issue needs a title' });
+            expect(mockFetch).not.toHaveBeenCalled();
+        });
+    });
+
+    describe('settings', () => {
+        it('returns the saved settings', () => {
+            const settings = mockIpcMain._handlers.get(IPC_CHANNELS.ISSUES_SETTINGS_GET)!();
+
+            expect(settings).toEqual({
+                tracker: 'github',
+                githubRepository: 'octo/app',
+                githubToken: 'ghp_token',
+                jiraBaseUrl: '',
+                jiraEmail: '',
+                jiraApiToken: '',
+                jiraProjectKey: '',
+            });
+        });
+
+        it('saves valid settings', () => {
+            mockIpcMain._listeners.get(IPC_CHANNELS.ISSUES_SETTINGS_SET)!(
+                {},
+                { tracker: 'jira', jiraBaseUrl: ' https://example.atlassian.net ', jiraProjectKey: 'APP' }
+            );
+
+            expect(mockStore.setMany).toHaveBeenCalledTimes(1);
+            expect(mockStore.setMany).toHaveBeenCalledWith(
+                expect.objectContaining({
+                    issueTracker: 'jira',
+                    jiraBaseUrl: 'https://example.atlassian.net',
+                    jiraProjectKey: 'APP',
+                    githubToken: '',
+                })
+            );
+            expect(mockStore.set).not.toHaveBeenCalled();
+        });
+
+        it('does not save tokens that did not change', () => {
+            mockIpcMain._listeners.get(IPC_CHANNELS.ISSUES_SETTINGS_SET)!(
+                {},
+                { tracker: 'github', githubRepository: 'octo/app', githubToken: 'ghp_token' }
+            );
+
+            const values = mockStore.setMany.mock.calls[0]![0];
+            expect(values).toMatchObject({ issueTracker: 'github', githubRepository: 'octo/app' });
+            expect(values).not.toHaveProperty('githubToken');
+            expect(values).not.toHaveProperty('jiraApiToken');
+        });
+
+        it('rejects invalid settings', () => {
+            mockIpcMain._listeners.get(IPC_CHANNELS.ISSUES_SETTINGS_SET)!(
+                {},
+                { tracker: 'jira', jiraBaseUrl: 'http://jira.local' }
+            );
+
+            expect(mockStore.setMany).not.toHaveBeenCalled();
+            expect(mockLogger.warn).toHaveBeenCalledWith('Jira URL must use https');
+        });
+    });
+
+    describe('unregister', () => {
+        it('removes all issue channels and the menu listener', () => {
+            handler.unregister();
+
+            expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.ISSUES_DRAFT_REQUEST);
+            expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.ISSUES_CREATE);
+            expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.ISSUES_SETTINGS_GET);
+            expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.ISSUES_SETTINGS_SET);
+            expect(mockWindowManager.removeListener).toHaveBeenCalledWith(
+                'create-issue-triggered',
+                expect.any(Function)
+            );
+        });
+    });
+});
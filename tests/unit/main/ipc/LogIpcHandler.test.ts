@@ -0,0 +1,85 @@
+/**
+ * Unit tests for LogIpcHandler.
+ *
+ * Tests the log:get-level / log:set-level IPC handlers.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { LogIpcHandler } from '../../../../src/main/managers/ipc/LogIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    return { mockIpcMain };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+}));
+
+vi.mock('../../../../src/main/utils/logger');
+import { setLogLevel, getLogLevel } from '../../../../src/main/utils/__mocks__/logger';
+
+describe('LogIpcHandler', () => {
+    let handler: LogIpcHandler;
+    let mockLogger: ReturnType<typeof createMockLogger>;
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+
+        mockLogger = createMockLogger();
+        handler = new LogIpcHandler({
+            store: createMockStore({}) as unknown as IpcHandlerDependencies['store'],
+            logger: mockLogger as unknown as IpcHandlerDependencies['logger'],
+            windowManager: createMockWindowManager() as unknown as IpcHandlerDependencies['windowManager'],
+        });
+        handler.register();
+    });
+
+    it('returns the active log level spec', () => {
+        getLogLevel.mockReturnValue('info,exportmanager=debug');
+        const getHandler = mockIpcMain._handlers.get(IPC_CHANNELS.LOG_GET_LEVEL)!;
+        expect(getHandler()).toBe('info,exportmanager=debug');
+    });
+
+    it('applies a valid spec', () => {
+        setLogLevel.mockReturnValue(true);
+        mockIpcMain._listeners.get(IPC_CHANNELS.LOG_SET_LEVEL)!({}, 'debug');
+
+        expect(setLogLevel).toHaveBeenCalledWith('debug');
+        expect(mockLogger.warn).toHaveBeenCalledWith('Log level set to: debug');
+    });
+
+    it('warns on an invalid spec', () => {
+        setLogLevel.mockReturnValue(false);
+        mockIpcMain._listeners.get(IPC_CHANNELS.LOG_SET_LEVEL)!({}, 'loud');
+
+        expect(mockLogger.warn).toHaveBeenCalledWith('Invalid log level: loud');
+    });
+
+    it('removes its channels on unregister', () => {
+        handler.unregister();
+
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.LOG_GET_LEVEL);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.LOG_SET_LEVEL);
+    });
+});
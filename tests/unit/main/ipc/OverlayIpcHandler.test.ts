@@ -0,0 +1,109 @@
+/**
+ * Unit tests for OverlayIpcHandler.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { OverlayIpcHandler } from '../../../../src/main/managers/ipc/OverlayIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain, mockBrowserWindow } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    return {
+        mockIpcMain,
+        mockBrowserWindow: { getAllWindows: vi.fn().mockReturnValue([]) },
+    };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    BrowserWindow: mockBrowserWindow,
+}));
+
+describe('OverlayIpcHandler', () => {
+    let handler: OverlayIpcHandler;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mockStore: ReturnType<typeof createMockStore>;
+    const win = { isDestroyed: () => false, webContents: { send: vi.fn() } };
+
+    const getListener = (event: string) =>
+        mockWindowManager.on.mock.calls.find(([name]) => name === event)![1];
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+        mockBrowserWindow.getAllWindows.mockReturnValue([win]);
+
+        mockWindowManager = createMockWindowManager();
+        mockStore = createMockStore({ windowOpacity: 0.7 });
+        handler = new OverlayIpcHandler({
+            store: mockStore as unknown as IpcHandlerDependencies['store'],
+            logger: createMockLogger() as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+        });
+        handler.register();
+    });
+
+    it('returns and sets the window opacity', () => {
+        mockWindowManager.getWindowOpacity.mockReturnValue(0.5);
+
+        expect(mockIpcMain._handlers.get(IPC_CHANNELS.WINDOW_OPACITY_GET)!({})).toEqual({ opacity: 0.5 });
+
+        mockIpcMain._listeners.get(IPC_CHANNELS.WINDOW_OPACITY_SET)!({}, 0.4);
+        expect(mockWindowManager.setWindowOpacity).toHaveBeenCalledWith(0.4);
+    });
+
+    it('persists and broadcasts opacity changes', () => {
+        getListener('window-opacity-changed')(0.4);
+
+        expect(mockStore.set).toHaveBeenCalledWith('windowOpacity', 0.4);
+        expect(win.webContents.send).toHaveBeenCalledWith(IPC_CHANNELS.WINDOW_OPACITY_CHANGED, { opacity: 0.4 });
+    });
+
+    it('returns and toggles overlay mode', () => {
+        mockWindowManager.isOverlayMode.mockReturnValue(true);
+
+        expect(mockIpcMain._handlers.get(IPC_CHANNELS.OVERLAY_MODE_GET)!({})).toEqual({ enabled: true });
+
+        mockIpcMain._listeners.get(IPC_CHANNELS.OVERLAY_MODE_TOGGLE)!({});
+        expect(mockWindowManager.toggleOverlayMode).toHaveBeenCalled();
+    });
+
+    it('broadcasts overlay mode changes without saving them', () => {
+        getListener('overlay-mode-changed')(true);
+
+        expect(win.webContents.send).toHaveBeenCalledWith(IPC_CHANNELS.OVERLAY_MODE_CHANGED, { enabled: true });
+        expect(mockStore.set).not.toHaveBeenCalled();
+    });
+
+    it('applies the saved opacity on initialize', () => {
+        handler.initialize();
+
+        expect(mockWindowManager.setWindowOpacity).toHaveBeenCalledWith(0.7);
+    });
+
+    it('removes its listeners on unregister', () => {
+        handler.unregister();
+
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.WINDOW_OPACITY_GET);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.OVERLAY_MODE_TOGGLE);
+        expect(mockWindowManager.removeListener).toHaveBeenCalledWith('overlay-mode-changed', expect.any(Function));
+    });
+});
@@ -0,0 +1,179 @@
+/**
+ * Unit tests for ProxyIpcHandler.
+ *
+ * Tests saving and applying proxy settings, the connection test channel,
+ * and answering proxy authentication challenges.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { ProxyIpcHandler } from '../../../../src/main/managers/ipc/ProxyIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain, mockApp, mockSession } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    return {
+        mockIpcMain,
+        mockApp: { on: vi.fn(), removeListener: vi.fn() },
+        mockSession: { defaultSession: {} },
+    };
+});
+
+vi.mock('electron', () => ({
+    app: mockApp,
+    ipcMain: mockIpcMain,
+    session: mockSession,
+}));
+
+const { mockApplyProxySettings, mockTestProxyConnection } = vi.hoisted(() => ({
+    mockApplyProxySettings: vi.fn().mockResolvedValue(undefined),
+    mockTestProxyConnection: vi.fn().mockResolvedValue({ success: true, statusCode: 200, latencyMs: 12 }),
+}));
+
+vi.mock('../../../../src/main/utils/proxy', async (importOriginal) => ({
+    ...(await importOriginal<typeof import('../../../../src/main/utils/proxy')>()),
+    applyProxySettings: mockApplyProxySettings,
+    testProxyConnection: mockTestProxyConnection,
+    encryptProxyPassword: (password: string) => (password ? `enc:${password}` : ''),
+    decryptProxyPassword: (stored: string) => stored.replace(/^enc:/, ''),
+}));
+
+describe('ProxyIpcHandler', () => {
+    let handler: ProxyIpcHandler;
+    let mockLogger: ReturnType<typeof createMockLogger>;
+    let mockStore: ReturnType<typeof createMockStore>;
+
+    const setSettings = (settings: unknown) =>
+        mockIpcMain._listeners.get(IPC_CHANNELS.PROXY_SET_SETTINGS)!({}, settings);
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+
+        mockLogger = createMockLogger();
+        mockStore = createMockStore({ proxyMode: 'system', proxyPort: 8080 });
+        handler = new ProxyIpcHandler({
+            store: mockStore as unknown as IpcHandlerDependencies['store'],
+            logger: mockLogger as unknown as IpcHandlerDependencies['logger'],
+            windowManager: createMockWindowManager() as unknown as IpcHandlerDependencies['windowManager'],
+        });
+        handler.register();
+    });
+
+    it('applies the saved settings on register', () => {
+        expect(mockApplyProxySettings).toHaveBeenCalledWith(
+            mockSession.defaultSession,
+            expect.objectContaining({ mode: 'system' })
+        );
+    });
+
+    it('saves, encrypts and applies valid settings', () => {
+        setSettings({
+            mode: 'http',
+            host: ' proxy.corp ',
+            port: 3128,
+            username: 'alice',
+            password: 'hunter2',
+            bypass: 'localhost',
+        });
+
+        expect(mockStore.setMany).toHaveBeenCalledTimes(1);
+        expect(mockStore.setMany).toHaveBeenCalledWith({
+            proxyMode: 'http',
+            proxyHost: 'proxy.corp',
+            proxyPort: 3128,
+            proxyUsername: 'alice',
+            proxyPassword: 'enc:hunter2',
+            proxyBypass: 'localhost',
+        });
+        expect(mockApplyProxySettings).toHaveBeenLastCalledWith(
+            mockSession.defaultSession,
+            expect.objectContaining({ mode: 'http', host: 'proxy.corp', port: 3128 })
+        );
+
+        const getHandler = mockIpcMain._handlers.get(IPC_CHANNELS.PROXY_GET_SETTINGS)!;
+        expect(getHandler()).toMatchObject({ mode: 'http', password: 'hunter2' });
+    });
+
+    it('does not rewrite an unchanged password', () => {
+        mockStore.set('proxyPassword', 'enc:hunter2');
+
+        setSettings({ mode: 'http', host: 'proxy.corp', port: 3128, username: 'alice', password: 'hunter2' });
+
+        expect(mockStore.setMany.mock.calls[0]![0]).not.toHaveProperty('proxyPassword');
+    });
+
+    it('rejects invalid settings', () => {
+        setSettings({ mode: 'socks5', host: '', port: 1080 });
+
+        expect(mockStore.setMany).not.toHaveBeenCalled();
+        expect(mockLogger.warn).toHaveBeenCalledWith('Invalid proxy host: ');
+    });
+
+    it('never logs the password', () => {
+        setSettings({ mode: 'http', host: 'proxy.corp', port: 3128, username: 'alice', password: 'hunter2' });
+
+        expect(JSON.stringify(mockLogger.log.mock.calls)).not.toContain('hunter2');
+    });
+
+    it('tests the connection through the default session', async () => {
+        const result = await mockIpcMain._handlers.get(IPC_CHANNELS.PROXY_TEST)!();
+
+        expect(mockTestProxyConnection).toHaveBeenCalledWith(mockSession.defaultSession);
+        expect(result).toEqual({ success: true, statusCode: 200, latencyMs: 12 });
+    });
+
+    describe('proxy authentication', () => {
+        const login = (isProxy: boolean) => {
+            const onLogin = mockApp.on.mock.calls.find(([event]) => event === 'login')![1];
+            const event = { preventDefault: vi.fn() };
+            const callback = vi.fn();
+            onLogin(event, null, {}, { isProxy }, callback);
+            return { event, callback };
+        };
+
+        it('answers proxy challenges with the saved credentials', () => {
+            mockStore.set('proxyUsername', 'alice');
+            mockStore.set('proxyPassword', 'enc:hunter2');
+
+            const { event, callback } = login(true);
+
+            expect(event.preventDefault).toHaveBeenCalled();
+            expect(callback).toHaveBeenCalledWith('alice', 'hunter2');
+        });
+
+        it('ignores site authentication challenges', () => {
+            mockStore.set('proxyUsername', 'alice');
+
+            const { callback } = login(false);
+
+            expect(callback).not.toHaveBeenCalled();
+        });
+    });
+
+    it('removes handlers on unregister', () => {
+        handler.unregister();
+
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.PROXY_GET_SETTINGS);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.PROXY_SET_SETTINGS);
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.PROXY_TEST);
+        expect(mockApp.removeListener).toHaveBeenCalledWith('login', expect.any(Function));
+    });
+});
@@ -4,6 +4,7 @@
  * Tests Quick Chat IPC handlers including:
  * - quick-chat:submit, hide, cancel
  * - gemini:ready with text injection
+ * - quick-chat:translate with clipboard fallback
  * - E2E mode handling
  * - Error scenarios
  */
@@ -14,7 +15,7 @@ import { createMockLogger, createMockWindowManager, createMockStore } from '../.
 import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
 
 // Mock Electron
-const { mockIpcMain } = vi.hoisted(() => {
+const { mockIpcMain, mockClipboard } = vi.hoisted(() => {
     const mockIpcMain = {
         on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
             mockIpcMain._listeners.set(channel, listener);
@@ -30,11 +31,16 @@ const { mockIpcMain } = vi.hoisted(() => {
         },
     };
 
-    return { mockIpcMain };
+    const mockClipboard = {
+        readText: vi.fn().mockReturnValue(''),
+    };
+
+    return { mockIpcMain, mockClipboard };
 });
 
 vi.mock('electron', () => ({
     ipcMain: mockIpcMain,
+    clipboard: mockClipboard,
 }));
 
 // Mock InjectionScriptBuilder
@@ -112,6 +118,21 @@ describe('QuickChatIpcHandler', () => {
         });
     });
 
+    describe('ask-triggered event', () => {
+        it('submits the prompt like Quick Chat', () => {
+            handler.register();
+
+            const listener = mockWindowManager.on.mock.calls.find(([event]) => event === 'ask-triggered')![1];
+            listener('Hello from the CLI');
+
+            expect(mockWindowManager.focusMainWindow).toHaveBeenCalled();
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                IPC_CHANNELS.GEMINI_NAVIGATE,
+                expect.objectContaining({ text: 'Hello from the CLI' })
+            );
+        });
+    });
+
     describe('quick-chat:submit handler', () => {
         beforeEach(() => {
             handler.register();
@@ -300,4 +321,69 @@ describe('QuickChatIpcHandler', () => {
             }
         });
     });
+
+    describe('quick-chat:translate handler', () => {
+        let mockStore: ReturnType<typeof createMockStore>;
+
+        beforeEach(() => {
+            mockStore = createMockStore({ translateTargetLanguage: 'French' });
+            handler = new QuickChatIpcHandler({ ...mockDeps, store: mockStore });
+            handler.register();
+            mockClipboard.readText.mockReturnValue('');
+        });
+
+        it('returns the remembered target language', () => {
+            const getHandler = mockIpcMain._handlers.get(IPC_CHANNELS.QUICK_CHAT_TRANSLATE_LANGUAGE_GET);
+            expect(getHandler!()).toBe('French');
+        });
+
+        it('falls back to the default language when stored value is invalid', () => {
+            mockStore._defaults.translateTargetLanguage = 'Klingon';
+            const getHandler = mockIpcMain._handlers.get(IPC_CHANNELS.QUICK_CHAT_TRANSLATE_LANGUAGE_GET);
+            expect(getHandler!()).toBe('English');
+        });
+
+        it('wraps provided text in a translation prompt and submits it', () => {
+            const listener = mockIpcMain._listeners.get(IPC_CHANNELS.QUICK_CHAT_TRANSLATE);
+            listener!({}, 'Hola mundo', 'German');
+
+            expect(mockStore.set).toHaveBeenCalledWith('translateTargetLanguage', 'German');
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                IPC_CHANNELS.GEMINI_NAVIGATE,
+                expect.objectContaining({
+                    text: expect.stringContaining('Translate the following text to German'),
+                })
+            );
+            expect(mockClipboard.readText).not.toHaveBeenCalled();
+        });
+
+        it('uses clipboard contents when no text is provided', () => {
+            mockClipboard.readText.mockReturnValue('Bonjour');
+            const listener = mockIpcMain._listeners.get(IPC_CHANNELS.QUICK_CHAT_TRANSLATE);
+            listener!({}, '', 'English');
+
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                IPC_CHANNELS.GEMINI_NAVIGATE,
+                expect.objectContaining({
+                    text: expect.stringContaining('Bonjour'),
+                })
+            );
+        });
+
+        it('does nothing when input and clipboard are empty', () => {
+            const listener = mockIpcMain._listeners.get(IPC_CHANNELS.QUICK_CHAT_TRANSLATE);
+            listener!({}, '  ', 'English');
+
+            expect(mockMainWindow.webContents.send).not.toHaveBeenCalled();
+            expect(mockLogger.warn).toHaveBeenCalled();
+        });
+
+        it('rejects unsupported languages', () => {
+            const listener = mockIpcMain._listeners.get(IPC_CHANNELS.QUICK_CHAT_TRANSLATE);
+            listener!({}, 'text', 'Klingon');
+
+            expect(mockStore.set).not.toHaveBeenCalled();
+            expect(mockMainWindow.webContents.send).not.toHaveBeenCalled();
+        });
+    });
 });
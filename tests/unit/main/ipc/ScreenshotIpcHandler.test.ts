@@ -0,0 +1,176 @@
+/**
+ * Unit tests for ScreenshotIpcHandler.
+ *
+ * Tests capturing the Gemini view or the latest answer to the clipboard
+ * or a PNG file.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { ScreenshotIpcHandler } from '../../../../src/main/managers/ipc/ScreenshotIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain, mockClipboard, mockDialog, mockWriteFile } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn(),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+        },
+    };
+
+    return {
+        mockIpcMain,
+        mockClipboard: { writeImage: vi.fn() },
+        mockDialog: { showSaveDialog: vi.fn() },
+        mockWriteFile: vi.fn(),
+    };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    clipboard: mockClipboard,
+    dialog: mockDialog,
+    app: { getPath: vi.fn().mockReturnValue('/downloads') },
+}));
+
+vi.mock('fs/promises', () => ({ writeFile: mockWriteFile, default: { writeFile: mockWriteFile } }));
+
+describe('ScreenshotIpcHandler', () => {
+    let handler: ScreenshotIpcHandler;
+    let mockLogger: ReturnType<typeof createMockLogger>;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mockImage: { toPNG: ReturnType<typeof vi.fn> };
+    let mockGeminiFrame: { url: string; executeJavaScript: ReturnType<typeof vi.fn> };
+    let mockMainWindow: {
+        isDestroyed: ReturnType<typeof vi.fn>;
+        webContents: {
+            send: ReturnType<typeof vi.fn>;
+            executeJavaScript: ReturnType<typeof vi.fn>;
+            capturePage: ReturnType<typeof vi.fn>;
+            mainFrame: { frames: unknown[] };
+        };
+    };
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+        mockDialog.showSaveDialog.mockResolvedValue({ canceled: false, filePath: '/downloads/shot.png' });
+        mockWriteFile.mockResolvedValue(undefined);
+
+        mockLogger = createMockLogger();
+        mockWindowManager = createMockWindowManager();
+        mockImage = { toPNG: vi.fn().mockReturnValue(Buffer.from('png')) };
+        mockGeminiFrame = {
+            url: 'https://gemini.google.com/app',
+            executeJavaScript: vi.fn().mockResolvedValue({ x: 10, y: 100, width: 600, height: 200 }),
+        };
+        mockMainWindow = {
+            isDestroyed: vi.fn().mockReturnValue(false),
+            webContents: {
+                send: vi.fn(),
+                executeJavaScript: vi.fn().mockResolvedValue({ x: 0, y: 40, width: 1200, height: 760 }),
+                capturePage: vi.fn().mockResolvedValue(mockImage),
+                mainFrame: { frames: [mockGeminiFrame] },
+            },
+        };
+        (mockWindowManager.getMainWindow as ReturnType<typeof vi.fn>).mockReturnValue(mockMainWindow);
+
+        handler = new ScreenshotIpcHandler({
+            store: createMockStore({}) as unknown as IpcHandlerDependencies['store'],
+            logger: mockLogger as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+        });
+        handler.register();
+    });
+
+    it('copies the visible Gemini view to the clipboard', async () => {
+        await handler.capture({ target: 'clipboard', region: 'view' });
+
+        expect(mockMainWindow.webContents.capturePage).toHaveBeenCalledWith({ x: 0, y: 40, width: 1200, height: 760 });
+        expect(mockGeminiFrame.executeJavaScript).not.toHaveBeenCalled();
+        expect(mockClipboard.writeImage).toHaveBeenCalledWith(mockImage);
+        expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+            IPC_CHANNELS.TOAST_SHOW,
+            expect.objectContaining({ type: 'success', message: 'Screenshot copied to clipboard' })
+        );
+    });
+
+    it('captures only the latest answer, offset by the frame position', async () => {
+        await handler.capture({ target: 'clipboard', region: 'response' });
+
+        expect(mockMainWindow.webContents.capturePage).toHaveBeenCalledWith({ x: 10, y: 140, width: 600, height: 200 });
+    });
+
+    it('saves the screenshot as a PNG file', async () => {
+        await handler.capture({ target: 'file', region: 'view' });
+
+        expect(mockDialog.showSaveDialog).toHaveBeenCalledWith(
+            mockMainWindow,
+            expect.objectContaining({ defaultPath: expect.stringMatching(/gemini-screenshot_.+\.png$/) })
+        );
+        expect(mockWriteFile).toHaveBeenCalledWith('/downloads/shot.png', Buffer.from('png'));
+        expect(mockClipboard.writeImage).not.toHaveBeenCalled();
+    });
+
+    it('does not write a file when the save dialog is cancelled', async () => {
+        mockDialog.showSaveDialog.mockResolvedValue({ canceled: true });
+
+        await handler.capture({ target: 'file', region: 'view' });
+
+        expect(mockWriteFile).not.toHaveBeenCalled();
+    });
+
+    it('shows a toast when there is no answer to capture', async () => {
+        mockGeminiFrame.executeJavaScript.mockResolvedValue(null);
+
+        await handler.capture({ target: 'clipboard', region: 'response' });
+
+        expect(mockMainWindow.webContents.capturePage).not.toHaveBeenCalled();
+        expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+            IPC_CHANNELS.TOAST_SHOW,
+            expect.objectContaining({ type: 'info', message: 'No answer to capture' })
+        );
+    });
+
+    it('shows a toast when Gemini is not loaded', async () => {
+        mockMainWindow.webContents.mainFrame.frames = [];
+
+        await handler.capture({ target: 'clipboard', region: 'view' });
+
+        expect(mockMainWindow.webContents.capturePage).not.toHaveBeenCalled();
+        expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+            IPC_CHANNELS.TOAST_SHOW,
+            expect.objectContaining({ type: 'error', message: 'Cannot capture screenshot: Gemini is not loaded' })
+        );
+    });
+
+    it('ignores invalid options from the renderer', () => {
+        mockIpcMain._listeners.get(IPC_CHANNELS.SCREENSHOT_CAPTURE)!({}, { target: 'printer', region: 'view' });
+
+        expect(mockLogger.warn).toHaveBeenCalled();
+        expect(mockMainWindow.webContents.capturePage).not.toHaveBeenCalled();
+    });
+
+    it('is triggered by the screenshot-triggered menu event', async () => {
+        const call = (mockWindowManager.on as ReturnType<typeof vi.fn>).mock.calls.find(
+            ([event]) => event === 'screenshot-triggered'
+        );
+        (call![1] as (options: unknown) => void)({ target: 'clipboard', region: 'view' });
+
+        await vi.waitFor(() => expect(mockClipboard.writeImage).toHaveBeenCalled());
+    });
+
+    it('removes its listeners on unregister', () => {
+        handler.unregister();
+
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.SCREENSHOT_CAPTURE);
+        expect(mockWindowManager.removeListener).toHaveBeenCalledWith('screenshot-triggered', expect.any(Function));
+    });
+});
@@ -0,0 +1,220 @@
+/**
+ * Unit tests for SettingsIpcHandler.
+ *
+ * Tests the settings:reset handler: section validation, backup before reset,
+ * and applying the restored values at runtime. Also tests the settings history
+ * and undo handlers.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { SettingsIpcHandler } from '../../../../src/main/managers/ipc/SettingsIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import {
+    createMockLogger,
+    createMockWindowManager,
+    createMockStore,
+    createMockHotkeyManager,
+} from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+import { DEFAULT_ACCELERATORS } from '../../../../src/shared/types/hotkeys';
+
+// Mock Electron
+const { mockIpcMain, mockNativeTheme, mockBrowserWindow } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn(),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    return {
+        mockIpcMain,
+        mockNativeTheme: { themeSource: 'dark', shouldUseDarkColors: false },
+        mockBrowserWindow: { getAllWindows: vi.fn(() => []) },
+    };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    nativeTheme: mockNativeTheme,
+    BrowserWindow: mockBrowserWindow,
+}));
+
+describe('SettingsIpcHandler', () => {
+    let handler: SettingsIpcHandler;
+    let mockLogger: ReturnType<typeof createMockLogger>;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mockHotkeyManager: ReturnType<typeof createMockHotkeyManager>;
+    let mockStore: ReturnType<typeof createMockStore> & {
+        backup: ReturnType<typeof vi.fn>;
+        resetKeys: ReturnType<typeof vi.fn>;
+        getAll: ReturnType<typeof vi.fn>;
+        getHistory: ReturnType<typeof vi.fn>;
+        undo: ReturnType<typeof vi.fn>;
+    };
+
+    const reset = (section: unknown) => mockIpcMain._handlers.get(IPC_CHANNELS.SETTINGS_RESET)!({}, section);
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+        mockNativeTheme.themeSource = 'dark';
+
+        mockLogger = createMockLogger();
+        mockWindowManager = createMockWindowManager();
+        mockHotkeyManager = createMockHotkeyManager();
+        mockStore = Object.assign(createMockStore({ theme: 'system', alwaysOnTop: false, zoomLevel: 100 }), {
+            backup: vi.fn().mockReturnValue('/data/user-preferences.backup-1.json'),
+            resetKeys: vi.fn().mockReturnValue(true),
+            getAll: vi.fn(() => ({
+                theme: 'dark',
+                zoomLevel: 150,
+                textPredictionModelStatus: 'ready',
+                whatsNewLastVersion: '1.2.0',
+                windowWidth: 1200,
+                windowMaximized: true,
+            })),
+            getHistory: vi.fn(() => [
+                { timestamp: 1, key: 'theme', previous: 'system', value: 'dark' },
+                { timestamp: 2, key: 'proxyPassword', previous: '', value: 'enc:secret' },
+                { timestamp: 3, key: 'shareWebhookUrl', previous: '', value: 'https://hooks.slack.com/services/T/B/x' },
+            ]),
+            undo: vi.fn(() => ({
+                undone: [{ timestamp: 1, key: 'theme', previous: 'system', value: 'dark' }],
+                saved: true,
+            })),
+        });
+
+        handler = new SettingsIpcHandler({
+            store: mockStore as unknown as IpcHandlerDependencies['store'],
+            logger: mockLogger as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+            hotkeyManager: mockHotkeyManager as unknown as IpcHandlerDependencies['hotkeyManager'],
+        });
+        handler.register();
+    });
+
+    it('backs up settings before resetting a section', () => {
+        const result = reset('appearance');
+
+        expect(result).toEqual({ success: true, backupPath: '/data/user-preferences.backup-1.json' });
+        expect(mockStore.resetKeys).toHaveBeenCalledWith(['theme', 'alwaysOnTop', 'zoomLevel']);
+        expect(mockStore.backup.mock.invocationCallOrder[0]).toBeLessThan(
+            mockStore.resetKeys.mock.invocationCallOrder[0]
+        );
+    });
+
+    it('applies reset appearance settings at runtime', () => {
+        reset('appearance');
+
+        expect(mockNativeTheme.themeSource).toBe('system');
+        expect(mockWindowManager.setAlwaysOnTop).toHaveBeenCalledWith(false);
+        expect(mockWindowManager.setZoomLevel).toHaveBeenCalledWith(100);
+        expect(mockHotkeyManager.updateAllSettings).not.toHaveBeenCalled();
+    });
+
+    it('falls back to default accelerators after a hotkey reset', () => {
+        reset('hotkeys');
+
+        expect(mockStore.resetKeys).toHaveBeenCalledWith(expect.arrayContaining(['acceleratorBossKey']));
+        expect(mockHotkeyManager.updateAllAccelerators).toHaveBeenCalledWith(DEFAULT_ACCELERATORS);
+        expect(mockWindowManager.setZoomLevel).not.toHaveBeenCalled();
+    });
+
+    it('resets every stored key except state for "all"', () => {
+        reset('all');
+
+        expect(mockStore.resetKeys).toHaveBeenCalledWith(['theme', 'zoomLevel']);
+        expect(mockHotkeyManager.updateAllSettings).toHaveBeenCalled();
+        expect(mockWindowManager.setZoomLevel).toHaveBeenCalled();
+    });
+
+    it('rejects unknown sections', () => {
+        const result = reset('network');
+
+        expect(result).toMatchObject({ success: false, backupPath: null });
+        expect(mockStore.backup).not.toHaveBeenCalled();
+        expect(mockLogger.warn).toHaveBeenCalled();
+    });
+
+    it('reports a failed save', () => {
+        mockStore.resetKeys.mockReturnValue(false);
+
+        expect(reset('hotkeys')).toMatchObject({ success: false, backupPath: '/data/user-preferences.backup-1.json' });
+    });
+
+    describe('history', () => {
+        const undo = (count: unknown) => mockIpcMain._handlers.get(IPC_CHANNELS.SETTINGS_UNDO)!({}, count);
+
+        it('returns changes newest first with secrets hidden', () => {
+            expect(mockIpcMain._handlers.get(IPC_CHANNELS.SETTINGS_GET_HISTORY)!({})).toEqual([
+                { timestamp: 3, key: 'shareWebhookUrl', previous: '', value: '••••••••' },
+                { timestamp: 2, key: 'proxyPassword', previous: '', value: '••••••••' },
+                { timestamp: 1, key: 'theme', previous: 'system', value: 'dark' },
+            ]);
+        });
+
+        it('applies undone appearance settings at runtime', () => {
+            const result = undo(1);
+
+            expect(mockStore.undo).toHaveBeenCalledWith(1);
+            expect(result).toEqual({
+                success: true,
+                undone: [{ timestamp: 1, key: 'theme', previous: 'system', value: 'dark' }],
+                restartRecommended: false,
+            });
+            expect(mockWindowManager.setZoomLevel).toHaveBeenCalled();
+            expect(mockHotkeyManager.updateAllSettings).not.toHaveBeenCalled();
+        });
+
+        it('recommends a restart for settings that are not applied at runtime', () => {
+            mockStore.undo.mockReturnValue({
+                undone: [{ timestamp: 3, key: 'proxyMode', value: 'manual' }],
+                saved: true,
+            });
+
+            expect(undo(1)).toMatchObject({ success: true, restartRecommended: true });
+        });
+
+        it('redacts and considers settings undone together', () => {
+            mockStore.undo.mockReturnValue({
+                undone: [
+                    {
+                        timestamp: 4,
+                        key: 'proxyMode',
+                        value: 'http',
+                        batch: [{ timestamp: 4, key: 'proxyPassword', previous: 'enc:old', value: 'enc:new' }],
+                    },
+                ],
+                saved: true,
+            });
+
+            expect(undo(1)).toMatchObject({
+                restartRecommended: true,
+                undone: [{ batch: [{ key: 'proxyPassword', previous: '••••••••', value: '••••••••' }] }],
+            });
+        });
+
+        it('rejects invalid counts', () => {
+            expect(undo(0)).toMatchObject({ success: false });
+            expect(undo('2')).toMatchObject({ success: false });
+            expect(mockStore.undo).not.toHaveBeenCalled();
+        });
+
+        it('reports a failed save', () => {
+            mockStore.undo.mockReturnValue({ undone: [], saved: false });
+
+            expect(undo(1)).toMatchObject({ success: false, error: expect.stringContaining('could not be saved') });
+        });
+    });
+
+    it('removes the handler on unregister', () => {
+        handler.unregister();
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.SETTINGS_RESET);
+    });
+});
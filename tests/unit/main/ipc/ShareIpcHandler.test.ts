@@ -0,0 +1,292 @@
+/**
+ * Unit tests for ShareIpcHandler.
+ *
+ * Tests the redacted preview of the latest answer, posting it to the
+ * configured webhook, the share settings channels and sharing as a QR code.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { ShareIpcHandler } from '../../../../src/main/managers/ipc/ShareIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import type QrCodeManager from '../../../../src/main/managers/qrCodeManager';
+import {
+    createMockLogger,
+    createMockWindowManager,
+    createMockStore,
+    createMockExportManager,
+} from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain, mockFetch } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    return { mockIpcMain, mockFetch: vi.fn() };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    session: { defaultSession: { fetch: mockFetch } },
+    safeStorage: {
+        isEncryptionAvailable: vi.fn().mockReturnValue(false),
+        encryptString: vi.fn(),
+        decryptString: vi.fn(),
+    },
+}));
+
+vi.mock('../../../../src/main/managers/qrCodeManager', () => ({
+    default: vi.fn(),
+}));
+
+const WEBHOOK_URL = 'https://hooks.slack.com/services/T0/B0/x';
+
+describe('ShareIpcHandler', () => {
+    let handler: ShareIpcHandler;
+    let mockLogger: ReturnType<typeof createMockLogger>;
+    let mockStore: ReturnType<typeof createMockStore>;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mockExportManager: ReturnType<typeof createMockExportManager>;
+    let mockQrCodeManager: { show: ReturnType<typeof vi.fn>; close: ReturnType<typeof vi.fn> };
+    let mockMainWindow: {
+        isDestroyed: ReturnType<typeof vi.fn>;
+        webContents: { send: ReturnType<typeof vi.fn> };
+    };
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+        mockFetch.mockResolvedValue({ ok: true, status: 200, statusText: 'OK' });
+
+        mockLogger = createMockLogger();
+        mockStore = createMockStore({ shareService: 'slack', shareWebhookUrl: WEBHOOK_URL });
+        mockWindowManager = createMockWindowManager();
+        mockExportManager = createMockExportManager();
+        mockExportManager.getLastResponse.mockResolvedValue({
+            markdown: 'Set password=hunter2 and retry.',
+            text: 'Set password=hunter2 and retry.',
+            html: '<p>Set password=hunter2 and retry.</p>',
+        });
+        mockMainWindow = {
+            isDestroyed: vi.fn().mockReturnValue(false),
+            webContents: { send: vi.fn() },
+        };
+        (mockWindowManager.getMainWindow as ReturnType<typeof vi.fn>).mockReturnValue(mockMainWindow);
+        mockQrCodeManager = { show: vi.fn(), close: vi.fn() };
+
+        handler = new ShareIpcHandler(
+            {
+                store: mockStore as unknown as IpcHandlerDependencies['store'],
+                logger: mockLogger as unknown as IpcHandlerDependencies['logger'],
+                windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+                exportManager: mockExportManager as unknown as IpcHandlerDependencies['exportManager'],
+            },
+            mockQrCodeManager as unknown as QrCodeManager
+        );
+        handler.register();
+    });
+
+    describe('previewLastAnswer', () => {
+        it('sends the redacted answer to the main window', async () => {
+            await handler.previewLastAnswer();
+
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(IPC_CHANNELS.SHARE_PREVIEW, {
+                service: 'slack',
+                markdown: 'Set password=[REDACTED] and retry.',
+                redactions: 1,
+            });
+        });
+
+        it('shows a toast when sharing is not configured', async () => {
+            mockStore.set('shareWebhookUrl', '');
+
+            await handler.previewLastAnswer();
+
+            expect(mockExportManager.getLastResponse).not.toHaveBeenCalled();
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                IPC_CHANNELS.TOAST_SHOW,
+                expect.objectContaining({ type: 'error', message: 'Cannot share: Slack webhook URL is not set' })
+            );
+        });
+
+        it('shows a toast when there is no answer', async () => {
+            mockExportManager.getLastResponse.mockResolvedValue(null);
+
+            await handler.previewLastAnswer();
+
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                IPC_CHANNELS.TOAST_SHOW,
+                expect.objectContaining({ type: 'info', message: 'No answer to share' })
+            );
+        });
+
+        it('is triggered by the share-answer-triggered menu event', async () => {
+            const call = (mockWindowManager.on as ReturnType<typeof vi.fn>).mock.calls.find(
+                ([event]) => event === 'share-answer-triggered'
+            );
+            (call![1] as () => void)();
+
+            await vi.waitFor(() =>
+                expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                    IPC_CHANNELS.SHARE_PREVIEW,
+                    expect.anything()
+                )
+            );
+        });
+    });
+
+    describe('postAnswer', () => {
+        it('posts the reviewed answer, redacting it again', async () => {
+            await handler.postAnswer('Token: abc123 edited in');
+
+            const [url, init] = mockFetch.mock.calls[0];
+            expect(url).toBe(WEBHOOK_URL);
+            expect(JSON.parse(init.body).text).toBe('Token: [REDACTED] edited in');
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                IPC_CHANNELS.TOAST_SHOW,
+                expect.objectContaining({ type: 'success', message: 'Answer posted to Slack' })
+            );
+        });
+
+        it('shows an error toast when the webhook fails', async () => {
+            mockFetch.mockResolvedValue({ ok: false, status: 403, statusText: 'Forbidden' });
+
+            await handler.postAnswer('Answer');
+
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                IPC_CHANNELS.TOAST_SHOW,
+                expect.objectContaining({ type: 'error', message: 'Failed to post answer to Slack' })
+            );
+        });
+
+        it('ignores empty messages', async () => {
+            await handler.postAnswer('  ');
+
+            expect(mockFetch).not.toHaveBeenCalled();
+            expect(mockLogger.warn).toHaveBeenCalled();
+        });
+    });
+
+    describe('settings', () => {
+        it('returns the saved settings', () => {
+            const settings = mockIpcMain._handlers.get(IPC_CHANNELS.SHARE_SETTINGS_GET)!();
+
+            expect(settings).toEqual({ service: 'slack', webhookUrl: WEBHOOK_URL });
+        });
+
+        it('saves valid settings', () => {
+            const webhookUrl = 'https://contoso.webhook.office.com/webhookb2/x';
+            mockIpcMain._listeners.get(IPC_CHANNELS.SHARE_SETTINGS_SET)!({}, { service: 'teams', webhookUrl });
+
+            expect(mockStore.setMany).toHaveBeenCalledWith({ shareService: 'teams', shareWebhookUrl: webhookUrl });
+        });
+
+        it('does not save an unchanged webhook URL', () => {
+            mockIpcMain._listeners.get(IPC_CHANNELS.SHARE_SETTINGS_SET)!(
+                {},
+                { service: 'slack', webhookUrl: WEBHOOK_URL }
+            );
+
+            expect(mockStore.setMany).toHaveBeenCalledWith({ shareService: 'slack' });
+        });
+
+        it('rejects a webhook URL for another service', () => {
+            mockIpcMain._listeners.get(IPC_CHANNELS.SHARE_SETTINGS_SET)!(
+                {},
+                { service: 'teams', webhookUrl: WEBHOOK_URL }
+            );
+
+            expect(mockStore.setMany).not.toHaveBeenCalled();
+            expect(mockLogger.warn).toHaveBeenCalledWith('Not a Teams webhook URL: hooks.slack.com');
+        });
+    });
+
+    describe('shareAsQr', () => {
+        const CONVERSATION_URL = 'https://gemini.google.com/app/abc123';
+
+        beforeEach(() => {
+            mockWindowManager.getGeminiLocation.mockResolvedValue({
+                url: CONVERSATION_URL,
+                title: 'Chat',
+                conversationId: 'abc123',
+            });
+        });
+
+        it('shows the conversation Markdown when it fits', async () => {
+            mockExportManager.getConversationMarkdown.mockResolvedValue('# Chat\n\nHello');
+
+            await handler.shareAsQr();
+
+            expect(mockQrCodeManager.show).toHaveBeenCalledWith('# Chat\n\nHello', 'Conversation as Markdown');
+        });
+
+        it('shows the conversation link when the Markdown is too long', async () => {
+            mockExportManager.getConversationMarkdown.mockResolvedValue('x'.repeat(3000));
+
+            await handler.shareAsQr();
+
+            expect(mockQrCodeManager.show).toHaveBeenCalledWith(CONVERSATION_URL, CONVERSATION_URL);
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                IPC_CHANNELS.TOAST_SHOW,
+                expect.objectContaining({ type: 'info' })
+            );
+        });
+
+        it('shows a toast outside a conversation', async () => {
+            mockWindowManager.getGeminiLocation.mockResolvedValue({
+                url: 'https://gemini.google.com/app',
+                title: 'Gemini',
+                conversationId: null,
+            });
+
+            await handler.shareAsQr();
+
+            expect(mockQrCodeManager.show).not.toHaveBeenCalled();
+            expect(mockMainWindow.webContents.send).toHaveBeenCalledWith(
+                IPC_CHANNELS.TOAST_SHOW,
+                expect.objectContaining({ type: 'info', message: 'No conversation to share' })
+            );
+        });
+
+        it('is triggered by the share:qr channel and the share-qr-triggered menu event', async () => {
+            mockIpcMain._listeners.get(IPC_CHANNELS.SHARE_QR)!();
+            const call = (mockWindowManager.on as ReturnType<typeof vi.fn>).mock.calls.find(
+                ([event]) => event === 'share-qr-triggered'
+            );
+            (call![1] as () => void)();
+
+            await vi.waitFor(() => expect(mockQrCodeManager.show).toHaveBeenCalledTimes(2));
+        });
+    });
+
+    describe('unregister', () => {
+        it('removes all share channels and the menu listener', () => {
+            handler.unregister();
+
+            expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.SHARE_PREVIEW_REQUEST);
+            expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.SHARE_POST);
+            expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.SHARE_SETTINGS_GET);
+            expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.SHARE_SETTINGS_SET);
+            expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.SHARE_QR);
+            expect(mockWindowManager.removeListener).toHaveBeenCalledWith(
+                'share-answer-triggered',
+                expect.any(Function)
+            );
+            expect(mockWindowManager.removeListener).toHaveBeenCalledWith('share-qr-triggered', expect.any(Function));
+            expect(mockQrCodeManager.close).toHaveBeenCalled();
+        });
+    });
+});
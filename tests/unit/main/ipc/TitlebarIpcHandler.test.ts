@@ -0,0 +1,169 @@
+/**
+ * Unit tests for TitlebarIpcHandler.
+ */
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { TitlebarIpcHandler } from '../../../../src/main/managers/ipc/TitlebarIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+import { clearTitlebarWidgets, setTitlebarWidget } from '../../../../src/main/utils/titlebarWidgets';
+
+// Mock Electron
+const { mockIpcMain, mockBrowserWindow } = vi.hoisted(() => {
+    const mockIpcMain = {
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+    };
+
+    const mockBrowserWindow = { getAllWindows: vi.fn().mockReturnValue([]) };
+
+    return { mockIpcMain, mockBrowserWindow };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    BrowserWindow: mockBrowserWindow,
+}));
+
+describe('TitlebarIpcHandler', () => {
+    let handler: TitlebarIpcHandler;
+    let mockStore: ReturnType<typeof createMockStore>;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    const offline = { id: 'offline', label: 'Offline', tone: 'warning' as const, order: 10 };
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._handlers.clear();
+        clearTitlebarWidgets();
+
+        mockStore = createMockStore({ titlebarHeight: 40, titlebarEffect: 'mica' });
+        mockWindowManager = createMockWindowManager();
+        handler = new TitlebarIpcHandler({
+            store: mockStore as unknown as IpcHandlerDependencies['store'],
+            logger: createMockLogger() as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+        });
+        handler.register();
+    });
+
+    afterEach(() => {
+        handler.unregister();
+    });
+
+    it('returns the current widgets', () => {
+        setTitlebarWidget(offline);
+
+        expect(mockIpcMain._handlers.get(IPC_CHANNELS.TITLEBAR_GET_STATE)!()).toEqual({ widgets: [offline] });
+    });
+
+    it('broadcasts widget changes to all windows', () => {
+        const win = { isDestroyed: vi.fn().mockReturnValue(false), webContents: { send: vi.fn() } };
+        mockBrowserWindow.getAllWindows.mockReturnValue([win]);
+
+        setTitlebarWidget(offline);
+
+        expect(win.webContents.send).toHaveBeenCalledWith(IPC_CHANNELS.TITLEBAR_STATE_CHANGED, { widgets: [offline] });
+    });
+
+    it('stops broadcasting after unregister', () => {
+        const win = { isDestroyed: vi.fn().mockReturnValue(false), webContents: { send: vi.fn() } };
+        mockBrowserWindow.getAllWindows.mockReturnValue([win]);
+
+        handler.unregister();
+        setTitlebarWidget(offline);
+
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.TITLEBAR_GET_STATE);
+        expect(win.webContents.send).not.toHaveBeenCalled();
+    });
+
+    describe('titlebar height', () => {
+        it('returns the current height', () => {
+            mockWindowManager.getTitlebarHeight.mockReturnValue(40);
+
+            expect(mockIpcMain._handlers.get(IPC_CHANNELS.TITLEBAR_GET_HEIGHT)!()).toBe(40);
+        });
+
+        it('sets the height and reports whether it was accepted', () => {
+            mockWindowManager.setTitlebarHeight.mockReturnValueOnce(true).mockReturnValueOnce(false);
+            const setHeight = mockIpcMain._handlers.get(IPC_CHANNELS.TITLEBAR_SET_HEIGHT)!;
+
+            expect(setHeight({}, 48)).toBe(true);
+            expect(setHeight({}, 500)).toBe(false);
+            expect(mockWindowManager.setTitlebarHeight).toHaveBeenCalledWith(48);
+            expect(mockWindowManager.setTitlebarHeight).toHaveBeenCalledWith(500);
+        });
+
+        it('saves and broadcasts height changes', () => {
+            const win = { isDestroyed: vi.fn().mockReturnValue(false), webContents: { send: vi.fn() } };
+            mockBrowserWindow.getAllWindows.mockReturnValue([win]);
+            const onChanged = mockWindowManager.on.mock.calls.find(
+                ([event]) => event === 'titlebar-height-changed'
+            )![1];
+
+            onChanged(48);
+
+            expect(mockStore.set).toHaveBeenCalledWith('titlebarHeight', 48);
+            expect(win.webContents.send).toHaveBeenCalledWith(IPC_CHANNELS.TITLEBAR_HEIGHT_CHANGED, { height: 48 });
+        });
+
+        it('applies the saved height on initialize', () => {
+            handler.initialize();
+
+            expect(mockWindowManager.setTitlebarHeight).toHaveBeenCalledWith(40);
+        });
+
+        it('stops listening for height changes after unregister', () => {
+            handler.unregister();
+
+            expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.TITLEBAR_SET_HEIGHT);
+            expect(mockWindowManager.removeListener).toHaveBeenCalledWith(
+                'titlebar-height-changed',
+                expect.any(Function)
+            );
+        });
+    });
+
+    describe('titlebar effect', () => {
+        it('returns the effect in use and the supported effects', () => {
+            mockWindowManager.getTitlebarEffect.mockReturnValue('mica');
+            mockWindowManager.getSupportedTitlebarEffects.mockReturnValue(['none', 'mica', 'acrylic']);
+
+            expect(mockIpcMain._handlers.get(IPC_CHANNELS.TITLEBAR_GET_EFFECT)!()).toEqual({
+                effect: 'mica',
+                supported: ['none', 'mica', 'acrylic'],
+            });
+        });
+
+        it('sets the effect and reports whether it was accepted', () => {
+            mockWindowManager.setTitlebarEffect.mockReturnValueOnce(false);
+
+            expect(mockIpcMain._handlers.get(IPC_CHANNELS.TITLEBAR_SET_EFFECT)!({}, 'glass')).toBe(false);
+            expect(mockWindowManager.setTitlebarEffect).toHaveBeenCalledWith('glass');
+        });
+
+        it('saves the chosen effect and broadcasts the one in use', () => {
+            const win = { isDestroyed: vi.fn().mockReturnValue(false), webContents: { send: vi.fn() } };
+            mockBrowserWindow.getAllWindows.mockReturnValue([win]);
+            const onChanged = mockWindowManager.on.mock.calls.find(
+                ([event]) => event === 'titlebar-effect-changed'
+            )![1];
+
+            onChanged('vibrancy');
+
+            expect(mockStore.set).toHaveBeenCalledWith('titlebarEffect', 'vibrancy');
+            expect(win.webContents.send).toHaveBeenCalledWith(IPC_CHANNELS.TITLEBAR_EFFECT_CHANGED, {
+                effect: 'none',
+                supported: ['none'],
+            });
+        });
+
+        it('applies the saved effect on initialize', () => {
+            handler.initialize();
+
+            expect(mockWindowManager.setTitlebarEffect).toHaveBeenCalledWith('mica');
+        });
+    });
+});
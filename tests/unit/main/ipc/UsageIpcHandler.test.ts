@@ -0,0 +1,304 @@
+/**
+ * Unit tests for UsageIpcHandler.
+ *
+ * Tests usage settings persistence, starting/stopping tracking, the report channel, daily limits and the
+ * Gemini quota estimate.
+ */
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { UsageIpcHandler } from '../../../../src/main/managers/ipc/UsageIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import type UsageManager from '../../../../src/main/managers/usageManager';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+import { clearTitlebarWidgets, getTitlebarState } from '../../../../src/main/utils/titlebarWidgets';
+
+// Mock Electron
+const { mockIpcMain, mockBrowserWindow, mockLoadUsagePolicy } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    const mockBrowserWindow = { getAllWindows: vi.fn().mockReturnValue([]) };
+
+    return { mockIpcMain, mockBrowserWindow, mockLoadUsagePolicy: vi.fn().mockReturnValue(null) };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    BrowserWindow: mockBrowserWindow,
+}));
+
+vi.mock('../../../../src/main/managers/usageManager', () => ({
+    default: vi.fn(),
+    loadUsagePolicy: mockLoadUsagePolicy,
+}));
+
+describe('UsageIpcHandler', () => {
+    let mockLogger: ReturnType<typeof createMockLogger>;
+    let mockStore: ReturnType<typeof createMockStore>;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mockUsageManager: {
+        start: ReturnType<typeof vi.fn>;
+        stop: ReturnType<typeof vi.fn>;
+        getReport: ReturnType<typeof vi.fn>;
+        on: ReturnType<typeof vi.fn>;
+        removeListener: ReturnType<typeof vi.fn>;
+        setLimits: ReturnType<typeof vi.fn>;
+        hasLimits: ReturnType<typeof vi.fn>;
+        getLimitStatus: ReturnType<typeof vi.fn>;
+        recordPrompt: ReturnType<typeof vi.fn>;
+    };
+
+    const createHandler = (defaults: Record<string, unknown>) => {
+        mockStore = createMockStore(defaults);
+        const handler = new UsageIpcHandler(
+            {
+                store: mockStore as unknown as IpcHandlerDependencies['store'],
+                logger: mockLogger as unknown as IpcHandlerDependencies['logger'],
+                windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+            },
+            mockUsageManager as unknown as UsageManager
+        );
+        handler.register();
+        return handler;
+    };
+
+    const setSettings = (settings: unknown) =>
+        mockIpcMain._listeners.get(IPC_CHANNELS.USAGE_SET_SETTINGS)!({}, settings);
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+        clearTitlebarWidgets();
+        mockWindowManager = createMockWindowManager();
+
+        mockLogger = createMockLogger();
+        mockUsageManager = {
+            start: vi.fn(),
+            stop: vi.fn(),
+            getReport: vi.fn().mockReturnValue({ todaySeconds: 60, weekSeconds: 120, todayPrompts: 0, days: [] }),
+            on: vi.fn(),
+            removeListener: vi.fn(),
+            setLimits: vi.fn(),
+            hasLimits: vi.fn().mockReturnValue(false),
+            getLimitStatus: vi.fn().mockReturnValue({ enforcedByPolicy: false }),
+            recordPrompt: vi.fn(),
+        };
+        mockLoadUsagePolicy.mockReturnValue(null);
+    });
+
+    it('does not track until enabled', () => {
+        createHandler({ usageTrackingEnabled: false, usageIdleDetection: true });
+        expect(mockUsageManager.start).not.toHaveBeenCalled();
+    });
+
+    it('starts tracking on register when enabled', () => {
+        createHandler({ usageTrackingEnabled: true, usageIdleDetection: false });
+        expect(mockUsageManager.start).toHaveBeenCalledWith(false);
+    });
+
+    it('persists settings and starts or stops tracking', () => {
+        createHandler({ usageTrackingEnabled: false, usageIdleDetection: true });
+
+        setSettings({ enabled: true });
+        expect(mockStore.set).toHaveBeenCalledWith('usageTrackingEnabled', true);
+        expect(mockUsageManager.start).toHaveBeenCalledWith(true);
+
+        setSettings({ enabled: false });
+        expect(mockUsageManager.stop).toHaveBeenCalled();
+    });
+
+    it('rejects non-boolean settings', () => {
+        createHandler({});
+
+        setSettings({ enabled: 'yes' });
+
+        expect(mockStore.set).not.toHaveBeenCalled();
+        expect(mockLogger.warn).toHaveBeenCalled();
+    });
+
+    it('returns settings and the usage report', () => {
+        createHandler({ usageTrackingEnabled: true, usageIdleDetection: true });
+
+        expect(mockIpcMain._handlers.get(IPC_CHANNELS.USAGE_GET_SETTINGS)!()).toEqual({
+            enabled: true,
+            idleDetection: true,
+        });
+        expect(mockIpcMain._handlers.get(IPC_CHANNELS.USAGE_GET_REPORT)!()).toEqual({
+            todaySeconds: 60,
+            weekSeconds: 120,
+            todayPrompts: 0,
+            days: [],
+        });
+    });
+
+    it('stops tracking on unregister', () => {
+        const handler = createHandler({});
+
+        handler.unregister();
+
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.USAGE_GET_REPORT);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.USAGE_SET_SETTINGS);
+        expect(mockUsageManager.stop).toHaveBeenCalled();
+    });
+
+    describe('daily limits', () => {
+        const setLimits = (limits: unknown) => mockIpcMain._listeners.get(IPC_CHANNELS.USAGE_SET_LIMITS)!({}, limits);
+
+        it('applies saved limits and tracks while a limit is set, even with tracking off', () => {
+            mockUsageManager.hasLimits.mockReturnValue(true);
+            createHandler({ usageTrackingEnabled: false, usageDailyLimitMinutes: 60, usagePromptLimit: 0 });
+
+            expect(mockUsageManager.setLimits).toHaveBeenCalledWith({ dailyMinutes: 60, dailyPrompts: 0 });
+            expect(mockUsageManager.start).toHaveBeenCalled();
+        });
+
+        it('prefers limits from the policy file', () => {
+            mockLoadUsagePolicy.mockReturnValue({ dailyMinutes: 0, dailyPrompts: 20 });
+            createHandler({ usageDailyLimitMinutes: 60 });
+
+            expect(mockUsageManager.setLimits).toHaveBeenCalledWith({ dailyMinutes: 0, dailyPrompts: 20 }, true);
+        });
+
+        it('persists valid limits', () => {
+            createHandler({});
+
+            setLimits({ dailyMinutes: 45, dailyPrompts: 10 });
+
+            expect(mockStore.set).toHaveBeenCalledWith('usageDailyLimitMinutes', 45);
+            expect(mockStore.set).toHaveBeenCalledWith('usagePromptLimit', 10);
+        });
+
+        it('rejects invalid limits', () => {
+            createHandler({});
+
+            setLimits({ dailyMinutes: -1, dailyPrompts: 10 });
+
+            expect(mockStore.set).not.toHaveBeenCalled();
+            expect(mockLogger.warn).toHaveBeenCalled();
+        });
+
+        it('ignores changes when limits are enforced by policy', () => {
+            mockUsageManager.getLimitStatus.mockReturnValue({ enforcedByPolicy: true });
+            createHandler({});
+
+            setLimits({ dailyMinutes: 0, dailyPrompts: 0 });
+
+            expect(mockStore.set).not.toHaveBeenCalled();
+            expect(mockLogger.warn).toHaveBeenCalled();
+        });
+
+        it('broadcasts limit changes to all windows', () => {
+            const win = { isDestroyed: () => false, webContents: { send: vi.fn() } };
+            mockBrowserWindow.getAllWindows.mockReturnValue([win]);
+            createHandler({});
+
+            const listener = mockUsageManager.on.mock.calls.find(([event]) => event === 'limit-changed')![1];
+            listener({ blocked: true, reason: 'time' });
+
+            expect(win.webContents.send).toHaveBeenCalledWith(IPC_CHANNELS.USAGE_LIMIT_CHANGED, {
+                blocked: true,
+                reason: 'time',
+            });
+        });
+
+        it('only counts prompts while a limit is set', () => {
+            const handler = createHandler({});
+
+            handler.recordPrompt();
+            expect(mockUsageManager.recordPrompt).not.toHaveBeenCalled();
+
+            mockUsageManager.hasLimits.mockReturnValue(true);
+            handler.recordPrompt();
+            expect(mockUsageManager.recordPrompt).toHaveBeenCalledTimes(1);
+        });
+    });
+
+    describe('Gemini quota estimate', () => {
+        const setQuota = (settings: unknown) => mockIpcMain._listeners.get(IPC_CHANNELS.USAGE_SET_QUOTA)!({}, settings);
+        const getQuota = () => mockIpcMain._handlers.get(IPC_CHANNELS.USAGE_GET_QUOTA)!();
+        const usePrompts = (todayPrompts: number) =>
+            mockUsageManager.getReport.mockReturnValue({ todaySeconds: 0, weekSeconds: 0, todayPrompts, days: [] });
+
+        beforeEach(() => {
+            vi.useFakeTimers();
+        });
+
+        afterEach(() => {
+            vi.useRealTimers();
+        });
+
+        it('estimates the prompts left from the saved plan', () => {
+            usePrompts(30);
+            createHandler({ geminiQuotaPlan: 'pro', geminiQuotaCustomPrompts: 0 });
+
+            expect(getQuota()).toMatchObject({ plan: 'pro', used: 30, remaining: 70, level: 'ok' });
+            expect(getTitlebarState().widgets).toEqual([
+                expect.objectContaining({ id: 'gemini-quota', label: '~70 prompts left', tone: 'info' }),
+            ]);
+        });
+
+        it('shows nothing while off', () => {
+            createHandler({ geminiQuotaPlan: 'off' });
+
+            expect(getQuota()).toMatchObject({ plan: 'off', dailyPrompts: 0 });
+            expect(getTitlebarState().widgets).toEqual([]);
+        });
+
+        it('persists valid settings and rejects invalid ones', () => {
+            createHandler({ geminiQuotaPlan: 'off' });
+
+            setQuota({ plan: 'advanced', customDailyPrompts: 0 });
+            setQuota({ plan: 'custom', customDailyPrompts: -5 });
+            expect(mockStore.set).not.toHaveBeenCalled();
+            expect(mockLogger.warn).toHaveBeenCalledTimes(2);
+
+            setQuota({ plan: 'custom', customDailyPrompts: 40 });
+            expect(mockStore.set).toHaveBeenCalledWith('geminiQuotaPlan', 'custom');
+            expect(mockStore.set).toHaveBeenCalledWith('geminiQuotaCustomPrompts', 40);
+            expect(getTitlebarState().widgets[0]?.label).toBe('~40 prompts left');
+        });
+
+        it('counts prompts while a plan is chosen, even without limits', () => {
+            const handler = createHandler({ geminiQuotaPlan: 'free' });
+
+            handler.recordPrompt();
+
+            expect(mockUsageManager.recordPrompt).toHaveBeenCalledTimes(1);
+        });
+
+        it('warns once as the quota runs low and again when it is used up', () => {
+            const mainWindow = { isDestroyed: () => false, webContents: { send: vi.fn() } };
+            mockWindowManager.getMainWindow.mockReturnValue(mainWindow);
+            usePrompts(7);
+            const handler = createHandler({ geminiQuotaPlan: 'custom', geminiQuotaCustomPrompts: 10 });
+            const toasts = () =>
+                mainWindow.webContents.send.mock.calls.filter(([channel]) => channel === IPC_CHANNELS.TOAST_SHOW);
+
+            usePrompts(8);
+            handler.recordPrompt();
+            usePrompts(9);
+            handler.recordPrompt();
+            expect(toasts()).toHaveLength(1);
+            expect(getTitlebarState().widgets[0]?.tone).toBe('warning');
+
+            usePrompts(10);
+            handler.recordPrompt();
+            expect(toasts()).toHaveLength(2);
+            expect(getTitlebarState().widgets[0]).toMatchObject({ label: '~0 prompts left', tone: 'error' });
+        });
+    });
+});
@@ -0,0 +1,195 @@
+/**
+ * Unit tests for UserscriptIpcHandler.
+ *
+ * Tests listing and toggling userscripts and running enabled scripts
+ * when the Gemini frame loads or navigates.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { UserscriptIpcHandler } from '../../../../src/main/managers/ipc/UserscriptIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+
+// Mock Electron
+const { mockIpcMain, mockShell, mocks } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    return {
+        mockIpcMain,
+        mockShell: { openPath: vi.fn() },
+        mocks: { safeMode: false, mkdir: vi.fn() },
+    };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    shell: mockShell,
+    app: { getPath: vi.fn().mockReturnValue('/userData') },
+}));
+
+vi.mock('fs/promises', () => ({ mkdir: mocks.mkdir, default: { mkdir: mocks.mkdir } }));
+
+vi.mock('../../../../src/main/utils/safeMode', () => ({ isSafeMode: () => mocks.safeMode }));
+
+vi.mock('../../../../src/main/utils/userscripts', async (importOriginal) => ({
+    ...(await importOriginal<typeof import('../../../../src/main/utils/userscripts')>()),
+    getUserscriptsDir: () => '/userData/scripts',
+    loadUserscripts: vi.fn().mockResolvedValue([
+        {
+            id: 'app.js',
+            name: 'App only',
+            description: '',
+            matches: ['https://gemini.google.com/app*'],
+            source: 'window.appOnly = true;',
+        },
+        {
+            id: 'gems.js',
+            name: 'Gems only',
+            description: 'Runs on Gems',
+            matches: ['https://gemini.google.com/gem/*'],
+            source: 'window.gemsOnly = true;',
+        },
+    ]),
+}));
+
+describe('UserscriptIpcHandler', () => {
+    let handler: UserscriptIpcHandler;
+    let mockStore: ReturnType<typeof createMockStore>;
+    let mockWindowManager: ReturnType<typeof createMockWindowManager>;
+    let mockGeminiFrame: { url: string; executeJavaScript: ReturnType<typeof vi.fn> };
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+        mocks.safeMode = false;
+        mockShell.openPath.mockResolvedValue('');
+
+        mockStore = createMockStore({ enabledUserscripts: 'app.js\ngems.js' });
+        mockWindowManager = createMockWindowManager();
+        mockGeminiFrame = {
+            url: 'https://gemini.google.com/app/abc123',
+            executeJavaScript: vi.fn().mockResolvedValue(undefined),
+        };
+        (mockWindowManager.getMainWindow as ReturnType<typeof vi.fn>).mockReturnValue({
+            isDestroyed: vi.fn().mockReturnValue(false),
+            webContents: { mainFrame: { frames: [mockGeminiFrame] } },
+        });
+
+        handler = new UserscriptIpcHandler({
+            store: mockStore as unknown as IpcHandlerDependencies['store'],
+            logger: createMockLogger() as unknown as IpcHandlerDependencies['logger'],
+            windowManager: mockWindowManager as unknown as IpcHandlerDependencies['windowManager'],
+        });
+        handler.register();
+    });
+
+    it('lists scripts with their enabled state and without their source', async () => {
+        mockStore.set('enabledUserscripts', 'gems.js');
+
+        const scripts = await mockIpcMain._handlers.get(IPC_CHANNELS.USERSCRIPTS_LIST)!();
+
+        expect(scripts).toEqual([
+            expect.objectContaining({ id: 'app.js', enabled: false }),
+            expect.objectContaining({ id: 'gems.js', name: 'Gems only', enabled: true }),
+        ]);
+        expect((scripts as object[])[0]).not.toHaveProperty('source');
+    });
+
+    it('stores enabled scripts one per line', () => {
+        mockStore.set('enabledUserscripts', '');
+        const listener = mockIpcMain._listeners.get(IPC_CHANNELS.USERSCRIPTS_SET_ENABLED)!;
+
+        listener({}, 'app.js', true);
+        listener({}, 'gems.js', true);
+        listener({}, 'app.js', false);
+
+        expect(mockStore.get('enabledUserscripts')).toBe('gems.js');
+    });
+
+    it('ignores toggles for invalid script IDs', () => {
+        mockIpcMain._listeners.get(IPC_CHANNELS.USERSCRIPTS_SET_ENABLED)!({}, '../evil.js', true);
+
+        expect(mockStore.set).not.toHaveBeenCalled();
+    });
+
+    it('runs enabled scripts that match the Gemini frame URL', async () => {
+        await handler.runUserscripts();
+
+        expect(mockGeminiFrame.executeJavaScript).toHaveBeenCalledTimes(1);
+        expect(mockGeminiFrame.executeJavaScript).toHaveBeenCalledWith(
+            expect.stringContaining('window.appOnly = true;')
+        );
+    });
+
+    it('does not run disabled scripts', async () => {
+        mockStore.set('enabledUserscripts', 'gems.js');
+
+        await handler.runUserscripts();
+
+        expect(mockGeminiFrame.executeJavaScript).not.toHaveBeenCalled();
+    });
+
+    it('does not run scripts in safe mode', async () => {
+        mocks.safeMode = true;
+
+        await handler.runUserscripts();
+
+        expect(mockGeminiFrame.executeJavaScript).not.toHaveBeenCalled();
+    });
+
+    it('runs scripts when the Gemini frame loads', async () => {
+        const call = (mockWindowManager.on as ReturnType<typeof vi.fn>).mock.calls.find(
+            ([event]) => event === 'gemini-frame-loaded'
+        );
+        (call![1] as () => void)();
+
+        await vi.waitFor(() => expect(mockGeminiFrame.executeJavaScript).toHaveBeenCalled());
+    });
+
+    it('matches scripts again when Gemini navigates within the page', async () => {
+        const call = (mockWindowManager.on as ReturnType<typeof vi.fn>).mock.calls.find(
+            ([event]) => event === 'gemini-location-changed'
+        );
+        mockGeminiFrame.url = 'https://gemini.google.com/gem/coder';
+        (call![1] as () => void)();
+
+        await vi.waitFor(() =>
+            expect(mockGeminiFrame.executeJavaScript).toHaveBeenCalledWith(
+                expect.stringContaining('window.gemsOnly = true;')
+            )
+        );
+        expect(mockGeminiFrame.executeJavaScript).toHaveBeenCalledTimes(1);
+    });
+
+    it('creates and opens the scripts folder', async () => {
+        mockIpcMain._listeners.get(IPC_CHANNELS.USERSCRIPTS_OPEN_FOLDER)!();
+
+        await vi.waitFor(() => expect(mockShell.openPath).toHaveBeenCalledWith('/userData/scripts'));
+        expect(mocks.mkdir).toHaveBeenCalledWith('/userData/scripts', { recursive: true });
+    });
+
+    it('removes its listeners on unregister', () => {
+        handler.unregister();
+
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.USERSCRIPTS_LIST);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.USERSCRIPTS_SET_ENABLED);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.USERSCRIPTS_OPEN_FOLDER);
+        expect(mockWindowManager.removeListener).toHaveBeenCalledWith('gemini-frame-loaded', expect.any(Function));
+        expect(mockWindowManager.removeListener).toHaveBeenCalledWith('gemini-location-changed', expect.any(Function));
+    });
+});
@@ -0,0 +1,106 @@
+/**
+ * Unit tests for WhatsNewIpcHandler.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { WhatsNewIpcHandler } from '../../../../src/main/managers/ipc/WhatsNewIpcHandler';
+import type { IpcHandlerDependencies } from '../../../../src/main/managers/ipc/types';
+import { createMockLogger, createMockWindowManager, createMockStore } from '../../../helpers/mocks';
+import { IPC_CHANNELS } from '../../../../src/shared/constants/ipc-channels';
+import { CHANGELOG } from '../../../../src/main/utils/whatsNew';
+import type { WhatsNew } from '../../../../src/shared/types/whats-new';
+
+// Mock Electron
+const { mockIpcMain, mockApp } = vi.hoisted(() => {
+    const mockIpcMain = {
+        on: vi.fn((channel: string, listener: (...args: unknown[]) => void) => {
+            mockIpcMain._listeners.set(channel, listener);
+        }),
+        handle: vi.fn((channel: string, handler: (...args: unknown[]) => unknown) => {
+            mockIpcMain._handlers.set(channel, handler);
+        }),
+        removeHandler: vi.fn(),
+        removeAllListeners: vi.fn(),
+        _listeners: new Map<string, (...args: unknown[]) => void>(),
+        _handlers: new Map<string, (...args: unknown[]) => unknown>(),
+        _reset: () => {
+            mockIpcMain._listeners.clear();
+            mockIpcMain._handlers.clear();
+        },
+    };
+
+    return { mockIpcMain, mockApp: { getVersion: vi.fn() } };
+});
+
+vi.mock('electron', () => ({
+    ipcMain: mockIpcMain,
+    app: mockApp,
+}));
+
+describe('WhatsNewIpcHandler', () => {
+    const latest = CHANGELOG[0]!.version;
+    let mockStore: ReturnType<typeof createMockStore>;
+
+    const createHandler = (prefs: Record<string, unknown>) => {
+        mockStore = createMockStore(prefs);
+        const handler = new WhatsNewIpcHandler({
+            store: mockStore as unknown as IpcHandlerDependencies['store'],
+            logger: createMockLogger() as unknown as IpcHandlerDependencies['logger'],
+            windowManager: createMockWindowManager() as unknown as IpcHandlerDependencies['windowManager'],
+        });
+        handler.register();
+        return handler;
+    };
+
+    const getWhatsNew = () => mockIpcMain._handlers.get(IPC_CHANNELS.WHATS_NEW_GET)!() as WhatsNew;
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockIpcMain._reset();
+        mockApp.getVersion.mockReturnValue(latest);
+    });
+
+    it('returns notes since the acknowledged version', () => {
+        createHandler({ whatsNewLastVersion: '0.0.1' });
+
+        const result = getWhatsNew();
+
+        expect(result.previousVersion).toBe('0.0.1');
+        expect(result.entries[0]!.version).toBe(latest);
+    });
+
+    it('returns nothing once the running version is acknowledged', () => {
+        createHandler({ whatsNewLastVersion: '0.0.1' });
+
+        mockIpcMain._listeners.get(IPC_CHANNELS.WHATS_NEW_ACKNOWLEDGE)!({});
+
+        expect(mockStore.set).toHaveBeenCalledWith('whatsNewLastVersion', latest);
+        expect(getWhatsNew().entries).toEqual([]);
+    });
+
+    it('starts a fresh install at the running version', () => {
+        createHandler({ whatsNewLastVersion: '' }).initialize();
+
+        expect(mockStore.set).toHaveBeenCalledWith('whatsNewLastVersion', latest);
+        expect(getWhatsNew().entries).toEqual([]);
+    });
+
+    it('shows every shipped entry to installs that predate release notes', () => {
+        createHandler({ whatsNewLastVersion: '', windowWidth: 1200 }).initialize();
+
+        expect(mockStore.set).toHaveBeenCalledWith('whatsNewLastVersion', '0.0.0');
+        expect(getWhatsNew().entries.length).toBeGreaterThan(0);
+    });
+
+    it('keeps an acknowledged version on initialize', () => {
+        createHandler({ whatsNewLastVersion: '0.5.1' }).initialize();
+
+        expect(mockStore.set).not.toHaveBeenCalled();
+    });
+
+    it('removes its listeners on unregister', () => {
+        createHandler({}).unregister();
+
+        expect(mockIpcMain.removeHandler).toHaveBeenCalledWith(IPC_CHANNELS.WHATS_NEW_GET);
+        expect(mockIpcMain.removeAllListeners).toHaveBeenCalledWith(IPC_CHANNELS.WHATS_NEW_ACKNOWLEDGE);
+    });
+});
@@ -277,6 +277,95 @@ describe('WindowIpcHandler', () => {
         });
     });
 
+    describe('window-new handler', () => {
+        beforeEach(() => {
+            handler.register();
+        });
+
+        it('opens another main window', () => {
+            mockIpcMain._listeners.get('window-new')!({ sender: {} });
+
+            expect(mockWindowManager.createAdditionalMainWindow).toHaveBeenCalled();
+        });
+
+        it('logs error when the window cannot be created', () => {
+            mockWindowManager.createAdditionalMainWindow.mockImplementation(() => {
+                throw new Error('Create failed');
+            });
+
+            mockIpcMain._listeners.get('window-new')!({ sender: {} });
+
+            expect(mockLogger.error).toHaveBeenCalledWith('Error opening new window:', expect.any(Error));
+        });
+    });
+
+    describe('fullscreen handlers', () => {
+        beforeEach(() => {
+            handler.register();
+        });
+
+        it('toggles fullscreen on the calling window', () => {
+            const win = { isDestroyed: () => false };
+            mockBrowserWindow.fromWebContents.mockReturnValue(win);
+
+            mockIpcMain._listeners.get('window-toggle-fullscreen')!({ sender: {} });
+
+            expect(mockWindowManager.toggleFullScreen).toHaveBeenCalledWith(win);
+        });
+
+        it('returns whether the calling window is fullscreen', async () => {
+            mockBrowserWindow.fromWebContents.mockReturnValue({ isDestroyed: () => false, isFullScreen: () => true });
+
+            const result = await mockIpcMain._handlers.get('window-is-fullscreen')!({ sender: {} });
+
+            expect(result).toBe(true);
+        });
+
+        it('sends fullscreen changes only to the affected window', () => {
+            const win = { isDestroyed: () => false, webContents: { send: vi.fn() } };
+            const listener = mockWindowManager.on.mock.calls.find(([event]) => event === 'fullscreen-changed')![1];
+
+            listener(true, win);
+
+            expect(win.webContents.send).toHaveBeenCalledWith('window-fullscreen-changed', { enabled: true });
+        });
+    });
+
+    describe('display handlers', () => {
+        beforeEach(() => {
+            handler.register();
+        });
+
+        it('lists displays for the calling window', async () => {
+            const win = { isDestroyed: () => false };
+            const displays = [{ index: 0, current: true }];
+            mockBrowserWindow.fromWebContents.mockReturnValue(win);
+            mockWindowManager.getDisplays.mockReturnValue(displays);
+
+            const result = await mockIpcMain._handlers.get('window-get-displays')!({ sender: {} });
+
+            expect(result).toBe(displays);
+            expect(mockWindowManager.getDisplays).toHaveBeenCalledWith(win);
+        });
+
+        it('moves the calling window to a display', () => {
+            const win = { isDestroyed: () => false };
+            mockBrowserWindow.fromWebContents.mockReturnValue(win);
+
+            mockIpcMain._listeners.get('window-move-to-display')!({ sender: {} }, 1);
+
+            expect(mockWindowManager.moveToDisplay).toHaveBeenCalledWith(1, win);
+        });
+
+        it('ignores a display index that is not an integer', () => {
+            mockBrowserWindow.fromWebContents.mockReturnValue({ isDestroyed: () => false });
+
+            mockIpcMain._listeners.get('window-move-to-display')!({ sender: {} }, '1');
+
+            expect(mockWindowManager.moveToDisplay).not.toHaveBeenCalled();
+        });
+    });
+
     describe('window-is-maximized handler', () => {
         beforeEach(() => {
             handler.register();
@@ -346,4 +435,45 @@ describe('WindowIpcHandler', () => {
             expect(mockLogger.error).toHaveBeenCalledWith('Error checking maximized state:', expect.any(Error));
         });
     });
+
+    describe('window state', () => {
+        it('hands the saved state to windowManager on initialize', () => {
+            mockDeps.store = createMockStore({
+                windowX: -1200,
+                windowY: 40,
+                windowWidth: 1000,
+                windowHeight: 700,
+                windowFullScreen: true,
+            });
+            handler = new WindowIpcHandler(mockDeps);
+
+            handler.initialize();
+
+            expect(mockWindowManager.setSavedWindowState).toHaveBeenCalledWith({
+                bounds: { x: -1200, y: 40, width: 1000, height: 700 },
+                isMaximized: false,
+                isFullScreen: true,
+            });
+        });
+
+        it('keeps the default bounds when nothing was saved', () => {
+            handler.initialize();
+
+            expect(mockWindowManager.setSavedWindowState).not.toHaveBeenCalled();
+        });
+
+        it('saves window-state-changed events', () => {
+            const store = createMockStore({});
+            handler = new WindowIpcHandler({ ...mockDeps, store });
+            handler.register();
+            const listener = mockWindowManager.on.mock.calls.find(([event]) => event === 'window-state-changed')![1];
+
+            listener({ bounds: { x: 10, y: 20, width: 800, height: 600 }, isMaximized: true, isFullScreen: false });
+
+            expect(store.set).toHaveBeenCalledWith('windowX', 10);
+            expect(store.set).toHaveBeenCalledWith('windowHeight', 600);
+            expect(store.set).toHaveBeenCalledWith('windowMaximized', true);
+            expect(store.set).toHaveBeenCalledWith('windowFullScreen', false);
+        });
+    });
 });
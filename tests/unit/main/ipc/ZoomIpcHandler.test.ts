@@ -228,6 +228,42 @@ describe('ZoomIpcHandler', () => {
         });
     });
 
+    describe('zoom:set-level handler', () => {
+        beforeEach(() => {
+            handler.register();
+        });
+
+        it('calls windowManager.setZoomLevel() and returns the applied level', async () => {
+            mockWindowManager.getZoomLevel.mockReturnValue(110);
+
+            const result = await mockIpcMain._handlers.get(IPC_CHANNELS.ZOOM_SET_LEVEL)!({}, 112);
+
+            expect(mockWindowManager.setZoomLevel).toHaveBeenCalledWith(112);
+            expect(result).toBe(110);
+        });
+
+        it('rejects non-numeric levels', async () => {
+            const result = await mockIpcMain._handlers.get(IPC_CHANNELS.ZOOM_SET_LEVEL)!({}, 'big');
+
+            expect(mockWindowManager.setZoomLevel).not.toHaveBeenCalled();
+            expect(mockLogger.warn).toHaveBeenCalled();
+            expect(result).toBe(100);
+        });
+    });
+
+    describe('zoom:reset handler', () => {
+        beforeEach(() => {
+            handler.register();
+        });
+
+        it('calls windowManager.resetZoom() and returns the new level', async () => {
+            const result = await mockIpcMain._handlers.get(IPC_CHANNELS.ZOOM_RESET)!();
+
+            expect(mockWindowManager.resetZoom).toHaveBeenCalled();
+            expect(result).toBe(100);
+        });
+    });
+
     describe('zoom-level-changed event (2.2.12, 2.2.13)', () => {
         beforeEach(() => {
             handler.register();
@@ -0,0 +1,129 @@
+/**
+ * Unit tests for issue tracker helpers.
+ */
+import { describe, it, expect, vi } from 'vitest';
+import { validateIssueTrackerSettings, draftIssue, toJiraWiki, createIssue } from '../../../src/main/utils/issues';
+import { DEFAULT_ISSUE_TRACKER_SETTINGS } from '../../../src/shared/types/issues';
+
+const github = {
+    ...DEFAULT_ISSUE_TRACKER_SETTINGS,
+    githubRepository: 'octo/app',
+    githubToken: 'ghp_token',
+};
+const jira = {
+    ...DEFAULT_ISSUE_TRACKER_SETTINGS,
+    tracker: 'jira' as const,
+    jiraBaseUrl: 'https://example.atlassian.net/',
+    jiraEmail: 'me@example.com',
+    jiraApiToken: 'secret',
+    jiraProjectKey: 'APP',
+};
+
+const mockSession = (body: unknown, ok = true) => {
+    const fetch = vi.fn().mockResolvedValue({
+        ok,
+        status: ok ? 201 : 401,
+        statusText: ok ? 'Created' : 'Unauthorized',
+        json: vi.fn().mockResolvedValue(body),
+    });
+    return { session: { fetch } as any, fetch };
+};
+
+describe('issues', () => {
+    describe('validateIssueTrackerSettings', () => {
+        it('accepts partial settings unless a complete configuration is required', () => {
+            expect(validateIssueTrackerSettings(DEFAULT_ISSUE_TRACKER_SETTINGS)).toBeNull();
+            expect(validateIssueTrackerSettings(DEFAULT_ISSUE_TRACKER_SETTINGS, true)).toBe(
+                'GitHub repository is not set'
+            );
+            expect(validateIssueTrackerSettings({ ...github, githubToken: '' }, true)).toBe('GitHub token is not set');
+            expect(validateIssueTrackerSettings(github, true)).toBeNull();
+            expect(validateIssueTrackerSettings({ ...jira, jiraApiToken: '' }, true)).toBe('Jira API token is not set');
+            expect(validateIssueTrackerSettings(jira, true)).toBeNull();
+        });
+
+        it('rejects malformed values', () => {
+            expect(validateIssueTrackerSettings({ ...github, githubRepository: 'app' })).toMatch(/owner\/name/);
+            expect(validateIssueTrackerSettings({ ...jira, jiraBaseUrl: 'http://jira.local' })).toBe(
+                'Jira URL must use https'
+            );
+            expect(validateIssueTrackerSettings({ ...jira, jiraProjectKey: '1APP' })).toMatch(/project key/);
+            expect(validateIssueTrackerSettings({ ...github, tracker: 'trello' as never })).toMatch(/Unknown/);
+        });
+    });
+
+    describe('draftIssue', () => {
+        it('uses the first heading as the title', () => {
+            expect(draftIssue('Intro text\n\n## Fix the **login** `retry`\n\nDetails')).toEqual({
+                title: 'Fix the login retry',
+                body: 'Intro text\n\n## Fix the **login** `retry`\n\nDetails',
+            });
+        });
+
+        it('falls back to the first line outside code blocks', () => {
+            expect(draftIssue('```\ncode\n```\n- Rename [max_tokens](https://x.dev)').title).toBe('Rename max_tokens');
+        });
+
+        it('shortens long titles', () => {
+            const { title } = draftIssue('word '.repeat(40));
+
+            expect(title).toHaveLength(80);
+            expect(title.endsWith('…')).toBe(true);
+        });
+    });
+
+    describe('toJiraWiki', () => {
+        it('converts Markdown but keeps code blocks verbatim', () => {
+            expect(
+                toJiraWiki('## Steps\n**Note** `npm i` [docs](https://x.dev)\n- one\n    - two\n```ts\n**raw**\n```')
+            ).toBe('h2. Steps\n*Note* {{npm i}} [docs|https://x.dev]\n* one\n** two\n{code:ts}\n**raw**\n{code}');
+        });
+    });
+
+    describe('createIssue', () => {
+        it('creates a GitHub issue and returns its URL', async () => {
+            const { session, fetch } = mockSession({ html_url: 'https://github.com/octo/app/issues/7' });
+
+            const url = await createIssue(session, github, { title: 'Bug', body: 'Body' });
+
+            expect(url).toBe('https://github.com/octo/app/issues/7');
+            const [target, init] = fetch.mock.calls[0];
+            expect(target).toBe('https://api.github.com/repos/octo/app/issues');
+            expect(init.headers.Authorization).toBe('Bearer ghp_token');
+            expect(JSON.parse(init.body)).toEqual({ title: 'Bug', body: 'Body' });
+        });
+
+        it('creates a Jira issue with a wiki markup description', async () => {
+            const { session, fetch } = mockSession({ key: 'APP-12' });
+
+            const url = await createIssue(session, jira, { title: 'Bug', body: '**Body**' });
+
+            expect(url).toBe('https://example.atlassian.net/browse/APP-12');
+            const [target, init] = fetch.mock.calls[0];
+            expect(target).toBe('https://example.atlassian.net/rest/api/2/issue');
+            expect(init.headers.Authorization).toBe(`Basic ${Buffer.from('me@example.com:secret').toString('base64')}`);
+            expect(JSON.parse(init.body).fields).toEqual({
+                project: { key: 'APP' },
+                summary: 'Bug',
+                description: '*Body*',
+                issuetype: { name: 'Task' },
+            });
+        });
+
+        it('uses the Jira token as a bearer token without an email', async () => {
+            const { session, fetch } = mockSession({ key: 'APP-1' });
+
+            await createIssue(session, { ...jira, jiraEmail: '' }, { title: 'Bug', body: '' });
+
+            expect(fetch.mock.calls[0][1].headers.Authorization).toBe('Bearer secret');
+        });
+
+        it('throws on HTTP errors', async () => {
+            const { session } = mockSession({}, false);
+
+            await expect(createIssue(session, github, { title: 'Bug', body: '' })).rejects.toThrow(
+                'GitHub request failed: 401 Unauthorized'
+            );
+        });
+    });
+});
@@ -0,0 +1,79 @@
+/**
+ * Unit tests for the Gemini language override.
+ */
+import { describe, it, expect, vi } from 'vitest';
+import {
+    buildAcceptLanguage,
+    resolveSpellCheckerLanguage,
+    applyGeminiLanguage,
+    applySpellCheck,
+    parseSpellCheckLanguages,
+} from '../../../src/main/utils/language';
+import { isLanguageTag } from '../../../src/shared/types/language';
+
+describe('language', () => {
+    it('validates language tags', () => {
+        expect(isLanguageTag('')).toBe(true);
+        expect(isLanguageTag('de')).toBe(true);
+        expect(isLanguageTag('pt-BR')).toBe(true);
+        expect(isLanguageTag('zh-Hant-TW')).toBe(true);
+        expect(isLanguageTag('de\r\nX-Injected: 1')).toBe(false);
+        expect(isLanguageTag('en-' + 'x'.repeat(40))).toBe(false);
+        expect(isLanguageTag(42)).toBe(false);
+    });
+
+    it('falls back to the bare language in Accept-Language', () => {
+        expect(buildAcceptLanguage('de')).toBe('de');
+        expect(buildAcceptLanguage('pt-BR')).toBe('pt-BR,pt;q=0.9');
+    });
+
+    it('picks the closest spell checker dictionary', () => {
+        expect(resolveSpellCheckerLanguage('en-GB', ['en-US', 'en-GB'])).toBe('en-GB');
+        expect(resolveSpellCheckerLanguage('de-AT', ['en-US', 'de'])).toBe('de');
+        expect(resolveSpellCheckerLanguage('ja', ['en-US'])).toBeNull();
+    });
+
+    it('parses the saved spell check languages', () => {
+        expect(parseSpellCheckLanguages('')).toEqual([]);
+        expect(parseSpellCheckLanguages('en-US\n de \n\n')).toEqual(['en-US', 'de']);
+    });
+
+    it('overrides Accept-Language for Google and restores the default for the system locale', () => {
+        const session = { webRequest: { onBeforeSendHeaders: vi.fn() } };
+
+        expect(applyGeminiLanguage(session as unknown as Electron.Session, 'de-DE')).toBe('de-DE,de;q=0.9');
+
+        const [filter, listener] = session.webRequest.onBeforeSendHeaders.mock.calls[0];
+        expect(filter).toEqual({ urls: ['*://*.google.com/*'] });
+        const callback = vi.fn();
+        listener({ requestHeaders: { 'Accept-Language': 'en-US', Cookie: 'a=b' } }, callback);
+        expect(callback).toHaveBeenCalledWith({
+            requestHeaders: { 'Accept-Language': 'de-DE,de;q=0.9', Cookie: 'a=b' },
+        });
+
+        expect(applyGeminiLanguage(session as unknown as Electron.Session, '')).toBeNull();
+        expect(session.webRequest.onBeforeSendHeaders).toHaveBeenLastCalledWith(null);
+    });
+
+    it('checks the chosen languages, then the Gemini language, then the system default', () => {
+        const session = {
+            getSpellCheckerLanguages: vi.fn().mockReturnValue(['en-US']),
+            setSpellCheckerLanguages: vi.fn(),
+            setSpellCheckerEnabled: vi.fn(),
+            availableSpellCheckerLanguages: ['en-US', 'en-GB', 'de', 'fr'],
+        };
+        const apply = (languages: string[], geminiLanguage: string, enabled = true) =>
+            applySpellCheck(session as unknown as Electron.Session, { enabled, languages }, geminiLanguage);
+
+        expect(apply(['fr', 'de-AT', 'de', 'ja'], 'en-GB')).toEqual(['fr', 'de']);
+        expect(session.setSpellCheckerLanguages).toHaveBeenLastCalledWith(['fr', 'de']);
+        expect(session.setSpellCheckerEnabled).toHaveBeenLastCalledWith(true);
+
+        expect(apply([], 'de-DE')).toEqual(['de']);
+        expect(apply([], '')).toEqual(['en-US']);
+        expect(apply(['ja'], '')).toEqual(['en-US']);
+
+        apply([], '', false);
+        expect(session.setSpellCheckerEnabled).toHaveBeenLastCalledWith(false);
+    });
+});
@@ -1,8 +1,14 @@
 /**
  * Unit tests for logger utility.
  */
-import { describe, it, expect, vi, beforeEach } from 'vitest';
-import { createLogger } from '../../../src/main/utils/logger';
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import {
+    createLogger,
+    setLogLevel,
+    getLogLevel,
+    resetLogLevel,
+    parseLogLevelSpec,
+} from '../../../src/main/utils/logger';
 
 describe('createLogger', () => {
     beforeEach(() => {
@@ -132,4 +138,51 @@ describe('createLogger', () => {
             mockConsole.mockRestore();
         });
     });
+
+    describe('log levels', () => {
+        afterEach(() => {
+            resetLogLevel();
+        });
+
+        it('parses a default level with module targets', () => {
+            expect(parseLogLevelSpec('warn, ExportManager=debug,[IpcManager]=INFO')).toEqual({
+                level: 'warn',
+                targets: { exportmanager: 'debug', ipcmanager: 'info' },
+            });
+        });
+
+        it('rejects unknown levels and empty specs', () => {
+            expect(parseLogLevelSpec('verbose')).toBeNull();
+            expect(parseLogLevelSpec('info,Export=loud')).toBeNull();
+            expect(parseLogLevelSpec('  ')).toBeNull();
+            expect(setLogLevel('verbose')).toBe(false);
+        });
+
+        it('suppresses messages below the active level', () => {
+            setLogLevel('warn');
+            const logger = createLogger('[Quiet]');
+
+            logger.log('info message');
+            logger.warn('warn message');
+
+            expect(console.log).not.toHaveBeenCalled();
+            expect(console.warn).toHaveBeenCalledWith('[Quiet] warn message');
+        });
+
+        it('applies per-module overrides', () => {
+            setLogLevel('error,Noisy=debug');
+
+            createLogger('[Noisy]').debug('details');
+            createLogger('[Other]').log('hidden');
+
+            expect(console.log).toHaveBeenCalledTimes(1);
+            expect(console.log).toHaveBeenCalledWith('[Noisy] [DEBUG] details');
+        });
+
+        it('reports the active spec', () => {
+            expect(getLogLevel()).toBe('');
+            setLogLevel('info,ExportManager=debug');
+            expect(getLogLevel()).toBe('info,exportmanager=debug');
+        });
+    });
 });
@@ -4,7 +4,7 @@
 
 import { describe, it, expect, vi, beforeEach, Mock } from 'vitest';
 import MainWindow from '../../../../src/main/windows/mainWindow';
-import { BrowserWindow } from 'electron';
+import { BrowserWindow, shell } from 'electron';
 
 // Mock Electron
 vi.mock('electron', () => ({
@@ -37,6 +37,7 @@ describe('MainWindow Navigation', () => {
     let mainWindow: MainWindow;
     let mockWebContents: any;
     let navigationHandler: (event: any, url: string) => void;
+    let frameNavigationHandler: (details: any) => void;
 
     beforeEach(() => {
         vi.clearAllMocks();
@@ -46,6 +47,8 @@ describe('MainWindow Navigation', () => {
             on: vi.fn((event, handler) => {
                 if (event === 'will-navigate') {
                     navigationHandler = handler;
+                } else if (event === 'will-frame-navigate') {
+                    frameNavigationHandler = handler;
                 }
             }),
             once: vi.fn(),
@@ -120,4 +123,47 @@ describe('MainWindow Navigation', () => {
         const event = simulateNavigation('file:///app/index.html');
         expect(event.preventDefault).not.toHaveBeenCalled();
     });
+
+    describe('Gemini iframe links', () => {
+        const mainFrame = { parent: null };
+        const geminiFrame = { parent: mainFrame };
+
+        const simulateFrameNavigation = (url: string, frame: any = geminiFrame) => {
+            const details = { url, frame, isMainFrame: frame === mainFrame, preventDefault: vi.fn() };
+            frameNavigationHandler(details);
+            return details;
+        };
+
+        it('keeps Gemini and sign-in pages in the app', () => {
+            expect(simulateFrameNavigation('https://gemini.google.com/app/abc').preventDefault).not.toHaveBeenCalled();
+            expect(simulateFrameNavigation('https://accounts.google.com/signin').preventDefault).not.toHaveBeenCalled();
+            expect(shell.openExternal).not.toHaveBeenCalled();
+        });
+
+        it('opens other sites in the system browser', () => {
+            const details = simulateFrameNavigation('https://en.wikipedia.org/wiki/Gemini');
+
+            expect(details.preventDefault).toHaveBeenCalled();
+            expect(shell.openExternal).toHaveBeenCalledWith('https://en.wikipedia.org/wiki/Gemini');
+        });
+
+        it('keeps allow-listed hosts and their subdomains in the app', () => {
+            mainWindow.setAllowedHosts(['example.com']);
+
+            expect(simulateFrameNavigation('https://docs.example.com/a').preventDefault).not.toHaveBeenCalled();
+            expect(simulateFrameNavigation('https://notexample.com/').preventDefault).toHaveBeenCalled();
+        });
+
+        it('ignores the main frame and frames embedded by Gemini', () => {
+            const nestedFrame = { parent: geminiFrame };
+
+            expect(simulateFrameNavigation('https://example.com/', mainFrame).preventDefault).not.toHaveBeenCalled();
+            expect(simulateFrameNavigation('https://example.com/', nestedFrame).preventDefault).not.toHaveBeenCalled();
+            expect(shell.openExternal).not.toHaveBeenCalled();
+        });
+
+        it('ignores non-web URLs', () => {
+            expect(simulateFrameNavigation('about:blank').preventDefault).not.toHaveBeenCalled();
+        });
+    });
 });
@@ -144,6 +144,18 @@ describe('MainWindow', () => {
             expect(result).toEqual({ action: 'deny' });
         });
 
+        it('never opens local file links from web content', () => {
+            mainWindow.create();
+            const win = (BrowserWindow as any).getAllWindows()[0];
+            const handler = win.webContents.setWindowOpenHandler.mock.calls[0][0];
+
+            const result = handler({ url: 'file:///home/me/run.sh' });
+
+            expect(result).toEqual({ action: 'deny' });
+            expect(shell.openPath).not.toHaveBeenCalled();
+            expect(shell.openExternal).not.toHaveBeenCalled();
+        });
+
         it('intercepts OAuth links and calls auth callback', () => {
             const authCallback = vi.fn();
             mainWindow.setAuthWindowCallback(authCallback);
@@ -223,6 +235,40 @@ describe('MainWindow', () => {
         });
     });
 
+    describe('close to tray', () => {
+        const close = (win: any) => {
+            const event = { preventDefault: vi.fn() };
+            win._listeners.get('close')(event);
+            return event;
+        };
+
+        it('hides the window instead of closing it', () => {
+            const win = mainWindow.create() as any;
+
+            const event = close(win);
+
+            expect(event.preventDefault).toHaveBeenCalled();
+            expect(win.hide).toHaveBeenCalled();
+        });
+
+        it('closes the window when close to tray is off', () => {
+            mainWindow.setCloseToTray(false);
+            const win = mainWindow.create() as any;
+
+            const event = close(win);
+
+            expect(event.preventDefault).not.toHaveBeenCalled();
+            expect(win.hide).not.toHaveBeenCalled();
+        });
+
+        it('closes the window while quitting', () => {
+            mainWindow.setQuitting(true);
+            const win = mainWindow.create() as any;
+
+            expect(close(win).preventDefault).not.toHaveBeenCalled();
+        });
+    });
+
     describe('restoreFromTray', () => {
         beforeEach(() => {
             mocks.isMacOS = false;
@@ -1,5 +1,6 @@
 import { describe, it, expect, vi, beforeEach } from 'vitest';
 import ExportManager from '../../../../src/main/managers/exportManager';
+import { endFocusSession, startFocusSession } from '../../../../src/main/utils/focusSession';
 
 // Mock electron-log
 vi.mock('electron-log', () => ({
@@ -17,6 +18,16 @@ vi.mock('electron-log', () => ({
 }));
 
 // Mock electron
+const { mockFromWebContents, mockNotification } = vi.hoisted(() => {
+    const mockNotification = Object.assign(
+        vi.fn().mockImplementation(function () {
+            return { on: vi.fn(), show: vi.fn(), close: vi.fn() };
+        }),
+        { isSupported: vi.fn().mockReturnValue(true) }
+    );
+    return { mockFromWebContents: vi.fn().mockReturnValue(null), mockNotification };
+});
+
 vi.mock('electron', () => ({
     app: {
         getPath: vi.fn().mockReturnValue('/mock/downloads'),
@@ -24,7 +35,12 @@ vi.mock('electron', () => ({
     dialog: {
         showSaveDialog: vi.fn().mockResolvedValue({ canceled: true }),
     },
-    BrowserWindow: vi.fn(),
+    BrowserWindow: Object.assign(vi.fn(), { fromWebContents: mockFromWebContents }),
+    Notification: mockNotification,
+}));
+
+vi.mock('fs/promises', () => ({
+    writeFile: vi.fn().mockResolvedValue(undefined),
 }));
 
 // Mock turndown - use class constructor for proper instantiation
@@ -155,3 +171,528 @@ describe('ExportManager URL Validation Security', () => {
         });
     });
 });
+
+describe('ExportManager job cancellation', () => {
+    let exportManager: ExportManager;
+
+    beforeEach(() => {
+        exportManager = new ExportManager();
+    });
+
+    const createWebContents = () => ({ send: vi.fn(), isDestroyed: vi.fn().mockReturnValue(false) });
+
+    it('returns false for unknown job IDs', () => {
+        expect(exportManager.cancelExport('missing')).toBe(false);
+    });
+
+    it('marks a running job as cancelled and destroys its render window', () => {
+        const job = (exportManager as any).startJob(createWebContents(), 'pdf');
+        const renderWindow = { isDestroyed: vi.fn().mockReturnValue(false), destroy: vi.fn() };
+        job.renderWindow = renderWindow;
+
+        expect(exportManager.cancelExport(job.id)).toBe(true);
+        expect(job.cancelled).toBe(true);
+        expect(renderWindow.destroy).toHaveBeenCalled();
+        // Cancelling twice is a no-op
+        expect(exportManager.cancelExport(job.id)).toBe(false);
+    });
+
+    it('reports done progress and a success toast when the pipeline completes', async () => {
+        const webContents = createWebContents();
+        await (exportManager as any).runJob(webContents, 'markdown', 'Saved', 'Failed', async () => {});
+
+        expect(webContents.send).toHaveBeenCalledWith(
+            'export-chat:progress',
+            expect.objectContaining({ stage: 'done', percent: 100, format: 'markdown' })
+        );
+        expect(webContents.send).toHaveBeenCalledWith('toast:show', { message: 'Saved', type: 'success' });
+        expect((exportManager as any).jobs.size).toBe(0);
+    });
+
+    it('reports cancelled progress when the job is cancelled mid-pipeline', async () => {
+        const webContents = createWebContents();
+        await (exportManager as any).runJob(webContents, 'pdf', 'Saved', 'Failed', async (job: { id: string }) => {
+            exportManager.cancelExport(job.id);
+        });
+
+        expect(webContents.send).toHaveBeenCalledWith(
+            'export-chat:progress',
+            expect.objectContaining({ stage: 'cancelled' })
+        );
+        expect(webContents.send).toHaveBeenCalledWith('toast:show', { message: 'Export cancelled', type: 'info' });
+    });
+
+    it('reports error progress when the pipeline throws', async () => {
+        const webContents = createWebContents();
+        await (exportManager as any).runJob(webContents, 'pdf', 'Saved', 'Failed', async () => {
+            throw new Error('boom');
+        });
+
+        expect(webContents.send).toHaveBeenCalledWith(
+            'export-chat:progress',
+            expect.objectContaining({ stage: 'error' })
+        );
+        expect(webContents.send).toHaveBeenCalledWith('toast:show', { message: 'Failed', type: 'error' });
+    });
+
+    it('says the disk is full instead of the generic error', async () => {
+        const webContents = createWebContents();
+        await (exportManager as any).runJob(webContents, 'pdf', 'Saved', 'Failed', async () => {
+            throw Object.assign(new Error('ENOSPC: no space left on device, write'), { code: 'ENOSPC' });
+        });
+
+        expect(webContents.send).toHaveBeenCalledWith(
+            'export-chat:progress',
+            expect.objectContaining({ stage: 'error' })
+        );
+        expect(webContents.send).toHaveBeenCalledWith('toast:show', {
+            message: 'Not enough disk space to save the export. Free up space and try again.',
+            type: 'error',
+        });
+    });
+
+    describe('system progress', () => {
+        const createOwnerWindow = (focused: boolean) => ({
+            isDestroyed: vi.fn().mockReturnValue(false),
+            isFocused: vi.fn().mockReturnValue(focused),
+            setProgressBar: vi.fn(),
+        });
+
+        beforeEach(() => {
+            mockNotification.mockClear();
+        });
+
+        it('mirrors progress on the taskbar and clears it when done', async () => {
+            const owner = createOwnerWindow(true);
+            mockFromWebContents.mockReturnValueOnce(owner);
+
+            await (exportManager as any).runJob(createWebContents(), 'pdf', 'Saved', 'Failed', async (job: any) => {
+                (exportManager as any).reportProgress(job, 'rendering', 30);
+            });
+
+            expect(owner.setProgressBar).toHaveBeenCalledWith(0.3);
+            expect(owner.setProgressBar).toHaveBeenLastCalledWith(-1);
+            expect(mockNotification).not.toHaveBeenCalled();
+        });
+
+        it('shows a notification with a Cancel action when the window is in the background', async () => {
+            mockFromWebContents.mockReturnValueOnce(createOwnerWindow(false));
+            let notification: any;
+
+            await (exportManager as any).runJob(createWebContents(), 'pdf', 'Saved', 'Failed', async (job: any) => {
+                (exportManager as any).reportProgress(job, 'preparing', 10);
+                (exportManager as any).reportProgress(job, 'rendering', 30);
+                notification = mockNotification.mock.results[0]!.value;
+
+                // Clicking Cancel on the notification cancels the job
+                const onAction = notification.on.mock.calls.find(([event]: [string]) => event === 'action')[1];
+                onAction();
+                expect(job.cancelled).toBe(true);
+            });
+
+            expect(mockNotification).toHaveBeenCalledTimes(1);
+            expect(mockNotification).toHaveBeenCalledWith(
+                expect.objectContaining({ actions: [{ type: 'button', text: 'Cancel' }] })
+            );
+            expect(notification.close).toHaveBeenCalled();
+        });
+
+        it('does not show a notification during a focus session', async () => {
+            const owner = createOwnerWindow(false);
+            mockFromWebContents.mockReturnValueOnce(owner);
+            startFocusSession(25);
+
+            try {
+                await (exportManager as any).runJob(createWebContents(), 'pdf', 'Saved', 'Failed', async (job: any) => {
+                    (exportManager as any).reportProgress(job, 'rendering', 30);
+                });
+            } finally {
+                endFocusSession();
+            }
+
+            expect(owner.setProgressBar).toHaveBeenCalledWith(0.3);
+            expect(mockNotification).not.toHaveBeenCalled();
+        });
+    });
+});
+
+describe('ExportManager Confluence export', () => {
+    let exportManager: ExportManager;
+
+    beforeEach(() => {
+        exportManager = new ExportManager();
+    });
+
+    it('reports missing settings without extracting the chat', async () => {
+        const webContents = { send: vi.fn(), isDestroyed: vi.fn().mockReturnValue(false) };
+        const extract = vi.spyOn(exportManager as any, 'extractChatData');
+
+        await exportManager.exportToConfluence(webContents as any, {
+            baseUrl: '',
+            email: '',
+            apiToken: '',
+            spaceKey: '',
+            parentId: '',
+        });
+
+        expect(extract).not.toHaveBeenCalled();
+        expect(webContents.send).toHaveBeenCalledWith('toast:show', {
+            message: 'Cannot export: Confluence URL is not set',
+            type: 'error',
+        });
+    });
+
+    it('builds the page body in storage format', () => {
+        const storage = (exportManager as any).generateConfluenceStorage({
+            title: 'Chat',
+            timestamp: '2025-01-01T00:00:00Z',
+            conversation: [
+                { role: 'user', text: 'Hi' },
+                { role: 'model', text: 'Hello' },
+            ],
+        });
+
+        expect(storage).toContain('<h2>You</h2>');
+        expect(storage).toContain('<h2>Gemini</h2>');
+        expect(storage).toContain('<hr />');
+    });
+
+    it('labels answers with the model that wrote them', () => {
+        const storage = (exportManager as any).generateConfluenceStorage({
+            title: 'Chat',
+            timestamp: '2025-01-01T00:00:00Z',
+            conversation: [{ role: 'model', text: 'Hello', modelName: '2.5 Pro' }],
+        });
+
+        expect(storage).toContain('<h2>Gemini (2.5 Pro)</h2>');
+    });
+});
+
+describe('ExportManager model names', () => {
+    const createWebContents = (conversation: unknown[]) => ({
+        getURL: vi.fn().mockReturnValue('file:///app/index.html'),
+        mainFrame: {
+            frames: [
+                {
+                    url: 'https://gemini.google.com/app/abc123',
+                    executeJavaScript: vi.fn().mockResolvedValue({
+                        title: 'Chat',
+                        timestamp: '2025-01-01T00:00:00Z',
+                        conversation,
+                    }),
+                },
+            ],
+        },
+    });
+
+    it('returns the model of the latest answer', async () => {
+        const webContents = createWebContents([
+            { role: 'model', text: 'First', modelName: '2.5 Flash' },
+            { role: 'model', text: 'Second', modelName: '2.5 Pro' },
+        ]);
+
+        const response = await new ExportManager().getLastResponse(webContents as any);
+
+        expect(response?.modelName).toBe('2.5 Pro');
+    });
+
+    it('returns the prompt the latest answer replies to', async () => {
+        const webContents = createWebContents([
+            { role: 'user', text: 'First question' },
+            { role: 'model', text: 'First' },
+            { role: 'user', text: 'Second question' },
+            { role: 'model', text: 'Second' },
+        ]);
+
+        const response = await new ExportManager().getLastResponse(webContents as any);
+
+        expect(response).toMatchObject({ text: 'Second', prompt: 'Second question' });
+    });
+
+    it('drops model names that do not look like one', async () => {
+        const webContents = createWebContents([
+            { role: 'model', text: 'Hi', modelName: '<img src=x onerror=alert(1)>' },
+        ]);
+
+        const response = await new ExportManager().getLastResponse(webContents as any);
+
+        expect(response?.modelName).toBeUndefined();
+    });
+
+    it('returns the whole conversation as Markdown', async () => {
+        const webContents = createWebContents([
+            { role: 'user', text: 'Question' },
+            { role: 'model', text: 'Answer' },
+        ]);
+
+        const markdown = await new ExportManager().getConversationMarkdown(webContents as any);
+
+        expect(markdown).toContain('# Chat');
+        expect(markdown).toContain('## You\n\nQuestion');
+        expect(markdown).toContain('Answer');
+    });
+
+    it('returns null for an empty conversation', async () => {
+        const markdown = await new ExportManager().getConversationMarkdown(createWebContents([]) as any);
+
+        expect(markdown).toBeNull();
+    });
+});
+
+describe('ExportManager Canvas export', () => {
+    const createWebContents = (canvas: unknown) => ({
+        send: vi.fn(),
+        getURL: vi.fn().mockReturnValue('file:///app/index.html'),
+        mainFrame: {
+            frames: [
+                {
+                    url: 'https://gemini.google.com/app/abc123',
+                    executeJavaScript: vi.fn().mockResolvedValue({
+                        title: 'Chat',
+                        timestamp: '2025-01-01T00:00:00Z',
+                        conversation: [{ role: 'model', text: 'Here is your draft' }],
+                        canvas,
+                    }),
+                },
+            ],
+        },
+    });
+
+    it('tells the user when no Canvas is open', async () => {
+        const webContents = createWebContents(null);
+
+        await new ExportManager().exportCanvasToMarkdown(webContents as any);
+
+        expect(webContents.send).toHaveBeenCalledWith('toast:show', { message: 'No Canvas is open', type: 'info' });
+    });
+
+    it('offers to save the Canvas under its own title', async () => {
+        const { dialog } = await import('electron');
+        const webContents = createWebContents({
+            title: 'Launch plan',
+            text: 'Plan',
+            html: '<p>Plan</p>',
+            versions: [],
+        });
+
+        await new ExportManager().exportCanvasToMarkdown(webContents as any, '{title}');
+
+        expect(dialog.showSaveDialog).toHaveBeenCalledWith(
+            expect.objectContaining({
+                title: 'Save Canvas as Markdown',
+                defaultPath: expect.stringContaining('Launch plan'),
+            })
+        );
+    });
+
+    it('adds the Canvas and its version history to chat exports', () => {
+        const exportManager = new ExportManager();
+        const canvas = {
+            title: 'Launch <plan>',
+            text: 'Plan',
+            html: '<p>Plan</p>',
+            versions: ['Version 1', 'Version 2'],
+        };
+
+        expect((exportManager as any).getCanvasMarkdown(canvas)).toBe(
+            'mocked markdown\n\n### Version history\n\n- Version 1\n- Version 2'
+        );
+        expect(
+            (exportManager as any).generateConfluenceStorage({
+                title: 'Chat',
+                timestamp: '2025-01-01T00:00:00Z',
+                conversation: [],
+                canvas,
+            })
+        ).toContain('<h2>Canvas: Launch &lt;plan&gt;</h2>');
+    });
+});
+
+describe('ExportManager Gem awareness', () => {
+    const createWebContents = (url: string, gem: string) => ({
+        send: vi.fn(),
+        getURL: vi.fn().mockReturnValue('file:///app/index.html'),
+        mainFrame: {
+            frames: [
+                {
+                    url,
+                    executeJavaScript: vi.fn().mockResolvedValue({
+                        title: 'Chat',
+                        timestamp: '2025-01-01T00:00:00Z',
+                        conversation: [{ role: 'model', text: 'Hello' }],
+                        gem,
+                    }),
+                },
+            ],
+        },
+    });
+
+    it('records the Gem with the extracted chat', async () => {
+        const data = await (new ExportManager() as any).extractChatData(
+            createWebContents('https://gemini.google.com/gem/coach/abc123', 'Writing Coach')
+        );
+
+        expect(data.gem).toBe('Writing Coach');
+        expect(data.id).toBe('abc123');
+    });
+
+    it('leaves the Gem unset for plain Gemini chats', async () => {
+        const data = await (new ExportManager() as any).extractChatData(
+            createWebContents('https://gemini.google.com/app/abc123', '')
+        );
+
+        expect(data.gem).toBeUndefined();
+    });
+
+    it('names the Gem in export headers and filenames', async () => {
+        const { dialog } = await import('electron');
+        const exportManager = new ExportManager();
+        const data = { title: 'Chat', timestamp: '2025-01-01T00:00:00Z', conversation: [], gem: 'Coach <beta>' };
+
+        expect((exportManager as any).getHeaderMarkdown(data)).toContain('*Gem: Coach <beta>*');
+        expect((exportManager as any).generatePdfHtml(data)).toContain('Gem: Coach &lt;beta&gt;');
+        expect((exportManager as any).generateConfluenceStorage(data)).toContain(
+            '<p><em>Gem: Coach &lt;beta&gt;</em></p>'
+        );
+
+        await exportManager.exportToMarkdown(
+            createWebContents('https://gemini.google.com/gem/coach/abc123', 'Writing Coach') as any,
+            '{gem}_{title}'
+        );
+
+        expect(dialog.showSaveDialog).toHaveBeenCalledWith(
+            expect.objectContaining({ defaultPath: expect.stringContaining('Writing_Coach_Chat.md') })
+        );
+    });
+});
+
+describe('ExportManager Deep Research report export', () => {
+    const report = {
+        title: 'Market study',
+        text: 'Findings',
+        html: '<h2>Findings</h2><table><tr><td>A</td></tr></table>',
+        sources: [
+            { title: 'Example <source>', url: 'https://example.com/a' },
+            { title: 'Script', url: 'javascript:alert(1)' },
+        ],
+    };
+
+    const createWebContents = (extractedReport: unknown) => ({
+        send: vi.fn(),
+        getURL: vi.fn().mockReturnValue('file:///app/index.html'),
+        mainFrame: {
+            frames: [
+                {
+                    url: 'https://gemini.google.com/app/abc123',
+                    executeJavaScript: vi.fn().mockResolvedValue({
+                        title: 'Chat',
+                        timestamp: '2025-01-01T00:00:00Z',
+                        conversation: [{ role: 'model', text: 'Here is your report' }],
+                        report: extractedReport,
+                    }),
+                },
+            ],
+        },
+    });
+
+    it('tells the user when no report is open', async () => {
+        const webContents = createWebContents(null);
+
+        await new ExportManager().exportResearchReport(webContents as any, 'pdf');
+
+        expect(webContents.send).toHaveBeenCalledWith('toast:show', {
+            message: 'No Deep Research report is open',
+            type: 'info',
+        });
+    });
+
+    it('keeps only web sources', async () => {
+        const data = await (new ExportManager() as any).extractChatData(createWebContents(report));
+
+        expect(data.report.sources).toEqual([{ title: 'Example <source>', url: 'https://example.com/a' }]);
+    });
+
+    it('offers to save the report under its own title', async () => {
+        const { dialog } = await import('electron');
+
+        await new ExportManager().exportResearchReport(createWebContents(report) as any, 'markdown', '{title}');
+
+        expect(dialog.showSaveDialog).toHaveBeenCalledWith(
+            expect.objectContaining({
+                title: 'Save Research Report as Markdown',
+                defaultPath: expect.stringContaining('Market_study.md'),
+            })
+        );
+    });
+
+    it('keeps the report HTML and lists its sources', () => {
+        const exportManager = new ExportManager() as any;
+        const sources = [{ title: 'Example <source>', url: 'https://example.com/a' }];
+        const data = { title: 'Chat', timestamp: '2025-01-01T00:00:00Z', conversation: [] };
+
+        expect(exportManager.getResearchReportMarkdown({ ...report, sources })).toBe(
+            'mocked markdown\n\n## Sources\n\n1. [Example <source>](https://example.com/a)'
+        );
+
+        const html = exportManager.generateResearchReportHtml(data, { ...report, sources });
+        expect(html).toContain('<table><tr><td>A</td></tr></table>');
+        expect(html).toContain('<li><a href="https://example.com/a">Example &lt;source&gt;</a></li>');
+    });
+});
+
+describe('ExportManager headless export', () => {
+    const createWindow = (url: string) => ({
+        loadURL: vi.fn().mockResolvedValue(undefined),
+        isDestroyed: vi.fn().mockReturnValue(false),
+        destroy: vi.fn(),
+        webContents: {
+            getURL: vi.fn().mockReturnValue(url),
+            mainFrame: {
+                executeJavaScript: vi.fn().mockResolvedValue({
+                    title: 'Chat',
+                    timestamp: '2025-01-01T00:00:00Z',
+                    conversation: [{ role: 'user', text: 'Hi' }],
+                }),
+            },
+        },
+    });
+
+    const useWindow = async (win: ReturnType<typeof createWindow>) => {
+        const { BrowserWindow } = await import('electron');
+        vi.mocked(BrowserWindow).mockImplementationOnce(function () {
+            return win as any;
+        });
+    };
+
+    it('only loads Gemini URLs', async () => {
+        await expect(
+            new ExportManager().exportConversationToFile('https://example.com/app/abc', 'markdown', '/tmp/chat.md')
+        ).rejects.toThrow('Not a Gemini URL');
+    });
+
+    it('fails when Gemini asks to sign in', async () => {
+        const win = createWindow('https://accounts.google.com/signin');
+        await useWindow(win);
+
+        const exportManager = new ExportManager();
+
+        await expect(
+            exportManager.exportConversationToFile('https://gemini.google.com/app/abc', 'markdown', '/tmp/chat.md')
+        ).rejects.toThrow('sign in');
+        expect(win.destroy).toHaveBeenCalled();
+    });
+
+    it('writes the conversation to the output file', async () => {
+        const fs = await import('fs/promises');
+        const win = createWindow('https://gemini.google.com/app/abc');
+        await useWindow(win);
+
+        const exportManager = new ExportManager();
+
+        await exportManager.exportConversationToFile('https://gemini.google.com/app/abc', 'markdown', '/tmp/chat.md');
+
+        expect(win.loadURL).toHaveBeenCalledWith('https://gemini.google.com/app/abc');
+        expect(fs.writeFile).toHaveBeenCalledWith('/tmp/chat.md', expect.stringContaining('# Chat'));
+        expect(win.destroy).toHaveBeenCalled();
+    });
+});
@@ -9,6 +9,7 @@ import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
 import type { BrowserWindow } from 'electron';
 import NotificationManager from '../../../../src/main/managers/notificationManager';
 import { createMockStore } from '../../../helpers/mocks';
+import { endFocusSession, startFocusSession } from '../../../../src/main/utils/focusSession';
 
 // Mock Notification class
 const mockNotification = vi.hoisted(() => {
@@ -196,6 +197,21 @@ describe('NotificationManager', () => {
             expect(mockBadgeManager.showNotificationBadge).not.toHaveBeenCalled();
         });
 
+        it('does not show notification or badge during a focus session', () => {
+            mockMainWindow.isFocused = vi.fn().mockReturnValue(false);
+            const manager = new NotificationManager(mockMainWindow, mockBadgeManager, mockStore as any);
+            startFocusSession(25);
+
+            try {
+                manager.onResponseComplete();
+            } finally {
+                endFocusSession();
+            }
+
+            expect(mockNotification._instances.length).toBe(0);
+            expect(mockBadgeManager.showNotificationBadge).not.toHaveBeenCalled();
+        });
+
         it('correctly checks focus state after blur event', () => {
             // Start focused
             mockMainWindow.isFocused = vi.fn().mockReturnValue(true);
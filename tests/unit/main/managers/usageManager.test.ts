@@ -0,0 +1,237 @@
+/**
+ * Unit tests for UsageManager.
+ *
+ * Tests focus time accounting, idle detection, the usage report, daily limits and the policy and data files.
+ */
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import type { UsageDataStore } from '../../../../src/main/managers/usageManager';
+import { createMockStore } from '../../../helpers/mocks';
+
+const { mockApp, mockPowerMonitor, mockBrowserWindow } = vi.hoisted(() => ({
+    mockApp: { on: vi.fn(), removeListener: vi.fn() },
+    mockPowerMonitor: { on: vi.fn(), removeListener: vi.fn(), getSystemIdleTime: vi.fn().mockReturnValue(0) },
+    mockBrowserWindow: { getFocusedWindow: vi.fn().mockReturnValue(null) },
+}));
+
+vi.mock('electron', () => ({
+    app: mockApp,
+    powerMonitor: mockPowerMonitor,
+    BrowserWindow: mockBrowserWindow,
+}));
+
+// Safe mode ignores saved settings; the usage policy and data must not be affected
+vi.mock('../../../../src/main/utils/safeMode', () => ({ isSafeMode: () => true }));
+
+import UsageManager, {
+    loadUsagePolicy,
+    normalizeUsageLimits,
+    UsageDataFile,
+    toLocalDate,
+    USAGE_IDLE_THRESHOLD_SECONDS,
+} from '../../../../src/main/managers/usageManager';
+
+/** Monday 2026-10-12, 10:00 local time */
+const MONDAY = new Date(2026, 9, 12, 10, 0, 0).getTime();
+const MINUTE = 60 * 1000;
+
+describe('UsageManager', () => {
+    let clock: number;
+    let store: ReturnType<typeof createMockStore>;
+    let manager: UsageManager;
+
+    const appListener = (event: string) => mockApp.on.mock.calls.find(([name]) => name === event)![1] as () => void;
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        vi.useFakeTimers();
+        mockBrowserWindow.getFocusedWindow.mockReturnValue(null);
+        mockPowerMonitor.getSystemIdleTime.mockReturnValue(0);
+
+        clock = MONDAY;
+        store = createMockStore({ days: {} });
+        manager = new UsageManager({ store: store as unknown as UsageDataStore, now: () => clock });
+    });
+
+    afterEach(() => {
+        manager.stop();
+        vi.useRealTimers();
+    });
+
+    it('counts time between window focus and blur', () => {
+        manager.start(true);
+        appListener('browser-window-focus')();
+        clock += 10 * MINUTE;
+        appListener('browser-window-blur')();
+
+        expect(store.get('days')).toEqual({ [toLocalDate(MONDAY)]: 600 });
+    });
+
+    it('starts counting immediately if a window is already focused', () => {
+        mockBrowserWindow.getFocusedWindow.mockReturnValue({});
+        manager.start(true);
+        clock += 2 * MINUTE;
+
+        expect(manager.getReport().todaySeconds).toBe(120);
+    });
+
+    it('does not credit idle time when idle detection is on', () => {
+        mockBrowserWindow.getFocusedWindow.mockReturnValue({});
+        manager.start(true);
+        clock += 20 * MINUTE;
+        mockPowerMonitor.getSystemIdleTime.mockReturnValue(USAGE_IDLE_THRESHOLD_SECONDS + 5 * 60);
+
+        manager.tick();
+
+        // 20 minutes focused, the last 10 of them idle
+        expect(store.get('days')).toEqual({ [toLocalDate(MONDAY)]: 600 });
+        clock += 10 * MINUTE;
+        expect(manager.getReport().todaySeconds).toBe(600);
+    });
+
+    it('keeps counting while idle when idle detection is off', () => {
+        mockBrowserWindow.getFocusedWindow.mockReturnValue({});
+        manager.start(false);
+        clock += 20 * MINUTE;
+        mockPowerMonitor.getSystemIdleTime.mockReturnValue(USAGE_IDLE_THRESHOLD_SECONDS * 2);
+
+        manager.tick();
+
+        expect(store.get('days')).toEqual({ [toLocalDate(MONDAY)]: 1200 });
+    });
+
+    it('reports today, the week since Monday, and the last 14 days', () => {
+        const sunday = toLocalDate(MONDAY - 24 * 60 * MINUTE);
+        store.set('days', { [sunday]: 3600, [toLocalDate(MONDAY)]: 900 });
+
+        const report = manager.getReport();
+
+        expect(report.todaySeconds).toBe(900);
+        expect(report.weekSeconds).toBe(900);
+        expect(report.days).toHaveLength(14);
+        expect(report.days[12]).toEqual({ date: sunday, seconds: 3600 });
+    });
+
+    it('removes listeners and commits pending time on stop', () => {
+        mockBrowserWindow.getFocusedWindow.mockReturnValue({});
+        manager.start(true);
+        clock += MINUTE;
+
+        manager.stop();
+
+        expect(store.get('days')).toEqual({ [toLocalDate(MONDAY)]: 60 });
+        expect(mockApp.removeListener).toHaveBeenCalledWith('browser-window-focus', expect.any(Function));
+        expect(mockPowerMonitor.removeListener).toHaveBeenCalledWith('suspend', expect.any(Function));
+    });
+
+    describe('daily limits', () => {
+        it('treats missing, negative and non-numeric limits as no limit', () => {
+            expect(normalizeUsageLimits({ dailyMinutes: -5, dailyPrompts: 'ten' })).toEqual({
+                dailyMinutes: 0,
+                dailyPrompts: 0,
+            });
+            expect(normalizeUsageLimits({ dailyMinutes: 90.7 })).toEqual({ dailyMinutes: 90, dailyPrompts: 0 });
+        });
+
+        it('blocks once the daily time limit is reached', () => {
+            const listener = vi.fn();
+            manager.on('limit-changed', listener);
+            manager.setLimits({ dailyMinutes: 30, dailyPrompts: 0 });
+            mockBrowserWindow.getFocusedWindow.mockReturnValue({});
+            manager.start(true);
+
+            clock += 20 * MINUTE;
+            manager.tick();
+            expect(listener).not.toHaveBeenCalled();
+
+            clock += 10 * MINUTE;
+            manager.tick();
+            expect(listener).toHaveBeenCalledWith(expect.objectContaining({ blocked: true, reason: 'time' }));
+        });
+
+        it('blocks once the daily prompt limit is reached', () => {
+            const listener = vi.fn();
+            manager.on('limit-changed', listener);
+            manager.setLimits({ dailyMinutes: 0, dailyPrompts: 2 });
+
+            manager.recordPrompt();
+            expect(manager.getLimitStatus().blocked).toBe(false);
+            manager.recordPrompt();
+
+            expect(manager.getReport().todayPrompts).toBe(2);
+            expect(listener).toHaveBeenCalledTimes(1);
+            expect(manager.getLimitStatus()).toEqual({
+                blocked: true,
+                reason: 'prompts',
+                limits: { dailyMinutes: 0, dailyPrompts: 2 },
+                enforcedByPolicy: false,
+            });
+        });
+
+        it('clears the block on the next day', () => {
+            const listener = vi.fn();
+            manager.on('limit-changed', listener);
+            manager.setLimits({ dailyMinutes: 0, dailyPrompts: 1 }, true);
+            manager.recordPrompt();
+
+            clock += 24 * 60 * MINUTE;
+            manager.tick();
+
+            expect(listener).toHaveBeenLastCalledWith(
+                expect.objectContaining({ blocked: false, reason: null, enforcedByPolicy: true })
+            );
+        });
+
+        it('unblocks when the limit is removed', () => {
+            manager.setLimits({ dailyMinutes: 0, dailyPrompts: 1 });
+            manager.recordPrompt();
+            expect(manager.getLimitStatus().blocked).toBe(true);
+
+            manager.setLimits({ dailyMinutes: 0, dailyPrompts: 0 });
+
+            expect(manager.hasLimits()).toBe(false);
+            expect(manager.getLimitStatus().blocked).toBe(false);
+        });
+    });
+
+    describe('policy and data files', () => {
+        let dir: string;
+
+        beforeEach(() => {
+            dir = fs.mkdtempSync(path.join(os.tmpdir(), 'gd-usage-'));
+        });
+
+        afterEach(() => {
+            fs.rmSync(dir, { recursive: true, force: true });
+        });
+
+        it('applies the policy file in safe mode', () => {
+            expect(loadUsagePolicy(dir)).toBeNull();
+
+            fs.writeFileSync(path.join(dir, 'usage-policy.json'), JSON.stringify({ dailyPrompts: 20 }));
+
+            expect(loadUsagePolicy(dir)).toEqual({ dailyMinutes: 0, dailyPrompts: 20 });
+        });
+
+        it('ignores an unreadable policy file', () => {
+            fs.writeFileSync(path.join(dir, 'usage-policy.json'), '{ not json');
+
+            expect(loadUsagePolicy(dir)).toBeNull();
+        });
+
+        it('keeps the usage counts in safe mode', () => {
+            const filePath = path.join(dir, 'usage.json');
+            fs.writeFileSync(filePath, JSON.stringify({ days: {}, prompts: { [toLocalDate(MONDAY)]: 4 } }));
+
+            const data = new UsageDataFile(filePath);
+            const limited = new UsageManager({ store: data, now: () => MONDAY });
+            limited.setLimits({ dailyMinutes: 0, dailyPrompts: 5 }, true);
+            limited.recordPrompt();
+
+            expect(limited.getLimitStatus().blocked).toBe(true);
+            expect(new UsageDataFile(filePath).get('prompts')).toEqual({ [toLocalDate(MONDAY)]: 5 });
+        });
+    });
+});
@@ -220,6 +220,20 @@ describe('MenuManager', () => {
             expect(mockWindowManager.getMainWindow().reload).toHaveBeenCalled();
         });
 
+        it('New Window item opens another main window', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const fileMenu = findMenuItem(template, 'File');
+            const newWindowItem = findSubmenuItem(fileMenu, 'New Window');
+
+            expect(newWindowItem.id).toBe('menu-file-newwindow');
+            expect(newWindowItem.accelerator).toBe('CmdOrCtrl+Shift+N');
+
+            newWindowItem.click();
+            expect(mockWindowManager.createAdditionalMainWindow).toHaveBeenCalled();
+        });
+
         it('Export as PDF item calls emit("print-to-pdf-triggered")', () => {
             setPlatform('win32');
             menuManager.buildMenu();
@@ -249,6 +263,136 @@ describe('MenuManager', () => {
             expect(mockWindowManager.emit).toHaveBeenCalledWith('export-markdown-triggered');
         });
 
+        it('Export to Confluence item calls emit("export-confluence-triggered")', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const fileMenu = findMenuItem(template, 'File');
+            const confluenceItem = findSubmenuItem(fileMenu, 'Export to Confluence');
+
+            expect(confluenceItem.id).toBe('menu-view-export-confluence');
+
+            confluenceItem.click();
+            expect(mockWindowManager.emit).toHaveBeenCalledWith('export-confluence-triggered');
+        });
+
+        it('Export Canvas as Markdown item calls emit("export-canvas-triggered")', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const fileMenu = findMenuItem(template, 'File');
+            const canvasItem = findSubmenuItem(fileMenu, 'Export Canvas as Markdown');
+
+            expect(canvasItem.id).toBe('menu-file-export-canvas');
+
+            canvasItem.click();
+            expect(mockWindowManager.emit).toHaveBeenCalledWith('export-canvas-triggered');
+        });
+
+        it('Export Research Report items emit("export-research-report-triggered") with the format', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const fileMenu = findMenuItem(template, 'File');
+
+            findSubmenuItem(fileMenu, 'Export Research Report as PDF').click();
+            findSubmenuItem(fileMenu, 'Export Research Report as Markdown').click();
+
+            expect(mockWindowManager.emit).toHaveBeenCalledWith('export-research-report-triggered', 'pdf');
+            expect(mockWindowManager.emit).toHaveBeenCalledWith('export-research-report-triggered', 'markdown');
+        });
+
+        it('Pin Last Answer item calls emit("pin-answer-triggered")', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const fileMenu = findMenuItem(template, 'File');
+            const pinItem = findSubmenuItem(fileMenu, 'Pin Last Answer');
+
+            expect(pinItem).toBeTruthy();
+            expect(pinItem.id).toBe('menu-file-pin-answer');
+
+            pinItem.click();
+            expect(mockWindowManager.emit).toHaveBeenCalledWith('pin-answer-triggered');
+        });
+
+        it('Share Last Answer item calls emit("share-answer-triggered")', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const fileMenu = findMenuItem(template, 'File');
+            const shareItem = findSubmenuItem(fileMenu, 'Share Last Answer...');
+
+            expect(shareItem.id).toBe('menu-file-share-answer');
+
+            shareItem.click();
+            expect(mockWindowManager.emit).toHaveBeenCalledWith('share-answer-triggered');
+        });
+
+        it('Share as QR Code item calls emit("share-qr-triggered")', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const fileMenu = findMenuItem(template, 'File');
+            const qrItem = findSubmenuItem(fileMenu, 'Share as QR Code...');
+
+            expect(qrItem.id).toBe('menu-file-share-qr');
+
+            qrItem.click();
+            expect(mockWindowManager.emit).toHaveBeenCalledWith('share-qr-triggered');
+        });
+
+        it('Create Issue from Answer item calls emit("create-issue-triggered")', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const fileMenu = findMenuItem(template, 'File');
+            const issueItem = findSubmenuItem(fileMenu, 'Create Issue from Answer...');
+
+            expect(issueItem.id).toBe('menu-file-create-issue');
+
+            issueItem.click();
+            expect(mockWindowManager.emit).toHaveBeenCalledWith('create-issue-triggered');
+        });
+
+        it('Print item prints the Gemini frame', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const fileMenu = findMenuItem(template, 'File');
+            const printItem = findSubmenuItem(fileMenu, 'Print...');
+
+            expect(printItem.id).toBe('menu-file-print');
+            expect(printItem.accelerator).toBe('CmdOrCtrl+P');
+
+            printItem.click();
+            expect(mockWindowManager.printGemini).toHaveBeenCalled();
+        });
+
+        it('Screenshot items emit screenshot-triggered with their target and region', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const fileMenu = findMenuItem(template, 'File');
+
+            findSubmenuItem(fileMenu, 'Save Screenshot...').click();
+            findSubmenuItem(fileMenu, 'Copy Screenshot').click();
+            findSubmenuItem(fileMenu, 'Copy Last Answer as Image').click();
+
+            expect(mockWindowManager.emit).toHaveBeenCalledWith('screenshot-triggered', {
+                target: 'file',
+                region: 'view',
+            });
+            expect(mockWindowManager.emit).toHaveBeenCalledWith('screenshot-triggered', {
+                target: 'clipboard',
+                region: 'view',
+            });
+            expect(mockWindowManager.emit).toHaveBeenCalledWith('screenshot-triggered', {
+                target: 'clipboard',
+                region: 'response',
+            });
+        });
+
         it('Options/Settings item logic adapts to platform', () => {
             // macOS: Settings...
             setPlatform('darwin');
@@ -344,6 +488,103 @@ describe('MenuManager', () => {
 
             expect(alwaysOnTopItem.checked).toBe(true);
         });
+
+        it('Reload User Styles item reloads user styles', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const viewMenu = findMenuItem(template, 'View');
+            const item = findSubmenuItem(viewMenu, 'Reload User Styles');
+
+            expect(item.id).toBe('menu-view-reload-user-styles');
+
+            item.click();
+            expect(mockWindowManager.reloadUserStyles).toHaveBeenCalled();
+        });
+
+        it('Immersive Mode item toggles immersive mode', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const viewMenu = findMenuItem(template, 'View');
+            const item = findSubmenuItem(viewMenu, 'Immersive Mode');
+
+            expect(item.id).toBe('menu-view-immersive');
+            expect(item.accelerator).toBe('Shift+F11');
+
+            item.click();
+            expect(mockWindowManager.toggleImmersiveMode).toHaveBeenCalled();
+        });
+
+        it('Toggle Full Screen item toggles fullscreen', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const viewMenu = findMenuItem(template, 'View');
+            const item = findSubmenuItem(viewMenu, 'Toggle Full Screen');
+
+            expect(item.id).toBe('menu-view-fullscreen');
+            expect(item.accelerator).toBe('F11');
+
+            item.click();
+            expect(mockWindowManager.toggleFullScreen).toHaveBeenCalled();
+        });
+
+        it('Compact Mode item toggles compact mode', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const viewMenu = findMenuItem(template, 'View');
+            const item = findSubmenuItem(viewMenu, 'Compact Mode');
+
+            expect(item.id).toBe('menu-view-compact');
+            expect(item.accelerator).toBe('CmdOrCtrl+Shift+M');
+
+            item.click();
+            expect(mockWindowManager.toggleCompactMode).toHaveBeenCalled();
+        });
+
+        it('Overlay Mode item toggles overlay mode and is disabled where unsupported', () => {
+            setPlatform('linux');
+            mockWindowManager.isWindowOpacitySupported.mockReturnValue(false);
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const viewMenu = findMenuItem(template, 'View');
+            const item = findSubmenuItem(viewMenu, 'Overlay Mode');
+
+            expect(item.id).toBe('menu-view-overlay');
+            expect(item.accelerator).toBe('CmdOrCtrl+Shift+O');
+            expect(item.enabled).toBe(false);
+
+            item.click();
+            expect(mockWindowManager.toggleOverlayMode).toHaveBeenCalled();
+        });
+
+        it('Move to Next Display item moves the window to the next display', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const viewMenu = findMenuItem(template, 'View');
+            const item = findSubmenuItem(viewMenu, 'Move to Next Display');
+
+            expect(item.id).toBe('menu-view-move-to-next-display');
+
+            item.click();
+            expect(mockWindowManager.moveToNextDisplay).toHaveBeenCalled();
+        });
+
+        it('Focus Session item toggles a focus session', () => {
+            setPlatform('win32');
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const viewMenu = findMenuItem(template, 'View');
+            const item = findSubmenuItem(viewMenu, 'Focus Session');
+
+            expect(item.id).toBe('menu-view-focus-session');
+
+            item.click();
+            expect(mockWindowManager.emit).toHaveBeenCalledWith('focus-session-toggle-triggered');
+        });
     });
 
     describe('Context Menu', () => {
@@ -421,6 +662,46 @@ describe('MenuManager', () => {
             ].value;
             expect(menu.popup).toHaveBeenCalled();
         });
+
+        it('offers spelling suggestions for a misspelled word', async () => {
+            const { app, Menu } = await import('electron');
+            mockContents.replaceMisspelling = vi.fn();
+            mockContents.session = { addWordToSpellCheckerDictionary: vi.fn() };
+
+            menuManager.setupContextMenu();
+            webContentsCreatedCallback = (app.on as any).mock.calls.find(
+                (call: any[]) => call[0] === 'web-contents-created'
+            )?.[1];
+            webContentsCreatedCallback({}, mockContents);
+
+            contextMenuCallback(
+                {},
+                {
+                    misspelledWord: 'helo',
+                    dictionarySuggestions: ['hello', 'help'],
+                    editFlags: { canCut: true, canCopy: true, canPaste: true, canDelete: true, canSelectAll: true },
+                }
+            );
+
+            const template = (Menu.buildFromTemplate as any).mock.calls.at(-1)[0];
+            expect(template.slice(0, 2).map((item: any) => item.label)).toEqual(['hello', 'help']);
+            expect(template.map((item: any) => item.id).filter(Boolean)).toEqual([
+                'cut',
+                'copy',
+                'paste',
+                'delete',
+                'selectAll',
+            ]);
+
+            template[0].click();
+            expect(mockContents.replaceMisspelling).toHaveBeenCalledWith('hello');
+
+            findMenuItem(template, 'Add to Dictionary').click();
+            expect(mockContents.session.addWordToSpellCheckerDictionary).toHaveBeenCalledWith('helo');
+
+            const menu = (Menu.buildFromTemplate as any).mock.results.at(-1).value;
+            expect(menu.popup).toHaveBeenCalled();
+        });
     });
 
     describe('Debug Menu', () => {
@@ -507,6 +788,18 @@ describe('MenuManager', () => {
 
             expect(() => errorItem.click()).not.toThrow();
         });
+
+        it('Toggle Gemini DevTools item calls toggleGeminiDevTools', () => {
+            setPlatform('win32');
+
+            menuManager.buildMenu();
+            const template = (Menu.buildFromTemplate as any).mock.calls[0][0];
+            const debugMenu = findMenuItem(template, 'Debug');
+            const devToolsItem = findSubmenuItem(debugMenu, 'Toggle Gemini DevTools');
+
+            devToolsItem.click();
+            expect(mockWindowManager.toggleGeminiDevTools).toHaveBeenCalled();
+        });
     });
 
     describe('Dock Menu Click Handlers', () => {
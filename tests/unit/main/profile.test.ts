@@ -0,0 +1,47 @@
+/**
+ * Unit tests for config profiles.
+ * @module profile.test
+ */
+
+import * as path from 'path';
+import { describe, it, expect } from 'vitest';
+import { parseProfileArg, getProfileDataPath, isProfileName } from '../../../src/main/utils/profile';
+
+describe('parseProfileArg', () => {
+    it('returns null without a profile', () => {
+        expect(parseProfileArg(['electron', '.', '--safe-mode'])).toBeNull();
+    });
+
+    it('reads --profile <name>', () => {
+        expect(parseProfileArg(['electron', '.', '--profile', 'work'])).toEqual({ name: 'work' });
+    });
+
+    it('reads --profile=<name> and lowercases it', () => {
+        expect(parseProfileArg(['gemini-desktop', '--profile=Work_2'])).toEqual({ name: 'work_2' });
+    });
+
+    it('rejects names that are not safe folder names', () => {
+        expect(parseProfileArg(['electron', '.', '--profile', '../other'])).toHaveProperty('error');
+        expect(parseProfileArg(['electron', '.', '--profile'])).toHaveProperty('error');
+        expect(parseProfileArg(['electron', '.', '--profile='])).toHaveProperty('error');
+    });
+});
+
+describe('isProfileName', () => {
+    it('limits the length', () => {
+        expect(isProfileName('a'.repeat(64))).toBe(true);
+        expect(isProfileName('a'.repeat(65))).toBe(false);
+    });
+
+    it('does not allow a leading separator', () => {
+        expect(isProfileName('-work')).toBe(false);
+    });
+});
+
+describe('getProfileDataPath', () => {
+    it('places profiles in the default data directory', () => {
+        expect(getProfileDataPath('/data/Gemini Desktop', 'work')).toBe(
+            path.join('/data/Gemini Desktop', 'profiles', 'work')
+        );
+    });
+});
@@ -0,0 +1,117 @@
+/**
+ * Unit tests for upstream proxy helpers.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+const { mockSafeStorage } = vi.hoisted(() => ({
+    mockSafeStorage: {
+        isEncryptionAvailable: vi.fn().mockReturnValue(true),
+        encryptString: vi.fn((value: string) => Buffer.from(`secret(${value})`)),
+        decryptString: vi.fn((buffer: Buffer) => buffer.toString().slice(7, -1)),
+    },
+}));
+
+vi.mock('electron', () => ({ safeStorage: mockSafeStorage }));
+
+import {
+    validateProxySettings,
+    toProxyConfig,
+    applyProxySettings,
+    encryptProxyPassword,
+    decryptProxyPassword,
+    testProxyConnection,
+} from '../../../src/main/utils/proxy';
+import { DEFAULT_PROXY_SETTINGS, type ProxySettings } from '../../../src/shared/types/proxy';
+
+const httpProxy: ProxySettings = { ...DEFAULT_PROXY_SETTINGS, mode: 'http', host: 'proxy.corp', port: 3128 };
+
+describe('proxy', () => {
+    beforeEach(() => {
+        vi.clearAllMocks();
+    });
+
+    describe('validateProxySettings', () => {
+        it('accepts system and direct modes without a server', () => {
+            expect(validateProxySettings(DEFAULT_PROXY_SETTINGS)).toBeNull();
+            expect(validateProxySettings({ ...DEFAULT_PROXY_SETTINGS, mode: 'direct' })).toBeNull();
+        });
+
+        it('requires a host and valid port for server modes', () => {
+            expect(validateProxySettings(httpProxy)).toBeNull();
+            expect(validateProxySettings({ ...httpProxy, host: '' })).toMatch(/host/);
+            expect(validateProxySettings({ ...httpProxy, host: 'user@proxy' })).toMatch(/host/);
+            expect(validateProxySettings({ ...httpProxy, port: 70000 })).toMatch(/port/);
+        });
+
+        it('rejects unknown modes', () => {
+            expect(validateProxySettings({ ...httpProxy, mode: 'ftp' as ProxySettings['mode'] })).toMatch(/mode/);
+        });
+    });
+
+    describe('toProxyConfig', () => {
+        it('passes system and direct modes through', () => {
+            expect(toProxyConfig(DEFAULT_PROXY_SETTINGS)).toEqual({ mode: 'system' });
+        });
+
+        it('builds proxy rules and a normalized bypass list', () => {
+            expect(toProxyConfig({ ...httpProxy, mode: 'socks5', bypass: 'localhost, *.corp;10.0.0.0/8' })).toEqual({
+                mode: 'fixed_servers',
+                proxyRules: 'socks5://proxy.corp:3128',
+                proxyBypassRules: 'localhost,*.corp,10.0.0.0/8',
+            });
+        });
+    });
+
+    it('applies the config and drops existing connections', async () => {
+        const session = { setProxy: vi.fn().mockResolvedValue(undefined), closeAllConnections: vi.fn() };
+
+        await applyProxySettings(session as unknown as Electron.Session, httpProxy);
+
+        expect(session.setProxy).toHaveBeenCalledWith(
+            expect.objectContaining({ proxyRules: 'http://proxy.corp:3128' })
+        );
+        expect(session.closeAllConnections).toHaveBeenCalled();
+    });
+
+    describe('password storage', () => {
+        it('round-trips encrypted passwords', () => {
+            const stored = encryptProxyPassword('hunter2');
+
+            expect(stored).toMatch(/^enc:/);
+            expect(decryptProxyPassword(stored)).toBe('hunter2');
+        });
+
+        it('stores plain text when encryption is unavailable', () => {
+            mockSafeStorage.isEncryptionAvailable.mockReturnValueOnce(false);
+            expect(encryptProxyPassword('hunter2')).toBe('hunter2');
+            expect(decryptProxyPassword('hunter2')).toBe('hunter2');
+        });
+
+        it('returns an empty password when decryption fails', () => {
+            mockSafeStorage.decryptString.mockImplementationOnce(() => {
+                throw new Error('keychain locked');
+            });
+            expect(decryptProxyPassword('enc:AAAA')).toBe('');
+        });
+    });
+
+    describe('testProxyConnection', () => {
+        it('reports status and latency on success', async () => {
+            const session = { fetch: vi.fn().mockResolvedValue({ status: 200 }) };
+
+            const result = await testProxyConnection(session as unknown as Electron.Session);
+
+            expect(result).toMatchObject({ success: true, statusCode: 200 });
+            expect(result.latencyMs).toBeGreaterThanOrEqual(0);
+        });
+
+        it('reports connection errors', async () => {
+            const session = { fetch: vi.fn().mockRejectedValue(new Error('net::ERR_PROXY_CONNECTION_FAILED')) };
+
+            expect(await testProxyConnection(session as unknown as Electron.Session)).toEqual({
+                success: false,
+                error: 'net::ERR_PROXY_CONNECTION_FAILED',
+            });
+        });
+    });
+});
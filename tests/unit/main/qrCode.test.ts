@@ -0,0 +1,92 @@
+/**
+ * Unit tests for the QR code encoder.
+ */
+import { describe, it, expect } from 'vitest';
+import { encodeQrCode, qrCodeToSvg, reedSolomonRemainder, QR_CODE_MAX_BYTES } from '../../../src/main/utils/qrCode';
+
+/** Reads the first copy of the format bits, unmasked */
+function readFormatBits(modules: boolean[][]): number {
+    const positions: [number, number][] = [
+        [8, 0],
+        [8, 1],
+        [8, 2],
+        [8, 3],
+        [8, 4],
+        [8, 5],
+        [8, 7],
+        [8, 8],
+        [7, 8],
+        [5, 8],
+        [4, 8],
+        [3, 8],
+        [2, 8],
+        [1, 8],
+        [0, 8],
+    ];
+    const bits = positions.reduce((value, [x, y], i) => value | (Number(modules[y]![x]) << i), 0);
+    return bits ^ 0x5412;
+}
+
+/** Checks a finder pattern with its top-left corner at (x, y) */
+function hasFinderPattern(modules: boolean[][], x: number, y: number): boolean {
+    for (let dy = 0; dy < 7; dy++) {
+        for (let dx = 0; dx < 7; dx++) {
+            const ring = Math.max(Math.abs(dx - 3), Math.abs(dy - 3));
+            if (modules[y + dy]![x + dx] !== (ring !== 2)) return false;
+        }
+    }
+    return true;
+}
+
+describe('qrCode', () => {
+    describe('reedSolomonRemainder', () => {
+        it('computes the error correction codewords of a 1-M code', () => {
+            const data = [32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17];
+
+            expect(reedSolomonRemainder(data, 10)).toEqual([196, 35, 39, 119, 235, 215, 231, 226, 93, 23]);
+        });
+    });
+
+    describe('encodeQrCode', () => {
+        it('uses the smallest version that fits', () => {
+            expect(encodeQrCode('HELLO WORLD')).toHaveLength(21);
+            expect(encodeQrCode('https://gemini.google.com/app/0123456789abcdef')).toHaveLength(33);
+            expect(encodeQrCode('x'.repeat(QR_CODE_MAX_BYTES))).toHaveLength(177);
+        });
+
+        it('draws the three finder patterns', () => {
+            const modules = encodeQrCode('HELLO WORLD');
+            const size = modules.length;
+
+            expect(hasFinderPattern(modules, 0, 0)).toBe(true);
+            expect(hasFinderPattern(modules, size - 7, 0)).toBe(true);
+            expect(hasFinderPattern(modules, 0, size - 7)).toBe(true);
+        });
+
+        it('writes valid format bits for error correction level M', () => {
+            const format = readFormatBits(encodeQrCode('HELLO WORLD'));
+            let remainder = format >>> 10;
+            for (let i = 0; i < 10; i++) remainder = (remainder << 1) ^ ((remainder >>> 9) * 0x537);
+
+            expect(format >>> 13).toBe(0);
+            expect(format & 0x3ff).toBe(remainder & 0x3ff);
+        });
+
+        it('counts UTF-8 bytes against the capacity', () => {
+            expect(() => encodeQrCode('é'.repeat(Math.ceil(QR_CODE_MAX_BYTES / 2)))).toThrow(/too long/);
+            expect(() => encodeQrCode('x'.repeat(QR_CODE_MAX_BYTES + 1))).toThrow(/too long/);
+        });
+    });
+
+    describe('qrCodeToSvg', () => {
+        it('draws one square per dark module inside the quiet zone', () => {
+            const svg = qrCodeToSvg([
+                [true, false],
+                [false, true],
+            ]);
+
+            expect(svg).toContain('viewBox="0 0 10 10"');
+            expect(svg).toContain('d="M4,4h1v1h-1zM5,5h1v1h-1z"');
+        });
+    });
+});
@@ -0,0 +1,21 @@
+/**
+ * Unit tests for safe mode detection.
+ * @module safeMode.test
+ */
+
+import { describe, it, expect } from 'vitest';
+import { isSafeMode, SAFE_MODE_SWITCH } from '../../../src/main/utils/safeMode';
+
+describe('isSafeMode', () => {
+    it('detects the --safe-mode switch', () => {
+        expect(isSafeMode(['electron', '.', SAFE_MODE_SWITCH])).toBe(true);
+    });
+
+    it('is off without the switch', () => {
+        expect(isSafeMode(['electron', '.', '--log-level=debug'])).toBe(false);
+    });
+
+    it('does not match switch values or prefixes', () => {
+        expect(isSafeMode(['electron', '--safe-mode-extra', 'safe-mode'])).toBe(false);
+    });
+});
@@ -0,0 +1,47 @@
+/**
+ * Unit tests for screenshot helpers.
+ */
+import { describe, it, expect } from 'vitest';
+import { isValidScreenshotOptions, toCaptureRect } from '../../../src/main/utils/screenshot';
+
+const frameRect = { x: 0, y: 40.5, width: 1200, height: 760 };
+
+describe('screenshot', () => {
+    describe('isValidScreenshotOptions', () => {
+        it('accepts known targets and regions only', () => {
+            expect(isValidScreenshotOptions({ target: 'file', region: 'view' })).toBe(true);
+            expect(isValidScreenshotOptions({ target: 'clipboard', region: 'response' })).toBe(true);
+            expect(isValidScreenshotOptions({ target: 'printer', region: 'view' })).toBe(false);
+            expect(isValidScreenshotOptions({ target: 'file' })).toBe(false);
+            expect(isValidScreenshotOptions(null)).toBe(false);
+        });
+    });
+
+    describe('toCaptureRect', () => {
+        it('captures the whole frame in whole pixels', () => {
+            expect(toCaptureRect(frameRect)).toEqual({ x: 0, y: 40, width: 1200, height: 761 });
+        });
+
+        it('offsets a rectangle inside the frame by the frame position', () => {
+            expect(toCaptureRect(frameRect, { x: 100, y: 200, width: 600, height: 300 })).toEqual({
+                x: 100,
+                y: 240,
+                width: 600,
+                height: 301,
+            });
+        });
+
+        it('clips a rectangle that extends past the frame', () => {
+            expect(toCaptureRect(frameRect, { x: 100, y: 500, width: 600, height: 2000 })).toEqual({
+                x: 100,
+                y: 540,
+                width: 600,
+                height: 261,
+            });
+        });
+
+        it('returns null when the rectangle is outside the frame', () => {
+            expect(toCaptureRect(frameRect, { x: 100, y: -500, width: 600, height: 300 })).toBeNull();
+        });
+    });
+});
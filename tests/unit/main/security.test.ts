@@ -4,7 +4,12 @@
 
 import { describe, it, expect, beforeEach, vi, afterEach } from 'vitest';
 import electron from 'electron';
-import { setupHeaderStripping } from '../../../src/main/utils/security';
+import {
+    setupHeaderStripping,
+    normalizeHeaderPolicy,
+    resolveHeaderPolicy,
+    DEFAULT_HEADER_POLICY,
+} from '../../../src/main/utils/security';
 
 describe('setupHeaderStripping', () => {
     const mockSession = electron.session as any;
@@ -145,6 +150,106 @@ describe('setupHeaderStripping', () => {
             expect(result!.responseHeaders['content-type']).toEqual(['text/html']);
         });
     });
+
+    describe('with a custom policy', () => {
+        const run = (headers: Record<string, string[]>, url = 'https://gemini.google.com/app') => {
+            const details = { url, responseHeaders: headers };
+            let result: { responseHeaders: Record<string, string[]> } | undefined;
+            headerCallback(details, (res) => {
+                result = res;
+            });
+            return result!.responseHeaders;
+        };
+
+        it('strips additional configured headers', () => {
+            setupHeaderStripping(mockSession.defaultSession, {
+                ...DEFAULT_HEADER_POLICY,
+                strip: ['x-frame-options', 'Cross-Origin-Opener-Policy'],
+            });
+
+            const headers = run({ 'cross-origin-opener-policy': ['same-origin'], 'content-type': ['text/html'] });
+
+            expect(headers['cross-origin-opener-policy']).toBeUndefined();
+            expect(headers['content-type']).toEqual(['text/html']);
+        });
+
+        it('leaves pass-through headers untouched', () => {
+            setupHeaderStripping(mockSession.defaultSession, {
+                ...DEFAULT_HEADER_POLICY,
+                passThrough: ['content-security-policy'],
+            });
+
+            const headers = run({ 'Content-Security-Policy': ["frame-ancestors 'none'"], 'X-Frame-Options': ['DENY'] });
+
+            expect(headers['Content-Security-Policy']).toEqual(["frame-ancestors 'none'"]);
+            expect(headers['X-Frame-Options']).toBeUndefined();
+        });
+
+        it('applies per-path overrides', () => {
+            setupHeaderStripping(mockSession.defaultSession, {
+                ...DEFAULT_HEADER_POLICY,
+                overrides: [{ pathPrefix: '/static/', strip: [], stripFrameAncestors: false }],
+            });
+
+            const csp = ["frame-ancestors 'none'; default-src 'self'"];
+            const staticHeaders = run(
+                { 'x-frame-options': ['DENY'], 'content-security-policy': csp },
+                'https://gemini.google.com/static/app.js'
+            );
+            const appHeaders = run({ 'x-frame-options': ['DENY'], 'content-security-policy': csp });
+
+            expect(staticHeaders['x-frame-options']).toEqual(['DENY']);
+            expect(staticHeaders['content-security-policy']).toEqual(csp);
+            expect(appHeaders['x-frame-options']).toBeUndefined();
+            expect(appHeaders['content-security-policy'][0]).not.toContain('frame-ancestors');
+        });
+    });
+});
+
+describe('normalizeHeaderPolicy', () => {
+    it('returns defaults for non-object input', () => {
+        expect(normalizeHeaderPolicy(null)).toEqual(DEFAULT_HEADER_POLICY);
+        expect(normalizeHeaderPolicy('strip everything')).toEqual(DEFAULT_HEADER_POLICY);
+    });
+
+    it('lowercases header names and drops non-string entries', () => {
+        const policy = normalizeHeaderPolicy({ strip: ['X-Frame-Options', 42, 'Report-To'] });
+        expect(policy.strip).toEqual(['x-frame-options', 'report-to']);
+    });
+
+    it('falls back per field for invalid values', () => {
+        const policy = normalizeHeaderPolicy({ passThrough: 'all', stripFrameAncestors: 'yes' });
+        expect(policy.passThrough).toEqual(DEFAULT_HEADER_POLICY.passThrough);
+        expect(policy.stripFrameAncestors).toBe(true);
+    });
+
+    it('keeps only overrides with a path prefix', () => {
+        const policy = normalizeHeaderPolicy({
+            overrides: [{ pathPrefix: '/a/', strip: ['Server'] }, { strip: ['x'] }, null],
+        });
+        expect(policy.overrides).toEqual([
+            { pathPrefix: '/a/', strip: ['server'], passThrough: undefined, stripFrameAncestors: undefined },
+        ]);
+    });
+});
+
+describe('resolveHeaderPolicy', () => {
+    const policy = normalizeHeaderPolicy({
+        overrides: [
+            { pathPrefix: '/_/', strip: ['a'] },
+            { pathPrefix: '/_/BardChatUi/', strip: ['b'] },
+        ],
+    });
+
+    it('uses the longest matching prefix', () => {
+        expect(resolveHeaderPolicy(policy, 'https://gemini.google.com/_/BardChatUi/data').strip).toEqual(['b']);
+        expect(resolveHeaderPolicy(policy, 'https://gemini.google.com/_/other').strip).toEqual(['a']);
+    });
+
+    it('uses the base policy when nothing matches or the URL is invalid', () => {
+        expect(resolveHeaderPolicy(policy, 'https://gemini.google.com/app').strip).toEqual(['x-frame-options']);
+        expect(resolveHeaderPolicy(policy, 'not a url').strip).toEqual(['x-frame-options']);
+    });
 });
 
 describe('setupMediaPermissions', () => {
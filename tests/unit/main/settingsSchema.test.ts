@@ -0,0 +1,63 @@
+/**
+ * Unit tests for settings file schema validation.
+ */
+import { describe, it, expect } from 'vitest';
+import {
+    validateSettings,
+    parseErrorToIssue,
+    formatValidationIssue,
+    type SettingsSchema,
+} from '../../../src/main/utils/settingsSchema';
+
+const schema: SettingsSchema = {
+    theme: { type: 'string', enum: ['light', 'dark', 'system'] },
+    alwaysOnTop: { type: 'boolean' },
+    zoomLevel: { type: 'number', min: 50, max: 200 },
+};
+
+describe('validateSettings', () => {
+    it('accepts valid settings and ignores unknown keys', () => {
+        expect(validateSettings({ theme: 'dark', zoomLevel: 125, extra: 1 }, schema)).toEqual([]);
+    });
+
+    it('reports enum violations with the line of the key', () => {
+        const source = '{\n  "alwaysOnTop": true,\n  "theme": "blue"\n}';
+        const issues = validateSettings(JSON.parse(source), schema, source);
+
+        expect(issues).toEqual([
+            { key: 'theme', message: 'theme must be one of light|dark|system (got "blue")', line: 3 },
+        ]);
+        expect(formatValidationIssue(issues[0])).toBe('theme must be one of light|dark|system (got "blue") at line 3');
+    });
+
+    it('reports type and range violations', () => {
+        const issues = validateSettings({ alwaysOnTop: 'yes', zoomLevel: 500 }, schema);
+
+        expect(issues.map((issue) => issue.message)).toEqual([
+            'alwaysOnTop must be a boolean (got "yes")',
+            'zoomLevel must be a number between 50 and 200 (got 500)',
+        ]);
+        expect(issues[0].line).toBeNull();
+    });
+
+    it('rejects files that are not an object', () => {
+        expect(validateSettings([1, 2], schema)[0]).toMatchObject({ key: null, line: 1 });
+    });
+});
+
+describe('parseErrorToIssue', () => {
+    it('locates the line of a JSON syntax error', () => {
+        const source = '{\n  "theme": "dark",\n  oops\n}';
+        let error: Error | undefined;
+        try {
+            JSON.parse(source);
+        } catch (e) {
+            error = e as Error;
+        }
+
+        const issue = parseErrorToIssue(error!, source);
+        expect(issue.key).toBeNull();
+        expect(issue.message).toMatch(/^invalid JSON: /);
+        expect(issue.line).toBe(3);
+    });
+});
@@ -1,7 +1,7 @@
 /**
  * Unit tests for SettingsStore.
  */
-import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
 
 // Mock fs module before importing store
 vi.mock('fs', () => ({
@@ -10,6 +10,17 @@ vi.mock('fs', () => ({
     existsSync: vi.fn(),
 }));
 
+// Mock the data directory lock (read-only mode is toggled per test)
+const { mockIsDataDirReadOnly } = vi.hoisted(() => ({ mockIsDataDirReadOnly: vi.fn().mockReturnValue(false) }));
+vi.mock('../../../src/main/utils/dataDirLock', () => ({
+    isDataDirReadOnly: mockIsDataDirReadOnly,
+}));
+
+const { mockIsSafeMode } = vi.hoisted(() => ({ mockIsSafeMode: vi.fn().mockReturnValue(false) }));
+vi.mock('../../../src/main/utils/safeMode', () => ({
+    isSafeMode: mockIsSafeMode,
+}));
+
 import * as fs from 'fs';
 import SettingsStore from '../../../src/main/store';
 
@@ -94,6 +105,300 @@ describe('SettingsStore', () => {
         });
     });
 
+    describe('schema validation', () => {
+        const schema = { theme: { type: 'string' as const, enum: ['light', 'dark', 'system'] } };
+
+        it('drops invalid values so defaults are used, and reports them', () => {
+            mockFs.readFileSync.mockReturnValue('{\n  "zoomLevel": 150,\n  "theme": "blue"\n}');
+
+            const store = new SettingsStore({ configName: 'test', defaults: { theme: 'system' }, fs: mockFs, schema });
+
+            expect(store.get('theme')).toBe('system');
+            expect(store.get('zoomLevel')).toBe(150);
+            expect(store.getValidationIssues()).toEqual([
+                { key: 'theme', message: 'theme must be one of light|dark|system (got "blue")', line: 3 },
+            ]);
+        });
+
+        it('reports unparseable files', () => {
+            mockFs.readFileSync.mockReturnValue('{ "theme": ');
+
+            const store = new SettingsStore({ configName: 'test', defaults: { theme: 'system' }, fs: mockFs, schema });
+
+            expect(store.get('theme')).toBe('system');
+            expect(store.getValidationIssues()).toEqual([expect.objectContaining({ key: null, line: 1 })]);
+        });
+
+        it('has no issues for valid files', () => {
+            mockFs.readFileSync.mockReturnValue(JSON.stringify({ theme: 'dark' }));
+
+            const store = new SettingsStore({ configName: 'test', defaults: {}, fs: mockFs, schema });
+
+            expect(store.getValidationIssues()).toEqual([]);
+        });
+    });
+
+    describe('environment overrides', () => {
+        afterEach(() => {
+            vi.unstubAllEnvs();
+        });
+
+        it('gives environment variables precedence over the file and defaults', () => {
+            vi.stubEnv('GEMINI_DESKTOP_THEME', 'light');
+            vi.stubEnv('GEMINI_DESKTOP_ZOOM_LEVEL', '125');
+            mockFs.readFileSync.mockReturnValue(JSON.stringify({ theme: 'dark' }));
+
+            const store = new SettingsStore({
+                configName: 'test',
+                defaults: { theme: 'system', zoomLevel: 100, alwaysOnTop: false },
+                fs: mockFs,
+                envPrefix: 'GEMINI_DESKTOP_',
+            });
+
+            expect(store.get('theme')).toBe('light');
+            expect(store.get('zoomLevel')).toBe(125);
+            expect(store.get('alwaysOnTop')).toBe(false);
+            expect(store.getOverriddenKeys()).toEqual(['theme', 'zoomLevel']);
+            expect(store.getAll()).toMatchObject({ theme: 'light', zoomLevel: 125 });
+        });
+
+        it('never writes overridden values to the settings file', () => {
+            vi.stubEnv('GEMINI_DESKTOP_THEME', 'light');
+            mockFs.readFileSync.mockReturnValue(JSON.stringify({ theme: 'dark' }));
+            mockFs.writeFileSync.mockImplementation(() => {});
+
+            const store = new SettingsStore({
+                configName: 'test',
+                defaults: { theme: 'system', zoomLevel: 100 },
+                fs: mockFs,
+                envPrefix: 'GEMINI_DESKTOP_',
+            });
+            store.set('zoomLevel', 150);
+
+            const written = JSON.parse(mockFs.writeFileSync.mock.calls[0][1] as string);
+            expect(written.theme).toBe('dark');
+        });
+
+        it('ignores overrides that fail schema validation', () => {
+            vi.stubEnv('GEMINI_DESKTOP_THEME', 'blue');
+            mockFs.readFileSync.mockReturnValue('{}');
+
+            const store = new SettingsStore({
+                configName: 'test',
+                defaults: { theme: 'system' },
+                fs: mockFs,
+                schema: { theme: { type: 'string', enum: ['light', 'dark', 'system'] } },
+                envPrefix: 'GEMINI_DESKTOP_',
+            });
+
+            expect(store.get('theme')).toBe('system');
+        });
+
+        it('ignores the environment in safe mode', () => {
+            vi.stubEnv('GEMINI_DESKTOP_THEME', 'light');
+            mockIsSafeMode.mockReturnValue(true);
+
+            try {
+                const store = new SettingsStore({
+                    configName: 'test',
+                    defaults: { theme: 'system' },
+                    fs: mockFs,
+                    envPrefix: 'GEMINI_DESKTOP_',
+                });
+
+                expect(store.get('theme')).toBe('system');
+                expect(store.getAll()).toEqual({ theme: 'system' });
+                expect(store.getOverriddenKeys()).toEqual([]);
+            } finally {
+                mockIsSafeMode.mockReturnValue(false);
+            }
+        });
+
+        it('ignores the environment without an envPrefix', () => {
+            vi.stubEnv('GEMINI_DESKTOP_THEME', 'light');
+            mockFs.readFileSync.mockReturnValue('{}');
+
+            const store = new SettingsStore({ configName: 'test', defaults: { theme: 'system' }, fs: mockFs });
+
+            expect(store.get('theme')).toBe('system');
+        });
+    });
+
+    describe('resetKeys', () => {
+        it('restores defaults for the given keys only and saves once', () => {
+            mockFs.existsSync.mockReturnValue(true);
+            mockFs.readFileSync.mockReturnValue(JSON.stringify({ theme: 'dark', zoomLevel: 150, custom: 'value' }));
+            mockFs.writeFileSync.mockImplementation(() => {});
+
+            const store = new SettingsStore({
+                configName: 'test',
+                defaults: { theme: 'system', zoomLevel: 100 },
+                fs: mockFs,
+            });
+
+            expect(store.resetKeys(['theme', 'custom'])).toBe(true);
+            expect(store._data).toEqual({ theme: 'system', zoomLevel: 150 });
+            expect(mockFs.writeFileSync).toHaveBeenCalledTimes(1);
+        });
+    });
+
+    describe('backup', () => {
+        it('writes a timestamped copy next to the settings file', () => {
+            mockFs.existsSync.mockReturnValue(true);
+            mockFs.readFileSync.mockReturnValue(JSON.stringify({ theme: 'dark' }));
+            mockFs.writeFileSync.mockImplementation(() => {});
+
+            const store = new SettingsStore({ configName: 'test', defaults: {}, fs: mockFs });
+            const backupPath = store.backup();
+
+            expect(backupPath).toMatch(/test\.backup-[\dT-]+Z\.json$/);
+            expect(mockFs.writeFileSync).toHaveBeenCalledWith(backupPath, expect.stringContaining('"dark"'), 'utf-8');
+        });
+
+        it('returns null when the data directory is read-only', () => {
+            mockIsDataDirReadOnly.mockReturnValue(true);
+            const store = new SettingsStore({ configName: 'test', defaults: {}, fs: mockFs });
+
+            expect(store.backup()).toBeNull();
+            expect(mockFs.writeFileSync).not.toHaveBeenCalled();
+            mockIsDataDirReadOnly.mockReturnValue(false);
+        });
+    });
+
+    describe('history', () => {
+        const createStore = (history = '[]') => {
+            mockFs.readFileSync.mockImplementation((file) =>
+                String(file).endsWith('.history.json') ? history : JSON.stringify({ theme: 'dark' })
+            );
+            mockFs.writeFileSync.mockImplementation(() => {});
+            return new SettingsStore({
+                configName: 'test',
+                defaults: { theme: 'system', zoomLevel: 100 },
+                fs: mockFs,
+                history: { limit: 3, excludeKeys: ['windowX'] },
+            });
+        };
+
+        it('logs changes with their previous values and saves the log', () => {
+            const store = createStore();
+
+            store.set('theme', 'light');
+            store.set('proxyMode', 'manual');
+
+            expect(store.getHistory()).toEqual([
+                { timestamp: expect.any(Number), key: 'theme', previous: 'dark', value: 'light' },
+                { timestamp: expect.any(Number), key: 'proxyMode', value: 'manual' },
+            ]);
+            expect(mockFs.writeFileSync).toHaveBeenCalledWith(
+                expect.stringContaining('test.history.json'),
+                expect.stringContaining('proxyMode'),
+                'utf-8'
+            );
+        });
+
+        it('skips unchanged values and excluded keys', () => {
+            const store = createStore();
+
+            store.set('theme', 'dark');
+            store.set('windowX', 10);
+
+            expect(store.getHistory()).toEqual([]);
+        });
+
+        it('drops excluded keys from a log saved before they were excluded', () => {
+            const store = createStore(
+                JSON.stringify([
+                    { timestamp: 1, key: 'windowX', previous: 0, value: 10 },
+                    {
+                        timestamp: 2,
+                        key: 'windowX',
+                        value: 20,
+                        batch: [{ timestamp: 2, key: 'zoomLevel', previous: 100, value: 125 }],
+                    },
+                ])
+            );
+
+            expect(store.getHistory()).toEqual([{ timestamp: 2, key: 'zoomLevel', previous: 100, value: 125 }]);
+            const written = mockFs.writeFileSync.mock.calls.find(([file]) => String(file).endsWith('.history.json'));
+            expect(written?.[1]).not.toContain('windowX');
+        });
+
+        it('keeps only the most recent changes', () => {
+            const store = createStore();
+
+            for (const zoom of [110, 120, 130, 140]) store.set('zoomLevel', zoom);
+
+            expect(store.getHistory().map((change) => change.value)).toEqual([120, 130, 140]);
+        });
+
+        it('logs resets', () => {
+            const store = createStore();
+
+            store.resetKeys(['theme']);
+
+            expect(store.getHistory()).toEqual([
+                { timestamp: expect.any(Number), key: 'theme', previous: 'dark', value: 'system' },
+            ]);
+        });
+
+        it('logs setMany as one entry and undoes it together', () => {
+            const store = createStore();
+            store.set('zoomLevel', 120);
+
+            store.setMany({ theme: 'light', proxyMode: 'manual', windowX: 10 });
+
+            expect(store.getHistory()).toEqual([
+                { timestamp: expect.any(Number), key: 'zoomLevel', previous: 100, value: 120 },
+                {
+                    timestamp: expect.any(Number),
+                    key: 'theme',
+                    previous: 'dark',
+                    value: 'light',
+                    batch: [{ timestamp: expect.any(Number), key: 'proxyMode', value: 'manual' }],
+                },
+            ]);
+
+            store.undo(1);
+
+            expect(store._data).toEqual({ theme: 'dark', zoomLevel: 120, windowX: 10 });
+        });
+
+        it('undoes the most recent changes and removes them from the log', () => {
+            const store = createStore();
+            store.set('theme', 'light');
+            store.set('proxyMode', 'manual');
+            store.set('zoomLevel', 150);
+
+            const { undone, saved } = store.undo(2);
+
+            expect(saved).toBe(true);
+            expect(undone.map((change) => change.key)).toEqual(['zoomLevel', 'proxyMode']);
+            expect(store._data).toEqual({ theme: 'light', zoomLevel: 100 });
+            expect(store.getHistory().map((change) => change.key)).toEqual(['theme']);
+        });
+
+        it('loads the saved log', () => {
+            const store = createStore(
+                JSON.stringify([{ timestamp: 1, key: 'theme', previous: 'light', value: 'dark' }])
+            );
+
+            store.undo();
+
+            expect(store.get('theme')).toBe('light');
+        });
+
+        it('ignores an unreadable log', () => {
+            expect(createStore('not json').getHistory()).toEqual([]);
+        });
+
+        it('does nothing when there is nothing to undo', () => {
+            const store = createStore();
+
+            expect(store.undo()).toEqual({ undone: [], saved: true });
+            expect(mockFs.writeFileSync).not.toHaveBeenCalled();
+        });
+    });
+
     it('handles corrupted JSON gracefully', () => {
         mockFs.existsSync.mockReturnValue(true);
         mockFs.readFileSync.mockReturnValue('invalid-json{');
@@ -163,6 +468,32 @@ describe('SettingsStore', () => {
 
             expect(result).toBe(false);
         });
+
+        it('keeps the value in memory but does not write when the data directory is locked', () => {
+            mockFs.readFileSync.mockReturnValue(JSON.stringify({}));
+            mockIsDataDirReadOnly.mockReturnValueOnce(true);
+
+            const store = new SettingsStore({ configName: 'test', defaults: {}, fs: mockFs });
+            const result = store.set('theme', 'dark');
+
+            expect(result).toBe(false);
+            expect(store.get('theme')).toBe('dark');
+            expect(mockFs.writeFileSync).not.toHaveBeenCalled();
+        });
+
+        it('ignores saved settings and never writes in safe mode', () => {
+            mockFs.readFileSync.mockReturnValue(JSON.stringify({ theme: 'light' }));
+            mockIsSafeMode.mockReturnValue(true);
+
+            const store = new SettingsStore({ configName: 'test', defaults: { theme: 'dark' }, fs: mockFs });
+            const result = store.set('theme', 'system');
+
+            expect(mockFs.readFileSync).not.toHaveBeenCalled();
+            expect(result).toBe(false);
+            expect(store.get('theme')).toBe('system');
+            expect(mockFs.writeFileSync).not.toHaveBeenCalled();
+            mockIsSafeMode.mockReturnValue(false);
+        });
     });
 
     describe('getAll', () => {
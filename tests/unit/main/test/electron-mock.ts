@@ -10,6 +10,7 @@ export const app = {
     isPackaged: false,
     whenReady: vi.fn().mockResolvedValue(undefined),
     on: vi.fn(),
+    removeListener: vi.fn(),
     quit: vi.fn(),
     requestSingleInstanceLock: vi.fn().mockReturnValue(true),
     getVersion: vi.fn().mockReturnValue('1.0.0'),
@@ -82,10 +83,18 @@ export class BrowserWindow {
             isMaximized: vi.fn(() => isMaximized),
 
             minimize: vi.fn(),
+            isMinimized: vi.fn(() => false),
+            isFullScreen: vi.fn(() => false),
             reload: vi.fn(),
 
             setSkipTaskbar: vi.fn(),
             setOverlayIcon: vi.fn(),
+            setOpacity: vi.fn(),
+            setIgnoreMouseEvents: vi.fn(),
+            setWindowButtonPosition: vi.fn(),
+            setBackgroundColor: vi.fn(),
+            setBackgroundMaterial: vi.fn(),
+            setVibrancy: vi.fn(),
 
             setAlwaysOnTop: vi.fn((flag) => {
                 isAlwaysOnTop = flag;
@@ -95,6 +104,9 @@ export class BrowserWindow {
             focus: vi.fn(),
             setPosition: vi.fn(),
             setSize: vi.fn(),
+            setBounds: vi.fn(),
+            getBounds: vi.fn(() => ({ x: 100, y: 100, width: 1200, height: 800 })),
+            getNormalBounds: vi.fn(() => ({ x: 100, y: 100, width: 1200, height: 800 })),
             on: vi.fn((event, handler) => {
                 instance._listeners.set(event, handler);
             }),
@@ -160,6 +172,9 @@ export const session = {
             remove: vi.fn(),
         },
         setPermissionRequestHandler: vi.fn(),
+        getSpellCheckerLanguages: vi.fn().mockReturnValue(['en-US']),
+        setSpellCheckerLanguages: vi.fn(),
+        availableSpellCheckerLanguages: ['en-US', 'de', 'fr'],
     },
 };
 
@@ -180,6 +195,10 @@ export const screen = {
         workArea: { x: 0, y: 0 },
         workAreaSize: { width: 1920, height: 1080 },
     }),
+    getAllDisplays: vi.fn().mockReturnValue([{ workArea: { x: 0, y: 0, width: 1920, height: 1040 } }]),
+    getPrimaryDisplay: vi.fn().mockReturnValue({ workArea: { x: 0, y: 0, width: 1920, height: 1040 } }),
+    getDisplayMatching: vi.fn().mockReturnValue({ workArea: { x: 0, y: 0, width: 1920, height: 1040 } }),
+    on: vi.fn(),
     _reset: () => {
         screen.getCursorScreenPoint.mockClear();
         screen.getDisplayNearestPoint.mockClear();
@@ -188,6 +207,7 @@ export const screen = {
 
 export const shell = {
     openExternal: vi.fn().mockResolvedValue(undefined),
+    openPath: vi.fn().mockResolvedValue(''),
 };
 
 export const dialog = {
@@ -0,0 +1,62 @@
+/**
+ * Unit tests for the --test-ipc event stream.
+ * @module testEvents.test
+ */
+
+import { describe, it, expect, vi, afterEach } from 'vitest';
+import {
+    disableTestEvents,
+    emitTestEvent,
+    enableTestEvents,
+    isTestIpcMode,
+    TEST_EVENT_PREFIX,
+    TEST_IPC_SWITCH,
+} from '../../../src/main/utils/testEvents';
+
+describe('testEvents', () => {
+    afterEach(() => {
+        disableTestEvents();
+    });
+
+    it('detects the --test-ipc switch', () => {
+        expect(isTestIpcMode(['electron', '.', TEST_IPC_SWITCH])).toBe(true);
+        expect(isTestIpcMode(['electron', '.', '--test-ipc-extra'])).toBe(false);
+    });
+
+    it('writes nothing while disabled', () => {
+        const write = vi.fn();
+        enableTestEvents(write);
+        disableTestEvents();
+
+        emitTestEvent('app-ready');
+
+        expect(write).not.toHaveBeenCalled();
+    });
+
+    it('writes one numbered JSON line per event', () => {
+        const write = vi.fn();
+        enableTestEvents(write);
+
+        emitTestEvent('app-ready');
+        emitTestEvent('export-finished', { format: 'pdf', stage: 'done' });
+
+        expect(write.mock.calls.map(([line]) => line)).toEqual([
+            `${TEST_EVENT_PREFIX}{"seq":1,"event":"app-ready"}\n`,
+            `${TEST_EVENT_PREFIX}{"seq":2,"event":"export-finished","data":{"format":"pdf","stage":"done"}}\n`,
+        ]);
+    });
+
+    it('restarts the sequence when re-enabled and survives a closed stream', () => {
+        const write = vi.fn(() => {
+            throw new Error('EPIPE');
+        });
+        enableTestEvents(write);
+
+        expect(() => emitTestEvent('app-ready')).not.toThrow();
+
+        const next = vi.fn();
+        enableTestEvents(next);
+        emitTestEvent('app-ready');
+        expect(next).toHaveBeenCalledWith(`${TEST_EVENT_PREFIX}{"seq":1,"event":"app-ready"}\n`);
+    });
+});
@@ -0,0 +1,51 @@
+/**
+ * Unit tests for titlebar effects.
+ */
+import { describe, it, expect, vi, afterEach } from 'vitest';
+import { applyTitlebarEffect, getSupportedTitlebarEffects } from '../../../src/main/utils/titlebarEffects';
+import { restorePlatform, stubPlatform } from '../../helpers/harness';
+
+describe('titlebarEffects', () => {
+    afterEach(() => {
+        restorePlatform();
+    });
+
+    const createWindow = () => ({
+        setVibrancy: vi.fn(),
+        setBackgroundMaterial: vi.fn(),
+        setBackgroundColor: vi.fn(),
+    });
+
+    it('supports vibrancy on macOS and materials on Windows 11 22H2 or later', () => {
+        expect(getSupportedTitlebarEffects('darwin', '24.0.0')).toEqual(['none', 'vibrancy']);
+        expect(getSupportedTitlebarEffects('win32', '10.0.22631')).toEqual(['none', 'mica', 'acrylic']);
+        expect(getSupportedTitlebarEffects('win32', '10.0.19045')).toEqual(['none']);
+        expect(getSupportedTitlebarEffects('linux', '6.8.0')).toEqual(['none']);
+    });
+
+    it('sets the background material and a transparent background on Windows', () => {
+        stubPlatform('win32');
+        const win = createWindow();
+
+        applyTitlebarEffect(win as unknown as Electron.BrowserWindow, 'acrylic');
+        expect(win.setBackgroundMaterial).toHaveBeenLastCalledWith('acrylic');
+        expect(win.setBackgroundColor).toHaveBeenLastCalledWith('#00000000');
+
+        applyTitlebarEffect(win as unknown as Electron.BrowserWindow, 'none');
+        expect(win.setBackgroundMaterial).toHaveBeenLastCalledWith('none');
+        expect(win.setBackgroundColor).toHaveBeenLastCalledWith('#1a1a1a');
+        expect(win.setVibrancy).not.toHaveBeenCalled();
+    });
+
+    it('sets titlebar vibrancy on macOS', () => {
+        stubPlatform('darwin');
+        const win = createWindow();
+
+        applyTitlebarEffect(win as unknown as Electron.BrowserWindow, 'vibrancy');
+        expect(win.setVibrancy).toHaveBeenLastCalledWith('titlebar');
+
+        applyTitlebarEffect(win as unknown as Electron.BrowserWindow, 'none');
+        expect(win.setVibrancy).toHaveBeenLastCalledWith(null);
+        expect(win.setBackgroundMaterial).not.toHaveBeenCalled();
+    });
+});
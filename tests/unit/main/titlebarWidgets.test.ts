@@ -0,0 +1,57 @@
+/**
+ * Unit tests for the titlebar widget registry.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import {
+    clearTitlebarWidgets,
+    getTitlebarState,
+    onTitlebarStateChanged,
+    removeTitlebarWidget,
+    setTitlebarWidget,
+} from '../../../src/main/utils/titlebarWidgets';
+import type { TitlebarWidget } from '../../../src/shared/types/titlebar';
+
+const widget = (id: string, order: number, label = id): TitlebarWidget => ({ id, label, tone: 'info', order });
+
+describe('titlebarWidgets', () => {
+    beforeEach(() => {
+        clearTitlebarWidgets();
+    });
+
+    it('returns widgets sorted by order', () => {
+        setTitlebarWidget(widget('profile', 40));
+        setTitlebarWidget(widget('offline', 10));
+
+        expect(getTitlebarState().widgets.map((w) => w.id)).toEqual(['offline', 'profile']);
+    });
+
+    it('replaces a widget with the same ID', () => {
+        setTitlebarWidget(widget('exports', 30, 'Exporting…'));
+        setTitlebarWidget(widget('exports', 30, 'Exporting 2…'));
+
+        expect(getTitlebarState().widgets).toEqual([widget('exports', 30, 'Exporting 2…')]);
+    });
+
+    it('notifies listeners only when the state changes', () => {
+        const listener = vi.fn();
+        const unsubscribe = onTitlebarStateChanged(listener);
+
+        setTitlebarWidget(widget('offline', 10));
+        setTitlebarWidget(widget('offline', 10));
+        removeTitlebarWidget('offline');
+        removeTitlebarWidget('offline');
+
+        expect(listener).toHaveBeenCalledTimes(2);
+        expect(listener).toHaveBeenLastCalledWith({ widgets: [] });
+        unsubscribe();
+    });
+
+    it('stops notifying after unsubscribe', () => {
+        const listener = vi.fn();
+        onTitlebarStateChanged(listener)();
+
+        setTitlebarWidget(widget('offline', 10));
+
+        expect(listener).not.toHaveBeenCalled();
+    });
+});
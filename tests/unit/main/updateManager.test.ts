@@ -3,6 +3,7 @@ import { app, BrowserWindow } from 'electron';
 import { autoUpdater } from 'electron-updater';
 import UpdateManager from '../../../src/main/managers/updateManager';
 import type SettingsStore from '../../../src/main/store';
+import { endFocusSession, startFocusSession } from '../../../src/main/utils/focusSession';
 import { useFakeTimers, useRealTimers } from '../../helpers/harness';
 
 // Mock dependencies
@@ -329,6 +330,23 @@ describe('UpdateManager', () => {
             expect(autoUpdater.checkForUpdatesAndNotify).toHaveBeenCalled();
         });
 
+        it('skips background checks during a focus session', async () => {
+            (app as any).isPackaged = true;
+            updateManager = new UpdateManager(mockSettingsStore);
+            updateManager.setEnabled(true);
+            startFocusSession(25);
+
+            try {
+                await updateManager.checkForUpdates();
+                expect(autoUpdater.checkForUpdatesAndNotify).not.toHaveBeenCalled();
+
+                await updateManager.checkForUpdates(true);
+                expect(autoUpdater.checkForUpdatesAndNotify).toHaveBeenCalled();
+            } finally {
+                endFocusSession();
+            }
+        });
+
         it('skips if not packaged', async () => {
             (app as any).isPackaged = false;
             updateManager = new UpdateManager(mockSettingsStore);
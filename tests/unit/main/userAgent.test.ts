@@ -0,0 +1,61 @@
+/**
+ * Unit tests for User-Agent overrides.
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+const { mockWebContents } = vi.hoisted(() => ({
+    mockWebContents: { getAllWebContents: vi.fn(() => [] as unknown[]) },
+}));
+
+vi.mock('electron', () => ({ webContents: mockWebContents }));
+
+import {
+    buildChromeUserAgent,
+    validateUserAgentSettings,
+    resolveUserAgent,
+    applyUserAgent,
+} from '../../../src/main/utils/userAgent';
+
+describe('userAgent', () => {
+    beforeEach(() => {
+        vi.clearAllMocks();
+    });
+
+    it('builds Chrome presets from the Chromium major version', () => {
+        expect(buildChromeUserAgent('chrome-windows', '142.0.7444.52')).toBe(
+            'Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36'
+        );
+        expect(buildChromeUserAgent('chrome-linux', '142.0.7444.52')).toContain('(X11; Linux x86_64)');
+    });
+
+    it('resolves presets and custom strings', () => {
+        expect(resolveUserAgent({ preset: 'default', custom: 'ignored' })).toBeNull();
+        expect(resolveUserAgent({ preset: 'custom', custom: '  MyAgent/1.0 ' })).toBe('MyAgent/1.0');
+        expect(resolveUserAgent({ preset: 'chrome-macos', custom: '' })).toContain('Macintosh');
+    });
+
+    it('validates settings', () => {
+        expect(validateUserAgentSettings({ preset: 'chrome-linux', custom: '' })).toBeNull();
+        expect(validateUserAgentSettings({ preset: 'custom', custom: ' ' })).toMatch(/empty/);
+        expect(validateUserAgentSettings({ preset: 'custom', custom: 'a\r\nX-Injected: 1' })).toMatch(/control/);
+        expect(validateUserAgentSettings({ preset: 'custom', custom: 'x'.repeat(600) })).toMatch(/too long/);
+        expect(validateUserAgentSettings({ preset: 'firefox' as 'custom', custom: '' })).toMatch(/Unknown/);
+    });
+
+    it('applies to the session and its web contents, restoring the original for default', () => {
+        const session = { getUserAgent: vi.fn().mockReturnValue('Electron UA'), setUserAgent: vi.fn() };
+        const contents = { session, setUserAgent: vi.fn() };
+        const otherContents = { session: {}, setUserAgent: vi.fn() };
+        mockWebContents.getAllWebContents.mockReturnValue([contents, otherContents]);
+
+        applyUserAgent(session as unknown as Electron.Session, { preset: 'custom', custom: 'MyAgent/1.0' });
+        expect(session.setUserAgent).toHaveBeenLastCalledWith('MyAgent/1.0');
+        expect(contents.setUserAgent).toHaveBeenLastCalledWith('MyAgent/1.0');
+        expect(otherContents.setUserAgent).not.toHaveBeenCalled();
+
+        session.getUserAgent.mockReturnValue('MyAgent/1.0');
+        expect(applyUserAgent(session as unknown as Electron.Session, { preset: 'default', custom: '' })).toBe(
+            'Electron UA'
+        );
+    });
+});
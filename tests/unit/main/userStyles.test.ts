@@ -0,0 +1,47 @@
+/**
+ * Unit tests for user styles.
+ */
+import { describe, it, expect, beforeEach, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { buildUserStylesScript, getUserStylesPath, readUserStyles } from '../../../src/main/utils/userStyles';
+
+describe('userStyles', () => {
+    let dir: string;
+
+    beforeEach(() => {
+        dir = fs.mkdtempSync(path.join(os.tmpdir(), 'gemini-styles-'));
+    });
+
+    afterEach(() => {
+        fs.rmSync(dir, { recursive: true, force: true });
+    });
+
+    it('keeps user.css in the data directory', () => {
+        expect(getUserStylesPath()).toBe(path.join('/mock/userData', 'user.css'));
+    });
+
+    it('reads the user stylesheet', async () => {
+        const filePath = path.join(dir, 'user.css');
+        fs.writeFileSync(filePath, 'nav { display: none; }');
+
+        await expect(readUserStyles(filePath)).resolves.toBe('nav { display: none; }');
+    });
+
+    it('treats a missing stylesheet as empty', async () => {
+        await expect(readUserStyles(path.join(dir, 'missing.css'))).resolves.toBe('');
+    });
+
+    it('embeds the CSS as a string literal in a constructed stylesheet', () => {
+        const script = buildUserStylesScript('a::after { content: "`${x}`"; }');
+
+        expect(script).toContain('sheet.replaceSync("a::after { content: \\"`${x}`\\"; }")');
+        expect(script).toContain('document.adoptedStyleSheets');
+    });
+
+    it('keeps stylesheets with different names separate', () => {
+        expect(buildUserStylesScript('')).toContain('window["__geminiDesktopUserStyles"]');
+        expect(buildUserStylesScript('', '__other')).toContain('window["__other"]');
+    });
+});
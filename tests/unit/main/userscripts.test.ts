@@ -0,0 +1,120 @@
+/**
+ * Unit tests for userscripts.
+ */
+import { describe, it, expect, beforeEach, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import {
+    getUserscriptsDir,
+    isValidUserscriptId,
+    loadUserscripts,
+    matchesUrl,
+    parseEnabledUserscripts,
+    parseUserscript,
+    wrapUserscript,
+} from '../../../src/main/utils/userscripts';
+
+const WIDE_CHAT = `// ==UserScript==
+// @name        Wide chat
+// @description Widen the chat column
+// @match       https://gemini.google.com/app*
+// @match       https://gemini.google.com/gem/*
+// ==/UserScript==
+document.body.classList.add('wide');`;
+
+describe('userscripts', () => {
+    let dir: string;
+
+    beforeEach(() => {
+        dir = fs.mkdtempSync(path.join(os.tmpdir(), 'gemini-scripts-'));
+    });
+
+    afterEach(() => {
+        fs.rmSync(dir, { recursive: true, force: true });
+    });
+
+    it('keeps scripts in the data directory', () => {
+        expect(getUserscriptsDir()).toBe(path.join('/mock/userData', 'scripts'));
+    });
+
+    describe('parseUserscript', () => {
+        it('reads the metadata block', () => {
+            expect(parseUserscript('wide.js', WIDE_CHAT)).toEqual({
+                id: 'wide.js',
+                name: 'Wide chat',
+                description: 'Widen the chat column',
+                matches: ['https://gemini.google.com/app*', 'https://gemini.google.com/gem/*'],
+                source: WIDE_CHAT,
+            });
+        });
+
+        it('defaults to the file name and every Gemini page', () => {
+            const script = parseUserscript('plain.js', 'console.log(1);');
+
+            expect(script.name).toBe('plain.js');
+            expect(script.matches).toEqual(['https://gemini.google.com/*']);
+        });
+    });
+
+    describe('matchesUrl', () => {
+        it('matches wildcards against the whole URL', () => {
+            expect(matchesUrl('https://gemini.google.com/app*', 'https://gemini.google.com/app/abc123')).toBe(true);
+            expect(matchesUrl('https://gemini.google.com/app*', 'https://gemini.google.com/gem/x')).toBe(false);
+            expect(matchesUrl('https://gemini.google.com/app', 'https://gemini.google.com/app?x=1')).toBe(false);
+        });
+
+        it('treats other characters literally', () => {
+            expect(matchesUrl('https://gemini.google.com/app?hl=en', 'https://gemini.google.com/app?hl=en')).toBe(true);
+            expect(matchesUrl('https://gemini.google.com/*', 'https://geminiXgoogle.com/')).toBe(false);
+        });
+    });
+
+    describe('loadUserscripts', () => {
+        it('loads .js files sorted by name', async () => {
+            fs.writeFileSync(path.join(dir, 'b.js'), WIDE_CHAT);
+            fs.writeFileSync(path.join(dir, 'a.js'), 'console.log(1);');
+            fs.writeFileSync(path.join(dir, 'notes.txt'), 'not a script');
+
+            const scripts = await loadUserscripts(dir);
+
+            expect(scripts.map((script) => script.id)).toEqual(['a.js', 'b.js']);
+            expect(scripts[1].name).toBe('Wide chat');
+        });
+
+        it('returns no scripts when the folder does not exist', async () => {
+            await expect(loadUserscripts(path.join(dir, 'missing'))).resolves.toEqual([]);
+        });
+    });
+
+    it('wraps scripts so errors are logged with the script name', () => {
+        const wrapped = wrapUserscript(parseUserscript('wide.js', WIDE_CHAT));
+
+        expect(wrapped).toContain("document.body.classList.add('wide');");
+        expect(wrapped).toContain('console.error("[Userscript] Wide chat:", error)');
+    });
+
+    it('wraps scripts so each runs once per document', () => {
+        const wrapped = wrapUserscript(parseUserscript('count.js', 'window.count = (window.count ?? 0) + 1;'));
+        const page: Record<string, unknown> = {};
+        const run = new Function('window', wrapped);
+
+        run(page);
+        run(page);
+
+        expect(page.count).toBe(1);
+        expect(page.__geminiDesktopUserscripts).toEqual(new Set(['count.js']));
+    });
+
+    it('validates script IDs', () => {
+        expect(isValidUserscriptId('wide.js')).toBe(true);
+        expect(isValidUserscriptId('../wide.js')).toBe(false);
+        expect(isValidUserscriptId('wide.txt')).toBe(false);
+        expect(isValidUserscriptId(42)).toBe(false);
+    });
+
+    it('parses the enabled list, dropping invalid entries', () => {
+        expect(parseEnabledUserscripts('a.js\n\n../b.js\nc.js')).toEqual(['a.js', 'c.js']);
+        expect(parseEnabledUserscripts('')).toEqual([]);
+    });
+});
@@ -0,0 +1,59 @@
+/**
+ * Unit tests for the what's new changelog.
+ */
+import { describe, it, expect } from 'vitest';
+import { CHANGELOG, compareVersions, getWhatsNew } from '../../../src/main/utils/whatsNew';
+import type { ChangelogEntry } from '../../../src/shared/types/whats-new';
+
+const changelog: ChangelogEntry[] = [
+    { version: '0.8.0', highlights: ['Unreleased'] },
+    {
+        version: '0.7.0',
+        highlights: ['New cookie store'],
+        actions: [{ kind: 'sign-in', message: 'Sign in again' }],
+    },
+    { version: '0.6.0', highlights: ['Compact mode'], actions: [{ kind: 'info', message: 'Moved a setting' }] },
+    { version: '0.5.0', highlights: ['Old'] },
+];
+
+describe('whatsNew', () => {
+    describe('compareVersions', () => {
+        it('compares each part numerically', () => {
+            expect(compareVersions('0.10.0', '0.9.0')).toBeGreaterThan(0);
+            expect(compareVersions('1.0.0', '1.0.1')).toBeLessThan(0);
+            expect(compareVersions('1.2', '1.2.0')).toBe(0);
+        });
+
+        it('ignores pre-release suffixes', () => {
+            expect(compareVersions('0.6.0-beta.1', '0.6.0')).toBe(0);
+        });
+    });
+
+    describe('getWhatsNew', () => {
+        it('returns entries after the previous version up to the current one, newest first', () => {
+            const result = getWhatsNew('0.5.1', '0.7.0', changelog);
+
+            expect(result.entries.map((entry) => entry.version)).toEqual(['0.7.0', '0.6.0']);
+            expect(result.previousVersion).toBe('0.5.1');
+            expect(result.currentVersion).toBe('0.7.0');
+        });
+
+        it('lists actions oldest version first', () => {
+            expect(getWhatsNew('0.5.0', '0.7.0', changelog).actions).toEqual([
+                { kind: 'info', message: 'Moved a setting' },
+                { kind: 'sign-in', message: 'Sign in again' },
+            ]);
+        });
+
+        it('shows nothing on a fresh install, when up to date, or after a downgrade', () => {
+            expect(getWhatsNew(null, '0.7.0', changelog).entries).toEqual([]);
+            expect(getWhatsNew('0.7.0', '0.7.0', changelog).entries).toEqual([]);
+            expect(getWhatsNew('0.8.0', '0.7.0', changelog).actions).toEqual([]);
+        });
+
+        it('keeps the shipped changelog newest first', () => {
+            const versions = CHANGELOG.map((entry) => entry.version);
+            expect([...versions].sort((a, b) => compareVersions(b, a))).toEqual(versions);
+        });
+    });
+});
@@ -4,12 +4,15 @@
  * WindowManager now acts as a facade delegating to individual window classes.
  * These tests verify the facade pattern works correctly.
  */
-import { describe, it, expect, vi, beforeEach } from 'vitest';
-import { BrowserWindow } from 'electron';
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { BrowserWindow, screen } from 'electron';
 import WindowManager from '../../../src/main/managers/windowManager';
 
 const mocks = vi.hoisted(() => ({
     isMacOS: false,
+    isLinux: false,
+    safeMode: false,
+    readUserStyles: vi.fn(),
 }));
 
 vi.mock('../../../src/main/utils/constants', async (importOriginal) => {
@@ -19,6 +22,9 @@ vi.mock('../../../src/main/utils/constants', async (importOriginal) => {
         get isMacOS() {
             return mocks.isMacOS;
         },
+        get isLinux() {
+            return mocks.isLinux;
+        },
     };
 });
 
@@ -30,6 +36,18 @@ vi.mock('../../../src/main/utils/paths', async (importOriginal) => {
     };
 });
 
+vi.mock('../../../src/main/utils/safeMode', () => ({
+    isSafeMode: () => mocks.safeMode,
+}));
+
+vi.mock('../../../src/main/utils/userStyles', async (importOriginal) => {
+    const actual = await importOriginal<typeof import('../../../src/main/utils/userStyles')>();
+    return {
+        ...actual,
+        readUserStyles: mocks.readUserStyles,
+    };
+});
+
 describe('WindowManager', () => {
     let windowManager: WindowManager;
 
@@ -104,6 +122,856 @@ describe('WindowManager', () => {
         });
     });
 
+    describe('additional main windows', () => {
+        it('opens a window offset from the focused one and makes it active', () => {
+            const first = windowManager.createMainWindow();
+
+            const second = windowManager.createAdditionalMainWindow() as any;
+
+            expect((BrowserWindow as any)._instances.length).toBe(2);
+            expect(second.setBounds).toHaveBeenCalledWith({ x: 130, y: 130, width: 1200, height: 800 });
+            expect(windowManager.getMainWindow()).toBe(second);
+            expect(windowManager.getMainWindows()).toEqual([first, second]);
+        });
+
+        it('makes the focused main window active', () => {
+            const first = windowManager.createMainWindow() as any;
+            windowManager.createAdditionalMainWindow();
+
+            first._listeners.get('focus')();
+
+            expect(windowManager.getMainWindow()).toBe(first);
+        });
+
+        it('falls back to the first window when the active one closes', () => {
+            const first = windowManager.createMainWindow();
+            const second = windowManager.createAdditionalMainWindow();
+
+            second.close();
+
+            expect(windowManager.getMainWindow()).toBe(first);
+            expect(windowManager.getMainWindows()).toEqual([first]);
+        });
+
+        it('applies zoom to every main window', () => {
+            const first = windowManager.createMainWindow() as any;
+            const second = windowManager.createAdditionalMainWindow() as any;
+
+            windowManager.setZoomLevel(150);
+
+            expect(first.webContents.setZoomFactor).toHaveBeenCalledWith(1.5);
+            expect(second.webContents.setZoomFactor).toHaveBeenCalledWith(1.5);
+        });
+    });
+
+    describe('reloadGemini', () => {
+        const createWindowWithFrames = (urls: string[]) => {
+            const win = windowManager.createMainWindow() as any;
+            const frames = urls.map((url) => ({ url, reload: vi.fn() }));
+            win.webContents.mainFrame = { frames };
+            win.webContents.reload = vi.fn();
+            win.webContents.reloadIgnoringCache = vi.fn();
+            return { win, frames };
+        };
+
+        it('reloads only the Gemini frame', () => {
+            const { win, frames } = createWindowWithFrames(['https://gemini.google.com/app']);
+
+            windowManager.reloadGemini();
+
+            expect(frames[0].reload).toHaveBeenCalled();
+            expect(win.webContents.reload).not.toHaveBeenCalled();
+        });
+
+        it('reloads the window when the Gemini frame is not loaded', () => {
+            const { win } = createWindowWithFrames(['https://accounts.google.com/signin']);
+
+            windowManager.reloadGemini();
+
+            expect(win.webContents.reload).toHaveBeenCalled();
+        });
+
+        it('bypasses the cache on hard reload', () => {
+            const { win, frames } = createWindowWithFrames(['https://gemini.google.com/app']);
+
+            windowManager.reloadGemini(true);
+
+            expect(win.webContents.reloadIgnoringCache).toHaveBeenCalled();
+            expect(frames[0].reload).not.toHaveBeenCalled();
+        });
+
+        it('does nothing without a main window', () => {
+            expect(() => windowManager.reloadGemini()).not.toThrow();
+        });
+    });
+
+    describe('Gemini navigation', () => {
+        const createWindowWithHistory = (canGoBack: boolean, canGoForward: boolean, frameUrls: string[] = []) => {
+            const win = windowManager.createMainWindow() as any;
+            win.webContents.navigationHistory = {
+                canGoBack: vi.fn(() => canGoBack),
+                canGoForward: vi.fn(() => canGoForward),
+                goBack: vi.fn(),
+                goForward: vi.fn(),
+            };
+            const frames = frameUrls.map((url) => ({ url, executeJavaScript: vi.fn(async () => null) }));
+            win.webContents.mainFrame = { frames };
+            win.webContents.reload = vi.fn();
+            return { win, frames };
+        };
+
+        it('reports the navigation state', () => {
+            createWindowWithHistory(true, false);
+            expect(windowManager.getGeminiNavigationState()).toEqual({ canGoBack: true, canGoForward: false });
+        });
+
+        it('reports no history without a main window', () => {
+            expect(windowManager.getGeminiNavigationState()).toEqual({ canGoBack: false, canGoForward: false });
+        });
+
+        it('goes back and forward only when possible', () => {
+            const { win } = createWindowWithHistory(true, false);
+
+            windowManager.goBackGemini();
+            windowManager.goForwardGemini();
+
+            expect(win.webContents.navigationHistory.goBack).toHaveBeenCalled();
+            expect(win.webContents.navigationHistory.goForward).not.toHaveBeenCalled();
+        });
+
+        it('navigates the Gemini frame home', () => {
+            const { frames } = createWindowWithHistory(false, false, ['https://gemini.google.com/app/abc123']);
+
+            windowManager.goHomeGemini();
+
+            expect(frames[0].executeJavaScript).toHaveBeenCalledWith(
+                expect.stringContaining('https://gemini.google.com/app')
+            );
+        });
+
+        it('reloads the window home when the Gemini frame is not loaded', () => {
+            const { win } = createWindowWithHistory(false, false, ['https://accounts.google.com/signin']);
+
+            windowManager.goHomeGemini();
+
+            expect(win.webContents.reload).toHaveBeenCalled();
+        });
+
+        it('navigates the Gemini frame to a conversation', () => {
+            const { frames } = createWindowWithHistory(false, false, ['https://gemini.google.com/app']);
+
+            windowManager.navigateGemini('https://gemini.google.com/app/abc123');
+
+            expect(frames[0].executeJavaScript).toHaveBeenCalledWith(
+                'window.location.assign("https://gemini.google.com/app/abc123")'
+            );
+        });
+
+        it('emits navigation changes when a frame navigates', () => {
+            const { win } = createWindowWithHistory(true, false);
+            const listener = vi.fn();
+            windowManager.on('gemini-navigation-changed', listener);
+
+            const onNavigate = win.webContents.on.mock.calls.find(
+                ([event]: [string]) => event === 'did-navigate-in-page'
+            )[1];
+            onNavigate();
+
+            expect(listener).toHaveBeenCalledWith({ canGoBack: true, canGoForward: false });
+        });
+
+        it('reports the Gemini URL, title and conversation', async () => {
+            const { frames } = createWindowWithHistory(false, false, ['https://gemini.google.com/app/abc123']);
+            frames[0].executeJavaScript.mockResolvedValue(' Trip plan ');
+
+            await expect(windowManager.getGeminiLocation()).resolves.toEqual({
+                url: 'https://gemini.google.com/app/abc123',
+                title: 'Trip plan',
+                conversationId: 'abc123',
+            });
+        });
+
+        it('reports no location when the Gemini frame is not loaded', async () => {
+            createWindowWithHistory(false, false, ['https://accounts.google.com/signin']);
+
+            await expect(windowManager.getGeminiLocation()).resolves.toEqual({
+                url: '',
+                title: '',
+                conversationId: null,
+            });
+        });
+
+        it('emits location changes once per new location', async () => {
+            const { win, frames } = createWindowWithHistory(false, false, ['https://gemini.google.com/app']);
+            frames[0].executeJavaScript.mockResolvedValue('Gemini');
+            const listener = vi.fn();
+            windowManager.on('gemini-location-changed', listener);
+            const onNavigate = win.webContents.on.mock.calls.find(
+                ([event]: [string]) => event === 'did-navigate-in-page'
+            )[1];
+
+            onNavigate();
+            await vi.waitFor(() => expect(listener).toHaveBeenCalledTimes(1));
+            onNavigate();
+            await new Promise((resolve) => setTimeout(resolve, 0));
+
+            expect(listener).toHaveBeenCalledTimes(1);
+            expect(listener).toHaveBeenCalledWith({
+                url: 'https://gemini.google.com/app',
+                title: 'Gemini',
+                conversationId: null,
+            });
+        });
+    });
+
+    describe('immersive mode', () => {
+        const createWindow = () => {
+            const win = windowManager.createMainWindow() as any;
+            win.setFullScreen = vi.fn();
+            return win;
+        };
+
+        const pressKey = (win: any, key: string) => {
+            const onInput = win.webContents.on.mock.calls.find(
+                ([event]: [string]) => event === 'before-input-event'
+            )[1];
+            const event = { preventDefault: vi.fn() };
+            onInput(event, { type: 'keyDown', key });
+            return event;
+        };
+
+        it('goes fullscreen and emits the change', () => {
+            const win = createWindow();
+            const listener = vi.fn();
+            windowManager.on('immersive-mode-changed', listener);
+
+            windowManager.toggleImmersiveMode();
+
+            expect(win.setFullScreen).toHaveBeenCalledWith(true);
+            expect(windowManager.isImmersiveMode()).toBe(true);
+            expect(listener).toHaveBeenCalledWith(true);
+        });
+
+        it('leaves immersive mode on Esc or F11', () => {
+            const win = createWindow();
+
+            for (const key of ['Escape', 'F11']) {
+                windowManager.setImmersiveMode(true);
+                const event = pressKey(win, key);
+
+                expect(event.preventDefault).toHaveBeenCalled();
+                expect(windowManager.isImmersiveMode()).toBe(false);
+                expect(win.setFullScreen).toHaveBeenLastCalledWith(false);
+            }
+        });
+
+        it('leaves Esc to Gemini outside immersive mode', () => {
+            const win = createWindow();
+
+            const event = pressKey(win, 'Escape');
+
+            expect(event.preventDefault).not.toHaveBeenCalled();
+            expect(win.setFullScreen).not.toHaveBeenCalled();
+        });
+
+        it('toggles fullscreen on F11 outside immersive mode', () => {
+            const win = createWindow();
+
+            const event = pressKey(win, 'F11');
+
+            expect(event.preventDefault).toHaveBeenCalled();
+            expect(win.setFullScreen).toHaveBeenCalledWith(true);
+            expect(windowManager.isImmersiveMode()).toBe(false);
+        });
+
+        it('leaves immersive mode when the window leaves fullscreen', () => {
+            const win = createWindow();
+            windowManager.setImmersiveMode(true);
+
+            win._listeners.get('leave-full-screen')();
+
+            expect(windowManager.isImmersiveMode()).toBe(false);
+        });
+    });
+
+    describe('window opacity', () => {
+        it('clamps and applies the opacity to every main window', () => {
+            const first = windowManager.createMainWindow() as any;
+            const second = windowManager.createAdditionalMainWindow() as any;
+            const listener = vi.fn();
+            windowManager.on('window-opacity-changed', listener);
+
+            windowManager.setWindowOpacity(0.05);
+
+            expect(windowManager.getWindowOpacity()).toBe(0.2);
+            expect(first.setOpacity).toHaveBeenCalledWith(0.2);
+            expect(second.setOpacity).toHaveBeenCalledWith(0.2);
+            expect(listener).toHaveBeenCalledWith(0.2);
+        });
+
+        it('ignores invalid values', () => {
+            windowManager.setWindowOpacity(Number.NaN);
+
+            expect(windowManager.getWindowOpacity()).toBe(1);
+        });
+    });
+
+    describe('titlebar height', () => {
+        it('accepts heights in range and notifies listeners', () => {
+            const listener = vi.fn();
+            windowManager.on('titlebar-height-changed', listener);
+
+            expect(windowManager.setTitlebarHeight(48)).toBe(true);
+
+            expect(windowManager.getTitlebarHeight()).toBe(48);
+            expect(listener).toHaveBeenCalledWith(48);
+        });
+
+        it('rejects heights out of range or fractional', () => {
+            expect(windowManager.setTitlebarHeight(10)).toBe(false);
+            expect(windowManager.setTitlebarHeight(100)).toBe(false);
+            expect(windowManager.setTitlebarHeight(40.5)).toBe(false);
+
+            expect(windowManager.getTitlebarHeight()).toBe(32);
+        });
+
+        it('re-centers the macOS traffic lights', () => {
+            mocks.isMacOS = true;
+            try {
+                const win = windowManager.createMainWindow() as any;
+
+                windowManager.setTitlebarHeight(48);
+                expect(win.setWindowButtonPosition).toHaveBeenLastCalledWith({ x: 12, y: 16 });
+
+                windowManager.setTitlebarHeight(32);
+                expect(win.setWindowButtonPosition).toHaveBeenLastCalledWith(null);
+            } finally {
+                mocks.isMacOS = false;
+            }
+        });
+    });
+
+    describe('titlebar effect', () => {
+        it('falls back to none where the effect is unsupported but keeps the choice', () => {
+            vi.spyOn(windowManager, 'getSupportedTitlebarEffects').mockReturnValue(['none']);
+            const win = windowManager.createMainWindow() as any;
+            const listener = vi.fn();
+            windowManager.on('titlebar-effect-changed', listener);
+
+            expect(windowManager.setTitlebarEffect('mica')).toBe(true);
+
+            expect(windowManager.getTitlebarEffect()).toBe('none');
+            expect(listener).toHaveBeenCalledWith('mica');
+            expect(win.setBackgroundColor).not.toHaveBeenCalled();
+        });
+
+        it('applies supported effects to open and new windows', () => {
+            vi.spyOn(windowManager, 'getSupportedTitlebarEffects').mockReturnValue(['none', 'mica', 'acrylic']);
+            const win = windowManager.createMainWindow() as any;
+
+            windowManager.setTitlebarEffect('acrylic');
+            expect(windowManager.getTitlebarEffect()).toBe('acrylic');
+            expect(win.setBackgroundColor).toHaveBeenLastCalledWith('#00000000');
+
+            const second = windowManager.createAdditionalMainWindow() as any;
+            expect(second.setBackgroundColor).toHaveBeenLastCalledWith('#00000000');
+
+            windowManager.setTitlebarEffect('none');
+            expect(win.setBackgroundColor).toHaveBeenLastCalledWith('#1a1a1a');
+        });
+
+        it('rejects unknown effects', () => {
+            expect(windowManager.setTitlebarEffect('glass' as never)).toBe(false);
+            expect(windowManager.getTitlebarEffect()).toBe('none');
+        });
+    });
+
+    describe('overlay mode', () => {
+        it('makes the window translucent, on top and click-through', () => {
+            const win = windowManager.createMainWindow() as any;
+            const listener = vi.fn();
+            windowManager.on('overlay-mode-changed', listener);
+
+            windowManager.toggleOverlayMode();
+
+            expect(windowManager.isOverlayMode()).toBe(true);
+            expect(win.setIgnoreMouseEvents).toHaveBeenCalledWith(true, { forward: true });
+            expect(win.setAlwaysOnTop).toHaveBeenCalledWith(true, 'floating');
+            expect(win.setOpacity).toHaveBeenCalledWith(0.85);
+            expect(listener).toHaveBeenCalledWith(true);
+        });
+
+        it('restores the window when left', () => {
+            const win = windowManager.createMainWindow() as any;
+            windowManager.setWindowOpacity(0.6);
+            windowManager.setOverlayMode(true);
+
+            windowManager.setOverlayMode(false);
+
+            expect(win.setIgnoreMouseEvents).toHaveBeenLastCalledWith(false);
+            expect(win.setAlwaysOnTop).toHaveBeenLastCalledWith(false);
+            expect(win.setOpacity).toHaveBeenLastCalledWith(0.6);
+        });
+
+        it('is left when the window is restored from the tray', () => {
+            windowManager.createMainWindow();
+            windowManager.setOverlayMode(true);
+
+            windowManager.restoreFromTray();
+
+            expect(windowManager.isOverlayMode()).toBe(false);
+        });
+
+        it('is not entered on Linux', () => {
+            mocks.isLinux = true;
+            const win = windowManager.createMainWindow() as any;
+
+            windowManager.setOverlayMode(true);
+
+            expect(windowManager.isOverlayMode()).toBe(false);
+            expect(win.setIgnoreMouseEvents).not.toHaveBeenCalled();
+            mocks.isLinux = false;
+        });
+    });
+
+    describe('fullscreen', () => {
+        it('toggles fullscreen on the focused window', () => {
+            const win = windowManager.createMainWindow() as any;
+            win.setFullScreen = vi.fn();
+
+            windowManager.toggleFullScreen();
+
+            expect(win.setFullScreen).toHaveBeenCalledWith(true);
+        });
+
+        it('leaves immersive mode instead of toggling fullscreen', () => {
+            const win = windowManager.createMainWindow() as any;
+            win.setFullScreen = vi.fn();
+            windowManager.setImmersiveMode(true);
+
+            windowManager.toggleFullScreen();
+
+            expect(windowManager.isImmersiveMode()).toBe(false);
+            expect(win.setFullScreen).toHaveBeenLastCalledWith(false);
+        });
+
+        it('emits fullscreen-changed when the window enters or leaves fullscreen', () => {
+            const win = windowManager.createMainWindow() as any;
+            const listener = vi.fn();
+            windowManager.on('fullscreen-changed', listener);
+
+            win._listeners.get('enter-full-screen')();
+            win._listeners.get('leave-full-screen')();
+
+            expect(listener).toHaveBeenNthCalledWith(1, true, win);
+            expect(listener).toHaveBeenNthCalledWith(2, false, win);
+        });
+    });
+
+    describe('compact mode', () => {
+        let frame: { url: string; executeJavaScript: ReturnType<typeof vi.fn> };
+
+        const createWindow = () => {
+            const win = windowManager.createMainWindow() as any;
+            frame = { url: 'https://gemini.google.com/app', executeJavaScript: vi.fn().mockResolvedValue(undefined) };
+            win.webContents.mainFrame = { frames: [frame] };
+            return win;
+        };
+
+        it('docks the window at the right edge and hides the sidebar', () => {
+            const win = createWindow();
+            const listener = vi.fn();
+            windowManager.on('compact-mode-changed', listener);
+
+            windowManager.toggleCompactMode();
+
+            expect(win.setBounds).toHaveBeenLastCalledWith({ x: 1500, y: 0, width: 420, height: 1040 });
+            expect(frame.executeJavaScript).toHaveBeenCalledWith(expect.stringContaining('bard-sidenav'));
+            expect(windowManager.isCompactMode()).toBe(true);
+            expect(listener).toHaveBeenCalledWith(true, win);
+        });
+
+        it('restores the previous bounds and maximized state on exit', () => {
+            const win = createWindow();
+            win.maximize();
+
+            windowManager.setCompactMode(true);
+            expect(win.isMaximized()).toBe(false);
+
+            windowManager.setCompactMode(false);
+            expect(win.setBounds).toHaveBeenLastCalledWith({ x: 100, y: 100, width: 1200, height: 800 });
+            expect(win.isMaximized()).toBe(true);
+            expect(frame.executeJavaScript).toHaveBeenLastCalledWith(expect.not.stringContaining('bard-sidenav'));
+        });
+
+        it('keeps the sidebar when the preference is off', () => {
+            createWindow();
+            windowManager.setCompactModeHidesSidebar(false);
+
+            windowManager.setCompactMode(true);
+
+            expect(frame.executeJavaScript).toHaveBeenCalledWith(expect.not.stringContaining('bard-sidenav'));
+        });
+
+        it('does not enter compact mode while fullscreen', () => {
+            const win = createWindow();
+            win.isFullScreen.mockReturnValue(true);
+
+            windowManager.setCompactMode(true);
+
+            expect(win.setBounds).not.toHaveBeenCalled();
+            expect(windowManager.isCompactMode()).toBe(false);
+        });
+    });
+
+    describe('displays', () => {
+        const primary = {
+            id: 1,
+            scaleFactor: 1,
+            size: { width: 1920, height: 1080 },
+            workArea: { x: 0, y: 0, width: 1920, height: 1040 },
+        };
+        const secondary = {
+            id: 2,
+            scaleFactor: 2,
+            size: { width: 2560, height: 1440 },
+            workArea: { x: 1920, y: 0, width: 2560, height: 1400 },
+        };
+
+        beforeEach(() => {
+            vi.mocked(screen.getAllDisplays).mockReturnValue([primary, secondary] as any);
+            vi.mocked(screen.getPrimaryDisplay).mockReturnValue(primary as any);
+            vi.mocked(screen.getDisplayMatching).mockImplementation(
+                (bounds) => (bounds.x >= secondary.workArea.x ? secondary : primary) as any
+            );
+        });
+
+        afterEach(() => {
+            const workArea = { x: 0, y: 0, width: 1920, height: 1040 };
+            vi.mocked(screen.getAllDisplays).mockReturnValue([{ workArea }] as any);
+            vi.mocked(screen.getPrimaryDisplay).mockReturnValue({ workArea } as any);
+            vi.mocked(screen.getDisplayMatching).mockReset().mockReturnValue({ workArea } as any);
+        });
+
+        it('lists displays, marking the primary one and the one the window is on', () => {
+            windowManager.createMainWindow();
+
+            expect(windowManager.getDisplays()).toEqual([
+                {
+                    index: 0,
+                    label: 'Display 1 (1920×1080)',
+                    workArea: primary.workArea,
+                    scaleFactor: 1,
+                    primary: true,
+                    current: true,
+                },
+                {
+                    index: 1,
+                    label: 'Display 2 (2560×1440)',
+                    workArea: secondary.workArea,
+                    scaleFactor: 2,
+                    primary: false,
+                    current: false,
+                },
+            ]);
+        });
+
+        it('centers the window on the target display, applying the bounds again for a new scale factor', () => {
+            const win = windowManager.createMainWindow() as any;
+
+            expect(windowManager.moveToDisplay(1)).toBe(true);
+
+            expect(win.setBounds).toHaveBeenCalledTimes(2);
+            expect(win.setBounds).toHaveBeenLastCalledWith({ x: 2600, y: 300, width: 1200, height: 800 });
+        });
+
+        it('keeps a maximized window maximized', () => {
+            const win = windowManager.createMainWindow() as any;
+            win.maximize();
+
+            windowManager.moveToDisplay(1);
+
+            expect(win.unmaximize).toHaveBeenCalled();
+            expect(win.setBounds).toHaveBeenLastCalledWith({ x: 2600, y: 300, width: 1200, height: 800 });
+            expect(win.isMaximized()).toBe(true);
+        });
+
+        it('docks a compact window on the target display and restores it there', () => {
+            const win = windowManager.createMainWindow() as any;
+            windowManager.setCompactMode(true);
+
+            windowManager.moveToDisplay(1);
+            expect(win.setBounds).toHaveBeenLastCalledWith({ x: 4060, y: 0, width: 420, height: 1400 });
+
+            windowManager.setCompactMode(false);
+            expect(win.setBounds).toHaveBeenLastCalledWith({ x: 2600, y: 300, width: 1200, height: 800 });
+        });
+
+        it('does not move to an unknown display or while fullscreen', () => {
+            const win = windowManager.createMainWindow() as any;
+
+            expect(windowManager.moveToDisplay(5)).toBe(false);
+            win.isFullScreen.mockReturnValue(true);
+            expect(windowManager.moveToDisplay(1)).toBe(false);
+            expect(win.setBounds).not.toHaveBeenCalled();
+        });
+
+        it('wraps around to the first display', () => {
+            const win = windowManager.createMainWindow() as any;
+            win.getBounds.mockReturnValue({ x: 2600, y: 300, width: 1200, height: 800 });
+
+            windowManager.moveToNextDisplay();
+
+            expect(win.setBounds).toHaveBeenLastCalledWith({ x: 360, y: 120, width: 1200, height: 800 });
+        });
+
+        it('reapplies zoom and bounds when the window is moved to a display with another scale factor', () => {
+            const win = windowManager.createMainWindow() as any;
+            windowManager.setZoomLevel(125);
+            win.webContents.setZoomFactor.mockClear();
+            const bounds = { x: 2600, y: 300, width: 1200, height: 800 };
+            win.getBounds.mockReturnValue(bounds);
+
+            win._listeners.get('moved')();
+
+            expect(win.webContents.setZoomFactor).toHaveBeenCalledWith(1.25);
+            expect(win.setBounds).toHaveBeenCalledWith(bounds);
+        });
+
+        it('does nothing when the window stays on displays with the same scale factor', () => {
+            const win = windowManager.createMainWindow() as any;
+
+            win._listeners.get('moved')();
+
+            expect(win.webContents.setZoomFactor).not.toHaveBeenCalled();
+            expect(win.setBounds).not.toHaveBeenCalled();
+        });
+
+        it("reapplies bounds when the scaling of the window's display changes", () => {
+            const win = windowManager.createMainWindow() as any;
+            const [, listener] = (screen.on as any).mock.calls.find(
+                ([event]: [string]) => event === 'display-metrics-changed'
+            );
+            vi.mocked(screen.getDisplayMatching).mockReturnValue({ ...primary, scaleFactor: 1.5 } as any);
+
+            listener({}, primary, ['workArea']);
+            expect(win.setBounds).not.toHaveBeenCalled();
+
+            listener({}, primary, ['scaleFactor']);
+            expect(win.setBounds).toHaveBeenCalledWith({ x: 100, y: 100, width: 1200, height: 800 });
+        });
+
+        it('leaves maximized windows alone when the scale factor changes', () => {
+            const win = windowManager.createMainWindow() as any;
+            win.maximize();
+            win.getBounds.mockReturnValue({ x: 2600, y: 300, width: 1200, height: 800 });
+
+            win._listeners.get('moved')();
+
+            expect(win.webContents.setZoomFactor).toHaveBeenCalled();
+            expect(win.setBounds).not.toHaveBeenCalled();
+        });
+    });
+
+    describe('window state', () => {
+        const savedState = {
+            bounds: { x: 200, y: 150, width: 1000, height: 700 },
+            isMaximized: true,
+            isFullScreen: false,
+        };
+
+        it('restores the saved bounds and maximizes once shown', () => {
+            windowManager.setSavedWindowState(savedState);
+
+            const win = windowManager.createMainWindow() as any;
+
+            expect(win.setBounds).toHaveBeenCalledWith(savedState.bounds);
+            expect(win.maximize).not.toHaveBeenCalled();
+            win._listeners.get('show')();
+            expect(win.maximize).toHaveBeenCalled();
+        });
+
+        it('centers the window on the primary display when its monitor is gone', () => {
+            windowManager.setSavedWindowState({ ...savedState, bounds: { x: 3000, y: 150, width: 1000, height: 700 } });
+
+            const win = windowManager.createMainWindow() as any;
+
+            expect(win.setBounds).toHaveBeenCalledWith({ x: 460, y: 170, width: 1000, height: 700 });
+        });
+
+        it('uses the default bounds without a saved state', () => {
+            const win = windowManager.createMainWindow() as any;
+
+            expect(win.setBounds).not.toHaveBeenCalled();
+        });
+
+        it('emits the state once the window stops moving', () => {
+            vi.useFakeTimers();
+            try {
+                const win = windowManager.createMainWindow() as any;
+                const listener = vi.fn();
+                windowManager.on('window-state-changed', listener);
+
+                win._listeners.get('move')();
+                win._listeners.get('move')();
+                vi.advanceTimersByTime(500);
+
+                expect(listener).toHaveBeenCalledTimes(1);
+                expect(listener).toHaveBeenCalledWith({
+                    bounds: { x: 100, y: 100, width: 1200, height: 800 },
+                    isMaximized: false,
+                    isFullScreen: false,
+                });
+            } finally {
+                vi.useRealTimers();
+            }
+        });
+
+        it('does not record a minimized window', () => {
+            const win = windowManager.createMainWindow() as any;
+            win.isMinimized.mockReturnValue(true);
+            const listener = vi.fn();
+            windowManager.on('window-state-changed', listener);
+
+            win._listeners.get('close')();
+
+            expect(listener).not.toHaveBeenCalled();
+        });
+    });
+
+    describe('printGemini', () => {
+        it('prints from inside the Gemini frame', () => {
+            const win = windowManager.createMainWindow() as any;
+            const frame = {
+                url: 'https://gemini.google.com/app',
+                executeJavaScript: vi.fn().mockResolvedValue(undefined),
+            };
+            win.webContents.mainFrame = { frames: [frame] };
+
+            windowManager.printGemini();
+
+            expect(frame.executeJavaScript).toHaveBeenCalledWith('window.print()', true);
+        });
+
+        it('does nothing when the Gemini frame is not loaded', () => {
+            const win = windowManager.createMainWindow() as any;
+            win.webContents.mainFrame = { frames: [] };
+
+            expect(() => windowManager.printGemini()).not.toThrow();
+        });
+    });
+
+    describe('user styles', () => {
+        let frame: { url: string; processId: number; routingId: number; executeJavaScript: ReturnType<typeof vi.fn> };
+
+        beforeEach(() => {
+            mocks.safeMode = false;
+            mocks.readUserStyles.mockResolvedValue('nav { display: none; }');
+            frame = {
+                url: 'https://gemini.google.com/app',
+                processId: 4,
+                routingId: 7,
+                executeJavaScript: vi.fn().mockResolvedValue(undefined),
+            };
+            const win = windowManager.createMainWindow() as any;
+            win.webContents.mainFrame = { frames: [frame] };
+        });
+
+        it('applies user.css to the Gemini frame', async () => {
+            await expect(windowManager.applyUserStyles()).resolves.toBe(true);
+
+            expect(frame.executeJavaScript).toHaveBeenCalledWith(expect.stringContaining('"nav { display: none; }"'));
+        });
+
+        it('does not apply user styles in safe mode', async () => {
+            mocks.safeMode = true;
+
+            await expect(windowManager.applyUserStyles()).resolves.toBe(false);
+
+            expect(mocks.readUserStyles).not.toHaveBeenCalled();
+            expect(frame.executeJavaScript).not.toHaveBeenCalled();
+        });
+
+        it('applies user styles and emits gemini-frame-loaded when a frame loads', () => {
+            const win = windowManager.getMainWindow() as any;
+            const listener = vi.fn();
+            windowManager.on('gemini-frame-loaded', listener);
+
+            const onFrameLoad = win.webContents.on.mock.calls.find(
+                ([event]: [string]) => event === 'did-frame-finish-load'
+            )[1];
+            onFrameLoad({}, true, 4, 1);
+            expect(listener).not.toHaveBeenCalled();
+
+            onFrameLoad({}, false, 4, 7);
+            expect(listener).toHaveBeenCalledTimes(1);
+            expect(mocks.readUserStyles).toHaveBeenCalled();
+        });
+
+        it('ignores frames nested inside the Gemini frame', () => {
+            const win = windowManager.getMainWindow() as any;
+            const listener = vi.fn();
+            windowManager.on('gemini-frame-loaded', listener);
+
+            const onFrameLoad = win.webContents.on.mock.calls.find(
+                ([event]: [string]) => event === 'did-frame-finish-load'
+            )[1];
+            onFrameLoad({}, false, 4, 12);
+
+            expect(listener).not.toHaveBeenCalled();
+            expect(mocks.readUserStyles).not.toHaveBeenCalled();
+        });
+
+        it('reports a reload that fails', async () => {
+            mocks.readUserStyles.mockRejectedValue(new Error('EACCES'));
+            const win = windowManager.getMainWindow() as any;
+
+            await windowManager.reloadUserStyles();
+
+            expect(win.webContents.send).toHaveBeenCalledWith(
+                'toast:show',
+                expect.objectContaining({ type: 'error', message: 'Failed to reload user styles: EACCES' })
+            );
+        });
+    });
+
+    describe('toggleGeminiDevTools', () => {
+        const createWindowWithDevTools = (frameUrls: string[], opened = false) => {
+            const win = windowManager.createMainWindow() as any;
+            win.getContentSize = vi.fn().mockReturnValue([1200, 801]);
+            win.webContents.mainFrame = { frames: frameUrls.map((url) => ({ url })) };
+            win.webContents.isDevToolsOpened = vi.fn().mockReturnValue(opened);
+            win.webContents.closeDevTools = vi.fn();
+            win.webContents.inspectElement = vi.fn();
+            return win;
+        };
+
+        it('inspects a point inside the Gemini frame', () => {
+            const win = createWindowWithDevTools(['https://gemini.google.com/app']);
+
+            windowManager.toggleGeminiDevTools();
+
+            expect(win.webContents.inspectElement).toHaveBeenCalledWith(600, 401);
+        });
+
+        it('closes DevTools when they are already open', () => {
+            const win = createWindowWithDevTools(['https://gemini.google.com/app'], true);
+
+            windowManager.toggleGeminiDevTools();
+
+            expect(win.webContents.closeDevTools).toHaveBeenCalled();
+            expect(win.webContents.inspectElement).not.toHaveBeenCalled();
+        });
+
+        it('does nothing when the Gemini frame is not loaded', () => {
+            const win = createWindowWithDevTools(['https://accounts.google.com/signin']);
+
+            windowManager.toggleGeminiDevTools();
+
+            expect(win.webContents.inspectElement).not.toHaveBeenCalled();
+        });
+    });
+
     describe('getQuickChatWindow', () => {
         it('returns null when no window exists', () => {
             expect(windowManager.getQuickChatWindow()).toBeNull();
@@ -0,0 +1,82 @@
+/**
+ * Unit tests for main window state restoration.
+ * @module windowState.test
+ */
+
+import { describe, it, expect } from 'vitest';
+import { centerInWorkArea, fitWindowBounds, isWindowBounds } from '../../../src/main/utils/windowState';
+
+const primary = { x: 0, y: 0, width: 1920, height: 1040 };
+const secondary = { x: 1920, y: 0, width: 1280, height: 1000 };
+
+describe('fitWindowBounds', () => {
+    it('keeps bounds that are on a monitor', () => {
+        const bounds = { x: 2000, y: 100, width: 1000, height: 700 };
+
+        expect(fitWindowBounds(bounds, [primary, secondary], primary)).toEqual(bounds);
+    });
+
+    it('centers the window on the primary monitor when its monitor is gone', () => {
+        const bounds = { x: 2000, y: 100, width: 1000, height: 700 };
+
+        expect(fitWindowBounds(bounds, [primary], primary)).toEqual({ x: 460, y: 170, width: 1000, height: 700 });
+    });
+
+    it('centers the window when only a sliver of the titlebar is visible', () => {
+        const bounds = { x: 1880, y: 100, width: 1000, height: 700 };
+
+        expect(fitWindowBounds(bounds, [primary], primary)).toEqual({ x: 460, y: 170, width: 1000, height: 700 });
+    });
+
+    it('counts a taller titlebar as reachable', () => {
+        const bounds = { x: 100, y: -40, width: 1000, height: 700 };
+
+        expect(fitWindowBounds(bounds, [primary], primary)).toEqual({ x: 460, y: 170, width: 1000, height: 700 });
+        expect(fitWindowBounds(bounds, [primary], primary, 48)).toEqual({ x: 100, y: 0, width: 1000, height: 700 });
+    });
+
+    it('shrinks the window to fit its monitor', () => {
+        const bounds = { x: 1950, y: 0, width: 2500, height: 1400 };
+
+        expect(fitWindowBounds(bounds, [primary, secondary], primary)).toEqual({
+            x: 1950,
+            y: 0,
+            width: 1280,
+            height: 1000,
+        });
+    });
+
+    it('moves the titlebar below the top of the monitor', () => {
+        const bounds = { x: 100, y: -20, width: 1000, height: 700 };
+
+        expect(fitWindowBounds(bounds, [primary], primary)).toEqual({ x: 100, y: 0, width: 1000, height: 700 });
+    });
+});
+
+describe('centerInWorkArea', () => {
+    it('centers the size in the work area', () => {
+        expect(centerInWorkArea({ width: 1000, height: 700 }, secondary)).toEqual({
+            x: 2060,
+            y: 150,
+            width: 1000,
+            height: 700,
+        });
+    });
+
+    it('shrinks a size larger than the work area', () => {
+        expect(centerInWorkArea({ width: 2500, height: 1400 }, secondary)).toEqual(secondary);
+    });
+});
+
+describe('isWindowBounds', () => {
+    it('accepts finite positions and positive sizes', () => {
+        expect(isWindowBounds({ x: -1200, y: 0, width: 800, height: 600 })).toBe(true);
+    });
+
+    it('rejects missing or invalid values', () => {
+        expect(isWindowBounds({ x: 0, y: 0, width: 800 })).toBe(false);
+        expect(isWindowBounds({ x: 0, y: 0, width: 0, height: 600 })).toBe(false);
+        expect(isWindowBounds({ x: NaN, y: 0, width: 800, height: 600 })).toBe(false);
+        expect(isWindowBounds(null)).toBe(false);
+    });
+});
@@ -117,6 +117,27 @@ describe('WindowManager Zoom', () => {
         });
     });
 
+    describe('resetZoom', () => {
+        it('resets zoom to 100%', () => {
+            windowManager.createMainWindow();
+            windowManager.setZoomLevel(150);
+            windowManager.resetZoom();
+            expect(windowManager.getZoomLevel()).toBe(100);
+        });
+    });
+
+    describe('Zoom reapplied on window creation', () => {
+        it('applies the saved zoom once the new window finishes loading', () => {
+            windowManager.initializeZoomLevel(125);
+            const win = windowManager.createMainWindow() as any;
+
+            const onLoad = win.webContents.once.mock.calls.find(([event]: [string]) => event === 'did-finish-load')[1];
+            onLoad();
+
+            expect(win.webContents.setZoomFactor).toHaveBeenCalledWith(1.25);
+        });
+    });
+
     describe('zoom-level-changed Event', () => {
         // 4.3 Test zoom level persistence via event
         it('emits zoom-level-changed event when zoom changes', () => {
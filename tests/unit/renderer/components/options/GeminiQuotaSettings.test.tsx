@@ -0,0 +1,51 @@
+/**
+ * Unit tests for GeminiQuotaSettings component.
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, fireEvent } from '@testing-library/react';
+import { GeminiQuotaSettings } from '../../../../../src/renderer/components/options/GeminiQuotaSettings';
+import { setupMockElectronAPI } from '../../../../helpers/mocks';
+
+describe('GeminiQuotaSettings', () => {
+    const mockSetGeminiQuota = vi.fn();
+
+    const setup = (status: Record<string, unknown>) => {
+        setupMockElectronAPI({
+            getGeminiQuotaStatus: vi.fn().mockResolvedValue({
+                plan: 'off',
+                customDailyPrompts: 0,
+                dailyPrompts: 0,
+                used: 0,
+                remaining: 0,
+                level: 'ok',
+                ...status,
+            }),
+            setGeminiQuota: mockSetGeminiQuota,
+            onGeminiQuotaChanged: vi.fn().mockReturnValue(() => {}),
+        });
+        render(<GeminiQuotaSettings />);
+    };
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+    });
+
+    it('shows the estimate for the chosen plan', async () => {
+        setup({ plan: 'pro', dailyPrompts: 100, used: 40, remaining: 60 });
+
+        expect(await screen.findByTestId('gemini-quota-plan-select')).toHaveValue('pro');
+        expect(screen.getByTestId('gemini-quota-hint')).toHaveTextContent('About 60 of 100 prompts left today');
+        expect(screen.queryByTestId('gemini-quota-custom-input')).not.toBeInTheDocument();
+    });
+
+    it('saves the plan and a custom daily quota', async () => {
+        setup({});
+
+        fireEvent.change(await screen.findByTestId('gemini-quota-plan-select'), { target: { value: 'custom' } });
+        expect(mockSetGeminiQuota).toHaveBeenCalledWith({ plan: 'custom', customDailyPrompts: 0 });
+
+        fireEvent.change(screen.getByTestId('gemini-quota-custom-input'), { target: { value: '25' } });
+        expect(mockSetGeminiQuota).toHaveBeenLastCalledWith({ plan: 'custom', customDailyPrompts: 25 });
+    });
+});
@@ -0,0 +1,66 @@
+/**
+ * Unit tests for OpacitySettings component.
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, fireEvent, waitFor } from '@testing-library/react';
+import { OpacitySettings } from '../../../../../src/renderer/components/options/OpacitySettings';
+import { setupMockElectronAPI } from '../../../../helpers/mocks';
+
+describe('OpacitySettings', () => {
+    const mockGetWindowOpacity = vi.fn();
+    const mockSetWindowOpacity = vi.fn();
+    const mockGetCapabilityReport = vi.fn();
+
+    const report = (available: boolean) => ({
+        platform: available ? 'win32' : 'linux',
+        versions: { electron: '39.0.0', chrome: '142.0.0.0' },
+        capabilities: {
+            windowOpacity: available
+                ? { available }
+                : { available, reason: 'Window opacity and overlay mode are not supported on Linux' },
+        },
+    });
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockGetWindowOpacity.mockResolvedValue({ opacity: 0.8 });
+        mockGetCapabilityReport.mockResolvedValue(report(true));
+
+        setupMockElectronAPI({
+            getWindowOpacity: mockGetWindowOpacity,
+            setWindowOpacity: mockSetWindowOpacity,
+            onWindowOpacityChanged: vi.fn().mockReturnValue(() => {}),
+            getCapabilityReport: mockGetCapabilityReport,
+        });
+    });
+
+    it('shows the saved opacity', async () => {
+        render(<OpacitySettings />);
+
+        await waitFor(() => {
+            expect(screen.getByTestId('opacity-value')).toHaveTextContent('80%');
+        });
+        expect(screen.getByTestId('opacity-slider')).toHaveValue('80');
+    });
+
+    it('sets the opacity when the slider moves', async () => {
+        render(<OpacitySettings />);
+
+        fireEvent.change(await screen.findByTestId('opacity-slider'), { target: { value: '55' } });
+
+        expect(mockSetWindowOpacity).toHaveBeenCalledWith(0.55);
+        expect(screen.getByTestId('opacity-value')).toHaveTextContent('55%');
+    });
+
+    it('is disabled with the reason where opacity is unsupported', async () => {
+        mockGetCapabilityReport.mockResolvedValue(report(false));
+
+        render(<OpacitySettings />);
+
+        await waitFor(() => {
+            expect(screen.getByTestId('opacity-slider')).toBeDisabled();
+        });
+        expect(screen.getByTestId('opacity-hint')).toHaveTextContent('not supported on Linux');
+    });
+});
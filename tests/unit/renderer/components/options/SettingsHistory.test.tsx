@@ -0,0 +1,87 @@
+/**
+ * Unit tests for SettingsHistory component.
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, fireEvent, waitFor } from '@testing-library/react';
+import { SettingsHistory } from '../../../../../src/renderer/components/options/SettingsHistory';
+import { setupMockElectronAPI } from '../../../../helpers/mocks';
+
+describe('SettingsHistory', () => {
+    const mockGetSettingsHistory = vi.fn();
+    const mockUndoSettingsChange = vi.fn();
+    const history = [
+        { timestamp: 2, key: 'proxyMode', previous: 'system', value: 'manual' },
+        { timestamp: 1, key: 'theme', value: 'dark' },
+    ];
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        vi.spyOn(window, 'confirm').mockReturnValue(true);
+        mockGetSettingsHistory.mockResolvedValue(history);
+        mockUndoSettingsChange.mockResolvedValue({ success: true, undone: history, restartRecommended: true });
+
+        setupMockElectronAPI({
+            getSettingsHistory: mockGetSettingsHistory,
+            undoSettingsChange: mockUndoSettingsChange,
+        });
+    });
+
+    it('shows each change with its old and new value', async () => {
+        render(<SettingsHistory />);
+
+        const items = await screen.findAllByTestId('settings-history-item');
+        expect(items).toHaveLength(2);
+        expect(items[0]).toHaveTextContent('proxyMode');
+        expect(items[0]).toHaveTextContent('"system" → "manual"');
+        expect(items[1]).toHaveTextContent('(unset) → "dark"');
+    });
+
+    it('shows settings saved together as one entry', async () => {
+        mockGetSettingsHistory.mockResolvedValue([
+            {
+                timestamp: 3,
+                key: 'proxyMode',
+                previous: 'system',
+                value: 'http',
+                batch: [{ timestamp: 3, key: 'proxyHost', previous: '', value: 'proxy.corp' }],
+            },
+        ]);
+
+        render(<SettingsHistory />);
+
+        const items = await screen.findAllByTestId('settings-history-item');
+        expect(items).toHaveLength(1);
+        expect(items[0]).toHaveTextContent('proxyHost');
+        expect(items[0]).toHaveTextContent('"" → "proxy.corp"');
+    });
+
+    it('shows a placeholder when nothing has changed', async () => {
+        mockGetSettingsHistory.mockResolvedValue([]);
+
+        render(<SettingsHistory />);
+
+        expect(await screen.findByText('No settings changes recorded yet.')).toBeInTheDocument();
+    });
+
+    it('undoes a change and every later one', async () => {
+        render(<SettingsHistory />);
+
+        fireEvent.click(await screen.findByTestId('settings-history-undo-1'));
+
+        await waitFor(() => {
+            expect(screen.getByTestId('settings-history-status')).toHaveTextContent('Restored proxyMode, theme');
+        });
+        expect(mockUndoSettingsChange).toHaveBeenCalledWith(2);
+        expect(mockGetSettingsHistory).toHaveBeenCalledTimes(2);
+    });
+
+    it('does nothing when the undo is not confirmed', async () => {
+        vi.mocked(window.confirm).mockReturnValue(false);
+        render(<SettingsHistory />);
+
+        fireEvent.click(await screen.findByTestId('settings-history-undo-0'));
+
+        expect(mockUndoSettingsChange).not.toHaveBeenCalled();
+    });
+});
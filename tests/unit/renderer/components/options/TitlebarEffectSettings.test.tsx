@@ -0,0 +1,46 @@
+/**
+ * Unit tests for TitlebarEffectSettings component.
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, fireEvent, waitFor } from '@testing-library/react';
+import { TitlebarEffectSettings } from '../../../../../src/renderer/components/options/TitlebarEffectSettings';
+import { setupMockElectronAPI } from '../../../../helpers/mocks';
+
+describe('TitlebarEffectSettings', () => {
+    const mockSetTitlebarEffect = vi.fn();
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockSetTitlebarEffect.mockResolvedValue(true);
+    });
+
+    it('offers the supported effects and sets the chosen one', async () => {
+        setupMockElectronAPI({
+            getTitlebarEffect: vi.fn().mockResolvedValue({ effect: 'mica', supported: ['none', 'mica', 'acrylic'] }),
+            setTitlebarEffect: mockSetTitlebarEffect,
+            onTitlebarEffectChanged: vi.fn().mockReturnValue(() => {}),
+        });
+        render(<TitlebarEffectSettings />);
+
+        const select = screen.getByTestId('titlebar-effect-select');
+        await waitFor(() => expect(select).toHaveValue('mica'));
+        expect(select).toBeEnabled();
+        expect(screen.getAllByRole('option').map((option) => option.textContent)).toEqual(['None', 'Mica', 'Acrylic']);
+
+        fireEvent.change(select, { target: { value: 'acrylic' } });
+        expect(mockSetTitlebarEffect).toHaveBeenCalledWith('acrylic');
+    });
+
+    it('is disabled with a hint where no effect is supported', async () => {
+        setupMockElectronAPI({
+            getTitlebarEffect: vi.fn().mockResolvedValue({ effect: 'none', supported: ['none'] }),
+            setTitlebarEffect: mockSetTitlebarEffect,
+            onTitlebarEffectChanged: vi.fn().mockReturnValue(() => {}),
+        });
+        render(<TitlebarEffectSettings />);
+
+        expect(await screen.findByTestId('titlebar-effect-hint')).toBeInTheDocument();
+        expect(screen.getByTestId('titlebar-effect-select')).toBeDisabled();
+    });
+});
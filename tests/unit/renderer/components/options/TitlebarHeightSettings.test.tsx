@@ -0,0 +1,41 @@
+/**
+ * Unit tests for TitlebarHeightSettings component.
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, fireEvent, waitFor } from '@testing-library/react';
+import { TitlebarHeightSettings } from '../../../../../src/renderer/components/options/TitlebarHeightSettings';
+import { setupMockElectronAPI } from '../../../../helpers/mocks';
+
+describe('TitlebarHeightSettings', () => {
+    const mockSetTitlebarHeight = vi.fn();
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockSetTitlebarHeight.mockResolvedValue(true);
+
+        setupMockElectronAPI({
+            getTitlebarHeight: vi.fn().mockResolvedValue(40),
+            setTitlebarHeight: mockSetTitlebarHeight,
+            onTitlebarHeightChanged: vi.fn().mockReturnValue(() => {}),
+        });
+    });
+
+    it('shows the saved height', async () => {
+        render(<TitlebarHeightSettings />);
+
+        await waitFor(() => {
+            expect(screen.getByTestId('titlebar-height-value')).toHaveTextContent('40px');
+        });
+        expect(screen.getByTestId('titlebar-height-slider')).toHaveValue('40');
+    });
+
+    it('sets the height when the slider moves', async () => {
+        render(<TitlebarHeightSettings />);
+
+        fireEvent.change(await screen.findByTestId('titlebar-height-slider'), { target: { value: '48' } });
+
+        expect(mockSetTitlebarHeight).toHaveBeenCalledWith(48);
+        expect(screen.getByTestId('titlebar-height-value')).toHaveTextContent('48px');
+    });
+});
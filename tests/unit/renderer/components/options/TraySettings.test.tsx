@@ -0,0 +1,42 @@
+/**
+ * Unit tests for TraySettings component.
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, fireEvent, waitFor } from '@testing-library/react';
+import { TraySettings } from '../../../../../src/renderer/components/options/TraySettings';
+import { setupMockElectronAPI } from '../../../../helpers/mocks';
+
+describe('TraySettings', () => {
+    const mockGetCloseToTray = vi.fn();
+    const mockSetCloseToTray = vi.fn();
+
+    beforeEach(() => {
+        vi.clearAllMocks();
+        mockGetCloseToTray.mockResolvedValue(true);
+
+        setupMockElectronAPI({
+            getCloseToTray: mockGetCloseToTray,
+            setCloseToTray: mockSetCloseToTray,
+        });
+    });
+
+    it('shows the saved setting', async () => {
+        mockGetCloseToTray.mockResolvedValue(false);
+
+        render(<TraySettings />);
+
+        await waitFor(() => {
+            expect(screen.getByTestId('close-to-tray-toggle-switch')).toHaveAttribute('aria-checked', 'false');
+        });
+    });
+
+    it('saves the setting when toggled', async () => {
+        render(<TraySettings />);
+
+        fireEvent.click(await screen.findByTestId('close-to-tray-toggle-switch'));
+
+        expect(mockSetCloseToTray).toHaveBeenCalledWith(false);
+        expect(screen.getByTestId('close-to-tray-toggle-switch')).toHaveAttribute('aria-checked', 'false');
+    });
+});
@@ -53,7 +53,43 @@ const mockElectronAPI = {
     closeWindow: vi.fn(),
     openOptions: vi.fn(),
     openGoogleSignIn: vi.fn().mockResolvedValue(undefined),
+    quitApp: vi.fn(),
+    getCloseToTray: vi.fn().mockResolvedValue(true),
+    setCloseToTray: vi.fn(),
+    getCapabilityReport: vi.fn().mockResolvedValue({
+        platform: 'win32',
+        versions: { electron: '39.0.0', chrome: '142.0.0.0' },
+        capabilities: {
+            globalHotkeys: { available: true },
+            notifications: { available: true },
+            keychain: { available: true },
+            tray: { available: true },
+            autoUpdate: { available: true },
+            windowOpacity: { available: true },
+        },
+    }),
+    getWhatsNew: vi.fn().mockResolvedValue({
+        currentVersion: '0.5.1',
+        previousVersion: '0.5.1',
+        entries: [],
+        actions: [],
+    }),
+    acknowledgeWhatsNew: vi.fn(),
+    getDiskSpaceStatus: vi.fn().mockResolvedValue({
+        freeBytes: 10_000_000_000,
+        thresholdBytes: 524_288_000,
+        low: false,
+    }),
+    getFocusSessionStatus: vi.fn().mockResolvedValue({ active: false, startedAt: null, endsAt: null, entryCount: 0 }),
+    toggleFocusSession: vi.fn(),
+    onFocusSessionChanged: vi.fn().mockReturnValue(() => {}),
     isMaximized: vi.fn().mockResolvedValue(false),
+    openNewWindow: vi.fn(),
+    toggleFullScreen: vi.fn(),
+    isFullScreen: vi.fn().mockResolvedValue(false),
+    onFullScreenChanged: vi.fn().mockReturnValue(() => {}),
+    getDisplays: vi.fn().mockResolvedValue([]),
+    moveToDisplay: vi.fn(),
 
     // Theme API - returns object with preference and effectiveTheme
     getTheme: vi.fn().mockResolvedValue({ preference: 'system', effectiveTheme: 'dark' }),
@@ -94,25 +130,176 @@ const mockElectronAPI = {
     setAlwaysOnTop: vi.fn(),
     onAlwaysOnTopChanged: vi.fn().mockReturnValue(() => {}),
 
+    // Immersive Mode API
+    getImmersiveMode: vi.fn().mockResolvedValue({ enabled: false }),
+    toggleImmersiveMode: vi.fn(),
+    onImmersiveModeChanged: vi.fn().mockReturnValue(() => {}),
+    getCompactMode: vi.fn().mockResolvedValue({ enabled: false }),
+    toggleCompactMode: vi.fn(),
+    onCompactModeChanged: vi.fn().mockReturnValue(() => {}),
+    getWindowOpacity: vi.fn().mockResolvedValue({ opacity: 1 }),
+    setWindowOpacity: vi.fn(),
+    onWindowOpacityChanged: vi.fn().mockReturnValue(() => {}),
+    getOverlayMode: vi.fn().mockResolvedValue({ enabled: false }),
+    toggleOverlayMode: vi.fn(),
+    onOverlayModeChanged: vi.fn().mockReturnValue(() => {}),
+
+    // Titlebar API
+    getTitlebarState: vi.fn().mockResolvedValue({ widgets: [] }),
+    onTitlebarStateChanged: vi.fn().mockReturnValue(() => {}),
+    getTitlebarHeight: vi.fn().mockResolvedValue(32),
+    setTitlebarHeight: vi.fn().mockResolvedValue(true),
+    onTitlebarHeightChanged: vi.fn().mockReturnValue(() => {}),
+    getTitlebarEffect: vi.fn().mockResolvedValue({ effect: 'none', supported: ['none'] }),
+    setTitlebarEffect: vi.fn().mockResolvedValue(true),
+    onTitlebarEffectChanged: vi.fn().mockReturnValue(() => {}),
+
+    // App Events API
+    getRecentEvents: vi.fn().mockResolvedValue([]),
+
     // Zoom API
     getZoomLevel: vi.fn().mockResolvedValue(100),
     zoomIn: vi.fn().mockResolvedValue(110),
     zoomOut: vi.fn().mockResolvedValue(90),
+    setZoomLevel: vi.fn().mockResolvedValue(100),
+    resetZoom: vi.fn().mockResolvedValue(100),
     onZoomLevelChanged: vi.fn().mockReturnValue(() => {}),
 
     // Quick Chat API
     submitQuickChat: vi.fn(),
     hideQuickChat: vi.fn(),
     cancelQuickChat: vi.fn(),
+    translateQuickChat: vi.fn(),
+    getTranslateLanguage: vi.fn().mockResolvedValue('English'),
     onQuickChatExecute: vi.fn().mockReturnValue(() => {}),
 
     // Gemini Iframe Navigation API
     onGeminiNavigate: vi.fn().mockReturnValue(() => {}),
     signalGeminiReady: vi.fn(),
+    reloadGemini: vi.fn(),
+    goBackGemini: vi.fn(),
+    goForwardGemini: vi.fn(),
+    goHomeGemini: vi.fn(),
+    printGemini: vi.fn(),
+    reloadUserStyles: vi.fn(),
+    getGeminiNavigationState: vi.fn().mockResolvedValue({ canGoBack: false, canGoForward: false }),
+    onGeminiNavigationChanged: vi.fn().mockReturnValue(() => {}),
+    getGeminiLocation: vi.fn().mockResolvedValue({
+        url: 'https://gemini.google.com/app/abc123',
+        title: '',
+        conversationId: 'abc123',
+    }),
+    onGeminiLocationChanged: vi.fn().mockReturnValue(() => {}),
 
     // Export API
     exportChatToPdf: vi.fn(),
     exportChatToMarkdown: vi.fn(),
+    exportChatToConfluence: vi.fn(),
+    exportCanvasToMarkdown: vi.fn(),
+    exportResearchReport: vi.fn(),
+    getConfluenceSettings: vi.fn().mockResolvedValue({
+        baseUrl: '',
+        email: '',
+        apiToken: '',
+        spaceKey: '',
+        parentId: '',
+    }),
+    setConfluenceSettings: vi.fn(),
+    getAutoCopySettings: vi.fn().mockResolvedValue({ mode: 'off', format: 'markdown' }),
+    setAutoCopySettings: vi.fn(),
+    getExportFilenameTemplate: vi.fn().mockResolvedValue('{title}'),
+    setExportFilenameTemplate: vi.fn(),
+    cancelExport: vi.fn(),
+    onExportProgress: vi.fn().mockReturnValue(() => {}),
+
+    // Logging API
+    getLogLevel: vi.fn().mockResolvedValue(''),
+    setLogLevel: vi.fn(),
+
+    // Pinned Answer Cards API
+    pinLastAnswer: vi.fn(),
+
+    // Answer Sharing API
+    requestSharePreview: vi.fn(),
+    onSharePreview: vi.fn().mockReturnValue(() => {}),
+    postShare: vi.fn(),
+    getShareSettings: vi.fn().mockResolvedValue({ service: 'slack', webhookUrl: '' }),
+    setShareSettings: vi.fn(),
+    shareAsQr: vi.fn(),
+
+    // Issue Creation API
+    requestIssueDraft: vi.fn(),
+    onIssueDraft: vi.fn().mockReturnValue(() => {}),
+    createIssue: vi.fn().mockResolvedValue({ success: true, url: 'https://github.com/o/r/issues/1' }),
+    getIssueTrackerSettings: vi.fn().mockResolvedValue({
+        tracker: 'github',
+        githubRepository: '',
+        githubToken: '',
+        jiraBaseUrl: '',
+        jiraEmail: '',
+        jiraApiToken: '',
+        jiraProjectKey: '',
+    }),
+    setIssueTrackerSettings: vi.fn(),
+    captureScreenshot: vi.fn(),
+
+    // Userscripts API
+    listUserscripts: vi.fn().mockResolvedValue([]),
+    setUserscriptEnabled: vi.fn(),
+    openUserscriptsFolder: vi.fn(),
+
+    // Settings Maintenance API
+    resetSettings: vi.fn().mockResolvedValue({ success: true, backupPath: null }),
+    getSettingsHistory: vi.fn().mockResolvedValue([]),
+    undoSettingsChange: vi.fn().mockResolvedValue({ success: true, undone: [], restartRecommended: false }),
+
+    // Upstream Proxy API
+    getProxySettings: vi.fn().mockResolvedValue({
+        mode: 'system',
+        host: '',
+        port: 8080,
+        username: '',
+        password: '',
+        bypass: '',
+    }),
+    setProxySettings: vi.fn(),
+    testProxyConnection: vi.fn().mockResolvedValue({ success: true, statusCode: 200, latencyMs: 100 }),
+
+    // Usage Tracking API
+    getUsageSettings: vi.fn().mockResolvedValue({ enabled: false, idleDetection: true }),
+    setUsageSettings: vi.fn(),
+    getUsageReport: vi.fn().mockResolvedValue({ todaySeconds: 0, weekSeconds: 0, todayPrompts: 0, days: [] }),
+    getUsageLimitStatus: vi.fn().mockResolvedValue({
+        blocked: false,
+        reason: null,
+        limits: { dailyMinutes: 0, dailyPrompts: 0 },
+        enforcedByPolicy: false,
+    }),
+    setUsageLimits: vi.fn(),
+    onUsageLimitChanged: vi.fn().mockReturnValue(() => {}),
+    getGeminiQuotaStatus: vi.fn().mockResolvedValue({
+        plan: 'off',
+        customDailyPrompts: 0,
+        dailyPrompts: 0,
+        used: 0,
+        remaining: 0,
+        level: 'ok',
+    }),
+    setGeminiQuota: vi.fn(),
+    onGeminiQuotaChanged: vi.fn().mockReturnValue(() => {}),
+
+    // User-Agent API
+    getUserAgentSettings: vi.fn().mockResolvedValue({ preset: 'default', custom: '' }),
+    setUserAgentSettings: vi.fn(),
+    getGeminiLanguage: vi.fn().mockResolvedValue(''),
+    setGeminiLanguage: vi.fn(),
+    getSpellCheckSettings: vi.fn().mockResolvedValue({ enabled: true, languages: [], available: [] }),
+    setSpellCheckEnabled: vi.fn(),
+    setSpellCheckLanguages: vi.fn(),
+
+    // Link Handling API
+    getAllowedHosts: vi.fn().mockResolvedValue('consent.google.com'),
+    setAllowedHosts: vi.fn(),
 
     // Toast API
     onToastShow: vi.fn().mockReturnValue(() => {}),